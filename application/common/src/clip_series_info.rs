@@ -0,0 +1,37 @@
+use domain::clip_series::ClipSeries;
+use domain::movie_clip::MovieClipId;
+use serde::{Deserialize, Serialize};
+
+/// クリップのシリーズを新規に保存するためにAPIにjsonとして渡す情報．id, like, create_dateはサーバーが決定する．
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NewClipSeriesInfo {
+    pub title: String,
+    pub description: String,
+    /// 再生する順番に並べたクリップのid
+    pub clips: Vec<MovieClipId>,
+}
+
+impl NewClipSeriesInfo {
+    pub fn new(
+        title: impl Into<String>,
+        description: impl Into<String>,
+        clips: Vec<MovieClipId>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            clips,
+        }
+    }
+}
+
+/// id, like, create_dateを捨ててNewClipSeriesInfoに変換する．
+impl From<ClipSeries> for NewClipSeriesInfo {
+    fn from(clip_series: ClipSeries) -> Self {
+        Self {
+            title: clip_series.title().to_string(),
+            description: clip_series.description().to_string(),
+            clips: clip_series.clips().to_vec(),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use domain::ValidationError;
 use serde::{Deserialize, Serialize};
 
 /// サーバーサイドのエラーをフロントエンドに伝えるためのエラー．
@@ -6,6 +7,9 @@ pub enum AppCommonError {
     #[error("AppCommonError::DomainError: {0}")]
     DomainError(String),
 
+    #[error("AppCommonError::ValidationError: {0}")]
+    ValidationError(ValidationError),
+
     #[error("AppCommonError::DBConnectionError: {0}")]
     DBConnectionError(String),
 
@@ -21,6 +25,9 @@ pub enum AppCommonError {
     #[error("AppCommonError::NoRecordError: not existing row accessed")]
     NoRecordError,
 
+    #[error("AppCommonError::StaleVersion: row has been edited by another request")]
+    StaleVersion,
+
     #[error("AppCommonError::JsonRejectionError: {0}")]
     JsonRejectionError(String),
 
@@ -29,12 +36,34 @@ pub enum AppCommonError {
 
     #[error("AppCommonError::PathRejectionError: {0}")]
     PathRejectionError(String),
+
+    #[error("AppCommonError::UnauthorizedError: valid session is required")]
+    UnauthorizedError,
+
+    #[error("AppCommonError::ForbiddenError: role of the session is not permitted")]
+    ForbiddenError,
+
+    #[error("AppCommonError::NoClientTokenError: valid client token is required")]
+    NoClientTokenError,
+
+    #[error("AppCommonError::IncorrectAnswerError: answer of the quiz is incorrect")]
+    IncorrectAnswerError,
+
+    #[error("AppCommonError::RateLimited: too many requests, retry after {retry_after} seconds")]
+    RateLimited { retry_after: u64 },
+
+    #[error("AppCommonError::MissingExtensionError: {0} is not added to the router")]
+    MissingExtensionError(String),
 }
 
 #[cfg(feature = "server")]
 mod from_server_errors_into_response {
     use super::AppCommonError;
-    use axum::{http::StatusCode, response::IntoResponse, Json};
+    use axum::{
+        http::{header, StatusCode},
+        response::IntoResponse,
+        Json,
+    };
     use domain::DomainError;
     use infrastructure::InfraError;
 
@@ -43,6 +72,9 @@ mod from_server_errors_into_response {
     impl From<InfraError> for AppCommonError {
         fn from(infra_error: InfraError) -> Self {
             match infra_error {
+                InfraError::DomainError(DomainError::DomainValidationError(validation_error)) => {
+                    AppCommonError::ValidationError(validation_error)
+                }
                 e @ InfraError::DomainError(_) => AppCommonError::DomainError(format!("{e}")),
                 e @ InfraError::DBConnectionError(_) => {
                     AppCommonError::DBConnectionError(format!("{e}"))
@@ -51,13 +83,19 @@ mod from_server_errors_into_response {
                 e @ InfraError::DBDecodeError(_) => AppCommonError::DBDecodeError(format!("{e}")),
                 InfraError::ConflictError => AppCommonError::ConflictError,
                 InfraError::NoRecordError => AppCommonError::NoRecordError,
+                InfraError::StaleVersion => AppCommonError::StaleVersion,
             }
         }
     }
 
     impl From<DomainError> for AppCommonError {
         fn from(domain_error: DomainError) -> Self {
-            AppCommonError::DomainError(format!("{domain_error}"))
+            match domain_error {
+                DomainError::DomainValidationError(validation_error) => {
+                    AppCommonError::ValidationError(validation_error)
+                }
+                _ => AppCommonError::DomainError(format!("{domain_error}")),
+            }
         }
     }
 
@@ -92,8 +130,86 @@ mod from_server_errors_into_response {
                     (StatusCode::BAD_REQUEST, Json(self)).into_response()
                 }
                 Self::PathRejectionError(_) => (StatusCode::NOT_FOUND, Json(self)).into_response(),
+                Self::NoRecordError => (StatusCode::NOT_FOUND, Json(self)).into_response(),
+                Self::ConflictError => (StatusCode::CONFLICT, Json(self)).into_response(),
+                Self::StaleVersion => (StatusCode::CONFLICT, Json(self)).into_response(),
+                Self::DomainError(_) => {
+                    (StatusCode::UNPROCESSABLE_ENTITY, Json(self)).into_response()
+                }
+                Self::ValidationError(_) => {
+                    (StatusCode::UNPROCESSABLE_ENTITY, Json(self)).into_response()
+                }
+                Self::DBConnectionError(_) => {
+                    (StatusCode::SERVICE_UNAVAILABLE, Json(self)).into_response()
+                }
+                Self::UnauthorizedError => (StatusCode::UNAUTHORIZED, Json(self)).into_response(),
+                Self::ForbiddenError => (StatusCode::FORBIDDEN, Json(self)).into_response(),
+                Self::NoClientTokenError => (StatusCode::UNAUTHORIZED, Json(self)).into_response(),
+                Self::IncorrectAnswerError => {
+                    (StatusCode::UNAUTHORIZED, Json(self)).into_response()
+                }
+                Self::RateLimited { retry_after } => (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(header::RETRY_AFTER, retry_after.to_string())],
+                    Json(self),
+                )
+                    .into_response(),
                 _ => (StatusCode::INTERNAL_SERVER_ERROR, Json(self)).into_response(),
             }
         }
     }
+
+    #[cfg(test)]
+    mod test {
+        use super::AppCommonError;
+        use axum::{http::StatusCode, response::IntoResponse};
+        use domain::{ValidationCode, ValidationError};
+
+        #[test]
+        fn test_status_code() {
+            let cases = [
+                (AppCommonError::NoRecordError, StatusCode::NOT_FOUND),
+                (AppCommonError::ConflictError, StatusCode::CONFLICT),
+                (AppCommonError::StaleVersion, StatusCode::CONFLICT),
+                (
+                    AppCommonError::DomainError("invalid".to_string()),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                ),
+                (
+                    AppCommonError::ValidationError(ValidationError::new(
+                        ValidationCode::EndBeforeStart,
+                        "range.end",
+                    )),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                ),
+                (
+                    AppCommonError::DBConnectionError("closed".to_string()),
+                    StatusCode::SERVICE_UNAVAILABLE,
+                ),
+                (
+                    AppCommonError::JsonRejectionError("invalid".to_string()),
+                    StatusCode::BAD_REQUEST,
+                ),
+                (AppCommonError::UnauthorizedError, StatusCode::UNAUTHORIZED),
+                (AppCommonError::ForbiddenError, StatusCode::FORBIDDEN),
+                (AppCommonError::NoClientTokenError, StatusCode::UNAUTHORIZED),
+                (
+                    AppCommonError::RateLimited { retry_after: 1 },
+                    StatusCode::TOO_MANY_REQUESTS,
+                ),
+                (
+                    AppCommonError::OtherSQLXError("error".to_string()),
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ),
+                (
+                    AppCommonError::MissingExtensionError("AuthConfig".to_string()),
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ),
+            ];
+
+            for (err, status_code) in cases {
+                assert_eq!(err.into_response().status(), status_code);
+            }
+        }
+    }
 }
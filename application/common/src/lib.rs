@@ -1,5 +1,17 @@
+mod clip_series_info;
 mod common_error;
-mod query_info;
+mod login_info;
+mod movie_clip_info;
+mod page;
+mod quiz_info;
+mod user_info;
+mod video_info;
 
+pub use clip_series_info::NewClipSeriesInfo;
 pub use common_error::AppCommonError;
-pub use query_info::{QueryInfo, QueryInfoRef};
+pub use login_info::LoginInfo;
+pub use movie_clip_info::{NewMovieClipInfo, NewMovieClipInput};
+pub use page::Page;
+pub use quiz_info::{EditToken, QuizAnswer, QuizQuestion};
+pub use user_info::{NewUserInfo, UserInfo};
+pub use video_info::{NewVideoInfo, NewVideoInput};
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// ログインのためにAPIにjsonとして渡す情報．`name`が無い場合は管理者としてログインする．
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct LoginInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub password: String,
+}
+
+impl LoginInfo {
+    /// 管理者としてログインするための情報
+    pub fn new(password: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            password: password.into(),
+        }
+    }
+    /// 登録されたユーザーとしてログインするための情報
+    pub fn with_name(name: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            password: password.into(),
+        }
+    }
+}
+
+/// パスワードがログに出力されないようにする．
+impl std::fmt::Debug for LoginInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginInfo")
+            .field("name", &self.name)
+            .field("password", &"********")
+            .finish()
+    }
+}
@@ -0,0 +1,59 @@
+use domain::movie_clip::{MovieClip, Second, SecondRange, SecondRangeInput};
+use domain::{field_path, DomainError, FromInput, MovieUrl};
+use serde::{Deserialize, Serialize};
+
+/// クリップを新規に保存するためにAPIにjsonとして渡す情報．id, like, create_dateはサーバーが決定する．
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NewMovieClipInfo {
+    pub title: String,
+    pub url: MovieUrl,
+    pub range: SecondRange,
+    /// クリップの元動画の長さ．不明な場合はNone
+    #[serde(default)]
+    pub duration: Option<Second>,
+}
+
+impl NewMovieClipInfo {
+    pub fn new(title: impl Into<String>, url: MovieUrl, range: SecondRange) -> Self {
+        Self {
+            title: title.into(),
+            url,
+            range,
+            duration: None,
+        }
+    }
+}
+
+/// 検証前の`NewMovieClipInfo`の入力．url・再生範囲は`FromInput`で検証する
+#[derive(Deserialize, Debug, Clone)]
+pub struct NewMovieClipInput {
+    pub title: String,
+    pub url: String,
+    pub range: SecondRangeInput,
+    #[serde(default)]
+    pub duration: Option<Second>,
+}
+
+impl FromInput for NewMovieClipInfo {
+    type Input = NewMovieClipInput;
+    fn from_input(input: NewMovieClipInput, field: &str) -> Result<Self, DomainError> {
+        Ok(Self {
+            title: input.title,
+            url: MovieUrl::from_input(input.url, &field_path(field, "url"))?,
+            range: SecondRange::from_input(input.range, &field_path(field, "range"))?,
+            duration: input.duration,
+        })
+    }
+}
+
+/// id, like, create_dateを捨ててNewMovieClipInfoに変換する．
+impl From<MovieClip> for NewMovieClipInfo {
+    fn from(movie_clip: MovieClip) -> Self {
+        Self {
+            title: movie_clip.title().to_string(),
+            url: movie_clip.url().clone(),
+            range: movie_clip.range().clone(),
+            duration: movie_clip.duration(),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use domain::PageSize;
+use serde::{Deserialize, Serialize};
+
+/// クエリで取得したページ．`next_cursor`をクエリパラメーターとして渡すことで続きを取得できる．
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// 取得した要素
+    pub items: Vec<T>,
+    /// 続きの要素が存在するかどうか
+    pub has_more: bool,
+    /// 続きを取得するためのサーバーが署名したカーソル．中身に依存してはならない．
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, has_more: bool, next_cursor: Option<String>) -> Self {
+        Self {
+            items,
+            has_more,
+            next_cursor,
+        }
+    }
+    /// `page_size`より一つ多く取得した要素からページを作成する．カーソルは持たない．
+    pub fn from_lookahead(mut items: Vec<T>, page_size: PageSize) -> Self {
+        let has_more = items.len() > page_size.get();
+        items.truncate(page_size.get());
+        Self::new(items, has_more, None)
+    }
+    /// 続きが存在する場合に，最後の要素から続きのカーソルを作成する．
+    pub fn with_next_cursor(mut self, issue: impl FnOnce(&T) -> String) -> Self {
+        if self.has_more {
+            self.next_cursor = self.items.last().map(issue);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Page;
+    use domain::episode::Episode;
+    use domain::PageSize;
+
+    use fake::{Fake, Faker};
+
+    #[test]
+    fn test_serialize_deserialize_json() {
+        let page = Page::new(
+            vec![Faker.fake::<Episode>(), Faker.fake::<Episode>()],
+            true,
+            Some("cursor".to_string()),
+        );
+
+        let page_json = serde_json::to_string(&page).unwrap();
+        let page_de = serde_json::from_str::<Page<Episode>>(&page_json).unwrap();
+        assert_eq!(page, page_de);
+    }
+
+    #[test]
+    fn test_from_lookahead() {
+        let page_size = PageSize::new(3).unwrap();
+
+        // 一つ多く取得できた場合は続きが存在する
+        let page = Page::from_lookahead(vec![1, 2, 3, 4], page_size)
+            .with_next_cursor(|last| format!("cursor{last}"));
+        assert_eq!(
+            page,
+            Page::new(vec![1, 2, 3], true, Some("cursor3".to_string()))
+        );
+
+        // 取得数がページのサイズ以下の場合は続きが存在しない
+        let page = Page::from_lookahead(vec![1, 2, 3], page_size)
+            .with_next_cursor(|last| format!("cursor{last}"));
+        assert_eq!(page, Page::new(vec![1, 2, 3], false, None));
+    }
+}
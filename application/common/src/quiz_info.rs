@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// APIが出題するクイズ．回答は含まず，署名付きのチャレンジidを含む．
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuizQuestion {
+    pub challenge_id: String,
+    pub question: String,
+}
+
+/// クイズに回答するためにAPIにjsonとして渡す情報
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuizAnswer {
+    pub challenge_id: String,
+    pub answer: String,
+}
+
+impl QuizAnswer {
+    pub fn new(challenge_id: impl Into<String>, answer: impl Into<String>) -> Self {
+        Self {
+            challenge_id: challenge_id.into(),
+            answer: answer.into(),
+        }
+    }
+}
+
+/// クイズに正解したときにAPIが返す編集用のトークン．`max_age`は有効期間(秒)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EditToken {
+    pub token: String,
+    pub max_age: u64,
+}
@@ -0,0 +1,49 @@
+use domain::user::{Role, User, UserId};
+use serde::{Deserialize, Serialize};
+
+/// APIが返すユーザーの情報．パスワードのハッシュは含まない．
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UserInfo {
+    pub id: UserId,
+    pub name: String,
+    pub role: Role,
+}
+
+impl From<User> for UserInfo {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id(),
+            name: user.name().to_string(),
+            role: user.role(),
+        }
+    }
+}
+
+/// ユーザーを登録するためにAPIにjsonとして渡す情報
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewUserInfo {
+    pub name: String,
+    pub password: String,
+    pub role: Role,
+}
+
+impl NewUserInfo {
+    pub fn new(name: impl Into<String>, password: impl Into<String>, role: Role) -> Self {
+        Self {
+            name: name.into(),
+            password: password.into(),
+            role,
+        }
+    }
+}
+
+/// パスワードがログに出力されないようにする．
+impl std::fmt::Debug for NewUserInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NewUserInfo")
+            .field("name", &self.name)
+            .field("password", &"********")
+            .field("role", &self.role)
+            .finish()
+    }
+}
@@ -0,0 +1,72 @@
+use domain::channel::ChannelId;
+use domain::movie_clip::Second;
+use domain::video::{Video, VideoType};
+use domain::{field_path, DateTime, DomainError, FromInput, MovieUrl};
+use serde::{Deserialize, Serialize};
+
+/// 動画を新規に保存するためにAPIにjsonとして渡す情報．id, likeはサーバーが決定し，投稿者名はチャンネルから導出する．
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NewVideoInfo {
+    pub title: String,
+    pub url: MovieUrl,
+    pub date: DateTime,
+    /// 動画を投稿したチャンネル
+    pub channel_id: ChannelId,
+    /// 動画の長さ．不明な場合はNone
+    #[serde(default)]
+    pub duration: Option<Second>,
+}
+
+impl NewVideoInfo {
+    pub fn new(
+        title: impl Into<String>,
+        url: MovieUrl,
+        date: DateTime,
+        channel_id: ChannelId,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            url,
+            date,
+            channel_id,
+            duration: None,
+        }
+    }
+}
+
+/// 検証前の`NewVideoInfo`の入力．urlは`FromInput`で検証する
+#[derive(Deserialize, Debug, Clone)]
+pub struct NewVideoInput {
+    pub title: String,
+    pub url: String,
+    pub date: DateTime,
+    pub channel_id: ChannelId,
+    #[serde(default)]
+    pub duration: Option<Second>,
+}
+
+impl FromInput for NewVideoInfo {
+    type Input = NewVideoInput;
+    fn from_input(input: NewVideoInput, field: &str) -> Result<Self, DomainError> {
+        Ok(Self {
+            title: input.title,
+            url: MovieUrl::from_input(input.url, &field_path(field, "url"))?,
+            date: input.date,
+            channel_id: input.channel_id,
+            duration: input.duration,
+        })
+    }
+}
+
+/// id, like, 投稿者名を捨ててNewVideoInfoに変換する．
+impl<T: VideoType> From<Video<T>> for NewVideoInfo {
+    fn from(video: Video<T>) -> Self {
+        Self {
+            title: video.title().to_string(),
+            url: video.url().clone(),
+            date: video.date(),
+            channel_id: video.channel_id(),
+            duration: video.duration(),
+        }
+    }
+}
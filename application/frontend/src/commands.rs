@@ -1,3 +1,10 @@
+pub mod auth_commands;
+pub mod channel_commands;
+pub mod clip_series_commands;
 pub mod episode_commands;
 pub mod movie_clip_commands;
+pub mod quiz_commands;
+pub mod revision_commands;
+pub mod search_commands;
+pub mod user_commands;
 pub mod video_commands;
@@ -0,0 +1,12 @@
+use derive_new::new;
+
+/// `name`がNoneの場合は管理者としてログインする．
+#[derive(new)]
+pub struct LoginCommand<'a> {
+    pub name: Option<&'a str>,
+    pub password: &'a str,
+}
+
+pub struct LogoutCommand;
+
+pub struct CheckSessionCommand;
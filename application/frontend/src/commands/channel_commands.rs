@@ -0,0 +1,27 @@
+use domain::channel::{Channel, ChannelId};
+
+use derive_new::new;
+
+#[derive(new)]
+pub struct SaveChannelCommand<'a> {
+    pub channel: &'a Channel,
+}
+
+#[derive(new)]
+pub struct EditChannelCommand<'a> {
+    pub channel: &'a Channel,
+}
+
+pub struct AllChannelsCommand;
+
+#[derive(new)]
+pub struct FindChannelCommand {
+    pub id: ChannelId,
+}
+
+pub struct ChannelSummariesCommand;
+
+#[derive(new)]
+pub struct RemoveChannelCommand {
+    pub id: ChannelId,
+}
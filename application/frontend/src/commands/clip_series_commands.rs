@@ -0,0 +1,63 @@
+use common::NewClipSeriesInfo;
+use domain::clip_series::{ClipSeries, ClipSeriesId};
+
+use derive_new::new;
+
+#[derive(new)]
+pub struct SaveClipSeriesCommand<'a> {
+    pub clip_series_info: &'a NewClipSeriesInfo,
+}
+
+#[derive(new)]
+pub struct EditClipSeriesCommand<'a> {
+    pub clip_series: &'a ClipSeries,
+}
+
+#[derive(new)]
+pub struct IncrementLikeClipSeriesCommand {
+    pub id: ClipSeriesId,
+}
+
+#[derive(new)]
+pub struct UnlikeClipSeriesCommand {
+    pub id: ClipSeriesId,
+}
+
+#[derive(new)]
+pub struct LikedClipSeriesIdsCommand<'a> {
+    pub ids: &'a [ClipSeriesId],
+}
+
+pub struct AllClipSeriesCommand;
+
+#[derive(new)]
+pub struct FindClipSeriesCommand {
+    pub id: ClipSeriesId,
+}
+
+#[derive(new)]
+pub struct OrderByLikeClipSeriesCommand {
+    pub length: usize,
+}
+
+#[derive(new)]
+pub struct OrderByLikeLaterClipSeriesCommand<'a> {
+    pub cursor: &'a str,
+    pub length: usize,
+}
+
+#[derive(new)]
+pub struct OrderByCreateDateClipSeriesCommand {
+    pub length: usize,
+}
+
+#[derive(new)]
+pub struct OrderByCreateDateLaterClipSeriesCommand<'a> {
+    pub cursor: &'a str,
+    pub length: usize,
+}
+
+#[derive(new)]
+pub struct RemoveClipSeriesCommand {
+    pub id: ClipSeriesId,
+}
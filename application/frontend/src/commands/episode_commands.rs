@@ -1,5 +1,5 @@
 use domain::episode::{Episode, EpisodeId};
-use domain::Date;
+use domain::{Date, Tag};
 
 use derive_new::new;
 
@@ -15,13 +15,46 @@ pub struct EditEpisodeCommand<'a> {
 
 pub struct AllEpisodesCommand;
 
+/// `tags`が空でない場合は`tags`を全て持つエピソードのみを取得する．
 #[derive(new)]
-pub struct OrderByDateRangeEpisodesCommand {
+pub struct OrderByDateRangeEpisodesCommand<'a> {
+    pub tags: &'a [Tag],
     pub start: Date,
     pub end: Date,
 }
 
+pub struct AllEpisodeTagsCommand;
+
+#[derive(new)]
+pub struct EpisodeTagsCommand {
+    pub id: EpisodeId,
+}
+
+#[derive(new)]
+pub struct AddEpisodeTagCommand<'a> {
+    pub id: EpisodeId,
+    pub tag: &'a Tag,
+}
+
+#[derive(new)]
+pub struct RemoveEpisodeTagCommand<'a> {
+    pub id: EpisodeId,
+    pub tag: &'a Tag,
+}
+
 #[derive(new)]
 pub struct RemoveEpisodeCommand {
     pub id: EpisodeId,
 }
+
+pub struct TrashedEpisodesCommand;
+
+#[derive(new)]
+pub struct RestoreTrashedEpisodeCommand {
+    pub id: EpisodeId,
+}
+
+#[derive(new)]
+pub struct PurgeEpisodeCommand {
+    pub id: EpisodeId,
+}
@@ -1,11 +1,12 @@
+use common::NewMovieClipInfo;
 use domain::movie_clip::{MovieClip, MovieClipId};
-use domain::Date;
+use domain::{Date, Tag};
 
 use derive_new::new;
 
 #[derive(new)]
 pub struct SaveMovieClipCommand<'a> {
-    pub movie_clip: &'a MovieClip,
+    pub movie_clip_info: &'a NewMovieClipInfo,
 }
 
 #[derive(new)]
@@ -13,21 +14,39 @@ pub struct EditMovieClipCommand<'a> {
     pub movie_clip: &'a MovieClip,
 }
 
+#[derive(new)]
+pub struct ImportMovieClipsCommand<'a> {
+    pub movie_clips: &'a [MovieClip],
+}
+
 #[derive(new)]
 pub struct IncrementLikeMovieClipCommand {
     pub id: MovieClipId,
 }
 
+#[derive(new)]
+pub struct UnlikeMovieClipCommand {
+    pub id: MovieClipId,
+}
+
+#[derive(new)]
+pub struct LikedMovieClipIdsCommand<'a> {
+    pub ids: &'a [MovieClipId],
+}
+
 pub struct AllMovieClipsCommand;
 
+/// `tags`が空でない場合は`tags`を全て持つクリップのみを取得する．
 #[derive(new)]
-pub struct OrderByLikeMovieClipsCommand {
+pub struct OrderByLikeMovieClipsCommand<'a> {
+    pub tags: &'a [Tag],
     pub length: usize,
 }
 
 #[derive(new)]
 pub struct OrderByLikeLaterMovieClipsCommand<'a> {
-    pub reference: &'a MovieClip,
+    pub tags: &'a [Tag],
+    pub cursor: &'a str,
     pub length: usize,
 }
 
@@ -38,17 +57,50 @@ pub struct OrderByCreateDateRangeMovieClipsCommand {
 }
 
 #[derive(new)]
-pub struct OrderByCreateDateMovieClipsCommand {
+pub struct OrderByCreateDateMovieClipsCommand<'a> {
+    pub tags: &'a [Tag],
     pub length: usize,
 }
 
 #[derive(new)]
 pub struct OrderByCreateDateLaterMovieClipsCommand<'a> {
-    pub reference: &'a MovieClip,
+    pub tags: &'a [Tag],
+    pub cursor: &'a str,
     pub length: usize,
 }
 
+pub struct AllMovieClipTagsCommand;
+
+#[derive(new)]
+pub struct MovieClipTagsCommand {
+    pub id: MovieClipId,
+}
+
+#[derive(new)]
+pub struct AddMovieClipTagCommand<'a> {
+    pub id: MovieClipId,
+    pub tag: &'a Tag,
+}
+
+#[derive(new)]
+pub struct RemoveMovieClipTagCommand<'a> {
+    pub id: MovieClipId,
+    pub tag: &'a Tag,
+}
+
 #[derive(new)]
 pub struct RemoveMovieClipCommand {
     pub id: MovieClipId,
 }
+
+pub struct TrashedMovieClipsCommand;
+
+#[derive(new)]
+pub struct RestoreTrashedMovieClipCommand {
+    pub id: MovieClipId,
+}
+
+#[derive(new)]
+pub struct PurgeMovieClipCommand {
+    pub id: MovieClipId,
+}
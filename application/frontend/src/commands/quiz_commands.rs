@@ -0,0 +1,28 @@
+use common::QuizAnswer;
+use domain::quiz::{Quiz, QuizId};
+
+use derive_new::new;
+
+pub struct QuestionCommand;
+
+#[derive(new)]
+pub struct AnswerCommand<'a> {
+    pub quiz_answer: &'a QuizAnswer,
+}
+
+#[derive(new)]
+pub struct SaveQuizCommand<'a> {
+    pub quiz: &'a Quiz,
+}
+
+#[derive(new)]
+pub struct EditQuizCommand<'a> {
+    pub quiz: &'a Quiz,
+}
+
+pub struct AllQuizzesCommand;
+
+#[derive(new)]
+pub struct RemoveQuizCommand {
+    pub id: QuizId,
+}
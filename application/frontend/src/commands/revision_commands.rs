@@ -0,0 +1,29 @@
+use domain::revision::{EntityKind, RevisionId};
+
+use derive_new::new;
+use uuid::Uuid;
+
+#[derive(new)]
+pub struct LatestRevisionsCommand {
+    pub kind: EntityKind,
+    pub length: usize,
+}
+
+#[derive(new)]
+pub struct EntityRevisionsCommand {
+    pub kind: EntityKind,
+    pub entity_id: Uuid,
+}
+
+#[derive(new)]
+pub struct RevisionDiffCommand {
+    pub kind: EntityKind,
+    pub from: RevisionId,
+    pub to: RevisionId,
+}
+
+#[derive(new)]
+pub struct RestoreRevisionCommand {
+    pub kind: EntityKind,
+    pub id: RevisionId,
+}
@@ -0,0 +1,10 @@
+use domain::search::SearchQuery;
+
+use derive_new::new;
+
+/// クリップ・エピソード・動画をまとめて検索する．
+#[derive(new)]
+pub struct SearchCommand<'a> {
+    pub query: &'a SearchQuery,
+    pub length: usize,
+}
@@ -0,0 +1,16 @@
+use common::NewUserInfo;
+use domain::user::UserId;
+
+use derive_new::new;
+
+#[derive(new)]
+pub struct SaveUserCommand<'a> {
+    pub user_info: &'a NewUserInfo,
+}
+
+pub struct AllUsersCommand;
+
+#[derive(new)]
+pub struct RemoveUserCommand {
+    pub id: UserId,
+}
@@ -1,10 +1,13 @@
+use common::NewVideoInfo;
+use domain::channel::ChannelId;
 use domain::video::{Video, VideoId, VideoType};
+use domain::Tag;
 
 use derive_new::new;
 
 #[derive(new)]
-pub struct SaveVideoCommand<'a, T: VideoType> {
-    pub video: &'a Video<T>,
+pub struct SaveVideoCommand<'a> {
+    pub video_info: &'a NewVideoInfo,
 }
 
 #[derive(new)]
@@ -12,37 +15,94 @@ pub struct EditVideoCommand<'a, T: VideoType> {
     pub video: &'a Video<T>,
 }
 
+#[derive(new)]
+pub struct ImportVideosCommand<'a, T: VideoType> {
+    pub videos: &'a [Video<T>],
+}
+
 #[derive(new)]
 pub struct IncrementLikeVideoCommand {
     pub id: VideoId,
 }
 
+#[derive(new)]
+pub struct UnlikeVideoCommand {
+    pub id: VideoId,
+}
+
+#[derive(new)]
+pub struct LikedVideoIdsCommand<'a> {
+    pub ids: &'a [VideoId],
+}
+
 #[derive(new)]
 pub struct AllVideosCommand;
 
+/// `tags`が空でない場合は`tags`を全て持つVideoのみを取得する．
 #[derive(new)]
-pub struct OrderByLikeVideosCommand {
+pub struct OrderByLikeVideosCommand<'a> {
+    pub tags: &'a [Tag],
     pub length: usize,
 }
 
 #[derive(new)]
-pub struct OrderByLikeLaterVideosCommand<'a, T: VideoType> {
-    pub reference: &'a Video<T>,
+pub struct OrderByLikeLaterVideosCommand<'a> {
+    pub tags: &'a [Tag],
+    pub cursor: &'a str,
     pub length: usize,
 }
 
 #[derive(new)]
-pub struct OrderByDateVideosCommand {
+pub struct OrderByDateVideosCommand<'a> {
+    pub tags: &'a [Tag],
     pub length: usize,
 }
 
 #[derive(new)]
-pub struct OrderByDateLaterVideosCommand<'a, T: VideoType> {
-    pub reference: &'a Video<T>,
+pub struct OrderByDateLaterVideosCommand<'a> {
+    pub tags: &'a [Tag],
+    pub cursor: &'a str,
     pub length: usize,
 }
 
+#[derive(new)]
+pub struct ChannelVideosCommand {
+    pub channel: ChannelId,
+}
+
+#[derive(new)]
+pub struct AllVideoTagsCommand;
+
+#[derive(new)]
+pub struct VideoTagsCommand {
+    pub id: VideoId,
+}
+
+#[derive(new)]
+pub struct AddVideoTagCommand<'a> {
+    pub id: VideoId,
+    pub tag: &'a Tag,
+}
+
+#[derive(new)]
+pub struct RemoveVideoTagCommand<'a> {
+    pub id: VideoId,
+    pub tag: &'a Tag,
+}
+
 #[derive(new)]
 pub struct RemoveVideoCommand {
     pub id: VideoId,
 }
+
+pub struct TrashedVideosCommand;
+
+#[derive(new)]
+pub struct RestoreTrashedVideoCommand {
+    pub id: VideoId,
+}
+
+#[derive(new)]
+pub struct PurgeVideoCommand {
+    pub id: VideoId,
+}
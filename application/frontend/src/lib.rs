@@ -3,7 +3,10 @@ mod error;
 pub mod usecases;
 pub mod utils;
 
-pub use common::AppCommonError;
+pub use common::{
+    AppCommonError, EditToken, NewClipSeriesInfo, NewMovieClipInfo, NewUserInfo, NewVideoInfo,
+    Page, QuizAnswer, QuizQuestion, UserInfo,
+};
 pub use error::AppFrontError;
 
 #[cfg(not(feature = "fake"))]
@@ -30,3 +33,25 @@ pub(crate) fn api_base_url() -> String {
         format!("{}{}", origin, CONFIG.api_domain)
     }
 }
+
+/// APIへのリクエストに用いるクライアント．wasm以外ではセッションのクッキーを保持するためにクッキージャーを共有する．
+#[cfg(not(feature = "fake"))]
+pub(crate) fn api_client() -> reqwest::Client {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use once_cell::sync::Lazy;
+        use reqwest::cookie::Jar;
+        use std::sync::Arc;
+
+        static COOKIE_JAR: Lazy<Arc<Jar>> = Lazy::new(|| Arc::new(Jar::default()));
+
+        reqwest::Client::builder()
+            .cookie_provider(COOKIE_JAR.clone())
+            .build()
+            .expect("Cannot build api client.")
+    }
+
+    // ブラウザではクッキーはブラウザが管理する
+    #[cfg(target_arch = "wasm32")]
+    reqwest::Client::new()
+}
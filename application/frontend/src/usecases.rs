@@ -1,3 +1,10 @@
+pub mod auth_usecase;
+pub mod channel_usecase;
+pub mod clip_series_usecase;
 pub mod episode_usecase;
 pub mod movie_clip_usecase;
+pub mod quiz_usecase;
+pub mod revision_usecase;
+pub mod search_usecase;
+pub mod user_usecase;
 pub mod video_usecase;
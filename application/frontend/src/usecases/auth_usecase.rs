@@ -0,0 +1,279 @@
+#[cfg(not(feature = "fake"))]
+pub use self::product::*;
+
+#[cfg(feature = "fake")]
+pub use self::fake::*;
+
+/// APIをチェックするためのbehavior
+#[cfg(test)]
+mod _behavior {
+    use crate::commands::auth_commands;
+    use crate::AppFrontError;
+    use domain::user::Role;
+
+    #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
+    #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
+    #[async_trait::async_trait]
+    trait Behavior {
+        async fn login<'a>(cmd: auth_commands::LoginCommand<'a>) -> Result<(), AppFrontError>;
+        async fn logout(cmd: auth_commands::LogoutCommand) -> Result<(), AppFrontError>;
+        async fn check_session(
+            cmd: auth_commands::CheckSessionCommand,
+        ) -> Result<Role, AppFrontError>;
+    }
+}
+
+#[cfg(not(feature = "fake"))]
+mod product {
+    /// テストのためにuriを引数とした関数にするためのモジュール
+    pub(crate) mod product_inner {
+        use crate::commands::auth_commands;
+        use crate::{
+            api_client,
+            utils::{deserialize_response, deserialize_response_null},
+            AppFrontError,
+        };
+        use common::LoginInfo;
+        use domain::user::Role;
+
+        /// ログイン
+        pub async fn login<'a>(
+            url: &str,
+            cmd: auth_commands::LoginCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let login_info = match cmd.name {
+                Some(name) => LoginInfo::with_name(name, cmd.password),
+                None => LoginInfo::new(cmd.password),
+            };
+            let request = api_client()
+                .post(&format!("{}{}", url, "/auth/login"))
+                .json(&login_info);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// ログアウト
+        pub async fn logout(
+            url: &str,
+            _cmd: auth_commands::LogoutCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().post(&format!("{}{}", url, "/auth/logout"));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// 有効なセッションを持っているかを確認し，その権限を取得
+        pub async fn check_session(
+            url: &str,
+            _cmd: auth_commands::CheckSessionCommand,
+        ) -> Result<Role, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/auth/session"));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+    }
+
+    use crate::commands::auth_commands;
+    use crate::AppFrontError;
+    use crate::{api_base_url, API_BASE_URL};
+    use domain::user::Role;
+
+    /// ログイン
+    pub async fn login<'a>(cmd: auth_commands::LoginCommand<'_>) -> Result<(), AppFrontError> {
+        product_inner::login(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// ログアウト
+    pub async fn logout(cmd: auth_commands::LogoutCommand) -> Result<(), AppFrontError> {
+        product_inner::logout(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// 有効なセッションを持っているかを確認し，その権限を取得
+    pub async fn check_session(
+        cmd: auth_commands::CheckSessionCommand,
+    ) -> Result<Role, AppFrontError> {
+        product_inner::check_session(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake {
+    use crate::commands::auth_commands;
+    use crate::AppFrontError;
+    use domain::user::Role;
+
+    /// ログイン(フェイク)
+    pub async fn login<'a>(_cmd: auth_commands::LoginCommand<'_>) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// ログアウト(フェイク)
+    pub async fn logout(_cmd: auth_commands::LogoutCommand) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// 有効なセッションを持っているかを確認し，その権限を取得(フェイク)
+    pub async fn check_session(
+        _cmd: auth_commands::CheckSessionCommand,
+    ) -> Result<Role, AppFrontError> {
+        Ok(Role::Admin)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(not(feature = "fake"))]
+    mod product_test {
+        use super::super::product::product_inner;
+        use crate::commands::auth_commands;
+        use crate::AppFrontError;
+        use common::{AppCommonError, LoginInfo};
+        use domain::user::Role;
+
+        use pretty_assertions::assert_eq;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[tokio::test]
+        async fn test_login() {
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("POST"))
+                    .and(path("/auth/login"))
+                    .and(body_json(LoginInfo::new("password")))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = auth_commands::LoginCommand::new(None, "password");
+                    product_inner::login(&mock_server.uri(), cmd).await
+                };
+
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+            {
+                // 失敗した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("POST"))
+                    .and(path("/auth/login"))
+                    .and(body_json(LoginInfo::new("wrong password")))
+                    .respond_with(
+                        ResponseTemplate::new(401).set_body_json(AppCommonError::UnauthorizedError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = auth_commands::LoginCommand::new(None, "wrong password");
+                    product_inner::login(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(
+                            AppCommonError::UnauthorizedError
+                        ))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+            {
+                // ユーザー名を指定した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("POST"))
+                    .and(path("/auth/login"))
+                    .and(body_json(LoginInfo::with_name("contributor", "password")))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = auth_commands::LoginCommand::new(Some("contributor"), "password");
+                    product_inner::login(&mock_server.uri(), cmd).await
+                };
+
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+        }
+
+        #[tokio::test]
+        async fn test_logout() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("POST"))
+                .and(path("/auth/logout"))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = auth_commands::LogoutCommand;
+                product_inner::logout(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_check_session() {
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("GET"))
+                    .and(path("/auth/session"))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(Role::Contributor))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = auth_commands::CheckSessionCommand;
+                    product_inner::check_session(&mock_server.uri(), cmd).await
+                };
+
+                assert_eq!(res.unwrap(), Role::Contributor);
+            }
+            {
+                // 失敗した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("GET"))
+                    .and(path("/auth/session"))
+                    .respond_with(
+                        ResponseTemplate::new(401).set_body_json(AppCommonError::UnauthorizedError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = auth_commands::CheckSessionCommand;
+                    product_inner::check_session(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(
+                            AppCommonError::UnauthorizedError
+                        ))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
+    }
+}
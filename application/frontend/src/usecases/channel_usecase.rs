@@ -0,0 +1,362 @@
+#[cfg(not(feature = "fake"))]
+pub use self::product::*;
+
+#[cfg(feature = "fake")]
+pub use self::fake::*;
+
+/// APIをチェックするためのbehavior
+#[cfg(test)]
+mod _behavior {
+    use crate::commands::channel_commands;
+    use crate::AppFrontError;
+    use domain::channel::{Channel, ChannelSummary};
+
+    #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
+    #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
+    #[async_trait::async_trait]
+    trait Behavior {
+        async fn save_channel<'a>(
+            cmd: channel_commands::SaveChannelCommand<'_>,
+        ) -> Result<(), AppFrontError>;
+        async fn edit_channel<'a>(
+            cmd: channel_commands::EditChannelCommand<'_>,
+        ) -> Result<(), AppFrontError>;
+        async fn all_channels(
+            cmd: channel_commands::AllChannelsCommand,
+        ) -> Result<Vec<Channel>, AppFrontError>;
+        async fn find_channel(
+            cmd: channel_commands::FindChannelCommand,
+        ) -> Result<Channel, AppFrontError>;
+        async fn channel_summaries(
+            cmd: channel_commands::ChannelSummariesCommand,
+        ) -> Result<Vec<ChannelSummary>, AppFrontError>;
+        async fn remove_channel(
+            cmd: channel_commands::RemoveChannelCommand,
+        ) -> Result<(), AppFrontError>;
+    }
+}
+
+#[cfg(not(feature = "fake"))]
+mod product {
+    pub(crate) mod product_inner {
+        use crate::commands::channel_commands;
+        use crate::{
+            api_client,
+            utils::{deserialize_response, deserialize_response_null},
+            AppFrontError,
+        };
+        use domain::channel::{Channel, ChannelSummary};
+
+        pub async fn save_channel<'a>(
+            url: &str,
+            cmd: channel_commands::SaveChannelCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}{}", url, "/channel"))
+                .json(&cmd.channel);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        pub async fn edit_channel<'a>(
+            url: &str,
+            cmd: channel_commands::EditChannelCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .patch(&format!("{}{}", url, "/channel"))
+                .json(&cmd.channel);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        pub async fn all_channels(
+            url: &str,
+            _cmd: channel_commands::AllChannelsCommand,
+        ) -> Result<Vec<Channel>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/channel"));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn find_channel(
+            url: &str,
+            cmd: channel_commands::FindChannelCommand,
+        ) -> Result<Channel, AppFrontError> {
+            let request = api_client().get(&format!("{}{}{}", url, "/channel/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// 全てのチャンネルを動画の数とともに名前の順で取得する．
+        pub async fn channel_summaries(
+            url: &str,
+            _cmd: channel_commands::ChannelSummariesCommand,
+        ) -> Result<Vec<ChannelSummary>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/channel/summary"));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn remove_channel(
+            url: &str,
+            cmd: channel_commands::RemoveChannelCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().delete(&format!("{}{}{}", url, "/channel/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+    }
+
+    use crate::commands::channel_commands;
+    use crate::AppFrontError;
+    use crate::{api_base_url, API_BASE_URL};
+    use domain::channel::{Channel, ChannelSummary};
+
+    pub async fn save_channel<'a>(
+        cmd: channel_commands::SaveChannelCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::save_channel(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn edit_channel<'a>(
+        cmd: channel_commands::EditChannelCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::edit_channel(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn all_channels(
+        cmd: channel_commands::AllChannelsCommand,
+    ) -> Result<Vec<Channel>, AppFrontError> {
+        product_inner::all_channels(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn find_channel(
+        cmd: channel_commands::FindChannelCommand,
+    ) -> Result<Channel, AppFrontError> {
+        product_inner::find_channel(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn channel_summaries(
+        cmd: channel_commands::ChannelSummariesCommand,
+    ) -> Result<Vec<ChannelSummary>, AppFrontError> {
+        product_inner::channel_summaries(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn remove_channel(
+        cmd: channel_commands::RemoveChannelCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::remove_channel(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake {
+    use crate::commands::channel_commands;
+    use crate::AppFrontError;
+    use domain::channel::{Channel, ChannelSummary};
+
+    use fake::{Fake, Faker};
+
+    pub async fn save_channel<'a>(
+        _cmd: channel_commands::SaveChannelCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn edit_channel<'a>(
+        _cmd: channel_commands::EditChannelCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn all_channels(
+        _cmd: channel_commands::AllChannelsCommand,
+    ) -> Result<Vec<Channel>, AppFrontError> {
+        Ok((0..20).map(|_| Faker.fake::<Channel>()).collect::<Vec<_>>())
+    }
+
+    pub async fn find_channel(
+        _cmd: channel_commands::FindChannelCommand,
+    ) -> Result<Channel, AppFrontError> {
+        Ok(Faker.fake::<Channel>())
+    }
+
+    pub async fn channel_summaries(
+        _cmd: channel_commands::ChannelSummariesCommand,
+    ) -> Result<Vec<ChannelSummary>, AppFrontError> {
+        let mut channels = (0..20).map(|_| Faker.fake::<Channel>()).collect::<Vec<_>>();
+        channels.sort_by(|x, y| x.name().cmp(y.name()));
+        Ok(channels
+            .into_iter()
+            .map(|channel| ChannelSummary::new(channel, (0..100).fake()))
+            .collect::<Vec<_>>())
+    }
+
+    pub async fn remove_channel(
+        _cmd: channel_commands::RemoveChannelCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[cfg(not(feature = "fake"))]
+    mod product_test {
+        use super::super::product::product_inner;
+        use crate::commands::channel_commands;
+        use crate::AppFrontError;
+        use common::AppCommonError;
+        use domain::channel::{Channel, ChannelId, ChannelSummary};
+
+        use fake::{Fake, Faker};
+        use pretty_assertions::assert_eq;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[tokio::test]
+        async fn test_save_and_edit_channel() {
+            let channel = Faker.fake::<Channel>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PUT"))
+                .and(path("/channel"))
+                .and(body_json(channel.clone()))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            Mock::given(method("PATCH"))
+                .and(path("/channel"))
+                .and(body_json(channel.clone()))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = channel_commands::SaveChannelCommand::new(&channel);
+                product_inner::save_channel(&mock_server.uri(), cmd).await
+            };
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+
+            let res = {
+                let cmd = channel_commands::EditChannelCommand::new(&channel);
+                product_inner::edit_channel(&mock_server.uri(), cmd).await
+            };
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_find_channel() {
+            let channel = Faker.fake::<Channel>();
+
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("GET"))
+                    .and(path(format!("/channel/{}", channel.id())))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(channel.clone()))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = channel_commands::FindChannelCommand::new(channel.id());
+                    product_inner::find_channel(&mock_server.uri(), cmd).await
+                };
+
+                assert_eq!(res.unwrap(), channel);
+            }
+            {
+                // 失敗した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("GET"))
+                    .and(path(format!("/channel/{}", channel.id())))
+                    .respond_with(
+                        ResponseTemplate::new(404).set_body_json(AppCommonError::NoRecordError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = channel_commands::FindChannelCommand::new(channel.id());
+                    product_inner::find_channel(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+                    ),
+                    "{:?}",
+                    res.unwrap_err()
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn test_channel_summaries() {
+            let summaries = (0..10)
+                .map(|i| ChannelSummary::new(Faker.fake(), i))
+                .collect::<Vec<_>>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/channel/summary"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(summaries.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = channel_commands::ChannelSummariesCommand;
+                product_inner::channel_summaries(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), summaries);
+        }
+
+        #[tokio::test]
+        async fn test_remove_channel() {
+            let id = ChannelId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("DELETE"))
+                .and(path(format!("/channel/{}", id)))
+                .respond_with(
+                    ResponseTemplate::new(409).set_body_json(AppCommonError::ConflictError),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = channel_commands::RemoveChannelCommand::new(id);
+                product_inner::remove_channel(&mock_server.uri(), cmd).await
+            };
+
+            assert!(
+                matches!(
+                    res,
+                    Err(AppFrontError::CommonError(AppCommonError::ConflictError))
+                ),
+                "{:?}",
+                res.unwrap_err()
+            );
+        }
+    }
+}
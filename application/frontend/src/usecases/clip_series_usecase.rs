@@ -0,0 +1,653 @@
+#[cfg(not(feature = "fake"))]
+pub use self::product::*;
+
+#[cfg(feature = "fake")]
+pub use self::fake::*;
+
+/// APIをチェックするためのbehavior
+#[cfg(test)]
+mod _behavior {
+    use crate::commands::clip_series_commands;
+    use crate::AppFrontError;
+    use common::Page;
+    use domain::clip_series::{ClipSeries, ClipSeriesId};
+
+    #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
+    #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
+    #[async_trait::async_trait]
+    trait Behavior {
+        async fn save_clip_series<'a>(
+            cmd: clip_series_commands::SaveClipSeriesCommand<'_>,
+        ) -> Result<ClipSeries, AppFrontError>;
+        async fn edit_clip_series<'a>(
+            cmd: clip_series_commands::EditClipSeriesCommand<'_>,
+        ) -> Result<(), AppFrontError>;
+        async fn increment_like_clip_series(
+            cmd: clip_series_commands::IncrementLikeClipSeriesCommand,
+        ) -> Result<(), AppFrontError>;
+        async fn unlike_clip_series(
+            cmd: clip_series_commands::UnlikeClipSeriesCommand,
+        ) -> Result<(), AppFrontError>;
+        async fn liked_clip_series_ids<'a>(
+            cmd: clip_series_commands::LikedClipSeriesIdsCommand<'_>,
+        ) -> Result<Vec<ClipSeriesId>, AppFrontError>;
+        async fn all_clip_series(
+            cmd: clip_series_commands::AllClipSeriesCommand,
+        ) -> Result<Vec<ClipSeries>, AppFrontError>;
+        async fn find_clip_series(
+            cmd: clip_series_commands::FindClipSeriesCommand,
+        ) -> Result<ClipSeries, AppFrontError>;
+        async fn order_by_like_clip_series(
+            cmd: clip_series_commands::OrderByLikeClipSeriesCommand,
+        ) -> Result<Page<ClipSeries>, AppFrontError>;
+        async fn order_by_like_later_clip_series<'a>(
+            cmd: clip_series_commands::OrderByLikeLaterClipSeriesCommand<'_>,
+        ) -> Result<Page<ClipSeries>, AppFrontError>;
+        async fn order_by_create_date_clip_series(
+            cmd: clip_series_commands::OrderByCreateDateClipSeriesCommand,
+        ) -> Result<Page<ClipSeries>, AppFrontError>;
+        async fn order_by_create_date_later_clip_series<'a>(
+            cmd: clip_series_commands::OrderByCreateDateLaterClipSeriesCommand<'_>,
+        ) -> Result<Page<ClipSeries>, AppFrontError>;
+        async fn remove_clip_series(
+            cmd: clip_series_commands::RemoveClipSeriesCommand,
+        ) -> Result<(), AppFrontError>;
+    }
+}
+
+#[cfg(not(feature = "fake"))]
+mod product {
+    pub(crate) mod product_inner {
+        use crate::commands::clip_series_commands;
+        use crate::{
+            api_client,
+            utils::{deserialize_response, deserialize_response_null, send_as_client},
+            AppFrontError,
+        };
+        use common::Page;
+        use domain::clip_series::{ClipSeries, ClipSeriesId};
+
+        /// シリーズを保存し，サーバーがid, like, create_dateを決定したシリーズを返す．
+        pub async fn save_clip_series<'a>(
+            url: &str,
+            cmd: clip_series_commands::SaveClipSeriesCommand<'_>,
+        ) -> Result<ClipSeries, AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}{}", url, "/clip_series"))
+                .json(&cmd.clip_series_info);
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn edit_clip_series<'a>(
+            url: &str,
+            cmd: clip_series_commands::EditClipSeriesCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .patch(&format!("{}{}", url, "/clip_series"))
+                .json(&cmd.clip_series);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        pub async fn increment_like_clip_series(
+            url: &str,
+            cmd: clip_series_commands::IncrementLikeClipSeriesCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().patch(&format!(
+                "{}{}{}",
+                url, "/clip_series/increment_like/", cmd.id
+            ));
+
+            let response = send_as_client(url, request).await?;
+
+            deserialize_response_null(response).await
+        }
+
+        pub async fn unlike_clip_series(
+            url: &str,
+            cmd: clip_series_commands::UnlikeClipSeriesCommand,
+        ) -> Result<(), AppFrontError> {
+            let request =
+                api_client().patch(&format!("{}{}{}", url, "/clip_series/unlike/", cmd.id));
+
+            let response = send_as_client(url, request).await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// `ids`のうち，このクライアントがLikeしているシリーズのidを取得する．
+        pub async fn liked_clip_series_ids<'a>(
+            url: &str,
+            cmd: clip_series_commands::LikedClipSeriesIdsCommand<'_>,
+        ) -> Result<Vec<ClipSeriesId>, AppFrontError> {
+            let request = api_client()
+                .post(&format!("{}{}", url, "/clip_series/liked"))
+                .json(&cmd.ids);
+
+            let response = send_as_client(url, request).await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn all_clip_series(
+            url: &str,
+            _cmd: clip_series_commands::AllClipSeriesCommand,
+        ) -> Result<Vec<ClipSeries>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/clip_series"));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn find_clip_series(
+            url: &str,
+            cmd: clip_series_commands::FindClipSeriesCommand,
+        ) -> Result<ClipSeries, AppFrontError> {
+            let request = api_client().get(&format!("{}{}{}", url, "/clip_series/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn order_by_like_clip_series(
+            url: &str,
+            cmd: clip_series_commands::OrderByLikeClipSeriesCommand,
+        ) -> Result<Page<ClipSeries>, AppFrontError> {
+            let query_string = format!("?sort_type=like&length={}", cmd.length);
+            let request =
+                api_client().get(&format!("{}{}{}", url, "/clip_series/query", query_string));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn order_by_like_later_clip_series<'a>(
+            url: &str,
+            cmd: clip_series_commands::OrderByLikeLaterClipSeriesCommand<'_>,
+        ) -> Result<Page<ClipSeries>, AppFrontError> {
+            let query_string = format!(
+                "?sort_type=like&length={}&cursor={}",
+                cmd.length, cmd.cursor
+            );
+            let request =
+                api_client().get(&format!("{}{}{}", url, "/clip_series/query", query_string));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn order_by_create_date_clip_series(
+            url: &str,
+            cmd: clip_series_commands::OrderByCreateDateClipSeriesCommand,
+        ) -> Result<Page<ClipSeries>, AppFrontError> {
+            let query_string = format!("?sort_type=create_date&length={}", cmd.length);
+            let request =
+                api_client().get(&format!("{}{}{}", url, "/clip_series/query", query_string));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn order_by_create_date_later_clip_series<'a>(
+            url: &str,
+            cmd: clip_series_commands::OrderByCreateDateLaterClipSeriesCommand<'_>,
+        ) -> Result<Page<ClipSeries>, AppFrontError> {
+            let query_string = format!(
+                "?sort_type=create_date&length={}&cursor={}",
+                cmd.length, cmd.cursor
+            );
+            let request =
+                api_client().get(&format!("{}{}{}", url, "/clip_series/query", query_string));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn remove_clip_series(
+            url: &str,
+            cmd: clip_series_commands::RemoveClipSeriesCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().delete(&format!("{}{}{}", url, "/clip_series/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+    }
+
+    use crate::commands::clip_series_commands;
+    use crate::AppFrontError;
+    use crate::{api_base_url, API_BASE_URL};
+    use common::Page;
+    use domain::clip_series::{ClipSeries, ClipSeriesId};
+
+    pub async fn save_clip_series<'a>(
+        cmd: clip_series_commands::SaveClipSeriesCommand<'_>,
+    ) -> Result<ClipSeries, AppFrontError> {
+        product_inner::save_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn edit_clip_series<'a>(
+        cmd: clip_series_commands::EditClipSeriesCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::edit_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn increment_like_clip_series(
+        cmd: clip_series_commands::IncrementLikeClipSeriesCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::increment_like_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn unlike_clip_series(
+        cmd: clip_series_commands::UnlikeClipSeriesCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::unlike_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn liked_clip_series_ids<'a>(
+        cmd: clip_series_commands::LikedClipSeriesIdsCommand<'_>,
+    ) -> Result<Vec<ClipSeriesId>, AppFrontError> {
+        product_inner::liked_clip_series_ids(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn all_clip_series(
+        cmd: clip_series_commands::AllClipSeriesCommand,
+    ) -> Result<Vec<ClipSeries>, AppFrontError> {
+        product_inner::all_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn find_clip_series(
+        cmd: clip_series_commands::FindClipSeriesCommand,
+    ) -> Result<ClipSeries, AppFrontError> {
+        product_inner::find_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn order_by_like_clip_series(
+        cmd: clip_series_commands::OrderByLikeClipSeriesCommand,
+    ) -> Result<Page<ClipSeries>, AppFrontError> {
+        product_inner::order_by_like_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn order_by_like_later_clip_series<'a>(
+        cmd: clip_series_commands::OrderByLikeLaterClipSeriesCommand<'_>,
+    ) -> Result<Page<ClipSeries>, AppFrontError> {
+        product_inner::order_by_like_later_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd)
+            .await
+    }
+
+    pub async fn order_by_create_date_clip_series(
+        cmd: clip_series_commands::OrderByCreateDateClipSeriesCommand,
+    ) -> Result<Page<ClipSeries>, AppFrontError> {
+        product_inner::order_by_create_date_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd)
+            .await
+    }
+
+    pub async fn order_by_create_date_later_clip_series<'a>(
+        cmd: clip_series_commands::OrderByCreateDateLaterClipSeriesCommand<'_>,
+    ) -> Result<Page<ClipSeries>, AppFrontError> {
+        product_inner::order_by_create_date_later_clip_series(
+            API_BASE_URL.get_or_init(api_base_url),
+            cmd,
+        )
+        .await
+    }
+
+    pub async fn remove_clip_series(
+        cmd: clip_series_commands::RemoveClipSeriesCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::remove_clip_series(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake {
+    use crate::commands::clip_series_commands;
+    use crate::AppFrontError;
+    use common::{AppCommonError, Page};
+    use domain::clip_series::{ClipSeries, ClipSeriesId};
+    use domain::Date;
+
+    use fake::{Fake, Faker};
+
+    /// `length`分のシリーズのフェイクのページ
+    fn fake_page(length: usize) -> Page<ClipSeries> {
+        let items = (0..length)
+            .map(|_| Faker.fake::<ClipSeries>())
+            .collect::<Vec<_>>();
+        Page::new(items, true, Some("fake_cursor".to_string()))
+    }
+
+    pub async fn save_clip_series<'a>(
+        cmd: clip_series_commands::SaveClipSeriesCommand<'_>,
+    ) -> Result<ClipSeries, AppFrontError> {
+        let clip_series_info = cmd.clip_series_info.clone();
+        ClipSeries::new(
+            clip_series_info.title,
+            clip_series_info.description,
+            clip_series_info.clips,
+            Date::today(),
+        )
+        .map_err(|e| AppCommonError::DomainError(format!("{e}")).into())
+    }
+
+    pub async fn edit_clip_series<'a>(
+        _cmd: clip_series_commands::EditClipSeriesCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn increment_like_clip_series(
+        _cmd: clip_series_commands::IncrementLikeClipSeriesCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn unlike_clip_series(
+        _cmd: clip_series_commands::UnlikeClipSeriesCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn liked_clip_series_ids<'a>(
+        _cmd: clip_series_commands::LikedClipSeriesIdsCommand<'_>,
+    ) -> Result<Vec<ClipSeriesId>, AppFrontError> {
+        Ok(Vec::new())
+    }
+
+    pub async fn all_clip_series(
+        _cmd: clip_series_commands::AllClipSeriesCommand,
+    ) -> Result<Vec<ClipSeries>, AppFrontError> {
+        Ok((0..20)
+            .map(|_| Faker.fake::<ClipSeries>())
+            .collect::<Vec<_>>())
+    }
+
+    pub async fn find_clip_series(
+        _cmd: clip_series_commands::FindClipSeriesCommand,
+    ) -> Result<ClipSeries, AppFrontError> {
+        Ok(Faker.fake::<ClipSeries>())
+    }
+
+    pub async fn order_by_like_clip_series(
+        cmd: clip_series_commands::OrderByLikeClipSeriesCommand,
+    ) -> Result<Page<ClipSeries>, AppFrontError> {
+        Ok(fake_page(cmd.length))
+    }
+
+    pub async fn order_by_like_later_clip_series<'a>(
+        cmd: clip_series_commands::OrderByLikeLaterClipSeriesCommand<'_>,
+    ) -> Result<Page<ClipSeries>, AppFrontError> {
+        Ok(fake_page(cmd.length))
+    }
+
+    pub async fn order_by_create_date_clip_series(
+        cmd: clip_series_commands::OrderByCreateDateClipSeriesCommand,
+    ) -> Result<Page<ClipSeries>, AppFrontError> {
+        Ok(fake_page(cmd.length))
+    }
+
+    pub async fn order_by_create_date_later_clip_series<'a>(
+        cmd: clip_series_commands::OrderByCreateDateLaterClipSeriesCommand<'_>,
+    ) -> Result<Page<ClipSeries>, AppFrontError> {
+        Ok(fake_page(cmd.length))
+    }
+
+    pub async fn remove_clip_series(
+        _cmd: clip_series_commands::RemoveClipSeriesCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[cfg(not(feature = "fake"))]
+    mod product_test {
+        use super::super::product::product_inner;
+        use crate::commands::clip_series_commands;
+        use crate::AppFrontError;
+        use common::{AppCommonError, NewClipSeriesInfo, Page};
+        use domain::clip_series::{ClipSeries, ClipSeriesId};
+
+        use fake::{Fake, Faker};
+        use pretty_assertions::assert_eq;
+        use wiremock::matchers::{body_json, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[tokio::test]
+        async fn test_save_clip_series() {
+            let clip_series = Faker.fake::<ClipSeries>();
+            let clip_series_info = NewClipSeriesInfo::from(clip_series.clone());
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PUT"))
+                .and(path("/clip_series"))
+                .and(body_json(clip_series_info.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(clip_series.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = clip_series_commands::SaveClipSeriesCommand::new(&clip_series_info);
+                product_inner::save_clip_series(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), clip_series);
+        }
+
+        #[tokio::test]
+        async fn test_edit_clip_series() {
+            let clip_series = Faker.fake::<ClipSeries>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PATCH"))
+                .and(path("/clip_series"))
+                .and(body_json(clip_series.clone()))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = clip_series_commands::EditClipSeriesCommand::new(&clip_series);
+                product_inner::edit_clip_series(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_like_clip_series() {
+            let id = ClipSeriesId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PATCH"))
+                .and(path(format!("/clip_series/increment_like/{}", id)))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            Mock::given(method("PATCH"))
+                .and(path(format!("/clip_series/unlike/{}", id)))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = clip_series_commands::IncrementLikeClipSeriesCommand::new(id);
+                product_inner::increment_like_clip_series(&mock_server.uri(), cmd).await
+            };
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+
+            let res = {
+                let cmd = clip_series_commands::UnlikeClipSeriesCommand::new(id);
+                product_inner::unlike_clip_series(&mock_server.uri(), cmd).await
+            };
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_liked_clip_series_ids() {
+            let ids = (0..10)
+                .map(|_| ClipSeriesId::generate())
+                .collect::<Vec<_>>();
+            let liked_ids = ids[..5].to_vec();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("POST"))
+                .and(path("/clip_series/liked"))
+                .and(body_json(ids.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(liked_ids.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = clip_series_commands::LikedClipSeriesIdsCommand::new(&ids);
+                product_inner::liked_clip_series_ids(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), liked_ids);
+        }
+
+        #[tokio::test]
+        async fn test_find_clip_series() {
+            let clip_series = Faker.fake::<ClipSeries>();
+
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("GET"))
+                    .and(path(format!("/clip_series/{}", clip_series.id())))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(clip_series.clone()))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = clip_series_commands::FindClipSeriesCommand::new(clip_series.id());
+                    product_inner::find_clip_series(&mock_server.uri(), cmd).await
+                };
+
+                assert_eq!(res.unwrap(), clip_series);
+            }
+            {
+                // 失敗した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("GET"))
+                    .and(path(format!("/clip_series/{}", clip_series.id())))
+                    .respond_with(
+                        ResponseTemplate::new(404).set_body_json(AppCommonError::NoRecordError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = clip_series_commands::FindClipSeriesCommand::new(clip_series.id());
+                    product_inner::find_clip_series(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+                    ),
+                    "{:?}",
+                    res.unwrap_err()
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn test_order_by_like_later_clip_series() {
+            let clip_series_vec = (0..100)
+                .map(|_| Faker.fake::<ClipSeries>())
+                .collect::<Vec<_>>();
+
+            let length = 10_usize;
+            let cursor = "cursor";
+            let page = Page::new(clip_series_vec, true, Some("next_cursor".to_string()));
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/clip_series/query"))
+                .and(query_param("sort_type", "like"))
+                .and(query_param("length", length.to_string()))
+                .and(query_param("cursor", cursor))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd =
+                    clip_series_commands::OrderByLikeLaterClipSeriesCommand::new(cursor, length);
+                product_inner::order_by_like_later_clip_series(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), page);
+        }
+
+        #[tokio::test]
+        async fn test_order_by_create_date_clip_series() {
+            let clip_series_vec = (0..100)
+                .map(|_| Faker.fake::<ClipSeries>())
+                .collect::<Vec<_>>();
+
+            let length = 10_usize;
+            let page = Page::new(clip_series_vec, false, None);
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/clip_series/query"))
+                .and(query_param("sort_type", "create_date"))
+                .and(query_param("length", length.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = clip_series_commands::OrderByCreateDateClipSeriesCommand::new(length);
+                product_inner::order_by_create_date_clip_series(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), page);
+        }
+
+        #[tokio::test]
+        async fn test_remove_clip_series() {
+            let id = ClipSeriesId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("DELETE"))
+                .and(path(format!("/clip_series/{}", id)))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = clip_series_commands::RemoveClipSeriesCommand::new(id);
+                product_inner::remove_clip_series(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+    }
+}
@@ -10,6 +10,7 @@ mod _behavior {
     use crate::commands::episode_commands;
     use crate::AppFrontError;
     use domain::episode::Episode;
+    use domain::Tag;
 
     #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
     #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
@@ -24,12 +25,36 @@ mod _behavior {
         async fn all_episodes(
             cmd: episode_commands::AllEpisodesCommand,
         ) -> Result<Vec<Episode>, AppFrontError>;
-        async fn order_by_date_range_episodes(
-            cmd: episode_commands::OrderByDateRangeEpisodesCommand,
+        async fn order_by_date_range_episodes<'a>(
+            cmd: episode_commands::OrderByDateRangeEpisodesCommand<'_>,
         ) -> Result<Vec<Episode>, AppFrontError>;
+        async fn all_episode_tags(
+            cmd: episode_commands::AllEpisodeTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError>;
+        async fn episode_tags(
+            cmd: episode_commands::EpisodeTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError>;
+        async fn add_episode_tag<'a>(
+            cmd: episode_commands::AddEpisodeTagCommand<'_>,
+        ) -> Result<(), AppFrontError>;
+        async fn remove_episode_tag<'a>(
+            cmd: episode_commands::RemoveEpisodeTagCommand<'_>,
+        ) -> Result<(), AppFrontError>;
         async fn remove_episode(
             cmd: episode_commands::RemoveEpisodeCommand,
         ) -> Result<(), AppFrontError>;
+        /// ゴミ箱のエピソードを削除した日時の新しい順に取得。
+        async fn trashed_episodes(
+            cmd: episode_commands::TrashedEpisodesCommand,
+        ) -> Result<Vec<Episode>, AppFrontError>;
+        /// ゴミ箱のエピソードを戻す。
+        async fn restore_trashed_episode(
+            cmd: episode_commands::RestoreTrashedEpisodeCommand,
+        ) -> Result<(), AppFrontError>;
+        /// ゴミ箱のエピソードを完全に削除。
+        async fn purge_episode(
+            cmd: episode_commands::PurgeEpisodeCommand,
+        ) -> Result<(), AppFrontError>;
     }
 }
 
@@ -39,19 +64,19 @@ mod product {
     pub(crate) mod product_inner {
         use crate::commands::episode_commands;
         use crate::{
-            utils::{deserialize_response, deserialize_response_null},
+            api_client,
+            utils::{deserialize_response, deserialize_response_null, tags_query},
             AppFrontError,
         };
         use domain::episode::Episode;
-
-        use reqwest::Client;
+        use domain::Tag;
 
         /// エピソードを保存
         pub async fn save_episode<'a>(
             url: &str,
             cmd: episode_commands::SaveEpisodeCommand<'_>,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new()
+            let request = api_client()
                 .put(&format!("{}{}", url, "/episode"))
                 .json(&cmd.episode);
 
@@ -65,7 +90,7 @@ mod product {
             url: &str,
             cmd: episode_commands::EditEpisodeCommand<'_>,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new()
+            let request = api_client()
                 .patch(&format!("{}{}", url, "/episode"))
                 .json(&cmd.episode);
 
@@ -79,7 +104,7 @@ mod product {
             url: &str,
             _cmd: episode_commands::AllEpisodesCommand,
         ) -> Result<Vec<Episode>, AppFrontError> {
-            let request = Client::new().get(&format!("{}{}", url, "/episode"));
+            let request = api_client().get(&format!("{}{}", url, "/episode"));
 
             let response = request.send().await?;
 
@@ -87,25 +112,114 @@ mod product {
         }
 
         /// Dateで降順・idで昇順で並べたエピソードを`length`分取得。
-        pub async fn order_by_date_range_episodes(
+        pub async fn order_by_date_range_episodes<'a>(
             url: &str,
-            cmd: episode_commands::OrderByDateRangeEpisodesCommand,
+            cmd: episode_commands::OrderByDateRangeEpisodesCommand<'_>,
         ) -> Result<Vec<Episode>, AppFrontError> {
             let query_string = format!("?sort_type=date&start={}&end={}", cmd.start, cmd.end);
-            let request =
-                Client::new().get(&format!("{}{}{}", url, "/episode/query", query_string));
+            let request = api_client()
+                .get(&format!("{}{}{}", url, "/episode/query", query_string))
+                .query(&tags_query(cmd.tags));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// エピソードに付けられている全てのタグを取得
+        pub async fn all_episode_tags(
+            url: &str,
+            _cmd: episode_commands::AllEpisodeTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/episode/tags"));
 
             let response = request.send().await?;
 
             deserialize_response(response).await
         }
 
+        /// `id`を持つエピソードのタグを取得
+        pub async fn episode_tags(
+            url: &str,
+            cmd: episode_commands::EpisodeTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}{}", url, "/episode/tags/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// `id`を持つエピソードにタグを付ける
+        pub async fn add_episode_tag<'a>(
+            url: &str,
+            cmd: episode_commands::AddEpisodeTagCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}{}{}", url, "/episode/tags/", cmd.id))
+                .json(cmd.tag);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// `id`を持つエピソードからタグを外す
+        pub async fn remove_episode_tag<'a>(
+            url: &str,
+            cmd: episode_commands::RemoveEpisodeTagCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .delete(&format!("{}{}{}", url, "/episode/tags/", cmd.id))
+                .json(cmd.tag);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
         /// エピソードを削除。
         pub async fn remove_episode(
             url: &str,
             cmd: episode_commands::RemoveEpisodeCommand,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new().delete(&format!("{}{}{}", url, "/episode/", cmd.id));
+            let request = api_client().delete(&format!("{}{}{}", url, "/episode/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// ゴミ箱のエピソードを削除した日時の新しい順に取得。
+        pub async fn trashed_episodes(
+            url: &str,
+            _cmd: episode_commands::TrashedEpisodesCommand,
+        ) -> Result<Vec<Episode>, AppFrontError> {
+            let request = api_client().get(&format!("{}/episode/trash", url));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// ゴミ箱のエピソードを戻す。
+        pub async fn restore_trashed_episode(
+            url: &str,
+            cmd: episode_commands::RestoreTrashedEpisodeCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().patch(&format!("{}/episode/trash/{}", url, cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// ゴミ箱のエピソードを完全に削除。
+        pub async fn purge_episode(
+            url: &str,
+            cmd: episode_commands::PurgeEpisodeCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().delete(&format!("{}/episode/trash/{}", url, cmd.id));
 
             let response = request.send().await?;
 
@@ -117,6 +231,7 @@ mod product {
     use crate::AppFrontError;
     use crate::{api_base_url, API_BASE_URL};
     use domain::episode::Episode;
+    use domain::Tag;
 
     /// エピソードを保存
     pub async fn save_episode<'a>(
@@ -140,19 +255,68 @@ mod product {
     }
 
     /// Dateで降順・idで昇順で並べたエピソードを`length`分取得。
-    pub async fn order_by_date_range_episodes(
-        cmd: episode_commands::OrderByDateRangeEpisodesCommand,
+    pub async fn order_by_date_range_episodes<'a>(
+        cmd: episode_commands::OrderByDateRangeEpisodesCommand<'_>,
     ) -> Result<Vec<Episode>, AppFrontError> {
         product_inner::order_by_date_range_episodes(API_BASE_URL.get_or_init(api_base_url), cmd)
             .await
     }
 
+    /// エピソードに付けられている全てのタグを取得
+    pub async fn all_episode_tags(
+        cmd: episode_commands::AllEpisodeTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        product_inner::all_episode_tags(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つエピソードのタグを取得
+    pub async fn episode_tags(
+        cmd: episode_commands::EpisodeTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        product_inner::episode_tags(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つエピソードにタグを付ける
+    pub async fn add_episode_tag<'a>(
+        cmd: episode_commands::AddEpisodeTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::add_episode_tag(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つエピソードからタグを外す
+    pub async fn remove_episode_tag<'a>(
+        cmd: episode_commands::RemoveEpisodeTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::remove_episode_tag(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
     /// エピソードを削除。
     pub async fn remove_episode(
         cmd: episode_commands::RemoveEpisodeCommand,
     ) -> Result<(), AppFrontError> {
         product_inner::remove_episode(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
+
+    /// ゴミ箱のエピソードを削除した日時の新しい順に取得。
+    pub async fn trashed_episodes(
+        cmd: episode_commands::TrashedEpisodesCommand,
+    ) -> Result<Vec<Episode>, AppFrontError> {
+        product_inner::trashed_episodes(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// ゴミ箱のエピソードを戻す。
+    pub async fn restore_trashed_episode(
+        cmd: episode_commands::RestoreTrashedEpisodeCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::restore_trashed_episode(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// ゴミ箱のエピソードを完全に削除。
+    pub async fn purge_episode(
+        cmd: episode_commands::PurgeEpisodeCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::purge_episode(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
 }
 
 #[cfg(feature = "fake")]
@@ -160,6 +324,7 @@ mod fake {
     use crate::commands::episode_commands;
     use crate::AppFrontError;
     use domain::episode::Episode;
+    use domain::Tag;
     use fake::{Fake, Faker};
 
     /// エピソードを保存(フェイク)
@@ -185,17 +350,66 @@ mod fake {
             .collect::<Vec<_>>())
     }
     /// Dateで降順・idで昇順で並べたエピソードを`length`分取得。(フェイク)
-    pub async fn order_by_date_range_episodes(
-        _cmd: episode_commands::OrderByDateRangeEpisodesCommand,
+    pub async fn order_by_date_range_episodes<'a>(
+        _cmd: episode_commands::OrderByDateRangeEpisodesCommand<'_>,
     ) -> Result<Vec<Episode>, AppFrontError> {
         Ok((0..50).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>())
     }
+    /// エピソードに付けられている全てのタグを取得(フェイク)
+    pub async fn all_episode_tags(
+        _cmd: episode_commands::AllEpisodeTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        Ok((0..10).map(|_| Faker.fake::<Tag>()).collect::<Vec<_>>())
+    }
+
+    /// `id`を持つエピソードのタグを取得(フェイク)
+    pub async fn episode_tags(
+        _cmd: episode_commands::EpisodeTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        Ok((0..3).map(|_| Faker.fake::<Tag>()).collect::<Vec<_>>())
+    }
+
+    /// `id`を持つエピソードにタグを付ける(フェイク)
+    pub async fn add_episode_tag<'a>(
+        _cmd: episode_commands::AddEpisodeTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// `id`を持つエピソードからタグを外す(フェイク)
+    pub async fn remove_episode_tag<'a>(
+        _cmd: episode_commands::RemoveEpisodeTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
     /// エピソードを削除。(フェイク)
     pub async fn remove_episode(
         _cmd: episode_commands::RemoveEpisodeCommand,
     ) -> Result<(), AppFrontError> {
         Ok(())
     }
+
+    /// ゴミ箱のエピソードを削除した日時の新しい順に取得。(フェイク)
+    pub async fn trashed_episodes(
+        _cmd: episode_commands::TrashedEpisodesCommand,
+    ) -> Result<Vec<Episode>, AppFrontError> {
+        Ok((0..5).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>())
+    }
+
+    /// ゴミ箱のエピソードを戻す。(フェイク)
+    pub async fn restore_trashed_episode(
+        _cmd: episode_commands::RestoreTrashedEpisodeCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// ゴミ箱のエピソードを完全に削除。(フェイク)
+    pub async fn purge_episode(
+        _cmd: episode_commands::PurgeEpisodeCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -207,7 +421,7 @@ mod test {
         use crate::AppFrontError;
         use common::AppCommonError;
         use domain::episode::{Episode, EpisodeId};
-        use domain::Date;
+        use domain::{Date, Tag};
 
         use fake::{Fake, Faker};
         use pretty_assertions::assert_eq;
@@ -235,7 +449,7 @@ mod test {
                     product_inner::save_episode(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
             {
                 // 失敗した場合
@@ -285,7 +499,7 @@ mod test {
                     product_inner::edit_episode(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
             {
                 // 失敗した場合
@@ -356,13 +570,106 @@ mod test {
                 .await;
 
             let res = {
-                let cmd = episode_commands::OrderByDateRangeEpisodesCommand::new(start, end);
+                let cmd = episode_commands::OrderByDateRangeEpisodesCommand::new(&[], start, end);
+                product_inner::order_by_date_range_episodes(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), episodes);
+        }
+
+        #[tokio::test]
+        async fn test_order_by_date_episodes_with_tags() {
+            let episodes = (0..10).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>();
+
+            let start = Faker.fake::<Date>();
+            let end = Faker.fake::<Date>();
+            let tags = vec![Tag::new("collab").unwrap()];
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/episode/query"))
+                .and(query_param("sort_type", "date"))
+                .and(query_param("start", start))
+                .and(query_param("end", end))
+                .and(query_param("tags", "collab"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(episodes.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = episode_commands::OrderByDateRangeEpisodesCommand::new(&tags, start, end);
                 product_inner::order_by_date_range_episodes(&mock_server.uri(), cmd).await
             };
 
             assert_eq!(res.unwrap(), episodes);
         }
 
+        #[tokio::test]
+        async fn test_episode_tags() {
+            let id = EpisodeId::generate();
+            let tags = vec![Tag::new("game").unwrap(), Tag::new("singing").unwrap()];
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/episode/tags"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(tags.clone()))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path(format!("/episode/tags/{}", id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(tags[..1].to_vec()))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("PUT"))
+                .and(path(format!("/episode/tags/{}", id)))
+                .and(body_json(tags[0].clone()))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("DELETE"))
+                .and(path(format!("/episode/tags/{}", id)))
+                .and(body_json(tags[1].clone()))
+                .respond_with(
+                    ResponseTemplate::new(404).set_body_json(AppCommonError::NoRecordError),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = episode_commands::AllEpisodeTagsCommand;
+                product_inner::all_episode_tags(&mock_server.uri(), cmd).await
+            };
+            assert_eq!(res.unwrap(), tags);
+
+            let res = {
+                let cmd = episode_commands::EpisodeTagsCommand::new(id);
+                product_inner::episode_tags(&mock_server.uri(), cmd).await
+            };
+            assert_eq!(res.unwrap(), tags[..1]);
+
+            let res = {
+                let cmd = episode_commands::AddEpisodeTagCommand::new(id, &tags[0]);
+                product_inner::add_episode_tag(&mock_server.uri(), cmd).await
+            };
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+
+            // 付けられていないタグを外す場合
+            let res = {
+                let cmd = episode_commands::RemoveEpisodeTagCommand::new(id, &tags[1]);
+                product_inner::remove_episode_tag(&mock_server.uri(), cmd).await
+            };
+            assert!(
+                matches!(
+                    res,
+                    Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+                ),
+                "{:?}",
+                res
+            );
+        }
+
         #[tokio::test]
         async fn test_remove_episode() {
             let id = EpisodeId::generate();
@@ -382,7 +689,7 @@ mod test {
                     product_inner::remove_episode(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
 
             {
@@ -409,5 +716,94 @@ mod test {
                 );
             }
         }
+
+        #[tokio::test]
+        async fn test_trashed_episodes() {
+            let episodes = (0..3).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/episode/trash"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(episodes.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = episode_commands::TrashedEpisodesCommand;
+                product_inner::trashed_episodes(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), episodes);
+        }
+
+        #[tokio::test]
+        async fn test_restore_trashed_episode() {
+            let id = EpisodeId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PATCH"))
+                .and(path(format!("/episode/trash/{id}")))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = episode_commands::RestoreTrashedEpisodeCommand::new(id);
+                product_inner::restore_trashed_episode(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_purge_episode() {
+            let id = EpisodeId::generate();
+
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("DELETE"))
+                    .and(path(format!("/episode/trash/{id}")))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = episode_commands::PurgeEpisodeCommand::new(id);
+                    product_inner::purge_episode(&mock_server.uri(), cmd).await
+                };
+
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+            {
+                // 管理者でない場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("DELETE"))
+                    .and(path(format!("/episode/trash/{id}")))
+                    .respond_with(
+                        ResponseTemplate::new(403).set_body_json(AppCommonError::ForbiddenError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = episode_commands::PurgeEpisodeCommand::new(id);
+                    product_inner::purge_episode(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::ForbiddenError))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
     }
 }
@@ -9,7 +9,9 @@ pub use self::fake::*;
 mod _behavior {
     use crate::commands::movie_clip_commands;
     use crate::AppFrontError;
-    use domain::movie_clip::MovieClip;
+    use common::Page;
+    use domain::movie_clip::{MovieClip, MovieClipId};
+    use domain::Tag;
 
     #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
     #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
@@ -17,34 +19,64 @@ mod _behavior {
     trait Behavior {
         async fn save_movie_clip<'a>(
             cmd: movie_clip_commands::SaveMovieClipCommand<'_>,
-        ) -> Result<(), AppFrontError>;
+        ) -> Result<MovieClip, AppFrontError>;
         async fn edit_movie_clip<'a>(
             cmd: movie_clip_commands::EditMovieClipCommand<'_>,
         ) -> Result<(), AppFrontError>;
+        async fn import_movie_clips<'a>(
+            cmd: movie_clip_commands::ImportMovieClipsCommand<'_>,
+        ) -> Result<(), AppFrontError>;
         async fn increment_like_movie_clip(
             cmd: movie_clip_commands::IncrementLikeMovieClipCommand,
         ) -> Result<(), AppFrontError>;
+        async fn unlike_movie_clip(
+            cmd: movie_clip_commands::UnlikeMovieClipCommand,
+        ) -> Result<(), AppFrontError>;
+        async fn liked_movie_clip_ids<'a>(
+            cmd: movie_clip_commands::LikedMovieClipIdsCommand<'_>,
+        ) -> Result<Vec<MovieClipId>, AppFrontError>;
         async fn all_movie_clips(
             cmd: movie_clip_commands::AllMovieClipsCommand,
         ) -> Result<Vec<MovieClip>, AppFrontError>;
-        async fn order_by_like_movie_clips(
-            cmd: movie_clip_commands::OrderByLikeMovieClipsCommand,
-        ) -> Result<Vec<MovieClip>, AppFrontError>;
+        async fn order_by_like_movie_clips<'a>(
+            cmd: movie_clip_commands::OrderByLikeMovieClipsCommand<'_>,
+        ) -> Result<Page<MovieClip>, AppFrontError>;
         async fn order_by_like_later_movie_clips<'a>(
             cmd: movie_clip_commands::OrderByLikeLaterMovieClipsCommand<'_>,
-        ) -> Result<Vec<MovieClip>, AppFrontError>;
+        ) -> Result<Page<MovieClip>, AppFrontError>;
         async fn order_by_create_date_range_movie_clips(
             cmd: movie_clip_commands::OrderByCreateDateRangeMovieClipsCommand,
         ) -> Result<Vec<MovieClip>, AppFrontError>;
-        async fn order_by_create_date_movie_clips(
-            cmd: movie_clip_commands::OrderByCreateDateMovieClipsCommand,
-        ) -> Result<Vec<MovieClip>, AppFrontError>;
+        async fn order_by_create_date_movie_clips<'a>(
+            cmd: movie_clip_commands::OrderByCreateDateMovieClipsCommand<'_>,
+        ) -> Result<Page<MovieClip>, AppFrontError>;
         async fn order_by_create_date_later_movie_clips<'a>(
             cmd: movie_clip_commands::OrderByCreateDateLaterMovieClipsCommand<'_>,
-        ) -> Result<Vec<MovieClip>, AppFrontError>;
+        ) -> Result<Page<MovieClip>, AppFrontError>;
+        async fn all_movie_clip_tags(
+            cmd: movie_clip_commands::AllMovieClipTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError>;
+        async fn movie_clip_tags(
+            cmd: movie_clip_commands::MovieClipTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError>;
+        async fn add_movie_clip_tag<'a>(
+            cmd: movie_clip_commands::AddMovieClipTagCommand<'_>,
+        ) -> Result<(), AppFrontError>;
+        async fn remove_movie_clip_tag<'a>(
+            cmd: movie_clip_commands::RemoveMovieClipTagCommand<'_>,
+        ) -> Result<(), AppFrontError>;
         async fn remove_movie_clip(
             cmd: movie_clip_commands::RemoveMovieClipCommand,
         ) -> Result<(), AppFrontError>;
+        async fn trashed_movie_clips(
+            cmd: movie_clip_commands::TrashedMovieClipsCommand,
+        ) -> Result<Vec<MovieClip>, AppFrontError>;
+        async fn restore_trashed_movie_clip(
+            cmd: movie_clip_commands::RestoreTrashedMovieClipCommand,
+        ) -> Result<(), AppFrontError>;
+        async fn purge_movie_clip(
+            cmd: movie_clip_commands::PurgeMovieClipCommand,
+        ) -> Result<(), AppFrontError>;
     }
 }
 
@@ -54,33 +86,33 @@ mod product {
     pub(crate) mod product_inner {
         use crate::commands::movie_clip_commands;
         use crate::{
-            utils::{deserialize_response, deserialize_response_null},
+            api_client,
+            utils::{deserialize_response, deserialize_response_null, send_as_client, tags_query},
             AppFrontError,
         };
-        use common::QueryInfoRef;
-        use domain::movie_clip::MovieClip;
-
-        use reqwest::Client;
-        use std::borrow::Cow::Borrowed;
+        use common::Page;
+        use domain::movie_clip::{MovieClip, MovieClipId};
+        use domain::Tag;
 
+        /// クリップを保存し，サーバーがid, like, create_dateを決定したクリップを返す．
         pub async fn save_movie_clip<'a>(
             url: &str,
             cmd: movie_clip_commands::SaveMovieClipCommand<'_>,
-        ) -> Result<(), AppFrontError> {
-            let request = Client::new()
+        ) -> Result<MovieClip, AppFrontError> {
+            let request = api_client()
                 .put(&format!("{}{}", url, "/movie_clip"))
-                .json(&cmd.movie_clip);
+                .json(&cmd.movie_clip_info);
 
             let response = request.send().await?;
 
-            deserialize_response_null(response).await
+            deserialize_response(response).await
         }
 
         pub async fn edit_movie_clip<'a>(
             url: &str,
             cmd: movie_clip_commands::EditMovieClipCommand<'_>,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new()
+            let request = api_client()
                 .patch(&format!("{}{}", url, "/movie_clip"))
                 .json(&cmd.movie_clip);
 
@@ -89,38 +121,79 @@ mod product {
             deserialize_response_null(response).await
         }
 
+        /// id, like, create_dateを保ったままクリップを保存する．管理者のみ
+        pub async fn import_movie_clips<'a>(
+            url: &str,
+            cmd: movie_clip_commands::ImportMovieClipsCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}{}", url, "/movie_clip/import"))
+                .json(&cmd.movie_clips);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
         pub async fn increment_like_movie_clip(
             url: &str,
             cmd: movie_clip_commands::IncrementLikeMovieClipCommand,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new().patch(&format!(
+            let request = api_client().patch(&format!(
                 "{}{}{}",
                 url, "/movie_clip/increment_like/", cmd.id
             ));
 
-            let response = request.send().await?;
+            let response = send_as_client(url, request).await?;
 
             deserialize_response_null(response).await
         }
 
+        pub async fn unlike_movie_clip(
+            url: &str,
+            cmd: movie_clip_commands::UnlikeMovieClipCommand,
+        ) -> Result<(), AppFrontError> {
+            let request =
+                api_client().patch(&format!("{}{}{}", url, "/movie_clip/unlike/", cmd.id));
+
+            let response = send_as_client(url, request).await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// `ids`のうち，このクライアントがLikeしているクリップのidを取得する．
+        pub async fn liked_movie_clip_ids<'a>(
+            url: &str,
+            cmd: movie_clip_commands::LikedMovieClipIdsCommand<'_>,
+        ) -> Result<Vec<MovieClipId>, AppFrontError> {
+            let request = api_client()
+                .post(&format!("{}{}", url, "/movie_clip/liked"))
+                .json(&cmd.ids);
+
+            let response = send_as_client(url, request).await?;
+
+            deserialize_response(response).await
+        }
+
         pub async fn all_movie_clips(
             url: &str,
             _cmd: movie_clip_commands::AllMovieClipsCommand,
         ) -> Result<Vec<MovieClip>, AppFrontError> {
-            let request = Client::new().get(&format!("{}{}", url, "/movie_clip"));
+            let request = api_client().get(&format!("{}{}", url, "/movie_clip"));
 
             let response = request.send().await?;
 
             deserialize_response(response).await
         }
 
-        pub async fn order_by_like_movie_clips(
+        pub async fn order_by_like_movie_clips<'a>(
             url: &str,
-            cmd: movie_clip_commands::OrderByLikeMovieClipsCommand,
-        ) -> Result<Vec<MovieClip>, AppFrontError> {
+            cmd: movie_clip_commands::OrderByLikeMovieClipsCommand<'_>,
+        ) -> Result<Page<MovieClip>, AppFrontError> {
             let query_string = format!("?sort_type=like&length={}", cmd.length);
-            let request =
-                Client::new().get(&format!("{}{}{}", url, "/movie_clip/query", query_string));
+            let request = api_client()
+                .get(&format!("{}{}{}", url, "/movie_clip/query", query_string))
+                .query(&tags_query(cmd.tags));
 
             let response = request.send().await?;
 
@@ -130,15 +203,14 @@ mod product {
         pub async fn order_by_like_later_movie_clips<'a>(
             url: &str,
             cmd: movie_clip_commands::OrderByLikeLaterMovieClipsCommand<'_>,
-        ) -> Result<Vec<MovieClip>, AppFrontError> {
-            let query_string = format!("?sort_type=like&length={}", cmd.length);
-            let query_info = QueryInfoRef::builder()
-                .reference(Borrowed(cmd.reference))
-                .build();
-
-            let request = Client::new()
-                .post(&format!("{}{}{}", url, "/movie_clip/query", query_string))
-                .json(&query_info);
+        ) -> Result<Page<MovieClip>, AppFrontError> {
+            let query_string = format!(
+                "?sort_type=like&length={}&cursor={}",
+                cmd.length, cmd.cursor
+            );
+            let request = api_client()
+                .get(&format!("{}{}{}", url, "/movie_clip/query", query_string))
+                .query(&tags_query(cmd.tags));
 
             let response = request.send().await?;
 
@@ -152,20 +224,22 @@ mod product {
             let query_string =
                 format!("?sort_type=create_date&start={}&end={}", cmd.start, cmd.end);
             let request =
-                Client::new().get(&format!("{}{}{}", url, "/movie_clip/query", query_string));
+                api_client().get(&format!("{}{}{}", url, "/movie_clip/query", query_string));
 
             let response = request.send().await?;
 
-            deserialize_response(response).await
+            let page: Page<MovieClip> = deserialize_response(response).await?;
+            Ok(page.items)
         }
 
-        pub async fn order_by_create_date_movie_clips(
+        pub async fn order_by_create_date_movie_clips<'a>(
             url: &str,
-            cmd: movie_clip_commands::OrderByCreateDateMovieClipsCommand,
-        ) -> Result<Vec<MovieClip>, AppFrontError> {
+            cmd: movie_clip_commands::OrderByCreateDateMovieClipsCommand<'_>,
+        ) -> Result<Page<MovieClip>, AppFrontError> {
             let query_string = format!("?sort_type=create_date&length={}", cmd.length);
-            let request =
-                Client::new().get(&format!("{}{}{}", url, "/movie_clip/query", query_string));
+            let request = api_client()
+                .get(&format!("{}{}{}", url, "/movie_clip/query", query_string))
+                .query(&tags_query(cmd.tags));
 
             let response = request.send().await?;
 
@@ -175,26 +249,110 @@ mod product {
         pub async fn order_by_create_date_later_movie_clips<'a>(
             url: &str,
             cmd: movie_clip_commands::OrderByCreateDateLaterMovieClipsCommand<'_>,
-        ) -> Result<Vec<MovieClip>, AppFrontError> {
-            let query_string = format!("?sort_type=create_date&length={}", cmd.length);
-            let query_info = QueryInfoRef::builder()
-                .reference(Borrowed(cmd.reference))
-                .build();
+        ) -> Result<Page<MovieClip>, AppFrontError> {
+            let query_string = format!(
+                "?sort_type=create_date&length={}&cursor={}",
+                cmd.length, cmd.cursor
+            );
+            let request = api_client()
+                .get(&format!("{}{}{}", url, "/movie_clip/query", query_string))
+                .query(&tags_query(cmd.tags));
 
-            let request = Client::new()
-                .post(&format!("{}{}{}", url, "/movie_clip/query", query_string))
-                .json(&query_info);
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// クリップに付けられている全てのタグを取得
+        pub async fn all_movie_clip_tags(
+            url: &str,
+            _cmd: movie_clip_commands::AllMovieClipTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/movie_clip/tags"));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// `id`を持つクリップのタグを取得
+        pub async fn movie_clip_tags(
+            url: &str,
+            cmd: movie_clip_commands::MovieClipTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}{}", url, "/movie_clip/tags/", cmd.id));
 
             let response = request.send().await?;
 
             deserialize_response(response).await
         }
 
+        /// `id`を持つクリップにタグを付ける
+        pub async fn add_movie_clip_tag<'a>(
+            url: &str,
+            cmd: movie_clip_commands::AddMovieClipTagCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}{}{}", url, "/movie_clip/tags/", cmd.id))
+                .json(cmd.tag);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// `id`を持つクリップからタグを外す
+        pub async fn remove_movie_clip_tag<'a>(
+            url: &str,
+            cmd: movie_clip_commands::RemoveMovieClipTagCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .delete(&format!("{}{}{}", url, "/movie_clip/tags/", cmd.id))
+                .json(cmd.tag);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
         pub async fn remove_movie_clip(
             url: &str,
             cmd: movie_clip_commands::RemoveMovieClipCommand,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new().delete(&format!("{}{}{}", url, "/movie_clip/", cmd.id));
+            let request = api_client().delete(&format!("{}{}{}", url, "/movie_clip/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        pub async fn trashed_movie_clips(
+            url: &str,
+            _cmd: movie_clip_commands::TrashedMovieClipsCommand,
+        ) -> Result<Vec<MovieClip>, AppFrontError> {
+            let request = api_client().get(&format!("{}/movie_clip/trash", url));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn restore_trashed_movie_clip(
+            url: &str,
+            cmd: movie_clip_commands::RestoreTrashedMovieClipCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().patch(&format!("{}/movie_clip/trash/{}", url, cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        pub async fn purge_movie_clip(
+            url: &str,
+            cmd: movie_clip_commands::PurgeMovieClipCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().delete(&format!("{}/movie_clip/trash/{}", url, cmd.id));
 
             let response = request.send().await?;
 
@@ -205,11 +363,13 @@ mod product {
     use crate::commands::movie_clip_commands;
     use crate::AppFrontError;
     use crate::{api_base_url, API_BASE_URL};
-    use domain::movie_clip::MovieClip;
+    use common::Page;
+    use domain::movie_clip::{MovieClip, MovieClipId};
+    use domain::Tag;
 
     pub async fn save_movie_clip<'a>(
         cmd: movie_clip_commands::SaveMovieClipCommand<'_>,
-    ) -> Result<(), AppFrontError> {
+    ) -> Result<MovieClip, AppFrontError> {
         product_inner::save_movie_clip(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
@@ -219,27 +379,45 @@ mod product {
         product_inner::edit_movie_clip(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
+    pub async fn import_movie_clips<'a>(
+        cmd: movie_clip_commands::ImportMovieClipsCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::import_movie_clips(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
     pub async fn increment_like_movie_clip(
         cmd: movie_clip_commands::IncrementLikeMovieClipCommand,
     ) -> Result<(), AppFrontError> {
         product_inner::increment_like_movie_clip(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
+    pub async fn unlike_movie_clip(
+        cmd: movie_clip_commands::UnlikeMovieClipCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::unlike_movie_clip(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn liked_movie_clip_ids<'a>(
+        cmd: movie_clip_commands::LikedMovieClipIdsCommand<'_>,
+    ) -> Result<Vec<MovieClipId>, AppFrontError> {
+        product_inner::liked_movie_clip_ids(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
     pub async fn all_movie_clips(
         cmd: movie_clip_commands::AllMovieClipsCommand,
     ) -> Result<Vec<MovieClip>, AppFrontError> {
         product_inner::all_movie_clips(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
-    pub async fn order_by_like_movie_clips(
-        cmd: movie_clip_commands::OrderByLikeMovieClipsCommand,
-    ) -> Result<Vec<MovieClip>, AppFrontError> {
+    pub async fn order_by_like_movie_clips<'a>(
+        cmd: movie_clip_commands::OrderByLikeMovieClipsCommand<'_>,
+    ) -> Result<Page<MovieClip>, AppFrontError> {
         product_inner::order_by_like_movie_clips(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
     pub async fn order_by_like_later_movie_clips<'a>(
         cmd: movie_clip_commands::OrderByLikeLaterMovieClipsCommand<'_>,
-    ) -> Result<Vec<MovieClip>, AppFrontError> {
+    ) -> Result<Page<MovieClip>, AppFrontError> {
         product_inner::order_by_like_later_movie_clips(API_BASE_URL.get_or_init(api_base_url), cmd)
             .await
     }
@@ -254,16 +432,16 @@ mod product {
         .await
     }
 
-    pub async fn order_by_create_date_movie_clips(
-        cmd: movie_clip_commands::OrderByCreateDateMovieClipsCommand,
-    ) -> Result<Vec<MovieClip>, AppFrontError> {
+    pub async fn order_by_create_date_movie_clips<'a>(
+        cmd: movie_clip_commands::OrderByCreateDateMovieClipsCommand<'_>,
+    ) -> Result<Page<MovieClip>, AppFrontError> {
         product_inner::order_by_create_date_movie_clips(API_BASE_URL.get_or_init(api_base_url), cmd)
             .await
     }
 
     pub async fn order_by_create_date_later_movie_clips<'a>(
         cmd: movie_clip_commands::OrderByCreateDateLaterMovieClipsCommand<'_>,
-    ) -> Result<Vec<MovieClip>, AppFrontError> {
+    ) -> Result<Page<MovieClip>, AppFrontError> {
         product_inner::order_by_create_date_later_movie_clips(
             API_BASE_URL.get_or_init(api_base_url),
             cmd,
@@ -271,25 +449,90 @@ mod product {
         .await
     }
 
+    /// クリップに付けられている全てのタグを取得
+    pub async fn all_movie_clip_tags(
+        cmd: movie_clip_commands::AllMovieClipTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        product_inner::all_movie_clip_tags(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つクリップのタグを取得
+    pub async fn movie_clip_tags(
+        cmd: movie_clip_commands::MovieClipTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        product_inner::movie_clip_tags(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つクリップにタグを付ける
+    pub async fn add_movie_clip_tag<'a>(
+        cmd: movie_clip_commands::AddMovieClipTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::add_movie_clip_tag(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つクリップからタグを外す
+    pub async fn remove_movie_clip_tag<'a>(
+        cmd: movie_clip_commands::RemoveMovieClipTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::remove_movie_clip_tag(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
     pub async fn remove_movie_clip(
         cmd: movie_clip_commands::RemoveMovieClipCommand,
     ) -> Result<(), AppFrontError> {
         product_inner::remove_movie_clip(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
+
+    pub async fn trashed_movie_clips(
+        cmd: movie_clip_commands::TrashedMovieClipsCommand,
+    ) -> Result<Vec<MovieClip>, AppFrontError> {
+        product_inner::trashed_movie_clips(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn restore_trashed_movie_clip(
+        cmd: movie_clip_commands::RestoreTrashedMovieClipCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::restore_trashed_movie_clip(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn purge_movie_clip(
+        cmd: movie_clip_commands::PurgeMovieClipCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::purge_movie_clip(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
 }
 
 #[cfg(feature = "fake")]
 mod fake {
     use crate::commands::movie_clip_commands;
     use crate::AppFrontError;
-    use domain::movie_clip::MovieClip;
+    use common::{AppCommonError, Page};
+    use domain::movie_clip::{MovieClip, MovieClipId};
+    use domain::Date;
+    use domain::Tag;
 
     use fake::{Fake, Faker};
 
+    /// `length`分のクリップのフェイクのページ
+    fn fake_page(length: usize) -> Page<MovieClip> {
+        let items = (0..length)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+        Page::new(items, true, Some("fake_cursor".to_string()))
+    }
+
     pub async fn save_movie_clip<'a>(
-        _cmd: movie_clip_commands::SaveMovieClipCommand<'_>,
-    ) -> Result<(), AppFrontError> {
-        Ok(())
+        cmd: movie_clip_commands::SaveMovieClipCommand<'_>,
+    ) -> Result<MovieClip, AppFrontError> {
+        let movie_clip_info = cmd.movie_clip_info.clone();
+        MovieClip::new_with_domains(
+            movie_clip_info.title,
+            movie_clip_info.url,
+            movie_clip_info.range,
+            Date::today(),
+        )
+        .with_duration(movie_clip_info.duration)
+        .map_err(|e| AppCommonError::DomainError(format!("{e}")).into())
     }
 
     pub async fn edit_movie_clip<'a>(
@@ -298,12 +541,30 @@ mod fake {
         Ok(())
     }
 
+    pub async fn import_movie_clips<'a>(
+        _cmd: movie_clip_commands::ImportMovieClipsCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
     pub async fn increment_like_movie_clip(
         _cmd: movie_clip_commands::IncrementLikeMovieClipCommand,
     ) -> Result<(), AppFrontError> {
         Ok(())
     }
 
+    pub async fn unlike_movie_clip(
+        _cmd: movie_clip_commands::UnlikeMovieClipCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn liked_movie_clip_ids<'a>(
+        _cmd: movie_clip_commands::LikedMovieClipIdsCommand<'_>,
+    ) -> Result<Vec<MovieClipId>, AppFrontError> {
+        Ok(Vec::new())
+    }
+
     pub async fn all_movie_clips(
         _cmd: movie_clip_commands::AllMovieClipsCommand,
     ) -> Result<Vec<MovieClip>, AppFrontError> {
@@ -312,20 +573,16 @@ mod fake {
             .collect::<Vec<_>>())
     }
 
-    pub async fn order_by_like_movie_clips(
-        cmd: movie_clip_commands::OrderByLikeMovieClipsCommand,
-    ) -> Result<Vec<MovieClip>, AppFrontError> {
-        Ok((0..cmd.length)
-            .map(|_| Faker.fake::<MovieClip>())
-            .collect::<Vec<_>>())
+    pub async fn order_by_like_movie_clips<'a>(
+        cmd: movie_clip_commands::OrderByLikeMovieClipsCommand<'_>,
+    ) -> Result<Page<MovieClip>, AppFrontError> {
+        Ok(fake_page(cmd.length))
     }
 
     pub async fn order_by_like_later_movie_clips<'a>(
         cmd: movie_clip_commands::OrderByLikeLaterMovieClipsCommand<'_>,
-    ) -> Result<Vec<MovieClip>, AppFrontError> {
-        Ok((0..cmd.length)
-            .map(|_| Faker.fake::<MovieClip>())
-            .collect::<Vec<_>>())
+    ) -> Result<Page<MovieClip>, AppFrontError> {
+        Ok(fake_page(cmd.length))
     }
 
     pub async fn order_by_create_date_range_movie_clips(
@@ -336,24 +593,68 @@ mod fake {
             .collect::<Vec<_>>())
     }
 
-    pub async fn order_by_create_date_movie_clips(
-        cmd: movie_clip_commands::OrderByCreateDateMovieClipsCommand,
-    ) -> Result<Vec<MovieClip>, AppFrontError> {
-        Ok((0..cmd.length)
-            .map(|_| Faker.fake::<MovieClip>())
-            .collect::<Vec<_>>())
+    pub async fn order_by_create_date_movie_clips<'a>(
+        cmd: movie_clip_commands::OrderByCreateDateMovieClipsCommand<'_>,
+    ) -> Result<Page<MovieClip>, AppFrontError> {
+        Ok(fake_page(cmd.length))
     }
 
     pub async fn order_by_create_date_later_movie_clips<'a>(
         cmd: movie_clip_commands::OrderByCreateDateLaterMovieClipsCommand<'_>,
+    ) -> Result<Page<MovieClip>, AppFrontError> {
+        Ok(fake_page(cmd.length))
+    }
+
+    /// クリップに付けられている全てのタグを取得(フェイク)
+    pub async fn all_movie_clip_tags(
+        _cmd: movie_clip_commands::AllMovieClipTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        Ok((0..10).map(|_| Faker.fake::<Tag>()).collect::<Vec<_>>())
+    }
+
+    /// `id`を持つクリップのタグを取得(フェイク)
+    pub async fn movie_clip_tags(
+        _cmd: movie_clip_commands::MovieClipTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        Ok((0..3).map(|_| Faker.fake::<Tag>()).collect::<Vec<_>>())
+    }
+
+    /// `id`を持つクリップにタグを付ける(フェイク)
+    pub async fn add_movie_clip_tag<'a>(
+        _cmd: movie_clip_commands::AddMovieClipTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// `id`を持つクリップからタグを外す(フェイク)
+    pub async fn remove_movie_clip_tag<'a>(
+        _cmd: movie_clip_commands::RemoveMovieClipTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn remove_movie_clip(
+        _cmd: movie_clip_commands::RemoveMovieClipCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn trashed_movie_clips(
+        _cmd: movie_clip_commands::TrashedMovieClipsCommand,
     ) -> Result<Vec<MovieClip>, AppFrontError> {
-        Ok((0..cmd.length)
+        Ok((0..5)
             .map(|_| Faker.fake::<MovieClip>())
             .collect::<Vec<_>>())
     }
 
-    pub async fn remove_movie_clip(
-        _cmd: movie_clip_commands::RemoveMovieClipCommand,
+    pub async fn restore_trashed_movie_clip(
+        _cmd: movie_clip_commands::RestoreTrashedMovieClipCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    pub async fn purge_movie_clip(
+        _cmd: movie_clip_commands::PurgeMovieClipCommand,
     ) -> Result<(), AppFrontError> {
         Ok(())
     }
@@ -366,47 +667,45 @@ pub mod test {
         use super::super::product::product_inner;
         use crate::commands::movie_clip_commands;
         use crate::AppFrontError;
-        use common::{AppCommonError, QueryInfoRef};
+        use common::{AppCommonError, NewMovieClipInfo, Page};
         use domain::movie_clip::{MovieClip, MovieClipId};
-        use domain::Date;
+        use domain::{Date, Tag};
 
         use fake::{Fake, Faker};
         use pretty_assertions::assert_eq;
-        use std::borrow::Cow::Borrowed;
         use wiremock::matchers::{body_json, method, path, query_param};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         #[tokio::test]
         async fn test_save_movie_clip() {
             let clip = Faker.fake::<MovieClip>();
+            let clip_info = NewMovieClipInfo::from(clip.clone());
 
             {
                 // 成功した場合
-                let clip = clip.clone();
                 let mock_server = MockServer::start().await;
 
                 Mock::given(method("PUT"))
                     .and(path("/movie_clip"))
-                    .and(body_json(clip.clone()))
-                    .respond_with(ResponseTemplate::new(200))
+                    .and(body_json(clip_info.clone()))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(clip.clone()))
                     .mount(&mock_server)
                     .await;
 
                 let res = {
-                    let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip);
+                    let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
                     product_inner::save_movie_clip(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert_eq!(res.unwrap(), clip);
             }
             {
                 // 失敗した場合
-                let clip = clip.clone();
                 let mock_server = MockServer::start().await;
 
                 Mock::given(method("PUT"))
                     .and(path("/movie_clip"))
-                    .and(body_json(clip.clone()))
+                    .and(body_json(clip_info.clone()))
                     .respond_with(
                         ResponseTemplate::new(500).set_body_json(AppCommonError::ConflictError),
                     )
@@ -414,7 +713,7 @@ pub mod test {
                     .await;
 
                 let res = {
-                    let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip);
+                    let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
                     product_inner::save_movie_clip(&mock_server.uri(), cmd).await
                 };
 
@@ -447,7 +746,7 @@ pub mod test {
                     product_inner::edit_movie_clip(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
             {
                 // 失敗した場合
@@ -476,6 +775,59 @@ pub mod test {
             }
         }
 
+        #[tokio::test]
+        async fn test_import_movie_clips() {
+            let clips = (0..3)
+                .map(|_| Faker.fake::<MovieClip>())
+                .collect::<Vec<_>>();
+
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PUT"))
+                    .and(path("/movie_clip/import"))
+                    .and(body_json(clips.clone()))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = movie_clip_commands::ImportMovieClipsCommand::new(&clips);
+                    product_inner::import_movie_clips(&mock_server.uri(), cmd).await
+                };
+
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+            {
+                // 失敗した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PUT"))
+                    .and(path("/movie_clip/import"))
+                    .and(body_json(clips.clone()))
+                    .respond_with(
+                        ResponseTemplate::new(409).set_body_json(AppCommonError::ConflictError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = movie_clip_commands::ImportMovieClipsCommand::new(&clips);
+                    product_inner::import_movie_clips(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::ConflictError))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
+
         #[tokio::test]
         async fn test_increment_like_movie_clip() {
             let id = MovieClipId::generate();
@@ -495,7 +847,7 @@ pub mod test {
                     product_inner::increment_like_movie_clip(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
             {
                 // 失敗した場合
@@ -520,6 +872,78 @@ pub mod test {
                     res.unwrap_err()
                 );
             }
+            {
+                // レート制限を超えた場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PATCH"))
+                    .and(path(format!("/movie_clip/increment_like/{}", id)))
+                    .respond_with(
+                        ResponseTemplate::new(429)
+                            .insert_header("Retry-After", "3")
+                            .set_body_json(AppCommonError::RateLimited { retry_after: 3 }),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(id);
+                    product_inner::increment_like_movie_clip(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::RateLimited {
+                            retry_after: 3
+                        }))
+                    ),
+                    "{:?}",
+                    res.unwrap_err()
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn test_unlike_movie_clip() {
+            let id = MovieClipId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PATCH"))
+                .and(path(format!("/movie_clip/unlike/{}", id)))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = movie_clip_commands::UnlikeMovieClipCommand::new(id);
+                product_inner::unlike_movie_clip(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_liked_movie_clip_ids() {
+            let ids = (0..10).map(|_| MovieClipId::generate()).collect::<Vec<_>>();
+            let liked_ids = ids[..5].to_vec();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("POST"))
+                .and(path("/movie_clip/liked"))
+                .and(body_json(ids.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(liked_ids.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = movie_clip_commands::LikedMovieClipIdsCommand::new(&ids);
+                product_inner::liked_movie_clip_ids(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), liked_ids);
         }
 
         #[tokio::test]
@@ -551,6 +975,7 @@ pub mod test {
                 .collect::<Vec<_>>();
 
             let length = 10_usize;
+            let page = Page::new(clips, true, Some("cursor".to_string()));
 
             let mock_server = MockServer::start().await;
 
@@ -558,16 +983,45 @@ pub mod test {
                 .and(path("/movie_clip/query"))
                 .and(query_param("sort_type", "like"))
                 .and(query_param("length", length.to_string()))
-                .respond_with(ResponseTemplate::new(200).set_body_json(clips.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
                 .mount(&mock_server)
                 .await;
 
             let res = {
-                let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(length);
+                let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(&[], length);
                 product_inner::order_by_like_movie_clips(&mock_server.uri(), cmd).await
             };
 
-            assert_eq!(res.unwrap(), clips);
+            assert_eq!(res.unwrap(), page);
+        }
+
+        #[tokio::test]
+        async fn test_order_by_like_movie_clips_with_tags() {
+            let clips = (0..10)
+                .map(|_| Faker.fake::<MovieClip>())
+                .collect::<Vec<_>>();
+
+            let length = 10_usize;
+            let tags = vec![Tag::new("game").unwrap(), Tag::new("歌枠").unwrap()];
+            let page = Page::new(clips, false, None);
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/movie_clip/query"))
+                .and(query_param("sort_type", "like"))
+                .and(query_param("length", length.to_string()))
+                .and(query_param("tags", "game,歌枠"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(&tags, length);
+                product_inner::order_by_like_movie_clips(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), page);
         }
 
         #[tokio::test]
@@ -577,30 +1031,30 @@ pub mod test {
                 .collect::<Vec<_>>();
 
             let length = 10_usize;
-            let reference = Faker.fake::<MovieClip>();
-
-            let query_info = QueryInfoRef::builder()
-                .reference(Borrowed(&reference))
-                .build();
+            let cursor = "cursor";
+            let page = Page::new(clips, true, Some("next_cursor".to_string()));
 
             let mock_server = MockServer::start().await;
 
-            Mock::given(method("POST"))
+            Mock::given(method("GET"))
                 .and(path("/movie_clip/query"))
                 .and(query_param("sort_type", "like"))
                 .and(query_param("length", length.to_string()))
-                .and(body_json(query_info))
-                .respond_with(ResponseTemplate::new(200).set_body_json(clips.clone()))
+                .and(query_param("cursor", cursor))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
                 .mount(&mock_server)
                 .await;
 
             let res = {
-                let cmd =
-                    movie_clip_commands::OrderByLikeLaterMovieClipsCommand::new(&reference, length);
+                let cmd = movie_clip_commands::OrderByLikeLaterMovieClipsCommand::new(
+                    &[],
+                    cursor,
+                    length,
+                );
                 product_inner::order_by_like_later_movie_clips(&mock_server.uri(), cmd).await
             };
 
-            assert_eq!(res.unwrap(), clips);
+            assert_eq!(res.unwrap(), page);
         }
 
         #[tokio::test]
@@ -619,7 +1073,11 @@ pub mod test {
                 .and(query_param("sort_type", "create_date"))
                 .and(query_param("start", start.to_string()))
                 .and(query_param("end", end.to_string()))
-                .respond_with(ResponseTemplate::new(200).set_body_json(clips.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(Page::new(
+                    clips.clone(),
+                    false,
+                    None,
+                )))
                 .mount(&mock_server)
                 .await;
 
@@ -639,6 +1097,7 @@ pub mod test {
                 .collect::<Vec<_>>();
 
             let length = 10_usize;
+            let page = Page::new(clips, true, Some("cursor".to_string()));
 
             let mock_server = MockServer::start().await;
 
@@ -646,16 +1105,16 @@ pub mod test {
                 .and(path("/movie_clip/query"))
                 .and(query_param("sort_type", "create_date"))
                 .and(query_param("length", length.to_string()))
-                .respond_with(ResponseTemplate::new(200).set_body_json(clips.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
                 .mount(&mock_server)
                 .await;
 
             let res = {
-                let cmd = movie_clip_commands::OrderByCreateDateMovieClipsCommand::new(length);
+                let cmd = movie_clip_commands::OrderByCreateDateMovieClipsCommand::new(&[], length);
                 product_inner::order_by_create_date_movie_clips(&mock_server.uri(), cmd).await
             };
 
-            assert_eq!(res.unwrap(), clips);
+            assert_eq!(res.unwrap(), page);
         }
 
         #[tokio::test]
@@ -665,31 +1124,95 @@ pub mod test {
                 .collect::<Vec<_>>();
 
             let length = 10_usize;
-            let reference = Faker.fake::<MovieClip>();
-
-            let query_info = QueryInfoRef::builder()
-                .reference(Borrowed(&reference))
-                .build();
+            let cursor = "cursor";
+            let page = Page::new(clips, true, Some("next_cursor".to_string()));
 
             let mock_server = MockServer::start().await;
 
-            Mock::given(method("POST"))
+            Mock::given(method("GET"))
                 .and(path("/movie_clip/query"))
                 .and(query_param("sort_type", "create_date"))
                 .and(query_param("length", length.to_string()))
-                .and(body_json(query_info))
-                .respond_with(ResponseTemplate::new(200).set_body_json(clips.clone()))
+                .and(query_param("cursor", cursor))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
                 .mount(&mock_server)
                 .await;
 
             let res = {
                 let cmd = movie_clip_commands::OrderByCreateDateLaterMovieClipsCommand::new(
-                    &reference, length,
+                    &[],
+                    cursor,
+                    length,
                 );
                 product_inner::order_by_create_date_later_movie_clips(&mock_server.uri(), cmd).await
             };
 
-            assert_eq!(res.unwrap(), clips);
+            assert_eq!(res.unwrap(), page);
+        }
+
+        #[tokio::test]
+        async fn test_movie_clip_tags() {
+            let id = MovieClipId::generate();
+            let tags = vec![Tag::new("game").unwrap(), Tag::new("singing").unwrap()];
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/movie_clip/tags"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(tags.clone()))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path(format!("/movie_clip/tags/{}", id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(tags[..1].to_vec()))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("PUT"))
+                .and(path(format!("/movie_clip/tags/{}", id)))
+                .and(body_json(tags[0].clone()))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("DELETE"))
+                .and(path(format!("/movie_clip/tags/{}", id)))
+                .and(body_json(tags[1].clone()))
+                .respond_with(
+                    ResponseTemplate::new(404).set_body_json(AppCommonError::NoRecordError),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = movie_clip_commands::AllMovieClipTagsCommand;
+                product_inner::all_movie_clip_tags(&mock_server.uri(), cmd).await
+            };
+            assert_eq!(res.unwrap(), tags);
+
+            let res = {
+                let cmd = movie_clip_commands::MovieClipTagsCommand::new(id);
+                product_inner::movie_clip_tags(&mock_server.uri(), cmd).await
+            };
+            assert_eq!(res.unwrap(), tags[..1]);
+
+            let res = {
+                let cmd = movie_clip_commands::AddMovieClipTagCommand::new(id, &tags[0]);
+                product_inner::add_movie_clip_tag(&mock_server.uri(), cmd).await
+            };
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+
+            // 付けられていないタグを外す場合
+            let res = {
+                let cmd = movie_clip_commands::RemoveMovieClipTagCommand::new(id, &tags[1]);
+                product_inner::remove_movie_clip_tag(&mock_server.uri(), cmd).await
+            };
+            assert!(
+                matches!(
+                    res,
+                    Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+                ),
+                "{:?}",
+                res
+            );
         }
 
         #[tokio::test]
@@ -711,7 +1234,7 @@ pub mod test {
                     product_inner::remove_movie_clip(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
             {
                 // 失敗した場合
@@ -737,5 +1260,96 @@ pub mod test {
                 );
             }
         }
+
+        #[tokio::test]
+        async fn test_trashed_movie_clips() {
+            let clips = (0..3)
+                .map(|_| Faker.fake::<MovieClip>())
+                .collect::<Vec<_>>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/movie_clip/trash"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(clips.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = movie_clip_commands::TrashedMovieClipsCommand;
+                product_inner::trashed_movie_clips(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), clips);
+        }
+
+        #[tokio::test]
+        async fn test_restore_trashed_movie_clip() {
+            let id = MovieClipId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PATCH"))
+                .and(path(format!("/movie_clip/trash/{id}")))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = movie_clip_commands::RestoreTrashedMovieClipCommand::new(id);
+                product_inner::restore_trashed_movie_clip(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_purge_movie_clip() {
+            let id = MovieClipId::generate();
+
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("DELETE"))
+                    .and(path(format!("/movie_clip/trash/{id}")))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = movie_clip_commands::PurgeMovieClipCommand::new(id);
+                    product_inner::purge_movie_clip(&mock_server.uri(), cmd).await
+                };
+
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+            {
+                // 管理者でない場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("DELETE"))
+                    .and(path(format!("/movie_clip/trash/{id}")))
+                    .respond_with(
+                        ResponseTemplate::new(403).set_body_json(AppCommonError::ForbiddenError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = movie_clip_commands::PurgeMovieClipCommand::new(id);
+                    product_inner::purge_movie_clip(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::ForbiddenError))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
     }
 }
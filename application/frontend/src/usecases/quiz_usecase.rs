@@ -0,0 +1,415 @@
+#[cfg(not(feature = "fake"))]
+pub use self::product::*;
+
+#[cfg(feature = "fake")]
+pub use self::fake::*;
+
+/// APIをチェックするためのbehavior
+#[cfg(test)]
+mod _behavior {
+    use crate::commands::quiz_commands;
+    use crate::AppFrontError;
+    use common::{EditToken, QuizQuestion};
+    use domain::quiz::Quiz;
+
+    #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
+    #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
+    #[async_trait::async_trait]
+    trait Behavior {
+        async fn question(
+            cmd: quiz_commands::QuestionCommand,
+        ) -> Result<QuizQuestion, AppFrontError>;
+        async fn answer<'a>(
+            cmd: quiz_commands::AnswerCommand<'a>,
+        ) -> Result<EditToken, AppFrontError>;
+        async fn save_quiz<'a>(
+            cmd: quiz_commands::SaveQuizCommand<'a>,
+        ) -> Result<(), AppFrontError>;
+        async fn edit_quiz<'a>(
+            cmd: quiz_commands::EditQuizCommand<'a>,
+        ) -> Result<(), AppFrontError>;
+        async fn all_quizzes(
+            cmd: quiz_commands::AllQuizzesCommand,
+        ) -> Result<Vec<Quiz>, AppFrontError>;
+        async fn remove_quiz(cmd: quiz_commands::RemoveQuizCommand) -> Result<(), AppFrontError>;
+    }
+}
+
+#[cfg(not(feature = "fake"))]
+mod product {
+    /// テストのためにuriを引数とした関数にするためのモジュール
+    pub(crate) mod product_inner {
+        use crate::commands::quiz_commands;
+        use crate::{
+            api_client,
+            utils::{deserialize_response, deserialize_response_null},
+            AppFrontError,
+        };
+        use common::{EditToken, QuizQuestion};
+        use domain::quiz::Quiz;
+
+        /// クイズを出題してもらう
+        pub async fn question(
+            url: &str,
+            _cmd: quiz_commands::QuestionCommand,
+        ) -> Result<QuizQuestion, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/quiz/question"));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// クイズに回答する．正解の場合は編集用のトークンがクッキーに設定される．
+        pub async fn answer<'a>(
+            url: &str,
+            cmd: quiz_commands::AnswerCommand<'_>,
+        ) -> Result<EditToken, AppFrontError> {
+            let request = api_client()
+                .post(&format!("{}{}", url, "/quiz/answer"))
+                .json(cmd.quiz_answer);
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// クイズを保存
+        pub async fn save_quiz<'a>(
+            url: &str,
+            cmd: quiz_commands::SaveQuizCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}{}", url, "/quiz"))
+                .json(cmd.quiz);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// クイズを編集
+        pub async fn edit_quiz<'a>(
+            url: &str,
+            cmd: quiz_commands::EditQuizCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .patch(&format!("{}{}", url, "/quiz"))
+                .json(cmd.quiz);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// 回答を含む全てのクイズを取得
+        pub async fn all_quizzes(
+            url: &str,
+            _cmd: quiz_commands::AllQuizzesCommand,
+        ) -> Result<Vec<Quiz>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/quiz"));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// クイズを削除
+        pub async fn remove_quiz(
+            url: &str,
+            cmd: quiz_commands::RemoveQuizCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().delete(&format!("{}{}{}", url, "/quiz/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+    }
+
+    use crate::commands::quiz_commands;
+    use crate::AppFrontError;
+    use crate::{api_base_url, API_BASE_URL};
+    use common::{EditToken, QuizQuestion};
+    use domain::quiz::Quiz;
+
+    /// クイズを出題してもらう
+    pub async fn question(
+        cmd: quiz_commands::QuestionCommand,
+    ) -> Result<QuizQuestion, AppFrontError> {
+        product_inner::question(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// クイズに回答する．正解の場合は編集用のトークンがクッキーに設定される．
+    pub async fn answer<'a>(
+        cmd: quiz_commands::AnswerCommand<'_>,
+    ) -> Result<EditToken, AppFrontError> {
+        product_inner::answer(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// クイズを保存
+    pub async fn save_quiz<'a>(
+        cmd: quiz_commands::SaveQuizCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::save_quiz(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// クイズを編集
+    pub async fn edit_quiz<'a>(
+        cmd: quiz_commands::EditQuizCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::edit_quiz(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// 回答を含む全てのクイズを取得
+    pub async fn all_quizzes(
+        cmd: quiz_commands::AllQuizzesCommand,
+    ) -> Result<Vec<Quiz>, AppFrontError> {
+        product_inner::all_quizzes(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// クイズを削除
+    pub async fn remove_quiz(cmd: quiz_commands::RemoveQuizCommand) -> Result<(), AppFrontError> {
+        product_inner::remove_quiz(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake {
+    use crate::commands::quiz_commands;
+    use crate::AppFrontError;
+    use common::{AppCommonError, EditToken, QuizQuestion};
+    use config::CONFIG;
+    use domain::quiz::Quiz;
+    use fake::{Fake, Faker};
+
+    /// クイズを出題してもらう(フェイク)．回答は"answer"
+    pub async fn question(
+        _cmd: quiz_commands::QuestionCommand,
+    ) -> Result<QuizQuestion, AppFrontError> {
+        Ok(QuizQuestion {
+            challenge_id: "fake_challenge".to_string(),
+            question: Faker.fake::<Quiz>().question().to_string(),
+        })
+    }
+
+    /// クイズに回答する(フェイク)．"answer"のみ正解とする．
+    pub async fn answer<'a>(
+        cmd: quiz_commands::AnswerCommand<'_>,
+    ) -> Result<EditToken, AppFrontError> {
+        if cmd.quiz_answer.answer != "answer" {
+            return Err(AppCommonError::IncorrectAnswerError.into());
+        }
+        Ok(EditToken {
+            token: "fake_token".to_string(),
+            max_age: CONFIG.edit_token_max_age,
+        })
+    }
+
+    /// クイズを保存(フェイク)
+    pub async fn save_quiz<'a>(
+        _cmd: quiz_commands::SaveQuizCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// クイズを編集(フェイク)
+    pub async fn edit_quiz<'a>(
+        _cmd: quiz_commands::EditQuizCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// 回答を含む全てのクイズを取得(フェイク)
+    pub async fn all_quizzes(
+        _cmd: quiz_commands::AllQuizzesCommand,
+    ) -> Result<Vec<Quiz>, AppFrontError> {
+        Ok((0..5).map(|_| Faker.fake::<Quiz>()).collect::<Vec<_>>())
+    }
+
+    /// クイズを削除(フェイク)
+    pub async fn remove_quiz(_cmd: quiz_commands::RemoveQuizCommand) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(not(feature = "fake"))]
+    mod product_test {
+        use super::super::product::product_inner;
+        use crate::commands::quiz_commands;
+        use crate::AppFrontError;
+        use common::{AppCommonError, EditToken, QuizAnswer, QuizQuestion};
+        use domain::quiz::{Quiz, QuizId};
+
+        use fake::{Fake, Faker};
+        use pretty_assertions::assert_eq;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[tokio::test]
+        async fn test_question() {
+            let quiz_question = QuizQuestion {
+                challenge_id: "challenge".to_string(),
+                question: "question".to_string(),
+            };
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/quiz/question"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(quiz_question.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = quiz_commands::QuestionCommand;
+                product_inner::question(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), quiz_question);
+        }
+
+        #[tokio::test]
+        async fn test_answer() {
+            {
+                // 正解の場合
+                let quiz_answer = QuizAnswer::new("challenge", "answer");
+                let edit_token = EditToken {
+                    token: "token".to_string(),
+                    max_age: 60,
+                };
+
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("POST"))
+                    .and(path("/quiz/answer"))
+                    .and(body_json(quiz_answer.clone()))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(edit_token.clone()))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = quiz_commands::AnswerCommand::new(&quiz_answer);
+                    product_inner::answer(&mock_server.uri(), cmd).await
+                };
+
+                assert_eq!(res.unwrap(), edit_token);
+            }
+            {
+                // 不正解の場合
+                let quiz_answer = QuizAnswer::new("challenge", "wrong answer");
+
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("POST"))
+                    .and(path("/quiz/answer"))
+                    .respond_with(
+                        ResponseTemplate::new(401)
+                            .set_body_json(AppCommonError::IncorrectAnswerError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = quiz_commands::AnswerCommand::new(&quiz_answer);
+                    product_inner::answer(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(
+                            AppCommonError::IncorrectAnswerError
+                        ))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn test_save_and_edit_quiz() {
+            let quiz = Faker.fake::<Quiz>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PUT"))
+                .and(path("/quiz"))
+                .and(body_json(quiz.clone()))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            Mock::given(method("PATCH"))
+                .and(path("/quiz"))
+                .and(body_json(quiz.clone()))
+                .respond_with(
+                    ResponseTemplate::new(403).set_body_json(AppCommonError::ForbiddenError),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = quiz_commands::SaveQuizCommand::new(&quiz);
+                product_inner::save_quiz(&mock_server.uri(), cmd).await
+            };
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+
+            let res = {
+                let cmd = quiz_commands::EditQuizCommand::new(&quiz);
+                product_inner::edit_quiz(&mock_server.uri(), cmd).await
+            };
+            assert!(
+                matches!(
+                    res,
+                    Err(AppFrontError::CommonError(AppCommonError::ForbiddenError))
+                ),
+                "{:?}",
+                res
+            );
+        }
+
+        #[tokio::test]
+        async fn test_all_quizzes() {
+            let quizzes = (0..5).map(|_| Faker.fake::<Quiz>()).collect::<Vec<_>>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/quiz"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(quizzes.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = quiz_commands::AllQuizzesCommand;
+                product_inner::all_quizzes(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), quizzes);
+        }
+
+        #[tokio::test]
+        async fn test_remove_quiz() {
+            let quiz_id = QuizId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("DELETE"))
+                .and(path(format!("/quiz/{quiz_id}")))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = quiz_commands::RemoveQuizCommand::new(quiz_id);
+                product_inner::remove_quiz(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+    }
+}
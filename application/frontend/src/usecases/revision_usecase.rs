@@ -0,0 +1,306 @@
+#[cfg(not(feature = "fake"))]
+pub use self::product::*;
+
+#[cfg(feature = "fake")]
+pub use self::fake::*;
+
+/// APIをチェックするためのbehavior
+#[cfg(test)]
+mod _behavior {
+    use crate::commands::revision_commands;
+    use crate::AppFrontError;
+    use domain::revision::{FieldChange, Revision};
+
+    #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
+    #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
+    #[async_trait::async_trait]
+    trait Behavior {
+        async fn latest_revisions(
+            cmd: revision_commands::LatestRevisionsCommand,
+        ) -> Result<Vec<Revision>, AppFrontError>;
+        async fn entity_revisions(
+            cmd: revision_commands::EntityRevisionsCommand,
+        ) -> Result<Vec<Revision>, AppFrontError>;
+        async fn revision_diff(
+            cmd: revision_commands::RevisionDiffCommand,
+        ) -> Result<Vec<FieldChange>, AppFrontError>;
+        async fn restore_revision(
+            cmd: revision_commands::RestoreRevisionCommand,
+        ) -> Result<(), AppFrontError>;
+    }
+}
+
+#[cfg(not(feature = "fake"))]
+mod product {
+    pub(crate) mod product_inner {
+        use crate::commands::revision_commands;
+        use crate::{
+            api_client,
+            utils::{deserialize_response, deserialize_response_null},
+            AppFrontError,
+        };
+        use domain::revision::{FieldChange, Revision};
+
+        /// `kind`の最近の編集履歴を新しい順に取得する．
+        pub async fn latest_revisions(
+            url: &str,
+            cmd: revision_commands::LatestRevisionsCommand,
+        ) -> Result<Vec<Revision>, AppFrontError> {
+            let query_string = format!("?length={}", cmd.length);
+            let request =
+                api_client().get(&format!("{}/{}/revisions{}", url, cmd.kind, query_string));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// 一つのエンティティの編集履歴を古い順に取得する．
+        pub async fn entity_revisions(
+            url: &str,
+            cmd: revision_commands::EntityRevisionsCommand,
+        ) -> Result<Vec<Revision>, AppFrontError> {
+            let request =
+                api_client().get(&format!("{}/{}/revisions/{}", url, cmd.kind, cmd.entity_id));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn revision_diff(
+            url: &str,
+            cmd: revision_commands::RevisionDiffCommand,
+        ) -> Result<Vec<FieldChange>, AppFrontError> {
+            let query_string = format!("?from={}&to={}", cmd.from, cmd.to);
+            let request = api_client().get(&format!(
+                "{}/{}/revision_diff{}",
+                url, cmd.kind, query_string
+            ));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        pub async fn restore_revision(
+            url: &str,
+            cmd: revision_commands::RestoreRevisionCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().patch(&format!("{}/{}/restore/{}", url, cmd.kind, cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+    }
+
+    use crate::commands::revision_commands;
+    use crate::AppFrontError;
+    use crate::{api_base_url, API_BASE_URL};
+    use domain::revision::{FieldChange, Revision};
+
+    pub async fn latest_revisions(
+        cmd: revision_commands::LatestRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppFrontError> {
+        product_inner::latest_revisions(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn entity_revisions(
+        cmd: revision_commands::EntityRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppFrontError> {
+        product_inner::entity_revisions(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn revision_diff(
+        cmd: revision_commands::RevisionDiffCommand,
+    ) -> Result<Vec<FieldChange>, AppFrontError> {
+        product_inner::revision_diff(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    pub async fn restore_revision(
+        cmd: revision_commands::RestoreRevisionCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::restore_revision(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake {
+    use crate::commands::revision_commands;
+    use crate::AppFrontError;
+    use domain::revision::{FieldChange, Revision};
+
+    use fake::{Fake, Faker};
+
+    pub async fn latest_revisions(
+        cmd: revision_commands::LatestRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppFrontError> {
+        Ok((0..cmd.length)
+            .map(|_| Faker.fake::<Revision>())
+            .collect::<Vec<_>>())
+    }
+
+    pub async fn entity_revisions(
+        _cmd: revision_commands::EntityRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppFrontError> {
+        Ok((0..5).map(|_| Faker.fake::<Revision>()).collect::<Vec<_>>())
+    }
+
+    pub async fn revision_diff(
+        _cmd: revision_commands::RevisionDiffCommand,
+    ) -> Result<Vec<FieldChange>, AppFrontError> {
+        Ok(vec![FieldChange {
+            field: "title".to_string(),
+            before: Some(Faker.fake::<String>().into()),
+            after: Some(Faker.fake::<String>().into()),
+        }])
+    }
+
+    pub async fn restore_revision(
+        _cmd: revision_commands::RestoreRevisionCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[cfg(not(feature = "fake"))]
+    mod product_test {
+        use super::super::product::product_inner;
+        use crate::commands::revision_commands;
+        use crate::AppFrontError;
+        use common::AppCommonError;
+        use domain::revision::{EntityKind, FieldChange, Revision, RevisionId};
+
+        use fake::{Fake, Faker};
+        use pretty_assertions::assert_eq;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[tokio::test]
+        async fn test_latest_revisions() {
+            let revisions = (0..10)
+                .map(|_| Faker.fake::<Revision>())
+                .collect::<Vec<_>>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/kirinuki/revisions"))
+                .and(query_param("length", "10"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(revisions.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = revision_commands::LatestRevisionsCommand::new(EntityKind::Kirinuki, 10);
+                product_inner::latest_revisions(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), revisions);
+        }
+
+        #[tokio::test]
+        async fn test_entity_revisions() {
+            let revisions = (0..3).map(|_| Faker.fake::<Revision>()).collect::<Vec<_>>();
+            let entity_id = revisions[0].entity_id();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path(format!("/movie_clip/revisions/{entity_id}")))
+                .respond_with(ResponseTemplate::new(200).set_body_json(revisions.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = revision_commands::EntityRevisionsCommand::new(
+                    EntityKind::MovieClip,
+                    entity_id,
+                );
+                product_inner::entity_revisions(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), revisions);
+        }
+
+        #[tokio::test]
+        async fn test_revision_diff() {
+            let (from, to) = (RevisionId::generate(), RevisionId::generate());
+            let changes = vec![FieldChange {
+                field: "title".to_string(),
+                before: Some("before".into()),
+                after: Some("after".into()),
+            }];
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/episode/revision_diff"))
+                .and(query_param("from", from.to_string()))
+                .and(query_param("to", to.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(changes.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd =
+                    revision_commands::RevisionDiffCommand::new(EntityKind::Episode, from, to);
+                product_inner::revision_diff(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), changes);
+        }
+
+        #[tokio::test]
+        async fn test_restore_revision() {
+            let id = RevisionId::generate();
+
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PATCH"))
+                    .and(path(format!("/original/restore/{id}")))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd =
+                        revision_commands::RestoreRevisionCommand::new(EntityKind::Original, id);
+                    product_inner::restore_revision(&mock_server.uri(), cmd).await
+                };
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+            {
+                // 失敗した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PATCH"))
+                    .and(path(format!("/original/restore/{id}")))
+                    .respond_with(
+                        ResponseTemplate::new(404).set_body_json(AppCommonError::NoRecordError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd =
+                        revision_commands::RestoreRevisionCommand::new(EntityKind::Original, id);
+                    product_inner::restore_revision(&mock_server.uri(), cmd).await
+                };
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+                    ),
+                    "{:?}",
+                    res.unwrap_err()
+                );
+            }
+        }
+    }
+}
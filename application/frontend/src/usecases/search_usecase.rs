@@ -0,0 +1,167 @@
+#[cfg(not(feature = "fake"))]
+pub use self::product::*;
+
+#[cfg(feature = "fake")]
+pub use self::fake::*;
+
+/// APIをチェックするためのbehavior
+#[cfg(test)]
+mod _behavior {
+    use crate::commands::search_commands;
+    use crate::AppFrontError;
+    use domain::search::{SearchHit, SearchItem};
+
+    #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
+    #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
+    #[async_trait::async_trait]
+    trait Behavior {
+        async fn search<'a>(
+            cmd: search_commands::SearchCommand<'_>,
+        ) -> Result<Vec<SearchHit<SearchItem>>, AppFrontError>;
+    }
+}
+
+#[cfg(not(feature = "fake"))]
+mod product {
+    pub(crate) mod product_inner {
+        use crate::commands::search_commands;
+        use crate::{api_client, utils::deserialize_response, AppFrontError};
+        use domain::search::{SearchHit, SearchItem};
+
+        /// 種類に関わらずスコアの降順で取得する．
+        pub async fn search<'a>(
+            url: &str,
+            cmd: search_commands::SearchCommand<'_>,
+        ) -> Result<Vec<SearchHit<SearchItem>>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/search")).query(&[
+                ("query", cmd.query.to_string()),
+                ("length", cmd.length.to_string()),
+            ]);
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+    }
+
+    use crate::commands::search_commands;
+    use crate::AppFrontError;
+    use crate::{api_base_url, API_BASE_URL};
+    use domain::search::{SearchHit, SearchItem};
+
+    pub async fn search<'a>(
+        cmd: search_commands::SearchCommand<'_>,
+    ) -> Result<Vec<SearchHit<SearchItem>>, AppFrontError> {
+        product_inner::search(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake {
+    use crate::commands::search_commands;
+    use crate::AppFrontError;
+    use domain::episode::Episode;
+    use domain::movie_clip::MovieClip;
+    use domain::search::{SearchHit, SearchItem};
+    use domain::video::{Kirinuki, Original, Video};
+
+    use fake::{Fake, Faker};
+
+    pub async fn search<'a>(
+        cmd: search_commands::SearchCommand<'_>,
+    ) -> Result<Vec<SearchHit<SearchItem>>, AppFrontError> {
+        let hits = (0..cmd.length)
+            .map(|i| {
+                let item = match i % 4 {
+                    0 => SearchItem::from(Faker.fake::<MovieClip>()),
+                    1 => SearchItem::from(Faker.fake::<Video<Original>>()),
+                    2 => SearchItem::from(Faker.fake::<Video<Kirinuki>>()),
+                    _ => SearchItem::from(Faker.fake::<Episode>()),
+                };
+                SearchHit::new(item, 1.0 / (i + 1) as f32)
+            })
+            .collect::<Vec<_>>();
+        Ok(hits)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[cfg(not(feature = "fake"))]
+    mod product_test {
+        use super::super::product::product_inner;
+        use crate::commands::search_commands;
+        use crate::AppFrontError;
+        use common::AppCommonError;
+        use domain::episode::Episode;
+        use domain::movie_clip::MovieClip;
+        use domain::search::{SearchHit, SearchItem, SearchQuery};
+        use domain::{ValidationCode, ValidationError};
+
+        use fake::{Fake, Faker};
+        use pretty_assertions::assert_eq;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[tokio::test]
+        async fn test_search() {
+            let query = SearchQuery::new("理子 歌枠").unwrap();
+            let length = 20_usize;
+
+            {
+                // 成功した場合
+                let hits = vec![
+                    SearchHit::new(Faker.fake::<MovieClip>(), 0.8).map(SearchItem::from),
+                    SearchHit::new(Faker.fake::<Episode>(), 0.3).map(SearchItem::from),
+                ];
+
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("GET"))
+                    .and(path("/search"))
+                    .and(query_param("query", "理子 歌枠"))
+                    .and(query_param("length", length.to_string()))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(hits.clone()))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = search_commands::SearchCommand::new(&query, length);
+                    product_inner::search(&mock_server.uri(), cmd).await
+                };
+
+                assert_eq!(res.unwrap(), hits);
+            }
+            {
+                // 失敗した場合
+                let mock_server = MockServer::start().await;
+
+                let validation_error = ValidationError::new(ValidationCode::TooLong, "query");
+                Mock::given(method("GET"))
+                    .and(path("/search"))
+                    .respond_with(
+                        ResponseTemplate::new(422).set_body_json(AppCommonError::ValidationError(
+                            validation_error.clone(),
+                        )),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = search_commands::SearchCommand::new(&query, length);
+                    product_inner::search(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        &res,
+                        Err(AppFrontError::CommonError(AppCommonError::ValidationError(e)))
+                            if *e == validation_error
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,240 @@
+#[cfg(not(feature = "fake"))]
+pub use self::product::*;
+
+#[cfg(feature = "fake")]
+pub use self::fake::*;
+
+/// APIをチェックするためのbehavior
+#[cfg(test)]
+mod _behavior {
+    use crate::commands::user_commands;
+    use crate::AppFrontError;
+    use common::UserInfo;
+
+    #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
+    #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
+    #[async_trait::async_trait]
+    trait Behavior {
+        async fn save_user<'a>(
+            cmd: user_commands::SaveUserCommand<'a>,
+        ) -> Result<(), AppFrontError>;
+        async fn all_users(
+            cmd: user_commands::AllUsersCommand,
+        ) -> Result<Vec<UserInfo>, AppFrontError>;
+        async fn remove_user(cmd: user_commands::RemoveUserCommand) -> Result<(), AppFrontError>;
+    }
+}
+
+#[cfg(not(feature = "fake"))]
+mod product {
+    /// テストのためにuriを引数とした関数にするためのモジュール
+    pub(crate) mod product_inner {
+        use crate::commands::user_commands;
+        use crate::{
+            api_client,
+            utils::{deserialize_response, deserialize_response_null},
+            AppFrontError,
+        };
+        use common::UserInfo;
+
+        /// ユーザーを登録
+        pub async fn save_user<'a>(
+            url: &str,
+            cmd: user_commands::SaveUserCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}{}", url, "/user"))
+                .json(cmd.user_info);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// 全てのユーザーを取得
+        pub async fn all_users(
+            url: &str,
+            _cmd: user_commands::AllUsersCommand,
+        ) -> Result<Vec<UserInfo>, AppFrontError> {
+            let request = api_client().get(&format!("{}{}", url, "/user"));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// ユーザーを削除
+        pub async fn remove_user(
+            url: &str,
+            cmd: user_commands::RemoveUserCommand,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client().delete(&format!("{}{}{}", url, "/user/", cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+    }
+
+    use crate::commands::user_commands;
+    use crate::AppFrontError;
+    use crate::{api_base_url, API_BASE_URL};
+    use common::UserInfo;
+
+    /// ユーザーを登録
+    pub async fn save_user<'a>(
+        cmd: user_commands::SaveUserCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::save_user(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// 全てのユーザーを取得
+    pub async fn all_users(
+        cmd: user_commands::AllUsersCommand,
+    ) -> Result<Vec<UserInfo>, AppFrontError> {
+        product_inner::all_users(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// ユーザーを削除
+    pub async fn remove_user(cmd: user_commands::RemoveUserCommand) -> Result<(), AppFrontError> {
+        product_inner::remove_user(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+}
+
+#[cfg(feature = "fake")]
+mod fake {
+    use crate::commands::user_commands;
+    use crate::AppFrontError;
+    use common::UserInfo;
+    use domain::user::User;
+    use fake::{Fake, Faker};
+
+    /// ユーザーを登録(フェイク)
+    pub async fn save_user<'a>(
+        _cmd: user_commands::SaveUserCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// 全てのユーザーを取得(フェイク)
+    pub async fn all_users(
+        _cmd: user_commands::AllUsersCommand,
+    ) -> Result<Vec<UserInfo>, AppFrontError> {
+        Ok((0..5)
+            .map(|_| Faker.fake::<User>().into())
+            .collect::<Vec<_>>())
+    }
+
+    /// ユーザーを削除(フェイク)
+    pub async fn remove_user(_cmd: user_commands::RemoveUserCommand) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(not(feature = "fake"))]
+    mod product_test {
+        use super::super::product::product_inner;
+        use crate::commands::user_commands;
+        use crate::AppFrontError;
+        use common::{AppCommonError, NewUserInfo, UserInfo};
+        use domain::user::{Role, User, UserId};
+
+        use fake::{Fake, Faker};
+        use pretty_assertions::assert_eq;
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[tokio::test]
+        async fn test_save_user() {
+            let user_info = NewUserInfo::new("contributor", "password", Role::Contributor);
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PUT"))
+                    .and(path("/user"))
+                    .and(body_json(user_info.clone()))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = user_commands::SaveUserCommand::new(&user_info);
+                    product_inner::save_user(&mock_server.uri(), cmd).await
+                };
+
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+            {
+                // 権限が足りない場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PUT"))
+                    .and(path("/user"))
+                    .respond_with(
+                        ResponseTemplate::new(403).set_body_json(AppCommonError::ForbiddenError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = user_commands::SaveUserCommand::new(&user_info);
+                    product_inner::save_user(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::ForbiddenError))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn test_all_users() {
+            let users = (0..5)
+                .map(|_| Faker.fake::<User>().into())
+                .collect::<Vec<UserInfo>>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/user"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(users.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = user_commands::AllUsersCommand;
+                product_inner::all_users(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), users);
+        }
+
+        #[tokio::test]
+        async fn test_remove_user() {
+            let user_id = UserId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("DELETE"))
+                .and(path(format!("/user/{user_id}")))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = user_commands::RemoveUserCommand::new(user_id);
+                product_inner::remove_user(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+    }
+}
@@ -9,7 +9,9 @@ pub use self::fake::*;
 mod _behavior {
     use crate::commands::video_commands;
     use crate::AppFrontError;
-    use domain::video::{Video, VideoType};
+    use common::Page;
+    use domain::video::{Video, VideoId, VideoType};
+    use domain::Tag;
 
     #[cfg_attr(not(feature = "fake"), behavior::behavior(modules(super::product)))]
     // #[cfg_attr(feature = "fake", behavior::behavior(modules(super::fake)))]
@@ -17,40 +19,84 @@ mod _behavior {
     trait Behavior {
         /// Videoを保存
         async fn save_video<'a, T: VideoType>(
-            cmd: video_commands::SaveVideoCommand<'a, T>,
-        ) -> Result<(), AppFrontError>;
+            cmd: video_commands::SaveVideoCommand<'a>,
+        ) -> Result<Video<T>, AppFrontError>;
         /// Videoを編集
         async fn edit_video<'a, T: VideoType>(
             cmd: video_commands::EditVideoCommand<'a, T>,
         ) -> Result<(), AppFrontError>;
+        /// id, likeを保ったままVideoを保存する
+        async fn import_videos<'a, T: VideoType>(
+            cmd: video_commands::ImportVideosCommand<'a, T>,
+        ) -> Result<(), AppFrontError>;
         /// `id`を持つVideoのLikeを一つ増やす
         async fn increment_like_video<T: VideoType>(
             cmd: video_commands::IncrementLikeVideoCommand,
         ) -> Result<(), AppFrontError>;
+        /// `id`を持つVideoのこのクライアントのLikeを取り消す
+        async fn unlike_video<T: VideoType>(
+            cmd: video_commands::UnlikeVideoCommand,
+        ) -> Result<(), AppFrontError>;
+        /// `ids`のうちこのクライアントがLikeしているVideoのidを取得
+        async fn liked_video_ids<'a, T: VideoType>(
+            cmd: video_commands::LikedVideoIdsCommand<'_>,
+        ) -> Result<Vec<VideoId>, AppFrontError>;
         /// 全てのVideoを取得する
         async fn all_videos<T: VideoType>(
             cmd: video_commands::AllVideosCommand,
         ) -> Result<Vec<Video<T>>, AppFrontError>;
         /// Likeを降順・idを昇順に並べたVideoを`length`分取得
-        async fn order_by_like_videos<T: VideoType>(
-            cmd: video_commands::OrderByLikeVideosCommand,
-        ) -> Result<Vec<Video<T>>, AppFrontError>;
-        /// Likeを降順・idを昇順に並べた`reference`以降のVideoを`length`分取得
+        async fn order_by_like_videos<'a, T: VideoType>(
+            cmd: video_commands::OrderByLikeVideosCommand<'_>,
+        ) -> Result<Page<Video<T>>, AppFrontError>;
+        /// Likeを降順・idを昇順に並べたカーソル以降のVideoを`length`分取得
         async fn order_by_like_later_videos<'a, T: VideoType>(
-            cmd: video_commands::OrderByLikeLaterVideosCommand<'_, T>,
-        ) -> Result<Vec<Video<T>>, AppFrontError>;
+            cmd: video_commands::OrderByLikeLaterVideosCommand<'_>,
+        ) -> Result<Page<Video<T>>, AppFrontError>;
         /// dateを降順・idを昇順に並べたVideoを`length`分取得
-        async fn order_by_date_videos<T: VideoType>(
-            cmd: video_commands::OrderByDateVideosCommand,
-        ) -> Result<Vec<Video<T>>, AppFrontError>;
-        /// dateを降順・idをしょうじゅんに並べた`reference`以降のVideoを`length`分取得
+        async fn order_by_date_videos<'a, T: VideoType>(
+            cmd: video_commands::OrderByDateVideosCommand<'_>,
+        ) -> Result<Page<Video<T>>, AppFrontError>;
+        /// dateを降順・idをしょうじゅんに並べたカーソル以降のVideoを`length`分取得
         async fn order_by_date_later_videos<'a, T: VideoType>(
-            cmd: video_commands::OrderByDateLaterVideosCommand<'_, T>,
+            cmd: video_commands::OrderByDateLaterVideosCommand<'_>,
+        ) -> Result<Page<Video<T>>, AppFrontError>;
+        /// `channel`に紐付いたVideoをdateの降順・idの昇順に全て取得
+        async fn channel_videos<T: VideoType>(
+            cmd: video_commands::ChannelVideosCommand,
         ) -> Result<Vec<Video<T>>, AppFrontError>;
+        /// Videoに付けられている全てのタグを取得
+        async fn all_video_tags<T: VideoType>(
+            cmd: video_commands::AllVideoTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError>;
+        /// `id`を持つVideoのタグを取得
+        async fn video_tags<T: VideoType>(
+            cmd: video_commands::VideoTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError>;
+        /// `id`を持つVideoにタグを付ける
+        async fn add_video_tag<'a, T: VideoType>(
+            cmd: video_commands::AddVideoTagCommand<'_>,
+        ) -> Result<(), AppFrontError>;
+        /// `id`を持つVideoからタグを外す
+        async fn remove_video_tag<'a, T: VideoType>(
+            cmd: video_commands::RemoveVideoTagCommand<'_>,
+        ) -> Result<(), AppFrontError>;
         /// `id`を持つVideoを削除
         async fn remove_video<T: VideoType>(
             cmd: video_commands::RemoveVideoCommand,
         ) -> Result<(), AppFrontError>;
+        /// ゴミ箱のVideoを削除した日時の新しい順に取得
+        async fn trashed_videos<T: VideoType>(
+            cmd: video_commands::TrashedVideosCommand,
+        ) -> Result<Vec<Video<T>>, AppFrontError>;
+        /// ゴミ箱の`id`を持つVideoを戻す
+        async fn restore_trashed_video<T: VideoType>(
+            cmd: video_commands::RestoreTrashedVideoCommand,
+        ) -> Result<(), AppFrontError>;
+        /// ゴミ箱の`id`を持つVideoを完全に削除
+        async fn purge_video<T: VideoType>(
+            cmd: video_commands::PurgeVideoCommand,
+        ) -> Result<(), AppFrontError>;
     }
 }
 
@@ -60,27 +106,26 @@ mod product {
         /// テストするためにurlを引数とする関数を定義するモジュール
         use crate::commands::video_commands;
         use crate::{
-            utils::{deserialize_response, deserialize_response_null},
+            api_client,
+            utils::{deserialize_response, deserialize_response_null, send_as_client, tags_query},
             AppFrontError,
         };
-        use common::QueryInfoRef;
-        use domain::video::{Video, VideoType};
+        use common::Page;
+        use domain::video::{Video, VideoId, VideoType};
+        use domain::Tag;
 
-        use reqwest::Client;
-        use std::borrow::Cow::Borrowed;
-
-        /// Videoを保存
+        /// Videoを保存し，サーバーがid, likeを決定したVideoを返す．
         pub async fn save_video<'a, T: VideoType>(
             url: &str,
-            cmd: video_commands::SaveVideoCommand<'_, T>,
-        ) -> Result<(), AppFrontError> {
-            let request = Client::new()
+            cmd: video_commands::SaveVideoCommand<'_>,
+        ) -> Result<Video<T>, AppFrontError> {
+            let request = api_client()
                 .put(&format!("{}/{}", url, T::snake_case()))
-                .json(&cmd.video);
+                .json(&cmd.video_info);
 
             let response = request.send().await?;
 
-            deserialize_response_null(response).await
+            deserialize_response(response).await
         }
 
         /// Videoを編集
@@ -88,7 +133,7 @@ mod product {
             url: &str,
             cmd: video_commands::EditVideoCommand<'_, T>,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new()
+            let request = api_client()
                 .patch(&format!("{}/{}", url, T::snake_case()))
                 .json(&cmd.video);
 
@@ -97,29 +142,70 @@ mod product {
             deserialize_response_null(response).await
         }
 
+        /// id, likeを保ったままVideoを保存する．管理者のみ
+        pub async fn import_videos<'a, T: VideoType>(
+            url: &str,
+            cmd: video_commands::ImportVideosCommand<'_, T>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}/{}/import", url, T::snake_case()))
+                .json(&cmd.videos);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
         /// `id`を持つVideoのLikeを一つ増やす
         pub async fn increment_like_video<T: VideoType>(
             url: &str,
             cmd: video_commands::IncrementLikeVideoCommand,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new().patch(&format!(
+            let request = api_client().patch(&format!(
                 "{}/{}/increment_like/{}",
                 url,
                 T::snake_case(),
                 cmd.id
             ));
 
-            let response = request.send().await?;
+            let response = send_as_client(url, request).await?;
 
             deserialize_response_null(response).await
         }
 
+        /// `id`を持つVideoのこのクライアントのLikeを取り消す
+        pub async fn unlike_video<T: VideoType>(
+            url: &str,
+            cmd: video_commands::UnlikeVideoCommand,
+        ) -> Result<(), AppFrontError> {
+            let request =
+                api_client().patch(&format!("{}/{}/unlike/{}", url, T::snake_case(), cmd.id));
+
+            let response = send_as_client(url, request).await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// `ids`のうちこのクライアントがLikeしているVideoのidを取得
+        pub async fn liked_video_ids<'a, T: VideoType>(
+            url: &str,
+            cmd: video_commands::LikedVideoIdsCommand<'_>,
+        ) -> Result<Vec<VideoId>, AppFrontError> {
+            let request = api_client()
+                .post(&format!("{}/{}/liked", url, T::snake_case()))
+                .json(&cmd.ids);
+
+            let response = send_as_client(url, request).await?;
+
+            deserialize_response(response).await
+        }
+
         /// 全てのVideoを取得する
         pub async fn all_videos<T: VideoType>(
             url: &str,
             _cmd: video_commands::AllVideosCommand,
         ) -> Result<Vec<Video<T>>, AppFrontError> {
-            let request = Client::new().get(&format!("{}/{}", url, T::snake_case()));
+            let request = api_client().get(&format!("{}/{}", url, T::snake_case()));
 
             let response = request.send().await?;
 
@@ -127,41 +213,42 @@ mod product {
         }
 
         /// Likeを降順・idを昇順に並べたVideoを`length`分取得
-        pub async fn order_by_like_videos<T: VideoType>(
+        pub async fn order_by_like_videos<'a, T: VideoType>(
             url: &str,
-            cmd: video_commands::OrderByLikeVideosCommand,
-        ) -> Result<Vec<Video<T>>, AppFrontError> {
+            cmd: video_commands::OrderByLikeVideosCommand<'_>,
+        ) -> Result<Page<Video<T>>, AppFrontError> {
             let query_string = format!("?sort_type=like&length={}", cmd.length);
-            let request = Client::new().get(&format!(
-                "{}/{}/query{}",
-                url,
-                T::snake_case(),
-                query_string
-            ));
+            let request = api_client()
+                .get(&format!(
+                    "{}/{}/query{}",
+                    url,
+                    T::snake_case(),
+                    query_string
+                ))
+                .query(&tags_query(cmd.tags));
 
             let response = request.send().await?;
 
             deserialize_response(response).await
         }
 
-        /// Likeを降順・idを昇順に並べた`reference`以降のVideoを`length`分取得
+        /// Likeを降順・idを昇順に並べたカーソル以降のVideoを`length`分取得
         pub async fn order_by_like_later_videos<'a, T: VideoType>(
             url: &str,
-            cmd: video_commands::OrderByLikeLaterVideosCommand<'_, T>,
-        ) -> Result<Vec<Video<T>>, AppFrontError> {
-            let query_string = format!("?sort_type=like&length={}", cmd.length);
-            let query_info = QueryInfoRef::builder()
-                .reference(Borrowed(cmd.reference))
-                .build();
-
-            let request = Client::new()
-                .post(&format!(
+            cmd: video_commands::OrderByLikeLaterVideosCommand<'_>,
+        ) -> Result<Page<Video<T>>, AppFrontError> {
+            let query_string = format!(
+                "?sort_type=like&length={}&cursor={}",
+                cmd.length, cmd.cursor
+            );
+            let request = api_client()
+                .get(&format!(
                     "{}/{}/query{}",
                     url,
                     T::snake_case(),
                     query_string
                 ))
-                .json(&query_info);
+                .query(&tags_query(cmd.tags));
 
             let response = request.send().await?;
 
@@ -169,54 +256,162 @@ mod product {
         }
 
         /// dateを降順・idを昇順に並べたVideoを`length`分取得
-        pub async fn order_by_date_videos<T: VideoType>(
+        pub async fn order_by_date_videos<'a, T: VideoType>(
             url: &str,
-            cmd: video_commands::OrderByDateVideosCommand,
-        ) -> Result<Vec<Video<T>>, AppFrontError> {
+            cmd: video_commands::OrderByDateVideosCommand<'_>,
+        ) -> Result<Page<Video<T>>, AppFrontError> {
             let query_string = format!("?sort_type=date&length={}", cmd.length);
 
-            let request = Client::new().get(&format!(
-                "{}/{}/query{}",
-                url,
-                T::snake_case(),
-                query_string
-            ));
+            let request = api_client()
+                .get(&format!(
+                    "{}/{}/query{}",
+                    url,
+                    T::snake_case(),
+                    query_string
+                ))
+                .query(&tags_query(cmd.tags));
 
             let response = request.send().await?;
 
             deserialize_response(response).await
         }
 
-        /// dateを降順・idをしょうじゅんに並べた`reference`以降のVideoを`length`分取得
+        /// dateを降順・idをしょうじゅんに並べたカーソル以降のVideoを`length`分取得
         pub async fn order_by_date_later_videos<'a, T: VideoType>(
             url: &str,
-            cmd: video_commands::OrderByDateLaterVideosCommand<'_, T>,
-        ) -> Result<Vec<Video<T>>, AppFrontError> {
-            let query_string = format!("?sort_type=date&length={}", cmd.length);
-            let query_info = QueryInfoRef::builder()
-                .reference(Borrowed(cmd.reference))
-                .build();
-
-            let request = Client::new()
-                .post(&format!(
+            cmd: video_commands::OrderByDateLaterVideosCommand<'_>,
+        ) -> Result<Page<Video<T>>, AppFrontError> {
+            let query_string = format!(
+                "?sort_type=date&length={}&cursor={}",
+                cmd.length, cmd.cursor
+            );
+            let request = api_client()
+                .get(&format!(
                     "{}/{}/query{}",
                     url,
                     T::snake_case(),
                     query_string
                 ))
-                .json(&query_info);
+                .query(&tags_query(cmd.tags));
 
             let response = request.send().await?;
 
             deserialize_response(response).await
         }
 
+        /// `channel`に紐付いたVideoをdateの降順・idの昇順に全て取得
+        pub async fn channel_videos<T: VideoType>(
+            url: &str,
+            cmd: video_commands::ChannelVideosCommand,
+        ) -> Result<Vec<Video<T>>, AppFrontError> {
+            let request = api_client().get(&format!(
+                "{}/{}/channel/{}",
+                url,
+                T::snake_case(),
+                cmd.channel
+            ));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// Videoに付けられている全てのタグを取得
+        pub async fn all_video_tags<T: VideoType>(
+            url: &str,
+            _cmd: video_commands::AllVideoTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError> {
+            let request = api_client().get(&format!("{}/{}/tags", url, T::snake_case()));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// `id`を持つVideoのタグを取得
+        pub async fn video_tags<T: VideoType>(
+            url: &str,
+            cmd: video_commands::VideoTagsCommand,
+        ) -> Result<Vec<Tag>, AppFrontError> {
+            let request = api_client().get(&format!("{}/{}/tags/{}", url, T::snake_case(), cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// `id`を持つVideoにタグを付ける
+        pub async fn add_video_tag<'a, T: VideoType>(
+            url: &str,
+            cmd: video_commands::AddVideoTagCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .put(&format!("{}/{}/tags/{}", url, T::snake_case(), cmd.id))
+                .json(cmd.tag);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// `id`を持つVideoからタグを外す
+        pub async fn remove_video_tag<'a, T: VideoType>(
+            url: &str,
+            cmd: video_commands::RemoveVideoTagCommand<'_>,
+        ) -> Result<(), AppFrontError> {
+            let request = api_client()
+                .delete(&format!("{}/{}/tags/{}", url, T::snake_case(), cmd.id))
+                .json(cmd.tag);
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
         /// `id`を持つVideoを削除
         pub async fn remove_video<T: VideoType>(
             url: &str,
             cmd: video_commands::RemoveVideoCommand,
         ) -> Result<(), AppFrontError> {
-            let request = Client::new().delete(&format!("{}/{}/{}", url, T::snake_case(), cmd.id));
+            let request = api_client().delete(&format!("{}/{}/{}", url, T::snake_case(), cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// ゴミ箱のVideoを削除した日時の新しい順に取得
+        pub async fn trashed_videos<T: VideoType>(
+            url: &str,
+            _cmd: video_commands::TrashedVideosCommand,
+        ) -> Result<Vec<Video<T>>, AppFrontError> {
+            let request = api_client().get(&format!("{}/{}/trash", url, T::snake_case()));
+
+            let response = request.send().await?;
+
+            deserialize_response(response).await
+        }
+
+        /// ゴミ箱の`id`を持つVideoを戻す
+        pub async fn restore_trashed_video<T: VideoType>(
+            url: &str,
+            cmd: video_commands::RestoreTrashedVideoCommand,
+        ) -> Result<(), AppFrontError> {
+            let request =
+                api_client().patch(&format!("{}/{}/trash/{}", url, T::snake_case(), cmd.id));
+
+            let response = request.send().await?;
+
+            deserialize_response_null(response).await
+        }
+
+        /// ゴミ箱の`id`を持つVideoを完全に削除
+        pub async fn purge_video<T: VideoType>(
+            url: &str,
+            cmd: video_commands::PurgeVideoCommand,
+        ) -> Result<(), AppFrontError> {
+            let request =
+                api_client().delete(&format!("{}/{}/trash/{}", url, T::snake_case(), cmd.id));
 
             let response = request.send().await?;
 
@@ -227,13 +422,15 @@ mod product {
     use crate::commands::video_commands;
     use crate::AppFrontError;
     use crate::{api_base_url, API_BASE_URL};
-    use domain::video::{Video, VideoType};
+    use common::Page;
+    use domain::video::{Video, VideoId, VideoType};
+    use domain::Tag;
 
-    /// Videoを保存
+    /// Videoを保存し，サーバーがid, likeを決定したVideoを返す．
     pub async fn save_video<'a, T: VideoType>(
-        cmd: video_commands::SaveVideoCommand<'_, T>,
-    ) -> Result<(), AppFrontError> {
-        product_inner::save_video(API_BASE_URL.get_or_init(api_base_url), cmd).await
+        cmd: video_commands::SaveVideoCommand<'_>,
+    ) -> Result<Video<T>, AppFrontError> {
+        product_inner::save_video::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
     /// Videoを編集
@@ -243,6 +440,13 @@ mod product {
         product_inner::edit_video(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
+    /// id, likeを保ったままVideoを保存する．管理者のみ
+    pub async fn import_videos<'a, T: VideoType>(
+        cmd: video_commands::ImportVideosCommand<'_, T>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::import_videos(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
     /// `id`を持つVideoのLikeを一つ増やす
     pub async fn increment_like_video<T: VideoType>(
         cmd: video_commands::IncrementLikeVideoCommand,
@@ -250,6 +454,20 @@ mod product {
         product_inner::increment_like_video::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
+    /// `id`を持つVideoのこのクライアントのLikeを取り消す
+    pub async fn unlike_video<T: VideoType>(
+        cmd: video_commands::UnlikeVideoCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::unlike_video::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `ids`のうちこのクライアントがLikeしているVideoのidを取得
+    pub async fn liked_video_ids<'a, T: VideoType>(
+        cmd: video_commands::LikedVideoIdsCommand<'_>,
+    ) -> Result<Vec<VideoId>, AppFrontError> {
+        product_inner::liked_video_ids::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
     /// 全てのVideoを取得する
     pub async fn all_videos<T: VideoType>(
         cmd: video_commands::AllVideosCommand,
@@ -258,31 +476,68 @@ mod product {
     }
 
     /// Likeを降順・idを昇順に並べたVideoを`length`分取得
-    pub async fn order_by_like_videos<T: VideoType>(
-        cmd: video_commands::OrderByLikeVideosCommand,
-    ) -> Result<Vec<Video<T>>, AppFrontError> {
+    pub async fn order_by_like_videos<'a, T: VideoType>(
+        cmd: video_commands::OrderByLikeVideosCommand<'_>,
+    ) -> Result<Page<Video<T>>, AppFrontError> {
         product_inner::order_by_like_videos(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
-    /// Likeを降順・idを昇順に並べた`reference`以降のVideoを`length`分取得
+    /// Likeを降順・idを昇順に並べたカーソル以降のVideoを`length`分取得
     pub async fn order_by_like_later_videos<'a, T: VideoType>(
-        cmd: video_commands::OrderByLikeLaterVideosCommand<'_, T>,
-    ) -> Result<Vec<Video<T>>, AppFrontError> {
-        product_inner::order_by_like_later_videos(API_BASE_URL.get_or_init(api_base_url), cmd).await
+        cmd: video_commands::OrderByLikeLaterVideosCommand<'_>,
+    ) -> Result<Page<Video<T>>, AppFrontError> {
+        product_inner::order_by_like_later_videos::<T>(API_BASE_URL.get_or_init(api_base_url), cmd)
+            .await
     }
 
     /// dateを降順・idを昇順に並べたVideoを`length`分取得
-    pub async fn order_by_date_videos<T: VideoType>(
-        cmd: video_commands::OrderByDateVideosCommand,
-    ) -> Result<Vec<Video<T>>, AppFrontError> {
+    pub async fn order_by_date_videos<'a, T: VideoType>(
+        cmd: video_commands::OrderByDateVideosCommand<'_>,
+    ) -> Result<Page<Video<T>>, AppFrontError> {
         product_inner::order_by_date_videos(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
-    /// dateを降順・idをしょうじゅんに並べた`reference`以降のVideoを`length`分取得
+    /// dateを降順・idをしょうじゅんに並べたカーソル以降のVideoを`length`分取得
     pub async fn order_by_date_later_videos<'a, T: VideoType>(
-        cmd: video_commands::OrderByDateLaterVideosCommand<'_, T>,
+        cmd: video_commands::OrderByDateLaterVideosCommand<'_>,
+    ) -> Result<Page<Video<T>>, AppFrontError> {
+        product_inner::order_by_date_later_videos::<T>(API_BASE_URL.get_or_init(api_base_url), cmd)
+            .await
+    }
+
+    /// `channel`に紐付いたVideoをdateの降順・idの昇順に全て取得
+    pub async fn channel_videos<T: VideoType>(
+        cmd: video_commands::ChannelVideosCommand,
     ) -> Result<Vec<Video<T>>, AppFrontError> {
-        product_inner::order_by_date_later_videos(API_BASE_URL.get_or_init(api_base_url), cmd).await
+        product_inner::channel_videos::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// Videoに付けられている全てのタグを取得
+    pub async fn all_video_tags<T: VideoType>(
+        cmd: video_commands::AllVideoTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        product_inner::all_video_tags::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つVideoのタグを取得
+    pub async fn video_tags<T: VideoType>(
+        cmd: video_commands::VideoTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        product_inner::video_tags::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つVideoにタグを付ける
+    pub async fn add_video_tag<'a, T: VideoType>(
+        cmd: video_commands::AddVideoTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::add_video_tag::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// `id`を持つVideoからタグを外す
+    pub async fn remove_video_tag<'a, T: VideoType>(
+        cmd: video_commands::RemoveVideoTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        product_inner::remove_video_tag::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
 
     /// `id`を持つVideoを削除
@@ -291,21 +546,60 @@ mod product {
     ) -> Result<(), AppFrontError> {
         product_inner::remove_video::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
     }
+
+    /// ゴミ箱のVideoを削除した日時の新しい順に取得
+    pub async fn trashed_videos<T: VideoType>(
+        cmd: video_commands::TrashedVideosCommand,
+    ) -> Result<Vec<Video<T>>, AppFrontError> {
+        product_inner::trashed_videos::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// ゴミ箱の`id`を持つVideoを戻す
+    pub async fn restore_trashed_video<T: VideoType>(
+        cmd: video_commands::RestoreTrashedVideoCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::restore_trashed_video::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
+
+    /// ゴミ箱の`id`を持つVideoを完全に削除
+    pub async fn purge_video<T: VideoType>(
+        cmd: video_commands::PurgeVideoCommand,
+    ) -> Result<(), AppFrontError> {
+        product_inner::purge_video::<T>(API_BASE_URL.get_or_init(api_base_url), cmd).await
+    }
 }
 
 #[cfg(feature = "fake")]
 mod fake {
     use crate::commands::video_commands;
     use crate::AppFrontError;
-    use domain::video::{Video, VideoType};
+    use common::Page;
+    use domain::channel::Channel;
+    use domain::video::{Video, VideoId, VideoType};
+    use domain::Tag;
 
     use fake::{Fake, Faker};
 
+    /// `length`分のVideoのフェイクのページ
+    fn fake_page<T: VideoType>(length: usize) -> Page<Video<T>> {
+        let items = (0..length)
+            .map(|_| Faker.fake::<Video<T>>())
+            .collect::<Vec<_>>();
+        Page::new(items, true, Some("fake_cursor".to_string()))
+    }
+
     /// Videoを保存(フェイク)
     pub async fn save_video<'a, T: VideoType>(
-        _cmd: video_commands::SaveVideoCommand<'_, T>,
-    ) -> Result<(), AppFrontError> {
-        Ok(())
+        cmd: video_commands::SaveVideoCommand<'_>,
+    ) -> Result<Video<T>, AppFrontError> {
+        let video_info = cmd.video_info.clone();
+        Ok(Video::new_with_domains(
+            video_info.title,
+            video_info.url,
+            video_info.date,
+            &video_info.channel_id.fake::<Channel>(),
+        )
+        .with_duration(video_info.duration))
     }
 
     /// Videoを編集(フェイク)
@@ -315,6 +609,13 @@ mod fake {
         Ok(())
     }
 
+    /// id, likeを保ったままVideoを保存する(フェイク)
+    pub async fn import_videos<'a, T: VideoType>(
+        _cmd: video_commands::ImportVideosCommand<'_, T>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
     /// `id`を持つVideoのLikeを一つ増やす(フェイク)
     pub async fn increment_like_video<T: VideoType>(
         _cmd: video_commands::IncrementLikeVideoCommand,
@@ -322,6 +623,20 @@ mod fake {
         Ok(())
     }
 
+    /// `id`を持つVideoのこのクライアントのLikeを取り消す(フェイク)
+    pub async fn unlike_video<T: VideoType>(
+        _cmd: video_commands::UnlikeVideoCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// `ids`のうちこのクライアントがLikeしているVideoのidを取得(フェイク)
+    pub async fn liked_video_ids<'a, T: VideoType>(
+        _cmd: video_commands::LikedVideoIdsCommand<'_>,
+    ) -> Result<Vec<VideoId>, AppFrontError> {
+        Ok(Vec::new())
+    }
+
     /// 全てのVideoを取得する(フェイク)
     pub async fn all_videos<T: VideoType>(
         _cmd: video_commands::AllVideosCommand,
@@ -332,47 +647,102 @@ mod fake {
     }
 
     /// Likeを降順・idを昇順に並べたVideoを`length`分取得(フェイク)
-    pub async fn order_by_like_videos<T: VideoType>(
-        cmd: video_commands::OrderByLikeVideosCommand,
-    ) -> Result<Vec<Video<T>>, AppFrontError> {
-        Ok((0..cmd.length)
-            .map(|_| Faker.fake::<Video<T>>())
-            .collect::<Vec<_>>())
+    pub async fn order_by_like_videos<'a, T: VideoType>(
+        cmd: video_commands::OrderByLikeVideosCommand<'_>,
+    ) -> Result<Page<Video<T>>, AppFrontError> {
+        Ok(fake_page(cmd.length))
     }
 
-    /// Likeを降順・idを昇順に並べた`reference`以降のVideoを`length`分取得(フェイク)
+    /// Likeを降順・idを昇順に並べたカーソル以降のVideoを`length`分取得(フェイク)
     pub async fn order_by_like_later_videos<'a, T: VideoType>(
-        cmd: video_commands::OrderByLikeLaterVideosCommand<'_, T>,
-    ) -> Result<Vec<Video<T>>, AppFrontError> {
-        Ok((0..cmd.length)
-            .map(|_| Faker.fake::<Video<T>>())
-            .collect::<Vec<_>>())
+        cmd: video_commands::OrderByLikeLaterVideosCommand<'_>,
+    ) -> Result<Page<Video<T>>, AppFrontError> {
+        Ok(fake_page(cmd.length))
     }
 
     /// dateを降順・idを昇順に並べたVideoを`length`分取得(フェイク)
-    pub async fn order_by_date_videos<T: VideoType>(
-        cmd: video_commands::OrderByDateVideosCommand,
-    ) -> Result<Vec<Video<T>>, AppFrontError> {
-        Ok((0..cmd.length)
-            .map(|_| Faker.fake::<Video<T>>())
-            .collect::<Vec<_>>())
+    pub async fn order_by_date_videos<'a, T: VideoType>(
+        cmd: video_commands::OrderByDateVideosCommand<'_>,
+    ) -> Result<Page<Video<T>>, AppFrontError> {
+        Ok(fake_page(cmd.length))
     }
 
-    /// dateを降順・idをしょうじゅんに並べた`reference`以降のVideoを`length`分取得(フェイク)
+    /// dateを降順・idをしょうじゅんに並べたカーソル以降のVideoを`length`分取得(フェイク)
     pub async fn order_by_date_later_videos<'a, T: VideoType>(
-        cmd: video_commands::OrderByDateLaterVideosCommand<'_, T>,
+        cmd: video_commands::OrderByDateLaterVideosCommand<'_>,
+    ) -> Result<Page<Video<T>>, AppFrontError> {
+        Ok(fake_page(cmd.length))
+    }
+
+    /// `channel`に紐付いたVideoをdateの降順・idの昇順に全て取得(フェイク)
+    pub async fn channel_videos<T: VideoType>(
+        cmd: video_commands::ChannelVideosCommand,
     ) -> Result<Vec<Video<T>>, AppFrontError> {
-        Ok((0..cmd.length)
-            .map(|_| Faker.fake::<Video<T>>())
+        let channel = cmd.channel.fake::<Channel>();
+        Ok((0..10)
+            .map(|_| {
+                let mut video = Faker.fake::<Video<T>>();
+                video.set_channel(&channel);
+                video
+            })
             .collect::<Vec<_>>())
     }
 
+    /// Videoに付けられている全てのタグを取得(フェイク)
+    pub async fn all_video_tags<T: VideoType>(
+        _cmd: video_commands::AllVideoTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        Ok((0..10).map(|_| Faker.fake::<Tag>()).collect::<Vec<_>>())
+    }
+
+    /// `id`を持つVideoのタグを取得(フェイク)
+    pub async fn video_tags<T: VideoType>(
+        _cmd: video_commands::VideoTagsCommand,
+    ) -> Result<Vec<Tag>, AppFrontError> {
+        Ok((0..3).map(|_| Faker.fake::<Tag>()).collect::<Vec<_>>())
+    }
+
+    /// `id`を持つVideoにタグを付ける(フェイク)
+    pub async fn add_video_tag<'a, T: VideoType>(
+        _cmd: video_commands::AddVideoTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// `id`を持つVideoからタグを外す(フェイク)
+    pub async fn remove_video_tag<'a, T: VideoType>(
+        _cmd: video_commands::RemoveVideoTagCommand<'_>,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
     /// `id`を持つVideoを削除(フェイク)
     pub async fn remove_video<T: VideoType>(
         _cmd: video_commands::RemoveVideoCommand,
     ) -> Result<(), AppFrontError> {
         Ok(())
     }
+
+    /// ゴミ箱のVideoを削除した日時の新しい順に取得(フェイク)
+    pub async fn trashed_videos<T: VideoType>(
+        _cmd: video_commands::TrashedVideosCommand,
+    ) -> Result<Vec<Video<T>>, AppFrontError> {
+        Ok((0..5).map(|_| Faker.fake::<Video<T>>()).collect::<Vec<_>>())
+    }
+
+    /// ゴミ箱の`id`を持つVideoを戻す(フェイク)
+    pub async fn restore_trashed_video<T: VideoType>(
+        _cmd: video_commands::RestoreTrashedVideoCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
+
+    /// ゴミ箱の`id`を持つVideoを完全に削除(フェイク)
+    pub async fn purge_video<T: VideoType>(
+        _cmd: video_commands::PurgeVideoCommand,
+    ) -> Result<(), AppFrontError> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -382,46 +752,46 @@ mod test {
         use super::super::product::product_inner;
         use crate::commands::video_commands;
         use crate::AppFrontError;
-        use common::{AppCommonError, QueryInfoRef};
-        use domain::video::{Original, Video, VideoId};
+        use common::{AppCommonError, NewVideoInfo, Page};
+        use domain::channel::Channel;
+        use domain::video::{Kirinuki, Original, Video, VideoId};
+        use domain::Tag;
 
         use fake::{Fake, Faker};
         use pretty_assertions::assert_eq;
-        use std::borrow::Cow::Borrowed;
         use wiremock::matchers::{body_json, method, path, query_param};
         use wiremock::{Mock, MockServer, ResponseTemplate};
 
         #[tokio::test]
         async fn test_save_video() {
             let video = Faker.fake::<Video<Original>>();
+            let video_info = NewVideoInfo::from(video.clone());
 
             {
                 // 成功した場合
-                let video = video.clone();
                 let mock_server = MockServer::start().await;
 
                 Mock::given(method("PUT"))
                     .and(path("/original"))
-                    .and(body_json(video.clone()))
-                    .respond_with(ResponseTemplate::new(200))
+                    .and(body_json(video_info.clone()))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(video.clone()))
                     .mount(&mock_server)
                     .await;
 
                 let res = {
-                    let cmd = video_commands::SaveVideoCommand::new(&video);
-                    product_inner::save_video(&mock_server.uri(), cmd).await
+                    let cmd = video_commands::SaveVideoCommand::new(&video_info);
+                    product_inner::save_video::<Original>(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert_eq!(res.unwrap(), video);
             }
             {
                 // 失敗した場合
-                let video = video.clone();
                 let mock_server = MockServer::start().await;
 
                 Mock::given(method("PUT"))
                     .and(path("/original"))
-                    .and(body_json(video.clone()))
+                    .and(body_json(video_info.clone()))
                     .respond_with(
                         ResponseTemplate::new(500).set_body_json(AppCommonError::ConflictError),
                     )
@@ -429,8 +799,8 @@ mod test {
                     .await;
 
                 let res = {
-                    let cmd = video_commands::SaveVideoCommand::new(&video);
-                    product_inner::save_video(&mock_server.uri(), cmd).await
+                    let cmd = video_commands::SaveVideoCommand::new(&video_info);
+                    product_inner::save_video::<Original>(&mock_server.uri(), cmd).await
                 };
 
                 assert!(
@@ -462,7 +832,7 @@ mod test {
                     product_inner::edit_video(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
             {
                 // 失敗した場合
@@ -491,6 +861,59 @@ mod test {
             }
         }
 
+        #[tokio::test]
+        async fn test_import_videos() {
+            let videos = (0..3)
+                .map(|_| Faker.fake::<Video<Original>>())
+                .collect::<Vec<_>>();
+
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PUT"))
+                    .and(path("/original/import"))
+                    .and(body_json(videos.clone()))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = video_commands::ImportVideosCommand::new(&videos);
+                    product_inner::import_videos(&mock_server.uri(), cmd).await
+                };
+
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+            {
+                // 失敗した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("PUT"))
+                    .and(path("/original/import"))
+                    .and(body_json(videos.clone()))
+                    .respond_with(
+                        ResponseTemplate::new(409).set_body_json(AppCommonError::ConflictError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = video_commands::ImportVideosCommand::new(&videos);
+                    product_inner::import_videos(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::ConflictError))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
+
         #[tokio::test]
         async fn test_increment_like_video() {
             let id = VideoId::generate();
@@ -510,7 +933,7 @@ mod test {
                     product_inner::increment_like_video::<Original>(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
             {
                 // 失敗した場合
@@ -537,6 +960,48 @@ mod test {
             }
         }
 
+        #[tokio::test]
+        async fn test_unlike_video() {
+            let id = VideoId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PATCH"))
+                .and(path(format!("/original/unlike/{}", id)))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = video_commands::UnlikeVideoCommand::new(id);
+                product_inner::unlike_video::<Original>(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_liked_video_ids() {
+            let ids = (0..10).map(|_| VideoId::generate()).collect::<Vec<_>>();
+            let liked_ids = ids[..5].to_vec();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("POST"))
+                .and(path("/original/liked"))
+                .and(body_json(ids.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(liked_ids.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = video_commands::LikedVideoIdsCommand::new(&ids);
+                product_inner::liked_video_ids::<Original>(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), liked_ids);
+        }
+
         #[tokio::test]
         async fn test_all_videos() {
             let videos = (0..100)
@@ -559,6 +1024,34 @@ mod test {
             assert_eq!(res.unwrap(), videos);
         }
 
+        #[tokio::test]
+        async fn test_channel_videos() {
+            let channel = Faker.fake::<Channel>();
+            let channel_id = channel.id();
+            let videos = (0..10)
+                .map(|_| {
+                    let mut video = Faker.fake::<Video<Kirinuki>>();
+                    video.set_channel(&channel);
+                    video
+                })
+                .collect::<Vec<_>>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path(format!("/kirinuki/channel/{}", channel_id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(videos.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = video_commands::ChannelVideosCommand::new(channel_id);
+                product_inner::channel_videos::<Kirinuki>(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), videos);
+        }
+
         #[tokio::test]
         async fn test_order_by_like_videos() {
             let videos = (0..100)
@@ -566,6 +1059,7 @@ mod test {
                 .collect::<Vec<_>>();
 
             let length = 10_usize;
+            let page = Page::new(videos, true, Some("cursor".to_string()));
 
             let mock_server = MockServer::start().await;
 
@@ -573,16 +1067,45 @@ mod test {
                 .and(path("/original/query"))
                 .and(query_param("sort_type", "like"))
                 .and(query_param("length", length.to_string()))
-                .respond_with(ResponseTemplate::new(200).set_body_json(videos.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
                 .mount(&mock_server)
                 .await;
 
             let res = {
-                let cmd = video_commands::OrderByLikeVideosCommand::new(length);
+                let cmd = video_commands::OrderByLikeVideosCommand::new(&[], length);
                 product_inner::order_by_like_videos::<Original>(&mock_server.uri(), cmd).await
             };
 
-            assert_eq!(res.unwrap(), videos);
+            assert_eq!(res.unwrap(), page);
+        }
+
+        #[tokio::test]
+        async fn test_order_by_date_videos_with_tags() {
+            let videos = (0..10)
+                .map(|_| Faker.fake::<Video<Original>>())
+                .collect::<Vec<_>>();
+
+            let length = 10_usize;
+            let tags = vec![Tag::new("a&b").unwrap(), Tag::new("singing").unwrap()];
+            let page = Page::new(videos, false, None);
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/original/query"))
+                .and(query_param("sort_type", "date"))
+                .and(query_param("length", length.to_string()))
+                .and(query_param("tags", "a&b,singing"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = video_commands::OrderByDateVideosCommand::new(&tags, length);
+                product_inner::order_by_date_videos::<Original>(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), page);
         }
 
         #[tokio::test]
@@ -592,29 +1115,26 @@ mod test {
                 .collect::<Vec<_>>();
 
             let length = 10_usize;
-            let reference = Faker.fake::<Video<Original>>();
-
-            let query_info = QueryInfoRef::builder()
-                .reference(Borrowed(&reference))
-                .build();
+            let cursor = "cursor";
+            let page = Page::new(videos, true, Some("next_cursor".to_string()));
 
             let mock_server = MockServer::start().await;
 
-            Mock::given(method("POST"))
+            Mock::given(method("GET"))
                 .and(path("/original/query"))
                 .and(query_param("sort_type", "like"))
                 .and(query_param("length", length.to_string()))
-                .and(body_json(query_info))
-                .respond_with(ResponseTemplate::new(200).set_body_json(videos.clone()))
+                .and(query_param("cursor", cursor))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
                 .mount(&mock_server)
                 .await;
 
             let res = {
-                let cmd = video_commands::OrderByLikeLaterVideosCommand::new(&reference, length);
-                product_inner::order_by_like_later_videos(&mock_server.uri(), cmd).await
+                let cmd = video_commands::OrderByLikeLaterVideosCommand::new(&[], cursor, length);
+                product_inner::order_by_like_later_videos::<Original>(&mock_server.uri(), cmd).await
             };
 
-            assert_eq!(res.unwrap(), videos);
+            assert_eq!(res.unwrap(), page);
         }
 
         #[tokio::test]
@@ -624,6 +1144,7 @@ mod test {
                 .collect::<Vec<_>>();
 
             let length = 10_usize;
+            let page = Page::new(videos, true, Some("cursor".to_string()));
 
             let mock_server = MockServer::start().await;
 
@@ -631,16 +1152,16 @@ mod test {
                 .and(path("/original/query"))
                 .and(query_param("sort_type", "date"))
                 .and(query_param("length", length.to_string()))
-                .respond_with(ResponseTemplate::new(200).set_body_json(videos.clone()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
                 .mount(&mock_server)
                 .await;
 
             let res = {
-                let cmd = video_commands::OrderByDateVideosCommand::new(length);
+                let cmd = video_commands::OrderByDateVideosCommand::new(&[], length);
                 product_inner::order_by_date_videos::<Original>(&mock_server.uri(), cmd).await
             };
 
-            assert_eq!(res.unwrap(), videos);
+            assert_eq!(res.unwrap(), page);
         }
 
         #[tokio::test]
@@ -650,29 +1171,91 @@ mod test {
                 .collect::<Vec<_>>();
 
             let length = 10_usize;
-            let reference = Faker.fake::<Video<Original>>();
-
-            let query_info = QueryInfoRef::builder()
-                .reference(Borrowed(&reference))
-                .build();
+            let cursor = "cursor";
+            let page = Page::new(videos, true, Some("next_cursor".to_string()));
 
             let mock_server = MockServer::start().await;
 
-            Mock::given(method("POST"))
+            Mock::given(method("GET"))
                 .and(path("/original/query"))
                 .and(query_param("sort_type", "date"))
                 .and(query_param("length", length.to_string()))
-                .and(body_json(query_info))
-                .respond_with(ResponseTemplate::new(200).set_body_json(videos.clone()))
+                .and(query_param("cursor", cursor))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page.clone()))
                 .mount(&mock_server)
                 .await;
 
             let res = {
-                let cmd = video_commands::OrderByDateLaterVideosCommand::new(&reference, length);
-                product_inner::order_by_date_later_videos(&mock_server.uri(), cmd).await
+                let cmd = video_commands::OrderByDateLaterVideosCommand::new(&[], cursor, length);
+                product_inner::order_by_date_later_videos::<Original>(&mock_server.uri(), cmd).await
             };
 
-            assert_eq!(res.unwrap(), videos);
+            assert_eq!(res.unwrap(), page);
+        }
+
+        #[tokio::test]
+        async fn test_video_tags() {
+            let id = VideoId::generate();
+            let tags = vec![Tag::new("game").unwrap(), Tag::new("singing").unwrap()];
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/original/tags"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(tags.clone()))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path(format!("/original/tags/{}", id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(tags[..1].to_vec()))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("PUT"))
+                .and(path(format!("/original/tags/{}", id)))
+                .and(body_json(tags[0].clone()))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("DELETE"))
+                .and(path(format!("/original/tags/{}", id)))
+                .and(body_json(tags[1].clone()))
+                .respond_with(
+                    ResponseTemplate::new(404).set_body_json(AppCommonError::NoRecordError),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = video_commands::AllVideoTagsCommand;
+                product_inner::all_video_tags::<Original>(&mock_server.uri(), cmd).await
+            };
+            assert_eq!(res.unwrap(), tags);
+
+            let res = {
+                let cmd = video_commands::VideoTagsCommand::new(id);
+                product_inner::video_tags::<Original>(&mock_server.uri(), cmd).await
+            };
+            assert_eq!(res.unwrap(), tags[..1]);
+
+            let res = {
+                let cmd = video_commands::AddVideoTagCommand::new(id, &tags[0]);
+                product_inner::add_video_tag::<Original>(&mock_server.uri(), cmd).await
+            };
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+
+            // 付けられていないタグを外す場合
+            let res = {
+                let cmd = video_commands::RemoveVideoTagCommand::new(id, &tags[1]);
+                product_inner::remove_video_tag::<Original>(&mock_server.uri(), cmd).await
+            };
+            assert!(
+                matches!(
+                    res,
+                    Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+                ),
+                "{:?}",
+                res
+            );
         }
 
         #[tokio::test]
@@ -694,7 +1277,7 @@ mod test {
                     product_inner::remove_video::<Original>(&mock_server.uri(), cmd).await
                 };
 
-                assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
             }
             {
                 // 失敗した場合
@@ -720,5 +1303,96 @@ mod test {
                 );
             }
         }
+
+        #[tokio::test]
+        async fn test_trashed_videos() {
+            let videos = (0..3)
+                .map(|_| Faker.fake::<Video<Original>>())
+                .collect::<Vec<_>>();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .and(path("/original/trash"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(videos.clone()))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = video_commands::TrashedVideosCommand;
+                product_inner::trashed_videos::<Original>(&mock_server.uri(), cmd).await
+            };
+
+            assert_eq!(res.unwrap(), videos);
+        }
+
+        #[tokio::test]
+        async fn test_restore_trashed_video() {
+            let id = VideoId::generate();
+
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("PATCH"))
+                .and(path(format!("/original/trash/{id}")))
+                .respond_with(ResponseTemplate::new(200))
+                .mount(&mock_server)
+                .await;
+
+            let res = {
+                let cmd = video_commands::RestoreTrashedVideoCommand::new(id);
+                product_inner::restore_trashed_video::<Original>(&mock_server.uri(), cmd).await
+            };
+
+            assert!(res.is_ok(), "{:?}", res.unwrap_err());
+        }
+
+        #[tokio::test]
+        async fn test_purge_video() {
+            let id = VideoId::generate();
+
+            {
+                // 成功した場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("DELETE"))
+                    .and(path(format!("/original/trash/{id}")))
+                    .respond_with(ResponseTemplate::new(200))
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = video_commands::PurgeVideoCommand::new(id);
+                    product_inner::purge_video::<Original>(&mock_server.uri(), cmd).await
+                };
+
+                assert!(res.is_ok(), "{:?}", res.unwrap_err());
+            }
+            {
+                // 管理者でない場合
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("DELETE"))
+                    .and(path(format!("/original/trash/{id}")))
+                    .respond_with(
+                        ResponseTemplate::new(403).set_body_json(AppCommonError::ForbiddenError),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                let res = {
+                    let cmd = video_commands::PurgeVideoCommand::new(id);
+                    product_inner::purge_video::<Original>(&mock_server.uri(), cmd).await
+                };
+
+                assert!(
+                    matches!(
+                        res,
+                        Err(AppFrontError::CommonError(AppCommonError::ForbiddenError))
+                    ),
+                    "{:?}",
+                    res
+                );
+            }
+        }
     }
 }
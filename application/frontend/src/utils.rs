@@ -1,8 +1,12 @@
 use crate::AppFrontError;
 use common::AppCommonError;
+use domain::Tag;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
 
+#[cfg(not(feature = "fake"))]
+use reqwest::{RequestBuilder, StatusCode};
+
 /// レスポンスのResultを特定の型とエラーにデシリアライズ
 pub async fn deserialize_response<T: DeserializeOwned>(
     response: Response,
@@ -29,3 +33,125 @@ pub async fn deserialize_response_null(response: Response) -> Result<(), AppFron
         }
     }
 }
+
+/// 匿名のクライアントとしてリクエストを送る．
+/// クライアントのトークンが無いために拒否された場合は，トークンを発行してから一度だけ送り直す．
+#[cfg(not(feature = "fake"))]
+pub async fn send_as_client(url: &str, request: RequestBuilder) -> Result<Response, AppFrontError> {
+    let retry_request = request.try_clone();
+    let response = request.send().await?;
+
+    match (response.status(), retry_request) {
+        (StatusCode::UNAUTHORIZED, Some(retry_request)) => {
+            let issue_response = crate::api_client()
+                .post(&format!("{}{}", url, "/auth/client"))
+                .send()
+                .await?;
+            deserialize_response_null(issue_response).await?;
+
+            Ok(retry_request.send().await?)
+        }
+        _ => Ok(response),
+    }
+}
+
+/// タグで絞り込むためのクエリパラメータ．`tags`が空の場合は何も付けない．
+pub fn tags_query(tags: &[Tag]) -> Vec<(&'static str, String)> {
+    match tags.is_empty() {
+        true => Vec::new(),
+        false => vec![("tags", Tag::join_list(tags))],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{deserialize_response, deserialize_response_null};
+    use crate::AppFrontError;
+    use common::AppCommonError;
+    use domain::{ValidationCode, ValidationError};
+
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_deserialize_error_response() {
+        let cases = [
+            (404, AppCommonError::NoRecordError),
+            (409, AppCommonError::ConflictError),
+            (409, AppCommonError::StaleVersion),
+            (422, AppCommonError::DomainError("invalid".to_string())),
+            (
+                422,
+                AppCommonError::ValidationError(
+                    ValidationError::new(ValidationCode::TooLong, "url").with_param("limit", 100),
+                ),
+            ),
+            (503, AppCommonError::DBConnectionError("closed".to_string())),
+            (500, AppCommonError::OtherSQLXError("error".to_string())),
+        ];
+
+        for (status_code, err) in cases {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(status_code).set_body_json(err.clone()))
+                .mount(&mock_server)
+                .await;
+
+            // どのステータスコードでもエラーのボディをデシリアライズする
+            let response = reqwest::get(mock_server.uri()).await.unwrap();
+            let res = deserialize_response::<()>(response).await;
+            assert!(
+                matches!(&res, Err(AppFrontError::CommonError(res_err)) if res_err.to_string() == err.to_string()),
+                "{status_code}: {res:?}"
+            );
+
+            let response = reqwest::get(mock_server.uri()).await.unwrap();
+            let res = deserialize_response_null(response).await;
+            assert!(
+                matches!(&res, Err(AppFrontError::CommonError(res_err)) if res_err.to_string() == err.to_string()),
+                "{status_code}: {res:?}"
+            );
+        }
+    }
+
+    #[cfg(not(feature = "fake"))]
+    #[tokio::test]
+    async fn test_send_as_client() {
+        use super::send_as_client;
+        use crate::api_client;
+        use wiremock::matchers::path;
+
+        let mock_server = MockServer::start().await;
+
+        // 最初はクライアントのトークンが無いため拒否される
+        Mock::given(method("PATCH"))
+            .and(path("/movie_clip/increment_like/id"))
+            .respond_with(
+                ResponseTemplate::new(401).set_body_json(AppCommonError::NoClientTokenError),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/auth/client"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/movie_clip/increment_like/id"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = mock_server.uri();
+        let request = api_client().patch(&format!("{}{}", url, "/movie_clip/increment_like/id"));
+        let response = send_as_client(&url, request).await.unwrap();
+        assert!(deserialize_response_null(response).await.is_ok());
+    }
+}
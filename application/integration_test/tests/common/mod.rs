@@ -0,0 +1,29 @@
+use frontend::{commands::auth_commands, usecases::auth_usecase};
+
+use toml::Table;
+
+/// Secrets.tomlの`admin_password`を取得する．
+pub fn admin_password() -> String {
+    let secret_table = include_str!("../../../../Secrets.toml")
+        .parse::<Table>()
+        .expect("Cannot Read Secrets.toml");
+
+    secret_table
+        .get("admin_password")
+        .expect("Cannot Get admin_password from Secrets.toml")
+        .as_str()
+        .expect("admin_password is invalid type.")
+        .to_string()
+}
+
+/// Secrets.tomlの`admin_password`を用いて管理者としてログインする．
+#[allow(dead_code)]
+pub fn login_as_admin() {
+    let admin_password = admin_password();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(async move {
+        let cmd = auth_commands::LoginCommand::new(None, &admin_password);
+        auth_usecase::login(cmd).await.expect("Admin login failed.");
+    });
+}
@@ -0,0 +1,207 @@
+mod common;
+
+use domain::movie_clip::MovieClip;
+use domain::user::Role;
+use frontend::{
+    commands::{auth_commands, movie_clip_commands, user_commands},
+    usecases::{auth_usecase, movie_clip_usecase, user_usecase},
+    AppCommonError, AppFrontError, NewMovieClipInfo, NewUserInfo,
+};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use serial_test::serial;
+
+#[test]
+#[serial("auth")]
+fn test_auth_sequence() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let clip_info = NewMovieClipInfo::from(Faker.fake::<MovieClip>());
+
+        // ログイン前は変更を伴うリクエストが拒否される
+        let res = {
+            let cmd = auth_commands::CheckSessionCommand;
+            auth_usecase::check_session(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::UnauthorizedError
+            ))
+        ));
+
+        let res = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::UnauthorizedError
+            ))
+        ));
+
+        // 誤ったパスワードではログインできない
+        let res = {
+            let cmd = auth_commands::LoginCommand::new(None, "wrong password");
+            auth_usecase::login(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::UnauthorizedError
+            ))
+        ));
+    });
+
+    // ログイン後は変更を伴うリクエストが可能
+    common::login_as_admin();
+
+    rt.block_on(async move {
+        let clip_info = NewMovieClipInfo::from(Faker.fake::<MovieClip>());
+
+        {
+            let cmd = auth_commands::CheckSessionCommand;
+            let role = auth_usecase::check_session(cmd).await.unwrap();
+            assert_eq!(role, Role::Admin);
+        }
+        let clip = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await.unwrap()
+        };
+        {
+            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(clip.id());
+            movie_clip_usecase::remove_movie_clip(cmd).await.unwrap();
+        }
+
+        // ログアウト後は再び拒否される
+        {
+            let cmd = auth_commands::LogoutCommand;
+            auth_usecase::logout(cmd).await.unwrap();
+        }
+        let res = {
+            let cmd = auth_commands::CheckSessionCommand;
+            auth_usecase::check_session(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::UnauthorizedError
+            ))
+        ));
+    });
+}
+
+#[test]
+#[serial("auth")]
+fn test_contributor_sequence() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    // 管理者としてContributorを登録
+    common::login_as_admin();
+
+    let user_name = format!("contributor_{}", (8..12).fake::<String>());
+    let user_info = NewUserInfo::new(user_name.clone(), "contributor password", Role::Contributor);
+
+    rt.block_on(async {
+        {
+            let cmd = user_commands::SaveUserCommand::new(&user_info);
+            user_usecase::save_user(cmd).await.unwrap();
+        }
+        {
+            let cmd = auth_commands::LogoutCommand;
+            auth_usecase::logout(cmd).await.unwrap();
+        }
+    });
+
+    // Contributorとしてログイン
+    let clip_info = NewMovieClipInfo::from(Faker.fake::<MovieClip>());
+    let clip = rt.block_on(async {
+        {
+            let cmd = auth_commands::LoginCommand::new(Some(&user_name), "contributor password");
+            auth_usecase::login(cmd).await.unwrap();
+        }
+        {
+            let cmd = auth_commands::CheckSessionCommand;
+            let role = auth_usecase::check_session(cmd).await.unwrap();
+            assert_eq!(role, Role::Contributor);
+        }
+
+        // 追加・編集はできる
+        let clip = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await.unwrap()
+        };
+        {
+            let cmd = movie_clip_commands::EditMovieClipCommand::new(&clip);
+            movie_clip_usecase::edit_movie_clip(cmd).await.unwrap();
+        }
+
+        // 削除・ユーザーの管理はできない
+        let res = {
+            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(clip.id());
+            movie_clip_usecase::remove_movie_clip(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(AppCommonError::ForbiddenError))
+        ));
+
+        let res = {
+            let cmd = user_commands::AllUsersCommand;
+            user_usecase::all_users(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(AppCommonError::ForbiddenError))
+        ));
+
+        // 誤ったパスワードではログインできない
+        let res = {
+            let cmd = auth_commands::LoginCommand::new(Some(&user_name), "wrong password");
+            auth_usecase::login(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::UnauthorizedError
+            ))
+        ));
+
+        {
+            let cmd = auth_commands::LogoutCommand;
+            auth_usecase::logout(cmd).await.unwrap();
+        }
+        clip
+    });
+
+    // 後片付けのため管理者でログインし直す
+    common::login_as_admin();
+
+    rt.block_on(async {
+        let user_id = {
+            let cmd = user_commands::AllUsersCommand;
+            user_usecase::all_users(cmd)
+                .await
+                .unwrap()
+                .into_iter()
+                .find(|user| user.name == user_name)
+                .unwrap()
+                .id
+        };
+        {
+            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(clip.id());
+            movie_clip_usecase::remove_movie_clip(cmd).await.unwrap();
+        }
+        {
+            let cmd = user_commands::RemoveUserCommand::new(user_id);
+            user_usecase::remove_user(cmd).await.unwrap();
+        }
+        {
+            let cmd = auth_commands::LogoutCommand;
+            auth_usecase::logout(cmd).await.unwrap();
+        }
+    });
+}
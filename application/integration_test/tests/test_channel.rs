@@ -0,0 +1,208 @@
+mod common;
+
+use domain::channel::{Channel, ChannelId};
+use domain::video::{Original, Video};
+use frontend::{
+    commands::{channel_commands, video_commands},
+    usecases::{channel_usecase, video_usecase},
+    AppCommonError, AppFrontError, NewVideoInfo,
+};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+use serial_test::serial;
+
+struct TearDown;
+
+#[allow(unused_must_use)]
+impl Drop for TearDown {
+    fn drop(&mut self) {
+        let rt = tokio::runtime::Runtime::new();
+
+        if let Ok(rt) = rt {
+            rt.block_on(async move {
+                // チャンネルに紐付いた動画を先に完全に削除する
+                let all_videos = {
+                    let cmd = video_commands::AllVideosCommand;
+                    video_usecase::all_videos::<Original>(cmd).await
+                };
+
+                if let Ok(all_videos) = all_videos {
+                    for video in all_videos.into_iter() {
+                        let cmd = video_commands::RemoveVideoCommand::new(video.id());
+                        video_usecase::remove_video::<Original>(cmd).await;
+                        let cmd = video_commands::PurgeVideoCommand::new(video.id());
+                        video_usecase::purge_video::<Original>(cmd).await;
+                    }
+                }
+
+                let all_channels = {
+                    let cmd = channel_commands::AllChannelsCommand;
+                    channel_usecase::all_channels(cmd).await
+                };
+
+                if let Ok(all_channels) = all_channels {
+                    for channel in all_channels.into_iter() {
+                        let cmd = channel_commands::RemoveChannelCommand::new(channel.id());
+                        channel_usecase::remove_channel(cmd).await;
+                    }
+                }
+            });
+        }
+    }
+}
+
+#[fixture]
+fn teardown() -> TearDown {
+    common::login_as_admin();
+    TearDown
+}
+
+#[rstest]
+#[test]
+#[serial("video")]
+fn test_channel_sequence(_teardown: TearDown) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let channels = (0..5).map(|_| Faker.fake::<Channel>()).collect::<Vec<_>>();
+        for channel in channels.iter() {
+            let cmd = channel_commands::SaveChannelCommand::new(channel);
+            channel_usecase::save_channel(cmd).await.unwrap();
+        }
+
+        // 同じ名前のチャンネルは保存できない
+        let mut duplicated = Faker.fake::<Channel>();
+        *duplicated.name_mut() = channels[0].name().clone();
+        let res = {
+            let cmd = channel_commands::SaveChannelCommand::new(&duplicated);
+            channel_usecase::save_channel(cmd).await
+        };
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::ConflictError))
+            ),
+            "{:?}",
+            res
+        );
+
+        // 先頭のチャンネルに動画を紐付ける
+        let mut channel = channels[0].clone();
+        let mut videos = Vec::new();
+        for _ in 0..3 {
+            let video_info = NewVideoInfo {
+                channel_id: channel.id(),
+                ..NewVideoInfo::from(Faker.fake::<Video<Original>>())
+            };
+            let cmd = video_commands::SaveVideoCommand::new(&video_info);
+            videos.push(video_usecase::save_video::<Original>(cmd).await.unwrap());
+        }
+        assert!(videos
+            .iter()
+            .all(|video| video.author() == channel.name().as_str()));
+
+        // 存在しないチャンネルの動画は保存できない
+        let res = {
+            let video_info = NewVideoInfo {
+                channel_id: ChannelId::generate(),
+                ..NewVideoInfo::from(Faker.fake::<Video<Original>>())
+            };
+            let cmd = video_commands::SaveVideoCommand::new(&video_info);
+            video_usecase::save_video::<Original>(cmd).await
+        };
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+            ),
+            "{:?}",
+            res
+        );
+
+        // チャンネル名の変更は動画の投稿者名に反映される
+        *channel.name_mut() = Faker.fake();
+        {
+            let cmd = channel_commands::EditChannelCommand::new(&channel);
+            channel_usecase::edit_channel(cmd).await.unwrap();
+        }
+        for video in videos.iter_mut() {
+            video.set_channel(&channel);
+        }
+
+        let mut res_videos = {
+            let cmd = video_commands::ChannelVideosCommand::new(channel.id());
+            video_usecase::channel_videos::<Original>(cmd)
+                .await
+                .unwrap()
+        };
+        res_videos.sort_by_key(|video| video.id());
+        videos.sort_by_key(|video| video.id());
+        assert_eq!(res_videos, videos);
+
+        // 動画の数とともに名前の順で取得できる
+        let channel_ids = channels
+            .iter()
+            .map(|channel| channel.id())
+            .collect::<Vec<ChannelId>>();
+        let summaries = {
+            let cmd = channel_commands::ChannelSummariesCommand;
+            channel_usecase::channel_summaries(cmd).await.unwrap()
+        }
+        .into_iter()
+        .filter(|summary| channel_ids.contains(&summary.channel().id()))
+        .collect::<Vec<_>>();
+
+        let mut expected_channels = channels.clone();
+        expected_channels[0] = channel.clone();
+        expected_channels.sort_by(|x, y| x.name().cmp(y.name()));
+        assert_eq!(
+            summaries
+                .iter()
+                .map(|summary| summary.channel().clone())
+                .collect::<Vec<_>>(),
+            expected_channels
+        );
+        for summary in summaries.iter() {
+            let expected_count = if summary.channel().id() == channel.id() {
+                3
+            } else {
+                0
+            };
+            assert_eq!(summary.video_count(), expected_count);
+        }
+
+        // 動画が紐付いているチャンネルは削除できない
+        let res = {
+            let cmd = channel_commands::RemoveChannelCommand::new(channel.id());
+            channel_usecase::remove_channel(cmd).await
+        };
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::ConflictError))
+            ),
+            "{:?}",
+            res
+        );
+
+        // 動画の紐付いていないチャンネルは削除できる
+        {
+            let cmd = channel_commands::RemoveChannelCommand::new(channels[1].id());
+            channel_usecase::remove_channel(cmd).await.unwrap();
+        }
+        let res = {
+            let cmd = channel_commands::FindChannelCommand::new(channels[1].id());
+            channel_usecase::find_channel(cmd).await
+        };
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+            ),
+            "{:?}",
+            res
+        );
+    });
+}
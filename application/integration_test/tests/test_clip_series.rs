@@ -0,0 +1,206 @@
+mod common;
+
+use domain::clip_series::ClipSeries;
+use domain::movie_clip::MovieClip;
+use frontend::{
+    commands::{clip_series_commands, movie_clip_commands},
+    usecases::{clip_series_usecase, movie_clip_usecase},
+    AppCommonError, AppFrontError, NewClipSeriesInfo, NewMovieClipInfo,
+};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use rand::{seq::SliceRandom, thread_rng};
+use rstest::{fixture, rstest};
+use serial_test::serial;
+
+/// 全てのシリーズを保存し，サーバーが返したシリーズを返す．
+async fn all_save(clip_series_vec: &[ClipSeries]) -> Result<Vec<ClipSeries>, AppFrontError> {
+    let mut saved_clip_series_vec = Vec::new();
+    for clip_series in clip_series_vec.iter() {
+        let clip_series_info = NewClipSeriesInfo::from(clip_series.clone());
+        let cmd = clip_series_commands::SaveClipSeriesCommand::new(&clip_series_info);
+        saved_clip_series_vec.push(clip_series_usecase::save_clip_series(cmd).await?);
+    }
+    Ok(saved_clip_series_vec)
+}
+
+struct TearDown;
+
+#[allow(unused_must_use)]
+impl Drop for TearDown {
+    fn drop(&mut self) {
+        let rt = tokio::runtime::Runtime::new();
+
+        if let Ok(rt) = rt {
+            rt.block_on(async move {
+                let all_clip_series = {
+                    let cmd = clip_series_commands::AllClipSeriesCommand;
+                    clip_series_usecase::all_clip_series(cmd).await
+                };
+
+                if let Ok(all_clip_series) = all_clip_series {
+                    for clip_series in all_clip_series.into_iter() {
+                        let cmd =
+                            clip_series_commands::RemoveClipSeriesCommand::new(clip_series.id());
+                        clip_series_usecase::remove_clip_series(cmd).await;
+                    }
+                }
+
+                let all_clips = {
+                    let cmd = movie_clip_commands::AllMovieClipsCommand;
+                    movie_clip_usecase::all_movie_clips(cmd).await
+                };
+
+                if let Ok(all_clips) = all_clips {
+                    for clip in all_clips.into_iter() {
+                        let cmd = movie_clip_commands::RemoveMovieClipCommand::new(clip.id());
+                        movie_clip_usecase::remove_movie_clip(cmd).await;
+                    }
+                }
+            });
+        }
+    }
+}
+
+#[fixture]
+fn teardown() -> TearDown {
+    common::login_as_admin();
+    TearDown
+}
+
+#[rstest]
+#[test]
+#[serial("clip")]
+fn test_clip_series_sequence(_teardown: TearDown) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        // シリーズに含めるクリップを保存
+        let mut clips = Vec::new();
+        for _ in 0..10 {
+            let clip_info = NewMovieClipInfo::from(Faker.fake::<MovieClip>());
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            clips.push(movie_clip_usecase::save_movie_clip(cmd).await.unwrap());
+        }
+        let clip_ids = clips.iter().map(|clip| clip.id()).collect::<Vec<_>>();
+
+        let clip_series_info = NewClipSeriesInfo::new("title", "description", clip_ids.clone());
+        let clip_series = {
+            let cmd = clip_series_commands::SaveClipSeriesCommand::new(&clip_series_info);
+            clip_series_usecase::save_clip_series(cmd).await.unwrap()
+        };
+        assert_eq!(clip_series.clips(), clip_ids.as_slice());
+
+        // 保存した順番で次のクリップが決まる
+        for (current, next) in clip_ids.iter().zip(clip_ids.iter().skip(1)) {
+            assert_eq!(clip_series.next_clip(*current), Some(*next));
+        }
+        assert_eq!(clip_series.next_clip(*clip_ids.last().unwrap()), None);
+
+        // クリップの順番を変更
+        let mut edited = clip_series.clone();
+        edited.clips_mut().shuffle(&mut thread_rng());
+        {
+            let cmd = clip_series_commands::EditClipSeriesCommand::new(&edited);
+            clip_series_usecase::edit_clip_series(cmd).await.unwrap();
+        }
+
+        let res = {
+            let cmd = clip_series_commands::FindClipSeriesCommand::new(clip_series.id());
+            clip_series_usecase::find_clip_series(cmd).await.unwrap()
+        };
+        assert_eq!(res, edited);
+
+        // 同じクライアントのLikeは一度だけ数えられる
+        for _ in 0..3 {
+            let cmd = clip_series_commands::IncrementLikeClipSeriesCommand::new(clip_series.id());
+            clip_series_usecase::increment_like_clip_series(cmd)
+                .await
+                .unwrap();
+        }
+        edited.increment_like();
+
+        let res = {
+            let cmd = clip_series_commands::OrderByLikeClipSeriesCommand::new(10);
+            clip_series_usecase::order_by_like_clip_series(cmd)
+                .await
+                .unwrap()
+        };
+        assert_eq!(res.items, vec![edited.clone()]);
+        assert!(!res.has_more);
+
+        let ids = [clip_series.id()];
+        let res_liked_ids = {
+            let cmd = clip_series_commands::LikedClipSeriesIdsCommand::new(&ids);
+            clip_series_usecase::liked_clip_series_ids(cmd)
+                .await
+                .unwrap()
+        };
+        assert_eq!(res_liked_ids, ids.to_vec());
+
+        // 削除したシリーズは取得できない
+        {
+            let cmd = clip_series_commands::RemoveClipSeriesCommand::new(clip_series.id());
+            clip_series_usecase::remove_clip_series(cmd).await.unwrap();
+        }
+        let res = {
+            let cmd = clip_series_commands::FindClipSeriesCommand::new(clip_series.id());
+            clip_series_usecase::find_clip_series(cmd).await
+        };
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+            ),
+            "{:?}",
+            res
+        );
+    });
+}
+
+#[rstest]
+#[test]
+#[serial("clip")]
+fn test_order_by_create_date_clip_series_sequence(_teardown: TearDown) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let clip_series_vec = (0..30)
+            .map(|_| Faker.fake::<ClipSeries>())
+            .collect::<Vec<_>>();
+
+        let mut clip_series_vec = all_save(&clip_series_vec).await.unwrap();
+
+        // カーソルを辿って全てのページを取得する
+        let length = 7_usize;
+        let mut page = {
+            let cmd = clip_series_commands::OrderByCreateDateClipSeriesCommand::new(length);
+            clip_series_usecase::order_by_create_date_clip_series(cmd)
+                .await
+                .unwrap()
+        };
+        let mut res = page.items.clone();
+        while page.has_more {
+            page = {
+                let cmd = clip_series_commands::OrderByCreateDateLaterClipSeriesCommand::new(
+                    page.next_cursor.as_deref().unwrap(),
+                    length,
+                );
+                clip_series_usecase::order_by_create_date_later_clip_series(cmd)
+                    .await
+                    .unwrap()
+            };
+            res.extend(page.items.clone());
+        }
+
+        // 作成日で降順・idで昇順にソート
+        clip_series_vec.sort_by(|x, y| {
+            y.create_date()
+                .cmp(&x.create_date())
+                .then(x.id().cmp(&y.id()))
+        });
+
+        assert_eq!(res, clip_series_vec);
+    });
+}
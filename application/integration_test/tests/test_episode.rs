@@ -1,3 +1,5 @@
+mod common;
+
 use domain::episode::Episode;
 use domain::Date;
 use frontend::commands::episode_commands;
@@ -43,9 +45,10 @@ impl Drop for TearDown {
     }
 }
 
-/// teardownの関数
+/// teardownの関数(事前に管理者としてログインする)
 #[fixture]
 fn teardown() -> TearDown {
+    common::login_as_admin();
     TearDown
 }
 
@@ -76,6 +79,7 @@ fn test_edit_episode_sequence(_teardown: TearDown) {
 
             let cmd = episode_commands::EditEpisodeCommand::new(episode);
             episode_usecase::edit_episode(cmd).await.unwrap();
+            episode.increment_version();
         }
 
         // 取得したものと比較
@@ -109,11 +113,11 @@ fn test_order_by_range_episodes_sequence(_teardown: TearDown) {
 
         // 参照元をDateを昇順・IDを昇順にソート・フィルタリング
         episodes.sort_by(|x, y| x.date().cmp(&y.date()).then_with(|| x.id().cmp(&y.id())));
-        episodes.retain(|episode| start <= episode.date() && episode.date() < end);
+        episodes.retain(|episode| start <= episode.date().date() && episode.date().date() < end);
 
         // リクエストをDateが同じ場合のみIDを昇順にソート
         let mut res = {
-            let cmd = episode_commands::OrderByDateRangeEpisodesCommand::new(start, end);
+            let cmd = episode_commands::OrderByDateRangeEpisodesCommand::new(&[], start, end);
             episode_usecase::order_by_date_range_episodes(cmd)
                 .await
                 .unwrap()
@@ -146,7 +150,7 @@ fn test_save_episode_failed(_teardown: TearDown) {
             episode_usecase::save_episode(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 二度目は失敗する
         let res = {
@@ -181,7 +185,7 @@ fn test_edit_episode_failed(_teardown: TearDown) {
             episode_usecase::save_episode(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 最初は成功する
         let res = {
@@ -189,14 +193,31 @@ fn test_edit_episode_failed(_teardown: TearDown) {
             episode_usecase::edit_episode(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
+
+        // 同じ版数からの編集は他の編集によって失敗する
+        let res = {
+            let cmd = episode_commands::EditEpisodeCommand::new(&episode);
+            episode_usecase::edit_episode(cmd).await
+        };
+
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(
+                    frontend::AppCommonError::StaleVersion
+                ))
+            ),
+            "{:?}",
+            res.unwrap_err()
+        );
 
         let res = {
             let cmd = episode_commands::RemoveEpisodeCommand::new(episode.id());
             episode_usecase::remove_episode(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 削除した後は失敗する
         let res = {
@@ -233,7 +254,7 @@ fn test_remove_episode_failed(_teardown: TearDown) {
             episode_usecase::save_episode(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 最初は成功する
         let res = {
@@ -241,7 +262,7 @@ fn test_remove_episode_failed(_teardown: TearDown) {
             episode_usecase::remove_episode(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 次は失敗する
         let res = {
@@ -1,20 +1,43 @@
-use domain::movie_clip::MovieClip;
+mod common;
+
+use domain::movie_clip::{MovieClip, MovieClipId};
+use domain::Tag;
 use frontend::{
     commands::movie_clip_commands, usecases::movie_clip_usecase, AppCommonError, AppFrontError,
+    NewMovieClipInfo,
 };
 
 use fake::{Fake, Faker};
-use pretty_assertions::assert_eq;
+use pretty_assertions::{assert_eq, assert_ne};
 use rand::Rng;
 use rand::{seq::SliceRandom, thread_rng};
 use rstest::{fixture, rstest};
 use serial_test::serial;
 use std::cmp::Ordering;
 
-async fn all_save(clips: &[MovieClip]) -> Result<(), AppFrontError> {
+/// 全てのクリップを保存し，サーバーが返したクリップを返す．
+async fn all_save(clips: &[MovieClip]) -> Result<Vec<MovieClip>, AppFrontError> {
+    let mut saved_clips = Vec::new();
     for clip in clips.iter() {
-        let cmd = movie_clip_commands::SaveMovieClipCommand::new(clip);
-        movie_clip_usecase::save_movie_clip(cmd).await?
+        let clip_info = NewMovieClipInfo::from(clip.clone());
+        let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+        saved_clips.push(movie_clip_usecase::save_movie_clip(cmd).await?);
+    }
+    Ok(saved_clips)
+}
+
+/// 保存したクリップのLikeをランダムに増やす．同じクライアントのLikeは一度だけ数えられる．
+async fn random_increment_like(clips: &mut [MovieClip]) -> Result<(), AppFrontError> {
+    for clip in clips.iter_mut() {
+        let like_number = thread_rng().gen_range(0..5);
+        if like_number > 0 {
+            clip.increment_like();
+        }
+
+        for _ in 0..like_number {
+            let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(clip.id());
+            movie_clip_usecase::increment_like_movie_clip(cmd).await?;
+        }
     }
     Ok(())
 }
@@ -46,6 +69,7 @@ impl Drop for TearDown {
 
 #[fixture]
 fn teardown() -> TearDown {
+    common::login_as_admin();
     TearDown
 }
 
@@ -56,11 +80,11 @@ fn test_edit_movie_clip_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut clips = (0..100)
+        let clips = (0..100)
             .map(|_| Faker.fake::<MovieClip>())
             .collect::<Vec<_>>();
 
-        all_save(&clips).await.unwrap();
+        let mut clips = all_save(&clips).await.unwrap();
 
         // 変更するMovieClipsのインデックス
         let mut edit_indices = (0..clips.len()).collect::<Vec<_>>();
@@ -76,6 +100,7 @@ fn test_edit_movie_clip_sequence(_teardown: TearDown) {
 
             let cmd = movie_clip_commands::EditMovieClipCommand::new(clip);
             movie_clip_usecase::edit_movie_clip(cmd).await.unwrap();
+            clip.increment_version();
         }
 
         // 取得したものと比較
@@ -98,21 +123,24 @@ fn test_increment_like_movie_clip_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut clips = (0..10)
+        let clips = (0..10)
             .map(|_| Faker.fake::<MovieClip>())
             .collect::<Vec<_>>();
 
-        all_save(&clips).await.unwrap();
+        let mut clips = all_save(&clips).await.unwrap();
 
         let like_lim = 10_usize;
         let like_numbers = (0..clips.len())
             .map(|_| thread_rng().gen_range(0..like_lim))
             .collect::<Vec<_>>();
 
+        // 同じクライアントが何度Likeしても一度だけ数えられる
         for (clip, like_number) in clips.iter_mut().zip(like_numbers.iter()) {
-            for _ in 0..*like_number {
+            if *like_number > 0 {
                 clip.increment_like();
+            }
 
+            for _ in 0..*like_number {
                 let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(clip.id());
                 movie_clip_usecase::increment_like_movie_clip(cmd)
                     .await
@@ -130,6 +158,44 @@ fn test_increment_like_movie_clip_sequence(_teardown: TearDown) {
         clips.sort_by_key(|clip| clip.id());
 
         assert_eq!(res, clips);
+
+        // Likeしたクリップのid
+        let ids = clips.iter().map(|clip| clip.id()).collect::<Vec<_>>();
+        let liked_ids = clips
+            .iter()
+            .filter(|clip| clip.like() > 0)
+            .map(|clip| clip.id())
+            .collect::<Vec<_>>();
+
+        let res_liked_ids = {
+            let cmd = movie_clip_commands::LikedMovieClipIdsCommand::new(&ids);
+            movie_clip_usecase::liked_movie_clip_ids(cmd).await.unwrap()
+        };
+        assert_eq!(res_liked_ids, liked_ids);
+
+        // Likeを取り消す．二度目は何もしない
+        for clip in clips.iter_mut() {
+            clip.decrement_like();
+
+            for _ in 0..2 {
+                let cmd = movie_clip_commands::UnlikeMovieClipCommand::new(clip.id());
+                movie_clip_usecase::unlike_movie_clip(cmd).await.unwrap();
+            }
+        }
+
+        let mut res = {
+            let cmd = movie_clip_commands::AllMovieClipsCommand;
+            movie_clip_usecase::all_movie_clips(cmd).await.unwrap()
+        };
+        res.sort_by_key(|clip| clip.id());
+
+        assert_eq!(res, clips);
+
+        let res_liked_ids = {
+            let cmd = movie_clip_commands::LikedMovieClipIdsCommand::new(&ids);
+            movie_clip_usecase::liked_movie_clip_ids(cmd).await.unwrap()
+        };
+        assert_eq!(res_liked_ids, Vec::<MovieClipId>::new());
     });
 }
 
@@ -140,19 +206,21 @@ fn test_order_by_like_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut clips = (0..100)
+        let clips = (0..100)
             .map(|_| Faker.fake::<MovieClip>())
             .collect::<Vec<_>>();
 
-        all_save(&clips).await.unwrap();
+        let mut clips = all_save(&clips).await.unwrap();
+        random_increment_like(&mut clips).await.unwrap();
 
         let length = 5_usize;
 
         let mut res = {
-            let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(length);
+            let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(&[], length);
             movie_clip_usecase::order_by_like_movie_clips(cmd)
                 .await
                 .unwrap()
+                .items
         };
 
         // Likeが同じ場合はidで昇順．
@@ -179,21 +247,34 @@ fn test_order_by_like_later_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut clips = (0..100)
+        let clips = (0..100)
             .map(|_| Faker.fake::<MovieClip>())
             .collect::<Vec<_>>();
 
-        all_save(&clips).await.unwrap();
+        let mut clips = all_save(&clips).await.unwrap();
+        random_increment_like(&mut clips).await.unwrap();
 
         let length = 20_usize;
-        let reference = clips.choose(&mut thread_rng()).unwrap().clone();
+
+        // 最初のページの最後のクリップ以降を取得する
+        let first_page = {
+            let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(&[], length);
+            movie_clip_usecase::order_by_like_movie_clips(cmd)
+                .await
+                .unwrap()
+        };
+        let reference = first_page.items.last().unwrap().clone();
 
         let mut res = {
-            let cmd =
-                movie_clip_commands::OrderByLikeLaterMovieClipsCommand::new(&reference, length);
+            let cmd = movie_clip_commands::OrderByLikeLaterMovieClipsCommand::new(
+                &[],
+                first_page.next_cursor.as_deref().unwrap(),
+                length,
+            );
             movie_clip_usecase::order_by_like_later_movie_clips(cmd)
                 .await
                 .unwrap()
+                .items
         };
 
         // Likeが同じ場合はidで昇順．
@@ -227,19 +308,20 @@ fn test_order_by_create_date_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut clips = (0..100)
+        let clips = (0..100)
             .map(|_| Faker.fake::<MovieClip>())
             .collect::<Vec<_>>();
 
-        all_save(&clips).await.unwrap();
+        let mut clips = all_save(&clips).await.unwrap();
 
         let length = 20_usize;
 
         let mut res = {
-            let cmd = movie_clip_commands::OrderByCreateDateMovieClipsCommand::new(length);
+            let cmd = movie_clip_commands::OrderByCreateDateMovieClipsCommand::new(&[], length);
             movie_clip_usecase::order_by_create_date_movie_clips(cmd)
                 .await
                 .unwrap()
+                .items
         };
 
         // 作成日時が同じ場合はidで昇順．
@@ -270,22 +352,33 @@ fn test_order_by_create_date_later_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut clips = (0..100)
+        let clips = (0..100)
             .map(|_| Faker.fake::<MovieClip>())
             .collect::<Vec<_>>();
 
-        all_save(&clips).await.unwrap();
+        let mut clips = all_save(&clips).await.unwrap();
 
         let length = 20_usize;
-        let reference = clips.choose(&mut thread_rng()).unwrap().clone();
+
+        // 最初のページの最後のクリップ以降を取得する
+        let first_page = {
+            let cmd = movie_clip_commands::OrderByCreateDateMovieClipsCommand::new(&[], length);
+            movie_clip_usecase::order_by_create_date_movie_clips(cmd)
+                .await
+                .unwrap()
+        };
+        let reference = first_page.items.last().unwrap().clone();
 
         let mut res = {
             let cmd = movie_clip_commands::OrderByCreateDateLaterMovieClipsCommand::new(
-                &reference, length,
+                &[],
+                first_page.next_cursor.as_deref().unwrap(),
+                length,
             );
             movie_clip_usecase::order_by_create_date_later_movie_clips(cmd)
                 .await
                 .unwrap()
+                .items
         };
 
         // 日時が同じ場合はidで昇順．
@@ -319,34 +412,187 @@ fn test_order_by_create_date_later_sequence(_teardown: TearDown) {
 #[rstest]
 #[test]
 #[serial("clip")]
-fn test_save_movie_clip_failed(_teardown: TearDown) {
+fn test_tag_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let clip = Faker.fake::<MovieClip>();
+        let clips = (0..20)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+
+        let clips = all_save(&clips).await.unwrap();
+
+        let game = Tag::new("Game").unwrap();
+        let singing = Tag::new("singing").unwrap();
+
+        // 最初の10個にgame，最初の5個にsingingを付ける
+        for (i, clip) in clips.iter().enumerate().take(10) {
+            let cmd = movie_clip_commands::AddMovieClipTagCommand::new(clip.id(), &game);
+            movie_clip_usecase::add_movie_clip_tag(cmd).await.unwrap();
+            if i < 5 {
+                let cmd = movie_clip_commands::AddMovieClipTagCommand::new(clip.id(), &singing);
+                movie_clip_usecase::add_movie_clip_tag(cmd).await.unwrap();
+            }
+        }
+
+        let all_tags = {
+            let cmd = movie_clip_commands::AllMovieClipTagsCommand;
+            movie_clip_usecase::all_movie_clip_tags(cmd).await.unwrap()
+        };
+        assert_eq!(all_tags, vec![game.clone(), singing.clone()]);
+
+        let clip_tags = {
+            let cmd = movie_clip_commands::MovieClipTagsCommand::new(clips[0].id());
+            movie_clip_usecase::movie_clip_tags(cmd).await.unwrap()
+        };
+        assert_eq!(clip_tags, vec![game.clone(), singing.clone()]);
+
+        // 全てのタグを持つクリップのみを取得する
+        let tags = [game.clone(), singing.clone()];
+        let mut res = {
+            let cmd = movie_clip_commands::OrderByCreateDateMovieClipsCommand::new(&tags, 20);
+            movie_clip_usecase::order_by_create_date_movie_clips(cmd)
+                .await
+                .unwrap()
+                .items
+        };
+        res.sort_by_key(|clip| clip.id());
+        let mut expected = clips[..5].to_vec();
+        expected.sort_by_key(|clip| clip.id());
+        assert_eq!(res, expected);
+
+        // タグを外す
+        let cmd = movie_clip_commands::RemoveMovieClipTagCommand::new(clips[0].id(), &singing);
+        movie_clip_usecase::remove_movie_clip_tag(cmd)
+            .await
+            .unwrap();
 
-        // 最初は成功する
         let res = {
-            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip);
-            movie_clip_usecase::save_movie_clip(cmd).await
+            let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(&tags, 20);
+            movie_clip_usecase::order_by_like_movie_clips(cmd)
+                .await
+                .unwrap()
+                .items
         };
+        assert_eq!(res.len(), 4);
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        let res = {
+            let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(&tags[..1], 20);
+            movie_clip_usecase::order_by_like_movie_clips(cmd)
+                .await
+                .unwrap()
+                .items
+        };
+        assert_eq!(res.len(), 10);
 
-        // 二度目は失敗する
+        // 存在しないクリップにはタグを付けられない
         let res = {
-            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip);
-            movie_clip_usecase::save_movie_clip(cmd).await
+            let cmd =
+                movie_clip_commands::AddMovieClipTagCommand::new(MovieClipId::generate(), &game);
+            movie_clip_usecase::add_movie_clip_tag(cmd).await
         };
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+            ),
+            "{:?}",
+            res
+        );
+    });
+}
+
+#[rstest]
+#[test]
+#[serial("clip")]
+fn test_save_movie_clip_server_fields(_teardown: TearDown) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let clip = Faker.fake::<MovieClip>();
+        let clip_info = NewMovieClipInfo::from(clip.clone());
+
+        // id, likeはサーバーが決定する
+        let saved_clip = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await.unwrap()
+        };
+
+        assert_ne!(saved_clip.id(), clip.id());
+        assert_eq!(saved_clip.like(), 0);
+        assert_eq!(NewMovieClipInfo::from(saved_clip.clone()), clip_info);
+
+        // 同じ内容でも別のクリップとして保存される
+        let saved_clip_2 = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await.unwrap()
+        };
+
+        assert_ne!(saved_clip.id(), saved_clip_2.id());
+
+        let res = {
+            let cmd = movie_clip_commands::AllMovieClipsCommand;
+            movie_clip_usecase::all_movie_clips(cmd).await.unwrap()
+        };
+        assert!(res.contains(&saved_clip));
+        assert!(res.contains(&saved_clip_2));
+    });
+}
+
+#[rstest]
+#[test]
+#[serial("clip")]
+fn test_import_movie_clips_sequence(_teardown: TearDown) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let mut clips = (0..10)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+        for (i, clip) in clips.iter_mut().enumerate() {
+            for _ in 0..i {
+                clip.increment_like();
+            }
+        }
+
+        // id, like, create_dateを保ったまま保存される
+        {
+            let cmd = movie_clip_commands::ImportMovieClipsCommand::new(&clips);
+            movie_clip_usecase::import_movie_clips(cmd).await.unwrap();
+        }
+
+        let mut res = {
+            let cmd = movie_clip_commands::AllMovieClipsCommand;
+            movie_clip_usecase::all_movie_clips(cmd).await.unwrap()
+        };
+        res.sort_by_key(|clip| clip.id());
+        clips.sort_by_key(|clip| clip.id());
+        assert_eq!(res, clips);
 
+        // 同じidのクリップは再びインポートできず，一緒にインポートしたクリップも保存されない
+        let mut with_conflicted = (0..3)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+        with_conflicted.push(clips[0].clone());
+        let res = {
+            let cmd = movie_clip_commands::ImportMovieClipsCommand::new(&with_conflicted);
+            movie_clip_usecase::import_movie_clips(cmd).await
+        };
         assert!(
             matches!(
                 res,
                 Err(AppFrontError::CommonError(AppCommonError::ConflictError))
             ),
             "{:?}",
-            res.unwrap_err()
+            res
         );
+
+        let mut res = {
+            let cmd = movie_clip_commands::AllMovieClipsCommand;
+            movie_clip_usecase::all_movie_clips(cmd).await.unwrap()
+        };
+        res.sort_by_key(|clip| clip.id());
+        assert_eq!(res, clips);
     });
 }
 
@@ -357,29 +603,42 @@ fn test_edit_clip_failed(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let clip = Faker.fake::<MovieClip>();
+        let clip_info = NewMovieClipInfo::from(Faker.fake::<MovieClip>());
+
+        let clip = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await.unwrap()
+        };
 
+        // 最初は成功する
         let res = {
-            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip);
-            movie_clip_usecase::save_movie_clip(cmd).await
+            let cmd = movie_clip_commands::EditMovieClipCommand::new(&clip);
+            movie_clip_usecase::edit_movie_clip(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
-        // 最初は成功する
+        // 同じ版数からの編集は他の編集によって失敗する
         let res = {
             let cmd = movie_clip_commands::EditMovieClipCommand::new(&clip);
             movie_clip_usecase::edit_movie_clip(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::StaleVersion))
+            ),
+            "{:?}",
+            res.unwrap_err()
+        );
 
         let res = {
             let cmd = movie_clip_commands::RemoveMovieClipCommand::new(clip.id());
             movie_clip_usecase::remove_movie_clip(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 削除した後は失敗する
         let res = {
@@ -407,22 +666,20 @@ fn test_remove_movie_clip_failed(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let clip = Faker.fake::<MovieClip>();
+        let clip_info = NewMovieClipInfo::from(Faker.fake::<MovieClip>());
 
-        let res = {
-            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip);
-            movie_clip_usecase::save_movie_clip(cmd).await
+        let clip = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await.unwrap()
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
-
         // 最初は成功する
         let res = {
             let cmd = movie_clip_commands::RemoveMovieClipCommand::new(clip.id());
             movie_clip_usecase::remove_movie_clip(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 次は失敗する
         let res = {
@@ -0,0 +1,144 @@
+mod common;
+
+use domain::movie_clip::MovieClip;
+use domain::quiz::Quiz;
+use frontend::{
+    commands::{auth_commands, movie_clip_commands, quiz_commands},
+    usecases::{auth_usecase, movie_clip_usecase, quiz_usecase},
+    AppCommonError, AppFrontError, NewMovieClipInfo, QuizAnswer,
+};
+
+use fake::{Fake, Faker};
+use serial_test::serial;
+use std::collections::HashMap;
+
+#[test]
+#[serial("auth")]
+fn test_quiz_sequence() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    // 管理者としてクイズを登録
+    common::login_as_admin();
+
+    let quiz = Quiz::new(
+        "integration test question".to_string(),
+        vec!["answer".to_string()],
+    )
+    .unwrap();
+
+    let answers_map = rt.block_on(async {
+        {
+            let cmd = quiz_commands::SaveQuizCommand::new(&quiz);
+            quiz_usecase::save_quiz(cmd).await.unwrap();
+        }
+        let answers_map = {
+            let cmd = quiz_commands::AllQuizzesCommand;
+            quiz_usecase::all_quizzes(cmd)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|quiz| (quiz.question().to_string(), quiz.answers()[0].clone()))
+                .collect::<HashMap<_, _>>()
+        };
+        {
+            let cmd = auth_commands::LogoutCommand;
+            auth_usecase::logout(cmd).await.unwrap();
+        }
+        answers_map
+    });
+
+    let clip_info = NewMovieClipInfo::from(Faker.fake::<MovieClip>());
+    let clip = rt.block_on(async {
+        // クイズに正解する前は追加できない
+        let res = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::UnauthorizedError
+            ))
+        ));
+
+        // クイズの一覧は管理者のみ
+        let res = {
+            let cmd = quiz_commands::AllQuizzesCommand;
+            quiz_usecase::all_quizzes(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::UnauthorizedError
+            ))
+        ));
+
+        let quiz_question = {
+            let cmd = quiz_commands::QuestionCommand;
+            quiz_usecase::question(cmd).await.unwrap()
+        };
+
+        // 不正解の場合
+        let res = {
+            let quiz_answer = QuizAnswer::new(&quiz_question.challenge_id, "wrong answer");
+            let cmd = quiz_commands::AnswerCommand::new(&quiz_answer);
+            quiz_usecase::answer(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::IncorrectAnswerError
+            ))
+        ));
+
+        // 正解の場合
+        {
+            let answer = answers_map.get(&quiz_question.question).unwrap();
+            let quiz_answer = QuizAnswer::new(&quiz_question.challenge_id, answer);
+            let cmd = quiz_commands::AnswerCommand::new(&quiz_answer);
+            quiz_usecase::answer(cmd).await.unwrap();
+        }
+
+        // 正解した後は追加・編集ができる
+        let clip = {
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await.unwrap()
+        };
+        {
+            let cmd = movie_clip_commands::EditMovieClipCommand::new(&clip);
+            movie_clip_usecase::edit_movie_clip(cmd).await.unwrap();
+        }
+
+        // 削除はできない
+        let res = {
+            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(clip.id());
+            movie_clip_usecase::remove_movie_clip(cmd).await
+        };
+        assert!(matches!(
+            res,
+            Err(AppFrontError::CommonError(
+                AppCommonError::UnauthorizedError
+            ))
+        ));
+
+        clip
+    });
+
+    // 後片付けのため管理者でログインし直す
+    common::login_as_admin();
+
+    rt.block_on(async {
+        {
+            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(clip.id());
+            movie_clip_usecase::remove_movie_clip(cmd).await.unwrap();
+        }
+        {
+            let cmd = quiz_commands::RemoveQuizCommand::new(quiz.id());
+            quiz_usecase::remove_quiz(cmd).await.unwrap();
+        }
+        {
+            let cmd = auth_commands::LogoutCommand;
+            auth_usecase::logout(cmd).await.unwrap();
+        }
+    });
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use domain::episode::Episode;
+use domain::revision::{Actor, EntityKind, Operation};
+use frontend::commands::{episode_commands, revision_commands};
+use frontend::usecases::{episode_usecase, revision_usecase};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+use serial_test::serial;
+
+struct TearDown;
+
+#[allow(unused_must_use)]
+impl Drop for TearDown {
+    fn drop(&mut self) {
+        let rt = tokio::runtime::Runtime::new();
+
+        if let Ok(rt) = rt {
+            rt.block_on(async move {
+                let all_episodes = {
+                    let cmd = episode_commands::AllEpisodesCommand;
+                    episode_usecase::all_episodes(cmd).await
+                };
+
+                if let Ok(all_episodes) = all_episodes {
+                    for episode in all_episodes.into_iter() {
+                        let cmd = episode_commands::RemoveEpisodeCommand::new(episode.id());
+                        episode_usecase::remove_episode(cmd).await;
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// teardownの関数(事前に管理者としてログインする)
+#[fixture]
+fn teardown() -> TearDown {
+    common::login_as_admin();
+    TearDown
+}
+
+#[rstest]
+#[test]
+#[serial("episode")]
+fn test_revision_sequence(_teardown: TearDown) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let original = Faker.fake::<Episode>();
+        let entity_id = original.id().to_uuid();
+
+        // 保存と編集
+        {
+            let cmd = episode_commands::SaveEpisodeCommand::new(&original);
+            episode_usecase::save_episode(cmd).await.unwrap();
+        }
+        let mut edited = original.clone();
+        edited.assign(Faker.fake());
+        {
+            let cmd = episode_commands::EditEpisodeCommand::new(&edited);
+            episode_usecase::edit_episode(cmd).await.unwrap();
+        }
+        edited.increment_version();
+
+        // 最近の編集履歴は新しい順
+        let latest = {
+            let cmd = revision_commands::LatestRevisionsCommand::new(EntityKind::Episode, 2);
+            revision_usecase::latest_revisions(cmd).await.unwrap()
+        };
+        assert_eq!(
+            latest
+                .iter()
+                .map(|revision| (revision.entity_id(), revision.operation()))
+                .collect::<Vec<_>>(),
+            vec![(entity_id, Operation::Edit), (entity_id, Operation::Save)]
+        );
+        assert!(latest
+            .iter()
+            .all(|revision| revision.actor() == Actor::Admin));
+
+        // エンティティごとの編集履歴は古い順
+        let revisions = {
+            let cmd =
+                revision_commands::EntityRevisionsCommand::new(EntityKind::Episode, entity_id);
+            revision_usecase::entity_revisions(cmd).await.unwrap()
+        };
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].snapshot_as::<Episode>().unwrap(), original);
+        assert_eq!(revisions[1].snapshot_as::<Episode>().unwrap(), edited);
+
+        let changes = {
+            let cmd = revision_commands::RevisionDiffCommand::new(
+                EntityKind::Episode,
+                revisions[0].id(),
+                revisions[1].id(),
+            );
+            revision_usecase::revision_diff(cmd).await.unwrap()
+        };
+        assert!(changes.iter().any(|change| change.field == "version"));
+
+        // 保存時の内容に戻す
+        {
+            let cmd = revision_commands::RestoreRevisionCommand::new(
+                EntityKind::Episode,
+                revisions[0].id(),
+            );
+            revision_usecase::restore_revision(cmd).await.unwrap();
+        }
+        let mut restored = edited.clone();
+        restored.assign(original.clone());
+        restored.increment_version();
+
+        let res = {
+            let cmd = episode_commands::AllEpisodesCommand;
+            episode_usecase::all_episodes(cmd).await.unwrap()
+        };
+        assert_eq!(res, vec![restored.clone()]);
+
+        // ゴミ箱にあるものは戻してから編集する
+        {
+            let cmd = episode_commands::RemoveEpisodeCommand::new(restored.id());
+            episode_usecase::remove_episode(cmd).await.unwrap();
+        }
+        let revisions = {
+            let cmd =
+                revision_commands::EntityRevisionsCommand::new(EntityKind::Episode, entity_id);
+            revision_usecase::entity_revisions(cmd).await.unwrap()
+        };
+        assert_eq!(revisions.len(), 4);
+        assert_eq!(revisions[3].operation(), Operation::Remove);
+        {
+            let cmd = revision_commands::RestoreRevisionCommand::new(
+                EntityKind::Episode,
+                revisions[3].id(),
+            );
+            revision_usecase::restore_revision(cmd).await.unwrap();
+        }
+        restored.increment_version();
+
+        let res = {
+            let cmd = episode_commands::AllEpisodesCommand;
+            episode_usecase::all_episodes(cmd).await.unwrap()
+        };
+        assert_eq!(res, vec![restored]);
+    });
+}
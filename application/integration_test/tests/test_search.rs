@@ -0,0 +1,181 @@
+mod common;
+
+use domain::channel::Channel;
+use domain::episode::Episode;
+use domain::movie_clip::MovieClip;
+use domain::search::{SearchItem, SearchQuery};
+use domain::video::{Kirinuki, Original, Video};
+use frontend::{
+    commands::{
+        channel_commands, episode_commands, movie_clip_commands, search_commands, video_commands,
+    },
+    usecases::{
+        channel_usecase, episode_usecase, movie_clip_usecase, search_usecase, video_usecase,
+    },
+    NewMovieClipInfo, NewVideoInfo,
+};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+use serial_test::serial;
+
+/// 他のテストのデータと一致しない検索語
+const SEARCH_TERM: &str = "めいたん検索";
+
+struct TearDown;
+
+#[allow(unused_must_use)]
+impl Drop for TearDown {
+    fn drop(&mut self) {
+        let rt = tokio::runtime::Runtime::new();
+
+        if let Ok(rt) = rt {
+            rt.block_on(async move {
+                // 検索に一致するものだけを削除する
+                let query = SearchQuery::new(SEARCH_TERM).unwrap();
+                let hits = {
+                    let cmd = search_commands::SearchCommand::new(&query, 100);
+                    search_usecase::search(cmd).await
+                };
+
+                if let Ok(hits) = hits {
+                    for hit in hits.into_iter() {
+                        match hit.item {
+                            SearchItem::MovieClip(movie_clip) => {
+                                let cmd = movie_clip_commands::RemoveMovieClipCommand::new(
+                                    movie_clip.id(),
+                                );
+                                movie_clip_usecase::remove_movie_clip(cmd).await;
+                            }
+                            SearchItem::Original(video) => {
+                                let cmd = video_commands::RemoveVideoCommand::new(video.id());
+                                video_usecase::remove_video::<Original>(cmd).await;
+                                let cmd = video_commands::PurgeVideoCommand::new(video.id());
+                                video_usecase::purge_video::<Original>(cmd).await;
+                            }
+                            SearchItem::Kirinuki(video) => {
+                                let cmd = video_commands::RemoveVideoCommand::new(video.id());
+                                video_usecase::remove_video::<Kirinuki>(cmd).await;
+                                let cmd = video_commands::PurgeVideoCommand::new(video.id());
+                                video_usecase::purge_video::<Kirinuki>(cmd).await;
+                            }
+                            SearchItem::Episode(episode) => {
+                                let cmd = episode_commands::RemoveEpisodeCommand::new(episode.id());
+                                episode_usecase::remove_episode(cmd).await;
+                            }
+                        }
+                    }
+                }
+
+                // 動画の紐付いていないチャンネルを削除する
+                let all_channels = {
+                    let cmd = channel_commands::AllChannelsCommand;
+                    channel_usecase::all_channels(cmd).await
+                };
+
+                if let Ok(all_channels) = all_channels {
+                    for channel in all_channels.into_iter() {
+                        let cmd = channel_commands::RemoveChannelCommand::new(channel.id());
+                        channel_usecase::remove_channel(cmd).await;
+                    }
+                }
+            });
+        }
+    }
+}
+
+#[fixture]
+fn teardown() -> TearDown {
+    common::login_as_admin();
+    TearDown
+}
+
+#[rstest]
+#[test]
+#[serial("clip", "episode", "video")]
+fn test_search_sequence(_teardown: TearDown) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        // 種類ごとに検索語を含むものを保存する
+        let mut movie_clip = Faker.fake::<MovieClip>();
+        *movie_clip.title_mut() = SEARCH_TERM.to_string();
+        let movie_clip = {
+            let movie_clip_info = NewMovieClipInfo::from(movie_clip);
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(&movie_clip_info);
+            movie_clip_usecase::save_movie_clip(cmd).await.unwrap()
+        };
+
+        // 投稿者名はチャンネルの表示名となる
+        let channel = Faker.fake::<Channel>();
+        let search_term_channel = Channel::new(
+            format!("{SEARCH_TERM}の切り抜き").parse().unwrap(),
+            None,
+            None,
+        );
+        for channel in [&channel, &search_term_channel] {
+            let cmd = channel_commands::SaveChannelCommand::new(channel);
+            channel_usecase::save_channel(cmd).await.unwrap();
+        }
+
+        let mut original = Faker.fake::<Video<Original>>();
+        *original.title_mut() = format!("{SEARCH_TERM}の歌枠");
+        original.set_channel(&channel);
+        let original = {
+            let video_info = NewVideoInfo::from(original);
+            let cmd = video_commands::SaveVideoCommand::new(&video_info);
+            video_usecase::save_video::<Original>(cmd).await.unwrap()
+        };
+
+        let mut kirinuki = Faker.fake::<Video<Kirinuki>>();
+        kirinuki.set_channel(&search_term_channel);
+        let kirinuki = {
+            let video_info = NewVideoInfo::from(kirinuki);
+            let cmd = video_commands::SaveVideoCommand::new(&video_info);
+            video_usecase::save_video::<Kirinuki>(cmd).await.unwrap()
+        };
+
+        let mut episode = Faker.fake::<Episode>();
+        *episode.content_mut() = format!("<p>{SEARCH_TERM}のまとめ</p>").parse().unwrap();
+        {
+            let cmd = episode_commands::SaveEpisodeCommand::new(&episode);
+            episode_usecase::save_episode(cmd).await.unwrap();
+        }
+
+        // 種類に関わらずスコアの降順
+        let query = SearchQuery::new(SEARCH_TERM).unwrap();
+        let hits = {
+            let cmd = search_commands::SearchCommand::new(&query, 100);
+            search_usecase::search(cmd).await.unwrap()
+        };
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+        assert_eq!(hits.len(), 4);
+
+        // 検索語のみのタイトルが最も適合する
+        assert_eq!(hits[0].item, SearchItem::MovieClip(movie_clip));
+        for item in [
+            SearchItem::Original(original),
+            SearchItem::Kirinuki(kirinuki),
+            SearchItem::Episode(episode),
+        ] {
+            assert!(hits.iter().any(|hit| hit.item == item));
+        }
+
+        // lengthを超えない
+        let hits = {
+            let cmd = search_commands::SearchCommand::new(&query, 2);
+            search_usecase::search(cmd).await.unwrap()
+        };
+        assert_eq!(hits.len(), 2);
+
+        // 全ての語を含むものに一致する
+        let query = SearchQuery::new(&format!("{SEARCH_TERM} 歌枠")).unwrap();
+        let hits = {
+            let cmd = search_commands::SearchCommand::new(&query, 100);
+            search_usecase::search(cmd).await.unwrap()
+        };
+        assert_eq!(hits.len(), 1);
+        assert!(matches!(hits[0].item, SearchItem::Original(_)));
+    });
+}
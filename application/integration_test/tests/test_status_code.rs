@@ -0,0 +1,189 @@
+mod common;
+
+use config::CONFIG;
+use domain::episode::Episode;
+use domain::movie_clip::{MovieClip, MovieClipId};
+use domain::user::Role;
+use domain::{ValidationCode, ValidationError};
+use frontend::{AppCommonError, NewMovieClipInfo, NewUserInfo};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, Response, StatusCode};
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn api_url(path: &str) -> String {
+    format!("{}{}", CONFIG.test_api_domain, path)
+}
+
+/// 管理者としてログインしたクライアント
+async fn admin_client() -> Client {
+    let client = Client::builder().cookie_store(true).build().unwrap();
+
+    let login_info = HashMap::from([("password", common::admin_password())]);
+    let response = client
+        .post(api_url("/auth/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    client
+}
+
+/// ステータスコードを確認し，ボディのエラーを返す．
+async fn assert_status(response: Response, status_code: StatusCode) -> AppCommonError {
+    assert_eq!(response.status(), status_code);
+    response.json::<AppCommonError>().await.unwrap()
+}
+
+#[test]
+#[serial("clip")]
+fn test_no_record_status_code() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let client = admin_client().await;
+
+        // 存在しないクリップの編集
+        let response = client
+            .patch(api_url("/movie_clip"))
+            .json(&Faker.fake::<MovieClip>())
+            .send()
+            .await
+            .unwrap();
+        let err = assert_status(response, StatusCode::NOT_FOUND).await;
+        assert!(matches!(err, AppCommonError::NoRecordError));
+
+        // 存在しないクリップの削除
+        let response = client
+            .delete(api_url(&format!("/movie_clip/{}", MovieClipId::generate())))
+            .send()
+            .await
+            .unwrap();
+        let err = assert_status(response, StatusCode::NOT_FOUND).await;
+        assert!(matches!(err, AppCommonError::NoRecordError));
+    });
+}
+
+#[test]
+#[serial("episode")]
+fn test_conflict_status_code() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let client = admin_client().await;
+        let episode = Faker.fake::<Episode>();
+
+        let response = client
+            .put(api_url("/episode"))
+            .json(&episode)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // 同じidのエピソードの保存
+        let response = client
+            .put(api_url("/episode"))
+            .json(&episode)
+            .send()
+            .await
+            .unwrap();
+        let err = assert_status(response, StatusCode::CONFLICT).await;
+        assert!(matches!(err, AppCommonError::ConflictError));
+
+        let response = client
+            .delete(api_url(&format!("/episode/{}", episode.id())))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    });
+}
+
+#[test]
+#[serial("user")]
+fn test_domain_error_status_code() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let client = admin_client().await;
+
+        // 空のユーザー名
+        let user_info = NewUserInfo::new(" ", "password", Role::Contributor);
+        let response = client
+            .put(api_url("/user"))
+            .json(&user_info)
+            .send()
+            .await
+            .unwrap();
+        let err = assert_status(response, StatusCode::UNPROCESSABLE_ENTITY).await;
+        assert!(matches!(err, AppCommonError::DomainError(_)));
+    });
+}
+
+#[test]
+#[serial("clip")]
+fn test_validation_error_status_code() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let client = admin_client().await;
+
+        // 終了時間が開始時間より前のクリップ
+        let movie_clip_info = NewMovieClipInfo::from(Faker.fake::<MovieClip>());
+        let mut movie_clip_info_json = serde_json::to_value(&movie_clip_info).unwrap();
+        movie_clip_info_json["range"] = serde_json::json!({"start": 100, "end": 50});
+
+        let response = client
+            .put(api_url("/movie_clip"))
+            .json(&movie_clip_info_json)
+            .send()
+            .await
+            .unwrap();
+        let err = assert_status(response, StatusCode::UNPROCESSABLE_ENTITY).await;
+        let AppCommonError::ValidationError(validation_error) = err else {
+            panic!("Expected validation error: {err:?}");
+        };
+        assert_eq!(
+            validation_error,
+            ValidationError::new(ValidationCode::EndBeforeStart, "range.end")
+                .with_param("start", 100)
+        );
+
+        // 0以下のページサイズ
+        let response = client
+            .get(api_url("/movie_clip/query?sort_type=like&length=0"))
+            .send()
+            .await
+            .unwrap();
+        let err = assert_status(response, StatusCode::UNPROCESSABLE_ENTITY).await;
+        let AppCommonError::ValidationError(validation_error) = err else {
+            panic!("Expected validation error: {err:?}");
+        };
+        assert_eq!(
+            validation_error,
+            ValidationError::new(ValidationCode::TooSmall, "length").with_param("min", 1)
+        );
+
+        // 長すぎる検索文字列
+        let too_long_query = "a".repeat(CONFIG.search_query_lim + 1);
+        let response = client
+            .get(api_url(&format!("/search?query={too_long_query}")))
+            .send()
+            .await
+            .unwrap();
+        let err = assert_status(response, StatusCode::UNPROCESSABLE_ENTITY).await;
+        let AppCommonError::ValidationError(validation_error) = err else {
+            panic!("Expected validation error: {err:?}");
+        };
+        assert_eq!(
+            validation_error,
+            ValidationError::new(ValidationCode::TooLong, "query")
+                .with_param("limit", CONFIG.search_query_lim)
+        );
+    });
+}
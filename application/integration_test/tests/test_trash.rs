@@ -0,0 +1,144 @@
+mod common;
+
+use domain::episode::Episode;
+use frontend::{
+    commands::episode_commands, usecases::episode_usecase, AppCommonError, AppFrontError,
+};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use rstest::{fixture, rstest};
+use serial_test::serial;
+
+struct TearDown;
+
+#[allow(unused_must_use)]
+impl Drop for TearDown {
+    fn drop(&mut self) {
+        let rt = tokio::runtime::Runtime::new();
+
+        if let Ok(rt) = rt {
+            rt.block_on(async move {
+                let all_episodes = {
+                    let cmd = episode_commands::AllEpisodesCommand;
+                    episode_usecase::all_episodes(cmd).await
+                };
+
+                if let Ok(all_episodes) = all_episodes {
+                    for episode in all_episodes.into_iter() {
+                        let cmd = episode_commands::RemoveEpisodeCommand::new(episode.id());
+                        episode_usecase::remove_episode(cmd).await;
+                    }
+                }
+
+                let trashed_episodes = {
+                    let cmd = episode_commands::TrashedEpisodesCommand;
+                    episode_usecase::trashed_episodes(cmd).await
+                };
+
+                if let Ok(trashed_episodes) = trashed_episodes {
+                    for episode in trashed_episodes.into_iter() {
+                        let cmd = episode_commands::PurgeEpisodeCommand::new(episode.id());
+                        episode_usecase::purge_episode(cmd).await;
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// teardownの関数(事前に管理者としてログインする)
+#[fixture]
+fn teardown() -> TearDown {
+    common::login_as_admin();
+    TearDown
+}
+
+#[rstest]
+#[test]
+#[serial("episode")]
+fn test_trash_sequence(_teardown: TearDown) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async move {
+        let episodes = (0..3).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>();
+
+        for episode in episodes.iter() {
+            let cmd = episode_commands::SaveEpisodeCommand::new(episode);
+            episode_usecase::save_episode(cmd).await.unwrap();
+        }
+
+        // 二つをゴミ箱に移す
+        for episode in episodes[..2].iter() {
+            let cmd = episode_commands::RemoveEpisodeCommand::new(episode.id());
+            episode_usecase::remove_episode(cmd).await.unwrap();
+        }
+
+        let res = {
+            let cmd = episode_commands::AllEpisodesCommand;
+            episode_usecase::all_episodes(cmd).await.unwrap()
+        };
+        assert_eq!(res, vec![episodes[2].clone()]);
+
+        let trashed_ids = {
+            let cmd = episode_commands::TrashedEpisodesCommand;
+            episode_usecase::trashed_episodes(cmd)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|episode| episode.id())
+                .collect::<Vec<_>>()
+        };
+        assert!(episodes[..2]
+            .iter()
+            .all(|episode| trashed_ids.contains(&episode.id())));
+
+        // ゴミ箱から戻す
+        {
+            let cmd = episode_commands::RestoreTrashedEpisodeCommand::new(episodes[0].id());
+            episode_usecase::restore_trashed_episode(cmd).await.unwrap();
+        }
+
+        // 完全に削除する
+        {
+            let cmd = episode_commands::PurgeEpisodeCommand::new(episodes[1].id());
+            episode_usecase::purge_episode(cmd).await.unwrap();
+        }
+
+        let mut res = {
+            let cmd = episode_commands::AllEpisodesCommand;
+            episode_usecase::all_episodes(cmd).await.unwrap()
+        };
+        res.sort_by_key(|episode| episode.id());
+        let mut expected = vec![episodes[0].clone(), episodes[2].clone()];
+        expected.sort_by_key(|episode| episode.id());
+        assert_eq!(res, expected);
+
+        let trashed_ids = {
+            let cmd = episode_commands::TrashedEpisodesCommand;
+            episode_usecase::trashed_episodes(cmd)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|episode| episode.id())
+                .collect::<Vec<_>>()
+        };
+        assert!(episodes
+            .iter()
+            .all(|episode| !trashed_ids.contains(&episode.id())));
+
+        // ゴミ箱にないものは完全に削除できない
+        let res = {
+            let cmd = episode_commands::PurgeEpisodeCommand::new(episodes[2].id());
+            episode_usecase::purge_episode(cmd).await
+        };
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::NoRecordError))
+            ),
+            "{:?}",
+            res
+        );
+    });
+}
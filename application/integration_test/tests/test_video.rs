@@ -1,18 +1,63 @@
-use domain::video::{Original, Video};
-use frontend::{commands::video_commands, usecases::video_usecase, AppCommonError, AppFrontError};
+mod common;
+
+use domain::channel::Channel;
+use domain::video::{Original, Video, VideoId};
+use frontend::{
+    commands::{channel_commands, video_commands},
+    usecases::{channel_usecase, video_usecase},
+    AppCommonError, AppFrontError, NewVideoInfo,
+};
 
 use fake::{Fake, Faker};
-use pretty_assertions::assert_eq;
+use pretty_assertions::{assert_eq, assert_ne};
 use rand::Rng;
 use rand::{seq::SliceRandom, thread_rng};
 use rstest::{fixture, rstest};
 use serial_test::serial;
 use std::cmp::Ordering;
 
-async fn all_save(videos: &[Video<Original>]) -> Result<(), AppFrontError> {
+/// 動画を投稿するチャンネルを保存して返す．
+async fn save_channel() -> Result<Channel, AppFrontError> {
+    let channel = Faker.fake::<Channel>();
+    let cmd = channel_commands::SaveChannelCommand::new(&channel);
+    channel_usecase::save_channel(cmd).await?;
+    Ok(channel)
+}
+
+/// `channel`の動画として`video`の保存に用いる情報を作る．
+fn video_info_of(video: &Video<Original>, channel: &Channel) -> NewVideoInfo {
+    NewVideoInfo {
+        channel_id: channel.id(),
+        ..NewVideoInfo::from(video.clone())
+    }
+}
+
+/// 全てのVideoを`channel`の動画として保存し，サーバーが返したVideoを返す．
+async fn all_save(
+    videos: &[Video<Original>],
+    channel: &Channel,
+) -> Result<Vec<Video<Original>>, AppFrontError> {
+    let mut saved_videos = Vec::new();
     for video in videos.iter() {
-        let cmd = video_commands::SaveVideoCommand::new(video);
-        video_usecase::save_video(cmd).await?
+        let video_info = video_info_of(video, channel);
+        let cmd = video_commands::SaveVideoCommand::new(&video_info);
+        saved_videos.push(video_usecase::save_video::<Original>(cmd).await?);
+    }
+    Ok(saved_videos)
+}
+
+/// 保存したVideoのLikeをランダムに増やす．同じクライアントのLikeは一度だけ数えられる．
+async fn random_increment_like(videos: &mut [Video<Original>]) -> Result<(), AppFrontError> {
+    for video in videos.iter_mut() {
+        let like_number = thread_rng().gen_range(0..5);
+        if like_number > 0 {
+            video.increment_like();
+        }
+
+        for _ in 0..like_number {
+            let cmd = video_commands::IncrementLikeVideoCommand::new(video.id());
+            video_usecase::increment_like_video::<Original>(cmd).await?;
+        }
     }
     Ok(())
 }
@@ -35,6 +80,21 @@ impl Drop for TearDown {
                     for video in all_videos.into_iter() {
                         let cmd = video_commands::RemoveVideoCommand::new(video.id());
                         video_usecase::remove_video::<Original>(cmd).await;
+                        let cmd = video_commands::PurgeVideoCommand::new(video.id());
+                        video_usecase::purge_video::<Original>(cmd).await;
+                    }
+                }
+
+                // 動画を完全に削除した後にチャンネルを削除する
+                let all_channels = {
+                    let cmd = channel_commands::AllChannelsCommand;
+                    channel_usecase::all_channels(cmd).await
+                };
+
+                if let Ok(all_channels) = all_channels {
+                    for channel in all_channels.into_iter() {
+                        let cmd = channel_commands::RemoveChannelCommand::new(channel.id());
+                        channel_usecase::remove_channel(cmd).await;
                     }
                 }
             });
@@ -44,6 +104,7 @@ impl Drop for TearDown {
 
 #[fixture]
 fn teardown() -> TearDown {
+    common::login_as_admin();
     TearDown
 }
 
@@ -54,11 +115,12 @@ fn test_edit_video_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut videos = (0..100)
+        let videos = (0..100)
             .map(|_| Faker.fake::<Video<Original>>())
             .collect::<Vec<_>>();
 
-        all_save(&videos).await.unwrap();
+        let channel = save_channel().await.unwrap();
+        let mut videos = all_save(&videos, &channel).await.unwrap();
 
         // 変更するVideo<Original>sのインデックス
         let mut edit_indices = (0..videos.len()).collect::<Vec<_>>();
@@ -68,12 +130,14 @@ fn test_edit_video_sequence(_teardown: TearDown) {
         // videosの一部を変更．
         for i in edit_indices.into_iter().take(edit_number) {
             let video = videos.get_mut(i).unwrap();
-            let new_video = Faker.fake::<Video<Original>>();
+            let mut new_video = Faker.fake::<Video<Original>>();
+            new_video.set_channel(&channel);
 
             video.assign(new_video);
 
             let cmd = video_commands::EditVideoCommand::new(video);
             video_usecase::edit_video(cmd).await.unwrap();
+            video.increment_version();
         }
 
         // 取得したものと比較
@@ -96,21 +160,25 @@ fn test_increment_like_video_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut videos = (0..10)
+        let videos = (0..10)
             .map(|_| Faker.fake::<Video<Original>>())
             .collect::<Vec<_>>();
 
-        all_save(&videos).await.unwrap();
+        let channel = save_channel().await.unwrap();
+        let mut videos = all_save(&videos, &channel).await.unwrap();
 
         let like_lim = 10_usize;
         let like_numbers = (0..videos.len())
             .map(|_| thread_rng().gen_range(0..like_lim))
             .collect::<Vec<_>>();
 
+        // 同じクライアントが何度Likeしても一度だけ数えられる
         for (video, like_number) in videos.iter_mut().zip(like_numbers.iter()) {
-            for _ in 0..*like_number {
+            if *like_number > 0 {
                 video.increment_like();
+            }
 
+            for _ in 0..*like_number {
                 let cmd = video_commands::IncrementLikeVideoCommand::new(video.id());
                 video_usecase::increment_like_video::<Original>(cmd)
                     .await
@@ -128,6 +196,48 @@ fn test_increment_like_video_sequence(_teardown: TearDown) {
         videos.sort_by_key(|video| video.id());
 
         assert_eq!(res, videos);
+
+        // LikeしたVideoのid
+        let ids = videos.iter().map(|video| video.id()).collect::<Vec<_>>();
+        let liked_ids = videos
+            .iter()
+            .filter(|video| video.like() > 0)
+            .map(|video| video.id())
+            .collect::<Vec<_>>();
+
+        let res_liked_ids = {
+            let cmd = video_commands::LikedVideoIdsCommand::new(&ids);
+            video_usecase::liked_video_ids::<Original>(cmd)
+                .await
+                .unwrap()
+        };
+        assert_eq!(res_liked_ids, liked_ids);
+
+        // Likeを取り消す．二度目は何もしない
+        for video in videos.iter_mut() {
+            video.decrement_like();
+
+            for _ in 0..2 {
+                let cmd = video_commands::UnlikeVideoCommand::new(video.id());
+                video_usecase::unlike_video::<Original>(cmd).await.unwrap();
+            }
+        }
+
+        let mut res = {
+            let cmd = video_commands::AllVideosCommand;
+            video_usecase::all_videos::<Original>(cmd).await.unwrap()
+        };
+        res.sort_by_key(|video| video.id());
+
+        assert_eq!(res, videos);
+
+        let res_liked_ids = {
+            let cmd = video_commands::LikedVideoIdsCommand::new(&ids);
+            video_usecase::liked_video_ids::<Original>(cmd)
+                .await
+                .unwrap()
+        };
+        assert_eq!(res_liked_ids, Vec::<VideoId>::new());
     });
 }
 
@@ -138,17 +248,22 @@ fn test_order_by_like_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut videos = (0..100)
+        let videos = (0..100)
             .map(|_| Faker.fake::<Video<Original>>())
             .collect::<Vec<_>>();
 
-        all_save(&videos).await.unwrap();
+        let channel = save_channel().await.unwrap();
+        let mut videos = all_save(&videos, &channel).await.unwrap();
+        random_increment_like(&mut videos).await.unwrap();
 
         let length = 5_usize;
 
         let mut res = {
-            let cmd = video_commands::OrderByLikeVideosCommand::new(length);
-            video_usecase::order_by_like_videos(cmd).await.unwrap()
+            let cmd = video_commands::OrderByLikeVideosCommand::new(&[], length);
+            video_usecase::order_by_like_videos(cmd)
+                .await
+                .unwrap()
+                .items
         };
 
         // Likeが同じ場合はidで昇順．
@@ -175,20 +290,35 @@ fn test_order_by_like_later_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut videos = (0..100)
+        let videos = (0..100)
             .map(|_| Faker.fake::<Video<Original>>())
             .collect::<Vec<_>>();
 
-        all_save(&videos).await.unwrap();
+        let channel = save_channel().await.unwrap();
+        let mut videos = all_save(&videos, &channel).await.unwrap();
+        random_increment_like(&mut videos).await.unwrap();
 
         let length = 20_usize;
-        let reference = videos.choose(&mut thread_rng()).unwrap().clone();
+
+        // 最初のページの最後のビデオ以降を取得する
+        let first_page = {
+            let cmd = video_commands::OrderByLikeVideosCommand::new(&[], length);
+            video_usecase::order_by_like_videos::<Original>(cmd)
+                .await
+                .unwrap()
+        };
+        let reference = first_page.items.last().unwrap().clone();
 
         let mut res = {
-            let cmd = video_commands::OrderByLikeLaterVideosCommand::new(&reference, length);
-            video_usecase::order_by_like_later_videos(cmd)
+            let cmd = video_commands::OrderByLikeLaterVideosCommand::new(
+                &[],
+                first_page.next_cursor.as_deref().unwrap(),
+                length,
+            );
+            video_usecase::order_by_like_later_videos::<Original>(cmd)
                 .await
                 .unwrap()
+                .items
         };
 
         // Likeが同じ場合はidで昇順．
@@ -222,17 +352,21 @@ fn test_order_by_date_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut videos = (0..100)
+        let videos = (0..100)
             .map(|_| Faker.fake::<Video<Original>>())
             .collect::<Vec<_>>();
 
-        all_save(&videos).await.unwrap();
+        let channel = save_channel().await.unwrap();
+        let mut videos = all_save(&videos, &channel).await.unwrap();
 
         let length = 20_usize;
 
         let mut res = {
-            let cmd = video_commands::OrderByDateVideosCommand::new(length);
-            video_usecase::order_by_date_videos(cmd).await.unwrap()
+            let cmd = video_commands::OrderByDateVideosCommand::new(&[], length);
+            video_usecase::order_by_date_videos(cmd)
+                .await
+                .unwrap()
+                .items
         };
 
         // 作成日時が同じ場合はidで昇順．
@@ -259,20 +393,34 @@ fn test_order_by_date_later_sequence(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let mut videos = (0..100)
+        let videos = (0..100)
             .map(|_| Faker.fake::<Video<Original>>())
             .collect::<Vec<_>>();
 
-        all_save(&videos).await.unwrap();
+        let channel = save_channel().await.unwrap();
+        let mut videos = all_save(&videos, &channel).await.unwrap();
 
         let length = 20_usize;
-        let reference = videos.choose(&mut thread_rng()).unwrap().clone();
+
+        // 最初のページの最後のビデオ以降を取得する
+        let first_page = {
+            let cmd = video_commands::OrderByDateVideosCommand::new(&[], length);
+            video_usecase::order_by_date_videos::<Original>(cmd)
+                .await
+                .unwrap()
+        };
+        let reference = first_page.items.last().unwrap().clone();
 
         let mut res = {
-            let cmd = video_commands::OrderByDateLaterVideosCommand::new(&reference, length);
-            video_usecase::order_by_date_later_videos(cmd)
+            let cmd = video_commands::OrderByDateLaterVideosCommand::new(
+                &[],
+                first_page.next_cursor.as_deref().unwrap(),
+                length,
+            );
+            video_usecase::order_by_date_later_videos::<Original>(cmd)
                 .await
                 .unwrap()
+                .items
         };
 
         // 日時が同じ場合はidで昇順．
@@ -302,34 +450,39 @@ fn test_order_by_date_later_sequence(_teardown: TearDown) {
 #[rstest]
 #[test]
 #[serial("video")]
-fn test_save_video_failed(_teardown: TearDown) {
+fn test_save_video_server_fields(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
+        let channel = save_channel().await.unwrap();
         let video = Faker.fake::<Video<Original>>();
+        let video_info = video_info_of(&video, &channel);
 
-        // 最初は成功する
-        let res = {
-            let cmd = video_commands::SaveVideoCommand::new(&video);
-            video_usecase::save_video(cmd).await
+        // id, likeはサーバーが決定し，投稿者名はチャンネルの表示名となる
+        let saved_video = {
+            let cmd = video_commands::SaveVideoCommand::new(&video_info);
+            video_usecase::save_video::<Original>(cmd).await.unwrap()
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert_ne!(saved_video.id(), video.id());
+        assert_eq!(saved_video.like(), 0);
+        assert_eq!(saved_video.author(), channel.name().as_str());
+        assert_eq!(NewVideoInfo::from(saved_video.clone()), video_info);
 
-        // 二度目は失敗する
-        let res = {
-            let cmd = video_commands::SaveVideoCommand::new(&video);
-            video_usecase::save_video(cmd).await
+        // 同じ内容でも別のVideoとして保存される
+        let saved_video_2 = {
+            let cmd = video_commands::SaveVideoCommand::new(&video_info);
+            video_usecase::save_video::<Original>(cmd).await.unwrap()
         };
 
-        assert!(
-            matches!(
-                res,
-                Err(AppFrontError::CommonError(AppCommonError::ConflictError))
-            ),
-            "{:?}",
-            res.unwrap_err()
-        );
+        assert_ne!(saved_video.id(), saved_video_2.id());
+
+        let res = {
+            let cmd = video_commands::AllVideosCommand;
+            video_usecase::all_videos::<Original>(cmd).await.unwrap()
+        };
+        assert!(res.contains(&saved_video));
+        assert!(res.contains(&saved_video_2));
     });
 }
 
@@ -340,29 +493,43 @@ fn test_edit_video_failed(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let video = Faker.fake::<Video<Original>>();
+        let channel = save_channel().await.unwrap();
+        let video_info = video_info_of(&Faker.fake(), &channel);
 
+        let video = {
+            let cmd = video_commands::SaveVideoCommand::new(&video_info);
+            video_usecase::save_video::<Original>(cmd).await.unwrap()
+        };
+
+        // 最初は成功する
         let res = {
-            let cmd = video_commands::SaveVideoCommand::new(&video);
-            video_usecase::save_video(cmd).await
+            let cmd = video_commands::EditVideoCommand::new(&video);
+            video_usecase::edit_video(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
-        // 最初は成功する
+        // 同じ版数からの編集は他の編集によって失敗する
         let res = {
             let cmd = video_commands::EditVideoCommand::new(&video);
             video_usecase::edit_video(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(
+            matches!(
+                res,
+                Err(AppFrontError::CommonError(AppCommonError::StaleVersion))
+            ),
+            "{:?}",
+            res.unwrap_err()
+        );
 
         let res = {
             let cmd = video_commands::RemoveVideoCommand::new(video.id());
             video_usecase::remove_video::<Original>(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 削除した後は失敗する
         let res = {
@@ -390,22 +557,21 @@ fn test_remove_video_failed(_teardown: TearDown) {
     let rt = tokio::runtime::Runtime::new().unwrap();
 
     rt.block_on(async move {
-        let video = Faker.fake::<Video<Original>>();
+        let channel = save_channel().await.unwrap();
+        let video_info = video_info_of(&Faker.fake(), &channel);
 
-        let res = {
-            let cmd = video_commands::SaveVideoCommand::new(&video);
-            video_usecase::save_video(cmd).await
+        let video = {
+            let cmd = video_commands::SaveVideoCommand::new(&video_info);
+            video_usecase::save_video::<Original>(cmd).await.unwrap()
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
-
         // 最初は成功する
         let res = {
             let cmd = video_commands::RemoveVideoCommand::new(video.id());
             video_usecase::remove_video::<Original>(cmd).await
         };
 
-        assert!(matches!(res, Ok(_)), "{:?}", res.unwrap_err());
+        assert!(res.is_ok(), "{:?}", res.unwrap_err());
 
         // 次は失敗する
         let res = {
@@ -0,0 +1,10 @@
+/// Secrets.tomlの`admin_password_hash`に設定するハッシュを作成する．
+/// `cargo run --example hash_password -- <password>`
+fn main() {
+    let password = std::env::args()
+        .nth(1)
+        .expect("Usage: cargo run --example hash_password -- <password>");
+
+    let hash = serverside::auth::hash_password(&password).expect("Cannot hash password.");
+    println!("{hash}");
+}
@@ -3,18 +3,33 @@ async fn main() {
     use config::CONFIG;
     use domain::video::{Kirinuki, Original};
 
-    use serverside::handlers::{episode_handlers, movie_clip_handlers, video_handlers};
+    use serverside::auth::{self, AuthConfig};
+    use serverside::handlers::{
+        auth_handlers, channel_handlers, clip_series_handlers, episode_handlers,
+        movie_clip_handlers, quiz_handlers, search_handlers, user_handlers, video_handlers,
+        SessionUserRepository,
+    };
+    use serverside::rate_limit::{self, RateLimiter};
 
+    use std::net::SocketAddr;
     use std::path::Path;
     use std::sync::Arc;
 
     use axum::{
         http::StatusCode,
-        routing::{delete, get, get_service, patch, put},
-        Router,
+        middleware,
+        routing::{delete, get, get_service, patch, post, put},
+        Extension, Router,
     };
+    use toml::Table;
     use tower_http::services::ServeDir;
 
+    // Secrets.toml
+    let secret_str = include_str!("../../../Secrets.toml");
+    let secret_table = secret_str
+        .parse::<Table>()
+        .expect("Cannot Read Secrets.toml");
+
     #[cfg(not(feature = "inmemory"))]
     let pool = async {
         use sqlx::postgres::PgPoolOptions;
@@ -47,6 +62,16 @@ async fn main() {
         infrastructure::movie_clip_repository_impl::MovieClipPgDBRepository::new(pool.clone()),
     );
 
+    // clip_series_repo
+    #[cfg(feature = "inmemory")]
+    let clip_series_repo =
+        Arc::new(infrastructure::clip_series_repository_impl::InMemoryClipSeriesRepository::new());
+
+    #[cfg(not(feature = "inmemory"))]
+    let clip_series_repo = Arc::new(
+        infrastructure::clip_series_repository_impl::ClipSeriesPgDBRepository::new(pool.clone()),
+    );
+
     // original_repo
     #[cfg(feature = "inmemory")]
     let original_repo =
@@ -67,6 +92,81 @@ async fn main() {
         infrastructure::video_repository_impl::VideoPgDbRepository::<Kirinuki>::new(pool.clone()),
     );
 
+    // channel_repo(インメモリの場合は動画の数を求め，チャンネル名を投稿者名へ反映するためにVideoのリポジトリを共有する)
+    #[cfg(feature = "inmemory")]
+    let channel_repo = Arc::new(
+        infrastructure::channel_repository_impl::InMemoryChannelRepository::with_videos(
+            (*original_repo).clone(),
+            (*kirinuki_repo).clone(),
+        ),
+    );
+
+    #[cfg(not(feature = "inmemory"))]
+    let channel_repo =
+        Arc::new(infrastructure::channel_repository_impl::ChannelPgDBRepository::new(pool.clone()));
+
+    // user_repo
+    #[cfg(feature = "inmemory")]
+    let user_repo = Arc::new(infrastructure::user_repository_impl::InMemoryUserRepository::new());
+
+    #[cfg(not(feature = "inmemory"))]
+    let user_repo =
+        Arc::new(infrastructure::user_repository_impl::UserPgDBRepository::new(pool.clone()));
+
+    // quiz_repo
+    #[cfg(feature = "inmemory")]
+    let quiz_repo = Arc::new(infrastructure::quiz_repository_impl::InMemoryQuizRepository::new());
+
+    #[cfg(not(feature = "inmemory"))]
+    let quiz_repo =
+        Arc::new(infrastructure::quiz_repository_impl::QuizPgDBRepository::new(pool.clone()));
+
+    // 管理者認証(テストサーバーでは平文のパスワードをハッシュ化して利用する)
+    let admin_password = secret_table
+        .get("admin_password")
+        .expect("Cannot Get admin_password from Secrets.toml")
+        .as_str()
+        .expect("admin_password is invalid type.");
+
+    let session_secret = secret_table
+        .get("session_secret")
+        .expect("Cannot Get session_secret from Secrets.toml")
+        .as_str()
+        .expect("session_secret is invalid type.");
+
+    let auth_config = Arc::new(AuthConfig::new(
+        auth::hash_password(admin_password).expect("Cannot hash admin_password."),
+        session_secret,
+    ));
+    let rate_limiter = Arc::new(RateLimiter::from_config());
+
+    let auth_api_router: Router<()> = Router::new()
+        .route("/auth/login", post(auth_handlers::login))
+        .route("/auth/logout", post(auth_handlers::logout))
+        .route("/auth/client", post(auth_handlers::issue_client))
+        .route("/auth/session", get(auth_handlers::check_session))
+        .with_state(user_repo.clone());
+
+    let user_api_router: Router<()> = Router::new()
+        .route(
+            "/user",
+            put(user_handlers::save_user).get(user_handlers::all_users),
+        )
+        .route("/user/:id", delete(user_handlers::remove_user))
+        .with_state(user_repo.clone());
+
+    let quiz_api_router: Router<()> = Router::new()
+        .route(
+            "/quiz",
+            put(quiz_handlers::save_quiz)
+                .patch(quiz_handlers::edit_quiz)
+                .get(quiz_handlers::all_quizzes),
+        )
+        .route("/quiz/question", get(quiz_handlers::question))
+        .route("/quiz/answer", post(quiz_handlers::answer))
+        .route("/quiz/:id", delete(quiz_handlers::remove_quiz))
+        .with_state(quiz_repo);
+
     let episode_api_router: Router<()> = Router::new()
         .route(
             "/episode",
@@ -79,7 +179,36 @@ async fn main() {
             get(episode_handlers::get_episodes_with_query),
         )
         .route("/episode/:id", delete(episode_handlers::remove_episode))
-        .with_state(episode_repo);
+        .route("/episode/tags", get(episode_handlers::all_episode_tags))
+        .route(
+            "/episode/tags/:id",
+            get(episode_handlers::episode_tags)
+                .put(episode_handlers::add_episode_tag)
+                .delete(episode_handlers::remove_episode_tag),
+        )
+        .route(
+            "/episode/revisions",
+            get(episode_handlers::latest_episode_revisions),
+        )
+        .route(
+            "/episode/revisions/:id",
+            get(episode_handlers::episode_revisions),
+        )
+        .route(
+            "/episode/revision_diff",
+            get(episode_handlers::episode_revision_diff),
+        )
+        .route(
+            "/episode/restore/:id",
+            patch(episode_handlers::restore_episode_revision),
+        )
+        .route("/episode/trash", get(episode_handlers::trashed_episodes))
+        .route(
+            "/episode/trash/:id",
+            patch(episode_handlers::restore_trashed_episode)
+                .delete(episode_handlers::purge_episode),
+        )
+        .with_state(episode_repo.clone());
 
     let movie_clip_api_router: Router<()> = Router::new()
         .route(
@@ -90,8 +219,7 @@ async fn main() {
         )
         .route(
             "/movie_clip/query",
-            get(movie_clip_handlers::get_movie_clips_with_query)
-                .post(movie_clip_handlers::get_movie_clips_with_query),
+            get(movie_clip_handlers::get_movie_clips_with_query),
         )
         .route(
             "/movie_clip/:id",
@@ -101,7 +229,84 @@ async fn main() {
             "/movie_clip/increment_like/:id",
             patch(movie_clip_handlers::increment_like_movie_clip),
         )
-        .with_state(movie_clip_repo);
+        .route(
+            "/movie_clip/unlike/:id",
+            patch(movie_clip_handlers::unlike_movie_clip),
+        )
+        .route(
+            "/movie_clip/liked",
+            post(movie_clip_handlers::liked_movie_clip_ids),
+        )
+        .route(
+            "/movie_clip/tags",
+            get(movie_clip_handlers::all_movie_clip_tags),
+        )
+        .route(
+            "/movie_clip/tags/:id",
+            get(movie_clip_handlers::movie_clip_tags)
+                .put(movie_clip_handlers::add_movie_clip_tag)
+                .delete(movie_clip_handlers::remove_movie_clip_tag),
+        )
+        .route(
+            "/movie_clip/revisions",
+            get(movie_clip_handlers::latest_movie_clip_revisions),
+        )
+        .route(
+            "/movie_clip/revisions/:id",
+            get(movie_clip_handlers::movie_clip_revisions),
+        )
+        .route(
+            "/movie_clip/revision_diff",
+            get(movie_clip_handlers::movie_clip_revision_diff),
+        )
+        .route(
+            "/movie_clip/restore/:id",
+            patch(movie_clip_handlers::restore_movie_clip_revision),
+        )
+        .route(
+            "/movie_clip/trash",
+            get(movie_clip_handlers::trashed_movie_clips),
+        )
+        .route(
+            "/movie_clip/trash/:id",
+            patch(movie_clip_handlers::restore_trashed_movie_clip)
+                .delete(movie_clip_handlers::purge_movie_clip),
+        )
+        .route(
+            "/movie_clip/import",
+            put(movie_clip_handlers::import_movie_clips),
+        )
+        .with_state(movie_clip_repo.clone());
+
+    let clip_series_api_router: Router<()> = Router::new()
+        .route(
+            "/clip_series",
+            put(clip_series_handlers::save_clip_series)
+                .patch(clip_series_handlers::edit_clip_series)
+                .get(clip_series_handlers::all_clip_series),
+        )
+        .route(
+            "/clip_series/query",
+            get(clip_series_handlers::get_clip_series_with_query),
+        )
+        .route(
+            "/clip_series/:id",
+            get(clip_series_handlers::find_clip_series)
+                .delete(clip_series_handlers::remove_clip_series),
+        )
+        .route(
+            "/clip_series/increment_like/:id",
+            patch(clip_series_handlers::increment_like_clip_series),
+        )
+        .route(
+            "/clip_series/unlike/:id",
+            patch(clip_series_handlers::unlike_clip_series),
+        )
+        .route(
+            "/clip_series/liked",
+            post(clip_series_handlers::liked_clip_series_ids),
+        )
+        .with_state(clip_series_repo);
 
     let original_api_router: Router<()> = Router::new()
         .route(
@@ -112,8 +317,7 @@ async fn main() {
         )
         .route(
             "/original/query",
-            get(video_handlers::get_videos_with_query::<Original>)
-                .post(video_handlers::get_videos_with_query::<Original>),
+            get(video_handlers::get_videos_with_query::<Original>),
         )
         .route(
             "/original/:id",
@@ -123,7 +327,59 @@ async fn main() {
             "/original/increment_like/:id",
             patch(video_handlers::increment_like_video::<Original>),
         )
-        .with_state(original_repo);
+        .route(
+            "/original/unlike/:id",
+            patch(video_handlers::unlike_video::<Original>),
+        )
+        .route(
+            "/original/liked",
+            post(video_handlers::liked_video_ids::<Original>),
+        )
+        .route(
+            "/original/tags",
+            get(video_handlers::all_video_tags::<Original>),
+        )
+        .route(
+            "/original/channel/:id",
+            get(video_handlers::channel_videos::<Original>),
+        )
+        .route(
+            "/original/tags/:id",
+            get(video_handlers::video_tags::<Original>)
+                .put(video_handlers::add_video_tag::<Original>)
+                .delete(video_handlers::remove_video_tag::<Original>),
+        )
+        .route(
+            "/original/revisions",
+            get(video_handlers::latest_video_revisions::<Original>),
+        )
+        .route(
+            "/original/revisions/:id",
+            get(video_handlers::video_revisions::<Original>),
+        )
+        .route(
+            "/original/revision_diff",
+            get(video_handlers::video_revision_diff::<Original>),
+        )
+        .route(
+            "/original/restore/:id",
+            patch(video_handlers::restore_video_revision::<Original>),
+        )
+        .route(
+            "/original/trash",
+            get(video_handlers::trashed_videos::<Original>),
+        )
+        .route(
+            "/original/trash/:id",
+            patch(video_handlers::restore_trashed_video::<Original>)
+                .delete(video_handlers::purge_video::<Original>),
+        )
+        .route(
+            "/original/import",
+            put(video_handlers::import_videos::<Original>),
+        )
+        .with_state(original_repo.clone())
+        .layer(Extension(channel_repo.clone()));
 
     let kirinuki_api_router: Router<()> = Router::new()
         .route(
@@ -134,8 +390,7 @@ async fn main() {
         )
         .route(
             "/kirinuki/query",
-            get(video_handlers::get_videos_with_query::<Kirinuki>)
-                .post(video_handlers::get_videos_with_query::<Kirinuki>),
+            get(video_handlers::get_videos_with_query::<Kirinuki>),
         )
         .route(
             "/kirinuki/:id",
@@ -145,7 +400,83 @@ async fn main() {
             "/kirinuki/increment_like/:id",
             patch(video_handlers::increment_like_video::<Kirinuki>),
         )
-        .with_state(kirinuki_repo);
+        .route(
+            "/kirinuki/unlike/:id",
+            patch(video_handlers::unlike_video::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/liked",
+            post(video_handlers::liked_video_ids::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/tags",
+            get(video_handlers::all_video_tags::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/channel/:id",
+            get(video_handlers::channel_videos::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/tags/:id",
+            get(video_handlers::video_tags::<Kirinuki>)
+                .put(video_handlers::add_video_tag::<Kirinuki>)
+                .delete(video_handlers::remove_video_tag::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/revisions",
+            get(video_handlers::latest_video_revisions::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/revisions/:id",
+            get(video_handlers::video_revisions::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/revision_diff",
+            get(video_handlers::video_revision_diff::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/restore/:id",
+            patch(video_handlers::restore_video_revision::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/trash",
+            get(video_handlers::trashed_videos::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/trash/:id",
+            patch(video_handlers::restore_trashed_video::<Kirinuki>)
+                .delete(video_handlers::purge_video::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/import",
+            put(video_handlers::import_videos::<Kirinuki>),
+        )
+        .with_state(kirinuki_repo.clone())
+        .layer(Extension(channel_repo.clone()));
+
+    let channel_api_router: Router<()> = Router::new()
+        .route(
+            "/channel",
+            put(channel_handlers::save_channel)
+                .patch(channel_handlers::edit_channel)
+                .get(channel_handlers::all_channels),
+        )
+        .route("/channel/summary", get(channel_handlers::channel_summaries))
+        .route(
+            "/channel/:id",
+            get(channel_handlers::find_channel).delete(channel_handlers::remove_channel),
+        )
+        .with_state(channel_repo);
+
+    // 検索は各ルーターと同じリポジトリを共有する
+    let search_api_router: Router<()> = Router::new()
+        .route("/search", get(search_handlers::search))
+        .with_state(search_handlers::SearchRepositories {
+            movie_clip_repo,
+            episode_repo,
+            original_repo,
+            kirinuki_repo,
+        });
 
     // distのパス
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
@@ -161,16 +492,26 @@ async fn main() {
         )
         .nest(
             "/api",
-            episode_api_router
+            auth_api_router
+                .merge(user_api_router)
+                .merge(quiz_api_router)
+                .merge(episode_api_router)
                 .merge(movie_clip_api_router)
+                .merge(clip_series_api_router)
                 .merge(original_api_router)
-                .merge(kirinuki_api_router),
+                .merge(kirinuki_api_router)
+                .merge(channel_api_router)
+                .merge(search_api_router)
+                .layer(middleware::from_fn(rate_limit::limit_rate))
+                .layer(Extension(rate_limiter))
+                .layer(Extension(user_repo as SessionUserRepository))
+                .layer(Extension(auth_config)),
         );
 
     println!("server started: {}", CONFIG.test_server_addr);
 
     axum::Server::bind(&CONFIG.test_server_addr.parse().unwrap())
-        .serve(app_router.into_make_service())
+        .serve(app_router.into_make_service_with_connect_info::<SocketAddr>())
         .await
         .unwrap();
 }
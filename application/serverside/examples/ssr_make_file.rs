@@ -1,5 +1,5 @@
 fn main() {
-    use presentation::{App, AppProps};
+    use presentation::App;
 
     use dioxus::prelude::*;
     use std::io::Write;
@@ -13,12 +13,7 @@ fn main() {
 
     let (base_html, _) = index_html_text.split_once("<body>").unwrap();
 
-    let mut vdom = VirtualDom::new_with_props(
-        App,
-        AppProps {
-            admin_password: "some password".to_string(),
-        },
-    );
+    let mut vdom = VirtualDom::new(App);
     let _ = vdom.rebuild();
 
     let html_content = format!(
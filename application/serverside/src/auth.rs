@@ -0,0 +1,700 @@
+use common::AppCommonError;
+use config::CONFIG;
+use domain::client::ClientId;
+use domain::quiz::QuizId;
+use domain::user::{Role, UserId};
+use domain::Cursor;
+
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use axum::http::{header, HeaderMap};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::Sha256;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// セッショントークンを保持するクッキーの名前
+pub const SESSION_COOKIE_NAME: &str = "rikou_meitan_session";
+
+/// 編集用のトークンを保持するクッキーの名前
+pub const EDIT_TOKEN_COOKIE_NAME: &str = "rikou_meitan_edit_token";
+
+/// クッキーの代わりに編集用のトークンを渡すためのヘッダーの名前
+pub const EDIT_TOKEN_HEADER_NAME: &str = "x-edit-token";
+
+/// 匿名のクライアントのidを保持するクッキーの名前
+pub const CLIENT_COOKIE_NAME: &str = "rikou_meitan_client";
+
+/// 現在のUNIX時間(秒)
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("System time is before UNIX epoch.")
+        .as_secs()
+}
+
+/// パスワードをargon2でハッシュ化する．Secrets.tomlやusersテーブルに保存するハッシュの作成に利用する．
+pub fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default().hash_password(password.as_bytes(), &salt)?;
+    Ok(hash.to_string())
+}
+
+/// パスワードがPHC形式のハッシュと一致するかどうか
+pub fn verify_password(password_hash: &str, password: &str) -> bool {
+    let parsed_hash = match PasswordHash::new(password_hash) {
+        Ok(parsed_hash) => parsed_hash,
+        Err(e) => {
+            tracing::error!("Invalid password hash: {e}");
+            return false;
+        }
+    };
+
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .is_ok()
+}
+
+// -------------------------------------------------------------------------------------------------
+// Claims
+
+/// 署名付きトークンの用途．用途の異なるトークンを流用できないように署名の対象に含める．
+#[derive(Debug, Clone, Copy)]
+enum TokenPurpose {
+    Session,
+    QuizChallenge,
+    Edit,
+    Client,
+    Cursor,
+}
+
+impl TokenPurpose {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TokenPurpose::Session => "session",
+            TokenPurpose::QuizChallenge => "quiz_challenge",
+            TokenPurpose::Edit => "edit",
+            TokenPurpose::Client => "client",
+            TokenPurpose::Cursor => "cursor",
+        }
+    }
+}
+
+/// 署名付きトークンに埋め込む有効期限付きの情報
+trait Claims: Serialize + DeserializeOwned {
+    const PURPOSE: TokenPurpose;
+    /// 有効期限(UNIX時間)
+    fn exp(&self) -> u64;
+}
+
+/// セッショントークンに署名して埋め込む情報
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SessionClaims {
+    /// 有効期限(UNIX時間)
+    pub exp: u64,
+    /// セッションの権限
+    pub role: Role,
+    /// ログインしたユーザーのid．Secrets.tomlの管理者の場合はNone
+    pub user_id: Option<UserId>,
+}
+
+impl Claims for SessionClaims {
+    const PURPOSE: TokenPurpose = TokenPurpose::Session;
+    fn exp(&self) -> u64 {
+        self.exp
+    }
+}
+
+/// クイズのチャレンジidに署名して埋め込む情報
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuizChallengeClaims {
+    /// 有効期限(UNIX時間)
+    pub exp: u64,
+    /// 出題したクイズのid
+    pub quiz_id: QuizId,
+}
+
+impl Claims for QuizChallengeClaims {
+    const PURPOSE: TokenPurpose = TokenPurpose::QuizChallenge;
+    fn exp(&self) -> u64 {
+        self.exp
+    }
+}
+
+/// クイズに正解したときに発行する編集用のトークンに署名して埋め込む情報
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EditTokenClaims {
+    /// 有効期限(UNIX時間)
+    pub exp: u64,
+}
+
+impl Claims for EditTokenClaims {
+    const PURPOSE: TokenPurpose = TokenPurpose::Edit;
+    fn exp(&self) -> u64 {
+        self.exp
+    }
+}
+
+/// 匿名のクライアントのトークンに署名して埋め込む情報
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClientClaims {
+    /// 有効期限(UNIX時間)
+    pub exp: u64,
+    /// クライアントのid
+    pub client_id: ClientId,
+}
+
+impl Claims for ClientClaims {
+    const PURPOSE: TokenPurpose = TokenPurpose::Client;
+    fn exp(&self) -> u64 {
+        self.exp
+    }
+}
+
+/// ページングのカーソルに署名して埋め込む情報
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CursorClaims<K, I> {
+    /// 有効期限(UNIX時間)
+    pub exp: u64,
+    /// カーソルを用いるクエリの対象とソートの種類．`movie_clip.like`など
+    pub scope: String,
+    /// ページングの基準となる位置
+    pub cursor: Cursor<K, I>,
+}
+
+impl<K, I> Claims for CursorClaims<K, I>
+where
+    K: Serialize + DeserializeOwned,
+    I: Serialize + DeserializeOwned,
+{
+    const PURPOSE: TokenPurpose = TokenPurpose::Cursor;
+    fn exp(&self) -> u64 {
+        self.exp
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// AuthConfig
+
+/// 認証の設定．`Extension`としてルーターに追加して共有する．
+pub struct AuthConfig {
+    admin_password_hash: String,
+    session_secret: Vec<u8>,
+}
+
+impl AuthConfig {
+    /// `admin_password_hash`はPHC形式のargon2ハッシュ．`session_secret`はトークンの署名に用いる鍵．
+    pub fn new(admin_password_hash: impl Into<String>, session_secret: impl AsRef<[u8]>) -> Self {
+        Self {
+            admin_password_hash: admin_password_hash.into(),
+            session_secret: session_secret.as_ref().to_vec(),
+        }
+    }
+
+    /// パスワードが管理者のものであるかどうか
+    pub fn verify_password(&self, password: &str) -> bool {
+        verify_password(&self.admin_password_hash, password)
+    }
+
+    /// 用途とペイロードに対する署名を計算するためのMAC
+    fn mac(&self, purpose: TokenPurpose, payload: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.session_secret)
+            .expect("HMAC can take key of any size");
+        mac.update(purpose.as_str().as_bytes());
+        mac.update(b".");
+        mac.update(payload.as_bytes());
+        mac
+    }
+
+    /// `claims`をペイロードとする署名付きのトークンを作成する．
+    fn sign<C: Claims>(&self, claims: &C) -> String {
+        let payload = URL_SAFE_NO_PAD
+            .encode(serde_json::to_vec(claims).expect("Claims serialize sanity check"));
+
+        let signature =
+            URL_SAFE_NO_PAD.encode(self.mac(C::PURPOSE, &payload).finalize().into_bytes());
+
+        format!("{payload}.{signature}")
+    }
+
+    /// トークンの署名と有効期限を検証してペイロードを取り出す．不正な場合は`UnauthorizedError`を返す．
+    fn verify<C: Claims>(&self, token: &str) -> Result<C, AppCommonError> {
+        let (payload, signature) = token
+            .split_once('.')
+            .ok_or(AppCommonError::UnauthorizedError)?;
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| AppCommonError::UnauthorizedError)?;
+
+        self.mac(C::PURPOSE, payload)
+            .verify_slice(&signature)
+            .map_err(|_| AppCommonError::UnauthorizedError)?;
+
+        let claims: C = URL_SAFE_NO_PAD
+            .decode(payload)
+            .ok()
+            .and_then(|payload| serde_json::from_slice(&payload).ok())
+            .ok_or(AppCommonError::UnauthorizedError)?;
+
+        if claims.exp() <= now_secs() {
+            return Err(AppCommonError::UnauthorizedError);
+        }
+
+        Ok(claims)
+    }
+
+    /// 権限とユーザーのidを埋め込んだ署名付きのセッショントークンを発行する．
+    pub fn issue_session_token(&self, role: Role, user_id: Option<UserId>) -> String {
+        self.sign(&SessionClaims {
+            exp: now_secs() + CONFIG.session_max_age,
+            role,
+            user_id,
+        })
+    }
+
+    /// セッショントークンの署名と有効期限を検証する．
+    pub fn verify_session_token(&self, token: &str) -> Result<SessionClaims, AppCommonError> {
+        self.verify(token)
+    }
+
+    /// リクエストヘッダーに含まれるセッションを検証する．
+    pub fn verify_headers(&self, headers: &HeaderMap) -> Result<SessionClaims, AppCommonError> {
+        let token = session_token_from_headers(headers).ok_or(AppCommonError::UnauthorizedError)?;
+        self.verify_session_token(&token)
+    }
+
+    /// 出題したクイズのidを埋め込んだ署名付きのチャレンジidを発行する．
+    pub fn issue_quiz_challenge(&self, quiz_id: QuizId) -> String {
+        self.sign(&QuizChallengeClaims {
+            exp: now_secs() + CONFIG.quiz_challenge_max_age,
+            quiz_id,
+        })
+    }
+
+    /// チャレンジidを検証し，出題したクイズのidを返す．
+    pub fn verify_quiz_challenge(&self, challenge_id: &str) -> Result<QuizId, AppCommonError> {
+        let claims: QuizChallengeClaims = self.verify(challenge_id)?;
+        Ok(claims.quiz_id)
+    }
+
+    /// 有効期間の短い編集用のトークンを発行する．
+    pub fn issue_edit_token(&self) -> String {
+        self.sign(&EditTokenClaims {
+            exp: now_secs() + CONFIG.edit_token_max_age,
+        })
+    }
+
+    /// 編集用のトークンの署名と有効期限を検証する．
+    pub fn verify_edit_token(&self, token: &str) -> Result<EditTokenClaims, AppCommonError> {
+        self.verify(token)
+    }
+
+    /// リクエストヘッダーに含まれる編集用のトークンを検証する．
+    pub fn verify_edit_token_headers(
+        &self,
+        headers: &HeaderMap,
+    ) -> Result<EditTokenClaims, AppCommonError> {
+        let token = edit_token_from_headers(headers).ok_or(AppCommonError::UnauthorizedError)?;
+        self.verify_edit_token(&token)
+    }
+
+    /// 匿名のクライアントのidを埋め込んだ署名付きのトークンを発行する．
+    pub fn issue_client_token(&self, client_id: ClientId) -> String {
+        self.sign(&ClientClaims {
+            exp: now_secs() + CONFIG.client_max_age,
+            client_id,
+        })
+    }
+
+    /// クライアントのトークンを検証し，クライアントのidを返す．
+    pub fn verify_client_token(&self, token: &str) -> Result<ClientId, AppCommonError> {
+        let claims: ClientClaims = self.verify(token)?;
+        Ok(claims.client_id)
+    }
+
+    /// リクエストヘッダーに含まれるクライアントのトークンを検証する．
+    pub fn verify_client_headers(&self, headers: &HeaderMap) -> Result<ClientId, AppCommonError> {
+        let token = cookie_from_headers(headers, CLIENT_COOKIE_NAME)
+            .ok_or(AppCommonError::UnauthorizedError)?;
+        self.verify_client_token(&token)
+    }
+
+    /// `scope`のクエリの続きを取得するための署名付きのカーソルを発行する．
+    pub fn issue_cursor<K, I>(&self, scope: &str, cursor: Cursor<K, I>) -> String
+    where
+        K: Serialize + DeserializeOwned,
+        I: Serialize + DeserializeOwned,
+    {
+        self.sign(&CursorClaims {
+            exp: now_secs() + CONFIG.cursor_max_age,
+            scope: scope.to_string(),
+            cursor,
+        })
+    }
+
+    /// カーソルの署名と有効期限，`scope`を検証してページングの基準となる位置を返す．
+    /// 不正な場合は`QueryStringRejectionError`を返す．
+    pub fn verify_cursor<K, I>(
+        &self,
+        scope: &str,
+        token: &str,
+    ) -> Result<Cursor<K, I>, AppCommonError>
+    where
+        K: Serialize + DeserializeOwned,
+        I: Serialize + DeserializeOwned,
+    {
+        let invalid_cursor =
+            || AppCommonError::QueryStringRejectionError("Invalid cursor.".to_string());
+
+        let claims: CursorClaims<K, I> = self.verify(token).map_err(|_| invalid_cursor())?;
+        if claims.scope != scope {
+            return Err(invalid_cursor());
+        }
+        Ok(claims.cursor)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// クッキー関連
+
+/// セッショントークンを設定するSet-Cookieの値
+pub fn session_cookie(token: &str) -> String {
+    format!(
+        "{SESSION_COOKIE_NAME}={token}; Max-Age={}; Path=/api; HttpOnly; Secure; SameSite=Strict",
+        CONFIG.session_max_age
+    )
+}
+
+/// セッションを削除するSet-Cookieの値
+pub fn removal_session_cookie() -> String {
+    format!("{SESSION_COOKIE_NAME}=; Max-Age=0; Path=/api; HttpOnly; Secure; SameSite=Strict")
+}
+
+/// 編集用のトークンを設定するSet-Cookieの値
+pub fn edit_token_cookie(token: &str) -> String {
+    format!(
+        "{EDIT_TOKEN_COOKIE_NAME}={token}; Max-Age={}; Path=/api; HttpOnly; Secure; SameSite=Strict",
+        CONFIG.edit_token_max_age
+    )
+}
+
+/// 匿名のクライアントのトークンを設定するSet-Cookieの値
+pub fn client_cookie(token: &str) -> String {
+    format!(
+        "{CLIENT_COOKIE_NAME}={token}; Max-Age={}; Path=/api; HttpOnly; Secure; SameSite=Strict",
+        CONFIG.client_max_age
+    )
+}
+
+/// Cookieヘッダーから`cookie_name`の値を取得する．
+fn cookie_from_headers(headers: &HeaderMap, cookie_name: &str) -> Option<String> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == cookie_name)
+        .map(|(_, value)| value.to_string())
+}
+
+/// CookieヘッダーあるいはAuthorizationヘッダー(Bearer)からセッショントークンを取得する．
+pub fn session_token_from_headers(headers: &HeaderMap) -> Option<String> {
+    cookie_from_headers(headers, SESSION_COOKIE_NAME).or_else(|| {
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|token| token.to_string())
+    })
+}
+
+/// CookieヘッダーあるいはX-Edit-Tokenヘッダーから編集用のトークンを取得する．
+pub fn edit_token_from_headers(headers: &HeaderMap) -> Option<String> {
+    cookie_from_headers(headers, EDIT_TOKEN_COOKIE_NAME).or_else(|| {
+        headers
+            .get(EDIT_TOKEN_HEADER_NAME)
+            .and_then(|value| value.to_str().ok())
+            .map(|token| token.to_string())
+    })
+}
+
+/// ハンドラのテストで利用するセッション関連のユーティリティ
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use super::{AuthConfig, CLIENT_COOKIE_NAME, EDIT_TOKEN_COOKIE_NAME, SESSION_COOKIE_NAME};
+    use domain::client::ClientId;
+    use domain::user::{Role, User};
+    use std::sync::Arc;
+
+    const TEST_SESSION_SECRET: &str = "test secret";
+
+    /// テスト用のAuthConfig．管理者のパスワードでのログインはできない．
+    pub(crate) fn auth_config() -> Arc<AuthConfig> {
+        Arc::new(AuthConfig::new("", TEST_SESSION_SECRET))
+    }
+
+    /// `role`のセッションを持つCookieヘッダーの値
+    pub(crate) fn session_cookie_of(role: Role) -> String {
+        let token = auth_config().issue_session_token(role, None);
+        format!("{SESSION_COOKIE_NAME}={token}")
+    }
+
+    /// `user`としてログインしたセッションを持つCookieヘッダーの値
+    pub(crate) fn user_session_cookie_of(user: &User) -> String {
+        let token = auth_config().issue_session_token(user.role(), Some(user.id()));
+        format!("{SESSION_COOKIE_NAME}={token}")
+    }
+
+    /// 編集用のトークンを持つCookieヘッダーの値
+    pub(crate) fn edit_token_cookie() -> String {
+        let token = auth_config().issue_edit_token();
+        format!("{EDIT_TOKEN_COOKIE_NAME}={token}")
+    }
+
+    /// `client_id`のクライアントのトークンを持つCookieヘッダーの値
+    pub(crate) fn client_cookie_of(client_id: ClientId) -> String {
+        let token = auth_config().issue_client_token(client_id);
+        format!("{CLIENT_COOKIE_NAME}={token}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        hash_password, verify_password, AuthConfig, CursorClaims, EditTokenClaims, SessionClaims,
+        CLIENT_COOKIE_NAME, EDIT_TOKEN_COOKIE_NAME, EDIT_TOKEN_HEADER_NAME, SESSION_COOKIE_NAME,
+    };
+    use common::AppCommonError;
+    use domain::client::ClientId;
+    use domain::movie_clip::MovieClipId;
+    use domain::quiz::QuizId;
+    use domain::user::{Role, UserId};
+    use domain::{Cursor, Date};
+
+    use axum::http::{header, HeaderMap, HeaderValue};
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use std::sync::Arc;
+
+    #[fixture]
+    fn auth_config() -> Arc<AuthConfig> {
+        let hash = hash_password("password").unwrap();
+        Arc::new(AuthConfig::new(hash, "secret"))
+    }
+
+    #[rstest]
+    fn test_verify_password(auth_config: Arc<AuthConfig>) {
+        assert!(auth_config.verify_password("password"));
+        assert!(!auth_config.verify_password("wrong password"));
+
+        let invalid_config = AuthConfig::new("not a phc string", "secret");
+        assert!(!invalid_config.verify_password("not a phc string"));
+
+        let hash = hash_password("user password").unwrap();
+        assert!(verify_password(&hash, "user password"));
+        assert!(!verify_password(&hash, "password"));
+    }
+
+    #[rstest]
+    fn test_session_token(auth_config: Arc<AuthConfig>) {
+        let user_id = UserId::generate();
+        let token = auth_config.issue_session_token(Role::Contributor, Some(user_id));
+        let claims = auth_config.verify_session_token(&token).unwrap();
+        assert_eq!(claims.role, Role::Contributor);
+        assert_eq!(claims.user_id, Some(user_id));
+
+        // 異なる鍵で署名されたトークン
+        let other_config = AuthConfig::new(hash_password("password").unwrap(), "other secret");
+        let other_token = other_config.issue_session_token(Role::Admin, None);
+        assert!(matches!(
+            auth_config.verify_session_token(&other_token),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+
+        // ペイロードを改ざんしたトークン
+        let (_, signature) = token.split_once('.').unwrap();
+        let tampered_payload = URL_SAFE_NO_PAD.encode(
+            serde_json::to_vec(&SessionClaims {
+                exp: u64::MAX,
+                role: Role::Admin,
+                user_id: Some(user_id),
+            })
+            .unwrap(),
+        );
+        let tampered_token = format!("{tampered_payload}.{signature}");
+        assert!(matches!(
+            auth_config.verify_session_token(&tampered_token),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+
+        // 不正な形式のトークン
+        assert!(matches!(
+            auth_config.verify_session_token("invalid"),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+    }
+
+    #[rstest]
+    fn test_verify_headers(auth_config: Arc<AuthConfig>) {
+        let token = auth_config.issue_session_token(Role::Admin, None);
+
+        // Cookieヘッダー
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::COOKIE,
+            HeaderValue::from_str(&format!("other=value; {SESSION_COOKIE_NAME}={token}")).unwrap(),
+        );
+        assert!(auth_config.verify_headers(&headers).is_ok());
+
+        // Authorizationヘッダー
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {token}")).unwrap(),
+        );
+        assert!(auth_config.verify_headers(&headers).is_ok());
+
+        // ヘッダーが無い場合
+        assert!(matches!(
+            auth_config.verify_headers(&HeaderMap::new()),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+    }
+
+    #[rstest]
+    fn test_quiz_challenge_and_edit_token(auth_config: Arc<AuthConfig>) {
+        let quiz_id = QuizId::generate();
+        let challenge_id = auth_config.issue_quiz_challenge(quiz_id);
+        assert_eq!(
+            auth_config.verify_quiz_challenge(&challenge_id).unwrap(),
+            quiz_id
+        );
+
+        let edit_token = auth_config.issue_edit_token();
+        assert!(auth_config.verify_edit_token(&edit_token).is_ok());
+
+        // 用途の異なるトークンは流用できない
+        let session_token = auth_config.issue_session_token(Role::Admin, None);
+        assert!(matches!(
+            auth_config.verify_edit_token(&session_token),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+        assert!(matches!(
+            auth_config.verify_edit_token(&challenge_id),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+        assert!(matches!(
+            auth_config.verify_session_token(&edit_token),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+
+        // 有効期限切れのトークン
+        let expired_token = auth_config.sign(&EditTokenClaims { exp: 0 });
+        assert!(matches!(
+            auth_config.verify_edit_token(&expired_token),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+    }
+
+    #[rstest]
+    fn test_verify_edit_token_headers(auth_config: Arc<AuthConfig>) {
+        let token = auth_config.issue_edit_token();
+
+        // Cookieヘッダー
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::COOKIE,
+            HeaderValue::from_str(&format!("{EDIT_TOKEN_COOKIE_NAME}={token}")).unwrap(),
+        );
+        assert!(auth_config.verify_edit_token_headers(&headers).is_ok());
+
+        // X-Edit-Tokenヘッダー
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            EDIT_TOKEN_HEADER_NAME,
+            HeaderValue::from_str(&token).unwrap(),
+        );
+        assert!(auth_config.verify_edit_token_headers(&headers).is_ok());
+
+        // ヘッダーが無い場合
+        assert!(matches!(
+            auth_config.verify_edit_token_headers(&HeaderMap::new()),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+    }
+
+    #[rstest]
+    fn test_client_token(auth_config: Arc<AuthConfig>) {
+        let client_id = ClientId::generate();
+        let token = auth_config.issue_client_token(client_id);
+        assert_eq!(auth_config.verify_client_token(&token).unwrap(), client_id);
+
+        // Cookieヘッダー
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::COOKIE,
+            HeaderValue::from_str(&format!("{CLIENT_COOKIE_NAME}={token}")).unwrap(),
+        );
+        assert_eq!(
+            auth_config.verify_client_headers(&headers).unwrap(),
+            client_id
+        );
+
+        // 用途の異なるトークンは流用できない
+        let edit_token = auth_config.issue_edit_token();
+        assert!(matches!(
+            auth_config.verify_client_token(&edit_token),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+
+        // ヘッダーが無い場合
+        assert!(matches!(
+            auth_config.verify_client_headers(&HeaderMap::new()),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+    }
+
+    #[rstest]
+    fn test_cursor(auth_config: Arc<AuthConfig>) {
+        let cursor = Cursor::new(Date::from_ymd(2023, 4, 1).unwrap(), MovieClipId::generate());
+        let token = auth_config.issue_cursor("movie_clip.create_date", cursor);
+        assert_eq!(
+            auth_config
+                .verify_cursor::<Date, MovieClipId>("movie_clip.create_date", &token)
+                .unwrap(),
+            cursor
+        );
+
+        // 異なるクエリのカーソルは流用できない
+        assert!(matches!(
+            auth_config.verify_cursor::<Date, MovieClipId>("original.date", &token),
+            Err(AppCommonError::QueryStringRejectionError(_))
+        ));
+
+        // ペイロードを改ざんしたカーソル
+        let (_, signature) = token.split_once('.').unwrap();
+        let tampered_payload = URL_SAFE_NO_PAD.encode(
+            serde_json::to_vec(&CursorClaims {
+                exp: u64::MAX,
+                scope: "movie_clip.like".to_string(),
+                cursor: Cursor::new(u32::MAX, MovieClipId::generate()),
+            })
+            .unwrap(),
+        );
+        assert!(matches!(
+            auth_config.verify_cursor::<u32, MovieClipId>(
+                "movie_clip.like",
+                &format!("{tampered_payload}.{signature}")
+            ),
+            Err(AppCommonError::QueryStringRejectionError(_))
+        ));
+    }
+}
@@ -0,0 +1,26 @@
+use derive_new::new;
+use domain::channel::{Channel, ChannelId};
+
+#[derive(new)]
+pub(crate) struct SaveChannelCommand {
+    pub channel: Channel,
+}
+
+#[derive(new)]
+pub(crate) struct EditChannelCommand {
+    pub channel: Channel,
+}
+
+pub(crate) struct AllChannelsCommand;
+
+#[derive(new)]
+pub(crate) struct FindChannelCommand {
+    pub id: ChannelId,
+}
+
+pub(crate) struct ChannelSummariesCommand;
+
+#[derive(new)]
+pub(crate) struct RemoveChannelCommand {
+    pub id: ChannelId,
+}
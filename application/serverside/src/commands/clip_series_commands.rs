@@ -0,0 +1,67 @@
+use common::NewClipSeriesInfo;
+use derive_new::new;
+use domain::client::ClientId;
+use domain::clip_series::{ClipSeries, ClipSeriesId};
+use domain::{Cursor, Date, PageSize};
+
+#[derive(new)]
+pub(crate) struct SaveClipSeriesCommand {
+    pub clip_series_info: NewClipSeriesInfo,
+}
+
+#[derive(new)]
+pub(crate) struct EditClipSeriesCommand {
+    pub clip_series: ClipSeries,
+}
+
+#[derive(new)]
+pub(crate) struct IncrementLikeClipSeriesCommand {
+    pub id: ClipSeriesId,
+    pub client: ClientId,
+}
+
+#[derive(new)]
+pub(crate) struct UnlikeClipSeriesCommand {
+    pub id: ClipSeriesId,
+    pub client: ClientId,
+}
+
+#[derive(new)]
+pub(crate) struct LikedClipSeriesIdsCommand {
+    pub ids: Vec<ClipSeriesId>,
+    pub client: ClientId,
+}
+
+pub(crate) struct AllClipSeriesCommand;
+
+#[derive(new)]
+pub(crate) struct FindClipSeriesCommand {
+    pub id: ClipSeriesId,
+}
+
+#[derive(new)]
+pub(crate) struct OrderByLikeClipSeriesCommand {
+    pub length: PageSize,
+}
+
+#[derive(new)]
+pub(crate) struct OrderByLikeLaterClipSeriesCommand {
+    pub cursor: Cursor<u32, ClipSeriesId>,
+    pub length: PageSize,
+}
+
+#[derive(new)]
+pub(crate) struct OrderByCreateDateClipSeriesCommand {
+    pub length: PageSize,
+}
+
+#[derive(new)]
+pub(crate) struct OrderByCreateDateLaterClipSeriesCommand {
+    pub cursor: Cursor<Date, ClipSeriesId>,
+    pub length: PageSize,
+}
+
+#[derive(new)]
+pub(crate) struct RemoveClipSeriesCommand {
+    pub id: ClipSeriesId,
+}
@@ -1,26 +1,87 @@
 use derive_new::new;
 use domain::episode::{Episode, EpisodeId};
-use domain::Date;
+use domain::revision::{Actor, RevisionId};
+use domain::{Date, PageSize, Tag};
 
 #[derive(new)]
 pub(crate) struct SaveEpisodeCommand {
     pub episode: Episode,
+    pub actor: Actor,
 }
 
 #[derive(new)]
 pub(crate) struct EditEpisodeCommand {
     pub episode: Episode,
+    pub actor: Actor,
 }
 
 pub(crate) struct AllEpisodeCommand;
 
+/// `tags`が空でない場合は`tags`を全て持つエピソードのみを対象とする．
 #[derive(new)]
 pub(crate) struct OrderByDateRangeEpisodeCommand {
+    pub tags: Vec<Tag>,
     pub start: Date,
     pub end: Date,
 }
 
+pub(crate) struct AllEpisodeTagsCommand;
+
+#[derive(new)]
+pub(crate) struct EpisodeTagsCommand {
+    pub id: EpisodeId,
+}
+
+#[derive(new)]
+pub(crate) struct AddEpisodeTagCommand {
+    pub id: EpisodeId,
+    pub tag: Tag,
+}
+
+#[derive(new)]
+pub(crate) struct RemoveEpisodeTagCommand {
+    pub id: EpisodeId,
+    pub tag: Tag,
+}
+
 #[derive(new)]
 pub(crate) struct RemoveEpisodeCommand {
     pub id: EpisodeId,
+    pub actor: Actor,
+}
+
+pub(crate) struct TrashedEpisodesCommand;
+
+#[derive(new)]
+pub(crate) struct RestoreTrashedEpisodeCommand {
+    pub id: EpisodeId,
+    pub actor: Actor,
+}
+
+#[derive(new)]
+pub(crate) struct PurgeEpisodeCommand {
+    pub id: EpisodeId,
+    pub actor: Actor,
+}
+
+#[derive(new)]
+pub(crate) struct EpisodeRevisionsCommand {
+    pub id: EpisodeId,
+}
+
+#[derive(new)]
+pub(crate) struct LatestEpisodeRevisionsCommand {
+    pub length: PageSize,
+}
+
+#[derive(new)]
+pub(crate) struct EpisodeRevisionDiffCommand {
+    pub from: RevisionId,
+    pub to: RevisionId,
+}
+
+#[derive(new)]
+pub(crate) struct RestoreEpisodeRevisionCommand {
+    pub id: RevisionId,
+    pub actor: Actor,
 }
@@ -1,33 +1,60 @@
+use common::NewMovieClipInfo;
 use derive_new::new;
+use domain::client::ClientId;
 use domain::movie_clip::{MovieClip, MovieClipId};
-use domain::Date;
+use domain::revision::{Actor, RevisionId};
+use domain::{Cursor, Date, PageSize, Tag};
 
 #[derive(new)]
 pub(crate) struct SaveMovieClipCommand {
-    pub movie_clip: MovieClip,
+    pub movie_clip_info: NewMovieClipInfo,
+    pub actor: Actor,
 }
 
 #[derive(new)]
 pub(crate) struct EditMovieClipCommand {
     pub movie_clip: MovieClip,
+    pub actor: Actor,
+}
+
+#[derive(new)]
+pub(crate) struct ImportMovieClipsCommand {
+    pub movie_clips: Vec<MovieClip>,
+    pub actor: Actor,
 }
 
 #[derive(new)]
 pub(crate) struct IncrementLikeMovieClipCommand {
     pub id: MovieClipId,
+    pub client: ClientId,
+}
+
+#[derive(new)]
+pub(crate) struct UnlikeMovieClipCommand {
+    pub id: MovieClipId,
+    pub client: ClientId,
+}
+
+#[derive(new)]
+pub(crate) struct LikedMovieClipIdsCommand {
+    pub ids: Vec<MovieClipId>,
+    pub client: ClientId,
 }
 
 pub(crate) struct AllMovieClipCommand;
 
+/// `tags`が空でない場合は`tags`を全て持つクリップのみを対象とする．
 #[derive(new)]
 pub(crate) struct OrderByLikeMovieClipCommand {
-    pub length: usize,
+    pub tags: Vec<Tag>,
+    pub length: PageSize,
 }
 
 #[derive(new)]
 pub(crate) struct OrderByLikeLaterMovieClipCommand {
-    pub reference: MovieClip,
-    pub length: usize,
+    pub tags: Vec<Tag>,
+    pub cursor: Cursor<u32, MovieClipId>,
+    pub length: PageSize,
 }
 
 #[derive(new)]
@@ -38,16 +65,74 @@ pub(crate) struct OrderByCreateDateRangeMovieClipCommand {
 
 #[derive(new)]
 pub(crate) struct OrderByCreateDateMovieClipCommand {
-    pub length: usize,
+    pub tags: Vec<Tag>,
+    pub length: PageSize,
 }
 
 #[derive(new)]
 pub(crate) struct OrderByCreateDateLaterMovieClipCommand {
-    pub reference: MovieClip,
-    pub length: usize,
+    pub tags: Vec<Tag>,
+    pub cursor: Cursor<Date, MovieClipId>,
+    pub length: PageSize,
+}
+
+pub(crate) struct AllMovieClipTagsCommand;
+
+#[derive(new)]
+pub(crate) struct MovieClipTagsCommand {
+    pub id: MovieClipId,
+}
+
+#[derive(new)]
+pub(crate) struct AddMovieClipTagCommand {
+    pub id: MovieClipId,
+    pub tag: Tag,
+}
+
+#[derive(new)]
+pub(crate) struct RemoveMovieClipTagCommand {
+    pub id: MovieClipId,
+    pub tag: Tag,
 }
 
 #[derive(new)]
 pub(crate) struct RemoveMovieClipCommand {
     pub id: MovieClipId,
+    pub actor: Actor,
+}
+
+pub(crate) struct TrashedMovieClipsCommand;
+
+#[derive(new)]
+pub(crate) struct RestoreTrashedMovieClipCommand {
+    pub id: MovieClipId,
+    pub actor: Actor,
+}
+
+#[derive(new)]
+pub(crate) struct PurgeMovieClipCommand {
+    pub id: MovieClipId,
+    pub actor: Actor,
+}
+
+#[derive(new)]
+pub(crate) struct MovieClipRevisionsCommand {
+    pub id: MovieClipId,
+}
+
+#[derive(new)]
+pub(crate) struct LatestMovieClipRevisionsCommand {
+    pub length: PageSize,
+}
+
+#[derive(new)]
+pub(crate) struct MovieClipRevisionDiffCommand {
+    pub from: RevisionId,
+    pub to: RevisionId,
+}
+
+#[derive(new)]
+pub(crate) struct RestoreMovieClipRevisionCommand {
+    pub id: RevisionId,
+    pub actor: Actor,
 }
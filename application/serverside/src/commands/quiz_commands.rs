@@ -0,0 +1,27 @@
+use derive_new::new;
+use domain::quiz::{Quiz, QuizId};
+
+pub(crate) struct RandomQuizCommand;
+
+#[derive(new)]
+pub(crate) struct CheckQuizAnswerCommand {
+    pub id: QuizId,
+    pub answer: String,
+}
+
+#[derive(new)]
+pub(crate) struct SaveQuizCommand {
+    pub quiz: Quiz,
+}
+
+#[derive(new)]
+pub(crate) struct EditQuizCommand {
+    pub quiz: Quiz,
+}
+
+pub(crate) struct AllQuizzesCommand;
+
+#[derive(new)]
+pub(crate) struct RemoveQuizCommand {
+    pub id: QuizId,
+}
@@ -0,0 +1,9 @@
+use derive_new::new;
+use domain::search::SearchQuery;
+use domain::PageSize;
+
+#[derive(new)]
+pub(crate) struct SearchCommand {
+    pub query: SearchQuery,
+    pub length: PageSize,
+}
@@ -0,0 +1,22 @@
+use derive_new::new;
+use domain::user::{Role, UserId};
+
+#[derive(new)]
+pub(crate) struct AuthenticateUserCommand {
+    pub name: String,
+    pub password: String,
+}
+
+#[derive(new)]
+pub(crate) struct SaveUserCommand {
+    pub name: String,
+    pub password: String,
+    pub role: Role,
+}
+
+pub(crate) struct AllUsersCommand;
+
+#[derive(new)]
+pub(crate) struct RemoveUserCommand {
+    pub id: UserId,
+}
@@ -1,47 +1,139 @@
+use common::NewVideoInfo;
 use derive_new::new;
+use domain::channel::ChannelId;
+use domain::client::ClientId;
+use domain::revision::{Actor, RevisionId};
 use domain::video::{Video, VideoId, VideoType};
+use domain::{Cursor, DateTime, PageSize, Tag};
 
 #[derive(new)]
-pub(crate) struct SaveVideoCommand<T: VideoType> {
-    pub video: Video<T>,
+pub(crate) struct SaveVideoCommand {
+    pub video_info: NewVideoInfo,
+    pub actor: Actor,
 }
 
 #[derive(new)]
 pub(crate) struct EditVideoCommand<T: VideoType> {
     pub video: Video<T>,
+    pub actor: Actor,
+}
+
+#[derive(new)]
+pub(crate) struct ImportVideosCommand<T: VideoType> {
+    pub videos: Vec<Video<T>>,
+    pub actor: Actor,
 }
 
 #[derive(new)]
 pub(crate) struct IncrementLikeVideoCommand {
     pub id: VideoId,
+    pub client: ClientId,
+}
+
+#[derive(new)]
+pub(crate) struct UnlikeVideoCommand {
+    pub id: VideoId,
+    pub client: ClientId,
+}
+
+#[derive(new)]
+pub(crate) struct LikedVideoIdsCommand {
+    pub ids: Vec<VideoId>,
+    pub client: ClientId,
 }
 
 #[derive(new)]
 pub(crate) struct AllVideosCommand;
 
+/// `tags`が空でない場合は`tags`を全て持つ動画のみを対象とする．
 #[derive(new)]
 pub(crate) struct OrderByLikeVideosCommand {
-    pub length: usize,
+    pub tags: Vec<Tag>,
+    pub length: PageSize,
 }
 
 #[derive(new)]
-pub(crate) struct OrderByLikeLaterVideosCommand<T: VideoType> {
-    pub reference: Video<T>,
-    pub length: usize,
+pub(crate) struct OrderByLikeLaterVideosCommand {
+    pub tags: Vec<Tag>,
+    pub cursor: Cursor<u32, VideoId>,
+    pub length: PageSize,
 }
 
 #[derive(new)]
 pub(crate) struct OrderByDateVideosCommand {
-    pub length: usize,
+    pub tags: Vec<Tag>,
+    pub length: PageSize,
 }
 
 #[derive(new)]
-pub(crate) struct OrderByDateLaterVideosCommand<T: VideoType> {
-    pub reference: Video<T>,
-    pub length: usize,
+pub(crate) struct OrderByDateLaterVideosCommand {
+    pub tags: Vec<Tag>,
+    pub cursor: Cursor<DateTime, VideoId>,
+    pub length: PageSize,
+}
+
+#[derive(new)]
+pub(crate) struct ChannelVideosCommand {
+    pub channel: ChannelId,
+}
+
+pub(crate) struct AllVideoTagsCommand;
+
+#[derive(new)]
+pub(crate) struct VideoTagsCommand {
+    pub id: VideoId,
+}
+
+#[derive(new)]
+pub(crate) struct AddVideoTagCommand {
+    pub id: VideoId,
+    pub tag: Tag,
+}
+
+#[derive(new)]
+pub(crate) struct RemoveVideoTagCommand {
+    pub id: VideoId,
+    pub tag: Tag,
 }
 
 #[derive(new)]
 pub(crate) struct RemoveVideoCommand {
     pub id: VideoId,
+    pub actor: Actor,
+}
+
+pub(crate) struct TrashedVideosCommand;
+
+#[derive(new)]
+pub(crate) struct RestoreTrashedVideoCommand {
+    pub id: VideoId,
+    pub actor: Actor,
+}
+
+#[derive(new)]
+pub(crate) struct PurgeVideoCommand {
+    pub id: VideoId,
+    pub actor: Actor,
+}
+
+#[derive(new)]
+pub(crate) struct VideoRevisionsCommand {
+    pub id: VideoId,
+}
+
+#[derive(new)]
+pub(crate) struct LatestVideoRevisionsCommand {
+    pub length: PageSize,
+}
+
+#[derive(new)]
+pub(crate) struct VideoRevisionDiffCommand {
+    pub from: RevisionId,
+    pub to: RevisionId,
+}
+
+#[derive(new)]
+pub(crate) struct RestoreVideoRevisionCommand {
+    pub id: RevisionId,
+    pub actor: Actor,
 }
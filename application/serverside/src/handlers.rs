@@ -1,3 +1,18 @@
+pub mod auth_handlers;
+mod caller;
+pub mod channel_handlers;
+mod client;
+pub mod clip_series_handlers;
 pub mod episode_handlers;
 pub mod movie_clip_handlers;
+pub mod quiz_handlers;
+mod revision_query;
+pub mod search_handlers;
+pub mod user_handlers;
+mod validated;
 pub mod video_handlers;
+
+pub use caller::{Caller, SessionUserRepository};
+pub use client::Client;
+pub use revision_query::{LatestRevisionsQuery, RevisionDiffQuery};
+pub use validated::ValidatedJson;
@@ -0,0 +1,327 @@
+use crate::auth::{client_cookie, removal_session_cookie, session_cookie, AuthConfig};
+use crate::commands::user_commands;
+use crate::handlers::Caller;
+use common::{AppCommonError, LoginInfo};
+use domain::client::ClientId;
+use domain::user::Role;
+
+// user_usecaseのモック化
+#[cfg(not(test))]
+use crate::usecases::user_usecases;
+
+#[cfg(test)]
+use crate::usecases::mock_user_usecases as user_usecases;
+
+// UserRepoのモック化
+#[cfg(all(not(test), feature = "inmemory"))]
+use infrastructure::user_repository_impl::InMemoryUserRepository as UserRepositoryImpl;
+
+#[cfg(all(not(test), not(feature = "inmemory")))]
+use infrastructure::user_repository_impl::UserPgDBRepository as UserRepositoryImpl;
+
+#[cfg(test)]
+use infrastructure::user_repository_impl::MockUserRepository as UserRepositoryImpl;
+
+use axum::{
+    extract::rejection::JsonRejection,
+    extract::{Json, State},
+    http::{header, HeaderMap},
+    response::IntoResponse,
+    Extension,
+};
+use std::sync::Arc;
+use tracing_attributes::instrument;
+
+/// ログインし，権限を埋め込んだセッションのクッキーを発行する．
+/// ユーザー名が無い場合はSecrets.tomlの管理者のパスワードと照合する．
+#[instrument(skip(auth_config, user_repo, login_info_res), err(Display))]
+pub async fn login(
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
+    State(user_repo): State<Arc<UserRepositoryImpl>>,
+    login_info_res: Result<Json<LoginInfo>, JsonRejection>,
+) -> Result<impl IntoResponse, AppCommonError> {
+    let LoginInfo { name, password } = login_info_res?.0;
+
+    let token = match name {
+        // 登録されたユーザー
+        Some(name) => {
+            let cmd = user_commands::AuthenticateUserCommand::new(name, password);
+            let user = user_usecases::authenticate_user(user_repo, cmd).await?;
+            auth_config.issue_session_token(user.role(), Some(user.id()))
+        }
+        // Secrets.tomlの管理者
+        None => {
+            if !auth_config.verify_password(&password) {
+                return Err(AppCommonError::UnauthorizedError);
+            }
+            auth_config.issue_session_token(Role::Admin, None)
+        }
+    };
+
+    Ok([(header::SET_COOKIE, session_cookie(&token))])
+}
+
+/// セッションのクッキーを削除する．
+#[instrument]
+pub async fn logout() -> impl IntoResponse {
+    [(header::SET_COOKIE, removal_session_cookie())]
+}
+
+/// 有効なセッションを持っているかどうかを確認し，その権限を返す．
+#[instrument(err(Display))]
+pub async fn check_session(caller: Caller) -> Result<Json<Role>, AppCommonError> {
+    caller
+        .role()
+        .map(Json)
+        .ok_or(AppCommonError::UnauthorizedError)
+}
+
+/// Likeを行う匿名のクライアントのトークンを発行し，クッキーに設定する．
+/// 有効なトークンを既に持っている場合は同じidのまま期限を延長する．
+#[instrument(skip(auth_config, headers))]
+pub async fn issue_client(
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let client_id = auth_config
+        .verify_client_headers(&headers)
+        .unwrap_or_else(|_| ClientId::generate());
+    let token = auth_config.issue_client_token(client_id);
+    [(header::SET_COOKIE, client_cookie(&token))]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::auth::{hash_password, AuthConfig, CLIENT_COOKIE_NAME, SESSION_COOKIE_NAME};
+    use crate::handlers::SessionUserRepository;
+    use crate::usecases::mock_user_usecases;
+    use common::{AppCommonError, LoginInfo};
+    use domain::client::ClientId;
+    use domain::user::{Role, User};
+    use domain::UserRepository;
+    use infrastructure::user_repository_impl::{InMemoryUserRepository, MockUserRepository};
+
+    use axum::{
+        body::Body,
+        http::{self, header, Request, StatusCode},
+        routing::{get, post},
+        Extension, Router,
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use serial_test::serial;
+    use std::sync::Arc;
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        let auth_config = Arc::new(AuthConfig::new(
+            hash_password("password").unwrap(),
+            "secret",
+        ));
+        let user_repo = Arc::new(MockUserRepository::new());
+
+        Router::new()
+            .route("/auth/login", post(super::login))
+            .route("/auth/logout", post(super::logout))
+            .route("/auth/session", get(super::check_session))
+            .route("/auth/client", post(super::issue_client))
+            .with_state(user_repo)
+            .layer(Extension(auth_config))
+    }
+
+    fn login_request(login_info: &LoginInfo) -> Request<Body> {
+        Request::builder()
+            .method(http::Method::POST)
+            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .uri("/auth/login")
+            .body(Body::from(serde_json::to_vec(login_info).unwrap()))
+            .unwrap()
+    }
+
+    /// ログインしてセッションの権限を確認する
+    async fn login_and_check_session(router: &mut Router, login_info: &LoginInfo) -> Role {
+        // ログイン
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(login_request(login_info))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let set_cookie = response
+            .headers()
+            .get(header::SET_COOKIE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(set_cookie.contains("HttpOnly"));
+
+        let cookie = set_cookie.split(';').next().unwrap().to_string();
+        assert!(cookie.starts_with(SESSION_COOKIE_NAME));
+
+        // セッションの確認
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/auth/session")
+            .header(header::COOKIE, cookie)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_login_and_check_session(mut router: Router) {
+        let role = login_and_check_session(&mut router, &LoginInfo::new("password")).await;
+        assert_eq!(role, Role::Admin);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_user")]
+    async fn test_login_as_user_and_check_session(router: Router) {
+        let user = User::new(
+            "contributor".to_string(),
+            "hash".to_string(),
+            Role::Contributor,
+        )
+        .unwrap();
+
+        // セッションの確認ではユーザーを読み直す
+        let session_user_repo = Arc::new(InMemoryUserRepository::new());
+        session_user_repo.save(user.clone()).await.unwrap();
+        let mut router = router.layer(Extension(session_user_repo as SessionUserRepository));
+
+        let mock_ctx = mock_user_usecases::authenticate_user_context();
+        mock_ctx
+            .expect::<MockUserRepository>()
+            .withf(|_, cmd| cmd.name == "contributor" && cmd.password == "password")
+            .return_const(Ok(user));
+
+        let role = login_and_check_session(
+            &mut router,
+            &LoginInfo::with_name("contributor", "password"),
+        )
+        .await;
+        assert_eq!(role, Role::Contributor);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_login_with_wrong_password(mut router: Router) {
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(login_request(&LoginInfo::new("wrong password")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(response.headers().get(header::SET_COOKIE).is_none());
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(err, AppCommonError::UnauthorizedError));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_check_session_without_cookie(mut router: Router) {
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/auth/session")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_logout(mut router: Router) {
+        let request = Request::builder()
+            .method(http::Method::POST)
+            .uri("/auth/logout")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let set_cookie = response
+            .headers()
+            .get(header::SET_COOKIE)
+            .unwrap()
+            .to_str()
+            .unwrap();
+        assert!(set_cookie.contains("Max-Age=0"));
+    }
+
+    /// クライアントのトークンを発行し，そのクライアントのidを返す
+    async fn issue_client(router: &mut Router, cookie: Option<String>) -> ClientId {
+        let builder = Request::builder()
+            .method(http::Method::POST)
+            .uri("/auth/client");
+        let builder = match cookie {
+            Some(cookie) => builder.header(header::COOKIE, cookie),
+            None => builder,
+        };
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(builder.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let set_cookie = response
+            .headers()
+            .get(header::SET_COOKIE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(set_cookie.contains("HttpOnly"));
+
+        let (name, token) = set_cookie
+            .split(';')
+            .next()
+            .and_then(|pair| pair.split_once('='))
+            .unwrap();
+        assert_eq!(name, CLIENT_COOKIE_NAME);
+
+        let auth_config = AuthConfig::new(hash_password("password").unwrap(), "secret");
+        auth_config.verify_client_token(token).unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_issue_client(mut router: Router) {
+        // トークンが無い場合は新しいidを発行する
+        let client_id = issue_client(&mut router, None).await;
+        let other_client_id = issue_client(&mut router, None).await;
+        assert_ne!(client_id, other_client_id);
+
+        // 不正なトークンの場合も新しいidを発行する
+        let new_client_id =
+            issue_client(&mut router, Some(format!("{CLIENT_COOKIE_NAME}=invalid"))).await;
+        assert_ne!(new_client_id, client_id);
+
+        // 有効なトークンの場合は同じidのまま再発行する
+        let auth_config = AuthConfig::new(hash_password("password").unwrap(), "secret");
+        let cookie = format!(
+            "{CLIENT_COOKIE_NAME}={}",
+            auth_config.issue_client_token(client_id)
+        );
+        let same_client_id = issue_client(&mut router, Some(cookie)).await;
+        assert_eq!(same_client_id, client_id);
+    }
+}
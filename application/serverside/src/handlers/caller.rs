@@ -0,0 +1,348 @@
+use crate::auth::{AuthConfig, SessionClaims};
+use common::AppCommonError;
+use domain::revision::Actor;
+use domain::user::{Role, UserId};
+use domain::UserRepository;
+use infrastructure::InfraError;
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use std::sync::Arc;
+
+/// セッションのユーザーを読み直すためのリポジトリ．`Extension`としてルーターに追加する．
+pub type SessionUserRepository = Arc<dyn UserRepository<Error = InfraError> + Send + Sync>;
+
+/// リクエストの送り主．`Extension`として追加された`AuthConfig`でセッションを検証し，権限を解決する．
+/// セッションが無い・不正な場合は匿名として扱う．
+/// ユーザーのセッションは`SessionUserRepository`からユーザーを読み直し，削除されたユーザーは匿名，権限は現在のものとする．
+#[derive(Debug, Clone, Default)]
+pub struct Caller {
+    claims: Option<SessionClaims>,
+    /// クイズに正解して得た有効な編集用のトークンを持つかどうか
+    has_edit_token: bool,
+}
+
+impl Caller {
+    /// 送り主の権限．匿名の場合はNone
+    pub fn role(&self) -> Option<Role> {
+        self.claims.as_ref().map(|claims| claims.role)
+    }
+    /// 送り主のユーザーid．匿名あるいはSecrets.tomlの管理者の場合はNone
+    pub fn user_id(&self) -> Option<UserId> {
+        self.claims.as_ref().and_then(|claims| claims.user_id)
+    }
+    /// `required`以上の権限を持つかどうかを確認する．匿名の場合は`UnauthorizedError`，権限が足りない場合は`ForbiddenError`を返す．
+    pub fn require(&self, required: Role) -> Result<(), AppCommonError> {
+        match self.role() {
+            None => Err(AppCommonError::UnauthorizedError),
+            Some(role) if role < required => Err(AppCommonError::ForbiddenError),
+            Some(_) => Ok(()),
+        }
+    }
+    /// 追加・編集ができるかどうかを確認する．協力者以上の権限か，有効な編集用のトークンが必要．
+    pub fn require_editor(&self) -> Result<(), AppCommonError> {
+        if self.has_edit_token {
+            return Ok(());
+        }
+        self.require(Role::Contributor)
+    }
+    /// 編集履歴に残す操作を行った者．ログインしていない場合は編集用のトークンによる匿名の編集者とする．
+    pub fn actor(&self) -> Actor {
+        match (self.role(), self.user_id()) {
+            (_, Some(user_id)) => Actor::User(user_id),
+            (Some(Role::Admin), None) => Actor::Admin,
+            _ => Actor::Anonymous,
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Caller
+where
+    S: Send + Sync,
+{
+    type Rejection = AppCommonError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let auth_config = match parts.extensions.get::<Arc<AuthConfig>>() {
+            Some(auth_config) => auth_config.clone(),
+            None => {
+                tracing::error!("AuthConfig is not added to the router as an extension.");
+                return Ok(Self::default());
+            }
+        };
+
+        let claims = match auth_config.verify_headers(&parts.headers).ok() {
+            Some(claims) => current_claims(parts, claims).await?,
+            None => None,
+        };
+        Ok(Self {
+            claims,
+            has_edit_token: auth_config
+                .verify_edit_token_headers(&parts.headers)
+                .is_ok(),
+        })
+    }
+}
+
+/// ユーザーのセッションの権限をusersテーブルから読み直す．ユーザーが削除されている場合はNone．
+/// Secrets.tomlの管理者のセッションはそのまま返す．
+async fn current_claims(
+    parts: &Parts,
+    claims: SessionClaims,
+) -> Result<Option<SessionClaims>, AppCommonError> {
+    let user_id = match claims.user_id {
+        Some(user_id) => user_id,
+        None => return Ok(Some(claims)),
+    };
+    let user_repo = match parts.extensions.get::<SessionUserRepository>() {
+        Some(user_repo) => user_repo.clone(),
+        None => {
+            tracing::error!("SessionUserRepository is not added to the router as an extension.");
+            return Ok(None);
+        }
+    };
+
+    let user = user_repo.find_by_id(user_id).await?;
+    Ok(user.map(|user| SessionClaims {
+        role: user.role(),
+        ..claims
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Caller, SessionUserRepository};
+    use crate::auth::test_utils::{
+        auth_config, edit_token_cookie, session_cookie_of, user_session_cookie_of,
+    };
+    use crate::auth::SessionClaims;
+    use common::AppCommonError;
+    use domain::revision::Actor;
+    use domain::user::{Role, User, UserId};
+    use domain::UserRepository;
+    use infrastructure::user_repository_impl::InMemoryUserRepository;
+    use infrastructure::InfraError;
+
+    use axum::{
+        body::Body,
+        http::{self, header, Request, StatusCode},
+        routing::{delete, patch, put},
+        Extension, Router,
+    };
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use std::sync::Arc;
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        Router::new()
+            .route(
+                "/resource",
+                put(|caller: Caller| async move { caller.require(Role::Contributor) }),
+            )
+            .route(
+                "/resource/:id",
+                delete(|caller: Caller| async move { caller.require(Role::Admin) }),
+            )
+            .route(
+                "/editable",
+                patch(|caller: Caller| async move { caller.require_editor() }),
+            )
+            .layer(Extension(auth_config()))
+    }
+
+    fn request(method: http::Method, uri: &str, role: Option<Role>) -> Request<Body> {
+        let builder = Request::builder().method(method).uri(uri);
+        let builder = match role {
+            Some(role) => builder.header(header::COOKIE, session_cookie_of(role)),
+            None => builder,
+        };
+        builder.body(Body::empty()).unwrap()
+    }
+
+    #[test]
+    fn test_require() {
+        assert!(matches!(
+            Caller::default().require(Role::Contributor),
+            Err(AppCommonError::UnauthorizedError)
+        ));
+    }
+
+    #[rstest]
+    #[case(http::Method::PUT, "/resource", None, StatusCode::UNAUTHORIZED)]
+    #[case(
+        http::Method::PUT,
+        "/resource",
+        Some(Role::Contributor),
+        StatusCode::OK
+    )]
+    #[case(http::Method::PUT, "/resource", Some(Role::Admin), StatusCode::OK)]
+    #[case(http::Method::DELETE, "/resource/1", None, StatusCode::UNAUTHORIZED)]
+    #[case(
+        http::Method::DELETE,
+        "/resource/1",
+        Some(Role::Contributor),
+        StatusCode::FORBIDDEN
+    )]
+    #[case(http::Method::DELETE, "/resource/1", Some(Role::Admin), StatusCode::OK)]
+    #[tokio::test]
+    async fn test_caller_permission(
+        mut router: Router,
+        #[case] method: http::Method,
+        #[case] uri: &str,
+        #[case] role: Option<Role>,
+        #[case] status: StatusCode,
+    ) {
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(request(method, uri, role))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), status);
+    }
+
+    #[rstest]
+    #[case(http::Method::PATCH, "/editable", false, StatusCode::UNAUTHORIZED)]
+    #[case(http::Method::PATCH, "/editable", true, StatusCode::OK)]
+    #[case(http::Method::PUT, "/resource", true, StatusCode::UNAUTHORIZED)]
+    #[case(http::Method::DELETE, "/resource/1", true, StatusCode::UNAUTHORIZED)]
+    #[tokio::test]
+    async fn test_caller_edit_token(
+        mut router: Router,
+        #[case] method: http::Method,
+        #[case] uri: &str,
+        #[case] with_edit_token: bool,
+        #[case] status: StatusCode,
+    ) {
+        // 編集用のトークンは`require_editor`のみを満たす
+        let builder = Request::builder().method(method).uri(uri);
+        let builder = match with_edit_token {
+            true => builder.header(header::COOKIE, edit_token_cookie()),
+            false => builder,
+        };
+
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(builder.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), status);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_caller_without_auth_config() {
+        // AuthConfigが無い場合は匿名として扱う
+        let mut router = Router::new().route(
+            "/resource",
+            put(|caller: Caller| async move { caller.require(Role::Contributor) }),
+        );
+
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(request(http::Method::PUT, "/resource", Some(Role::Admin)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    /// `cookie`を持つリクエストのステータス
+    async fn status_of(
+        router: &mut Router,
+        method: http::Method,
+        uri: &str,
+        cookie: &str,
+    ) -> StatusCode {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::COOKIE, cookie)
+            .body(Body::empty())
+            .unwrap();
+        router
+            .ready()
+            .await
+            .unwrap()
+            .call(request)
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_caller_reloads_user(router: Router) -> Result<(), InfraError> {
+        let user_repo = Arc::new(InMemoryUserRepository::new());
+        let mut router = router.layer(Extension(user_repo.clone() as SessionUserRepository));
+
+        let mut user = Faker.fake::<User>();
+        *user.role_mut() = Role::Admin;
+        user_repo.save(user.clone()).await?;
+        let cookie = user_session_cookie_of(&user);
+
+        let status = status_of(&mut router, http::Method::DELETE, "/resource/1", &cookie).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // 権限を下げられたユーザーの古いセッションは現在の権限で確認する
+        *user.role_mut() = Role::Contributor;
+        user_repo.edit(user.clone()).await?;
+        let status = status_of(&mut router, http::Method::DELETE, "/resource/1", &cookie).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let status = status_of(&mut router, http::Method::PUT, "/resource", &cookie).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // 削除されたユーザーの古いセッションは匿名として扱う
+        user_repo.remove(user.id()).await?;
+        let status = status_of(&mut router, http::Method::DELETE, "/resource/1", &cookie).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let status = status_of(&mut router, http::Method::PUT, "/resource", &cookie).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_caller_without_session_user_repository(mut router: Router) {
+        // SessionUserRepositoryが無い場合はユーザーのセッションを匿名として扱う
+        let user = Faker.fake::<User>();
+        let cookie = user_session_cookie_of(&user);
+
+        let status = status_of(&mut router, http::Method::PUT, "/resource", &cookie).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_actor() {
+        let caller_of = |role: Role, user_id: Option<UserId>| Caller {
+            claims: Some(SessionClaims {
+                exp: 0,
+                role,
+                user_id,
+            }),
+            has_edit_token: false,
+        };
+
+        let user_id = UserId::generate();
+        assert_eq!(
+            caller_of(Role::Contributor, Some(user_id)).actor(),
+            Actor::User(user_id)
+        );
+        assert_eq!(caller_of(Role::Admin, None).actor(), Actor::Admin);
+
+        // 編集用のトークンのみを持つ場合は匿名
+        let caller = Caller {
+            claims: None,
+            has_edit_token: true,
+        };
+        assert_eq!(caller.actor(), Actor::Anonymous);
+    }
+}
@@ -0,0 +1,365 @@
+use crate::commands::channel_commands;
+use crate::handlers::{Caller, ValidatedJson};
+use common::AppCommonError;
+use domain::channel::{Channel, ChannelId, ChannelSummary};
+use domain::user::Role;
+
+// channel_usecasesのモック化
+#[cfg(not(test))]
+use crate::usecases::channel_usecases;
+
+#[cfg(test)]
+use crate::usecases::mock_channel_usecases as channel_usecases;
+
+// ChannelRepositoryのモック化
+#[cfg(all(not(test), feature = "inmemory"))]
+use infrastructure::channel_repository_impl::InMemoryChannelRepository as ChannelRepositoryImpl;
+
+#[cfg(all(not(test), not(feature = "inmemory")))]
+use infrastructure::channel_repository_impl::ChannelPgDBRepository as ChannelRepositoryImpl;
+
+#[cfg(test)]
+use infrastructure::channel_repository_impl::MockChannelRepository as ChannelRepositoryImpl;
+
+use axum::{
+    extract::rejection::PathRejection,
+    extract::{Json, Path, State},
+};
+use std::sync::Arc;
+use tracing_attributes::instrument;
+
+#[instrument(skip(channel_repo), err(Display))]
+pub async fn save_channel(
+    caller: Caller,
+    State(channel_repo): State<Arc<ChannelRepositoryImpl>>,
+    channel_res: Result<ValidatedJson<Channel>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let channel = channel_res?.0;
+    let cmd = channel_commands::SaveChannelCommand::new(channel);
+    channel_usecases::save_channel(channel_repo, cmd).await?;
+    Ok(())
+}
+
+#[instrument(skip(channel_repo), err(Display))]
+pub async fn edit_channel(
+    caller: Caller,
+    State(channel_repo): State<Arc<ChannelRepositoryImpl>>,
+    channel_res: Result<ValidatedJson<Channel>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let channel = channel_res?.0;
+    let cmd = channel_commands::EditChannelCommand::new(channel);
+    channel_usecases::edit_channel(channel_repo, cmd).await?;
+    Ok(())
+}
+
+#[instrument(skip(channel_repo), err(Display))]
+pub async fn all_channels(
+    State(channel_repo): State<Arc<ChannelRepositoryImpl>>,
+) -> Result<Json<Vec<Channel>>, AppCommonError> {
+    let cmd = channel_commands::AllChannelsCommand;
+    let channels = channel_usecases::all_channels(channel_repo, cmd).await?;
+    Ok(Json(channels))
+}
+
+#[instrument(skip(channel_repo), err(Display))]
+pub async fn find_channel(
+    id: Result<Path<ChannelId>, PathRejection>,
+    State(channel_repo): State<Arc<ChannelRepositoryImpl>>,
+) -> Result<Json<Channel>, AppCommonError> {
+    let id = id?.0;
+    let cmd = channel_commands::FindChannelCommand::new(id);
+    let channel = channel_usecases::find_channel(channel_repo, cmd).await?;
+    Ok(Json(channel))
+}
+
+/// 全てのチャンネルを動画の数とともに名前の順で返す．
+#[instrument(skip(channel_repo), err(Display))]
+pub async fn channel_summaries(
+    State(channel_repo): State<Arc<ChannelRepositoryImpl>>,
+) -> Result<Json<Vec<ChannelSummary>>, AppCommonError> {
+    let cmd = channel_commands::ChannelSummariesCommand;
+    let summaries = channel_usecases::channel_summaries(channel_repo, cmd).await?;
+    Ok(Json(summaries))
+}
+
+#[instrument(skip(channel_repo), err(Display))]
+pub async fn remove_channel(
+    caller: Caller,
+    id: Result<Path<ChannelId>, PathRejection>,
+    State(channel_repo): State<Arc<ChannelRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = channel_commands::RemoveChannelCommand::new(id);
+    channel_usecases::remove_channel(channel_repo, cmd).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::auth::test_utils::{auth_config, edit_token_cookie, session_cookie_of};
+    use crate::usecases::mock_channel_usecases;
+    use common::AppCommonError;
+    use domain::channel::{Channel, ChannelId, ChannelSummary};
+    use domain::user::Role;
+    use infrastructure::channel_repository_impl::MockChannelRepository;
+
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+        routing::{get, put},
+        Extension, Router,
+    };
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use serial_test::serial;
+    use std::sync::Arc;
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        let channel_repo = Arc::new(MockChannelRepository::new());
+
+        Router::new()
+            .route(
+                "/channel",
+                put(super::save_channel)
+                    .patch(super::edit_channel)
+                    .get(super::all_channels),
+            )
+            .route("/channel/summary", get(super::channel_summaries))
+            .route(
+                "/channel/:id",
+                get(super::find_channel).delete(super::remove_channel),
+            )
+            .with_state(channel_repo)
+            .layer(Extension(auth_config()))
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_channel")]
+    async fn test_save_channel(mut router: Router) {
+        let channel = Faker.fake::<Channel>();
+        {
+            let mock_ctx = mock_channel_usecases::save_channel_context();
+            mock_ctx
+                .expect::<MockChannelRepository>()
+                .withf({
+                    let channel = channel.clone();
+                    move |_, cmd| cmd.channel == channel
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/channel")
+                .body(Body::from(serde_json::to_vec(&channel).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            // 同じ名前のチャンネルが存在する場合
+            let mock_ctx = mock_channel_usecases::save_channel_context();
+            mock_ctx
+                .expect::<MockChannelRepository>()
+                .times(1)
+                .return_const(Err(AppCommonError::ConflictError));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/channel")
+                .body(Body::from(serde_json::to_vec(&channel).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+        }
+        {
+            // 無効な名前のチャンネルは受け付けない
+            let mut channel_json = serde_json::to_value(&channel).unwrap();
+            channel_json["name"] = serde_json::Value::from(" ");
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/channel")
+                .body(Body::from(serde_json::to_vec(&channel_json).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        }
+        {
+            // 匿名では登録できない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/channel")
+                .body(Body::from(serde_json::to_vec(&channel).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_channel")]
+    async fn test_edit_channel(mut router: Router) {
+        let channel = Faker.fake::<Channel>();
+
+        let mock_ctx = mock_channel_usecases::edit_channel_context();
+        mock_ctx
+            .expect::<MockChannelRepository>()
+            .withf({
+                let channel = channel.clone();
+                move |_, cmd| cmd.channel == channel
+            })
+            .times(1)
+            .return_const(Err(AppCommonError::NoRecordError));
+
+        let request = Request::builder()
+            .method(http::Method::PATCH)
+            .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .uri("/channel")
+            .body(Body::from(serde_json::to_vec(&channel).unwrap()))
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(err, AppCommonError::NoRecordError));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_channel")]
+    async fn test_all_and_find_channel(mut router: Router) {
+        let channels = (0..10).map(|_| Faker.fake::<Channel>()).collect::<Vec<_>>();
+        {
+            let mock_ctx = mock_channel_usecases::all_channels_context();
+            mock_ctx
+                .expect::<MockChannelRepository>()
+                .times(1)
+                .return_const(Ok(channels.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri("/channel")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res_vec: Vec<Channel> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res_vec, channels);
+        }
+        {
+            let channel = channels[0].clone();
+            let mock_ctx = mock_channel_usecases::find_channel_context();
+            mock_ctx
+                .expect::<MockChannelRepository>()
+                .withf({
+                    let id = channel.id();
+                    move |_, cmd| cmd.id == id
+                })
+                .times(1)
+                .return_const(Ok(channel.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri(&format!("/channel/{}", channel.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res_channel: Channel = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res_channel, channel);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_channel")]
+    async fn test_channel_summaries(mut router: Router) {
+        let summaries = (0..10)
+            .map(|i| ChannelSummary::new(Faker.fake(), i))
+            .collect::<Vec<_>>();
+
+        let mock_ctx = mock_channel_usecases::channel_summaries_context();
+        mock_ctx
+            .expect::<MockChannelRepository>()
+            .times(1)
+            .return_const(Ok(summaries.clone()));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/channel/summary")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let res_vec: Vec<ChannelSummary> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(res_vec, summaries);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_channel")]
+    async fn test_remove_channel(mut router: Router) {
+        let channel_id = ChannelId::generate();
+        {
+            // 動画が紐付いている場合
+            let mock_ctx = mock_channel_usecases::remove_channel_context();
+            mock_ctx
+                .expect::<MockChannelRepository>()
+                .withf(move |_, cmd| cmd.id == channel_id)
+                .times(1)
+                .return_const(Err(AppCommonError::ConflictError));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/channel/{channel_id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+        }
+        {
+            // Contributorは削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/channel/{channel_id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+}
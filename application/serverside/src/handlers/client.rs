@@ -0,0 +1,128 @@
+use crate::auth::AuthConfig;
+use common::AppCommonError;
+use domain::client::ClientId;
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use std::sync::Arc;
+
+/// Likeを行う匿名のクライアント．署名付きのクッキーからクライアントのidを取得する．
+/// クッキーが無い・不正な場合は拒否するため，クライアントは先に`/auth/client`でトークンを発行しておく．
+#[derive(Debug, Clone)]
+pub struct Client {
+    id: ClientId,
+}
+
+impl Client {
+    /// クライアントのid
+    pub fn id(&self) -> ClientId {
+        self.id
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Client
+where
+    S: Send + Sync,
+{
+    type Rejection = AppCommonError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let auth_config = parts.extensions.get::<Arc<AuthConfig>>().ok_or_else(|| {
+            tracing::error!("AuthConfig is not added to the router as an extension.");
+            AppCommonError::MissingExtensionError("AuthConfig".to_string())
+        })?;
+        let id = auth_config
+            .verify_client_headers(&parts.headers)
+            .map_err(|_| AppCommonError::NoClientTokenError)?;
+        Ok(Self { id })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Client;
+    use crate::auth::test_utils::{auth_config, client_cookie_of};
+    use crate::auth::CLIENT_COOKIE_NAME;
+    use common::AppCommonError;
+    use domain::client::ClientId;
+
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+        routing::get,
+        Extension, Router,
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        Router::new()
+            .route(
+                "/client",
+                get(|client: Client| async move { client.id().to_string() }),
+            )
+            .layer(Extension(auth_config()))
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_client_with_cookie(mut router: Router) {
+        let client_id = ClientId::generate();
+        let request = Request::builder()
+            .uri("/client")
+            .header(header::COOKIE, client_cookie_of(client_id))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(body, client_id.to_string());
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(format!("{CLIENT_COOKIE_NAME}=invalid")))]
+    #[tokio::test]
+    async fn test_client_without_valid_cookie(mut router: Router, #[case] cookie: Option<String>) {
+        let builder = Request::builder().uri("/client");
+        let builder = match cookie {
+            Some(cookie) => builder.header(header::COOKIE, cookie),
+            None => builder,
+        };
+
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(builder.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        // 新しいidを発行せずに拒否する
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(response.headers().get(header::SET_COOKIE).is_none());
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(err, AppCommonError::NoClientTokenError));
+    }
+
+    #[tokio::test]
+    async fn test_client_without_auth_config() {
+        let mut router = Router::new().route(
+            "/client",
+            get(|client: Client| async move { client.id().to_string() }),
+        );
+        let request = Request::builder()
+            .uri("/client")
+            .header(header::COOKIE, client_cookie_of(ClientId::generate()))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,623 @@
+use crate::auth::AuthConfig;
+use crate::commands::clip_series_commands;
+use crate::handlers::{Caller, Client};
+use common::{AppCommonError, NewClipSeriesInfo, Page};
+use domain::clip_series::{ClipSeries, ClipSeriesId};
+use domain::user::Role;
+use domain::{FromInput, PageSize};
+
+// clip_series_usecasesのモック化
+#[cfg(not(test))]
+use crate::usecases::clip_series_usecases;
+
+#[cfg(test)]
+use crate::usecases::mock_clip_series_usecases as clip_series_usecases;
+
+// ClipSeriesRepositoryのモック化
+#[cfg(all(not(test), feature = "inmemory"))]
+use infrastructure::clip_series_repository_impl::InMemoryClipSeriesRepository as ClipSeriesRepositoryImpl;
+
+#[cfg(all(not(test), not(feature = "inmemory")))]
+use infrastructure::clip_series_repository_impl::ClipSeriesPgDBRepository as ClipSeriesRepositoryImpl;
+
+#[cfg(test)]
+use infrastructure::clip_series_repository_impl::MockClipSeriesRepository as ClipSeriesRepositoryImpl;
+
+use axum::{
+    extract::rejection::{JsonRejection, PathRejection, QueryRejection},
+    extract::{Json, Path, Query, State},
+    Extension,
+};
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing_attributes::instrument;
+
+#[instrument(skip(clip_series_repo), err(Display))]
+pub async fn save_clip_series(
+    caller: Caller,
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+    clip_series_info_res: Result<Json<NewClipSeriesInfo>, JsonRejection>,
+) -> Result<Json<ClipSeries>, AppCommonError> {
+    caller.require_editor()?;
+    let clip_series_info = clip_series_info_res?.0;
+
+    let cmd = clip_series_commands::SaveClipSeriesCommand::new(clip_series_info);
+    let clip_series = clip_series_usecases::save_clip_series(clip_series_repo, cmd).await?;
+    Ok(Json(clip_series))
+}
+
+#[instrument(skip(clip_series_repo), err(Display))]
+pub async fn edit_clip_series(
+    caller: Caller,
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+    clip_series_res: Result<Json<ClipSeries>, JsonRejection>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let clip_series = clip_series_res?.0;
+
+    let cmd = clip_series_commands::EditClipSeriesCommand::new(clip_series);
+    clip_series_usecases::edit_clip_series(clip_series_repo, cmd).await?;
+
+    Ok(())
+}
+
+/// クライアントごとに一度だけLikeをインクリメントする．
+#[instrument(skip(clip_series_repo), err(Display))]
+pub async fn increment_like_clip_series(
+    client: Client,
+    id: Result<Path<ClipSeriesId>, PathRejection>,
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    let id = id?.0;
+    let cmd = clip_series_commands::IncrementLikeClipSeriesCommand::new(id, client.id());
+    clip_series_usecases::increment_like_clip_series(clip_series_repo, cmd).await?;
+    Ok(())
+}
+
+/// クライアントのLikeを取り消す．
+#[instrument(skip(clip_series_repo), err(Display))]
+pub async fn unlike_clip_series(
+    client: Client,
+    id: Result<Path<ClipSeriesId>, PathRejection>,
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    let id = id?.0;
+    let cmd = clip_series_commands::UnlikeClipSeriesCommand::new(id, client.id());
+    clip_series_usecases::unlike_clip_series(clip_series_repo, cmd).await?;
+    Ok(())
+}
+
+/// jsonで与えたidのうちクライアントがLikeしているClipSeriesのidを返す．
+#[instrument(skip(clip_series_repo, ids_res), err(Display))]
+pub async fn liked_clip_series_ids(
+    client: Client,
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+    ids_res: Result<Json<Vec<ClipSeriesId>>, JsonRejection>,
+) -> Result<Json<Vec<ClipSeriesId>>, AppCommonError> {
+    let ids = ids_res?.0;
+    let cmd = clip_series_commands::LikedClipSeriesIdsCommand::new(ids, client.id());
+    let liked_ids = clip_series_usecases::liked_clip_series_ids(clip_series_repo, cmd).await?;
+    Ok(Json(liked_ids))
+}
+
+#[instrument(skip(clip_series_repo), err(Display))]
+pub async fn all_clip_series(
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+) -> Result<Json<Vec<ClipSeries>>, AppCommonError> {
+    let cmd = clip_series_commands::AllClipSeriesCommand;
+    let all_clip_series = clip_series_usecases::all_clip_series(clip_series_repo, cmd).await?;
+    Ok(Json(all_clip_series))
+}
+
+#[instrument(skip(clip_series_repo), err(Display))]
+pub async fn find_clip_series(
+    id: Result<Path<ClipSeriesId>, PathRejection>,
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+) -> Result<Json<ClipSeries>, AppCommonError> {
+    let id = id?.0;
+    let cmd = clip_series_commands::FindClipSeriesCommand::new(id);
+    let clip_series = clip_series_usecases::find_clip_series(clip_series_repo, cmd).await?;
+    Ok(Json(clip_series))
+}
+
+#[derive(Deserialize, strum_macros::EnumString, Debug)]
+#[serde(try_from = "String")]
+#[strum(serialize_all = "snake_case")]
+pub enum SortType {
+    CreateDate,
+    Like,
+}
+
+impl TryFrom<String> for SortType {
+    type Error = <SortType as FromStr>::Err;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Likeでソートしたシリーズのカーソルのスコープ
+const LIKE_CURSOR_SCOPE: &str = "clip_series.like";
+/// 作成日でソートしたシリーズのカーソルのスコープ
+const CREATE_DATE_CURSOR_SCOPE: &str = "clip_series.create_date";
+
+#[derive(Deserialize, Debug)]
+pub struct ClipSeriesQuery {
+    sort_type: SortType,
+    /// 与えられない場合はデフォルトのサイズとなる．`PageSize`としてハンドラで検証する
+    length: Option<i64>,
+    /// 前のページの`next_cursor`
+    cursor: Option<String>,
+}
+
+#[instrument(skip(auth_config, clip_series_repo), err(Display))]
+pub async fn get_clip_series_with_query(
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
+    query_res: Result<Query<ClipSeriesQuery>, QueryRejection>,
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+) -> Result<Json<Page<ClipSeries>>, AppCommonError> {
+    let query = query_res?.0;
+    let length = Option::<PageSize>::from_input(query.length, "length")?.unwrap_or_default();
+
+    match query.sort_type {
+        // Likeでソートする場合
+        SortType::Like => {
+            let page = match query.cursor {
+                Some(cursor) => {
+                    let cursor = auth_config.verify_cursor(LIKE_CURSOR_SCOPE, &cursor)?;
+                    let cmd = clip_series_commands::OrderByLikeLaterClipSeriesCommand::new(
+                        cursor, length,
+                    );
+                    clip_series_usecases::order_by_like_later_clip_series(clip_series_repo, cmd)
+                        .await?
+                }
+                None => {
+                    let cmd = clip_series_commands::OrderByLikeClipSeriesCommand::new(length);
+                    clip_series_usecases::order_by_like_clip_series(clip_series_repo, cmd).await?
+                }
+            };
+            Ok(Json(page.with_next_cursor(|clip_series| {
+                auth_config.issue_cursor(LIKE_CURSOR_SCOPE, clip_series.like_cursor())
+            })))
+        }
+        // CreateDateでソートする場合
+        SortType::CreateDate => {
+            let page = match query.cursor {
+                Some(cursor) => {
+                    let cursor = auth_config.verify_cursor(CREATE_DATE_CURSOR_SCOPE, &cursor)?;
+                    let cmd = clip_series_commands::OrderByCreateDateLaterClipSeriesCommand::new(
+                        cursor, length,
+                    );
+                    clip_series_usecases::order_by_create_date_later_clip_series(
+                        clip_series_repo,
+                        cmd,
+                    )
+                    .await?
+                }
+                None => {
+                    let cmd = clip_series_commands::OrderByCreateDateClipSeriesCommand::new(length);
+                    clip_series_usecases::order_by_create_date_clip_series(clip_series_repo, cmd)
+                        .await?
+                }
+            };
+            Ok(Json(page.with_next_cursor(|clip_series| {
+                auth_config.issue_cursor(CREATE_DATE_CURSOR_SCOPE, clip_series.create_date_cursor())
+            })))
+        }
+    }
+}
+
+#[instrument(skip(clip_series_repo), err(Display))]
+pub async fn remove_clip_series(
+    caller: Caller,
+    id: Result<Path<ClipSeriesId>, PathRejection>,
+    State(clip_series_repo): State<Arc<ClipSeriesRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = clip_series_commands::RemoveClipSeriesCommand::new(id);
+    clip_series_usecases::remove_clip_series(clip_series_repo, cmd).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::auth::test_utils::{
+        auth_config, client_cookie_of, edit_token_cookie, session_cookie_of,
+    };
+    use crate::usecases::mock_clip_series_usecases;
+    use common::{AppCommonError, NewClipSeriesInfo, Page};
+    use domain::client::ClientId;
+    use domain::clip_series::{ClipSeries, ClipSeriesId};
+    use domain::user::Role;
+    use domain::PageSize;
+    use infrastructure::clip_series_repository_impl::MockClipSeriesRepository;
+
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+        routing::{get, patch, post, put},
+        Extension, Router,
+    };
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use serial_test::serial;
+    use std::sync::Arc;
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        let clip_series_repo = Arc::new(MockClipSeriesRepository::new());
+
+        Router::new()
+            .route(
+                "/clip_series",
+                put(super::save_clip_series)
+                    .patch(super::edit_clip_series)
+                    .get(super::all_clip_series),
+            )
+            .route("/clip_series/query", get(super::get_clip_series_with_query))
+            .route(
+                "/clip_series/:id",
+                get(super::find_clip_series).delete(super::remove_clip_series),
+            )
+            .route(
+                "/clip_series/increment_like/:id",
+                patch(super::increment_like_clip_series),
+            )
+            .route("/clip_series/unlike/:id", patch(super::unlike_clip_series))
+            .route("/clip_series/liked", post(super::liked_clip_series_ids))
+            .with_state(clip_series_repo)
+            .layer(Extension(auth_config()))
+    }
+
+    #[fixture]
+    fn clip_series_vec() -> Vec<ClipSeries> {
+        (0..100)
+            .map(|_| Faker.fake::<ClipSeries>())
+            .collect::<Vec<_>>()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_clip_series")]
+    async fn test_save_clip_series(mut router: Router) {
+        let clip_series = Faker.fake::<ClipSeries>();
+        let clip_series_info = NewClipSeriesInfo::from(clip_series.clone());
+
+        let mock_ctx = mock_clip_series_usecases::save_clip_series_context();
+        mock_ctx
+            .expect::<MockClipSeriesRepository>()
+            .withf({
+                let clip_series_info = clip_series_info.clone();
+                move |_, cmd| cmd.clip_series_info == clip_series_info
+            })
+            .times(1)
+            .return_const(Ok(clip_series.clone()));
+
+        let request = Request::builder()
+            .method(http::Method::PUT)
+            .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .uri("/clip_series")
+            .body(Body::from(serde_json::to_vec(&clip_series_info).unwrap()))
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let saved_clip_series: ClipSeries = serde_json::from_slice(&body).unwrap();
+        assert_eq!(saved_clip_series, clip_series);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_clip_series")]
+    async fn test_edit_clip_series(mut router: Router) {
+        let clip_series = Faker.fake::<ClipSeries>();
+
+        let mock_ctx = mock_clip_series_usecases::edit_clip_series_context();
+        mock_ctx
+            .expect::<MockClipSeriesRepository>()
+            .withf({
+                let clip_series = clip_series.clone();
+                move |_, cmd| cmd.clip_series == clip_series
+            })
+            .times(1)
+            .return_const(Err(AppCommonError::NoRecordError));
+
+        let request = Request::builder()
+            .method(http::Method::PATCH)
+            .header(http::header::COOKIE, edit_token_cookie())
+            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .uri("/clip_series")
+            .body(Body::from(serde_json::to_vec(&clip_series).unwrap()))
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(err, AppCommonError::NoRecordError));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_clip_series")]
+    async fn test_like_clip_series(mut router: Router) {
+        let clip_series_id = ClipSeriesId::generate();
+        let client_id = ClientId::generate();
+        {
+            let mock_ctx = mock_clip_series_usecases::increment_like_clip_series_context();
+            mock_ctx
+                .expect::<MockClipSeriesRepository>()
+                .withf(move |_, cmd| cmd.id == clip_series_id && cmd.client == client_id)
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .uri(&format!("/clip_series/increment_like/{clip_series_id}"))
+                .header(http::header::COOKIE, client_cookie_of(client_id))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            let mock_ctx = mock_clip_series_usecases::unlike_clip_series_context();
+            mock_ctx
+                .expect::<MockClipSeriesRepository>()
+                .withf(move |_, cmd| cmd.id == clip_series_id && cmd.client == client_id)
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .uri(&format!("/clip_series/unlike/{clip_series_id}"))
+                .header(http::header::COOKIE, client_cookie_of(client_id))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            // クッキーが無い場合は新しいクライアントとして扱わずに拒否する
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .uri(&format!("/clip_series/unlike/{clip_series_id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+            assert!(matches!(err, AppCommonError::NoClientTokenError));
+        }
+        {
+            let ids = (0..10)
+                .map(|_| ClipSeriesId::generate())
+                .collect::<Vec<_>>();
+            let liked_ids = ids[..5].to_vec();
+
+            let mock_ctx = mock_clip_series_usecases::liked_clip_series_ids_context();
+            mock_ctx
+                .expect::<MockClipSeriesRepository>()
+                .withf({
+                    let ids = ids.clone();
+                    move |_, cmd| cmd.ids == ids && cmd.client == client_id
+                })
+                .times(1)
+                .return_const(Ok(liked_ids.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::POST)
+                .uri("/clip_series/liked")
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .header(http::header::COOKIE, client_cookie_of(client_id))
+                .body(Body::from(serde_json::to_vec(&ids).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res_ids: Vec<ClipSeriesId> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res_ids, liked_ids);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_clip_series")]
+    async fn test_all_and_find_clip_series(mut router: Router, clip_series_vec: Vec<ClipSeries>) {
+        {
+            let mock_ctx = mock_clip_series_usecases::all_clip_series_context();
+            mock_ctx
+                .expect::<MockClipSeriesRepository>()
+                .times(1)
+                .return_const(Ok(clip_series_vec.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri("/clip_series")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res_vec: Vec<ClipSeries> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res_vec, clip_series_vec);
+        }
+        {
+            let clip_series = clip_series_vec[0].clone();
+
+            let mock_ctx = mock_clip_series_usecases::find_clip_series_context();
+            mock_ctx
+                .expect::<MockClipSeriesRepository>()
+                .withf({
+                    let id = clip_series.id();
+                    move |_, cmd| cmd.id == id
+                })
+                .times(1)
+                .return_const(Ok(clip_series.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri(&format!("/clip_series/{}", clip_series.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: ClipSeries = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, clip_series);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_clip_series")]
+    async fn test_order_by_like_clip_series(mut router: Router, clip_series_vec: Vec<ClipSeries>) {
+        let length = 50_usize;
+        let cursor = Faker.fake::<ClipSeries>().like_cursor();
+
+        let mock_ctx = mock_clip_series_usecases::order_by_like_later_clip_series_context();
+        mock_ctx
+            .expect::<MockClipSeriesRepository>()
+            .withf(move |_, cmd| cmd.cursor == cursor && cmd.length.get() == length)
+            .times(1)
+            .return_const(Ok(Page::new(clip_series_vec.clone(), true, None)));
+
+        let cursor_token = auth_config().issue_cursor("clip_series.like", cursor);
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri(&format!(
+                "/clip_series/query?sort_type=like&length={length}&cursor={cursor_token}"
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<ClipSeries> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(page.items, clip_series_vec);
+        // 次のページのカーソル
+        let next_cursor = auth_config()
+            .verify_cursor("clip_series.like", &page.next_cursor.unwrap())
+            .unwrap();
+        assert_eq!(next_cursor, clip_series_vec.last().unwrap().like_cursor());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_clip_series")]
+    async fn test_order_by_create_date_clip_series(
+        mut router: Router,
+        clip_series_vec: Vec<ClipSeries>,
+    ) {
+        let mock_ctx = mock_clip_series_usecases::order_by_create_date_clip_series_context();
+        mock_ctx
+            .expect::<MockClipSeriesRepository>()
+            .withf(|_, cmd| cmd.length == PageSize::default())
+            .times(1)
+            .return_const(Ok(Page::new(clip_series_vec.clone(), false, None)));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/clip_series/query?sort_type=create_date")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<ClipSeries> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(page.items, clip_series_vec);
+        assert_eq!(page.next_cursor, None);
+
+        // 別のソートのカーソルは受け付けない
+        let cursor_token = auth_config()
+            .issue_cursor("clip_series.like", Faker.fake::<ClipSeries>().like_cursor());
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri(&format!(
+                "/clip_series/query?sort_type=create_date&cursor={cursor_token}"
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_clip_series")]
+    async fn test_clip_series_permission(mut router: Router) {
+        let clip_series = Faker.fake::<ClipSeries>();
+        {
+            // セッションが無い場合は保存できない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/clip_series")
+                .body(Body::from(
+                    serde_json::to_vec(&NewClipSeriesInfo::from(clip_series.clone())).unwrap(),
+                ))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // Contributorは削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/clip_series/{}", clip_series.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+        {
+            // 管理者は削除できる
+            let mock_ctx = mock_clip_series_usecases::remove_clip_series_context();
+            mock_ctx
+                .expect::<MockClipSeriesRepository>()
+                .withf({
+                    let id = clip_series.id();
+                    move |_, cmd| cmd.id == id
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/clip_series/{}", clip_series.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+}
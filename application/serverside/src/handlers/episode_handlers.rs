@@ -1,7 +1,10 @@
 use crate::commands::episode_commands;
+use crate::handlers::{Caller, LatestRevisionsQuery, RevisionDiffQuery, ValidatedJson};
 use common::AppCommonError;
 use domain::episode::{Episode, EpisodeId};
-use domain::Date;
+use domain::revision::{FieldChange, Revision, RevisionId};
+use domain::user::Role;
+use domain::{Date, Tag};
 
 /// usecaseについてのダブル
 #[cfg(not(test))]
@@ -21,7 +24,7 @@ use infrastructure::episode_repository_impl::EpisodePgDBRepository as EpisodeRep
 use infrastructure::episode_repository_impl::MockEpisodeRepository as EpisodeRepositoryImpl;
 
 use axum::{
-    extract::rejection::{JsonRejection, PathRejection, QueryRejection},
+    extract::rejection::{PathRejection, QueryRejection},
     extract::{Json, Path, Query, State},
 };
 
@@ -33,22 +36,26 @@ use std::sync::Arc;
 
 #[instrument(skip(episode_repo), err(Display))]
 pub async fn save_episode(
+    caller: Caller,
     State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
-    episode_res: Result<Json<Episode>, JsonRejection>,
+    episode_res: Result<ValidatedJson<Episode>, AppCommonError>,
 ) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
     let episode = episode_res?.0;
-    let cmd = episode_commands::SaveEpisodeCommand::new(episode);
+    let cmd = episode_commands::SaveEpisodeCommand::new(episode, caller.actor());
     episode_usecases::save_episode(episode_repo, cmd).await?;
     Ok(())
 }
 
 #[instrument(skip(episode_repo), err(Display))]
 pub async fn edit_episode(
+    caller: Caller,
     State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
-    episode_res: Result<Json<Episode>, JsonRejection>,
+    episode_res: Result<ValidatedJson<Episode>, AppCommonError>,
 ) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
     let episode = episode_res?.0;
-    let cmd = episode_commands::EditEpisodeCommand::new(episode);
+    let cmd = episode_commands::EditEpisodeCommand::new(episode, caller.actor());
     episode_usecases::edit_episode(episode_repo, cmd).await?;
     Ok(())
 }
@@ -85,6 +92,8 @@ pub struct EpisodeQuery {
     end: Option<Date>,
     /// クエリのソートタイプ
     sort_type: SortType,
+    /// `,`で区切ったタグ．全てのタグを持つエピソードのみを返す．
+    tags: Option<String>,
 }
 
 #[instrument(skip(episode_repo), err(Display))]
@@ -93,10 +102,16 @@ pub async fn get_episodes_with_query(
     State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
 ) -> Result<Json<Vec<Episode>>, AppCommonError> {
     let query = query_res?.0;
+    let tags = query
+        .tags
+        .as_deref()
+        .map(Tag::parse_list)
+        .transpose()?
+        .unwrap_or_default();
 
     match (query.sort_type, query.start, query.end) {
         (SortType::Date, Some(start), Some(end)) => {
-            let cmd = episode_commands::OrderByDateRangeEpisodeCommand::new(start, end);
+            let cmd = episode_commands::OrderByDateRangeEpisodeCommand::new(tags, start, end);
             let episodes =
                 episode_usecases::order_by_date_range_episodes(episode_repo, cmd).await?;
             Ok(Json(episodes))
@@ -107,30 +122,182 @@ pub async fn get_episodes_with_query(
     }
 }
 
+/// エピソードに付けられている全てのタグを返す．
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn all_episode_tags(
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<Json<Vec<Tag>>, AppCommonError> {
+    let cmd = episode_commands::AllEpisodeTagsCommand;
+    let tags = episode_usecases::all_episode_tags(episode_repo, cmd).await?;
+    Ok(Json(tags))
+}
+
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn episode_tags(
+    id: Result<Path<EpisodeId>, PathRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<Json<Vec<Tag>>, AppCommonError> {
+    let id = id?.0;
+    let cmd = episode_commands::EpisodeTagsCommand::new(id);
+    let tags = episode_usecases::episode_tags(episode_repo, cmd).await?;
+    Ok(Json(tags))
+}
+
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn add_episode_tag(
+    caller: Caller,
+    id: Result<Path<EpisodeId>, PathRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+    tag_res: Result<ValidatedJson<Tag>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let id = id?.0;
+    let tag = tag_res?.0;
+    let cmd = episode_commands::AddEpisodeTagCommand::new(id, tag);
+    episode_usecases::add_episode_tag(episode_repo, cmd).await?;
+    Ok(())
+}
+
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn remove_episode_tag(
+    caller: Caller,
+    id: Result<Path<EpisodeId>, PathRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+    tag_res: Result<ValidatedJson<Tag>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let id = id?.0;
+    let tag = tag_res?.0;
+    let cmd = episode_commands::RemoveEpisodeTagCommand::new(id, tag);
+    episode_usecases::remove_episode_tag(episode_repo, cmd).await?;
+    Ok(())
+}
+
 #[instrument(skip(episode_repo), err(Display))]
 pub async fn remove_episode(
+    caller: Caller,
     id: Result<Path<EpisodeId>, PathRejection>,
     State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
 ) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
     let id = id?.0;
-    let cmd = episode_commands::RemoveEpisodeCommand::new(id);
+    let cmd = episode_commands::RemoveEpisodeCommand::new(id, caller.actor());
     episode_usecases::remove_episode(episode_repo, cmd).await?;
     Ok(())
 }
 
+/// ゴミ箱のエピソードを削除した日時の新しい順に返す．協力者以上
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn trashed_episodes(
+    caller: Caller,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<Json<Vec<Episode>>, AppCommonError> {
+    caller.require(Role::Contributor)?;
+    let cmd = episode_commands::TrashedEpisodesCommand;
+    let episodes = episode_usecases::trashed_episodes(episode_repo, cmd).await?;
+    Ok(Json(episodes))
+}
+
+/// ゴミ箱のエピソードを戻す．協力者以上
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn restore_trashed_episode(
+    caller: Caller,
+    id: Result<Path<EpisodeId>, PathRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Contributor)?;
+    let id = id?.0;
+    let cmd = episode_commands::RestoreTrashedEpisodeCommand::new(id, caller.actor());
+    episode_usecases::restore_trashed_episode(episode_repo, cmd).await?;
+    Ok(())
+}
+
+/// ゴミ箱のエピソードを完全に削除する．管理者のみ
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn purge_episode(
+    caller: Caller,
+    id: Result<Path<EpisodeId>, PathRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = episode_commands::PurgeEpisodeCommand::new(id, caller.actor());
+    episode_usecases::purge_episode(episode_repo, cmd).await?;
+    Ok(())
+}
+
+/// エピソードの編集履歴を古い順に返す．管理者のみ
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn episode_revisions(
+    caller: Caller,
+    id: Result<Path<EpisodeId>, PathRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<Json<Vec<Revision>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = episode_commands::EpisodeRevisionsCommand::new(id);
+    let revisions = episode_usecases::episode_revisions(episode_repo, cmd).await?;
+    Ok(Json(revisions))
+}
+
+/// エピソードの最近の編集履歴を新しい順に返す．管理者のみ
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn latest_episode_revisions(
+    caller: Caller,
+    query_res: Result<Query<LatestRevisionsQuery>, QueryRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<Json<Vec<Revision>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let length = query_res?.0.page_size()?;
+    let cmd = episode_commands::LatestEpisodeRevisionsCommand::new(length);
+    let revisions = episode_usecases::latest_episode_revisions(episode_repo, cmd).await?;
+    Ok(Json(revisions))
+}
+
+/// 二つの編集履歴の間の変更を返す．管理者のみ
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn episode_revision_diff(
+    caller: Caller,
+    query_res: Result<Query<RevisionDiffQuery>, QueryRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<Json<Vec<FieldChange>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let RevisionDiffQuery { from, to } = query_res?.0;
+    let cmd = episode_commands::EpisodeRevisionDiffCommand::new(from, to);
+    let changes = episode_usecases::episode_revision_diff(episode_repo, cmd).await?;
+    Ok(Json(changes))
+}
+
+/// 編集履歴の時点の内容に戻し，戻したエピソードを返す．管理者のみ
+#[instrument(skip(episode_repo), err(Display))]
+pub async fn restore_episode_revision(
+    caller: Caller,
+    id: Result<Path<RevisionId>, PathRejection>,
+    State(episode_repo): State<Arc<EpisodeRepositoryImpl>>,
+) -> Result<Json<Episode>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = episode_commands::RestoreEpisodeRevisionCommand::new(id, caller.actor());
+    let episode = episode_usecases::restore_episode_revision(episode_repo, cmd).await?;
+    Ok(Json(episode))
+}
+
 #[cfg(test)]
 mod test {
+    use crate::auth::test_utils::{auth_config, edit_token_cookie, session_cookie_of};
     use crate::usecases::mock_episode_usecases;
     use common::AppCommonError;
     use domain::episode::{Episode, EpisodeId};
-    use domain::Date;
+    use domain::revision::{Actor, Revision, RevisionId};
+    use domain::user::Role;
+    use domain::{Date, PageSize, Tag};
     use infrastructure::episode_repository_impl::MockEpisodeRepository;
 
     use axum::{
         body::Body,
         http::{self, Request, StatusCode},
-        routing::{delete, get, put},
-        Router,
+        routing::{delete, get, patch, put},
+        Extension, Router,
     };
 
     use std::sync::Arc;
@@ -154,7 +321,27 @@ mod test {
             )
             .route("/episode/query", get(super::get_episodes_with_query))
             .route("/episode/:id", delete(super::remove_episode))
+            .route("/episode/tags", get(super::all_episode_tags))
+            .route(
+                "/episode/tags/:id",
+                get(super::episode_tags)
+                    .put(super::add_episode_tag)
+                    .delete(super::remove_episode_tag),
+            )
+            .route("/episode/revisions", get(super::latest_episode_revisions))
+            .route("/episode/revisions/:id", get(super::episode_revisions))
+            .route("/episode/revision_diff", get(super::episode_revision_diff))
+            .route(
+                "/episode/restore/:id",
+                patch(super::restore_episode_revision),
+            )
+            .route("/episode/trash", get(super::trashed_episodes))
+            .route(
+                "/episode/trash/:id",
+                patch(super::restore_trashed_episode).delete(super::purge_episode),
+            )
             .with_state(episode_repo)
+            .layer(Extension(auth_config()))
     }
 
     #[fixture]
@@ -182,6 +369,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/episode")
                 .body(Body::from(serde_json::to_vec(&episode).unwrap()))
@@ -204,6 +392,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/episode")
                 .body(Body::from(serde_json::to_vec(&episode).unwrap()))
@@ -237,6 +426,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/episode")
                 .body(Body::from(serde_json::to_vec(&episode).unwrap()))
@@ -259,6 +449,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/episode")
                 .body(Body::from(serde_json::to_vec(&episode).unwrap()))
@@ -272,6 +463,36 @@ mod test {
 
             assert!(matches!(err, AppCommonError::NoRecordError));
         }
+        {
+            // 他の編集によって版数が進んでいる場合
+            let mut episode = episode.clone();
+            episode.increment_version();
+
+            let mock_ctx_err = mock_episode_usecases::edit_episode_context();
+            mock_ctx_err
+                .expect::<MockEpisodeRepository>()
+                .withf({
+                    let episode = episode.clone();
+                    move |_, cmd| cmd.episode == episode
+                })
+                .return_const(Err(AppCommonError::StaleVersion));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/episode")
+                .body(Body::from(serde_json::to_vec(&episode).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+
+            assert!(matches!(err, AppCommonError::StaleVersion));
+        }
     }
 
     #[rstest]
@@ -308,7 +529,7 @@ mod test {
         let mock_ctx = mock_episode_usecases::order_by_date_range_episodes_context();
         mock_ctx
             .expect::<MockEpisodeRepository>()
-            .withf(move |_, cmd| start == cmd.start && end == cmd.end)
+            .withf(move |_, cmd| cmd.tags.is_empty() && start == cmd.start && end == cmd.end)
             .return_const(Ok(episodes.clone()));
 
         let request = Request::builder()
@@ -328,6 +549,118 @@ mod test {
         assert_eq!(res_vec, episodes);
     }
 
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_episode")]
+    async fn test_order_by_date_episodes_with_tags(mut router: Router, episodes: Vec<Episode>) {
+        let start = Faker.fake::<Date>();
+        let end = Faker.fake::<Date>();
+        {
+            let mock_ctx = mock_episode_usecases::order_by_date_range_episodes_context();
+            mock_ctx
+                .expect::<MockEpisodeRepository>()
+                .withf(move |_, cmd| {
+                    cmd.tags == vec![Tag::new("collab").unwrap(), Tag::new("game").unwrap()]
+                        && start == cmd.start
+                        && end == cmd.end
+                })
+                .times(1)
+                .return_const(Ok(episodes.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri(&format!(
+                    "/episode/query?start={start}&end={end}&sort_type=date&tags=Game,collab,game"
+                ))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res_vec: Vec<Episode> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res_vec, episodes);
+        }
+        {
+            // 長すぎるタグ
+            let too_long_tag = "a".repeat(config::CONFIG.tag_string_lim + 1);
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri(&format!(
+                    "/episode/query?start={start}&end={end}&sort_type=date&tags={too_long_tag}"
+                ))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_episode")]
+    async fn test_episode_tags(mut router: Router) {
+        let episode_id = EpisodeId::generate();
+        let tag = Tag::new("game").unwrap();
+        {
+            let mock_ctx = mock_episode_usecases::episode_tags_context();
+            mock_ctx
+                .expect::<MockEpisodeRepository>()
+                .withf(move |_, cmd| cmd.id == episode_id)
+                .times(1)
+                .return_const(Ok(vec![tag.clone()]));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri(&format!("/episode/tags/{episode_id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let tags: Vec<Tag> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(tags, vec![tag.clone()]);
+        }
+        {
+            // セッションが無い場合はタグを外せない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri(&format!("/episode/tags/{episode_id}"))
+                .body(Body::from(serde_json::to_vec(&tag).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            let mock_ctx = mock_episode_usecases::remove_episode_tag_context();
+            mock_ctx
+                .expect::<MockEpisodeRepository>()
+                .withf({
+                    let tag = tag.clone();
+                    move |_, cmd| cmd.id == episode_id && cmd.tag == tag
+                })
+                .times(1)
+                .return_const(Err(AppCommonError::NoRecordError));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri(&format!("/episode/tags/{episode_id}"))
+                .body(Body::from(serde_json::to_vec(&tag).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+    }
+
     #[rstest]
     #[tokio::test]
     #[serial("mock_episode")]
@@ -343,6 +676,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
                 .uri(&format!("/episode/{episode_id}"))
                 .body(Body::empty())
                 .unwrap();
@@ -361,6 +695,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
                 .uri(&format!("/episode/{episode_id}"))
                 .body(Body::empty())
                 .unwrap();
@@ -374,4 +709,223 @@ mod test {
             assert!(matches!(err, AppCommonError::NoRecordError));
         }
     }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_episode")]
+    async fn test_episode_permission(mut router: Router) {
+        let episode = Faker.fake::<Episode>();
+        {
+            // セッションが無い場合は保存できない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/episode")
+                .body(Body::from(serde_json::to_vec(&episode).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // 編集用のトークンがあれば匿名でも編集できる
+            let mock_ctx_ok = mock_episode_usecases::edit_episode_context();
+            mock_ctx_ok
+                .expect::<MockEpisodeRepository>()
+                .withf({
+                    let episode = episode.clone();
+                    move |_, cmd| cmd.episode == episode
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/episode")
+                .body(Body::from(serde_json::to_vec(&episode).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            // 編集用のトークンでは削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .uri(&format!("/episode/{}", episode.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // Contributorは削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/episode/{}", episode.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_episode")]
+    async fn test_episode_revisions(mut router: Router) {
+        let episode = Faker.fake::<Episode>();
+        let revisions = (0..3).map(|_| Faker.fake::<Revision>()).collect::<Vec<_>>();
+        {
+            let mock_ctx = mock_episode_usecases::latest_episode_revisions_context();
+            mock_ctx
+                .expect::<MockEpisodeRepository>()
+                .withf(|_, cmd| cmd.length == PageSize::default())
+                .times(1)
+                .return_const(Ok(revisions.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri("/episode/revisions")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Vec<Revision> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, revisions);
+        }
+        {
+            let id = RevisionId::generate();
+            let mock_ctx = mock_episode_usecases::restore_episode_revision_context();
+            mock_ctx
+                .expect::<MockEpisodeRepository>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Ok(episode.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/episode/restore/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Episode = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, episode);
+        }
+        {
+            // Contributorは編集履歴を参照できない
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/episode/revisions/{}", episode.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_episode")]
+    async fn test_episode_trash(mut router: Router) {
+        let episodes = (0..3).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>();
+        let id = episodes[0].id();
+        {
+            let mock_ctx = mock_episode_usecases::trashed_episodes_context();
+            mock_ctx
+                .expect::<MockEpisodeRepository>()
+                .times(1)
+                .return_const(Ok(episodes.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri("/episode/trash")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Vec<Episode> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, episodes);
+        }
+        {
+            let mock_ctx = mock_episode_usecases::restore_trashed_episode_context();
+            mock_ctx
+                .expect::<MockEpisodeRepository>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/episode/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            let mock_ctx = mock_episode_usecases::purge_episode_context();
+            mock_ctx
+                .expect::<MockEpisodeRepository>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Err(AppCommonError::NoRecordError));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/episode/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+        {
+            // 編集用のトークンではゴミ箱を参照できない
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .uri("/episode/trash")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // Contributorは完全に削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/episode/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
 }
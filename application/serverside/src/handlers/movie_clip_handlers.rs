@@ -1,7 +1,11 @@
+use crate::auth::AuthConfig;
 use crate::commands::movie_clip_commands;
-use common::{AppCommonError, QueryInfo};
+use crate::handlers::{Caller, Client, LatestRevisionsQuery, RevisionDiffQuery, ValidatedJson};
+use common::{AppCommonError, NewMovieClipInfo, Page};
 use domain::movie_clip::{MovieClip, MovieClipId};
-use domain::Date;
+use domain::revision::{FieldChange, Revision, RevisionId};
+use domain::user::Role;
+use domain::{Date, FromInput, PageSize, Tag};
 
 // movie_clip_usecaseのモック化
 #[cfg(not(test))]
@@ -23,6 +27,7 @@ use infrastructure::movie_clip_repository_impl::MockMovieClipRepository as Movie
 use axum::{
     extract::rejection::{JsonRejection, PathRejection, QueryRejection},
     extract::{Json, Path, Query, State},
+    Extension,
 };
 use serde::Deserialize;
 use std::str::FromStr;
@@ -31,40 +36,88 @@ use tracing_attributes::instrument;
 
 #[instrument(skip(movie_clip_repo), err(Display))]
 pub async fn save_movie_clip(
+    caller: Caller,
     State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
-    movie_clip_res: Result<Json<MovieClip>, JsonRejection>,
-) -> Result<(), AppCommonError> {
-    let movie_clip = movie_clip_res?.0;
-
-    let cmd = movie_clip_commands::SaveMovieClipCommand::new(movie_clip);
-    movie_clip_usecases::save_movie_clip(movie_clip_repo, cmd).await?;
-    Ok(())
+    movie_clip_info_res: Result<ValidatedJson<NewMovieClipInfo>, AppCommonError>,
+) -> Result<Json<MovieClip>, AppCommonError> {
+    caller.require_editor()?;
+    let movie_clip_info = movie_clip_info_res?.0;
+
+    let cmd = movie_clip_commands::SaveMovieClipCommand::new(movie_clip_info, caller.actor());
+    let movie_clip = movie_clip_usecases::save_movie_clip(movie_clip_repo, cmd).await?;
+    Ok(Json(movie_clip))
 }
 
 #[instrument(skip(movie_clip_repo), err(Display))]
 pub async fn edit_movie_clip(
+    caller: Caller,
     State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
-    movie_clip_res: Result<Json<MovieClip>, JsonRejection>,
+    movie_clip_res: Result<ValidatedJson<MovieClip>, AppCommonError>,
 ) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
     let movie_clip = movie_clip_res?.0;
 
-    let cmd = movie_clip_commands::EditMovieClipCommand::new(movie_clip);
+    let cmd = movie_clip_commands::EditMovieClipCommand::new(movie_clip, caller.actor());
     movie_clip_usecases::edit_movie_clip(movie_clip_repo, cmd).await?;
 
     Ok(())
 }
 
+/// バックアップのクリップをid, like, create_dateを保ったまま保存する．管理者のみ
+#[instrument(skip(movie_clip_repo, movie_clips_res), err(Display))]
+pub async fn import_movie_clips(
+    caller: Caller,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+    movie_clips_res: Result<ValidatedJson<Vec<MovieClip>>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let movie_clips = movie_clips_res?.0;
+
+    let cmd = movie_clip_commands::ImportMovieClipsCommand::new(movie_clips, caller.actor());
+    movie_clip_usecases::import_movie_clips(movie_clip_repo, cmd).await?;
+
+    Ok(())
+}
+
+/// クライアントごとに一度だけLikeをインクリメントする．
 #[instrument(skip(movie_clip_repo), err(Display))]
 pub async fn increment_like_movie_clip(
+    client: Client,
     id: Result<Path<MovieClipId>, PathRejection>,
     State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
 ) -> Result<(), AppCommonError> {
     let id = id?.0;
-    let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(id);
+    let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(id, client.id());
     movie_clip_usecases::increment_like_movie_clip(movie_clip_repo, cmd).await?;
     Ok(())
 }
 
+/// クライアントのLikeを取り消す．
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn unlike_movie_clip(
+    client: Client,
+    id: Result<Path<MovieClipId>, PathRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    let id = id?.0;
+    let cmd = movie_clip_commands::UnlikeMovieClipCommand::new(id, client.id());
+    movie_clip_usecases::unlike_movie_clip(movie_clip_repo, cmd).await?;
+    Ok(())
+}
+
+/// jsonで与えたidのうちクライアントがLikeしているMovieClipのidを返す．
+#[instrument(skip(movie_clip_repo, ids_res), err(Display))]
+pub async fn liked_movie_clip_ids(
+    client: Client,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+    ids_res: Result<Json<Vec<MovieClipId>>, JsonRejection>,
+) -> Result<Json<Vec<MovieClipId>>, AppCommonError> {
+    let ids = ids_res?.0;
+    let cmd = movie_clip_commands::LikedMovieClipIdsCommand::new(ids, client.id());
+    let liked_ids = movie_clip_usecases::liked_movie_clip_ids(movie_clip_repo, cmd).await?;
+    Ok(Json(liked_ids))
+}
+
 #[instrument(skip(movie_clip_repo), err(Display))]
 pub async fn all_movie_clips(
     State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
@@ -89,86 +142,104 @@ impl TryFrom<String> for SortType {
     }
 }
 
+/// Likeでソートしたクリップのカーソルのスコープ
+const LIKE_CURSOR_SCOPE: &str = "movie_clip.like";
+/// 作成日でソートしたクリップのカーソルのスコープ
+const CREATE_DATE_CURSOR_SCOPE: &str = "movie_clip.create_date";
+
 #[derive(Deserialize, Debug)]
 pub struct MovieClipQuery {
     sort_type: SortType,
-    length: Option<usize>,
+    /// 与えられない場合はデフォルトのサイズとなる．`PageSize`としてハンドラで検証する
+    length: Option<i64>,
     start: Option<Date>,
     end: Option<Date>,
+    /// 前のページの`next_cursor`
+    cursor: Option<String>,
+    /// `,`で区切ったタグ．全てのタグを持つクリップのみを返す．
+    tags: Option<String>,
 }
 
-#[instrument(skip(movie_clip_repo), err(Display))]
+#[instrument(skip(auth_config, movie_clip_repo), err(Display))]
 pub async fn get_movie_clips_with_query(
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
     query_res: Result<Query<MovieClipQuery>, QueryRejection>,
     State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
-    query_info_res: Result<Json<QueryInfo<MovieClip>>, JsonRejection>,
-) -> Result<Json<Vec<MovieClip>>, AppCommonError> {
+) -> Result<Json<Page<MovieClip>>, AppCommonError> {
     let query = query_res?.0;
-    let movie_clip_reference = match query_info_res {
-        // リクエストにjsonが与えられた場合
-        Ok(reference) => reference.0.reference,
-        // // リクエストにjsonが与えられない場合
-        Err(JsonRejection::MissingJsonContentType(_)) => None,
-        // その他のJsonRejection
-        Err(e) => return Err(e.into()),
-    };
-
-    match (query.sort_type, query.length, query.start, query.end) {
+    let tags = query
+        .tags
+        .as_deref()
+        .map(Tag::parse_list)
+        .transpose()?
+        .unwrap_or_default();
+    let length = Option::<PageSize>::from_input(query.length, "length")?;
+
+    match (
+        query.sort_type,
+        length,
+        query.start,
+        query.end,
+        query.cursor,
+    ) {
         // Likeでソートする場合
-        (SortType::Like, Some(length), None, None) => {
-            match movie_clip_reference {
-                // referenceが存在する場合
-                Some(reference) => {
+        (SortType::Like, length, None, None, cursor) => {
+            let length = length.unwrap_or_default();
+            let page = match cursor {
+                // カーソルが存在する場合
+                Some(cursor) => {
+                    let cursor = auth_config.verify_cursor(LIKE_CURSOR_SCOPE, &cursor)?;
                     let cmd = movie_clip_commands::OrderByLikeLaterMovieClipCommand::new(
-                        reference, length,
+                        tags, cursor, length,
                     );
-                    let clips =
-                        movie_clip_usecases::order_by_like_later_movie_clips(movie_clip_repo, cmd)
-                            .await?;
-                    Ok(Json(clips))
+                    movie_clip_usecases::order_by_like_later_movie_clips(movie_clip_repo, cmd)
+                        .await?
                 }
-                // referenceが存在しない場合
+                // カーソルが存在しない場合
                 None => {
-                    let cmd = movie_clip_commands::OrderByLikeMovieClipCommand::new(length);
-                    let clips =
-                        movie_clip_usecases::order_by_like_movie_clips(movie_clip_repo, cmd)
-                            .await?;
-                    Ok(Json(clips))
+                    let cmd = movie_clip_commands::OrderByLikeMovieClipCommand::new(tags, length);
+                    movie_clip_usecases::order_by_like_movie_clips(movie_clip_repo, cmd).await?
                 }
-            }
+            };
+            Ok(Json(page.with_next_cursor(|clip| {
+                auth_config.issue_cursor(LIKE_CURSOR_SCOPE, clip.like_cursor())
+            })))
         }
-        // CreateDateでソートしlengthを指定する場合
-        (SortType::CreateDate, Some(length), None, None) => {
-            match movie_clip_reference {
-                // referenceが存在する場合
-                Some(reference) => {
+        // CreateDateでソートしstartとendを指定しない場合
+        (SortType::CreateDate, length, None, None, cursor) => {
+            let length = length.unwrap_or_default();
+            let page = match cursor {
+                // カーソルが存在する場合
+                Some(cursor) => {
+                    let cursor = auth_config.verify_cursor(CREATE_DATE_CURSOR_SCOPE, &cursor)?;
                     let cmd = movie_clip_commands::OrderByCreateDateLaterMovieClipCommand::new(
-                        reference, length,
+                        tags, cursor, length,
                     );
-                    let clips = movie_clip_usecases::order_by_create_date_later_movie_clips(
+                    movie_clip_usecases::order_by_create_date_later_movie_clips(
                         movie_clip_repo,
                         cmd,
                     )
-                    .await?;
-                    Ok(Json(clips))
+                    .await?
                 }
-                // referenceが存在しない場合
+                // カーソルが存在しない場合
                 None => {
-                    let cmd = movie_clip_commands::OrderByCreateDateMovieClipCommand::new(length);
-                    let clips =
-                        movie_clip_usecases::order_by_create_date_movie_clips(movie_clip_repo, cmd)
-                            .await?;
-                    Ok(Json(clips))
+                    let cmd =
+                        movie_clip_commands::OrderByCreateDateMovieClipCommand::new(tags, length);
+                    movie_clip_usecases::order_by_create_date_movie_clips(movie_clip_repo, cmd)
+                        .await?
                 }
-            }
+            };
+            Ok(Json(page.with_next_cursor(|clip| {
+                auth_config.issue_cursor(CREATE_DATE_CURSOR_SCOPE, clip.create_date_cursor())
+            })))
         }
-        // CreateDateでソートしstartとendを指定する場合
-        (SortType::CreateDate, None, Some(start), Some(end)) => {
+        // CreateDateでソートしstartとendを指定する場合(タグによる絞り込みはできない)
+        (SortType::CreateDate, None, Some(start), Some(end), None) if tags.is_empty() => {
             let cmd = movie_clip_commands::OrderByCreateDateRangeMovieClipCommand::new(start, end);
             let clips =
                 movie_clip_usecases::order_by_create_date_range_movie_clips(movie_clip_repo, cmd)
                     .await?;
-            Ok(Json(clips))
+            Ok(Json(Page::new(clips, false, None)))
         }
         // 無効なクエリの場合
         _ => Err(AppCommonError::QueryStringRejectionError(
@@ -177,36 +248,192 @@ pub async fn get_movie_clips_with_query(
     }
 }
 
+/// クリップに付けられている全てのタグを返す．
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn all_movie_clip_tags(
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<Json<Vec<Tag>>, AppCommonError> {
+    let cmd = movie_clip_commands::AllMovieClipTagsCommand;
+    let tags = movie_clip_usecases::all_movie_clip_tags(movie_clip_repo, cmd).await?;
+    Ok(Json(tags))
+}
+
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn movie_clip_tags(
+    id: Result<Path<MovieClipId>, PathRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<Json<Vec<Tag>>, AppCommonError> {
+    let id = id?.0;
+    let cmd = movie_clip_commands::MovieClipTagsCommand::new(id);
+    let tags = movie_clip_usecases::movie_clip_tags(movie_clip_repo, cmd).await?;
+    Ok(Json(tags))
+}
+
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn add_movie_clip_tag(
+    caller: Caller,
+    id: Result<Path<MovieClipId>, PathRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+    tag_res: Result<ValidatedJson<Tag>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let id = id?.0;
+    let tag = tag_res?.0;
+
+    let cmd = movie_clip_commands::AddMovieClipTagCommand::new(id, tag);
+    movie_clip_usecases::add_movie_clip_tag(movie_clip_repo, cmd).await?;
+    Ok(())
+}
+
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn remove_movie_clip_tag(
+    caller: Caller,
+    id: Result<Path<MovieClipId>, PathRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+    tag_res: Result<ValidatedJson<Tag>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let id = id?.0;
+    let tag = tag_res?.0;
+
+    let cmd = movie_clip_commands::RemoveMovieClipTagCommand::new(id, tag);
+    movie_clip_usecases::remove_movie_clip_tag(movie_clip_repo, cmd).await?;
+    Ok(())
+}
+
 #[instrument(skip(movie_clip_repo), err(Display))]
 pub async fn remove_movie_clip(
+    caller: Caller,
     id: Result<Path<MovieClipId>, PathRejection>,
     State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
 ) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
     let id = id?.0;
-    let cmd = movie_clip_commands::RemoveMovieClipCommand::new(id);
+    let cmd = movie_clip_commands::RemoveMovieClipCommand::new(id, caller.actor());
     movie_clip_usecases::remove_movie_clip(movie_clip_repo, cmd).await?;
     Ok(())
 }
 
+/// ゴミ箱のクリップを削除した日時の新しい順に返す．協力者以上
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn trashed_movie_clips(
+    caller: Caller,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<Json<Vec<MovieClip>>, AppCommonError> {
+    caller.require(Role::Contributor)?;
+    let cmd = movie_clip_commands::TrashedMovieClipsCommand;
+    let movie_clips = movie_clip_usecases::trashed_movie_clips(movie_clip_repo, cmd).await?;
+    Ok(Json(movie_clips))
+}
+
+/// ゴミ箱のクリップを戻す．協力者以上
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn restore_trashed_movie_clip(
+    caller: Caller,
+    id: Result<Path<MovieClipId>, PathRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Contributor)?;
+    let id = id?.0;
+    let cmd = movie_clip_commands::RestoreTrashedMovieClipCommand::new(id, caller.actor());
+    movie_clip_usecases::restore_trashed_movie_clip(movie_clip_repo, cmd).await?;
+    Ok(())
+}
+
+/// ゴミ箱のクリップを完全に削除する．管理者のみ
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn purge_movie_clip(
+    caller: Caller,
+    id: Result<Path<MovieClipId>, PathRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = movie_clip_commands::PurgeMovieClipCommand::new(id, caller.actor());
+    movie_clip_usecases::purge_movie_clip(movie_clip_repo, cmd).await?;
+    Ok(())
+}
+
+/// クリップの編集履歴を古い順に返す．管理者のみ
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn movie_clip_revisions(
+    caller: Caller,
+    id: Result<Path<MovieClipId>, PathRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<Json<Vec<Revision>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = movie_clip_commands::MovieClipRevisionsCommand::new(id);
+    let revisions = movie_clip_usecases::movie_clip_revisions(movie_clip_repo, cmd).await?;
+    Ok(Json(revisions))
+}
+
+/// クリップの最近の編集履歴を新しい順に返す．管理者のみ
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn latest_movie_clip_revisions(
+    caller: Caller,
+    query_res: Result<Query<LatestRevisionsQuery>, QueryRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<Json<Vec<Revision>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let length = query_res?.0.page_size()?;
+    let cmd = movie_clip_commands::LatestMovieClipRevisionsCommand::new(length);
+    let revisions = movie_clip_usecases::latest_movie_clip_revisions(movie_clip_repo, cmd).await?;
+    Ok(Json(revisions))
+}
+
+/// 二つの編集履歴の間の変更を返す．管理者のみ
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn movie_clip_revision_diff(
+    caller: Caller,
+    query_res: Result<Query<RevisionDiffQuery>, QueryRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<Json<Vec<FieldChange>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let RevisionDiffQuery { from, to } = query_res?.0;
+    let cmd = movie_clip_commands::MovieClipRevisionDiffCommand::new(from, to);
+    let changes = movie_clip_usecases::movie_clip_revision_diff(movie_clip_repo, cmd).await?;
+    Ok(Json(changes))
+}
+
+/// 編集履歴の時点の内容に戻し，戻したクリップを返す．管理者のみ
+#[instrument(skip(movie_clip_repo), err(Display))]
+pub async fn restore_movie_clip_revision(
+    caller: Caller,
+    id: Result<Path<RevisionId>, PathRejection>,
+    State(movie_clip_repo): State<Arc<MovieClipRepositoryImpl>>,
+) -> Result<Json<MovieClip>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = movie_clip_commands::RestoreMovieClipRevisionCommand::new(id, caller.actor());
+    let movie_clip = movie_clip_usecases::restore_movie_clip_revision(movie_clip_repo, cmd).await?;
+    Ok(Json(movie_clip))
+}
+
 #[cfg(test)]
 mod test {
+    use crate::auth::test_utils::{
+        auth_config, client_cookie_of, edit_token_cookie, session_cookie_of,
+    };
     use crate::usecases::mock_movie_clip_usecases;
-    use common::{AppCommonError, QueryInfoRef};
+    use common::{AppCommonError, NewMovieClipInfo, Page};
+    use domain::client::ClientId;
     use domain::movie_clip::{MovieClip, MovieClipId};
-    use domain::Date;
+    use domain::revision::{Actor, FieldChange, Revision, RevisionId};
+    use domain::user::Role;
+    use domain::{Date, PageSize, Tag, ValidationCode, ValidationError};
     use infrastructure::movie_clip_repository_impl::MockMovieClipRepository;
 
     use axum::{
         body::Body,
         http::{self, Request, StatusCode},
-        routing::{delete, get, patch, put},
-        Router,
+        routing::{delete, get, patch, post, put},
+        Extension, Router,
     };
     use fake::{Fake, Faker};
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::{fixture, rstest};
     use serial_test::serial;
-    use std::borrow::Cow;
     use std::sync::Arc;
     use tower::{Service, ServiceExt};
 
@@ -227,7 +454,39 @@ mod test {
                 "/movie_clip/increment_like/:id",
                 patch(super::increment_like_movie_clip),
             )
+            .route("/movie_clip/unlike/:id", patch(super::unlike_movie_clip))
+            .route("/movie_clip/liked", post(super::liked_movie_clip_ids))
+            .route("/movie_clip/tags", get(super::all_movie_clip_tags))
+            .route(
+                "/movie_clip/tags/:id",
+                get(super::movie_clip_tags)
+                    .put(super::add_movie_clip_tag)
+                    .delete(super::remove_movie_clip_tag),
+            )
+            .route(
+                "/movie_clip/revisions",
+                get(super::latest_movie_clip_revisions),
+            )
+            .route(
+                "/movie_clip/revisions/:id",
+                get(super::movie_clip_revisions),
+            )
+            .route(
+                "/movie_clip/revision_diff",
+                get(super::movie_clip_revision_diff),
+            )
+            .route(
+                "/movie_clip/restore/:id",
+                patch(super::restore_movie_clip_revision),
+            )
+            .route("/movie_clip/trash", get(super::trashed_movie_clips))
+            .route(
+                "/movie_clip/trash/:id",
+                patch(super::restore_trashed_movie_clip).delete(super::purge_movie_clip),
+            )
+            .route("/movie_clip/import", put(super::import_movie_clips))
             .with_state(movie_clip_repo)
+            .layer(Extension(auth_config()))
     }
 
     #[fixture]
@@ -242,46 +501,50 @@ mod test {
     #[serial("mock_movie_clip")]
     async fn test_save_movie_clip(mut router: Router) {
         let movie_clip = Faker.fake::<MovieClip>();
+        let movie_clip_info = NewMovieClipInfo::from(movie_clip.clone());
 
         {
             let mock_ctx_ok = mock_movie_clip_usecases::save_movie_clip_context();
             mock_ctx_ok
                 .expect::<MockMovieClipRepository>()
                 .withf({
-                    let movie_clip = movie_clip.clone();
-                    move |_, cmd| cmd.movie_clip == movie_clip
+                    let movie_clip_info = movie_clip_info.clone();
+                    move |_, cmd| cmd.movie_clip_info == movie_clip_info
                 })
                 .times(1)
-                .return_const(Ok(()));
+                .return_const(Ok(movie_clip.clone()));
 
             let request = Request::builder()
                 .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/movie_clip")
-                .body(Body::from(serde_json::to_vec(&movie_clip).unwrap()))
+                .body(Body::from(serde_json::to_vec(&movie_clip_info).unwrap()))
                 .unwrap();
 
             let response = router.ready().await.unwrap().call(request).await.unwrap();
             assert_eq!(response.status(), StatusCode::OK);
             let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-            assert!(body.is_empty());
+            let saved_movie_clip: MovieClip = serde_json::from_slice(&body).unwrap();
+            assert_eq!(saved_movie_clip, movie_clip);
         }
         {
             let mock_ctx_err = mock_movie_clip_usecases::save_movie_clip_context();
             mock_ctx_err
                 .expect::<MockMovieClipRepository>()
                 .withf({
-                    let movie_clip = movie_clip.clone();
-                    move |_, cmd| cmd.movie_clip == movie_clip
+                    let movie_clip_info = movie_clip_info.clone();
+                    move |_, cmd| cmd.movie_clip_info == movie_clip_info
                 })
                 .times(1)
                 .return_const(Err(AppCommonError::ConflictError));
 
             let request = Request::builder()
                 .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/movie_clip")
-                .body(Body::from(serde_json::to_vec(&movie_clip).unwrap()))
+                .body(Body::from(serde_json::to_vec(&movie_clip_info).unwrap()))
                 .unwrap();
 
             let response = router.ready().await.unwrap().call(request).await.unwrap();
@@ -292,6 +555,36 @@ mod test {
 
             assert!(matches!(err, AppCommonError::ConflictError));
         }
+        {
+            // 再生範囲が無効な場合はユースケースを呼ばずにバリデーションエラーを返す
+            let mut movie_clip_info_json = serde_json::to_value(&movie_clip_info).unwrap();
+            movie_clip_info_json["range"] = serde_json::json!({"start": 100, "end": 50});
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/movie_clip")
+                .body(Body::from(
+                    serde_json::to_vec(&movie_clip_info_json).unwrap(),
+                ))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+
+            let AppCommonError::ValidationError(validation_error) = err else {
+                panic!("Expected validation error: {err:?}");
+            };
+            assert_eq!(
+                validation_error,
+                ValidationError::new(ValidationCode::EndBeforeStart, "range.end")
+                    .with_param("start", 100)
+            );
+        }
     }
 
     #[rstest]
@@ -313,6 +606,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/movie_clip")
                 .body(Body::from(serde_json::to_vec(&movie_clip).unwrap()))
@@ -336,6 +630,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/movie_clip")
                 .body(Body::from(serde_json::to_vec(&movie_clip).unwrap()))
@@ -349,6 +644,37 @@ mod test {
 
             assert!(matches!(err, AppCommonError::NoRecordError));
         }
+        {
+            // 他の編集によって版数が進んでいる場合
+            let mut movie_clip = movie_clip.clone();
+            movie_clip.increment_version();
+
+            let mock_ctx_err = mock_movie_clip_usecases::edit_movie_clip_context();
+            mock_ctx_err
+                .expect::<MockMovieClipRepository>()
+                .withf({
+                    let movie_clip = movie_clip.clone();
+                    move |_, cmd| cmd.movie_clip == movie_clip
+                })
+                .times(1)
+                .return_const(Err(AppCommonError::StaleVersion));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/movie_clip")
+                .body(Body::from(serde_json::to_vec(&movie_clip).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+
+            assert!(matches!(err, AppCommonError::StaleVersion));
+        }
     }
 
     #[rstest]
@@ -356,18 +682,20 @@ mod test {
     #[serial("mock_movie_clip")]
     async fn test_increment_like_movie_clip(mut router: Router) {
         let movie_clip_id = MovieClipId::generate();
+        let client_id = ClientId::generate();
 
         {
             let mock_ctx_ok = mock_movie_clip_usecases::increment_like_movie_clip_context();
             mock_ctx_ok
                 .expect::<MockMovieClipRepository>()
-                .withf(move |_, cmd| cmd.id == movie_clip_id)
+                .withf(move |_, cmd| cmd.id == movie_clip_id && cmd.client == client_id)
                 .times(1)
                 .return_const(Ok(()));
 
             let request = Request::builder()
                 .method(http::Method::PATCH)
                 .uri(&format!("/movie_clip/increment_like/{movie_clip_id}"))
+                .header(http::header::COOKIE, client_cookie_of(client_id))
                 .body(Body::empty())
                 .unwrap();
 
@@ -387,6 +715,7 @@ mod test {
             let request = Request::builder()
                 .method(http::Method::PATCH)
                 .uri(&format!("/movie_clip/increment_like/{movie_clip_id}"))
+                .header(http::header::COOKIE, client_cookie_of(client_id))
                 .body(Body::empty())
                 .unwrap();
 
@@ -403,115 +732,89 @@ mod test {
     #[rstest]
     #[tokio::test]
     #[serial("mock_movie_clip")]
-    async fn test_all_movie_clips(mut router: Router, movie_clips: Vec<MovieClip>) {
-        let mock_ctx = mock_movie_clip_usecases::all_movie_clips_context();
+    async fn test_unlike_movie_clip(mut router: Router) {
+        let movie_clip_id = MovieClipId::generate();
+        let client_id = ClientId::generate();
+
+        let mock_ctx = mock_movie_clip_usecases::unlike_movie_clip_context();
         mock_ctx
             .expect::<MockMovieClipRepository>()
+            .withf(move |_, cmd| cmd.id == movie_clip_id && cmd.client == client_id)
             .times(1)
-            .return_const(Ok(movie_clips.clone()));
+            .return_const(Ok(()));
 
         let request = Request::builder()
-            .method(http::Method::GET)
-            .uri("/movie_clip")
+            .method(http::Method::PATCH)
+            .uri(&format!("/movie_clip/unlike/{movie_clip_id}"))
+            .header(http::header::COOKIE, client_cookie_of(client_id))
             .body(Body::empty())
             .unwrap();
 
         let response = router.ready().await.unwrap().call(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<MovieClip> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(res_vec, movie_clips);
-    }
-
-    #[rstest]
-    #[tokio::test]
-    #[serial("mock_movie_clip")]
-    async fn test_order_by_like_movie_clips(mut router: Router, movie_clips: Vec<MovieClip>) {
-        let length = 100_usize;
-
-        let mock_ctx = mock_movie_clip_usecases::order_by_like_movie_clips_context();
-        mock_ctx
-            .expect::<MockMovieClipRepository>()
-            .withf(move |_, cmd| cmd.length == length)
-            .times(1)
-            .return_const(Ok(movie_clips.clone()));
-
+        // クッキーが無い場合は新しいクライアントとして扱わずに拒否する
         let request = Request::builder()
-            .method(http::Method::GET)
-            .uri(&format!("/movie_clip/query?sort_type=like&length={length}"))
+            .method(http::Method::PATCH)
+            .uri(&format!("/movie_clip/unlike/{movie_clip_id}"))
             .body(Body::empty())
             .unwrap();
 
         let response = router.ready().await.unwrap().call(request).await.unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<MovieClip> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(res_vec, movie_clips);
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(err, AppCommonError::NoClientTokenError));
     }
 
     #[rstest]
     #[tokio::test]
     #[serial("mock_movie_clip")]
-    async fn test_order_by_like_later_movie_clips(mut router: Router, movie_clips: Vec<MovieClip>) {
-        let reference = Faker.fake::<MovieClip>();
-        let length = 100_usize;
+    async fn test_liked_movie_clip_ids(mut router: Router) {
+        let ids = (0..10).map(|_| MovieClipId::generate()).collect::<Vec<_>>();
+        let liked_ids = ids[..5].to_vec();
+        let client_id = ClientId::generate();
 
-        let mock_ctx = mock_movie_clip_usecases::order_by_like_later_movie_clips_context();
+        let mock_ctx = mock_movie_clip_usecases::liked_movie_clip_ids_context();
         mock_ctx
             .expect::<MockMovieClipRepository>()
             .withf({
-                let reference = reference.clone();
-                move |_, cmd| cmd.reference == reference && cmd.length == length
+                let ids = ids.clone();
+                move |_, cmd| cmd.ids == ids && cmd.client == client_id
             })
             .times(1)
-            .return_const(Ok(movie_clips.clone()));
-
-        let query_info = QueryInfoRef::builder()
-            .reference(Cow::Borrowed(&reference))
-            .build();
+            .return_const(Ok(liked_ids.clone()));
 
         let request = Request::builder()
-            .method(http::Method::GET)
+            .method(http::Method::POST)
+            .uri("/movie_clip/liked")
             .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-            .uri(&format!("/movie_clip/query?sort_type=like&length={length}"))
-            .body(Body::from(serde_json::to_vec(&query_info).unwrap()))
+            .header(http::header::COOKIE, client_cookie_of(client_id))
+            .body(Body::from(serde_json::to_vec(&ids).unwrap()))
             .unwrap();
 
         let response = router.ready().await.unwrap().call(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<MovieClip> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(res_vec, movie_clips);
+        let res_ids: Vec<MovieClipId> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(res_ids, liked_ids);
     }
 
     #[rstest]
     #[tokio::test]
     #[serial("mock_movie_clip")]
-    async fn test_order_by_crate_date_range_movie_clips(
-        mut router: Router,
-        movie_clips: Vec<MovieClip>,
-    ) {
-        let start = Faker.fake::<Date>();
-        let end = Faker.fake::<Date>();
-
-        let mock_ctx = mock_movie_clip_usecases::order_by_create_date_range_movie_clips_context();
+    async fn test_all_movie_clips(mut router: Router, movie_clips: Vec<MovieClip>) {
+        let mock_ctx = mock_movie_clip_usecases::all_movie_clips_context();
         mock_ctx
             .expect::<MockMovieClipRepository>()
-            .withf(move |_, cmd| cmd.start == start && cmd.end == end)
             .times(1)
             .return_const(Ok(movie_clips.clone()));
 
         let request = Request::builder()
             .method(http::Method::GET)
-            .uri(&format!(
-                "/movie_clip/query?sort_type=create_date&start={start}&end={end}"
-            ))
+            .uri("/movie_clip")
             .body(Body::empty())
             .unwrap();
 
@@ -527,24 +830,19 @@ mod test {
     #[rstest]
     #[tokio::test]
     #[serial("mock_movie_clip")]
-    async fn test_order_by_create_date_movie_clips(
-        mut router: Router,
-        movie_clips: Vec<MovieClip>,
-    ) {
+    async fn test_order_by_like_movie_clips(mut router: Router, movie_clips: Vec<MovieClip>) {
         let length = 100_usize;
 
-        let mock_ctx = mock_movie_clip_usecases::order_by_create_date_movie_clips_context();
+        let mock_ctx = mock_movie_clip_usecases::order_by_like_movie_clips_context();
         mock_ctx
             .expect::<MockMovieClipRepository>()
-            .withf(move |_, cmd| cmd.length == length)
+            .withf(move |_, cmd| cmd.length.get() == length)
             .times(1)
-            .return_const(Ok(movie_clips.clone()));
+            .return_const(Ok(Page::new(movie_clips.clone(), true, None)));
 
         let request = Request::builder()
             .method(http::Method::GET)
-            .uri(&format!(
-                "/movie_clip/query?sort_type=create_date&length={length}"
-            ))
+            .uri(&format!("/movie_clip/query?sort_type=like&length={length}"))
             .body(Body::empty())
             .unwrap();
 
@@ -552,69 +850,431 @@ mod test {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<MovieClip> = serde_json::from_slice(&body).unwrap();
+        let page: Page<MovieClip> = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(res_vec, movie_clips);
+        assert_eq!(page.items, movie_clips);
+        // 次のページのカーソル
+        let next_cursor = auth_config()
+            .verify_cursor("movie_clip.like", &page.next_cursor.unwrap())
+            .unwrap();
+        assert_eq!(next_cursor, movie_clips.last().unwrap().like_cursor());
     }
 
     #[rstest]
     #[tokio::test]
     #[serial("mock_movie_clip")]
-    async fn test_order_by_create_date_later_movie_clips(
-        mut router: Router,
-        movie_clips: Vec<MovieClip>,
-    ) {
-        let reference = Faker.fake::<MovieClip>();
+    async fn test_order_by_like_later_movie_clips(mut router: Router, movie_clips: Vec<MovieClip>) {
+        let cursor = Faker.fake::<MovieClip>().like_cursor();
         let length = 100_usize;
 
-        let mock_ctx = mock_movie_clip_usecases::order_by_create_date_later_movie_clips_context();
+        let mock_ctx = mock_movie_clip_usecases::order_by_like_later_movie_clips_context();
         mock_ctx
             .expect::<MockMovieClipRepository>()
-            .withf({
-                let reference = reference.clone();
-                move |_, cmd| cmd.reference == reference && cmd.length == length
-            })
+            .withf(move |_, cmd| cmd.cursor == cursor && cmd.length.get() == length)
             .times(1)
-            .return_const(Ok(movie_clips.clone()));
+            .return_const(Ok(Page::new(movie_clips.clone(), true, None)));
 
-        let query_info = QueryInfoRef::builder()
-            .reference(Cow::Borrowed(&reference))
-            .build();
+        let cursor_token = auth_config().issue_cursor("movie_clip.like", cursor);
 
         let request = Request::builder()
             .method(http::Method::GET)
-            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
             .uri(&format!(
-                "/movie_clip/query?sort_type=create_date&length={length}"
+                "/movie_clip/query?sort_type=like&length={length}&cursor={cursor_token}"
             ))
-            .body(Body::from(serde_json::to_vec(&query_info).unwrap()))
+            .body(Body::empty())
             .unwrap();
 
         let response = router.ready().await.unwrap().call(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<MovieClip> = serde_json::from_slice(&body).unwrap();
+        let page: Page<MovieClip> = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(res_vec, movie_clips);
+        assert_eq!(page.items, movie_clips);
     }
 
     #[rstest]
     #[tokio::test]
     #[serial("mock_movie_clip")]
-    async fn test_remove_movie_clip(mut router: Router) {
-        let movie_clip_id = MovieClipId::generate();
-
-        {
-            let mock_ctx_ok = mock_movie_clip_usecases::remove_movie_clip_context();
-            mock_ctx_ok
-                .expect::<MockMovieClipRepository>()
-                .withf(move |_, cmd| cmd.id == movie_clip_id)
-                .times(1)
-                .return_const(Ok(()));
+    async fn test_invalid_cursor(mut router: Router) {
+        let cursor = Faker.fake::<MovieClip>().like_cursor();
+        // 別のソートのカーソル
+        let cursor_token = auth_config().issue_cursor("movie_clip.create_date", cursor);
 
-            let request = Request::builder()
-                .method(http::Method::DELETE)
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri(&format!(
+                "/movie_clip/query?sort_type=like&length=10&cursor={cursor_token}"
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        // 改竄されたカーソル
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/movie_clip/query?sort_type=like&length=10&cursor=invalid.cursor")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_page_size_query(mut router: Router, movie_clips: Vec<MovieClip>) {
+        let mock_ctx = mock_movie_clip_usecases::order_by_like_movie_clips_context();
+
+        // lengthが与えられない場合はデフォルトのサイズ
+        mock_ctx
+            .expect::<MockMovieClipRepository>()
+            .withf(|_, cmd| cmd.length == PageSize::default())
+            .times(1)
+            .return_const(Ok(Page::new(movie_clips.clone(), false, None)));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/movie_clip/query?sort_type=like")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // 続きが存在しない場合はカーソルを持たない
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<MovieClip> = serde_json::from_slice(&body).unwrap();
+        assert!(!page.has_more);
+        assert_eq!(page.next_cursor, None);
+
+        // 上限を超える場合は上限のサイズ
+        mock_ctx.checkpoint();
+        mock_ctx
+            .expect::<MockMovieClipRepository>()
+            .withf(|_, cmd| cmd.length == PageSize::max())
+            .times(1)
+            .return_const(Ok(Page::new(movie_clips.clone(), false, None)));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/movie_clip/query?sort_type=like&length=4294967296")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // 0以下のサイズ
+        for length in ["0", "-1"] {
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri(&format!("/movie_clip/query?sort_type=like&length={length}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+            let AppCommonError::ValidationError(validation_error) = err else {
+                panic!("Expected validation error: {err:?}");
+            };
+            assert_eq!(validation_error.code, ValidationCode::TooSmall);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_order_by_crate_date_range_movie_clips(
+        mut router: Router,
+        movie_clips: Vec<MovieClip>,
+    ) {
+        let start = Faker.fake::<Date>();
+        let end = Faker.fake::<Date>();
+
+        let mock_ctx = mock_movie_clip_usecases::order_by_create_date_range_movie_clips_context();
+        mock_ctx
+            .expect::<MockMovieClipRepository>()
+            .withf(move |_, cmd| cmd.start == start && cmd.end == end)
+            .times(1)
+            .return_const(Ok(movie_clips.clone()));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri(&format!(
+                "/movie_clip/query?sort_type=create_date&start={start}&end={end}"
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<MovieClip> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(page.items, movie_clips);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_order_by_create_date_movie_clips(
+        mut router: Router,
+        movie_clips: Vec<MovieClip>,
+    ) {
+        let length = 100_usize;
+
+        let mock_ctx = mock_movie_clip_usecases::order_by_create_date_movie_clips_context();
+        mock_ctx
+            .expect::<MockMovieClipRepository>()
+            .withf(move |_, cmd| cmd.length.get() == length)
+            .times(1)
+            .return_const(Ok(Page::new(movie_clips.clone(), true, None)));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri(&format!(
+                "/movie_clip/query?sort_type=create_date&length={length}"
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<MovieClip> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(page.items, movie_clips);
+        let next_cursor = auth_config()
+            .verify_cursor("movie_clip.create_date", &page.next_cursor.unwrap())
+            .unwrap();
+        assert_eq!(
+            next_cursor,
+            movie_clips.last().unwrap().create_date_cursor()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_order_by_create_date_later_movie_clips(
+        mut router: Router,
+        movie_clips: Vec<MovieClip>,
+    ) {
+        let cursor = Faker.fake::<MovieClip>().create_date_cursor();
+        let length = 100_usize;
+
+        let mock_ctx = mock_movie_clip_usecases::order_by_create_date_later_movie_clips_context();
+        mock_ctx
+            .expect::<MockMovieClipRepository>()
+            .withf(move |_, cmd| cmd.cursor == cursor && cmd.length.get() == length)
+            .times(1)
+            .return_const(Ok(Page::new(movie_clips.clone(), true, None)));
+
+        let cursor_token = auth_config().issue_cursor("movie_clip.create_date", cursor);
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri(&format!(
+                "/movie_clip/query?sort_type=create_date&length={length}&cursor={cursor_token}"
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<MovieClip> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(page.items, movie_clips);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_order_by_like_movie_clips_with_tags(
+        mut router: Router,
+        movie_clips: Vec<MovieClip>,
+    ) {
+        let mock_ctx = mock_movie_clip_usecases::order_by_like_movie_clips_context();
+        // タグは正規化・重複の削除がされて渡される
+        mock_ctx
+            .expect::<MockMovieClipRepository>()
+            .withf(|_, cmd| {
+                cmd.tags == vec![Tag::new("game").unwrap(), Tag::new("singing").unwrap()]
+            })
+            .times(1)
+            .return_const(Ok(Page::new(movie_clips.clone(), false, None)));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/movie_clip/query?sort_type=like&tags=Singing,game,singing")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<MovieClip> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(page.items, movie_clips);
+
+        // タグが多すぎる場合
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/movie_clip/query?sort_type=like&tags=a,b,c,d,e,f")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        // 範囲の指定とは併用できない
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri(
+                "/movie_clip/query?sort_type=create_date&start=2023-01-01&end=2023-02-01&tags=game",
+            )
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_movie_clip_tags(mut router: Router) {
+        let movie_clip_id = MovieClipId::generate();
+        let tag = Tag::new("game").unwrap();
+
+        {
+            let mock_ctx = mock_movie_clip_usecases::all_movie_clip_tags_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .times(1)
+                .return_const(Ok(vec![tag.clone()]));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri("/movie_clip/tags")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let tags: Vec<Tag> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(tags, vec![tag.clone()]);
+        }
+        {
+            let mock_ctx = mock_movie_clip_usecases::movie_clip_tags_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf(move |_, cmd| cmd.id == movie_clip_id)
+                .times(1)
+                .return_const(Ok(vec![tag.clone()]));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri(&format!("/movie_clip/tags/{movie_clip_id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let tags: Vec<Tag> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(tags, vec![tag.clone()]);
+        }
+        {
+            let mock_ctx = mock_movie_clip_usecases::add_movie_clip_tag_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf({
+                    let tag = tag.clone();
+                    move |_, cmd| cmd.id == movie_clip_id && cmd.tag == tag
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri(&format!("/movie_clip/tags/{movie_clip_id}"))
+                .body(Body::from(r#""Game""#))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            let mock_ctx = mock_movie_clip_usecases::remove_movie_clip_tag_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf({
+                    let tag = tag.clone();
+                    move |_, cmd| cmd.id == movie_clip_id && cmd.tag == tag
+                })
+                .times(1)
+                .return_const(Err(AppCommonError::NoRecordError));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri(&format!("/movie_clip/tags/{movie_clip_id}"))
+                .body(Body::from(serde_json::to_vec(&tag).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+        {
+            // セッションもトークンも無い場合はタグを付けられない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri(&format!("/movie_clip/tags/{movie_clip_id}"))
+                .body(Body::from(serde_json::to_vec(&tag).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_remove_movie_clip(mut router: Router) {
+        let movie_clip_id = MovieClipId::generate();
+
+        {
+            let mock_ctx_ok = mock_movie_clip_usecases::remove_movie_clip_context();
+            mock_ctx_ok
+                .expect::<MockMovieClipRepository>()
+                .withf(move |_, cmd| cmd.id == movie_clip_id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
                 .uri(&format!("/movie_clip/{movie_clip_id}"))
                 .body(Body::empty())
                 .unwrap();
@@ -634,6 +1294,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
                 .uri(&format!("/movie_clip/{movie_clip_id}"))
                 .body(Body::empty())
                 .unwrap();
@@ -647,4 +1308,355 @@ mod test {
             assert!(matches!(err, AppCommonError::NoRecordError));
         }
     }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_movie_clip_permission(mut router: Router) {
+        let movie_clip = Faker.fake::<MovieClip>();
+        {
+            // セッションが無い場合は保存できない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/movie_clip")
+                .body(Body::from(serde_json::to_vec(&movie_clip).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // 編集用のトークンがあれば匿名でも編集できる
+            let mock_ctx_ok = mock_movie_clip_usecases::edit_movie_clip_context();
+            mock_ctx_ok
+                .expect::<MockMovieClipRepository>()
+                .withf({
+                    let movie_clip = movie_clip.clone();
+                    move |_, cmd| cmd.movie_clip == movie_clip && cmd.actor == Actor::Anonymous
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/movie_clip")
+                .body(Body::from(serde_json::to_vec(&movie_clip).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            // 編集用のトークンでは削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .uri(&format!("/movie_clip/{}", movie_clip.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // Contributorは削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/movie_clip/{}", movie_clip.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+            assert!(matches!(err, AppCommonError::ForbiddenError));
+        }
+    }
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_movie_clip_revisions(mut router: Router) {
+        let movie_clip = Faker.fake::<MovieClip>();
+        let revisions = (0..3).map(|_| Faker.fake::<Revision>()).collect::<Vec<_>>();
+        {
+            let mock_ctx = mock_movie_clip_usecases::latest_movie_clip_revisions_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf(|_, cmd| cmd.length.get() == 10)
+                .times(1)
+                .return_const(Ok(revisions.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri("/movie_clip/revisions?length=10")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Vec<Revision> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, revisions);
+        }
+        {
+            let id = movie_clip.id();
+            let mock_ctx = mock_movie_clip_usecases::movie_clip_revisions_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf(move |_, cmd| cmd.id == id)
+                .times(1)
+                .return_const(Ok(revisions.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/movie_clip/revisions/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Vec<Revision> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, revisions);
+        }
+        {
+            let (from, to) = (RevisionId::generate(), RevisionId::generate());
+            let changes = vec![FieldChange {
+                field: "title".to_string(),
+                before: Some("before".into()),
+                after: Some("after".into()),
+            }];
+            let mock_ctx = mock_movie_clip_usecases::movie_clip_revision_diff_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf(move |_, cmd| cmd.from == from && cmd.to == to)
+                .times(1)
+                .return_const(Ok(changes.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/movie_clip/revision_diff?from={from}&to={to}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Vec<FieldChange> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, changes);
+        }
+        {
+            let id = RevisionId::generate();
+            let mock_ctx = mock_movie_clip_usecases::restore_movie_clip_revision_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Ok(movie_clip.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/movie_clip/restore/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: MovieClip = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, movie_clip);
+        }
+        {
+            // Contributorは編集履歴を参照できない
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri("/movie_clip/revisions")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+        {
+            // 編集用のトークンでは復元できない
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .uri(&format!("/movie_clip/restore/{}", RevisionId::generate()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_movie_clip_trash(mut router: Router) {
+        let movie_clips = (0..3)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+        let id = movie_clips[0].id();
+        {
+            let mock_ctx = mock_movie_clip_usecases::trashed_movie_clips_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .times(1)
+                .return_const(Ok(movie_clips.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri("/movie_clip/trash")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Vec<MovieClip> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, movie_clips);
+        }
+        {
+            let mock_ctx = mock_movie_clip_usecases::restore_trashed_movie_clip_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/movie_clip/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            let mock_ctx = mock_movie_clip_usecases::purge_movie_clip_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Err(AppCommonError::NoRecordError));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/movie_clip/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+        {
+            // 編集用のトークンではゴミ箱を参照できない
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .uri("/movie_clip/trash")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // Contributorは完全に削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/movie_clip/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_movie_clip")]
+    async fn test_import_movie_clips(mut router: Router) {
+        let movie_clips = (0..3)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+        {
+            // id, like, create_dateを含むクリップをそのまま渡す
+            let mock_ctx = mock_movie_clip_usecases::import_movie_clips_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .withf({
+                    let movie_clips = movie_clips.clone();
+                    move |_, cmd| cmd.movie_clips == movie_clips && cmd.actor == Actor::Admin
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/movie_clip/import")
+                .body(Body::from(serde_json::to_vec(&movie_clips).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            let mock_ctx = mock_movie_clip_usecases::import_movie_clips_context();
+            mock_ctx
+                .expect::<MockMovieClipRepository>()
+                .times(1)
+                .return_const(Err(AppCommonError::ConflictError));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/movie_clip/import")
+                .body(Body::from(serde_json::to_vec(&movie_clips).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+        }
+        {
+            // Contributorはインポートできない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/movie_clip/import")
+                .body(Body::from(serde_json::to_vec(&movie_clips).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
 }
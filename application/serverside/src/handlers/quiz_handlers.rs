@@ -0,0 +1,429 @@
+use crate::auth::{edit_token_cookie, AuthConfig};
+use crate::commands::quiz_commands;
+use crate::handlers::Caller;
+use common::{AppCommonError, EditToken, QuizAnswer, QuizQuestion};
+use config::CONFIG;
+use domain::quiz::{Quiz, QuizId};
+use domain::user::Role;
+
+// quiz_usecaseのモック化
+#[cfg(not(test))]
+use crate::usecases::quiz_usecases;
+
+#[cfg(test)]
+use crate::usecases::mock_quiz_usecases as quiz_usecases;
+
+// QuizRepoのモック化
+#[cfg(all(not(test), feature = "inmemory"))]
+use infrastructure::quiz_repository_impl::InMemoryQuizRepository as QuizRepositoryImpl;
+
+#[cfg(all(not(test), not(feature = "inmemory")))]
+use infrastructure::quiz_repository_impl::QuizPgDBRepository as QuizRepositoryImpl;
+
+#[cfg(test)]
+use infrastructure::quiz_repository_impl::MockQuizRepository as QuizRepositoryImpl;
+
+use axum::{
+    extract::rejection::{JsonRejection, PathRejection},
+    extract::{Json, Path, State},
+    http::header,
+    response::IntoResponse,
+    Extension,
+};
+use std::sync::Arc;
+use tracing_attributes::instrument;
+
+/// ランダムにクイズを出題する．回答の代わりに署名付きのチャレンジidを返す．
+#[instrument(skip(auth_config, quiz_repo), err(Display))]
+pub async fn question(
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
+    State(quiz_repo): State<Arc<QuizRepositoryImpl>>,
+) -> Result<Json<QuizQuestion>, AppCommonError> {
+    let cmd = quiz_commands::RandomQuizCommand;
+    let quiz = quiz_usecases::random_quiz(quiz_repo, cmd).await?;
+
+    Ok(Json(QuizQuestion {
+        challenge_id: auth_config.issue_quiz_challenge(quiz.id()),
+        question: quiz.question().to_string(),
+    }))
+}
+
+/// クイズの回答を確認し，正解の場合は編集用のトークンを発行してクッキーにも設定する．
+#[instrument(skip(auth_config, quiz_repo), err(Display))]
+pub async fn answer(
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
+    State(quiz_repo): State<Arc<QuizRepositoryImpl>>,
+    quiz_answer_res: Result<Json<QuizAnswer>, JsonRejection>,
+) -> Result<impl IntoResponse, AppCommonError> {
+    let QuizAnswer {
+        challenge_id,
+        answer,
+    } = quiz_answer_res?.0;
+    let quiz_id = auth_config.verify_quiz_challenge(&challenge_id)?;
+
+    let cmd = quiz_commands::CheckQuizAnswerCommand::new(quiz_id, answer);
+    quiz_usecases::check_quiz_answer(quiz_repo, cmd).await?;
+
+    let token = auth_config.issue_edit_token();
+    Ok((
+        [(header::SET_COOKIE, edit_token_cookie(&token))],
+        Json(EditToken {
+            token,
+            max_age: CONFIG.edit_token_max_age,
+        }),
+    ))
+}
+
+#[instrument(skip(quiz_repo), err(Display))]
+pub async fn save_quiz(
+    caller: Caller,
+    State(quiz_repo): State<Arc<QuizRepositoryImpl>>,
+    quiz_res: Result<Json<Quiz>, JsonRejection>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let quiz = quiz_res?.0;
+    let cmd = quiz_commands::SaveQuizCommand::new(quiz);
+    quiz_usecases::save_quiz(quiz_repo, cmd).await?;
+    Ok(())
+}
+
+#[instrument(skip(quiz_repo), err(Display))]
+pub async fn edit_quiz(
+    caller: Caller,
+    State(quiz_repo): State<Arc<QuizRepositoryImpl>>,
+    quiz_res: Result<Json<Quiz>, JsonRejection>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let quiz = quiz_res?.0;
+    let cmd = quiz_commands::EditQuizCommand::new(quiz);
+    quiz_usecases::edit_quiz(quiz_repo, cmd).await?;
+    Ok(())
+}
+
+/// 回答を含む全てのクイズを取得する．管理者のみ利用できる．
+#[instrument(skip(quiz_repo), err(Display))]
+pub async fn all_quizzes(
+    caller: Caller,
+    State(quiz_repo): State<Arc<QuizRepositoryImpl>>,
+) -> Result<Json<Vec<Quiz>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let cmd = quiz_commands::AllQuizzesCommand;
+    let quizzes = quiz_usecases::all_quizzes(quiz_repo, cmd).await?;
+    Ok(Json(quizzes))
+}
+
+#[instrument(skip(quiz_repo), err(Display))]
+pub async fn remove_quiz(
+    caller: Caller,
+    id: Result<Path<QuizId>, PathRejection>,
+    State(quiz_repo): State<Arc<QuizRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = quiz_commands::RemoveQuizCommand::new(id);
+    quiz_usecases::remove_quiz(quiz_repo, cmd).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::auth::test_utils::{auth_config, session_cookie_of};
+    use crate::auth::EDIT_TOKEN_COOKIE_NAME;
+    use crate::usecases::mock_quiz_usecases;
+    use common::{AppCommonError, EditToken, QuizAnswer, QuizQuestion};
+    use domain::quiz::{Quiz, QuizId};
+    use domain::user::Role;
+    use infrastructure::quiz_repository_impl::MockQuizRepository;
+
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+        routing::{delete, get, post, put},
+        Extension, Router,
+    };
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use serial_test::serial;
+    use std::sync::Arc;
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        let quiz_repo = Arc::new(MockQuizRepository::new());
+
+        Router::new()
+            .route(
+                "/quiz",
+                put(super::save_quiz)
+                    .patch(super::edit_quiz)
+                    .get(super::all_quizzes),
+            )
+            .route("/quiz/question", get(super::question))
+            .route("/quiz/answer", post(super::answer))
+            .route("/quiz/:id", delete(super::remove_quiz))
+            .with_state(quiz_repo)
+            .layer(Extension(auth_config()))
+    }
+
+    fn answer_request(quiz_answer: &QuizAnswer) -> Request<Body> {
+        Request::builder()
+            .method(http::Method::POST)
+            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .uri("/quiz/answer")
+            .body(Body::from(serde_json::to_vec(quiz_answer).unwrap()))
+            .unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_quiz")]
+    async fn test_question_and_answer(mut router: Router) {
+        let quiz = Faker.fake::<Quiz>();
+
+        // 出題
+        let mock_ctx_question = mock_quiz_usecases::random_quiz_context();
+        mock_ctx_question
+            .expect::<MockQuizRepository>()
+            .return_const(Ok(quiz.clone()));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/quiz/question")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let quiz_question: QuizQuestion = serde_json::from_slice(&body).unwrap();
+        assert_eq!(quiz_question.question, quiz.question());
+        // 回答は含まない
+        assert!(!quiz
+            .answers()
+            .iter()
+            .any(|answer| String::from_utf8_lossy(&body).contains(answer.as_str())));
+
+        {
+            // 正解の場合
+            let mock_ctx_ok = mock_quiz_usecases::check_quiz_answer_context();
+            mock_ctx_ok
+                .expect::<MockQuizRepository>()
+                .withf({
+                    let quiz_id = quiz.id();
+                    move |_, cmd| cmd.id == quiz_id && cmd.answer == "answer"
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let quiz_answer = QuizAnswer::new(&quiz_question.challenge_id, "answer");
+            let response = router
+                .ready()
+                .await
+                .unwrap()
+                .call(answer_request(&quiz_answer))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let set_cookie = response
+                .headers()
+                .get(http::header::SET_COOKIE)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string();
+            assert!(set_cookie.starts_with(&format!("{EDIT_TOKEN_COOKIE_NAME}=")));
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let edit_token: EditToken = serde_json::from_slice(&body).unwrap();
+            assert!(auth_config().verify_edit_token(&edit_token.token).is_ok());
+        }
+        {
+            // 不正解の場合
+            let mock_ctx_err = mock_quiz_usecases::check_quiz_answer_context();
+            mock_ctx_err
+                .expect::<MockQuizRepository>()
+                .times(1)
+                .return_const(Err(AppCommonError::IncorrectAnswerError));
+
+            let quiz_answer = QuizAnswer::new(&quiz_question.challenge_id, "wrong answer");
+            let response = router
+                .ready()
+                .await
+                .unwrap()
+                .call(answer_request(&quiz_answer))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert!(response.headers().get(http::header::SET_COOKIE).is_none());
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+            assert!(matches!(err, AppCommonError::IncorrectAnswerError));
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_quiz")]
+    async fn test_answer_with_invalid_challenge(mut router: Router) {
+        // 署名されていないチャレンジidでは回答できない
+        let mock_ctx = mock_quiz_usecases::check_quiz_answer_context();
+        mock_ctx
+            .expect::<MockQuizRepository>()
+            .times(0)
+            .return_const(Ok(()));
+
+        let quiz_answer = QuizAnswer::new(QuizId::generate().to_string(), "answer");
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(answer_request(&quiz_answer))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(err, AppCommonError::UnauthorizedError));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_quiz")]
+    async fn test_save_quiz(mut router: Router) {
+        let quiz = Faker.fake::<Quiz>();
+        {
+            let mock_ctx = mock_quiz_usecases::save_quiz_context();
+            mock_ctx
+                .expect::<MockQuizRepository>()
+                .withf({
+                    let quiz = quiz.clone();
+                    move |_, cmd| cmd.quiz == quiz
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/quiz")
+                .body(Body::from(serde_json::to_vec(&quiz).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            // Contributorはクイズを登録できない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/quiz")
+                .body(Body::from(serde_json::to_vec(&quiz).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_quiz")]
+    async fn test_edit_quiz(mut router: Router) {
+        let quiz = Faker.fake::<Quiz>();
+
+        let mock_ctx = mock_quiz_usecases::edit_quiz_context();
+        mock_ctx
+            .expect::<MockQuizRepository>()
+            .withf({
+                let quiz = quiz.clone();
+                move |_, cmd| cmd.quiz == quiz
+            })
+            .times(1)
+            .return_const(Err(AppCommonError::NoRecordError));
+
+        let request = Request::builder()
+            .method(http::Method::PATCH)
+            .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .uri("/quiz")
+            .body(Body::from(serde_json::to_vec(&quiz).unwrap()))
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(err, AppCommonError::NoRecordError));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_quiz")]
+    async fn test_all_quizzes(mut router: Router) {
+        let quizzes = (0..10).map(|_| Faker.fake::<Quiz>()).collect::<Vec<_>>();
+        {
+            let mock_ctx = mock_quiz_usecases::all_quizzes_context();
+            mock_ctx
+                .expect::<MockQuizRepository>()
+                .return_const(Ok(quizzes.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri("/quiz")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res_vec: Vec<Quiz> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res_vec, quizzes);
+        }
+        {
+            // 回答を含むため匿名では取得できない
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri("/quiz")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_quiz")]
+    async fn test_remove_quiz(mut router: Router) {
+        let quiz_id = QuizId::generate();
+
+        let mock_ctx_err = mock_quiz_usecases::remove_quiz_context();
+        mock_ctx_err
+            .expect::<MockQuizRepository>()
+            .withf(move |_, cmd| cmd.id == quiz_id)
+            .return_const(Err(AppCommonError::NoRecordError));
+
+        let request = Request::builder()
+            .method(http::Method::DELETE)
+            .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+            .uri(&format!("/quiz/{quiz_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+
+        assert!(matches!(err, AppCommonError::NoRecordError));
+    }
+}
@@ -0,0 +1,25 @@
+use domain::revision::RevisionId;
+use domain::{DomainError, FromInput, PageSize};
+
+use serde::Deserialize;
+
+/// 最近の編集履歴を取得するクエリ
+#[derive(Deserialize, Debug)]
+pub struct LatestRevisionsQuery {
+    /// 与えられない場合はデフォルトのサイズとなる
+    pub length: Option<i64>,
+}
+
+impl LatestRevisionsQuery {
+    /// 検証した取得する数．与えられない場合はデフォルトのサイズとなる
+    pub fn page_size(&self) -> Result<PageSize, DomainError> {
+        Ok(Option::<PageSize>::from_input(self.length, "length")?.unwrap_or_default())
+    }
+}
+
+/// 二つの編集履歴の間の変更を取得するクエリ
+#[derive(Deserialize, Debug)]
+pub struct RevisionDiffQuery {
+    pub from: RevisionId,
+    pub to: RevisionId,
+}
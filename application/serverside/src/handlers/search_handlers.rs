@@ -0,0 +1,217 @@
+use crate::commands::search_commands;
+use common::AppCommonError;
+use domain::search::{SearchHit, SearchItem, SearchQuery};
+use domain::{FromInput, PageSize};
+
+// search_usecasesのモック化
+#[cfg(not(test))]
+use crate::usecases::search_usecases;
+
+#[cfg(test)]
+use crate::usecases::mock_search_usecases as search_usecases;
+
+// 各リポジトリのモック化
+#[cfg(all(not(test), feature = "inmemory"))]
+use infrastructure::{
+    episode_repository_impl::InMemoryEpisodeRepository as EpisodeRepositoryImpl,
+    movie_clip_repository_impl::InMemoryMovieClipRepository as MovieClipRepositoryImpl,
+    video_repository_impl::InMemoryVideoRepository as VideoRepositoryImpl,
+};
+
+#[cfg(all(not(test), not(feature = "inmemory")))]
+use infrastructure::{
+    episode_repository_impl::EpisodePgDBRepository as EpisodeRepositoryImpl,
+    movie_clip_repository_impl::MovieClipPgDBRepository as MovieClipRepositoryImpl,
+    video_repository_impl::VideoPgDbRepository as VideoRepositoryImpl,
+};
+
+#[cfg(not(test))]
+type OriginalRepositoryImpl = VideoRepositoryImpl<domain::video::Original>;
+
+#[cfg(not(test))]
+type KirinukiRepositoryImpl = VideoRepositoryImpl<domain::video::Kirinuki>;
+
+#[cfg(test)]
+use infrastructure::{
+    episode_repository_impl::MockEpisodeRepository as EpisodeRepositoryImpl,
+    movie_clip_repository_impl::MockMovieClipRepository as MovieClipRepositoryImpl,
+    video_repository_impl::MockVideoKirinukiRepository as KirinukiRepositoryImpl,
+    video_repository_impl::MockVideoOriginalRepository as OriginalRepositoryImpl,
+};
+
+use axum::{
+    extract::rejection::QueryRejection,
+    extract::{Json, Query, State},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing_attributes::instrument;
+
+/// 検索の対象となるリポジトリ．各ルーターと同じリポジトリを共有する．
+#[derive(Clone)]
+pub struct SearchRepositories {
+    pub movie_clip_repo: Arc<MovieClipRepositoryImpl>,
+    pub episode_repo: Arc<EpisodeRepositoryImpl>,
+    pub original_repo: Arc<OriginalRepositoryImpl>,
+    pub kirinuki_repo: Arc<KirinukiRepositoryImpl>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SearchQueryParams {
+    /// `SearchQuery`としてハンドラで検証する
+    query: String,
+    /// 与えられない場合はデフォルトのサイズとなる．`PageSize`としてハンドラで検証する
+    length: Option<i64>,
+}
+
+/// クリップ・エピソード・動画をまとめて検索し，スコアの降順で取得する．
+#[instrument(skip(repos), err(Display))]
+pub async fn search(
+    query_res: Result<Query<SearchQueryParams>, QueryRejection>,
+    State(repos): State<SearchRepositories>,
+) -> Result<Json<Vec<SearchHit<SearchItem>>>, AppCommonError> {
+    let params = query_res?.0;
+    let query = SearchQuery::from_input(params.query, "query")?;
+    let length = Option::<PageSize>::from_input(params.length, "length")?.unwrap_or_default();
+    let cmd = search_commands::SearchCommand::new(query, length);
+    let hits = search_usecases::search(
+        repos.movie_clip_repo,
+        repos.episode_repo,
+        repos.original_repo,
+        repos.kirinuki_repo,
+        cmd,
+    )
+    .await?;
+    Ok(Json(hits))
+}
+
+#[cfg(test)]
+mod test {
+    use super::SearchRepositories;
+    use crate::usecases::mock_search_usecases;
+    use common::AppCommonError;
+    use domain::movie_clip::MovieClip;
+    use domain::search::{SearchHit, SearchItem, SearchQuery};
+    use domain::video::{Original, Video};
+    use domain::{PageSize, ValidationCode};
+    use infrastructure::episode_repository_impl::MockEpisodeRepository;
+    use infrastructure::movie_clip_repository_impl::MockMovieClipRepository;
+    use infrastructure::video_repository_impl::{
+        MockVideoKirinukiRepository, MockVideoOriginalRepository,
+    };
+
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+        routing::get,
+        Router,
+    };
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use serial_test::serial;
+    use std::sync::Arc;
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        let repos = SearchRepositories {
+            movie_clip_repo: Arc::new(MockMovieClipRepository::new()),
+            episode_repo: Arc::new(MockEpisodeRepository::new()),
+            original_repo: Arc::new(MockVideoOriginalRepository::new()),
+            kirinuki_repo: Arc::new(MockVideoKirinukiRepository::new()),
+        };
+
+        Router::new()
+            .route("/search", get(super::search))
+            .with_state(repos)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_search")]
+    async fn test_search(mut router: Router) {
+        let hits = vec![
+            SearchHit::new(Faker.fake::<MovieClip>(), 0.8).map(SearchItem::from),
+            SearchHit::new(Faker.fake::<Video<Original>>(), 0.4).map(SearchItem::from),
+        ];
+
+        let mock_ctx = mock_search_usecases::search_context();
+        mock_ctx
+            .expect::<MockMovieClipRepository, MockEpisodeRepository, MockVideoOriginalRepository, MockVideoKirinukiRepository>()
+            .withf(|_, _, _, _, cmd| {
+                cmd.query == SearchQuery::new("理子 歌枠").unwrap() && cmd.length.get() == 10
+            })
+            .times(1)
+            .return_const(Ok(hits.clone()));
+
+        // 空白はエンコードする
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/search?query=%E7%90%86%E5%AD%90+%E6%AD%8C%E6%9E%A0&length=10")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let hits_res: Vec<SearchHit<SearchItem>> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(hits_res, hits);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_search")]
+    async fn test_search_default_length(mut router: Router) {
+        // lengthが与えられない場合はデフォルトのサイズ
+        let mock_ctx = mock_search_usecases::search_context();
+        mock_ctx
+            .expect::<MockMovieClipRepository, MockEpisodeRepository, MockVideoOriginalRepository, MockVideoKirinukiRepository>()
+            .withf(|_, _, _, _, cmd| cmd.length == PageSize::default())
+            .times(1)
+            .return_const(Ok(Vec::new()));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/search?query=meitan")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_search")]
+    async fn test_search_invalid_query(mut router: Router) {
+        // 空白のみの検索文字列
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/search?query=+++")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        let AppCommonError::ValidationError(validation_error) = err else {
+            panic!("Expected validation error: {err:?}");
+        };
+        assert_eq!(validation_error.code, ValidationCode::Empty);
+        assert_eq!(validation_error.field, "query");
+
+        // 検索文字列が無い
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/search")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
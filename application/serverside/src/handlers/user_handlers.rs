@@ -0,0 +1,212 @@
+use crate::commands::user_commands;
+use crate::handlers::Caller;
+use common::{AppCommonError, NewUserInfo, UserInfo};
+use domain::user::{Role, UserId};
+
+// user_usecaseのモック化
+#[cfg(not(test))]
+use crate::usecases::user_usecases;
+
+#[cfg(test)]
+use crate::usecases::mock_user_usecases as user_usecases;
+
+// UserRepoのモック化
+#[cfg(all(not(test), feature = "inmemory"))]
+use infrastructure::user_repository_impl::InMemoryUserRepository as UserRepositoryImpl;
+
+#[cfg(all(not(test), not(feature = "inmemory")))]
+use infrastructure::user_repository_impl::UserPgDBRepository as UserRepositoryImpl;
+
+#[cfg(test)]
+use infrastructure::user_repository_impl::MockUserRepository as UserRepositoryImpl;
+
+use axum::{
+    extract::rejection::{JsonRejection, PathRejection},
+    extract::{Json, Path, State},
+};
+use std::sync::Arc;
+use tracing_attributes::instrument;
+
+#[instrument(skip(user_repo), err(Display))]
+pub async fn save_user(
+    caller: Caller,
+    State(user_repo): State<Arc<UserRepositoryImpl>>,
+    new_user_info_res: Result<Json<NewUserInfo>, JsonRejection>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let NewUserInfo {
+        name,
+        password,
+        role,
+    } = new_user_info_res?.0;
+    let cmd = user_commands::SaveUserCommand::new(name, password, role);
+    user_usecases::save_user(user_repo, cmd).await?;
+    Ok(())
+}
+
+#[instrument(skip(user_repo), err(Display))]
+pub async fn all_users(
+    caller: Caller,
+    State(user_repo): State<Arc<UserRepositoryImpl>>,
+) -> Result<Json<Vec<UserInfo>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let cmd = user_commands::AllUsersCommand;
+    let users = user_usecases::all_users(user_repo, cmd).await?;
+    Ok(Json(users.into_iter().map(UserInfo::from).collect()))
+}
+
+#[instrument(skip(user_repo), err(Display))]
+pub async fn remove_user(
+    caller: Caller,
+    id: Result<Path<UserId>, PathRejection>,
+    State(user_repo): State<Arc<UserRepositoryImpl>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = user_commands::RemoveUserCommand::new(id);
+    user_usecases::remove_user(user_repo, cmd).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::auth::test_utils::{auth_config, session_cookie_of};
+    use crate::usecases::mock_user_usecases;
+    use common::{AppCommonError, NewUserInfo, UserInfo};
+    use domain::user::{Role, User, UserId};
+    use infrastructure::user_repository_impl::MockUserRepository;
+
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+        routing::{delete, put},
+        Extension, Router,
+    };
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use serial_test::serial;
+    use std::sync::Arc;
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        let user_repo = Arc::new(MockUserRepository::new());
+
+        Router::new()
+            .route("/user", put(super::save_user).get(super::all_users))
+            .route("/user/:id", delete(super::remove_user))
+            .with_state(user_repo)
+            .layer(Extension(auth_config()))
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_user")]
+    async fn test_save_user(mut router: Router) {
+        let new_user_info = NewUserInfo::new("contributor", "password", Role::Contributor);
+        {
+            let mock_ctx = mock_user_usecases::save_user_context();
+            mock_ctx
+                .expect::<MockUserRepository>()
+                .withf(|_, cmd| {
+                    cmd.name == "contributor"
+                        && cmd.password == "password"
+                        && cmd.role == Role::Contributor
+                })
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/user")
+                .body(Body::from(serde_json::to_vec(&new_user_info).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            // Contributorはユーザーを登録できない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/user")
+                .body(Body::from(serde_json::to_vec(&new_user_info).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_user")]
+    async fn test_all_users(mut router: Router) {
+        let users = (0..10).map(|_| Faker.fake::<User>()).collect::<Vec<_>>();
+
+        let mock_ctx = mock_user_usecases::all_users_context();
+        mock_ctx
+            .expect::<MockUserRepository>()
+            .return_const(Ok(users.clone()));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+            .uri("/user")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let res_vec: Vec<UserInfo> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            res_vec,
+            users.into_iter().map(UserInfo::from).collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_user")]
+    async fn test_remove_user(mut router: Router) {
+        let user_id = UserId::generate();
+        {
+            let mock_ctx_err = mock_user_usecases::remove_user_context();
+            mock_ctx_err
+                .expect::<MockUserRepository>()
+                .withf(move |_, cmd| cmd.id == user_id)
+                .return_const(Err(AppCommonError::NoRecordError));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/user/{user_id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+
+            assert!(matches!(err, AppCommonError::NoRecordError));
+        }
+        {
+            // セッションが無い場合
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .uri(&format!("/user/{user_id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+}
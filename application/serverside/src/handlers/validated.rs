@@ -0,0 +1,114 @@
+use common::AppCommonError;
+use domain::FromInput;
+
+use axum::{
+    async_trait,
+    body::HttpBody,
+    extract::{FromRequest, Json},
+    http::Request,
+    BoxError,
+};
+
+/// ボディのjsonを検証前の入力としてデシリアライズし，`FromInput`で検証する`Json`．
+/// バリデーションエラーはjson中のパスとともに`ValidationError`として返す．
+#[derive(Debug, Clone)]
+pub struct ValidatedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S, B> FromRequest<S, B> for ValidatedJson<T>
+where
+    T: FromInput,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    type Rejection = AppCommonError;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(input) = Json::<T::Input>::from_request(req, state).await?;
+        Ok(Self(T::from_input(input, "")?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ValidatedJson;
+    use common::AppCommonError;
+    use domain::movie_clip::MovieClip;
+    use domain::ValidationCode;
+
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+        routing::put,
+        Router,
+    };
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn router() -> Router {
+        Router::new().route(
+            "/clips",
+            put(
+                |body: Result<ValidatedJson<Vec<MovieClip>>, AppCommonError>| async move {
+                    body.map(|_| ())
+                },
+            ),
+        )
+    }
+
+    async fn call(router: &mut Router, body: String) -> (StatusCode, Option<AppCommonError>) {
+        let request = Request::builder()
+            .method(http::Method::PUT)
+            .uri("/clips")
+            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .body(Body::from(body))
+            .unwrap();
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).ok())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_validated_json(mut router: Router) {
+        let movie_clips = vec![Faker.fake::<MovieClip>(), Faker.fake::<MovieClip>()];
+        let json = serde_json::to_value(&movie_clips).unwrap();
+
+        let (status, _) = call(&mut router, json.to_string()).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // ドメインのバリデーションエラーはjson中のパスとともに型のまま返す
+        let mut invalid_json = json.clone();
+        invalid_json[1]["url"] = serde_json::json!("https://example.com");
+        let (status, err) = call(&mut router, invalid_json.to_string()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let Some(AppCommonError::ValidationError(validation_error)) = err else {
+            panic!("Expected validation error: {err:?}");
+        };
+        assert_eq!(validation_error.code, ValidationCode::InvalidUrlPrefix);
+        assert_eq!(validation_error.field, "[1].url");
+
+        let mut invalid_json = json.clone();
+        invalid_json[0]["range"] = serde_json::json!({"start": 100, "end": 50});
+        let (status, err) = call(&mut router, invalid_json.to_string()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let Some(AppCommonError::ValidationError(validation_error)) = err else {
+            panic!("Expected validation error: {err:?}");
+        };
+        assert_eq!(validation_error.code, ValidationCode::EndBeforeStart);
+        assert_eq!(validation_error.field, "[0].range.end");
+
+        // それ以外のエラーはJsonRejectionError
+        let mut invalid_json = json;
+        invalid_json[0]["url"] = serde_json::json!(10);
+        let (status, err) = call(&mut router, invalid_json.to_string()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(matches!(err, Some(AppCommonError::JsonRejectionError(_))));
+    }
+}
@@ -1,6 +1,12 @@
+use crate::auth::AuthConfig;
 use crate::commands::video_commands;
-use common::{AppCommonError, QueryInfo};
+use crate::handlers::{Caller, Client, LatestRevisionsQuery, RevisionDiffQuery, ValidatedJson};
+use common::{AppCommonError, NewVideoInfo, Page};
+use domain::channel::ChannelId;
+use domain::revision::{FieldChange, Revision, RevisionId};
+use domain::user::Role;
 use domain::video::{Video, VideoId, VideoType};
+use domain::{FromInput, PageSize, Tag};
 
 // video_usecaseのモック化
 #[cfg(not(test))]
@@ -19,48 +25,114 @@ use infrastructure::video_repository_impl::VideoPgDbRepository as VideoRepositor
 #[cfg(test)]
 use infrastructure::video_repository_impl::InMemoryVideoRepository as VideoRepositoryImpl; // 実際には利用しない．
 
+// ChannelRepoのモック化
+#[cfg(all(not(test), feature = "inmemory"))]
+use infrastructure::channel_repository_impl::InMemoryChannelRepository as ChannelRepositoryImpl;
+
+#[cfg(all(not(test), not(feature = "inmemory")))]
+use infrastructure::channel_repository_impl::ChannelPgDBRepository as ChannelRepositoryImpl;
+
+#[cfg(test)]
+use infrastructure::channel_repository_impl::InMemoryChannelRepository as ChannelRepositoryImpl; // 実際には利用しない．
+
 use axum::{
     extract::rejection::{JsonRejection, PathRejection, QueryRejection},
     extract::{Json, Path, Query, State},
+    Extension,
 };
 use serde::Deserialize;
 use std::str::FromStr;
 use std::sync::Arc;
 use tracing_attributes::instrument;
 
-#[instrument(skip(video_repo), err(Display))]
+#[instrument(skip(video_repo, channel_repo), err(Display))]
 pub async fn save_video<T: VideoType + 'static>(
+    caller: Caller,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+    Extension(channel_repo): Extension<Arc<ChannelRepositoryImpl>>,
+    video_info_res: Result<ValidatedJson<NewVideoInfo>, AppCommonError>,
+) -> Result<Json<Video<T>>, AppCommonError> {
+    caller.require_editor()?;
+    let video_info = video_info_res?.0;
+    let cmd = video_commands::SaveVideoCommand::new(video_info, caller.actor());
+    let video = video_usecases::save_video::<VideoRepositoryImpl<T>, ChannelRepositoryImpl, T>(
+        video_repo,
+        channel_repo,
+        cmd,
+    )
+    .await?;
+    Ok(Json(video))
+}
+
+#[instrument(skip(video_repo, channel_repo), err(Display))]
+pub async fn edit_video<T: VideoType + 'static>(
+    caller: Caller,
     State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
-    video_res: Result<Json<Video<T>>, JsonRejection>,
+    Extension(channel_repo): Extension<Arc<ChannelRepositoryImpl>>,
+    video_res: Result<ValidatedJson<Video<T>>, AppCommonError>,
 ) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
     let video = video_res?.0;
-    let cmd = video_commands::SaveVideoCommand::<T>::new(video);
-    video_usecases::save_video(video_repo, cmd).await?;
+    let cmd = video_commands::EditVideoCommand::<T>::new(video, caller.actor());
+    video_usecases::edit_video(video_repo, channel_repo, cmd).await?;
     Ok(())
 }
 
-#[instrument(skip(video_repo), err(Display))]
-pub async fn edit_video<T: VideoType + 'static>(
+/// バックアップの動画をid, likeを保ったまま保存する．管理者のみ
+#[instrument(skip(video_repo, channel_repo, videos_res), err(Display))]
+pub async fn import_videos<T: VideoType + 'static>(
+    caller: Caller,
     State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
-    video_res: Result<Json<Video<T>>, JsonRejection>,
+    Extension(channel_repo): Extension<Arc<ChannelRepositoryImpl>>,
+    videos_res: Result<ValidatedJson<Vec<Video<T>>>, AppCommonError>,
 ) -> Result<(), AppCommonError> {
-    let video = video_res?.0;
-    let cmd = video_commands::EditVideoCommand::<T>::new(video);
-    video_usecases::edit_video(video_repo, cmd).await?;
+    caller.require(Role::Admin)?;
+    let videos = videos_res?.0;
+    let cmd = video_commands::ImportVideosCommand::<T>::new(videos, caller.actor());
+    video_usecases::import_videos(video_repo, channel_repo, cmd).await?;
     Ok(())
 }
 
+/// クライアントごとに一度だけLikeをインクリメントする．
 #[instrument(skip(video_repo), err(Display))]
 pub async fn increment_like_video<T: VideoType + 'static>(
+    client: Client,
     id: Result<Path<VideoId>, PathRejection>,
     State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
 ) -> Result<(), AppCommonError> {
     let id = id?.0;
-    let cmd = video_commands::IncrementLikeVideoCommand::new(id);
+    let cmd = video_commands::IncrementLikeVideoCommand::new(id, client.id());
     video_usecases::increment_like_video::<VideoRepositoryImpl<T>, _>(video_repo, cmd).await?;
     Ok(())
 }
 
+/// クライアントのLikeを取り消す．
+#[instrument(skip(video_repo), err(Display))]
+pub async fn unlike_video<T: VideoType + 'static>(
+    client: Client,
+    id: Result<Path<VideoId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<(), AppCommonError> {
+    let id = id?.0;
+    let cmd = video_commands::UnlikeVideoCommand::new(id, client.id());
+    video_usecases::unlike_video::<VideoRepositoryImpl<T>, _>(video_repo, cmd).await?;
+    Ok(())
+}
+
+/// jsonで与えたidのうちクライアントがLikeしているVideo<T>のidを返す．
+#[instrument(skip(video_repo, ids_res), err(Display))]
+pub async fn liked_video_ids<T: VideoType + 'static>(
+    client: Client,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+    ids_res: Result<Json<Vec<VideoId>>, JsonRejection>,
+) -> Result<Json<Vec<VideoId>>, AppCommonError> {
+    let ids = ids_res?.0;
+    let cmd = video_commands::LikedVideoIdsCommand::new(ids, client.id());
+    let liked_ids =
+        video_usecases::liked_video_ids::<VideoRepositoryImpl<T>, _>(video_repo, cmd).await?;
+    Ok(Json(liked_ids))
+}
+
 #[instrument(skip(video_repo), err(Display))]
 pub async fn all_videos<T: VideoType + 'static>(
     State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
@@ -88,100 +160,297 @@ impl TryFrom<String> for SortType {
 #[derive(Deserialize, Debug)]
 pub struct VideoQuery {
     sort_type: SortType,
-    length: Option<usize>,
+    /// 与えられない場合はデフォルトのサイズとなる．`PageSize`としてハンドラで検証する
+    length: Option<i64>,
+    /// 前のページの`next_cursor`
+    cursor: Option<String>,
+    /// `,`で区切ったタグ．全てのタグを持つ動画のみを返す．
+    tags: Option<String>,
 }
 
-#[instrument(skip(video_repo), err(Display))]
+/// ビデオの種類とソートタイプごとのカーソルのスコープ
+fn cursor_scope<T: VideoType>(sort_type: &str) -> String {
+    format!("{}.{sort_type}", T::snake_case())
+}
+
+#[instrument(skip(auth_config, video_repo), err(Display))]
 pub async fn get_videos_with_query<T: VideoType + 'static>(
+    Extension(auth_config): Extension<Arc<AuthConfig>>,
     path_query_res: Result<Query<VideoQuery>, QueryRejection>,
     State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
-    query_info_res: Result<Json<QueryInfo<Video<T>>>, JsonRejection>,
-) -> Result<Json<Vec<Video<T>>>, AppCommonError> {
+) -> Result<Json<Page<Video<T>>>, AppCommonError> {
     let path_query = path_query_res?.0;
-    let reference_video = match query_info_res {
-        // リクエストに正しいjsonが与えられた場合
-        Ok(query_info) => query_info.0.reference,
-        // Jsonが与えら無かった場合
-        Err(JsonRejection::MissingJsonContentType(_)) => None,
-        // その他のJsonRejection
-        Err(e) => return Err(e.into()),
-    };
-
-    match (path_query.sort_type, path_query.length) {
+    let length = Option::<PageSize>::from_input(path_query.length, "length")?.unwrap_or_default();
+    let tags = path_query
+        .tags
+        .as_deref()
+        .map(Tag::parse_list)
+        .transpose()?
+        .unwrap_or_default();
+
+    match path_query.sort_type {
         // ソートタイプがLikeの場合
-        (SortType::Like, Some(length)) => {
-            match reference_video {
-                // QueryInfoにreference_originalが与えられた場合
-                Some(reference_video) => {
+        SortType::Like => {
+            let scope = cursor_scope::<T>("like");
+            let page = match path_query.cursor {
+                // カーソルが与えられた場合
+                Some(cursor) => {
+                    let cursor = auth_config.verify_cursor(&scope, &cursor)?;
                     let cmd =
-                        video_commands::OrderByLikeLaterVideosCommand::new(reference_video, length);
-                    let videos =
-                        video_usecases::order_by_like_later_videos(video_repo, cmd).await?;
-                    Ok(Json(videos))
+                        video_commands::OrderByLikeLaterVideosCommand::new(tags, cursor, length);
+                    video_usecases::order_by_like_later_videos::<VideoRepositoryImpl<T>, T>(
+                        video_repo, cmd,
+                    )
+                    .await?
                 }
-                // QueryInfoにreference_originalが与えられなかった場合
+                // カーソルが与えられなかった場合
                 None => {
-                    let cmd = video_commands::OrderByLikeVideosCommand::new(length);
-                    let videos = video_usecases::order_by_like_videos(video_repo, cmd).await?;
-                    Ok(Json(videos))
+                    let cmd = video_commands::OrderByLikeVideosCommand::new(tags, length);
+                    video_usecases::order_by_like_videos::<VideoRepositoryImpl<T>, T>(
+                        video_repo, cmd,
+                    )
+                    .await?
                 }
-            }
+            };
+            Ok(Json(page.with_next_cursor(|video| {
+                auth_config.issue_cursor(&scope, video.like_cursor())
+            })))
         }
         // ソートタイプがDateの場合
-        (SortType::Date, Some(length)) => {
-            match reference_video {
-                // QueryInfoにreference_originalが与えられた場合
-                Some(reference_video) => {
+        SortType::Date => {
+            let scope = cursor_scope::<T>("date");
+            let page = match path_query.cursor {
+                // カーソルが与えられた場合
+                Some(cursor) => {
+                    let cursor = auth_config.verify_cursor(&scope, &cursor)?;
                     let cmd =
-                        video_commands::OrderByDateLaterVideosCommand::new(reference_video, length);
-                    let videos =
-                        video_usecases::order_by_date_later_videos(video_repo, cmd).await?;
-                    Ok(Json(videos))
+                        video_commands::OrderByDateLaterVideosCommand::new(tags, cursor, length);
+                    video_usecases::order_by_date_later_videos::<VideoRepositoryImpl<T>, T>(
+                        video_repo, cmd,
+                    )
+                    .await?
                 }
-                // QueryInfoにreference_originalが与えられなかった場合
+                // カーソルが与えられなかった場合
                 None => {
-                    let cmd = video_commands::OrderByDateVideosCommand::new(length);
-                    let videos = video_usecases::order_by_date_videos(video_repo, cmd).await?;
-                    Ok(Json(videos))
+                    let cmd = video_commands::OrderByDateVideosCommand::new(tags, length);
+                    video_usecases::order_by_date_videos::<VideoRepositoryImpl<T>, T>(
+                        video_repo, cmd,
+                    )
+                    .await?
                 }
-            }
+            };
+            Ok(Json(page.with_next_cursor(|video| {
+                auth_config.issue_cursor(&scope, video.date_cursor())
+            })))
         }
-        // 無効なクエリの場合
-        _ => Err(AppCommonError::QueryStringRejectionError(
-            "Invalid query parameter combination.".to_string(),
-        )),
     }
 }
 
+/// `id`のチャンネルが投稿したVideo<T>を新しい順に返す．
+#[instrument(skip(video_repo), err(Display))]
+pub async fn channel_videos<T: VideoType + 'static>(
+    id: Result<Path<ChannelId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<Json<Vec<Video<T>>>, AppCommonError> {
+    let id = id?.0;
+    let cmd = video_commands::ChannelVideosCommand::new(id);
+    let videos =
+        video_usecases::channel_videos::<VideoRepositoryImpl<T>, T>(video_repo, cmd).await?;
+    Ok(Json(videos))
+}
+
+/// Video<T>に付けられている全てのタグを返す．
+#[instrument(skip(video_repo), err(Display))]
+pub async fn all_video_tags<T: VideoType + 'static>(
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<Json<Vec<Tag>>, AppCommonError> {
+    let cmd = video_commands::AllVideoTagsCommand;
+    let tags = video_usecases::all_video_tags::<VideoRepositoryImpl<T>, T>(video_repo, cmd).await?;
+    Ok(Json(tags))
+}
+
+#[instrument(skip(video_repo), err(Display))]
+pub async fn video_tags<T: VideoType + 'static>(
+    id: Result<Path<VideoId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<Json<Vec<Tag>>, AppCommonError> {
+    let id = id?.0;
+    let cmd = video_commands::VideoTagsCommand::new(id);
+    let tags = video_usecases::video_tags::<VideoRepositoryImpl<T>, T>(video_repo, cmd).await?;
+    Ok(Json(tags))
+}
+
+#[instrument(skip(video_repo), err(Display))]
+pub async fn add_video_tag<T: VideoType + 'static>(
+    caller: Caller,
+    id: Result<Path<VideoId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+    tag_res: Result<ValidatedJson<Tag>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let id = id?.0;
+    let tag = tag_res?.0;
+    let cmd = video_commands::AddVideoTagCommand::new(id, tag);
+    video_usecases::add_video_tag::<VideoRepositoryImpl<T>, T>(video_repo, cmd).await?;
+    Ok(())
+}
+
+#[instrument(skip(video_repo), err(Display))]
+pub async fn remove_video_tag<T: VideoType + 'static>(
+    caller: Caller,
+    id: Result<Path<VideoId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+    tag_res: Result<ValidatedJson<Tag>, AppCommonError>,
+) -> Result<(), AppCommonError> {
+    caller.require_editor()?;
+    let id = id?.0;
+    let tag = tag_res?.0;
+    let cmd = video_commands::RemoveVideoTagCommand::new(id, tag);
+    video_usecases::remove_video_tag::<VideoRepositoryImpl<T>, T>(video_repo, cmd).await?;
+    Ok(())
+}
+
 #[instrument(skip(video_repo), err(Display))]
 pub async fn remove_video<T: VideoType + 'static>(
+    caller: Caller,
     id: Result<Path<VideoId>, PathRejection>,
     State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
 ) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
     let id = id?.0;
-    let cmd = video_commands::RemoveVideoCommand::new(id);
+    let cmd = video_commands::RemoveVideoCommand::new(id, caller.actor());
     video_usecases::remove_video(video_repo, cmd).await?;
     Ok(())
 }
 
+/// ゴミ箱の動画を削除した日時の新しい順に返す．協力者以上
+#[instrument(skip(video_repo), err(Display))]
+pub async fn trashed_videos<T: VideoType + 'static>(
+    caller: Caller,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<Json<Vec<Video<T>>>, AppCommonError> {
+    caller.require(Role::Contributor)?;
+    let cmd = video_commands::TrashedVideosCommand;
+    let videos = video_usecases::trashed_videos(video_repo, cmd).await?;
+    Ok(Json(videos))
+}
+
+/// ゴミ箱の動画を戻す．協力者以上
+#[instrument(skip(video_repo), err(Display))]
+pub async fn restore_trashed_video<T: VideoType + 'static>(
+    caller: Caller,
+    id: Result<Path<VideoId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Contributor)?;
+    let id = id?.0;
+    let cmd = video_commands::RestoreTrashedVideoCommand::new(id, caller.actor());
+    video_usecases::restore_trashed_video(video_repo, cmd).await?;
+    Ok(())
+}
+
+/// ゴミ箱の動画を完全に削除する．管理者のみ
+#[instrument(skip(video_repo), err(Display))]
+pub async fn purge_video<T: VideoType + 'static>(
+    caller: Caller,
+    id: Result<Path<VideoId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<(), AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = video_commands::PurgeVideoCommand::new(id, caller.actor());
+    video_usecases::purge_video(video_repo, cmd).await?;
+    Ok(())
+}
+
+/// 動画の編集履歴を古い順に返す．管理者のみ
+#[instrument(skip(video_repo), err(Display))]
+pub async fn video_revisions<T: VideoType + 'static>(
+    caller: Caller,
+    id: Result<Path<VideoId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<Json<Vec<Revision>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = video_commands::VideoRevisionsCommand::new(id);
+    let revisions =
+        video_usecases::video_revisions::<VideoRepositoryImpl<T>, T>(video_repo, cmd).await?;
+    Ok(Json(revisions))
+}
+
+/// 動画の最近の編集履歴を新しい順に返す．管理者のみ
+#[instrument(skip(video_repo), err(Display))]
+pub async fn latest_video_revisions<T: VideoType + 'static>(
+    caller: Caller,
+    query_res: Result<Query<LatestRevisionsQuery>, QueryRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<Json<Vec<Revision>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let length = query_res?.0.page_size()?;
+    let cmd = video_commands::LatestVideoRevisionsCommand::new(length);
+    let revisions =
+        video_usecases::latest_video_revisions::<VideoRepositoryImpl<T>, T>(video_repo, cmd)
+            .await?;
+    Ok(Json(revisions))
+}
+
+/// 二つの編集履歴の間の変更を返す．管理者のみ
+#[instrument(skip(video_repo), err(Display))]
+pub async fn video_revision_diff<T: VideoType + 'static>(
+    caller: Caller,
+    query_res: Result<Query<RevisionDiffQuery>, QueryRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<Json<Vec<FieldChange>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let RevisionDiffQuery { from, to } = query_res?.0;
+    let cmd = video_commands::VideoRevisionDiffCommand::new(from, to);
+    let changes =
+        video_usecases::video_revision_diff::<VideoRepositoryImpl<T>, T>(video_repo, cmd).await?;
+    Ok(Json(changes))
+}
+
+/// 編集履歴の時点の内容に戻し，戻した動画を返す．管理者のみ
+#[instrument(skip(video_repo), err(Display))]
+pub async fn restore_video_revision<T: VideoType + 'static>(
+    caller: Caller,
+    id: Result<Path<RevisionId>, PathRejection>,
+    State(video_repo): State<Arc<VideoRepositoryImpl<T>>>,
+) -> Result<Json<Video<T>>, AppCommonError> {
+    caller.require(Role::Admin)?;
+    let id = id?.0;
+    let cmd = video_commands::RestoreVideoRevisionCommand::new(id, caller.actor());
+    let video =
+        video_usecases::restore_video_revision::<VideoRepositoryImpl<T>, T>(video_repo, cmd)
+            .await?;
+    Ok(Json(video))
+}
+
 #[cfg(test)]
 mod test {
+    use crate::auth::test_utils::{
+        auth_config, client_cookie_of, edit_token_cookie, session_cookie_of,
+    };
     use crate::usecases::mock_video_usecases;
-    use common::{AppCommonError, QueryInfoRef};
+    use common::{AppCommonError, NewVideoInfo, Page};
+    use domain::channel::ChannelId;
+    use domain::client::ClientId;
+    use domain::revision::{Actor, Revision, RevisionId};
+    use domain::user::Role;
     use domain::video::{Original, Video, VideoId};
+    use domain::{PageSize, Tag, ValidationCode};
+    use infrastructure::channel_repository_impl::InMemoryChannelRepository;
     use infrastructure::video_repository_impl::InMemoryVideoRepository;
 
     use axum::{
         body::Body,
         http::{self, Request, StatusCode},
-        routing::{delete, get, patch, put},
-        Router,
+        routing::{delete, get, patch, post, put},
+        Extension, Router,
     };
     use fake::{Fake, Faker};
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::{fixture, rstest};
     use serial_test::serial;
-    use std::borrow::Cow;
     use std::sync::Arc;
     use tower::{Service, ServiceExt};
 
@@ -205,7 +474,48 @@ mod test {
                 "/original/increment_like/:id",
                 patch(super::increment_like_video::<Original>),
             )
+            .route(
+                "/original/unlike/:id",
+                patch(super::unlike_video::<Original>),
+            )
+            .route("/original/liked", post(super::liked_video_ids::<Original>))
+            .route("/original/tags", get(super::all_video_tags::<Original>))
+            .route(
+                "/original/channel/:id",
+                get(super::channel_videos::<Original>),
+            )
+            .route(
+                "/original/tags/:id",
+                get(super::video_tags::<Original>)
+                    .put(super::add_video_tag::<Original>)
+                    .delete(super::remove_video_tag::<Original>),
+            )
+            .route(
+                "/original/revisions",
+                get(super::latest_video_revisions::<Original>),
+            )
+            .route(
+                "/original/revisions/:id",
+                get(super::video_revisions::<Original>),
+            )
+            .route(
+                "/original/revision_diff",
+                get(super::video_revision_diff::<Original>),
+            )
+            .route(
+                "/original/restore/:id",
+                patch(super::restore_video_revision::<Original>),
+            )
+            .route("/original/trash", get(super::trashed_videos::<Original>))
+            .route(
+                "/original/trash/:id",
+                patch(super::restore_trashed_video::<Original>)
+                    .delete(super::purge_video::<Original>),
+            )
+            .route("/original/import", put(super::import_videos::<Original>))
             .with_state(video_repo)
+            .layer(Extension(Arc::new(InMemoryChannelRepository::new())))
+            .layer(Extension(auth_config()))
     }
 
     #[fixture]
@@ -220,46 +530,50 @@ mod test {
     #[serial("mock_video")]
     async fn test_save_video(mut router: Router) {
         let video = Faker.fake::<Video<Original>>();
+        let video_info = NewVideoInfo::from(video.clone());
 
         {
             let mock_ctx_ok = mock_video_usecases::save_video_context();
             mock_ctx_ok
-                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .expect::<InMemoryVideoRepository<Original>, InMemoryChannelRepository, Original>()
                 .withf({
-                    let video = video.clone();
-                    move |_, cmd| cmd.video == video
+                    let video_info = video_info.clone();
+                    move |_, _, cmd| cmd.video_info == video_info
                 })
                 .times(1)
-                .return_const(Ok(()));
+                .return_const(Ok(video.clone()));
 
             let request = Request::builder()
                 .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/original")
-                .body(Body::from(serde_json::to_vec(&video).unwrap()))
+                .body(Body::from(serde_json::to_vec(&video_info).unwrap()))
                 .unwrap();
 
             let response = router.ready().await.unwrap().call(request).await.unwrap();
             assert_eq!(response.status(), StatusCode::OK);
             let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-            assert!(body.is_empty());
+            let saved_video: Video<Original> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(saved_video, video);
         }
         {
             let mock_ctx_err = mock_video_usecases::save_video_context();
             mock_ctx_err
-                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .expect::<InMemoryVideoRepository<Original>, InMemoryChannelRepository, Original>()
                 .withf({
-                    let video = video.clone();
-                    move |_, cmd| cmd.video == video
+                    let video_info = video_info.clone();
+                    move |_, _, cmd| cmd.video_info == video_info
                 })
                 .times(1)
                 .return_const(Err(AppCommonError::ConflictError));
 
             let request = Request::builder()
                 .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/original")
-                .body(Body::from(serde_json::to_vec(&video).unwrap()))
+                .body(Body::from(serde_json::to_vec(&video_info).unwrap()))
                 .unwrap();
 
             let response = router.ready().await.unwrap().call(request).await.unwrap();
@@ -281,16 +595,17 @@ mod test {
         {
             let mock_ctx_ok = mock_video_usecases::edit_video_context();
             mock_ctx_ok
-                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .expect::<InMemoryVideoRepository<Original>, InMemoryChannelRepository, Original>()
                 .withf({
                     let video = video.clone();
-                    move |_, cmd| cmd.video == video
+                    move |_, _, cmd| cmd.video == video
                 })
                 .times(1)
                 .return_const(Ok(()));
 
             let request = Request::builder()
                 .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/original")
                 .body(Body::from(serde_json::to_vec(&video).unwrap()))
@@ -304,16 +619,17 @@ mod test {
         {
             let mock_ctx_err = mock_video_usecases::edit_video_context();
             mock_ctx_err
-                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .expect::<InMemoryVideoRepository<Original>, InMemoryChannelRepository, Original>()
                 .withf({
                     let video = video.clone();
-                    move |_, cmd| cmd.video == video
+                    move |_, _, cmd| cmd.video == video
                 })
                 .times(1)
                 .return_const(Err(AppCommonError::NoRecordError));
 
             let request = Request::builder()
                 .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
                 .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                 .uri("/original")
                 .body(Body::from(serde_json::to_vec(&video).unwrap()))
@@ -327,6 +643,37 @@ mod test {
 
             assert!(matches!(err, AppCommonError::NoRecordError));
         }
+        {
+            // 他の編集によって版数が進んでいる場合
+            let mut video = video.clone();
+            video.increment_version();
+
+            let mock_ctx_err = mock_video_usecases::edit_video_context();
+            mock_ctx_err
+                .expect::<InMemoryVideoRepository<Original>, InMemoryChannelRepository, Original>()
+                .withf({
+                    let video = video.clone();
+                    move |_, _, cmd| cmd.video == video
+                })
+                .times(1)
+                .return_const(Err(AppCommonError::StaleVersion));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/original")
+                .body(Body::from(serde_json::to_vec(&video).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+
+            assert!(matches!(err, AppCommonError::StaleVersion));
+        }
     }
 
     #[rstest]
@@ -334,18 +681,20 @@ mod test {
     #[serial("mock_video")]
     async fn test_increment_like_video(mut router: Router) {
         let video_id = VideoId::generate();
+        let client_id = ClientId::generate();
 
         {
             let mock_ctx_ok = mock_video_usecases::increment_like_video_context();
             mock_ctx_ok
                 .expect::<InMemoryVideoRepository<Original>, Original>()
-                .withf(move |_, cmd| cmd.id == video_id)
+                .withf(move |_, cmd| cmd.id == video_id && cmd.client == client_id)
                 .times(1)
                 .return_const(Ok(()));
 
             let request = Request::builder()
                 .method(http::Method::PATCH)
                 .uri(&format!("/original/increment_like/{video_id}"))
+                .header(http::header::COOKIE, client_cookie_of(client_id))
                 .body(Body::empty())
                 .unwrap();
 
@@ -365,6 +714,7 @@ mod test {
             let request = Request::builder()
                 .method(http::Method::PATCH)
                 .uri(&format!("/original/increment_like/{video_id}"))
+                .header(http::header::COOKIE, client_cookie_of(client_id))
                 .body(Body::empty())
                 .unwrap();
 
@@ -378,6 +728,79 @@ mod test {
         }
     }
 
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_unlike_video(mut router: Router) {
+        let video_id = VideoId::generate();
+        let client_id = ClientId::generate();
+
+        let mock_ctx = mock_video_usecases::unlike_video_context();
+        mock_ctx
+            .expect::<InMemoryVideoRepository<Original>, Original>()
+            .withf(move |_, cmd| cmd.id == video_id && cmd.client == client_id)
+            .times(1)
+            .return_const(Ok(()));
+
+        let request = Request::builder()
+            .method(http::Method::PATCH)
+            .uri(&format!("/original/unlike/{video_id}"))
+            .header(http::header::COOKIE, client_cookie_of(client_id))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // クッキーが無い場合は新しいクライアントとして扱わずに拒否する
+        let request = Request::builder()
+            .method(http::Method::PATCH)
+            .uri(&format!("/original/unlike/{video_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(err, AppCommonError::NoClientTokenError));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_liked_video_ids(mut router: Router) {
+        let ids = (0..10).map(|_| VideoId::generate()).collect::<Vec<_>>();
+        let liked_ids = ids[..5].to_vec();
+        let client_id = ClientId::generate();
+
+        let mock_ctx = mock_video_usecases::liked_video_ids_context();
+        mock_ctx
+            .expect::<InMemoryVideoRepository<Original>, Original>()
+            .withf({
+                let ids = ids.clone();
+                move |_, cmd| cmd.ids == ids && cmd.client == client_id
+            })
+            .times(1)
+            .return_const(Ok(liked_ids.clone()));
+
+        let request = Request::builder()
+            .method(http::Method::POST)
+            .uri("/original/liked")
+            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .header(http::header::COOKIE, client_cookie_of(client_id))
+            .body(Body::from(serde_json::to_vec(&ids).unwrap()))
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let res_ids: Vec<VideoId> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(res_ids, liked_ids);
+    }
+
     #[rstest]
     #[tokio::test]
     #[serial("mock_video")]
@@ -403,6 +826,44 @@ mod test {
         assert_eq!(res_vec, videos);
     }
 
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_channel_videos(mut router: Router, videos: Vec<Video<Original>>) {
+        let channel = ChannelId::generate();
+
+        let mock_ctx = mock_video_usecases::channel_videos_context();
+        mock_ctx
+            .expect::<InMemoryVideoRepository<Original>, Original>()
+            .withf(move |_, cmd| cmd.channel == channel)
+            .times(1)
+            .return_const(Ok(videos.clone()));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri(&format!("/original/channel/{channel}"))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let res_vec: Vec<Video<Original>> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(res_vec, videos);
+
+        // 無効なid
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/original/channel/invalid")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[rstest]
     #[tokio::test]
     #[serial("mock_video")]
@@ -412,9 +873,9 @@ mod test {
         let mock_ctx = mock_video_usecases::order_by_like_videos_context();
         mock_ctx
             .expect::<InMemoryVideoRepository<Original>, Original>()
-            .withf(move |_, cmd| cmd.length == length)
+            .withf(move |_, cmd| cmd.length.get() == length)
             .times(1)
-            .return_const(Ok(videos.clone()));
+            .return_const(Ok(Page::new(videos.clone(), true, None)));
 
         let request = Request::builder()
             .method(http::Method::GET)
@@ -426,46 +887,47 @@ mod test {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<Video<Original>> = serde_json::from_slice(&body).unwrap();
+        let page: Page<Video<Original>> = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(res_vec, videos);
+        assert_eq!(page.items, videos);
+        // 次のページのカーソル
+        let next_cursor = auth_config()
+            .verify_cursor("original.like", &page.next_cursor.unwrap())
+            .unwrap();
+        assert_eq!(next_cursor, videos.last().unwrap().like_cursor());
     }
 
     #[rstest]
     #[tokio::test]
     #[serial("mock_video")]
     async fn test_order_by_like_later_videos(mut router: Router, videos: Vec<Video<Original>>) {
-        let reference = Faker.fake::<Video<Original>>();
+        let cursor = Faker.fake::<Video<Original>>().like_cursor();
         let length = 100_usize;
 
         let mock_ctx = mock_video_usecases::order_by_like_later_videos_context();
         mock_ctx
             .expect::<InMemoryVideoRepository<Original>, Original>()
-            .withf({
-                let reference = reference.clone();
-                move |_, cmd| cmd.reference == reference && cmd.length == length
-            })
+            .withf(move |_, cmd| cmd.cursor == cursor && cmd.length.get() == length)
             .times(1)
-            .return_const(Ok(videos.clone()));
+            .return_const(Ok(Page::new(videos.clone(), true, None)));
 
-        let query_info = QueryInfoRef::builder()
-            .reference(Cow::Borrowed(&reference))
-            .build();
+        let cursor_token = auth_config().issue_cursor("original.like", cursor);
 
         let request = Request::builder()
             .method(http::Method::GET)
-            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-            .uri(&format!("/original/query?sort_type=like&length={length}"))
-            .body(Body::from(serde_json::to_vec(&query_info).unwrap()))
+            .uri(&format!(
+                "/original/query?sort_type=like&length={length}&cursor={cursor_token}"
+            ))
+            .body(Body::empty())
             .unwrap();
 
         let response = router.ready().await.unwrap().call(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<Video<Original>> = serde_json::from_slice(&body).unwrap();
+        let page: Page<Video<Original>> = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(res_vec, videos);
+        assert_eq!(page.items, videos);
     }
 
     #[rstest]
@@ -477,9 +939,9 @@ mod test {
         let mock_ctx = mock_video_usecases::order_by_date_videos_context();
         mock_ctx
             .expect::<InMemoryVideoRepository<Original>, Original>()
-            .withf(move |_, cmd| cmd.length == length)
+            .withf(move |_, cmd| cmd.length.get() == length)
             .times(1)
-            .return_const(Ok(videos.clone()));
+            .return_const(Ok(Page::new(videos.clone(), true, None)));
 
         let request = Request::builder()
             .method(http::Method::GET)
@@ -491,53 +953,188 @@ mod test {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<Video<Original>> = serde_json::from_slice(&body).unwrap();
+        let page: Page<Video<Original>> = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(res_vec, videos);
+        assert_eq!(page.items, videos);
+        // 次のページのカーソル
+        let next_cursor = auth_config()
+            .verify_cursor("original.date", &page.next_cursor.unwrap())
+            .unwrap();
+        assert_eq!(next_cursor, videos.last().unwrap().date_cursor());
     }
 
     #[rstest]
     #[tokio::test]
     #[serial("mock_video")]
     async fn test_order_by_date_later_videos(mut router: Router, videos: Vec<Video<Original>>) {
-        let reference = Faker.fake::<Video<Original>>();
+        let cursor = Faker.fake::<Video<Original>>().date_cursor();
         let length = 100_usize;
 
         let mock_ctx = mock_video_usecases::order_by_date_later_videos_context();
         mock_ctx
             .expect::<InMemoryVideoRepository<Original>, Original>()
-            .withf({
-                let reference = reference.clone();
-                move |_, cmd| cmd.reference == reference && cmd.length == length
-            })
+            .withf(move |_, cmd| cmd.cursor == cursor && cmd.length.get() == length)
             .times(1)
-            .return_const(Ok(videos.clone()));
+            .return_const(Ok(Page::new(videos.clone(), true, None)));
 
-        let query_info = QueryInfoRef::builder()
-            .reference(Cow::Borrowed(&reference))
-            .build();
+        let cursor_token = auth_config().issue_cursor("original.date", cursor);
 
         let request = Request::builder()
             .method(http::Method::GET)
-            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-            .uri(&format!("/original/query?sort_type=date&length={length}"))
-            .body(Body::from(serde_json::to_vec(&query_info).unwrap()))
+            .uri(&format!(
+                "/original/query?sort_type=date&length={length}&cursor={cursor_token}"
+            ))
+            .body(Body::empty())
             .unwrap();
 
         let response = router.ready().await.unwrap().call(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let res_vec: Vec<Video<Original>> = serde_json::from_slice(&body).unwrap();
+        let page: Page<Video<Original>> = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(res_vec, videos);
+        assert_eq!(page.items, videos);
     }
 
     #[rstest]
     #[tokio::test]
     #[serial("mock_video")]
-    async fn test_remove_video(mut router: Router) {
-        let video_id = VideoId::generate();
+    async fn test_page_size_query(mut router: Router, videos: Vec<Video<Original>>) {
+        // lengthが与えられない場合はデフォルトのサイズ
+        let mock_ctx = mock_video_usecases::order_by_like_videos_context();
+        mock_ctx
+            .expect::<InMemoryVideoRepository<Original>, Original>()
+            .withf(|_, cmd| cmd.length == PageSize::default())
+            .times(1)
+            .return_const(Ok(Page::new(videos.clone(), false, None)));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/original/query?sort_type=like")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // 続きが存在しない場合はカーソルを持たない
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<Video<Original>> = serde_json::from_slice(&body).unwrap();
+        assert!(!page.has_more);
+        assert_eq!(page.next_cursor, None);
+
+        // 0以下のサイズ
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/original/query?sort_type=like&length=0")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        let AppCommonError::ValidationError(validation_error) = err else {
+            panic!("Expected validation error: {err:?}");
+        };
+        assert_eq!(validation_error.code, ValidationCode::TooSmall);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_order_by_date_videos_with_tags(mut router: Router, videos: Vec<Video<Original>>) {
+        let mock_ctx = mock_video_usecases::order_by_date_videos_context();
+        mock_ctx
+            .expect::<InMemoryVideoRepository<Original>, Original>()
+            .withf(|_, cmd| cmd.tags == vec![Tag::new("歌枠").unwrap()])
+            .times(1)
+            .return_const(Ok(Page::new(videos.clone(), false, None)));
+
+        let request = Request::builder()
+            .method(http::Method::GET)
+            .uri("/original/query?sort_type=date&tags=%E6%AD%8C%E6%9E%A0")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let page: Page<Video<Original>> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(page.items, videos);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_video_tags(mut router: Router) {
+        let video_id = VideoId::generate();
+        let tag = Tag::new("singing").unwrap();
+
+        {
+            let mock_ctx = mock_video_usecases::all_video_tags_context();
+            mock_ctx
+                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .times(1)
+                .return_const(Ok(vec![tag.clone()]));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .uri("/original/tags")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let tags: Vec<Tag> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(tags, vec![tag.clone()]);
+        }
+        {
+            let mock_ctx = mock_video_usecases::add_video_tag_context();
+            mock_ctx
+                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .withf({
+                    let tag = tag.clone();
+                    move |_, cmd| cmd.id == video_id && cmd.tag == tag
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri(&format!("/original/tags/{video_id}"))
+                .body(Body::from(serde_json::to_vec(&tag).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            // 不正なタグ
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri(&format!("/original/tags/{video_id}"))
+                .body(Body::from(r#""a,b""#))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_ne!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_remove_video(mut router: Router) {
+        let video_id = VideoId::generate();
 
         {
             let mock_ctx_ok = mock_video_usecases::remove_video_context();
@@ -549,6 +1146,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
                 .uri(&format!("/original/{video_id}"))
                 .body(Body::empty())
                 .unwrap();
@@ -568,6 +1166,7 @@ mod test {
 
             let request = Request::builder()
                 .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
                 .uri(&format!("/original/{video_id}"))
                 .body(Body::empty())
                 .unwrap();
@@ -581,4 +1180,278 @@ mod test {
             assert!(matches!(err, AppCommonError::NoRecordError));
         }
     }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_video_permission(mut router: Router) {
+        let video = Faker.fake::<Video<Original>>();
+        {
+            // セッションが無い場合は編集できない
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/original")
+                .body(Body::from(serde_json::to_vec(&video).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+            assert!(matches!(err, AppCommonError::UnauthorizedError));
+        }
+        {
+            // 編集用のトークンがあれば匿名でも編集できる
+            let mock_ctx_ok = mock_video_usecases::edit_video_context();
+            mock_ctx_ok
+                .expect::<InMemoryVideoRepository<Original>, InMemoryChannelRepository, Original>()
+                .withf({
+                    let video = video.clone();
+                    move |_, _, cmd| cmd.video == video
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/original")
+                .body(Body::from(serde_json::to_vec(&video).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            // 編集用のトークンでは削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .uri(&format!("/original/{}", video.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // Contributorは削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/original/{}", video.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+            assert!(matches!(err, AppCommonError::ForbiddenError));
+        }
+    }
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_video_revisions(mut router: Router) {
+        let original = Faker.fake::<Video<Original>>();
+        let revisions = (0..3).map(|_| Faker.fake::<Revision>()).collect::<Vec<_>>();
+        {
+            let mock_ctx = mock_video_usecases::latest_video_revisions_context();
+            mock_ctx
+                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .withf(|_, cmd| cmd.length == PageSize::default())
+                .times(1)
+                .return_const(Ok(revisions.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri("/original/revisions")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Vec<Revision> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, revisions);
+        }
+        {
+            let id = RevisionId::generate();
+            let mock_ctx = mock_video_usecases::restore_video_revision_context();
+            mock_ctx
+                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Ok(original.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/original/restore/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Video<Original> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, original);
+        }
+        {
+            // Contributorは編集履歴を参照できない
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/original/revisions/{}", original.id()))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_video_trash(mut router: Router) {
+        let videos = (0..3)
+            .map(|_| Faker.fake::<Video<Original>>())
+            .collect::<Vec<_>>();
+        let id = videos[0].id();
+        {
+            let mock_ctx = mock_video_usecases::trashed_videos_context();
+            mock_ctx
+                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .times(1)
+                .return_const(Ok(videos.clone()));
+
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri("/original/trash")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let res: Vec<Video<Original>> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(res, videos);
+        }
+        {
+            let mock_ctx = mock_video_usecases::restore_trashed_video_context();
+            mock_ctx
+                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PATCH)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/original/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            let mock_ctx = mock_video_usecases::purge_video_context();
+            mock_ctx
+                .expect::<InMemoryVideoRepository<Original>, Original>()
+                .withf(move |_, cmd| cmd.id == id && cmd.actor == Actor::Admin)
+                .times(1)
+                .return_const(Err(AppCommonError::NoRecordError));
+
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .uri(&format!("/original/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+        {
+            // 編集用のトークンではゴミ箱を参照できない
+            let request = Request::builder()
+                .method(http::Method::GET)
+                .header(http::header::COOKIE, edit_token_cookie())
+                .uri("/original/trash")
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        {
+            // Contributorは完全に削除できない
+            let request = Request::builder()
+                .method(http::Method::DELETE)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .uri(&format!("/original/trash/{id}"))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[serial("mock_video")]
+    async fn test_import_videos(mut router: Router) {
+        let videos = (0..3)
+            .map(|_| Faker.fake::<Video<Original>>())
+            .collect::<Vec<_>>();
+        {
+            // id, likeを含む動画をそのまま渡す
+            let mock_ctx = mock_video_usecases::import_videos_context();
+            mock_ctx
+                .expect::<InMemoryVideoRepository<Original>, InMemoryChannelRepository, Original>()
+                .withf({
+                    let videos = videos.clone();
+                    move |_, _, cmd| cmd.videos == videos && cmd.actor == Actor::Admin
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Admin))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/original/import")
+                .body(Body::from(serde_json::to_vec(&videos).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        {
+            // Contributorはインポートできない
+            let request = Request::builder()
+                .method(http::Method::PUT)
+                .header(http::header::COOKIE, session_cookie_of(Role::Contributor))
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .uri("/original/import")
+                .body(Body::from(serde_json::to_vec(&videos).unwrap()))
+                .unwrap();
+
+            let response = router.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
 }
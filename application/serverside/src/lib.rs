@@ -1,3 +1,5 @@
+pub mod auth;
 mod commands;
 pub mod handlers;
+pub mod rate_limit;
 mod usecases;
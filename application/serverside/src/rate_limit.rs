@@ -0,0 +1,544 @@
+use crate::auth::AuthConfig;
+use common::AppCommonError;
+use config::{RateLimitBudget, CONFIG};
+
+use axum::{
+    extract::ConnectInfo,
+    http::{Method, Request},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use lru::LruCache;
+use std::net::{IpAddr, SocketAddr};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// 保持するバケットの最大数．超えた場合は最も長く使われていないバケットを破棄する．
+const MAX_BUCKETS: usize = 100_000;
+
+/// クライアントのIPアドレスが分からないリクエストが共有するキー
+const UNKNOWN_CLIENT_KEY: &str = "unknown";
+
+/// レート制限の予算を分けるリクエストの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitCategory {
+    /// Likeの追加・取り消し
+    Like,
+    /// 保存・編集・削除など
+    Write,
+    /// 取得のためのクエリ
+    Read,
+}
+
+impl RateLimitCategory {
+    /// メソッドとパスからリクエストの種類を決定する．
+    pub fn of(method: &Method, path: &str) -> Self {
+        if path.contains("/increment_like/") || path.contains("/unlike/") {
+            RateLimitCategory::Like
+        } else if method == Method::GET || path.ends_with("/query") || path.ends_with("/liked") {
+            RateLimitCategory::Read
+        } else {
+            RateLimitCategory::Write
+        }
+    }
+}
+
+/// トークンバケット
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn full(budget: &RateLimitBudget, now: Instant) -> Self {
+        Self {
+            tokens: budget.burst as f64,
+            updated: now,
+        }
+    }
+    /// `now`までに回復したトークンを追加する．
+    fn refill(&mut self, budget: &RateLimitBudget, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * refill_per_sec(budget)).min(budget.burst as f64);
+        self.updated = now;
+    }
+}
+
+/// 一秒間に回復するトークンの数
+fn refill_per_sec(budget: &RateLimitBudget) -> f64 {
+    budget.per_minute as f64 / 60.0
+}
+
+/// クライアントごと・リクエストの種類ごとのトークンバケットを保持するレート制限．
+/// `Extension`としてルーターに追加し，`limit_rate`ミドルウェアで利用する．
+/// クライアントのIPアドレスを得るため，`into_make_service_with_connect_info`でサーブする必要がある．
+/// バケットは最大数を超えると最も長く使われていないものから破棄するため，リクエストごとの処理は定数時間で済む．
+#[derive(Debug)]
+pub struct RateLimiter {
+    like: RateLimitBudget,
+    write: RateLimitBudget,
+    read: RateLimitBudget,
+    /// サーバーの前段にある信頼するプロキシの数．0の場合は`X-Forwarded-For`を用いない
+    trusted_proxies: usize,
+    buckets: Mutex<LruCache<(RateLimitCategory, String), TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(like: RateLimitBudget, write: RateLimitBudget, read: RateLimitBudget) -> Self {
+        Self {
+            like,
+            write,
+            read,
+            trusted_proxies: 0,
+            buckets: Mutex::new(LruCache::new(
+                NonZeroUsize::new(MAX_BUCKETS).expect("MAX_BUCKETS must be non-zero"),
+            )),
+        }
+    }
+    /// 保持するバケットの最大数を設定する．
+    pub fn with_max_buckets(mut self, max_buckets: NonZeroUsize) -> Self {
+        self.buckets = Mutex::new(LruCache::new(max_buckets));
+        self
+    }
+    /// サーバーの前段にある信頼するプロキシの数を設定する．
+    /// 設定した場合のみ，プロキシが付与する`X-Forwarded-For`からクライアントのIPアドレスを得る．
+    pub fn with_trusted_proxies(mut self, trusted_proxies: usize) -> Self {
+        self.trusted_proxies = trusted_proxies;
+        self
+    }
+    /// `CONFIG`の予算を用いたレート制限
+    pub fn from_config() -> Self {
+        Self::new(
+            CONFIG.like_rate_limit,
+            CONFIG.write_rate_limit,
+            CONFIG.read_rate_limit,
+        )
+    }
+    fn budget(&self, category: RateLimitCategory) -> &RateLimitBudget {
+        match category {
+            RateLimitCategory::Like => &self.like,
+            RateLimitCategory::Write => &self.write,
+            RateLimitCategory::Read => &self.read,
+        }
+    }
+    /// `key`のクライアントのトークンを一つ消費する．トークンが無い場合は`RateLimited`を返す．
+    pub fn check(&self, category: RateLimitCategory, key: &str) -> Result<(), AppCommonError> {
+        self.check_at(category, key, Instant::now())
+    }
+    fn check_at(
+        &self,
+        category: RateLimitCategory,
+        key: &str,
+        now: Instant,
+    ) -> Result<(), AppCommonError> {
+        let budget = *self.budget(category);
+        let mut buckets = self.buckets.lock().unwrap();
+
+        let bucket = buckets.get_or_insert_mut((category, key.to_string()), || {
+            TokenBucket::full(&budget, now)
+        });
+        bucket.refill(&budget, now);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let rate = refill_per_sec(&budget);
+            let retry_after = if rate > 0.0 {
+                ((1.0 - bucket.tokens) / rate).ceil().max(1.0) as u64
+            } else {
+                u64::MAX
+            };
+            Err(AppCommonError::RateLimited { retry_after })
+        }
+    }
+}
+
+/// レート制限のキー．有効なセッションがある場合はセッションのユーザー，無い場合はクライアントのIPアドレスを用いる．
+/// クライアントのIPアドレスが分からない場合はNone．
+fn rate_limit_key<B>(request: &Request<B>, trusted_proxies: usize) -> Option<String> {
+    let claims = request
+        .extensions()
+        .get::<Arc<AuthConfig>>()
+        .and_then(|auth_config| auth_config.verify_headers(request.headers()).ok());
+    if let Some(claims) = claims {
+        return match claims.user_id {
+            Some(user_id) => Some(format!("user:{user_id}")),
+            None => Some("admin".to_string()),
+        };
+    }
+
+    client_ip(request, trusted_proxies).map(|ip| format!("ip:{ip}"))
+}
+
+/// クライアントのIPアドレス．信頼するプロキシがある場合は`X-Forwarded-For`の末尾から`trusted_proxies`番目，
+/// それ以外の場合や`X-Forwarded-For`が不正な場合は接続元のアドレスとする．
+fn client_ip<B>(request: &Request<B>, trusted_proxies: usize) -> Option<IpAddr> {
+    if trusted_proxies > 0 {
+        let forwarded_ips = request
+            .headers()
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|ip| ip.trim().parse::<IpAddr>())
+            .collect::<Result<Vec<_>, _>>();
+
+        if let Ok(forwarded_ips) = forwarded_ips {
+            if let Some(ip) = forwarded_ips.iter().rev().nth(trusted_proxies - 1) {
+                return Some(*ip);
+            }
+        }
+    }
+
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+}
+
+/// `Extension`として追加された`RateLimiter`でリクエストを制限するミドルウェア．
+/// 制限を超えた場合は`RateLimited`(429)を返す．
+pub async fn limit_rate<B>(request: Request<B>, next: Next<B>) -> Response {
+    let rate_limiter = match request.extensions().get::<Arc<RateLimiter>>() {
+        Some(rate_limiter) => Arc::clone(rate_limiter),
+        None => {
+            tracing::error!("RateLimiter is not added to the router as an extension.");
+            return next.run(request).await;
+        }
+    };
+
+    let category = RateLimitCategory::of(request.method(), request.uri().path());
+    // クライアントのIPアドレスが分からない場合は制限を逃れないよう共有のキーで制限する
+    let key = match rate_limit_key(&request, rate_limiter.trusted_proxies) {
+        Some(key) => key,
+        None => {
+            tracing::error!(
+                "Cannot get the client address. Serve with into_make_service_with_connect_info."
+            );
+            UNKNOWN_CLIENT_KEY.to_string()
+        }
+    };
+
+    match rate_limiter.check(category, &key) {
+        Ok(_) => next.run(request).await,
+        Err(e) => {
+            tracing::info!("{e} (key: {key}, category: {category:?})");
+            e.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{client_ip, limit_rate, RateLimitCategory, RateLimiter};
+    use crate::auth::test_utils::{auth_config, session_cookie_of};
+    use common::AppCommonError;
+    use config::RateLimitBudget;
+    use domain::user::Role;
+
+    use axum::{
+        body::Body,
+        extract::ConnectInfo,
+        http::{header, Method, Request, StatusCode},
+        middleware,
+        routing::{get, patch},
+        Extension, Router,
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use std::net::{IpAddr, SocketAddr};
+    use std::num::NonZeroUsize;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tower::{Service, ServiceExt};
+
+    #[fixture]
+    fn rate_limiter() -> RateLimiter {
+        RateLimiter::new(
+            RateLimitBudget::new(2, 60),
+            RateLimitBudget::new(1, 6),
+            RateLimitBudget::new(5, 60),
+        )
+    }
+
+    #[rstest]
+    #[case(
+        Method::PATCH,
+        "/movie_clip/increment_like/id",
+        RateLimitCategory::Like
+    )]
+    #[case(Method::PATCH, "/original/unlike/id", RateLimitCategory::Like)]
+    #[case(Method::GET, "/movie_clip/query", RateLimitCategory::Read)]
+    #[case(Method::POST, "/kirinuki/query", RateLimitCategory::Read)]
+    #[case(Method::POST, "/movie_clip/liked", RateLimitCategory::Read)]
+    #[case(Method::PUT, "/movie_clip", RateLimitCategory::Write)]
+    #[case(Method::DELETE, "/original/id", RateLimitCategory::Write)]
+    #[case(Method::POST, "/auth/login", RateLimitCategory::Write)]
+    #[case(Method::POST, "/auth/client", RateLimitCategory::Write)]
+    fn test_rate_limit_category(
+        #[case] method: Method,
+        #[case] path: &str,
+        #[case] category: RateLimitCategory,
+    ) {
+        assert_eq!(RateLimitCategory::of(&method, path), category);
+    }
+
+    #[rstest]
+    fn test_token_bucket(rate_limiter: RateLimiter) {
+        let now = Instant::now();
+
+        // バケットの容量まで受け付ける
+        for _ in 0..2 {
+            assert!(rate_limiter
+                .check_at(RateLimitCategory::Like, "ip:1", now)
+                .is_ok());
+        }
+        let res = rate_limiter.check_at(RateLimitCategory::Like, "ip:1", now);
+        assert!(matches!(
+            res,
+            Err(AppCommonError::RateLimited { retry_after: 1 })
+        ));
+
+        // キー・種類ごとに予算は独立
+        assert!(rate_limiter
+            .check_at(RateLimitCategory::Like, "ip:2", now)
+            .is_ok());
+        assert!(rate_limiter
+            .check_at(RateLimitCategory::Read, "ip:1", now)
+            .is_ok());
+
+        // 一秒で一つ回復する
+        let later = now + Duration::from_secs(1);
+        assert!(rate_limiter
+            .check_at(RateLimitCategory::Like, "ip:1", later)
+            .is_ok());
+        assert!(rate_limiter
+            .check_at(RateLimitCategory::Like, "ip:1", later)
+            .is_err());
+
+        // 十秒で一つ回復する
+        assert!(rate_limiter
+            .check_at(RateLimitCategory::Write, "ip:1", now)
+            .is_ok());
+        let res = rate_limiter.check_at(RateLimitCategory::Write, "ip:1", now);
+        assert!(matches!(
+            res,
+            Err(AppCommonError::RateLimited { retry_after: 10 })
+        ));
+    }
+
+    #[rstest]
+    fn test_evict_least_recently_used_bucket(rate_limiter: RateLimiter) {
+        let rate_limiter = rate_limiter.with_max_buckets(NonZeroUsize::new(2).unwrap());
+        let now = Instant::now();
+
+        for key in ["ip:1", "ip:2"] {
+            for _ in 0..2 {
+                assert!(rate_limiter
+                    .check_at(RateLimitCategory::Like, key, now)
+                    .is_ok());
+            }
+        }
+        // 最大数を超えると最も長く使われていないip:1のバケットを破棄する
+        assert!(rate_limiter
+            .check_at(RateLimitCategory::Like, "ip:3", now)
+            .is_ok());
+        assert!(rate_limiter
+            .check_at(RateLimitCategory::Like, "ip:2", now)
+            .is_err());
+        assert!(rate_limiter
+            .check_at(RateLimitCategory::Like, "ip:1", now)
+            .is_ok());
+    }
+
+    #[rstest]
+    #[case(0, None, Some("192.0.2.9"))]
+    #[case(0, Some("192.0.2.1"), Some("192.0.2.9"))]
+    #[case(0, Some("192.0.2.1"), None)]
+    #[case(1, Some("10.0.0.1, 192.0.2.1"), Some("192.0.2.1"))]
+    #[case(2, Some("10.0.0.1, 192.0.2.1, 10.0.0.2"), Some("192.0.2.1"))]
+    #[case(2, Some("192.0.2.1"), Some("192.0.2.9"))]
+    #[case(1, Some("not an ip"), Some("192.0.2.9"))]
+    #[case(1, None, Some("192.0.2.9"))]
+    fn test_client_ip(
+        #[case] trusted_proxies: usize,
+        #[case] forwarded_for: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        // 接続元のアドレスは192.0.2.9．ただし期待値がNoneの場合は接続元のアドレスが無い
+        let builder = Request::builder();
+        let builder = match forwarded_for {
+            Some(forwarded_for) => builder.header("x-forwarded-for", forwarded_for),
+            None => builder,
+        };
+        let builder = match expected {
+            Some(_) => builder.extension(ConnectInfo(SocketAddr::from(([192, 0, 2, 9], 443)))),
+            None => builder,
+        };
+        let request = builder.body(()).unwrap();
+
+        assert_eq!(
+            client_ip(&request, trusted_proxies),
+            expected.map(|ip| ip.parse::<IpAddr>().unwrap())
+        );
+    }
+
+    /// `trusted_proxies`個のプロキシを信頼するルーター
+    fn router_with_trusted_proxies(rate_limiter: RateLimiter, trusted_proxies: usize) -> Router {
+        Router::new()
+            .route("/movie_clip/increment_like/:id", patch(|| async {}))
+            .route("/movie_clip", get(|| async {}))
+            .layer(middleware::from_fn(limit_rate))
+            .layer(Extension(Arc::new(
+                rate_limiter.with_trusted_proxies(trusted_proxies),
+            )))
+            .layer(Extension(auth_config()))
+    }
+
+    #[fixture]
+    fn router(rate_limiter: RateLimiter) -> Router {
+        router_with_trusted_proxies(rate_limiter, 1)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_limit_rate(mut router: Router) {
+        let like_request = |ip: &str| {
+            Request::builder()
+                .method(Method::PATCH)
+                .uri("/movie_clip/increment_like/id")
+                .header("x-forwarded-for", format!("10.0.0.1, {ip}"))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        for _ in 0..2 {
+            let response = router
+                .ready()
+                .await
+                .unwrap()
+                .call(like_request("192.0.2.1"))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(like_request("192.0.2.1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "1");
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let err: AppCommonError = serde_json::from_slice(&body).unwrap();
+        assert!(matches!(
+            err,
+            AppCommonError::RateLimited { retry_after: 1 }
+        ));
+
+        // 別のIPアドレスは制限されない
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(like_request("192.0.2.2"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // セッションがある場合はセッションごとに制限する
+        let session_request = || {
+            Request::builder()
+                .method(Method::PATCH)
+                .uri("/movie_clip/increment_like/id")
+                .header("x-forwarded-for", "192.0.2.1")
+                .header(header::COOKIE, session_cookie_of(Role::Admin))
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(session_request())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_limit_rate_without_trusted_proxies(rate_limiter: RateLimiter) {
+        let mut router = router_with_trusted_proxies(rate_limiter, 0);
+        let like_request = |forwarded_for: &str| {
+            Request::builder()
+                .method(Method::PATCH)
+                .uri("/movie_clip/increment_like/id")
+                .header("x-forwarded-for", forwarded_for)
+                .extension(ConnectInfo(SocketAddr::from(([192, 0, 2, 9], 443))))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        // 信頼するプロキシが無い場合はX-Forwarded-Forを変えても接続元のアドレスで制限する
+        for ip in ["192.0.2.1", "192.0.2.2"] {
+            let response = router
+                .ready()
+                .await
+                .unwrap()
+                .call(like_request(ip))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(like_request("192.0.2.3"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_limit_rate_without_client_address(mut router: Router) {
+        let like_request = || {
+            Request::builder()
+                .method(Method::PATCH)
+                .uri("/movie_clip/increment_like/id")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        // クライアントのアドレスが分からない場合は共有のキーで制限する
+        for _ in 0..2 {
+            let response = router
+                .ready()
+                .await
+                .unwrap()
+                .call(like_request())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = router
+            .ready()
+            .await
+            .unwrap()
+            .call(like_request())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+}
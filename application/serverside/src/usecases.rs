@@ -1,16 +1,56 @@
+mod channel_usecases_outer;
+mod clip_series_usecases_outer;
 mod episode_usecases_outer;
 mod movie_clip_usecases_outer;
+mod quiz_usecases_outer;
+mod search_usecases_outer;
+mod user_usecases_outer;
 mod video_usecases_outer;
 
+#[cfg(not(test))]
+pub use channel_usecases_outer::channel_usecases;
+
+#[cfg(not(test))]
+pub use clip_series_usecases_outer::clip_series_usecases;
+
+#[cfg(not(test))]
 pub use episode_usecases_outer::episode_usecases;
+
+#[cfg(not(test))]
 pub use movie_clip_usecases_outer::movie_clip_usecases;
+
+#[cfg(not(test))]
+pub use quiz_usecases_outer::quiz_usecases;
+
+#[cfg(not(test))]
+pub use search_usecases_outer::search_usecases;
+
+#[cfg(not(test))]
+pub use user_usecases_outer::user_usecases;
+
+#[cfg(not(test))]
 pub use video_usecases_outer::video_usecases;
 
+#[cfg(test)]
+pub use channel_usecases_outer::mock_channel_usecases;
+
+#[cfg(test)]
+pub use clip_series_usecases_outer::mock_clip_series_usecases;
+
 #[cfg(test)]
 pub use episode_usecases_outer::mock_episode_usecases;
 
 #[cfg(test)]
 pub use movie_clip_usecases_outer::mock_movie_clip_usecases;
 
+#[cfg(test)]
+pub use quiz_usecases_outer::mock_quiz_usecases;
+
+#[cfg(test)]
+pub use search_usecases_outer::mock_search_usecases;
+
+#[cfg(test)]
+pub use user_usecases_outer::mock_user_usecases;
+
 #[cfg(test)]
 pub use video_usecases_outer::mock_video_usecases;
@@ -0,0 +1,179 @@
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub mod channel_usecases {
+    use crate::commands::channel_commands;
+    use common::AppCommonError;
+    use domain::channel::{Channel, ChannelSummary};
+    use domain::ChannelRepository;
+    use infrastructure::InfraError;
+    use std::sync::Arc;
+
+    /// チャンネルを保存する．同じ名前のチャンネルが存在する場合は`ConflictError`を返す．
+    pub(crate) async fn save_channel<T>(
+        repo: Arc<T>,
+        cmd: channel_commands::SaveChannelCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: ChannelRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.save(cmd.channel).await?)
+    }
+
+    pub(crate) async fn edit_channel<T>(
+        repo: Arc<T>,
+        cmd: channel_commands::EditChannelCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: ChannelRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.edit(cmd.channel).await?)
+    }
+
+    pub(crate) async fn all_channels<T>(
+        repo: Arc<T>,
+        _cmd: channel_commands::AllChannelsCommand,
+    ) -> Result<Vec<Channel>, AppCommonError>
+    where
+        T: ChannelRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.all().await?)
+    }
+
+    pub(crate) async fn find_channel<T>(
+        repo: Arc<T>,
+        cmd: channel_commands::FindChannelCommand,
+    ) -> Result<Channel, AppCommonError>
+    where
+        T: ChannelRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.find_by_id(cmd.id).await?)
+    }
+
+    /// 全てのチャンネルを動画の数とともに名前の順で取得する．
+    pub(crate) async fn channel_summaries<T>(
+        repo: Arc<T>,
+        _cmd: channel_commands::ChannelSummariesCommand,
+    ) -> Result<Vec<ChannelSummary>, AppCommonError>
+    where
+        T: ChannelRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.all_with_video_count().await?)
+    }
+
+    /// チャンネルを削除する．動画が紐付いている場合は`ConflictError`を返す．
+    pub(crate) async fn remove_channel<T>(
+        repo: Arc<T>,
+        cmd: channel_commands::RemoveChannelCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: ChannelRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.remove(cmd.id).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::channel_usecases;
+    use crate::commands::channel_commands;
+    use common::AppCommonError;
+    use domain::channel::{Channel, ChannelId, ChannelSummary};
+    use fake::{Fake, Faker};
+    use infrastructure::channel_repository_impl::MockChannelRepository;
+    use infrastructure::InfraError;
+    use mockall::predicate;
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_save_and_edit_channel_usecase() {
+        let channel = Faker.fake::<Channel>();
+
+        let mut mock_repo = MockChannelRepository::new();
+        mock_repo
+            .expect_save()
+            .with(predicate::eq(channel.clone()))
+            .times(1)
+            .return_const(Err(InfraError::ConflictError));
+        mock_repo
+            .expect_edit()
+            .with(predicate::eq(channel.clone()))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = channel_commands::SaveChannelCommand::new(channel.clone());
+        let res_err = channel_usecases::save_channel(Arc::clone(&mock_repo), cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
+
+        let cmd = channel_commands::EditChannelCommand::new(channel);
+        let res_err = channel_usecases::edit_channel(mock_repo, cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_all_and_find_channel_usecase() {
+        let channels = (0..10).map(|_| Faker.fake::<Channel>()).collect::<Vec<_>>();
+
+        let mut mock_repo = MockChannelRepository::new();
+        mock_repo
+            .expect_all()
+            .times(1)
+            .return_const(Ok(channels.clone()));
+        mock_repo
+            .expect_find_by_id()
+            .with(predicate::eq(channels[0].id()))
+            .times(1)
+            .return_const(Ok(channels[0].clone()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = channel_commands::AllChannelsCommand;
+        let res_channels = channel_usecases::all_channels(Arc::clone(&mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res_channels, channels);
+
+        let cmd = channel_commands::FindChannelCommand::new(channels[0].id());
+        let res_channel = channel_usecases::find_channel(mock_repo, cmd)
+            .await
+            .unwrap();
+        assert_eq!(res_channel, channels[0]);
+    }
+
+    #[tokio::test]
+    async fn test_channel_summaries_usecase() {
+        let summaries = (0..10)
+            .map(|i| ChannelSummary::new(Faker.fake(), i))
+            .collect::<Vec<_>>();
+
+        let mut mock_repo = MockChannelRepository::new();
+        mock_repo
+            .expect_all_with_video_count()
+            .times(1)
+            .return_const(Ok(summaries.clone()));
+
+        let cmd = channel_commands::ChannelSummariesCommand;
+        let res_summaries = channel_usecases::channel_summaries(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res_summaries, summaries);
+    }
+
+    #[tokio::test]
+    async fn test_remove_channel_usecase() {
+        let channel_id = ChannelId::generate();
+
+        // 動画が紐付いている場合
+        let mut mock_repo_err = MockChannelRepository::new();
+        mock_repo_err
+            .expect_remove()
+            .with(predicate::eq(channel_id))
+            .return_const(Err(InfraError::ConflictError));
+
+        let cmd = channel_commands::RemoveChannelCommand::new(channel_id);
+        let res_err = channel_usecases::remove_channel(Arc::new(mock_repo_err), cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
+    }
+}
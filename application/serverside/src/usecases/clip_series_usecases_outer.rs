@@ -0,0 +1,420 @@
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub mod clip_series_usecases {
+    use crate::commands::clip_series_commands;
+    use common::{AppCommonError, NewClipSeriesInfo, Page};
+    use domain::clip_series::{ClipSeries, ClipSeriesId};
+    use domain::{ClipSeriesRepository, Date};
+    use infrastructure::InfraError;
+    use std::sync::Arc;
+
+    /// id, like, create_dateをサーバー側で決定してシリーズを保存し，保存したシリーズを返す．
+    pub(crate) async fn save_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::SaveClipSeriesCommand,
+    ) -> Result<ClipSeries, AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        let NewClipSeriesInfo {
+            title,
+            description,
+            clips,
+        } = cmd.clip_series_info;
+        let clip_series = ClipSeries::new(title, description, clips, Date::today())?;
+        repo.save(clip_series.clone()).await?;
+        Ok(clip_series)
+    }
+
+    pub(crate) async fn edit_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::EditClipSeriesCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        repo.edit(cmd.clip_series).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn increment_like_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::IncrementLikeClipSeriesCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        repo.increment_like(cmd.id, cmd.client).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn unlike_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::UnlikeClipSeriesCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        repo.unlike(cmd.id, cmd.client).await?;
+        Ok(())
+    }
+
+    /// `ids`のうちクライアントがLikeしているClipSeriesのidを取得する．
+    pub(crate) async fn liked_clip_series_ids<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::LikedClipSeriesIdsCommand,
+    ) -> Result<Vec<ClipSeriesId>, AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.liked_ids(&cmd.ids, cmd.client).await?)
+    }
+
+    pub(crate) async fn all_clip_series<T>(
+        repo: Arc<T>,
+        _cmd: clip_series_commands::AllClipSeriesCommand,
+    ) -> Result<Vec<ClipSeries>, AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.all().await?)
+    }
+
+    pub(crate) async fn find_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::FindClipSeriesCommand,
+    ) -> Result<ClipSeries, AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.find_by_id(cmd.id).await?)
+    }
+
+    pub(crate) async fn order_by_like_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::OrderByLikeClipSeriesCommand,
+    ) -> Result<Page<ClipSeries>, AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        let clip_series = repo.order_by_like(cmd.length.lookahead()).await?;
+        Ok(Page::from_lookahead(clip_series, cmd.length))
+    }
+
+    pub(crate) async fn order_by_like_later_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::OrderByLikeLaterClipSeriesCommand,
+    ) -> Result<Page<ClipSeries>, AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        let clip_series = repo
+            .order_by_like_later(&cmd.cursor, cmd.length.lookahead())
+            .await?;
+        Ok(Page::from_lookahead(clip_series, cmd.length))
+    }
+
+    pub(crate) async fn order_by_create_date_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::OrderByCreateDateClipSeriesCommand,
+    ) -> Result<Page<ClipSeries>, AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        let clip_series = repo.order_by_create_date(cmd.length.lookahead()).await?;
+        Ok(Page::from_lookahead(clip_series, cmd.length))
+    }
+
+    pub(crate) async fn order_by_create_date_later_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::OrderByCreateDateLaterClipSeriesCommand,
+    ) -> Result<Page<ClipSeries>, AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        let clip_series = repo
+            .order_by_create_date_later(&cmd.cursor, cmd.length.lookahead())
+            .await?;
+        Ok(Page::from_lookahead(clip_series, cmd.length))
+    }
+
+    pub(crate) async fn remove_clip_series<T>(
+        repo: Arc<T>,
+        cmd: clip_series_commands::RemoveClipSeriesCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: ClipSeriesRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.remove(cmd.id).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::clip_series_usecases;
+    use crate::commands::clip_series_commands;
+    use common::{AppCommonError, NewClipSeriesInfo};
+    use domain::{
+        client::ClientId,
+        clip_series::{ClipSeries, ClipSeriesId},
+        Date, PageSize,
+    };
+    use infrastructure::clip_series_repository_impl::MockClipSeriesRepository;
+    use infrastructure::InfraError;
+
+    use fake::{Fake, Faker};
+    use mockall::predicate;
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use std::sync::Arc;
+
+    #[fixture]
+    fn clip_series_vec() -> Vec<ClipSeries> {
+        (0..100)
+            .map(|_| Faker.fake::<ClipSeries>())
+            .collect::<Vec<_>>()
+    }
+
+    #[tokio::test]
+    async fn test_save_clip_series_usecase() {
+        let clip_series = Faker.fake::<ClipSeries>();
+        let clip_series_info = NewClipSeriesInfo::from(clip_series.clone());
+
+        {
+            // id, like, create_dateはサーバーで決定される
+            let mut mock_repo_ok = MockClipSeriesRepository::new();
+            mock_repo_ok
+                .expect_save()
+                .withf({
+                    let clip_series = clip_series.clone();
+                    move |saved| {
+                        saved.id() != clip_series.id()
+                            && saved.like() == 0
+                            && saved.create_date() == Date::today()
+                            && saved.title() == clip_series.title()
+                            && saved.clips() == clip_series.clips()
+                    }
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd = clip_series_commands::SaveClipSeriesCommand::new(clip_series_info.clone());
+            let saved = clip_series_usecases::save_clip_series(Arc::new(mock_repo_ok), cmd)
+                .await
+                .unwrap();
+            assert_eq!(NewClipSeriesInfo::from(saved), clip_series_info);
+        }
+        {
+            // クリップを含まないシリーズは保存しない
+            let mut mock_repo = MockClipSeriesRepository::new();
+            mock_repo.expect_save().times(0);
+
+            let cmd = clip_series_commands::SaveClipSeriesCommand::new(NewClipSeriesInfo::new(
+                "title",
+                "description",
+                vec![],
+            ));
+            let res_err = clip_series_usecases::save_clip_series(Arc::new(mock_repo), cmd).await;
+            assert!(matches!(res_err, Err(AppCommonError::DomainError(_))));
+        }
+        {
+            let mut mock_repo_err = MockClipSeriesRepository::new();
+            mock_repo_err
+                .expect_save()
+                .times(1)
+                .return_const(Err(InfraError::ConflictError));
+
+            let cmd = clip_series_commands::SaveClipSeriesCommand::new(clip_series_info);
+            let res_err =
+                clip_series_usecases::save_clip_series(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_edit_clip_series_usecase() {
+        let clip_series = Faker.fake::<ClipSeries>();
+
+        let mut mock_repo_err = MockClipSeriesRepository::new();
+        mock_repo_err
+            .expect_edit()
+            .with(predicate::eq(clip_series.clone()))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+
+        let cmd = clip_series_commands::EditClipSeriesCommand::new(clip_series);
+        let res_err = clip_series_usecases::edit_clip_series(Arc::new(mock_repo_err), cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_increment_like_and_unlike_clip_series_usecase() {
+        let id = ClipSeriesId::generate();
+        let client = ClientId::generate();
+
+        let mut mock_repo = MockClipSeriesRepository::new();
+        mock_repo
+            .expect_increment_like()
+            .with(predicate::eq(id), predicate::eq(client))
+            .times(1)
+            .return_const(Ok(()));
+        mock_repo
+            .expect_unlike()
+            .with(predicate::eq(id), predicate::eq(client))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = clip_series_commands::IncrementLikeClipSeriesCommand::new(id, client);
+        let res_ok =
+            clip_series_usecases::increment_like_clip_series(Arc::clone(&mock_repo), cmd).await;
+        assert!(res_ok.is_ok());
+
+        let cmd = clip_series_commands::UnlikeClipSeriesCommand::new(id, client);
+        let res_err = clip_series_usecases::unlike_clip_series(mock_repo, cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_liked_clip_series_ids_usecase() {
+        let ids = (0..10)
+            .map(|_| ClipSeriesId::generate())
+            .collect::<Vec<_>>();
+        let liked_ids = ids[..5].to_vec();
+        let client = ClientId::generate();
+
+        let mut mock_repo = MockClipSeriesRepository::new();
+        mock_repo
+            .expect_liked_ids()
+            .withf({
+                let ids = ids.clone();
+                move |arg_ids, arg_client| arg_ids == ids && *arg_client == client
+            })
+            .times(1)
+            .return_const(Ok(liked_ids.clone()));
+
+        let cmd = clip_series_commands::LikedClipSeriesIdsCommand::new(ids, client);
+        let res = clip_series_usecases::liked_clip_series_ids(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, liked_ids);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_all_and_find_clip_series_usecase(clip_series_vec: Vec<ClipSeries>) {
+        let found = clip_series_vec[0].clone();
+
+        let mut mock_repo = MockClipSeriesRepository::new();
+        mock_repo
+            .expect_all()
+            .times(1)
+            .return_const(Ok(clip_series_vec.clone()));
+        mock_repo
+            .expect_find_by_id()
+            .with(predicate::eq(found.id()))
+            .times(1)
+            .return_const(Ok(found.clone()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = clip_series_commands::AllClipSeriesCommand;
+        let res_vec = clip_series_usecases::all_clip_series(Arc::clone(&mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res_vec, clip_series_vec);
+
+        let cmd = clip_series_commands::FindClipSeriesCommand::new(found.id());
+        let res = clip_series_usecases::find_clip_series(mock_repo, cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, found);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_order_by_like_clip_series_usecase(clip_series_vec: Vec<ClipSeries>) {
+        let length = PageSize::new(50).unwrap();
+        let cursor = Faker.fake::<ClipSeries>().like_cursor();
+
+        let mut mock_repo = MockClipSeriesRepository::new();
+        mock_repo
+            .expect_order_by_like()
+            .with(predicate::eq(length.lookahead()))
+            .return_const(Ok(clip_series_vec.clone()));
+        mock_repo
+            .expect_order_by_like_later()
+            .withf(move |arg_cursor, arg_length| {
+                *arg_cursor == cursor && *arg_length == length.lookahead()
+            })
+            .return_const(Ok(clip_series_vec[..10].to_vec()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = clip_series_commands::OrderByLikeClipSeriesCommand::new(length);
+        let page = clip_series_usecases::order_by_like_clip_series(Arc::clone(&mock_repo), cmd)
+            .await
+            .unwrap();
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, clip_series_vec[..length.get()]);
+        assert!(page.has_more);
+
+        let cmd = clip_series_commands::OrderByLikeLaterClipSeriesCommand::new(cursor, length);
+        let page = clip_series_usecases::order_by_like_later_clip_series(mock_repo, cmd)
+            .await
+            .unwrap();
+        assert_eq!(page.items, clip_series_vec[..10]);
+        assert!(!page.has_more);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_order_by_create_date_clip_series_usecase(clip_series_vec: Vec<ClipSeries>) {
+        let length = PageSize::new(50).unwrap();
+        let cursor = Faker.fake::<ClipSeries>().create_date_cursor();
+
+        let mut mock_repo = MockClipSeriesRepository::new();
+        mock_repo
+            .expect_order_by_create_date()
+            .with(predicate::eq(length.lookahead()))
+            .return_const(Ok(clip_series_vec.clone()));
+        mock_repo
+            .expect_order_by_create_date_later()
+            .withf(move |arg_cursor, arg_length| {
+                *arg_cursor == cursor && *arg_length == length.lookahead()
+            })
+            .return_const(Ok(clip_series_vec.clone()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = clip_series_commands::OrderByCreateDateClipSeriesCommand::new(length);
+        let page =
+            clip_series_usecases::order_by_create_date_clip_series(Arc::clone(&mock_repo), cmd)
+                .await
+                .unwrap();
+        assert_eq!(page.items, clip_series_vec[..length.get()]);
+        assert!(page.has_more);
+
+        let cmd =
+            clip_series_commands::OrderByCreateDateLaterClipSeriesCommand::new(cursor, length);
+        let page = clip_series_usecases::order_by_create_date_later_clip_series(mock_repo, cmd)
+            .await
+            .unwrap();
+        assert_eq!(page.items, clip_series_vec[..length.get()]);
+        assert!(page.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_remove_clip_series_usecase() {
+        let id = ClipSeriesId::generate();
+
+        let mut mock_repo_err = MockClipSeriesRepository::new();
+        mock_repo_err
+            .expect_remove()
+            .with(predicate::eq(id))
+            .return_const(Err(InfraError::NoRecordError));
+
+        let cmd = clip_series_commands::RemoveClipSeriesCommand::new(id);
+        let res_err = clip_series_usecases::remove_clip_series(Arc::new(mock_repo_err), cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+}
@@ -5,7 +5,8 @@ use mockall::automock;
 pub mod episode_usecases {
     use crate::commands::episode_commands;
     use common::AppCommonError;
-    use domain::{episode::Episode, EpisodeRepository};
+    use domain::revision::{FieldChange, Operation, Revision};
+    use domain::{episode::Episode, EpisodeRepository, Tag};
     use infrastructure::InfraError;
     use std::sync::Arc;
 
@@ -16,7 +17,7 @@ pub mod episode_usecases {
     where
         T: EpisodeRepository<Error = InfraError> + 'static,
     {
-        repo.save(cmd.episode).await?;
+        repo.save(cmd.episode, cmd.actor).await?;
         Ok(())
     }
 
@@ -27,7 +28,7 @@ pub mod episode_usecases {
     where
         T: EpisodeRepository<Error = InfraError> + 'static,
     {
-        repo.edit(cmd.episode).await?;
+        repo.edit(cmd.episode, cmd.actor).await?;
         Ok(())
     }
 
@@ -48,7 +49,55 @@ pub mod episode_usecases {
     where
         T: EpisodeRepository<Error = InfraError> + 'static,
     {
-        Ok(repo.order_by_date_range(cmd.start, cmd.end).await?)
+        let episodes = match cmd.tags.is_empty() {
+            true => repo.order_by_date_range(cmd.start, cmd.end).await?,
+            false => {
+                repo.order_by_date_range_with_tags(&cmd.tags, cmd.start, cmd.end)
+                    .await?
+            }
+        };
+        Ok(episodes)
+    }
+
+    /// エピソードに付けられている全てのタグを取得する．
+    pub(crate) async fn all_episode_tags<T>(
+        repo: Arc<T>,
+        _cmd: episode_commands::AllEpisodeTagsCommand,
+    ) -> Result<Vec<Tag>, AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.all_tags().await?)
+    }
+
+    pub(crate) async fn episode_tags<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::EpisodeTagsCommand,
+    ) -> Result<Vec<Tag>, AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.tags(cmd.id).await?)
+    }
+
+    pub(crate) async fn add_episode_tag<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::AddEpisodeTagCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.add_tag(cmd.id, cmd.tag).await?)
+    }
+
+    pub(crate) async fn remove_episode_tag<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::RemoveEpisodeTagCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.remove_tag(cmd.id, cmd.tag).await?)
     }
 
     pub(crate) async fn remove_episode<T>(
@@ -58,7 +107,109 @@ pub mod episode_usecases {
     where
         T: EpisodeRepository<Error = InfraError> + 'static,
     {
-        Ok(repo.remove(cmd.id).await?)
+        Ok(repo.remove(cmd.id, cmd.actor).await?)
+    }
+
+    /// ゴミ箱のエピソードを削除した日時の新しい順に取得する．
+    pub(crate) async fn trashed_episodes<T>(
+        repo: Arc<T>,
+        _cmd: episode_commands::TrashedEpisodesCommand,
+    ) -> Result<Vec<Episode>, AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.trashed().await?)
+    }
+
+    /// ゴミ箱のエピソードを戻す．
+    pub(crate) async fn restore_trashed_episode<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::RestoreTrashedEpisodeCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.restore(cmd.id, cmd.actor).await?)
+    }
+
+    /// ゴミ箱のエピソードを完全に削除する．
+    pub(crate) async fn purge_episode<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::PurgeEpisodeCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.purge(cmd.id, cmd.actor).await?)
+    }
+
+    /// エピソードの編集履歴を古い順に取得する．
+    pub(crate) async fn episode_revisions<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::EpisodeRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.revisions(cmd.id).await?)
+    }
+
+    /// エピソードの編集履歴を新しい順に取得する．
+    pub(crate) async fn latest_episode_revisions<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::LatestEpisodeRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.latest_revisions(cmd.length.get()).await?)
+    }
+
+    /// 二つの編集履歴の間の変更を取得する．
+    pub(crate) async fn episode_revision_diff<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::EpisodeRevisionDiffCommand,
+    ) -> Result<Vec<FieldChange>, AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        let from = repo.revision(cmd.from).await?;
+        let to = repo.revision(cmd.to).await?;
+        Ok(from.diff(&to)?)
+    }
+
+    /// 編集履歴の時点の内容に戻し，戻したエピソードを返す．ゴミ箱にある場合は戻してから編集し，完全に削除されている場合は保存し直す．
+    pub(crate) async fn restore_episode_revision<T>(
+        repo: Arc<T>,
+        cmd: episode_commands::RestoreEpisodeRevisionCommand,
+    ) -> Result<Episode, AppCommonError>
+    where
+        T: EpisodeRepository<Error = InfraError> + 'static,
+    {
+        let restored = repo.revision(cmd.id).await?.snapshot_as::<Episode>()?;
+        let latest = repo
+            .revisions(restored.id())
+            .await?
+            .pop()
+            .ok_or(AppCommonError::NoRecordError)?;
+
+        match latest.operation() {
+            Operation::Purge => {
+                repo.save(restored.clone(), cmd.actor).await?;
+                Ok(restored)
+            }
+            Operation::Save | Operation::Edit | Operation::Remove | Operation::Restore => {
+                if latest.operation() == Operation::Remove {
+                    repo.restore(restored.id(), cmd.actor).await?;
+                }
+                // 現在の版数に対する編集として戻す
+                let mut episode = latest.snapshot_as::<Episode>()?;
+                episode.assign(restored);
+                repo.edit(episode.clone(), cmd.actor).await?;
+                episode.increment_version();
+                Ok(episode)
+            }
+        }
     }
 }
 
@@ -69,7 +220,8 @@ mod test {
     use common::AppCommonError;
     use domain::{
         episode::{Episode, EpisodeId},
-        Date,
+        revision::{Actor, EntityKind, Operation, Revision, RevisionId},
+        Date, PageSize, Tag,
     };
     use fake::{Fake, Faker};
     use infrastructure::episode_repository_impl::MockEpisodeRepository;
@@ -93,23 +245,23 @@ mod test {
             let mut mock_repo_ok = MockEpisodeRepository::new();
             mock_repo_ok
                 .expect_save()
-                .with(predicate::eq(episode.clone()))
+                .with(predicate::eq(episode.clone()), predicate::eq(Actor::Admin))
                 .times(1)
                 .return_const(Ok(()));
 
-            let cmd = episode_commands::SaveEpisodeCommand::new(episode.clone());
+            let cmd = episode_commands::SaveEpisodeCommand::new(episode.clone(), Actor::Admin);
             let res_ok = episode_usecases::save_episode(Arc::new(mock_repo_ok), cmd).await;
-            assert!(matches!(res_ok, Ok(_)));
+            assert!(res_ok.is_ok());
         }
         {
             let mut mock_repo_err = MockEpisodeRepository::new();
             mock_repo_err
                 .expect_save()
-                .with(predicate::eq(episode.clone()))
+                .with(predicate::eq(episode.clone()), predicate::eq(Actor::Admin))
                 .times(1)
                 .return_const(Err(InfraError::ConflictError));
 
-            let cmd = episode_commands::SaveEpisodeCommand::new(episode.clone());
+            let cmd = episode_commands::SaveEpisodeCommand::new(episode.clone(), Actor::Admin);
             let res_err = episode_usecases::save_episode(Arc::new(mock_repo_err), cmd).await;
             assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
         }
@@ -122,23 +274,23 @@ mod test {
             let mut mock_repo_ok = MockEpisodeRepository::new();
             mock_repo_ok
                 .expect_edit()
-                .with(predicate::eq(episode.clone()))
+                .with(predicate::eq(episode.clone()), predicate::eq(Actor::Admin))
                 .times(1)
                 .return_const(Ok(()));
 
-            let cmd = episode_commands::EditEpisodeCommand::new(episode.clone());
+            let cmd = episode_commands::EditEpisodeCommand::new(episode.clone(), Actor::Admin);
             let res_ok = episode_usecases::edit_episode(Arc::new(mock_repo_ok), cmd).await;
-            assert!(matches!(res_ok, Ok(_)));
+            assert!(res_ok.is_ok());
         }
         {
             let mut mock_repo_err = MockEpisodeRepository::new();
             mock_repo_err
                 .expect_edit()
-                .with(predicate::eq(episode.clone()))
+                .with(predicate::eq(episode.clone()), predicate::eq(Actor::Admin))
                 .times(1)
                 .return_const(Err(InfraError::NoRecordError));
 
-            let cmd = episode_commands::EditEpisodeCommand::new(episode.clone());
+            let cmd = episode_commands::EditEpisodeCommand::new(episode.clone(), Actor::Admin);
             let res_err = episode_usecases::edit_episode(Arc::new(mock_repo_err), cmd).await;
             assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
         }
@@ -173,13 +325,84 @@ mod test {
             .times(1)
             .return_const(Ok(episodes.clone()));
 
-        let cmd = episode_commands::OrderByDateRangeEpisodeCommand::new(start, end);
+        let cmd = episode_commands::OrderByDateRangeEpisodeCommand::new(vec![], start, end);
         let res_vec = episode_usecases::order_by_date_range_episodes(Arc::new(mock_repo), cmd)
             .await
             .unwrap();
         assert_eq!(res_vec, episodes);
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_order_by_date_range_episodes_with_tags_usecase(episodes: Vec<Episode>) {
+        let start = Faker.fake::<Date>();
+        let end = Faker.fake::<Date>();
+        let tags = vec![Tag::new("singing").unwrap()];
+
+        let mut mock_repo = MockEpisodeRepository::new();
+        mock_repo.expect_order_by_date_range().never();
+        mock_repo
+            .expect_order_by_date_range_with_tags()
+            .withf({
+                let tags = tags.clone();
+                move |t, s, e| t == tags && start == *s && end == *e
+            })
+            .times(1)
+            .return_const(Ok(episodes.clone()));
+
+        let cmd = episode_commands::OrderByDateRangeEpisodeCommand::new(tags, start, end);
+        let res_vec = episode_usecases::order_by_date_range_episodes(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res_vec, episodes);
+    }
+
+    #[tokio::test]
+    async fn test_episode_tags_usecase() {
+        let id = EpisodeId::generate();
+        let tag = Tag::new("singing").unwrap();
+
+        let mut mock_repo = MockEpisodeRepository::new();
+        mock_repo
+            .expect_all_tags()
+            .times(1)
+            .return_const(Ok(vec![tag.clone()]));
+        mock_repo
+            .expect_tags()
+            .with(predicate::eq(id))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        mock_repo
+            .expect_add_tag()
+            .with(predicate::eq(id), predicate::eq(tag.clone()))
+            .times(1)
+            .return_const(Ok(()));
+        mock_repo
+            .expect_remove_tag()
+            .with(predicate::eq(id), predicate::eq(tag.clone()))
+            .times(1)
+            .return_const(Ok(()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = episode_commands::AllEpisodeTagsCommand;
+        let res = episode_usecases::all_episode_tags(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, vec![tag.clone()]);
+
+        let cmd = episode_commands::EpisodeTagsCommand::new(id);
+        let res = episode_usecases::episode_tags(mock_repo.clone(), cmd).await;
+        assert!(matches!(res, Err(AppCommonError::NoRecordError)));
+
+        let cmd = episode_commands::AddEpisodeTagCommand::new(id, tag.clone());
+        let res = episode_usecases::add_episode_tag(mock_repo.clone(), cmd).await;
+        assert!(res.is_ok());
+
+        let cmd = episode_commands::RemoveEpisodeTagCommand::new(id, tag);
+        let res = episode_usecases::remove_episode_tag(mock_repo, cmd).await;
+        assert!(res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_remove_episode_usecase() {
         let episode_id = EpisodeId::generate();
@@ -188,23 +411,280 @@ mod test {
             let mut mock_repo_ok = MockEpisodeRepository::new();
             mock_repo_ok
                 .expect_remove()
-                .with(predicate::eq(episode_id))
+                .with(predicate::eq(episode_id), predicate::eq(Actor::Admin))
                 .return_const(Ok(()));
 
-            let cmd = episode_commands::RemoveEpisodeCommand::new(episode_id);
+            let cmd = episode_commands::RemoveEpisodeCommand::new(episode_id, Actor::Admin);
             let res_ok = episode_usecases::remove_episode(Arc::new(mock_repo_ok), cmd).await;
-            assert!(matches!(res_ok, Ok(_)));
+            assert!(res_ok.is_ok());
         }
         {
             let mut mock_repo_err = MockEpisodeRepository::new();
             mock_repo_err
                 .expect_remove()
-                .with(predicate::eq(episode_id))
+                .with(predicate::eq(episode_id), predicate::eq(Actor::Admin))
                 .return_const(Err(InfraError::NoRecordError));
 
-            let cmd = episode_commands::RemoveEpisodeCommand::new(episode_id);
+            let cmd = episode_commands::RemoveEpisodeCommand::new(episode_id, Actor::Admin);
             let res_err = episode_usecases::remove_episode(Arc::new(mock_repo_err), cmd).await;
             assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
         }
     }
+
+    #[tokio::test]
+    async fn test_episode_trash_usecase() {
+        let episodes = (0..3).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>();
+        let id = episodes[0].id();
+
+        let mut mock_repo = MockEpisodeRepository::new();
+        mock_repo
+            .expect_trashed()
+            .times(1)
+            .return_const(Ok(episodes.clone()));
+        mock_repo
+            .expect_restore()
+            .with(predicate::eq(id), predicate::eq(Actor::Admin))
+            .times(1)
+            .return_const(Ok(()));
+        mock_repo
+            .expect_purge()
+            .with(predicate::eq(id), predicate::eq(Actor::Admin))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = episode_commands::TrashedEpisodesCommand;
+        let res = episode_usecases::trashed_episodes(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, episodes);
+
+        let cmd = episode_commands::RestoreTrashedEpisodeCommand::new(id, Actor::Admin);
+        let res = episode_usecases::restore_trashed_episode(mock_repo.clone(), cmd).await;
+        assert!(res.is_ok());
+
+        // ゴミ箱に無い場合
+        let cmd = episode_commands::PurgeEpisodeCommand::new(id, Actor::Admin);
+        let res = episode_usecases::purge_episode(mock_repo, cmd).await;
+        assert!(matches!(res, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_episode_revisions_usecase() {
+        let episode = Faker.fake::<Episode>();
+        let revisions = (0..3).map(|_| Faker.fake::<Revision>()).collect::<Vec<_>>();
+        let length = PageSize::new(2).unwrap();
+
+        let mut mock_repo = MockEpisodeRepository::new();
+        mock_repo
+            .expect_revisions()
+            .with(predicate::eq(episode.id()))
+            .times(1)
+            .return_const(Ok(revisions.clone()));
+        mock_repo
+            .expect_latest_revisions()
+            .with(predicate::eq(length.get()))
+            .times(1)
+            .return_const(Ok(revisions[1..].to_vec()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = episode_commands::EpisodeRevisionsCommand::new(episode.id());
+        let res = episode_usecases::episode_revisions(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, revisions);
+
+        let cmd = episode_commands::LatestEpisodeRevisionsCommand::new(length);
+        let res = episode_usecases::latest_episode_revisions(mock_repo, cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, revisions[1..].to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_restore_episode_revision_usecase() {
+        let episode = Faker.fake::<Episode>();
+        let saved = Revision::new(
+            EntityKind::Episode,
+            episode.id().to_uuid(),
+            Operation::Save,
+            &episode,
+            Actor::Admin,
+        )
+        .unwrap();
+        let mut edited_episode = episode.clone();
+        edited_episode.assign(Faker.fake());
+        edited_episode.increment_version();
+        let edited = Revision::new(
+            EntityKind::Episode,
+            episode.id().to_uuid(),
+            Operation::Edit,
+            &edited_episode,
+            Actor::Anonymous,
+        )
+        .unwrap();
+        let removed = Revision::new(
+            EntityKind::Episode,
+            episode.id().to_uuid(),
+            Operation::Remove,
+            &edited_episode,
+            Actor::Admin,
+        )
+        .unwrap();
+        let purged = Revision::new(
+            EntityKind::Episode,
+            episode.id().to_uuid(),
+            Operation::Purge,
+            &edited_episode,
+            Actor::Admin,
+        )
+        .unwrap();
+
+        {
+            // 存在する場合は最新の版数に対する編集として戻す
+            let mut mock_repo = MockEpisodeRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(saved.id()))
+                .return_const(Ok(saved.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(episode.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone()]));
+
+            let mut expected = edited_episode.clone();
+            expected.assign(episode.clone());
+            mock_repo
+                .expect_edit()
+                .with(predicate::eq(expected.clone()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd =
+                episode_commands::RestoreEpisodeRevisionCommand::new(saved.id(), Actor::Admin);
+            let restored = episode_usecases::restore_episode_revision(Arc::new(mock_repo), cmd)
+                .await
+                .unwrap();
+            expected.increment_version();
+            assert_eq!(restored, expected);
+        }
+        {
+            // ゴミ箱にある場合は戻してから最新の版数に対する編集として戻す
+            let mut mock_repo = MockEpisodeRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(saved.id()))
+                .return_const(Ok(saved.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(episode.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone(), removed]));
+            mock_repo
+                .expect_restore()
+                .with(predicate::eq(episode.id()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let mut expected = edited_episode.clone();
+            expected.assign(episode.clone());
+            mock_repo
+                .expect_edit()
+                .with(predicate::eq(expected.clone()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd =
+                episode_commands::RestoreEpisodeRevisionCommand::new(saved.id(), Actor::Admin);
+            let restored = episode_usecases::restore_episode_revision(Arc::new(mock_repo), cmd)
+                .await
+                .unwrap();
+            expected.increment_version();
+            assert_eq!(restored, expected);
+        }
+        {
+            // 完全に削除されている場合は保存し直す
+            let mut mock_repo = MockEpisodeRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(edited.id()))
+                .return_const(Ok(edited.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(episode.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone(), purged]));
+            mock_repo
+                .expect_save()
+                .with(
+                    predicate::eq(edited_episode.clone()),
+                    predicate::eq(Actor::Admin),
+                )
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd =
+                episode_commands::RestoreEpisodeRevisionCommand::new(edited.id(), Actor::Admin);
+            let restored = episode_usecases::restore_episode_revision(Arc::new(mock_repo), cmd)
+                .await
+                .unwrap();
+            assert_eq!(restored, edited_episode);
+        }
+        {
+            let mut mock_repo_err = MockEpisodeRepository::new();
+            mock_repo_err
+                .expect_revision()
+                .return_const(Err(InfraError::NoRecordError));
+
+            let cmd = episode_commands::RestoreEpisodeRevisionCommand::new(
+                RevisionId::generate(),
+                Actor::Admin,
+            );
+            let res_err =
+                episode_usecases::restore_episode_revision(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_episode_revision_diff_usecase() {
+        let episode = Faker.fake::<Episode>();
+        let from = Revision::new(
+            EntityKind::Episode,
+            episode.id().to_uuid(),
+            Operation::Save,
+            &episode,
+            Actor::Admin,
+        )
+        .unwrap();
+        let other = Faker.fake::<Episode>();
+        let to = Revision::new(
+            EntityKind::Episode,
+            other.id().to_uuid(),
+            Operation::Save,
+            &other,
+            Actor::Admin,
+        )
+        .unwrap();
+
+        let mut mock_repo = MockEpisodeRepository::new();
+        mock_repo
+            .expect_revision()
+            .with(predicate::eq(from.id()))
+            .return_const(Ok(from.clone()));
+        mock_repo
+            .expect_revision()
+            .with(predicate::eq(to.id()))
+            .return_const(Ok(to.clone()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = episode_commands::EpisodeRevisionDiffCommand::new(from.id(), from.id());
+        let changes = episode_usecases::episode_revision_diff(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert!(changes.is_empty());
+
+        // 異なるエンティティの編集履歴は比較できない
+        let cmd = episode_commands::EpisodeRevisionDiffCommand::new(from.id(), to.id());
+        let res_err = episode_usecases::episode_revision_diff(mock_repo, cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::DomainError(_))));
+    }
 }
@@ -4,20 +4,31 @@ use mockall::automock;
 #[cfg_attr(test, automock)]
 pub mod movie_clip_usecases {
     use crate::commands::movie_clip_commands;
-    use common::AppCommonError;
-    use domain::{movie_clip::MovieClip, MovieClipRepository};
+    use common::{AppCommonError, NewMovieClipInfo, Page};
+    use domain::movie_clip::{MovieClip, MovieClipId};
+    use domain::revision::{FieldChange, Operation, Revision};
+    use domain::{Date, MovieClipRepository, Tag};
     use infrastructure::InfraError;
     use std::sync::Arc;
 
+    /// id, like, create_dateをサーバー側で決定してクリップを保存し，保存したクリップを返す．
     pub(crate) async fn save_movie_clip<T>(
         repo: Arc<T>,
         cmd: movie_clip_commands::SaveMovieClipCommand,
-    ) -> Result<(), AppCommonError>
+    ) -> Result<MovieClip, AppCommonError>
     where
         T: MovieClipRepository<Error = InfraError> + 'static,
     {
-        repo.save(cmd.movie_clip).await?;
-        Ok(())
+        let NewMovieClipInfo {
+            title,
+            url,
+            range,
+            duration,
+        } = cmd.movie_clip_info;
+        let movie_clip = MovieClip::new_with_domains(title, url, range, Date::today())
+            .with_duration(duration)?;
+        repo.save(movie_clip.clone(), cmd.actor).await?;
+        Ok(movie_clip)
     }
 
     pub(crate) async fn edit_movie_clip<T>(
@@ -27,7 +38,20 @@ pub mod movie_clip_usecases {
     where
         T: MovieClipRepository<Error = InfraError> + 'static,
     {
-        repo.edit(cmd.movie_clip).await?;
+        repo.edit(cmd.movie_clip, cmd.actor).await?;
+        Ok(())
+    }
+
+    /// バックアップから復元するためにid, like, create_dateを保ったままクリップを保存する．
+    /// 一つでも保存できないクリップがある場合は何も保存せずにエラーを返す．
+    pub(crate) async fn import_movie_clips<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::ImportMovieClipsCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        repo.save_many(cmd.movie_clips, cmd.actor).await?;
         Ok(())
     }
 
@@ -38,10 +62,32 @@ pub mod movie_clip_usecases {
     where
         T: MovieClipRepository<Error = InfraError> + 'static,
     {
-        repo.increment_like(cmd.id).await?;
+        repo.increment_like(cmd.id, cmd.client).await?;
         Ok(())
     }
 
+    pub(crate) async fn unlike_movie_clip<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::UnlikeMovieClipCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        repo.unlike(cmd.id, cmd.client).await?;
+        Ok(())
+    }
+
+    /// `ids`のうちクライアントがLikeしているMovieClipのidを取得する．
+    pub(crate) async fn liked_movie_clip_ids<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::LikedMovieClipIdsCommand,
+    ) -> Result<Vec<MovieClipId>, AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.liked_ids(&cmd.ids, cmd.client).await?)
+    }
+
     pub(crate) async fn all_movie_clips<T>(
         repo: Arc<T>,
         _cmd: movie_clip_commands::AllMovieClipCommand,
@@ -55,21 +101,38 @@ pub mod movie_clip_usecases {
     pub(crate) async fn order_by_like_movie_clips<T>(
         repo: Arc<T>,
         cmd: movie_clip_commands::OrderByLikeMovieClipCommand,
-    ) -> Result<Vec<MovieClip>, AppCommonError>
+    ) -> Result<Page<MovieClip>, AppCommonError>
     where
         T: MovieClipRepository<Error = InfraError> + 'static,
     {
-        Ok(repo.order_by_like(cmd.length).await?)
+        let clips = match cmd.tags.is_empty() {
+            true => repo.order_by_like(cmd.length.lookahead()).await?,
+            false => {
+                repo.order_by_like_with_tags(&cmd.tags, cmd.length.lookahead())
+                    .await?
+            }
+        };
+        Ok(Page::from_lookahead(clips, cmd.length))
     }
 
     pub(crate) async fn order_by_like_later_movie_clips<T>(
         repo: Arc<T>,
         cmd: movie_clip_commands::OrderByLikeLaterMovieClipCommand,
-    ) -> Result<Vec<MovieClip>, AppCommonError>
+    ) -> Result<Page<MovieClip>, AppCommonError>
     where
         T: MovieClipRepository<Error = InfraError> + 'static,
     {
-        Ok(repo.order_by_like_later(&cmd.reference, cmd.length).await?)
+        let clips = match cmd.tags.is_empty() {
+            true => {
+                repo.order_by_like_later(&cmd.cursor, cmd.length.lookahead())
+                    .await?
+            }
+            false => {
+                repo.order_by_like_later_with_tags(&cmd.tags, &cmd.cursor, cmd.length.lookahead())
+                    .await?
+            }
+        };
+        Ok(Page::from_lookahead(clips, cmd.length))
     }
 
     pub(crate) async fn order_by_create_date_range_movie_clips<T>(
@@ -85,23 +148,83 @@ pub mod movie_clip_usecases {
     pub(crate) async fn order_by_create_date_movie_clips<T>(
         repo: Arc<T>,
         cmd: movie_clip_commands::OrderByCreateDateMovieClipCommand,
-    ) -> Result<Vec<MovieClip>, AppCommonError>
+    ) -> Result<Page<MovieClip>, AppCommonError>
     where
         T: MovieClipRepository<Error = InfraError> + 'static,
     {
-        Ok(repo.order_by_create_date(cmd.length).await?)
+        let clips = match cmd.tags.is_empty() {
+            true => repo.order_by_create_date(cmd.length.lookahead()).await?,
+            false => {
+                repo.order_by_create_date_with_tags(&cmd.tags, cmd.length.lookahead())
+                    .await?
+            }
+        };
+        Ok(Page::from_lookahead(clips, cmd.length))
     }
 
     pub(crate) async fn order_by_create_date_later_movie_clips<T>(
         repo: Arc<T>,
         cmd: movie_clip_commands::OrderByCreateDateLaterMovieClipCommand,
-    ) -> Result<Vec<MovieClip>, AppCommonError>
+    ) -> Result<Page<MovieClip>, AppCommonError>
     where
         T: MovieClipRepository<Error = InfraError> + 'static,
     {
-        Ok(repo
-            .order_by_create_date_later(&cmd.reference, cmd.length)
-            .await?)
+        let clips = match cmd.tags.is_empty() {
+            true => {
+                repo.order_by_create_date_later(&cmd.cursor, cmd.length.lookahead())
+                    .await?
+            }
+            false => {
+                repo.order_by_create_date_later_with_tags(
+                    &cmd.tags,
+                    &cmd.cursor,
+                    cmd.length.lookahead(),
+                )
+                .await?
+            }
+        };
+        Ok(Page::from_lookahead(clips, cmd.length))
+    }
+
+    /// クリップに付けられている全てのタグを取得する．
+    pub(crate) async fn all_movie_clip_tags<T>(
+        repo: Arc<T>,
+        _cmd: movie_clip_commands::AllMovieClipTagsCommand,
+    ) -> Result<Vec<Tag>, AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.all_tags().await?)
+    }
+
+    pub(crate) async fn movie_clip_tags<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::MovieClipTagsCommand,
+    ) -> Result<Vec<Tag>, AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.tags(cmd.id).await?)
+    }
+
+    pub(crate) async fn add_movie_clip_tag<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::AddMovieClipTagCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.add_tag(cmd.id, cmd.tag).await?)
+    }
+
+    pub(crate) async fn remove_movie_clip_tag<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::RemoveMovieClipTagCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.remove_tag(cmd.id, cmd.tag).await?)
     }
 
     pub(crate) async fn remove_movie_clip<T>(
@@ -111,7 +234,109 @@ pub mod movie_clip_usecases {
     where
         T: MovieClipRepository<Error = InfraError> + 'static,
     {
-        Ok(repo.remove(cmd.id).await?)
+        Ok(repo.remove(cmd.id, cmd.actor).await?)
+    }
+
+    /// ゴミ箱のクリップを削除した日時の新しい順に取得する．
+    pub(crate) async fn trashed_movie_clips<T>(
+        repo: Arc<T>,
+        _cmd: movie_clip_commands::TrashedMovieClipsCommand,
+    ) -> Result<Vec<MovieClip>, AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.trashed().await?)
+    }
+
+    /// ゴミ箱のクリップを戻す．
+    pub(crate) async fn restore_trashed_movie_clip<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::RestoreTrashedMovieClipCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.restore(cmd.id, cmd.actor).await?)
+    }
+
+    /// ゴミ箱のクリップを完全に削除する．
+    pub(crate) async fn purge_movie_clip<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::PurgeMovieClipCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.purge(cmd.id, cmd.actor).await?)
+    }
+
+    /// クリップの編集履歴を古い順に取得する．
+    pub(crate) async fn movie_clip_revisions<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::MovieClipRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.revisions(cmd.id).await?)
+    }
+
+    /// クリップの編集履歴を新しい順に取得する．
+    pub(crate) async fn latest_movie_clip_revisions<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::LatestMovieClipRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.latest_revisions(cmd.length.get()).await?)
+    }
+
+    /// 二つの編集履歴の間の変更を取得する．
+    pub(crate) async fn movie_clip_revision_diff<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::MovieClipRevisionDiffCommand,
+    ) -> Result<Vec<FieldChange>, AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        let from = repo.revision(cmd.from).await?;
+        let to = repo.revision(cmd.to).await?;
+        Ok(from.diff(&to)?)
+    }
+
+    /// 編集履歴の時点の内容に戻し，戻したクリップを返す．ゴミ箱にある場合は戻してから編集し，完全に削除されている場合は保存し直す．
+    pub(crate) async fn restore_movie_clip_revision<T>(
+        repo: Arc<T>,
+        cmd: movie_clip_commands::RestoreMovieClipRevisionCommand,
+    ) -> Result<MovieClip, AppCommonError>
+    where
+        T: MovieClipRepository<Error = InfraError> + 'static,
+    {
+        let restored = repo.revision(cmd.id).await?.snapshot_as::<MovieClip>()?;
+        let latest = repo
+            .revisions(restored.id())
+            .await?
+            .pop()
+            .ok_or(AppCommonError::NoRecordError)?;
+
+        match latest.operation() {
+            Operation::Purge => {
+                repo.save(restored.clone(), cmd.actor).await?;
+                Ok(restored)
+            }
+            Operation::Save | Operation::Edit | Operation::Remove | Operation::Restore => {
+                if latest.operation() == Operation::Remove {
+                    repo.restore(restored.id(), cmd.actor).await?;
+                }
+                // 現在の版数に対する編集として戻す
+                let mut movie_clip = latest.snapshot_as::<MovieClip>()?;
+                movie_clip.assign(restored);
+                repo.edit(movie_clip.clone(), cmd.actor).await?;
+                movie_clip.increment_version();
+                Ok(movie_clip)
+            }
+        }
     }
 }
 
@@ -119,10 +344,12 @@ pub mod movie_clip_usecases {
 mod test {
     use super::movie_clip_usecases;
     use crate::commands::movie_clip_commands;
-    use common::AppCommonError;
+    use common::{AppCommonError, NewMovieClipInfo};
     use domain::{
-        movie_clip::{MovieClip, MovieClipId},
-        Date,
+        client::ClientId,
+        movie_clip::{MovieClip, MovieClipId, Second},
+        revision::{Actor, EntityKind, Operation, Revision, RevisionId},
+        Date, PageSize, Tag,
     };
     use infrastructure::movie_clip_repository_impl::MockMovieClipRepository;
     use infrastructure::InfraError;
@@ -142,32 +369,71 @@ mod test {
 
     #[tokio::test]
     async fn test_save_movie_clip_usecase() {
-        let movie_clip = Faker.fake::<MovieClip>();
+        let movie_clip = {
+            let movie_clip = Faker.fake::<MovieClip>();
+            let duration = Second::from_millis(movie_clip.range().end().to_millis() + 1000);
+            movie_clip.with_duration(Some(duration)).unwrap()
+        };
+        let movie_clip_info = NewMovieClipInfo::from(movie_clip.clone());
 
         {
+            // id, like, create_dateはサーバーで決定される
             let mut mock_repo_ok = MockMovieClipRepository::new();
             mock_repo_ok
                 .expect_save()
-                .with(predicate::eq(movie_clip.clone()))
+                .withf({
+                    let movie_clip = movie_clip.clone();
+                    move |saved_clip, actor| {
+                        *actor == Actor::Admin
+                            && saved_clip.id() != movie_clip.id()
+                            && saved_clip.like() == 0
+                            && saved_clip.create_date() == Date::today()
+                            && saved_clip.title() == movie_clip.title()
+                            && saved_clip.url() == movie_clip.url()
+                            && saved_clip.range() == movie_clip.range()
+                            && saved_clip.duration() == movie_clip.duration()
+                    }
+                })
                 .times(1)
                 .return_const(Ok(()));
 
-            let cmd = movie_clip_commands::SaveMovieClipCommand::new(movie_clip.clone());
-            let res_ok = movie_clip_usecases::save_movie_clip(Arc::new(mock_repo_ok), cmd).await;
-            assert!(matches!(res_ok, Ok(_)));
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(
+                movie_clip_info.clone(),
+                Actor::Admin,
+            );
+            let saved_clip = movie_clip_usecases::save_movie_clip(Arc::new(mock_repo_ok), cmd)
+                .await
+                .unwrap();
+            assert_eq!(saved_clip.like(), 0);
+            assert_eq!(NewMovieClipInfo::from(saved_clip), movie_clip_info);
         }
         {
             let mut mock_repo_err = MockMovieClipRepository::new();
             mock_repo_err
                 .expect_save()
-                .with(predicate::eq(movie_clip.clone()))
                 .times(1)
                 .return_const(Err(InfraError::ConflictError));
 
-            let cmd = movie_clip_commands::SaveMovieClipCommand::new(movie_clip.clone());
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(movie_clip_info, Actor::Admin);
             let res_err = movie_clip_usecases::save_movie_clip(Arc::new(mock_repo_err), cmd).await;
             assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
         }
+        {
+            // 元動画の長さが再生範囲の終了より短い場合は保存しない
+            let mut mock_repo_invalid = MockMovieClipRepository::new();
+            mock_repo_invalid.expect_save().times(0);
+
+            let movie_clip_info = NewMovieClipInfo {
+                duration: Some(Second::from_millis(
+                    movie_clip.range().end().to_millis() - 1,
+                )),
+                ..NewMovieClipInfo::from(movie_clip)
+            };
+            let cmd = movie_clip_commands::SaveMovieClipCommand::new(movie_clip_info, Actor::Admin);
+            let res_err =
+                movie_clip_usecases::save_movie_clip(Arc::new(mock_repo_invalid), cmd).await;
+            assert!(matches!(res_err, Err(AppCommonError::ValidationError(_))));
+        }
     }
 
     #[tokio::test]
@@ -178,23 +444,31 @@ mod test {
             let mut mock_repo_ok = MockMovieClipRepository::new();
             mock_repo_ok
                 .expect_edit()
-                .with(predicate::eq(movie_clip.clone()))
+                .with(
+                    predicate::eq(movie_clip.clone()),
+                    predicate::eq(Actor::Admin),
+                )
                 .times(1)
                 .return_const(Ok(()));
 
-            let cmd = movie_clip_commands::EditMovieClipCommand::new(movie_clip.clone());
+            let cmd =
+                movie_clip_commands::EditMovieClipCommand::new(movie_clip.clone(), Actor::Admin);
             let res_ok = movie_clip_usecases::edit_movie_clip(Arc::new(mock_repo_ok), cmd).await;
-            assert!(matches!(res_ok, Ok(_)));
+            assert!(res_ok.is_ok());
         }
         {
             let mut mock_repo_err = MockMovieClipRepository::new();
             mock_repo_err
                 .expect_edit()
-                .with(predicate::eq(movie_clip.clone()))
+                .with(
+                    predicate::eq(movie_clip.clone()),
+                    predicate::eq(Actor::Admin),
+                )
                 .times(1)
                 .return_const(Err(InfraError::NoRecordError));
 
-            let cmd = movie_clip_commands::EditMovieClipCommand::new(movie_clip.clone());
+            let cmd =
+                movie_clip_commands::EditMovieClipCommand::new(movie_clip.clone(), Actor::Admin);
             let res_err = movie_clip_usecases::edit_movie_clip(Arc::new(mock_repo_err), cmd).await;
             assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
         }
@@ -203,35 +477,76 @@ mod test {
     #[tokio::test]
     async fn test_increment_like_movie_clip_usecase() {
         let id = MovieClipId::generate();
+        let client = ClientId::generate();
 
         {
             let mut mock_repo_ok = MockMovieClipRepository::new();
             mock_repo_ok
                 .expect_increment_like()
-                .with(predicate::eq(id))
+                .with(predicate::eq(id), predicate::eq(client))
                 .times(1)
                 .return_const(Ok(()));
 
-            let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(id);
+            let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(id, client);
             let res_ok =
                 movie_clip_usecases::increment_like_movie_clip(Arc::new(mock_repo_ok), cmd).await;
-            assert!(matches!(res_ok, Ok(_)));
+            assert!(res_ok.is_ok());
         }
         {
             let mut mock_repo_err = MockMovieClipRepository::new();
             mock_repo_err
                 .expect_increment_like()
-                .with(predicate::eq(id))
+                .with(predicate::eq(id), predicate::eq(client))
                 .times(1)
                 .return_const(Err(InfraError::NoRecordError));
 
-            let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(id);
+            let cmd = movie_clip_commands::IncrementLikeMovieClipCommand::new(id, client);
             let res_err =
                 movie_clip_usecases::increment_like_movie_clip(Arc::new(mock_repo_err), cmd).await;
             assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
         }
     }
 
+    #[tokio::test]
+    async fn test_unlike_movie_clip_usecase() {
+        let id = MovieClipId::generate();
+        let client = ClientId::generate();
+
+        let mut mock_repo_err = MockMovieClipRepository::new();
+        mock_repo_err
+            .expect_unlike()
+            .with(predicate::eq(id), predicate::eq(client))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+
+        let cmd = movie_clip_commands::UnlikeMovieClipCommand::new(id, client);
+        let res_err = movie_clip_usecases::unlike_movie_clip(Arc::new(mock_repo_err), cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_liked_movie_clip_ids_usecase() {
+        let ids = (0..10).map(|_| MovieClipId::generate()).collect::<Vec<_>>();
+        let liked_ids = ids[..5].to_vec();
+        let client = ClientId::generate();
+
+        let mut mock_repo = MockMovieClipRepository::new();
+        mock_repo
+            .expect_liked_ids()
+            .withf({
+                let ids = ids.clone();
+                move |arg_ids, arg_client| arg_ids == ids && *arg_client == client
+            })
+            .times(1)
+            .return_const(Ok(liked_ids.clone()));
+
+        let cmd = movie_clip_commands::LikedMovieClipIdsCommand::new(ids, client);
+        let res = movie_clip_usecases::liked_movie_clip_ids(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, liked_ids);
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_all_movie_clips_usecase(movie_clips: Vec<MovieClip>) {
@@ -251,44 +566,47 @@ mod test {
     #[rstest]
     #[tokio::test]
     async fn test_order_by_like_movie_clips_usecase(movie_clips: Vec<MovieClip>) {
-        let length = 100_usize;
+        let length = PageSize::new(50).unwrap();
 
         let mut mock_repo = MockMovieClipRepository::new();
         mock_repo
             .expect_order_by_like()
-            .with(predicate::eq(length))
+            .with(predicate::eq(length.lookahead()))
             .return_const(Ok(movie_clips.clone()));
 
-        let cmd = movie_clip_commands::OrderByLikeMovieClipCommand::new(length);
-        let res_vec = movie_clip_usecases::order_by_like_movie_clips(Arc::new(mock_repo), cmd)
+        let cmd = movie_clip_commands::OrderByLikeMovieClipCommand::new(vec![], length);
+        let page = movie_clip_usecases::order_by_like_movie_clips(Arc::new(mock_repo), cmd)
             .await
             .unwrap();
-        assert_eq!(res_vec, movie_clips);
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, movie_clips[..length.get()]);
+        assert!(page.has_more);
     }
 
     #[rstest]
     #[tokio::test]
     async fn test_order_by_like_later_movie_clips_usecase(movie_clips: Vec<MovieClip>) {
-        let reference = Faker.fake::<MovieClip>();
-        let length = 100_usize;
+        let cursor = Faker.fake::<MovieClip>().like_cursor();
+        let length = PageSize::new(50).unwrap();
 
         let mut mock_repo = MockMovieClipRepository::new();
         mock_repo
             .expect_order_by_like_later()
             .withf({
-                let reference = reference.clone();
-                move |arg_reference, arg_length| {
-                    *arg_reference == reference && *arg_length == length
+                move |arg_cursor, arg_length| {
+                    *arg_cursor == cursor && *arg_length == length.lookahead()
                 }
             })
             .return_const(Ok(movie_clips.clone()));
 
-        let cmd = movie_clip_commands::OrderByLikeLaterMovieClipCommand::new(reference, length);
-        let res_vec =
-            movie_clip_usecases::order_by_like_later_movie_clips(Arc::new(mock_repo), cmd)
-                .await
-                .unwrap();
-        assert_eq!(res_vec, movie_clips);
+        let cmd =
+            movie_clip_commands::OrderByLikeLaterMovieClipCommand::new(vec![], cursor, length);
+        let page = movie_clip_usecases::order_by_like_later_movie_clips(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, movie_clips[..length.get()]);
+        assert!(page.has_more);
     }
 
     #[rstest]
@@ -314,46 +632,156 @@ mod test {
     #[rstest]
     #[tokio::test]
     async fn test_order_by_create_date_movie_clips_usecase(movie_clips: Vec<MovieClip>) {
-        let length = 100_usize;
+        let length = PageSize::new(50).unwrap();
 
         let mut mock_repo = MockMovieClipRepository::new();
         mock_repo
             .expect_order_by_create_date()
-            .with(predicate::eq(length))
+            .with(predicate::eq(length.lookahead()))
             .return_const(Ok(movie_clips.clone()));
 
-        let cmd = movie_clip_commands::OrderByCreateDateMovieClipCommand::new(length);
-        let res_vec =
-            movie_clip_usecases::order_by_create_date_movie_clips(Arc::new(mock_repo), cmd)
-                .await
-                .unwrap();
-        assert_eq!(res_vec, movie_clips);
+        let cmd = movie_clip_commands::OrderByCreateDateMovieClipCommand::new(vec![], length);
+        let page = movie_clip_usecases::order_by_create_date_movie_clips(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, movie_clips[..length.get()]);
+        assert!(page.has_more);
     }
 
     #[rstest]
     #[tokio::test]
     async fn test_order_by_create_date_later_movie_clips_usecase(movie_clips: Vec<MovieClip>) {
-        let reference = Faker.fake::<MovieClip>();
-        let length = 100_usize;
+        let cursor = Faker.fake::<MovieClip>().create_date_cursor();
+        let length = PageSize::new(50).unwrap();
 
         let mut mock_repo = MockMovieClipRepository::new();
         mock_repo
             .expect_order_by_create_date_later()
             .withf({
-                let reference = reference.clone();
-                move |arg_reference, arg_length| {
-                    *arg_reference == reference && *arg_length == length
+                move |arg_cursor, arg_length| {
+                    *arg_cursor == cursor && *arg_length == length.lookahead()
                 }
             })
             .return_const(Ok(movie_clips.clone()));
 
+        let cmd = movie_clip_commands::OrderByCreateDateLaterMovieClipCommand::new(
+            vec![],
+            cursor,
+            length,
+        );
+        let page =
+            movie_clip_usecases::order_by_create_date_later_movie_clips(Arc::new(mock_repo), cmd)
+                .await
+                .unwrap();
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, movie_clips[..length.get()]);
+        assert!(page.has_more);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_order_by_like_movie_clips_with_tags_usecase(movie_clips: Vec<MovieClip>) {
+        let tags = vec![Tag::new("game").unwrap(), Tag::new("singing").unwrap()];
+        let length = PageSize::new(50).unwrap();
+
+        let mut mock_repo = MockMovieClipRepository::new();
+        // タグが与えられた場合はタグで絞り込む
+        mock_repo.expect_order_by_like().never();
+        mock_repo
+            .expect_order_by_like_with_tags()
+            .withf({
+                let tags = tags.clone();
+                move |arg_tags, arg_length| arg_tags == tags && *arg_length == length.lookahead()
+            })
+            .times(1)
+            .return_const(Ok(movie_clips[..10].to_vec()));
+
+        let cmd = movie_clip_commands::OrderByLikeMovieClipCommand::new(tags, length);
+        let page = movie_clip_usecases::order_by_like_movie_clips(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(page.items, movie_clips[..10]);
+        assert!(!page.has_more);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_order_by_create_date_later_movie_clips_with_tags_usecase(
+        movie_clips: Vec<MovieClip>,
+    ) {
+        let tags = vec![Tag::new("game").unwrap()];
+        let cursor = Faker.fake::<MovieClip>().create_date_cursor();
+        let length = PageSize::new(50).unwrap();
+
+        let mut mock_repo = MockMovieClipRepository::new();
+        mock_repo
+            .expect_order_by_create_date_later_with_tags()
+            .withf({
+                let tags = tags.clone();
+                move |arg_tags, arg_cursor, arg_length| {
+                    arg_tags == tags && *arg_cursor == cursor && *arg_length == length.lookahead()
+                }
+            })
+            .times(1)
+            .return_const(Ok(movie_clips.clone()));
+
         let cmd =
-            movie_clip_commands::OrderByCreateDateLaterMovieClipCommand::new(reference, length);
-        let res_vec =
+            movie_clip_commands::OrderByCreateDateLaterMovieClipCommand::new(tags, cursor, length);
+        let page =
             movie_clip_usecases::order_by_create_date_later_movie_clips(Arc::new(mock_repo), cmd)
                 .await
                 .unwrap();
-        assert_eq!(res_vec, movie_clips);
+        assert_eq!(page.items, movie_clips[..length.get()]);
+        assert!(page.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_movie_clip_tags_usecase() {
+        let id = MovieClipId::generate();
+        let tag = Tag::new("game").unwrap();
+
+        let mut mock_repo = MockMovieClipRepository::new();
+        mock_repo
+            .expect_all_tags()
+            .times(1)
+            .return_const(Ok(vec![tag.clone()]));
+        mock_repo
+            .expect_tags()
+            .with(predicate::eq(id))
+            .times(1)
+            .return_const(Ok(vec![tag.clone()]));
+        mock_repo
+            .expect_add_tag()
+            .with(predicate::eq(id), predicate::eq(tag.clone()))
+            .times(1)
+            .return_const(Ok(()));
+        mock_repo
+            .expect_remove_tag()
+            .with(predicate::eq(id), predicate::eq(tag.clone()))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = movie_clip_commands::AllMovieClipTagsCommand;
+        let res = movie_clip_usecases::all_movie_clip_tags(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, vec![tag.clone()]);
+
+        let cmd = movie_clip_commands::MovieClipTagsCommand::new(id);
+        let res = movie_clip_usecases::movie_clip_tags(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, vec![tag.clone()]);
+
+        let cmd = movie_clip_commands::AddMovieClipTagCommand::new(id, tag.clone());
+        let res = movie_clip_usecases::add_movie_clip_tag(mock_repo.clone(), cmd).await;
+        assert!(res.is_ok());
+
+        let cmd = movie_clip_commands::RemoveMovieClipTagCommand::new(id, tag);
+        let res = movie_clip_usecases::remove_movie_clip_tag(mock_repo, cmd).await;
+        assert!(matches!(res, Err(AppCommonError::NoRecordError)));
     }
 
     #[tokio::test]
@@ -364,24 +792,329 @@ mod test {
             let mut mock_repo_ok = MockMovieClipRepository::new();
             mock_repo_ok
                 .expect_remove()
-                .with(predicate::eq(id))
+                .with(predicate::eq(id), predicate::eq(Actor::Admin))
                 .return_const(Ok(()));
 
-            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(id);
+            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(id, Actor::Admin);
             let res_ok = movie_clip_usecases::remove_movie_clip(Arc::new(mock_repo_ok), cmd).await;
-            assert!(matches!(res_ok, Ok(_)));
+            assert!(res_ok.is_ok());
         }
         {
             let mut mock_repo_err = MockMovieClipRepository::new();
             mock_repo_err
                 .expect_remove()
-                .with(predicate::eq(id))
+                .with(predicate::eq(id), predicate::eq(Actor::Admin))
                 .return_const(Err(InfraError::NoRecordError));
 
-            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(id);
+            let cmd = movie_clip_commands::RemoveMovieClipCommand::new(id, Actor::Admin);
             let res_err =
                 movie_clip_usecases::remove_movie_clip(Arc::new(mock_repo_err), cmd).await;
             assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
         }
     }
+
+    #[tokio::test]
+    async fn test_movie_clip_trash_usecase() {
+        let movie_clips = (0..3)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+        let id = movie_clips[0].id();
+
+        let mut mock_repo = MockMovieClipRepository::new();
+        mock_repo
+            .expect_trashed()
+            .times(1)
+            .return_const(Ok(movie_clips.clone()));
+        mock_repo
+            .expect_restore()
+            .with(predicate::eq(id), predicate::eq(Actor::Admin))
+            .times(1)
+            .return_const(Ok(()));
+        mock_repo
+            .expect_purge()
+            .with(predicate::eq(id), predicate::eq(Actor::Admin))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = movie_clip_commands::TrashedMovieClipsCommand;
+        let res = movie_clip_usecases::trashed_movie_clips(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, movie_clips);
+
+        let cmd = movie_clip_commands::RestoreTrashedMovieClipCommand::new(id, Actor::Admin);
+        let res = movie_clip_usecases::restore_trashed_movie_clip(mock_repo.clone(), cmd).await;
+        assert!(res.is_ok());
+
+        // ゴミ箱に無い場合
+        let cmd = movie_clip_commands::PurgeMovieClipCommand::new(id, Actor::Admin);
+        let res = movie_clip_usecases::purge_movie_clip(mock_repo, cmd).await;
+        assert!(matches!(res, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_movie_clip_revisions_usecase() {
+        let movie_clip = Faker.fake::<MovieClip>();
+        let revisions = (0..3).map(|_| Faker.fake::<Revision>()).collect::<Vec<_>>();
+        let length = PageSize::new(2).unwrap();
+
+        let mut mock_repo = MockMovieClipRepository::new();
+        mock_repo
+            .expect_revisions()
+            .with(predicate::eq(movie_clip.id()))
+            .times(1)
+            .return_const(Ok(revisions.clone()));
+        mock_repo
+            .expect_latest_revisions()
+            .with(predicate::eq(length.get()))
+            .times(1)
+            .return_const(Ok(revisions[1..].to_vec()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = movie_clip_commands::MovieClipRevisionsCommand::new(movie_clip.id());
+        let res = movie_clip_usecases::movie_clip_revisions(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, revisions);
+
+        let cmd = movie_clip_commands::LatestMovieClipRevisionsCommand::new(length);
+        let res = movie_clip_usecases::latest_movie_clip_revisions(mock_repo, cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, revisions[1..].to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_restore_movie_clip_revision_usecase() {
+        let movie_clip = Faker.fake::<MovieClip>();
+        let saved = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Save,
+            &movie_clip,
+            Actor::Admin,
+        )
+        .unwrap();
+        let mut edited_movie_clip = movie_clip.clone();
+        edited_movie_clip.assign(Faker.fake());
+        edited_movie_clip.increment_version();
+        let edited = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Edit,
+            &edited_movie_clip,
+            Actor::Anonymous,
+        )
+        .unwrap();
+        let removed = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Remove,
+            &edited_movie_clip,
+            Actor::Admin,
+        )
+        .unwrap();
+        let purged = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Purge,
+            &edited_movie_clip,
+            Actor::Admin,
+        )
+        .unwrap();
+
+        {
+            // 存在する場合は最新の版数に対する編集として戻す
+            let mut mock_repo = MockMovieClipRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(saved.id()))
+                .return_const(Ok(saved.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(movie_clip.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone()]));
+
+            let mut expected = edited_movie_clip.clone();
+            expected.assign(movie_clip.clone());
+            mock_repo
+                .expect_edit()
+                .with(predicate::eq(expected.clone()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd =
+                movie_clip_commands::RestoreMovieClipRevisionCommand::new(saved.id(), Actor::Admin);
+            let restored =
+                movie_clip_usecases::restore_movie_clip_revision(Arc::new(mock_repo), cmd)
+                    .await
+                    .unwrap();
+            expected.increment_version();
+            assert_eq!(restored, expected);
+        }
+        {
+            // ゴミ箱にある場合は戻してから最新の版数に対する編集として戻す
+            let mut mock_repo = MockMovieClipRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(saved.id()))
+                .return_const(Ok(saved.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(movie_clip.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone(), removed]));
+            mock_repo
+                .expect_restore()
+                .with(predicate::eq(movie_clip.id()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let mut expected = edited_movie_clip.clone();
+            expected.assign(movie_clip.clone());
+            mock_repo
+                .expect_edit()
+                .with(predicate::eq(expected.clone()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd =
+                movie_clip_commands::RestoreMovieClipRevisionCommand::new(saved.id(), Actor::Admin);
+            let restored =
+                movie_clip_usecases::restore_movie_clip_revision(Arc::new(mock_repo), cmd)
+                    .await
+                    .unwrap();
+            expected.increment_version();
+            assert_eq!(restored, expected);
+        }
+        {
+            // 完全に削除されている場合は保存し直す
+            let mut mock_repo = MockMovieClipRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(edited.id()))
+                .return_const(Ok(edited.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(movie_clip.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone(), purged]));
+            mock_repo
+                .expect_save()
+                .with(
+                    predicate::eq(edited_movie_clip.clone()),
+                    predicate::eq(Actor::Admin),
+                )
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd = movie_clip_commands::RestoreMovieClipRevisionCommand::new(
+                edited.id(),
+                Actor::Admin,
+            );
+            let restored =
+                movie_clip_usecases::restore_movie_clip_revision(Arc::new(mock_repo), cmd)
+                    .await
+                    .unwrap();
+            assert_eq!(restored, edited_movie_clip);
+        }
+        {
+            let mut mock_repo_err = MockMovieClipRepository::new();
+            mock_repo_err
+                .expect_revision()
+                .return_const(Err(InfraError::NoRecordError));
+
+            let cmd = movie_clip_commands::RestoreMovieClipRevisionCommand::new(
+                RevisionId::generate(),
+                Actor::Admin,
+            );
+            let res_err =
+                movie_clip_usecases::restore_movie_clip_revision(Arc::new(mock_repo_err), cmd)
+                    .await;
+            assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_movie_clip_revision_diff_usecase() {
+        let movie_clip = Faker.fake::<MovieClip>();
+        let from = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Save,
+            &movie_clip,
+            Actor::Admin,
+        )
+        .unwrap();
+        let other = Faker.fake::<MovieClip>();
+        let to = Revision::new(
+            EntityKind::MovieClip,
+            other.id().to_uuid(),
+            Operation::Save,
+            &other,
+            Actor::Admin,
+        )
+        .unwrap();
+
+        let mut mock_repo = MockMovieClipRepository::new();
+        mock_repo
+            .expect_revision()
+            .with(predicate::eq(from.id()))
+            .return_const(Ok(from.clone()));
+        mock_repo
+            .expect_revision()
+            .with(predicate::eq(to.id()))
+            .return_const(Ok(to.clone()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = movie_clip_commands::MovieClipRevisionDiffCommand::new(from.id(), from.id());
+        let changes = movie_clip_usecases::movie_clip_revision_diff(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert!(changes.is_empty());
+
+        // 異なるエンティティの編集履歴は比較できない
+        let cmd = movie_clip_commands::MovieClipRevisionDiffCommand::new(from.id(), to.id());
+        let res_err = movie_clip_usecases::movie_clip_revision_diff(mock_repo, cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::DomainError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_import_movie_clips_usecase() {
+        let movie_clips = (0..3)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+
+        {
+            // id, like, create_dateはそのまま保存される
+            let mut mock_repo_ok = MockMovieClipRepository::new();
+            mock_repo_ok
+                .expect_save_many()
+                .withf({
+                    let movie_clips = movie_clips.clone();
+                    move |saved_clips, actor| *actor == Actor::Admin && *saved_clips == movie_clips
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd = movie_clip_commands::ImportMovieClipsCommand::new(
+                movie_clips.clone(),
+                Actor::Admin,
+            );
+            let res = movie_clip_usecases::import_movie_clips(Arc::new(mock_repo_ok), cmd).await;
+            assert!(res.is_ok());
+        }
+        {
+            // 保存できないクリップがある場合はエラー
+            let mut mock_repo_err = MockMovieClipRepository::new();
+            mock_repo_err
+                .expect_save_many()
+                .times(1)
+                .return_const(Err(InfraError::ConflictError));
+
+            let cmd = movie_clip_commands::ImportMovieClipsCommand::new(movie_clips, Actor::Admin);
+            let res_err =
+                movie_clip_usecases::import_movie_clips(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
+        }
+    }
 }
@@ -0,0 +1,210 @@
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub mod quiz_usecases {
+    use crate::commands::quiz_commands;
+    use common::AppCommonError;
+    use domain::{quiz::Quiz, QuizRepository};
+    use infrastructure::InfraError;
+    use std::sync::Arc;
+
+    /// 出題するクイズをランダムに一つ取得する．
+    pub(crate) async fn random_quiz<T>(
+        repo: Arc<T>,
+        _cmd: quiz_commands::RandomQuizCommand,
+    ) -> Result<Quiz, AppCommonError>
+    where
+        T: QuizRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.random().await?)
+    }
+
+    /// クイズの回答が正しいかどうかを確認する．正しくない場合は`IncorrectAnswerError`を返す．
+    pub(crate) async fn check_quiz_answer<T>(
+        repo: Arc<T>,
+        cmd: quiz_commands::CheckQuizAnswerCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: QuizRepository<Error = InfraError> + 'static,
+    {
+        let quiz = repo.find_by_id(cmd.id).await?;
+        if !quiz.is_correct(&cmd.answer) {
+            return Err(AppCommonError::IncorrectAnswerError);
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn save_quiz<T>(
+        repo: Arc<T>,
+        cmd: quiz_commands::SaveQuizCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: QuizRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.save(cmd.quiz).await?)
+    }
+
+    pub(crate) async fn edit_quiz<T>(
+        repo: Arc<T>,
+        cmd: quiz_commands::EditQuizCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: QuizRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.edit(cmd.quiz).await?)
+    }
+
+    pub(crate) async fn all_quizzes<T>(
+        repo: Arc<T>,
+        _cmd: quiz_commands::AllQuizzesCommand,
+    ) -> Result<Vec<Quiz>, AppCommonError>
+    where
+        T: QuizRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.all().await?)
+    }
+
+    pub(crate) async fn remove_quiz<T>(
+        repo: Arc<T>,
+        cmd: quiz_commands::RemoveQuizCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: QuizRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.remove(cmd.id).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::quiz_usecases;
+    use crate::commands::quiz_commands;
+    use common::AppCommonError;
+    use domain::quiz::{Quiz, QuizId};
+    use fake::{Fake, Faker};
+    use infrastructure::quiz_repository_impl::MockQuizRepository;
+    use infrastructure::InfraError;
+    use mockall::predicate;
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_random_quiz_usecase() {
+        let quiz = Faker.fake::<Quiz>();
+        {
+            let mut mock_repo_ok = MockQuizRepository::new();
+            mock_repo_ok
+                .expect_random()
+                .times(1)
+                .return_const(Ok(quiz.clone()));
+
+            let cmd = quiz_commands::RandomQuizCommand;
+            let res = quiz_usecases::random_quiz(Arc::new(mock_repo_ok), cmd)
+                .await
+                .unwrap();
+            assert_eq!(res, quiz);
+        }
+        {
+            // Quizが無い場合
+            let mut mock_repo_err = MockQuizRepository::new();
+            mock_repo_err
+                .expect_random()
+                .times(1)
+                .return_const(Err(InfraError::NoRecordError));
+
+            let cmd = quiz_commands::RandomQuizCommand;
+            let res = quiz_usecases::random_quiz(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res, Err(AppCommonError::NoRecordError)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_quiz_answer_usecase() {
+        let quiz = Quiz::new("question".to_string(), vec!["answer".to_string()]).unwrap();
+
+        {
+            let mut mock_repo_ok = MockQuizRepository::new();
+            mock_repo_ok
+                .expect_find_by_id()
+                .with(predicate::eq(quiz.id()))
+                .times(1)
+                .return_const(Ok(quiz.clone()));
+
+            let cmd = quiz_commands::CheckQuizAnswerCommand::new(quiz.id(), "answer".to_string());
+            let res = quiz_usecases::check_quiz_answer(Arc::new(mock_repo_ok), cmd).await;
+            assert!(res.is_ok());
+        }
+        {
+            // 回答が違う場合
+            let mut mock_repo_err = MockQuizRepository::new();
+            mock_repo_err
+                .expect_find_by_id()
+                .times(1)
+                .return_const(Ok(quiz.clone()));
+
+            let cmd =
+                quiz_commands::CheckQuizAnswerCommand::new(quiz.id(), "wrong answer".to_string());
+            let res = quiz_usecases::check_quiz_answer(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res, Err(AppCommonError::IncorrectAnswerError)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_and_edit_quiz_usecase() {
+        let quiz = Faker.fake::<Quiz>();
+
+        let mut mock_repo = MockQuizRepository::new();
+        mock_repo
+            .expect_save()
+            .with(predicate::eq(quiz.clone()))
+            .times(1)
+            .return_const(Ok(()));
+        mock_repo
+            .expect_edit()
+            .with(predicate::eq(quiz.clone()))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = quiz_commands::SaveQuizCommand::new(quiz.clone());
+        let res_ok = quiz_usecases::save_quiz(Arc::clone(&mock_repo), cmd).await;
+        assert!(res_ok.is_ok());
+
+        let cmd = quiz_commands::EditQuizCommand::new(quiz);
+        let res_err = quiz_usecases::edit_quiz(mock_repo, cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_all_quizzes_usecase() {
+        let quizzes = (0..10).map(|_| Faker.fake::<Quiz>()).collect::<Vec<_>>();
+
+        let mut mock_repo = MockQuizRepository::new();
+        mock_repo
+            .expect_all()
+            .times(1)
+            .return_const(Ok(quizzes.clone()));
+
+        let cmd = quiz_commands::AllQuizzesCommand;
+        let res_quizzes = quiz_usecases::all_quizzes(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res_quizzes, quizzes);
+    }
+
+    #[tokio::test]
+    async fn test_remove_quiz_usecase() {
+        let quiz_id = QuizId::generate();
+
+        let mut mock_repo_err = MockQuizRepository::new();
+        mock_repo_err
+            .expect_remove()
+            .with(predicate::eq(quiz_id))
+            .return_const(Err(InfraError::NoRecordError));
+
+        let cmd = quiz_commands::RemoveQuizCommand::new(quiz_id);
+        let res_err = quiz_usecases::remove_quiz(Arc::new(mock_repo_err), cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+}
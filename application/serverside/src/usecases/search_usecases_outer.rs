@@ -0,0 +1,174 @@
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub mod search_usecases {
+    use crate::commands::search_commands;
+    use common::AppCommonError;
+    use domain::search::{SearchHit, SearchItem};
+    use domain::video::{Kirinuki, Original};
+    use domain::{EpisodeRepository, MovieClipRepository, VideoRepository};
+    use infrastructure::InfraError;
+    use std::sync::Arc;
+
+    /// クリップ・エピソード・動画をまとめて検索し，スコアの降順でlength分取得する．
+    pub(crate) async fn search<M, E, O, K>(
+        movie_clip_repo: Arc<M>,
+        episode_repo: Arc<E>,
+        original_repo: Arc<O>,
+        kirinuki_repo: Arc<K>,
+        cmd: search_commands::SearchCommand,
+    ) -> Result<Vec<SearchHit<SearchItem>>, AppCommonError>
+    where
+        M: MovieClipRepository<Error = InfraError> + 'static,
+        E: EpisodeRepository<Error = InfraError> + 'static,
+        O: VideoRepository<Original, Error = InfraError> + 'static,
+        K: VideoRepository<Kirinuki, Error = InfraError> + 'static,
+    {
+        let length = cmd.length.get();
+
+        // 種類ごとにlength分取得してからまとめる
+        let mut hits = Vec::new();
+        hits.extend(
+            movie_clip_repo
+                .search(&cmd.query, length)
+                .await?
+                .into_iter()
+                .map(|hit| hit.map(SearchItem::from)),
+        );
+        hits.extend(
+            original_repo
+                .search(&cmd.query, length)
+                .await?
+                .into_iter()
+                .map(|hit| hit.map(SearchItem::from)),
+        );
+        hits.extend(
+            kirinuki_repo
+                .search(&cmd.query, length)
+                .await?
+                .into_iter()
+                .map(|hit| hit.map(SearchItem::from)),
+        );
+        hits.extend(
+            episode_repo
+                .search(&cmd.query, length)
+                .await?
+                .into_iter()
+                .map(|hit| hit.map(SearchItem::from)),
+        );
+
+        // スコアが同じ場合は種類の順番を保つ
+        hits.sort_by(|x, y| y.score.total_cmp(&x.score));
+        hits.truncate(length);
+        Ok(hits)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::search_usecases;
+    use crate::commands::search_commands;
+    use common::AppCommonError;
+    use domain::episode::Episode;
+    use domain::movie_clip::MovieClip;
+    use domain::search::{SearchHit, SearchItem, SearchQuery};
+    use domain::video::{Kirinuki, Original, Video};
+    use domain::PageSize;
+    use infrastructure::episode_repository_impl::MockEpisodeRepository;
+    use infrastructure::movie_clip_repository_impl::MockMovieClipRepository;
+    use infrastructure::video_repository_impl::{
+        MockVideoKirinukiRepository, MockVideoOriginalRepository,
+    };
+    use infrastructure::InfraError;
+
+    use fake::{Fake, Faker};
+    use mockall::predicate;
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_search_usecase() {
+        let query = SearchQuery::new("歌枠").unwrap();
+        let length = 3_usize;
+
+        let clip_hits = vec![
+            SearchHit::new(Faker.fake::<MovieClip>(), 0.9),
+            SearchHit::new(Faker.fake::<MovieClip>(), 0.2),
+        ];
+        let original_hits = vec![SearchHit::new(Faker.fake::<Video<Original>>(), 0.5)];
+        let kirinuki_hits = vec![SearchHit::new(Faker.fake::<Video<Kirinuki>>(), 0.1)];
+        let episode_hits = vec![SearchHit::new(Faker.fake::<Episode>(), 0.7)];
+
+        let mut mock_movie_clip_repo = MockMovieClipRepository::new();
+        mock_movie_clip_repo
+            .expect_search()
+            .with(predicate::eq(query.clone()), predicate::eq(length))
+            .times(1)
+            .return_const(Ok(clip_hits.clone()));
+
+        let mut mock_episode_repo = MockEpisodeRepository::new();
+        mock_episode_repo
+            .expect_search()
+            .with(predicate::eq(query.clone()), predicate::eq(length))
+            .times(1)
+            .return_const(Ok(episode_hits.clone()));
+
+        let mut mock_original_repo = MockVideoOriginalRepository::new();
+        mock_original_repo
+            .expect_search()
+            .with(predicate::eq(query.clone()), predicate::eq(length))
+            .times(1)
+            .return_const(Ok(original_hits.clone()));
+
+        let mut mock_kirinuki_repo = MockVideoKirinukiRepository::new();
+        mock_kirinuki_repo
+            .expect_search()
+            .with(predicate::eq(query.clone()), predicate::eq(length))
+            .times(1)
+            .return_const(Ok(kirinuki_hits));
+
+        let cmd = search_commands::SearchCommand::new(query, PageSize::new(length as i64).unwrap());
+        let res = search_usecases::search(
+            Arc::new(mock_movie_clip_repo),
+            Arc::new(mock_episode_repo),
+            Arc::new(mock_original_repo),
+            Arc::new(mock_kirinuki_repo),
+            cmd,
+        )
+        .await
+        .unwrap();
+
+        // 種類に関わらずスコアの降順にlength分
+        assert_eq!(
+            res,
+            vec![
+                clip_hits[0].clone().map(SearchItem::from),
+                episode_hits[0].clone().map(SearchItem::from),
+                original_hits[0].clone().map(SearchItem::from),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_usecase_error() {
+        let query = SearchQuery::new("歌枠").unwrap();
+
+        let mut mock_movie_clip_repo = MockMovieClipRepository::new();
+        mock_movie_clip_repo
+            .expect_search()
+            .times(1)
+            .return_const(Err(InfraError::OtherSQLXError("error".to_string())));
+
+        let cmd = search_commands::SearchCommand::new(query, PageSize::default());
+        let res = search_usecases::search(
+            Arc::new(mock_movie_clip_repo),
+            Arc::new(MockEpisodeRepository::new()),
+            Arc::new(MockVideoOriginalRepository::new()),
+            Arc::new(MockVideoKirinukiRepository::new()),
+            cmd,
+        )
+        .await;
+        assert!(matches!(res, Err(AppCommonError::OtherSQLXError(_))));
+    }
+}
@@ -0,0 +1,210 @@
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+pub mod user_usecases {
+    use crate::auth::{hash_password, verify_password};
+    use crate::commands::user_commands;
+    use common::AppCommonError;
+    use domain::{user::User, UserRepository};
+    use infrastructure::InfraError;
+    use std::sync::Arc;
+
+    /// ユーザー名とパスワードを検証する．一致しない場合は`UnauthorizedError`を返す．
+    pub(crate) async fn authenticate_user<T>(
+        repo: Arc<T>,
+        cmd: user_commands::AuthenticateUserCommand,
+    ) -> Result<User, AppCommonError>
+    where
+        T: UserRepository<Error = InfraError> + 'static,
+    {
+        let user = repo
+            .find_by_name(&cmd.name)
+            .await?
+            .ok_or(AppCommonError::UnauthorizedError)?;
+
+        if !verify_password(user.password_hash(), &cmd.password) {
+            return Err(AppCommonError::UnauthorizedError);
+        }
+        Ok(user)
+    }
+
+    /// パスワードをハッシュ化してユーザーを保存する．
+    pub(crate) async fn save_user<T>(
+        repo: Arc<T>,
+        cmd: user_commands::SaveUserCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: UserRepository<Error = InfraError> + 'static,
+    {
+        let password_hash = hash_password(&cmd.password)
+            .map_err(|e| AppCommonError::DomainError(format!("Cannot hash password: {e}")))?;
+        let user = User::new(cmd.name, password_hash, cmd.role)?;
+        repo.save(user).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn all_users<T>(
+        repo: Arc<T>,
+        _cmd: user_commands::AllUsersCommand,
+    ) -> Result<Vec<User>, AppCommonError>
+    where
+        T: UserRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.all().await?)
+    }
+
+    pub(crate) async fn remove_user<T>(
+        repo: Arc<T>,
+        cmd: user_commands::RemoveUserCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: UserRepository<Error = InfraError> + 'static,
+    {
+        Ok(repo.remove(cmd.id).await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::user_usecases;
+    use crate::auth::{hash_password, verify_password};
+    use crate::commands::user_commands;
+    use common::AppCommonError;
+    use domain::user::{Role, User, UserId};
+    use fake::{Fake, Faker};
+    use infrastructure::user_repository_impl::MockUserRepository;
+    use infrastructure::InfraError;
+    use mockall::predicate;
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_authenticate_user_usecase() {
+        let user = User::new(
+            "contributor".to_string(),
+            hash_password("password").unwrap(),
+            Role::Contributor,
+        )
+        .unwrap();
+
+        {
+            let mut mock_repo_ok = MockUserRepository::new();
+            mock_repo_ok
+                .expect_find_by_name()
+                .withf(|name| name == "contributor")
+                .times(1)
+                .return_const(Ok(Some(user.clone())));
+
+            let cmd = user_commands::AuthenticateUserCommand::new(
+                "contributor".to_string(),
+                "password".to_string(),
+            );
+            let res = user_usecases::authenticate_user(Arc::new(mock_repo_ok), cmd)
+                .await
+                .unwrap();
+            assert_eq!(res, user);
+        }
+        {
+            // パスワードが違う場合
+            let mut mock_repo_err = MockUserRepository::new();
+            mock_repo_err
+                .expect_find_by_name()
+                .times(1)
+                .return_const(Ok(Some(user.clone())));
+
+            let cmd = user_commands::AuthenticateUserCommand::new(
+                "contributor".to_string(),
+                "wrong password".to_string(),
+            );
+            let res = user_usecases::authenticate_user(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res, Err(AppCommonError::UnauthorizedError)));
+        }
+        {
+            // ユーザーが存在しない場合
+            let mut mock_repo_err = MockUserRepository::new();
+            mock_repo_err
+                .expect_find_by_name()
+                .times(1)
+                .return_const(Ok(None));
+
+            let cmd = user_commands::AuthenticateUserCommand::new(
+                "no_exists".to_string(),
+                "password".to_string(),
+            );
+            let res = user_usecases::authenticate_user(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res, Err(AppCommonError::UnauthorizedError)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_user_usecase() {
+        {
+            let mut mock_repo_ok = MockUserRepository::new();
+            mock_repo_ok
+                .expect_save()
+                .withf(|user| {
+                    user.name() == "contributor"
+                        && user.role() == Role::Contributor
+                        && verify_password(user.password_hash(), "password")
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd = user_commands::SaveUserCommand::new(
+                "contributor".to_string(),
+                "password".to_string(),
+                Role::Contributor,
+            );
+            let res_ok = user_usecases::save_user(Arc::new(mock_repo_ok), cmd).await;
+            assert!(res_ok.is_ok());
+        }
+        {
+            let mut mock_repo_err = MockUserRepository::new();
+            mock_repo_err
+                .expect_save()
+                .times(1)
+                .return_const(Err(InfraError::ConflictError));
+
+            let cmd = user_commands::SaveUserCommand::new(
+                "contributor".to_string(),
+                "password".to_string(),
+                Role::Contributor,
+            );
+            let res_err = user_usecases::save_user(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_all_users_usecase() {
+        let users = (0..10).map(|_| Faker.fake::<User>()).collect::<Vec<_>>();
+
+        let mut mock_repo = MockUserRepository::new();
+        mock_repo
+            .expect_all()
+            .times(1)
+            .return_const(Ok(users.clone()));
+
+        let cmd = user_commands::AllUsersCommand;
+        let res_users = user_usecases::all_users(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res_users, users);
+    }
+
+    #[tokio::test]
+    async fn test_remove_user_usecase() {
+        let user_id = UserId::generate();
+
+        let mut mock_repo_err = MockUserRepository::new();
+        mock_repo_err
+            .expect_remove()
+            .with(predicate::eq(user_id))
+            .return_const(Err(InfraError::NoRecordError));
+
+        let cmd = user_commands::RemoveUserCommand::new(user_id);
+        let res_err = user_usecases::remove_user(Arc::new(mock_repo_err), cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+}
@@ -4,35 +4,91 @@ use mockall::automock;
 #[cfg_attr(test, automock)]
 pub mod video_usecases {
     use crate::commands::video_commands;
-    use common::AppCommonError;
+    use common::{AppCommonError, NewVideoInfo, Page};
     use domain::{
-        video::{Video, VideoType},
-        VideoRepository,
+        revision::{FieldChange, Operation, Revision},
+        video::{Video, VideoId, VideoType},
+        ChannelRepository, Tag, VideoRepository,
     };
     use infrastructure::InfraError;
+    use std::collections::BTreeMap;
     use std::sync::Arc;
 
-    pub(crate) async fn save_video<T, V>(
+    /// id, likeをサーバー側で決定して動画を保存し，保存した動画を返す．投稿者名はチャンネルの表示名とする．
+    pub(crate) async fn save_video<T, C, V>(
         repo: Arc<T>,
-        cmd: video_commands::SaveVideoCommand<V>,
+        channel_repo: Arc<C>,
+        cmd: video_commands::SaveVideoCommand,
+    ) -> Result<Video<V>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        C: ChannelRepository<Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        let NewVideoInfo {
+            title,
+            url,
+            date,
+            channel_id,
+            duration,
+        } = cmd.video_info;
+        let channel = channel_repo.find_by_id(channel_id).await?;
+        let video =
+            Video::<V>::new_with_domains(title, url, date, &channel).with_duration(duration);
+        repo.save(video.clone(), cmd.actor).await?;
+        Ok(video)
+    }
+
+    /// 動画を編集する．投稿者名はチャンネルの表示名とする．
+    pub(crate) async fn edit_video<T, C, V>(
+        repo: Arc<T>,
+        channel_repo: Arc<C>,
+        cmd: video_commands::EditVideoCommand<V>,
     ) -> Result<(), AppCommonError>
     where
         T: VideoRepository<V, Error = InfraError> + 'static,
+        C: ChannelRepository<Error = InfraError> + 'static,
         V: VideoType + 'static,
     {
-        repo.save(cmd.video).await?;
+        let mut video = cmd.video;
+        let channel = channel_repo.find_by_id(video.channel_id()).await?;
+        video.set_channel(&channel);
+        repo.edit(video, cmd.actor).await?;
         Ok(())
     }
 
-    pub(crate) async fn edit_video<T, V>(
+    /// バックアップから復元するためにid, likeを保ったまま動画を保存する．投稿者名はチャンネルの表示名とする．
+    /// 一つでも保存できない動画がある場合は何も保存せずにエラーを返す．
+    pub(crate) async fn import_videos<T, C, V>(
         repo: Arc<T>,
-        cmd: video_commands::EditVideoCommand<V>,
+        channel_repo: Arc<C>,
+        cmd: video_commands::ImportVideosCommand<V>,
     ) -> Result<(), AppCommonError>
     where
         T: VideoRepository<V, Error = InfraError> + 'static,
+        C: ChannelRepository<Error = InfraError> + 'static,
         V: VideoType + 'static,
     {
-        repo.edit(cmd.video).await?;
+        let channels = channel_repo
+            .all()
+            .await?
+            .into_iter()
+            .map(|channel| (channel.id(), channel))
+            .collect::<BTreeMap<_, _>>();
+
+        let videos = cmd
+            .videos
+            .into_iter()
+            .map(|mut video| {
+                let channel = channels
+                    .get(&video.channel_id())
+                    .ok_or(AppCommonError::NoRecordError)?;
+                video.set_channel(channel);
+                Ok(video)
+            })
+            .collect::<Result<Vec<_>, AppCommonError>>()?;
+
+        repo.save_many(videos, cmd.actor).await?;
         Ok(())
     }
 
@@ -44,10 +100,34 @@ pub mod video_usecases {
         T: VideoRepository<V, Error = InfraError> + 'static,
         V: VideoType + 'static,
     {
-        repo.increment_like(cmd.id).await?;
+        repo.increment_like(cmd.id, cmd.client).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn unlike_video<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::UnlikeVideoCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        repo.unlike(cmd.id, cmd.client).await?;
         Ok(())
     }
 
+    /// `ids`のうちクライアントがLikeしているVideo<V>のidを取得する．
+    pub(crate) async fn liked_video_ids<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::LikedVideoIdsCommand,
+    ) -> Result<Vec<VideoId>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        Ok(repo.liked_ids(&cmd.ids, cmd.client).await?)
+    }
+
     pub(crate) async fn all_videos<T, V>(
         repo: Arc<T>,
         _cmd: video_commands::AllVideosCommand,
@@ -63,49 +143,138 @@ pub mod video_usecases {
     pub(crate) async fn order_by_like_videos<T, V>(
         repo: Arc<T>,
         cmd: video_commands::OrderByLikeVideosCommand,
-    ) -> Result<Vec<Video<V>>, AppCommonError>
+    ) -> Result<Page<Video<V>>, AppCommonError>
     where
         T: VideoRepository<V, Error = InfraError> + 'static,
         V: VideoType + 'static,
     {
-        let videos = repo.order_by_like(cmd.length).await?;
-        Ok(videos)
+        let videos = match cmd.tags.is_empty() {
+            true => repo.order_by_like(cmd.length.lookahead()).await?,
+            false => {
+                repo.order_by_like_with_tags(&cmd.tags, cmd.length.lookahead())
+                    .await?
+            }
+        };
+        Ok(Page::from_lookahead(videos, cmd.length))
     }
 
     pub(crate) async fn order_by_like_later_videos<T, V>(
         repo: Arc<T>,
-        cmd: video_commands::OrderByLikeLaterVideosCommand<V>,
-    ) -> Result<Vec<Video<V>>, AppCommonError>
+        cmd: video_commands::OrderByLikeLaterVideosCommand,
+    ) -> Result<Page<Video<V>>, AppCommonError>
     where
         T: VideoRepository<V, Error = InfraError> + 'static,
         V: VideoType + 'static,
     {
-        let videos = repo.order_by_like_later(&cmd.reference, cmd.length).await?;
-        Ok(videos)
+        let videos = match cmd.tags.is_empty() {
+            true => {
+                repo.order_by_like_later(&cmd.cursor, cmd.length.lookahead())
+                    .await?
+            }
+            false => {
+                repo.order_by_like_later_with_tags(&cmd.tags, &cmd.cursor, cmd.length.lookahead())
+                    .await?
+            }
+        };
+        Ok(Page::from_lookahead(videos, cmd.length))
     }
 
     pub(crate) async fn order_by_date_videos<T, V>(
         repo: Arc<T>,
         cmd: video_commands::OrderByDateVideosCommand,
-    ) -> Result<Vec<Video<V>>, AppCommonError>
+    ) -> Result<Page<Video<V>>, AppCommonError>
     where
         T: VideoRepository<V, Error = InfraError> + 'static,
         V: VideoType + 'static,
     {
-        let videos = repo.order_by_date(cmd.length).await?;
-        Ok(videos)
+        let videos = match cmd.tags.is_empty() {
+            true => repo.order_by_date(cmd.length.lookahead()).await?,
+            false => {
+                repo.order_by_date_with_tags(&cmd.tags, cmd.length.lookahead())
+                    .await?
+            }
+        };
+        Ok(Page::from_lookahead(videos, cmd.length))
     }
 
     pub(crate) async fn order_by_date_later_videos<T, V>(
         repo: Arc<T>,
-        cmd: video_commands::OrderByDateLaterVideosCommand<V>,
+        cmd: video_commands::OrderByDateLaterVideosCommand,
+    ) -> Result<Page<Video<V>>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        let videos = match cmd.tags.is_empty() {
+            true => {
+                repo.order_by_date_later(&cmd.cursor, cmd.length.lookahead())
+                    .await?
+            }
+            false => {
+                repo.order_by_date_later_with_tags(&cmd.tags, &cmd.cursor, cmd.length.lookahead())
+                    .await?
+            }
+        };
+        Ok(Page::from_lookahead(videos, cmd.length))
+    }
+
+    /// `channel`が投稿したVideo<V>をdateの降順で全て取得する．
+    pub(crate) async fn channel_videos<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::ChannelVideosCommand,
     ) -> Result<Vec<Video<V>>, AppCommonError>
     where
         T: VideoRepository<V, Error = InfraError> + 'static,
         V: VideoType + 'static,
     {
-        let videos = repo.order_by_date_later(&cmd.reference, cmd.length).await?;
-        Ok(videos)
+        Ok(repo.order_by_date_with_channel(cmd.channel).await?)
+    }
+
+    /// Video<V>に付けられている全てのタグを取得する．
+    pub(crate) async fn all_video_tags<T, V>(
+        repo: Arc<T>,
+        _cmd: video_commands::AllVideoTagsCommand,
+    ) -> Result<Vec<Tag>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        Ok(repo.all_tags().await?)
+    }
+
+    pub(crate) async fn video_tags<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::VideoTagsCommand,
+    ) -> Result<Vec<Tag>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        Ok(repo.tags(cmd.id).await?)
+    }
+
+    pub(crate) async fn add_video_tag<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::AddVideoTagCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        repo.add_tag(cmd.id, cmd.tag).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn remove_video_tag<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::RemoveVideoTagCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        repo.remove_tag(cmd.id, cmd.tag).await?;
+        Ok(())
     }
 
     pub(crate) async fn remove_video<T, V>(
@@ -116,18 +285,135 @@ pub mod video_usecases {
         T: VideoRepository<V, Error = InfraError> + 'static,
         V: VideoType + 'static,
     {
-        repo.remove(cmd.id).await?;
+        repo.remove(cmd.id, cmd.actor).await?;
         Ok(())
     }
+
+    /// ゴミ箱の動画を削除した日時の新しい順に取得する．
+    pub(crate) async fn trashed_videos<T, V>(
+        repo: Arc<T>,
+        _cmd: video_commands::TrashedVideosCommand,
+    ) -> Result<Vec<Video<V>>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        Ok(repo.trashed().await?)
+    }
+
+    /// ゴミ箱の動画を戻す．
+    pub(crate) async fn restore_trashed_video<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::RestoreTrashedVideoCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        Ok(repo.restore(cmd.id, cmd.actor).await?)
+    }
+
+    /// ゴミ箱の動画を完全に削除する．
+    pub(crate) async fn purge_video<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::PurgeVideoCommand,
+    ) -> Result<(), AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        Ok(repo.purge(cmd.id, cmd.actor).await?)
+    }
+
+    /// 動画の編集履歴を古い順に取得する．
+    pub(crate) async fn video_revisions<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::VideoRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        Ok(repo.revisions(cmd.id).await?)
+    }
+
+    /// 動画の編集履歴を新しい順に取得する．
+    pub(crate) async fn latest_video_revisions<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::LatestVideoRevisionsCommand,
+    ) -> Result<Vec<Revision>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        Ok(repo.latest_revisions(cmd.length.get()).await?)
+    }
+
+    /// 二つの編集履歴の間の変更を取得する．
+    pub(crate) async fn video_revision_diff<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::VideoRevisionDiffCommand,
+    ) -> Result<Vec<FieldChange>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        let from = repo.revision(cmd.from).await?;
+        let to = repo.revision(cmd.to).await?;
+        Ok(from.diff(&to)?)
+    }
+
+    /// 編集履歴の時点の内容に戻し，戻した動画を返す．ゴミ箱にある場合は戻してから編集し，完全に削除されている場合は保存し直す．
+    pub(crate) async fn restore_video_revision<T, V>(
+        repo: Arc<T>,
+        cmd: video_commands::RestoreVideoRevisionCommand,
+    ) -> Result<Video<V>, AppCommonError>
+    where
+        T: VideoRepository<V, Error = InfraError> + 'static,
+        V: VideoType + 'static,
+    {
+        let restored = repo.revision(cmd.id).await?.snapshot_as::<Video<V>>()?;
+        let latest = repo
+            .revisions(restored.id())
+            .await?
+            .pop()
+            .ok_or(AppCommonError::NoRecordError)?;
+
+        match latest.operation() {
+            Operation::Purge => {
+                repo.save(restored.clone(), cmd.actor).await?;
+                Ok(restored)
+            }
+            Operation::Save | Operation::Edit | Operation::Remove | Operation::Restore => {
+                if latest.operation() == Operation::Remove {
+                    repo.restore(restored.id(), cmd.actor).await?;
+                }
+                // 現在の版数に対する編集として戻す
+                let mut video = latest.snapshot_as::<Video<V>>()?;
+                video.assign(restored);
+                repo.edit(video.clone(), cmd.actor).await?;
+                video.increment_version();
+                Ok(video)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::video_usecases;
     use crate::commands::video_commands;
-    use common::AppCommonError;
-    use domain::video::{Original, Video};
-    use infrastructure::{video_repository_impl::MockVideoOriginalRepository, InfraError};
+    use common::{AppCommonError, NewVideoInfo};
+    use domain::channel::{Channel, ChannelId};
+    use domain::client::ClientId;
+    use domain::movie_clip::Second;
+    use domain::revision::{Actor, EntityKind, Operation, Revision, RevisionId};
+    use domain::video::{Original, Video, VideoId};
+    use domain::{PageSize, Tag};
+    use infrastructure::{
+        channel_repository_impl::MockChannelRepository,
+        video_repository_impl::MockVideoOriginalRepository, InfraError,
+    };
 
     use fake::{Fake, Faker};
     use mockall::predicate;
@@ -142,87 +428,219 @@ mod test {
             .collect::<Vec<_>>()
     }
 
+    /// `channel`のみを持つチャンネルのリポジトリのモック
+    fn mock_channel_repo(channel: &Channel) -> MockChannelRepository {
+        let mut mock_channel_repo = MockChannelRepository::new();
+        mock_channel_repo.expect_find_by_id().returning({
+            let channel = channel.clone();
+            move |id| match id == channel.id() {
+                true => Ok(channel.clone()),
+                false => Err(InfraError::NoRecordError),
+            }
+        });
+        mock_channel_repo
+            .expect_all()
+            .return_const(Ok(vec![channel.clone()]));
+        mock_channel_repo
+    }
+
     #[tokio::test]
     async fn test_save_video_usecase() {
-        let original = Faker.fake::<Video<Original>>();
+        let channel = Faker.fake::<Channel>();
+        let mut original = Faker
+            .fake::<Video<Original>>()
+            .with_duration(Some(Second::from_u32(600)));
+        original.set_channel(&channel);
+        let original_info = NewVideoInfo::from(original.clone());
 
+        // id, likeはサーバーで決定される
         let mut mock_repo_ok = MockVideoOriginalRepository::new();
         mock_repo_ok
             .expect_save()
-            .with(predicate::eq(original.clone()))
+            .withf({
+                let original = original.clone();
+                move |saved_video, actor| {
+                    *actor == Actor::Admin
+                        && saved_video.id() != original.id()
+                        && saved_video.like() == 0
+                        && saved_video.title() == original.title()
+                        && saved_video.url() == original.url()
+                        && saved_video.date() == original.date()
+                        && saved_video.author() == original.author()
+                        && saved_video.channel_id() == original.channel_id()
+                        && saved_video.duration() == original.duration()
+                }
+            })
             .times(1)
             .return_const(Ok(()));
 
-        let cmd = video_commands::SaveVideoCommand::new(original.clone());
-        let res_ok = video_usecases::save_video(Arc::new(mock_repo_ok), cmd).await;
-        assert!(matches!(res_ok, Ok(_)));
+        let cmd = video_commands::SaveVideoCommand::new(original_info.clone(), Actor::Admin);
+        let saved_video = video_usecases::save_video(
+            Arc::new(mock_repo_ok),
+            Arc::new(mock_channel_repo(&channel)),
+            cmd,
+        )
+        .await
+        .unwrap();
+        assert_eq!(saved_video.like(), 0);
+        assert_eq!(NewVideoInfo::from(saved_video), original_info);
 
         let mut mock_repo_err = MockVideoOriginalRepository::new();
         mock_repo_err
             .expect_save()
-            .with(predicate::eq(original.clone()))
             .times(1)
             .return_const(Err(InfraError::ConflictError));
 
-        let cmd = video_commands::SaveVideoCommand::new(original);
-        let res_err = video_usecases::save_video(Arc::new(mock_repo_err), cmd).await;
+        let cmd = video_commands::SaveVideoCommand::new(original_info.clone(), Actor::Admin);
+        let res_err = video_usecases::save_video(
+            Arc::new(mock_repo_err),
+            Arc::new(mock_channel_repo(&channel)),
+            cmd,
+        )
+        .await;
         assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
+
+        // 存在しないチャンネルの動画は保存しない
+        let mut mock_repo_no_channel = MockVideoOriginalRepository::new();
+        mock_repo_no_channel.expect_save().times(0);
+
+        let cmd = video_commands::SaveVideoCommand::new(original_info, Actor::Admin);
+        let res_err = video_usecases::save_video(
+            Arc::new(mock_repo_no_channel),
+            Arc::new(mock_channel_repo(&Faker.fake())),
+            cmd,
+        )
+        .await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
     }
 
     #[tokio::test]
     async fn test_edit_video_usecase() {
-        let original = Faker.fake::<Video<Original>>();
+        let channel = Faker.fake::<Channel>();
+        let mut original = Faker.fake::<Video<Original>>();
+        original.set_channel(&channel);
+
+        // 投稿者名はチャンネルの表示名に戻される
+        let edited = {
+            let mut video_json = serde_json::to_value(&original).unwrap();
+            video_json["author"] = "他の名前".into();
+            serde_json::from_value::<Video<Original>>(video_json).unwrap()
+        };
 
         let mut mock_repo_ok = MockVideoOriginalRepository::new();
         mock_repo_ok
             .expect_edit()
-            .with(predicate::eq(original.clone()))
+            .with(predicate::eq(original.clone()), predicate::eq(Actor::Admin))
             .times(1)
             .return_const(Ok(()));
 
-        let cmd = video_commands::EditVideoCommand::new(original.clone());
-        let res_ok = video_usecases::edit_video(Arc::new(mock_repo_ok), cmd).await;
-        assert!(matches!(res_ok, Ok(_)));
+        let cmd = video_commands::EditVideoCommand::new(edited, Actor::Admin);
+        let res_ok = video_usecases::edit_video(
+            Arc::new(mock_repo_ok),
+            Arc::new(mock_channel_repo(&channel)),
+            cmd,
+        )
+        .await;
+        assert!(res_ok.is_ok());
 
         let mut mock_repo_err = MockVideoOriginalRepository::new();
         mock_repo_err
             .expect_edit()
-            .with(predicate::eq(original.clone()))
+            .with(predicate::eq(original.clone()), predicate::eq(Actor::Admin))
             .times(1)
             .return_const(Err(InfraError::NoRecordError));
 
-        let cmd = video_commands::EditVideoCommand::new(original);
-        let res_err = video_usecases::edit_video(Arc::new(mock_repo_err), cmd).await;
+        let cmd = video_commands::EditVideoCommand::new(original.clone(), Actor::Admin);
+        let res_err = video_usecases::edit_video(
+            Arc::new(mock_repo_err),
+            Arc::new(mock_channel_repo(&channel)),
+            cmd,
+        )
+        .await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+
+        // 存在しないチャンネルの動画は編集しない
+        let mut mock_repo_no_channel = MockVideoOriginalRepository::new();
+        mock_repo_no_channel.expect_edit().times(0);
+
+        let cmd = video_commands::EditVideoCommand::new(original, Actor::Admin);
+        let res_err = video_usecases::edit_video(
+            Arc::new(mock_repo_no_channel),
+            Arc::new(mock_channel_repo(&Faker.fake())),
+            cmd,
+        )
+        .await;
         assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
     }
 
     #[tokio::test]
     async fn test_increment_like_video_usecase() {
         let original = Faker.fake::<Video<Original>>();
+        let client = ClientId::generate();
 
         let mut mock_repo_ok = MockVideoOriginalRepository::new();
         mock_repo_ok
             .expect_increment_like()
-            .with(predicate::eq(original.id()))
+            .with(predicate::eq(original.id()), predicate::eq(client))
             .times(1)
             .return_const(Ok(()));
 
-        let cmd = video_commands::IncrementLikeVideoCommand::new(original.id());
+        let cmd = video_commands::IncrementLikeVideoCommand::new(original.id(), client);
         let res_ok = video_usecases::increment_like_video(Arc::new(mock_repo_ok), cmd).await;
-        assert!(matches!(res_ok, Ok(_)));
+        assert!(res_ok.is_ok());
 
         let mut mock_repo_err = MockVideoOriginalRepository::new();
         mock_repo_err
             .expect_increment_like()
-            .with(predicate::eq(original.id()))
+            .with(predicate::eq(original.id()), predicate::eq(client))
             .times(1)
             .return_const(Err(InfraError::NoRecordError));
 
-        let cmd = video_commands::IncrementLikeVideoCommand::new(original.id());
+        let cmd = video_commands::IncrementLikeVideoCommand::new(original.id(), client);
         let res_err = video_usecases::increment_like_video(Arc::new(mock_repo_err), cmd).await;
         assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
     }
 
+    #[tokio::test]
+    async fn test_unlike_video_usecase() {
+        let original = Faker.fake::<Video<Original>>();
+        let client = ClientId::generate();
+
+        let mut mock_repo_err = MockVideoOriginalRepository::new();
+        mock_repo_err
+            .expect_unlike()
+            .with(predicate::eq(original.id()), predicate::eq(client))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+
+        let cmd = video_commands::UnlikeVideoCommand::new(original.id(), client);
+        let res_err = video_usecases::unlike_video(Arc::new(mock_repo_err), cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_liked_video_ids_usecase() {
+        let ids = (0..10).map(|_| VideoId::generate()).collect::<Vec<_>>();
+        let liked_ids = ids[..5].to_vec();
+        let client = ClientId::generate();
+
+        let mut mock_repo = MockVideoOriginalRepository::new();
+        mock_repo
+            .expect_liked_ids()
+            .withf({
+                let ids = ids.clone();
+                move |arg_ids, arg_client| arg_ids == ids && *arg_client == client
+            })
+            .times(1)
+            .return_const(Ok(liked_ids.clone()));
+
+        let cmd = video_commands::LikedVideoIdsCommand::new(ids, client);
+        let res = video_usecases::liked_video_ids(Arc::new(mock_repo), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, liked_ids);
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_all_video_usecase(originals: Vec<Video<Original>>) {
@@ -237,86 +655,187 @@ mod test {
         assert_eq!(res_ok.unwrap(), originals);
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_videos_usecase(originals: Vec<Video<Original>>) {
+        let channel = ChannelId::generate();
+
+        let mut mock_repo_ok = MockVideoOriginalRepository::new();
+        mock_repo_ok
+            .expect_order_by_date_with_channel()
+            .with(predicate::eq(channel))
+            .times(1)
+            .return_const(Ok(originals.clone()));
+
+        let cmd = video_commands::ChannelVideosCommand::new(channel);
+        let res_ok = video_usecases::channel_videos(Arc::new(mock_repo_ok), cmd).await;
+        assert_eq!(res_ok.unwrap(), originals);
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_order_by_like_video_usecase(originals: Vec<Video<Original>>) {
-        let length = 100_usize;
+        let length = PageSize::new(50).unwrap();
 
         let mut mock_repo_ok = MockVideoOriginalRepository::new();
         mock_repo_ok
             .expect_order_by_like()
-            .with(predicate::eq(length))
+            .with(predicate::eq(length.lookahead()))
             .times(1)
             .return_const(Ok(originals.clone()));
 
-        let cmd = video_commands::OrderByLikeVideosCommand::new(length);
+        let cmd = video_commands::OrderByLikeVideosCommand::new(vec![], length);
         let res_ok = video_usecases::order_by_like_videos(Arc::new(mock_repo_ok), cmd).await;
-        assert_eq!(res_ok.unwrap(), originals);
+        let page = res_ok.unwrap();
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, originals[..length.get()]);
+        assert!(page.has_more);
     }
 
     #[rstest]
     #[tokio::test]
     async fn test_order_by_like_later_video_usecase(originals: Vec<Video<Original>>) {
-        let reference = Faker.fake::<Video<Original>>();
+        let cursor = Faker.fake::<Video<Original>>().like_cursor();
 
-        let length = 100_usize;
+        let length = PageSize::new(50).unwrap();
 
         let mut mock_repo_ok = MockVideoOriginalRepository::new();
         mock_repo_ok
             .expect_order_by_like_later()
             .withf({
-                let reference = reference.clone();
-                move |arg_reference, arg_length| {
-                    *arg_reference == reference && *arg_length == length
+                move |arg_cursor, arg_length| {
+                    *arg_cursor == cursor && *arg_length == length.lookahead()
                 }
             })
             .times(1)
             .return_const(Ok(originals.clone()));
 
-        let cmd = video_commands::OrderByLikeLaterVideosCommand::new(reference, length);
+        let cmd = video_commands::OrderByLikeLaterVideosCommand::new(vec![], cursor, length);
         let res_ok = video_usecases::order_by_like_later_videos(Arc::new(mock_repo_ok), cmd).await;
-        assert_eq!(res_ok.unwrap(), originals);
+        let page = res_ok.unwrap();
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, originals[..length.get()]);
+        assert!(page.has_more);
     }
 
     #[rstest]
     #[tokio::test]
     async fn test_order_by_date_video_usecase(originals: Vec<Video<Original>>) {
-        let length = 100_usize;
+        let length = PageSize::new(50).unwrap();
 
         let mut mock_repo_ok = MockVideoOriginalRepository::new();
         mock_repo_ok
             .expect_order_by_date()
-            .with(predicate::eq(length))
+            .with(predicate::eq(length.lookahead()))
             .times(1)
             .return_const(Ok(originals.clone()));
 
-        let cmd = video_commands::OrderByDateVideosCommand::new(length);
+        let cmd = video_commands::OrderByDateVideosCommand::new(vec![], length);
         let res_ok = video_usecases::order_by_date_videos(Arc::new(mock_repo_ok), cmd).await;
-        assert_eq!(res_ok.unwrap(), originals);
+        let page = res_ok.unwrap();
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, originals[..length.get()]);
+        assert!(page.has_more);
     }
 
     #[rstest]
     #[tokio::test]
     async fn test_order_by_date_later_video_usecase(originals: Vec<Video<Original>>) {
-        let reference = Faker.fake::<Video<Original>>();
+        let cursor = Faker.fake::<Video<Original>>().date_cursor();
 
-        let length = 100_usize;
+        let length = PageSize::new(50).unwrap();
 
         let mut mock_repo_ok = MockVideoOriginalRepository::new();
         mock_repo_ok
             .expect_order_by_date_later()
             .withf({
-                let reference = reference.clone();
-                move |arg_reference, arg_length| {
-                    *arg_reference == reference && *arg_length == length
+                move |arg_cursor, arg_length| {
+                    *arg_cursor == cursor && *arg_length == length.lookahead()
                 }
             })
             .times(1)
             .return_const(Ok(originals.clone()));
 
-        let cmd = video_commands::OrderByDateLaterVideosCommand::new(reference, length);
+        let cmd = video_commands::OrderByDateLaterVideosCommand::new(vec![], cursor, length);
         let res_ok = video_usecases::order_by_date_later_videos(Arc::new(mock_repo_ok), cmd).await;
-        assert_eq!(res_ok.unwrap(), originals);
+        let page = res_ok.unwrap();
+        // 一つ多く取得できた場合は続きが存在する
+        assert_eq!(page.items, originals[..length.get()]);
+        assert!(page.has_more);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_order_by_date_video_with_tags_usecase(originals: Vec<Video<Original>>) {
+        let tags = vec![Tag::new("singing").unwrap()];
+        let length = PageSize::new(50).unwrap();
+
+        let mut mock_repo_ok = MockVideoOriginalRepository::new();
+        // タグが与えられた場合はタグで絞り込む
+        mock_repo_ok.expect_order_by_date().never();
+        mock_repo_ok
+            .expect_order_by_date_with_tags()
+            .withf({
+                let tags = tags.clone();
+                move |arg_tags, arg_length| arg_tags == tags && *arg_length == length.lookahead()
+            })
+            .times(1)
+            .return_const(Ok(originals[..10].to_vec()));
+
+        let cmd = video_commands::OrderByDateVideosCommand::new(tags, length);
+        let page = video_usecases::order_by_date_videos(Arc::new(mock_repo_ok), cmd)
+            .await
+            .unwrap();
+        assert_eq!(page.items, originals[..10]);
+        assert!(!page.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_video_tags_usecase() {
+        let id = VideoId::generate();
+        let tag = Tag::new("singing").unwrap();
+
+        let mut mock_repo = MockVideoOriginalRepository::new();
+        mock_repo
+            .expect_all_tags()
+            .times(1)
+            .return_const(Ok(vec![tag.clone()]));
+        mock_repo
+            .expect_tags()
+            .with(predicate::eq(id))
+            .times(1)
+            .return_const(Ok(vec![tag.clone()]));
+        mock_repo
+            .expect_add_tag()
+            .with(predicate::eq(id), predicate::eq(tag.clone()))
+            .times(1)
+            .return_const(Ok(()));
+        mock_repo
+            .expect_remove_tag()
+            .with(predicate::eq(id), predicate::eq(tag.clone()))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = video_commands::AllVideoTagsCommand;
+        let res = video_usecases::all_video_tags(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, vec![tag.clone()]);
+
+        let cmd = video_commands::VideoTagsCommand::new(id);
+        let res = video_usecases::video_tags(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, vec![tag.clone()]);
+
+        let cmd = video_commands::AddVideoTagCommand::new(id, tag.clone());
+        let res = video_usecases::add_video_tag(mock_repo.clone(), cmd).await;
+        assert!(res.is_ok());
+
+        let cmd = video_commands::RemoveVideoTagCommand::new(id, tag);
+        let res = video_usecases::remove_video_tag(mock_repo, cmd).await;
+        assert!(matches!(res, Err(AppCommonError::NoRecordError)));
     }
 
     #[tokio::test]
@@ -326,23 +845,340 @@ mod test {
         let mut mock_repo_ok = MockVideoOriginalRepository::new();
         mock_repo_ok
             .expect_remove()
-            .with(predicate::eq(original.id()))
+            .with(predicate::eq(original.id()), predicate::eq(Actor::Admin))
             .times(1)
             .return_const(Ok(()));
 
-        let cmd = video_commands::RemoveVideoCommand::new(original.id());
+        let cmd = video_commands::RemoveVideoCommand::new(original.id(), Actor::Admin);
         let res_ok = video_usecases::remove_video(Arc::new(mock_repo_ok), cmd).await;
-        assert!(matches!(res_ok, Ok(_)));
+        assert!(res_ok.is_ok());
 
         let mut mock_repo_err = MockVideoOriginalRepository::new();
         mock_repo_err
             .expect_remove()
-            .with(predicate::eq(original.id()))
+            .with(predicate::eq(original.id()), predicate::eq(Actor::Admin))
             .times(1)
             .return_const(Err(InfraError::NoRecordError));
 
-        let cmd = video_commands::RemoveVideoCommand::new(original.id());
+        let cmd = video_commands::RemoveVideoCommand::new(original.id(), Actor::Admin);
         let res_err = video_usecases::remove_video(Arc::new(mock_repo_err), cmd).await;
         assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
     }
+
+    #[tokio::test]
+    async fn test_video_trash_usecase() {
+        let originals = (0..3)
+            .map(|_| Faker.fake::<Video<Original>>())
+            .collect::<Vec<_>>();
+        let id = originals[0].id();
+
+        let mut mock_repo = MockVideoOriginalRepository::new();
+        mock_repo
+            .expect_trashed()
+            .times(1)
+            .return_const(Ok(originals.clone()));
+        mock_repo
+            .expect_restore()
+            .with(predicate::eq(id), predicate::eq(Actor::Admin))
+            .times(1)
+            .return_const(Ok(()));
+        mock_repo
+            .expect_purge()
+            .with(predicate::eq(id), predicate::eq(Actor::Admin))
+            .times(1)
+            .return_const(Err(InfraError::NoRecordError));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = video_commands::TrashedVideosCommand;
+        let res = video_usecases::trashed_videos(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, originals);
+
+        let cmd = video_commands::RestoreTrashedVideoCommand::new(id, Actor::Admin);
+        let res = video_usecases::restore_trashed_video(mock_repo.clone(), cmd).await;
+        assert!(res.is_ok());
+
+        // ゴミ箱に無い場合
+        let cmd = video_commands::PurgeVideoCommand::new(id, Actor::Admin);
+        let res = video_usecases::purge_video(mock_repo, cmd).await;
+        assert!(matches!(res, Err(AppCommonError::NoRecordError)));
+    }
+
+    #[tokio::test]
+    async fn test_video_revisions_usecase() {
+        let original = Faker.fake::<Video<Original>>();
+        let revisions = (0..3).map(|_| Faker.fake::<Revision>()).collect::<Vec<_>>();
+        let length = PageSize::new(2).unwrap();
+
+        let mut mock_repo = MockVideoOriginalRepository::new();
+        mock_repo
+            .expect_revisions()
+            .with(predicate::eq(original.id()))
+            .times(1)
+            .return_const(Ok(revisions.clone()));
+        mock_repo
+            .expect_latest_revisions()
+            .with(predicate::eq(length.get()))
+            .times(1)
+            .return_const(Ok(revisions[1..].to_vec()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = video_commands::VideoRevisionsCommand::new(original.id());
+        let res = video_usecases::video_revisions(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, revisions);
+
+        let cmd = video_commands::LatestVideoRevisionsCommand::new(length);
+        let res = video_usecases::latest_video_revisions(mock_repo, cmd)
+            .await
+            .unwrap();
+        assert_eq!(res, revisions[1..].to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_restore_video_revision_usecase() {
+        let original = Faker.fake::<Video<Original>>();
+        let saved = Revision::new(
+            EntityKind::Original,
+            original.id().to_uuid(),
+            Operation::Save,
+            &original,
+            Actor::Admin,
+        )
+        .unwrap();
+        let mut edited_original = original.clone();
+        edited_original.assign(Faker.fake());
+        edited_original.increment_version();
+        let edited = Revision::new(
+            EntityKind::Original,
+            original.id().to_uuid(),
+            Operation::Edit,
+            &edited_original,
+            Actor::Anonymous,
+        )
+        .unwrap();
+        let removed = Revision::new(
+            EntityKind::Original,
+            original.id().to_uuid(),
+            Operation::Remove,
+            &edited_original,
+            Actor::Admin,
+        )
+        .unwrap();
+        let purged = Revision::new(
+            EntityKind::Original,
+            original.id().to_uuid(),
+            Operation::Purge,
+            &edited_original,
+            Actor::Admin,
+        )
+        .unwrap();
+
+        {
+            // 存在する場合は最新の版数に対する編集として戻す
+            let mut mock_repo = MockVideoOriginalRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(saved.id()))
+                .return_const(Ok(saved.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(original.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone()]));
+
+            let mut expected = edited_original.clone();
+            expected.assign(original.clone());
+            mock_repo
+                .expect_edit()
+                .with(predicate::eq(expected.clone()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd = video_commands::RestoreVideoRevisionCommand::new(saved.id(), Actor::Admin);
+            let restored = video_usecases::restore_video_revision(Arc::new(mock_repo), cmd)
+                .await
+                .unwrap();
+            expected.increment_version();
+            assert_eq!(restored, expected);
+        }
+        {
+            // ゴミ箱にある場合は戻してから最新の版数に対する編集として戻す
+            let mut mock_repo = MockVideoOriginalRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(saved.id()))
+                .return_const(Ok(saved.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(original.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone(), removed]));
+            mock_repo
+                .expect_restore()
+                .with(predicate::eq(original.id()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let mut expected = edited_original.clone();
+            expected.assign(original.clone());
+            mock_repo
+                .expect_edit()
+                .with(predicate::eq(expected.clone()), predicate::eq(Actor::Admin))
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd = video_commands::RestoreVideoRevisionCommand::new(saved.id(), Actor::Admin);
+            let restored = video_usecases::restore_video_revision(Arc::new(mock_repo), cmd)
+                .await
+                .unwrap();
+            expected.increment_version();
+            assert_eq!(restored, expected);
+        }
+        {
+            // 完全に削除されている場合は保存し直す
+            let mut mock_repo = MockVideoOriginalRepository::new();
+            mock_repo
+                .expect_revision()
+                .with(predicate::eq(edited.id()))
+                .return_const(Ok(edited.clone()));
+            mock_repo
+                .expect_revisions()
+                .with(predicate::eq(original.id()))
+                .return_const(Ok(vec![saved.clone(), edited.clone(), purged]));
+            mock_repo
+                .expect_save()
+                .with(
+                    predicate::eq(edited_original.clone()),
+                    predicate::eq(Actor::Admin),
+                )
+                .times(1)
+                .return_const(Ok(()));
+
+            let cmd = video_commands::RestoreVideoRevisionCommand::new(edited.id(), Actor::Admin);
+            let restored = video_usecases::restore_video_revision(Arc::new(mock_repo), cmd)
+                .await
+                .unwrap();
+            assert_eq!(restored, edited_original);
+        }
+        {
+            let mut mock_repo_err = MockVideoOriginalRepository::new();
+            mock_repo_err
+                .expect_revision()
+                .return_const(Err(InfraError::NoRecordError));
+
+            let cmd = video_commands::RestoreVideoRevisionCommand::new(
+                RevisionId::generate(),
+                Actor::Admin,
+            );
+            let res_err =
+                video_usecases::restore_video_revision(Arc::new(mock_repo_err), cmd).await;
+            assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_video_revision_diff_usecase() {
+        let original = Faker.fake::<Video<Original>>();
+        let from = Revision::new(
+            EntityKind::Original,
+            original.id().to_uuid(),
+            Operation::Save,
+            &original,
+            Actor::Admin,
+        )
+        .unwrap();
+        let other = Faker.fake::<Video<Original>>();
+        let to = Revision::new(
+            EntityKind::Original,
+            other.id().to_uuid(),
+            Operation::Save,
+            &other,
+            Actor::Admin,
+        )
+        .unwrap();
+
+        let mut mock_repo = MockVideoOriginalRepository::new();
+        mock_repo
+            .expect_revision()
+            .with(predicate::eq(from.id()))
+            .return_const(Ok(from.clone()));
+        mock_repo
+            .expect_revision()
+            .with(predicate::eq(to.id()))
+            .return_const(Ok(to.clone()));
+        let mock_repo = Arc::new(mock_repo);
+
+        let cmd = video_commands::VideoRevisionDiffCommand::new(from.id(), from.id());
+        let changes = video_usecases::video_revision_diff(mock_repo.clone(), cmd)
+            .await
+            .unwrap();
+        assert!(changes.is_empty());
+
+        // 異なるエンティティの編集履歴は比較できない
+        let cmd = video_commands::VideoRevisionDiffCommand::new(from.id(), to.id());
+        let res_err = video_usecases::video_revision_diff(mock_repo, cmd).await;
+        assert!(matches!(res_err, Err(AppCommonError::DomainError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_import_videos_usecase() {
+        let channel = Faker.fake::<Channel>();
+        let originals = (0..3)
+            .map(|_| {
+                let mut original = Faker.fake::<Video<Original>>();
+                original.set_channel(&channel);
+                original
+            })
+            .collect::<Vec<_>>();
+
+        // id, likeはそのまま保存される
+        let mut mock_repo_ok = MockVideoOriginalRepository::new();
+        mock_repo_ok
+            .expect_save_many()
+            .withf({
+                let originals = originals.clone();
+                move |saved_videos, actor| *actor == Actor::Admin && *saved_videos == originals
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let cmd = video_commands::ImportVideosCommand::new(originals.clone(), Actor::Admin);
+        let res = video_usecases::import_videos(
+            Arc::new(mock_repo_ok),
+            Arc::new(mock_channel_repo(&channel)),
+            cmd,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        // 保存できない動画がある場合はエラー
+        let mut mock_repo_err = MockVideoOriginalRepository::new();
+        mock_repo_err
+            .expect_save_many()
+            .times(1)
+            .return_const(Err(InfraError::ConflictError));
+
+        let cmd = video_commands::ImportVideosCommand::new(originals.clone(), Actor::Admin);
+        let res_err = video_usecases::import_videos(
+            Arc::new(mock_repo_err),
+            Arc::new(mock_channel_repo(&channel)),
+            cmd,
+        )
+        .await;
+        assert!(matches!(res_err, Err(AppCommonError::ConflictError)));
+
+        // 存在しないチャンネルの動画がある場合は何も保存しない
+        let mut mock_repo_no_channel = MockVideoOriginalRepository::new();
+        mock_repo_no_channel.expect_save_many().times(0);
+
+        let cmd = video_commands::ImportVideosCommand::new(originals, Actor::Admin);
+        let res_err = video_usecases::import_videos(
+            Arc::new(mock_repo_no_channel),
+            Arc::new(mock_channel_repo(&Faker.fake())),
+            cmd,
+        )
+        .await;
+        assert!(matches!(res_err, Err(AppCommonError::NoRecordError)));
+    }
 }
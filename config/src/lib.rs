@@ -1,17 +1,62 @@
+/// トークンバケットによるレート制限の予算
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitBudget {
+    /// バケットの容量．連続して受け付けられるリクエストの数
+    pub burst: u32,
+    /// 一分間に回復するトークンの数
+    pub per_minute: u32,
+}
+
+impl RateLimitBudget {
+    pub const fn new(burst: u32, per_minute: u32) -> Self {
+        Self { burst, per_minute }
+    }
+}
+
 pub struct Config {
     pub url_string_lim: usize,
+    pub tag_string_lim: usize,
+    pub channel_name_lim: usize,
+    pub search_query_lim: usize,
+    pub max_query_tags: usize,
     pub test_server_addr: &'static str,
     pub test_api_domain: &'static str,
     pub api_domain: &'static str,
+    pub session_max_age: u64,
+    pub quiz_challenge_max_age: u64,
+    pub edit_token_max_age: u64,
+    pub client_max_age: u64,
+    pub cursor_max_age: u64,
+    pub default_page_size: usize,
+    pub max_page_size: usize,
+    pub like_rate_limit: RateLimitBudget,
+    pub write_rate_limit: RateLimitBudget,
+    pub read_rate_limit: RateLimitBudget,
+    pub test_rate_limit: RateLimitBudget,
 }
 
 impl Config {
     const fn default() -> Self {
         Self {
             url_string_lim: 100,
+            tag_string_lim: 30,
+            channel_name_lim: 50,
+            search_query_lim: 100,
+            max_query_tags: 5,
             test_server_addr: "127.0.0.1:8000",
             test_api_domain: "http://127.0.0.1:8000/api",
             api_domain: "/api",
+            session_max_age: 60 * 60 * 24,
+            quiz_challenge_max_age: 60 * 10,
+            edit_token_max_age: 60 * 30,
+            client_max_age: 60 * 60 * 24 * 365,
+            cursor_max_age: 60 * 60 * 24,
+            default_page_size: 20,
+            max_page_size: 100,
+            like_rate_limit: RateLimitBudget::new(30, 30),
+            write_rate_limit: RateLimitBudget::new(20, 10),
+            read_rate_limit: RateLimitBudget::new(120, 240),
+            test_rate_limit: RateLimitBudget::new(100_000, 100_000),
         }
     }
 }
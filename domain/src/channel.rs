@@ -0,0 +1,267 @@
+mod channel_name;
+mod channel_url;
+
+pub use channel_name::ChannelName;
+pub use channel_url::{AvatarUrl, ChannelUrl};
+
+use crate::ids::Id;
+use crate::{field_path, DomainError, FromInput};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use sqlx::{postgres::PgRow, FromRow, Row};
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{Dummy, Fake, Faker};
+
+#[cfg(any(test, feature = "fake"))]
+use rand::Rng;
+
+// -------------------------------------------------------------------------------------------------
+// # ChannelId
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChannelIdType;
+
+/// Channelに対応するID
+pub type ChannelId = Id<ChannelIdType>;
+
+// -------------------------------------------------------------------------------------------------
+// # Channel
+
+/// 動画を投稿するチャンネルのエンティティ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Channel {
+    /// 表示名
+    name: ChannelName,
+    /// 動画プラットフォームのチャンネルのurl．投稿者名から移行したチャンネルでは未設定
+    #[serde(default)]
+    url: Option<ChannelUrl>,
+    /// アバター画像のurl
+    #[serde(default)]
+    avatar_url: Option<AvatarUrl>,
+    /// id
+    id: ChannelId,
+}
+
+impl Channel {
+    /// ドメイン固有型を用いたコンストラクタ
+    pub fn new(name: ChannelName, url: Option<ChannelUrl>, avatar_url: Option<AvatarUrl>) -> Self {
+        Self {
+            name,
+            url,
+            avatar_url,
+            id: ChannelId::generate(),
+        }
+    }
+    /// nameを取得
+    pub fn name(&self) -> &ChannelName {
+        &self.name
+    }
+    /// nameの可変参照を取得
+    pub fn name_mut(&mut self) -> &mut ChannelName {
+        &mut self.name
+    }
+    /// urlを取得
+    pub fn url(&self) -> Option<&ChannelUrl> {
+        self.url.as_ref()
+    }
+    /// urlの可変参照を取得
+    pub fn url_mut(&mut self) -> &mut Option<ChannelUrl> {
+        &mut self.url
+    }
+    /// avatar_urlを取得
+    pub fn avatar_url(&self) -> Option<&AvatarUrl> {
+        self.avatar_url.as_ref()
+    }
+    /// avatar_urlの可変参照を取得
+    pub fn avatar_url_mut(&mut self) -> &mut Option<AvatarUrl> {
+        &mut self.avatar_url
+    }
+    /// idを取得
+    pub fn id(&self) -> ChannelId {
+        self.id
+    }
+    /// idはそのままにotherをコピー
+    pub fn assign(&mut self, other: Self) {
+        let new_self = Self {
+            id: self.id,
+            ..other
+        };
+        *self = new_self;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # ChannelSummary
+
+/// 一覧表示のための，紐付いた動画の数を含むチャンネル
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelSummary {
+    /// チャンネル
+    channel: Channel,
+    /// 紐付いた動画(Original・Kirinuki)の数
+    video_count: u32,
+}
+
+impl ChannelSummary {
+    pub fn new(channel: Channel, video_count: u32) -> Self {
+        Self {
+            channel,
+            video_count,
+        }
+    }
+    /// channelを取得
+    pub fn channel(&self) -> &Channel {
+        &self.channel
+    }
+    /// video_countを取得
+    pub fn video_count(&self) -> u32 {
+        self.video_count
+    }
+}
+
+/// 検証前の`Channel`の入力．表示名・urlは`FromInput`で検証する
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChannelInput {
+    pub name: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+    pub id: ChannelId,
+}
+
+impl FromInput for Channel {
+    type Input = ChannelInput;
+    fn from_input(input: ChannelInput, field: &str) -> Result<Self, DomainError> {
+        Ok(Self {
+            name: ChannelName::from_input(input.name, &field_path(field, "name"))?,
+            url: Option::<ChannelUrl>::from_input(input.url, &field_path(field, "url"))?,
+            avatar_url: Option::<AvatarUrl>::from_input(
+                input.avatar_url,
+                &field_path(field, "avatar_url"),
+            )?,
+            id: input.id,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Channel as entity
+
+#[cfg(feature = "server")]
+impl FromRow<'_, PgRow> for Channel {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        use uuid::Uuid;
+
+        let name: String = row.try_get("name")?;
+        let url: Option<String> = row.try_get("url")?;
+        let avatar_url: Option<String> = row.try_get("avatar_url")?;
+        let id: Uuid = row.try_get("id")?;
+
+        Ok(Self {
+            name: name.try_into()?,
+            url: url.map(TryInto::try_into).transpose()?,
+            avatar_url: avatar_url.map(TryInto::try_into).transpose()?,
+            id: id.into(),
+        })
+    }
+}
+
+#[cfg(feature = "server")]
+impl FromRow<'_, PgRow> for ChannelSummary {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let video_count: i64 = row.try_get("video_count")?;
+
+        Ok(Self {
+            channel: Channel::from_row(row)?,
+            video_count: video_count as u32,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for Channel {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        Channel::new(
+            Faker.fake_with_rng(rng),
+            rng.gen_bool(0.8).then(|| Faker.fake_with_rng(rng)),
+            rng.gen_bool(0.5).then(|| Faker.fake_with_rng(rng)),
+        )
+    }
+}
+
+/// 与えたidをもつChannel
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<ChannelId> for Channel {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &ChannelId, rng: &mut R) -> Self {
+        Self {
+            id: *config,
+            ..Faker.fake_with_rng(rng)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Channel, ChannelName, ChannelSummary};
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assign() {
+        let mut channel = Faker.fake::<Channel>();
+        let previous_id = channel.id();
+
+        let other_channel = Faker.fake::<Channel>();
+        channel.assign(other_channel.clone());
+
+        assert_eq!(channel.id(), previous_id);
+        assert_eq!(channel.name(), other_channel.name());
+        assert_eq!(channel.url(), other_channel.url());
+        assert_eq!(channel.avatar_url(), other_channel.avatar_url());
+    }
+
+    #[test]
+    fn serialize_and_deserialize() {
+        let channel = Faker.fake::<Channel>();
+        let channel_json = serde_json::to_string(&channel).unwrap();
+        assert_eq!(
+            channel,
+            serde_json::from_str::<Channel>(&channel_json).unwrap()
+        );
+
+        // urlとavatar_urlは省略できる
+        let channel = Channel::new(ChannelName::new("りこう").unwrap(), None, None);
+        let channel_json = format!(
+            r#"{{"name":"りこう","id":{}}}"#,
+            serde_json::to_string(&channel.id()).unwrap()
+        );
+        assert_eq!(
+            channel,
+            serde_json::from_str::<Channel>(&channel_json).unwrap()
+        );
+
+        // 無効な名前はデシリアライズできない
+        let channel_json = channel_json.replace("りこう", " ");
+        assert!(serde_json::from_str::<Channel>(&channel_json).is_err());
+
+        let summary = ChannelSummary::new(Faker.fake(), 3);
+        let summary_json = serde_json::to_string(&summary).unwrap();
+        assert_eq!(
+            summary,
+            serde_json::from_str::<ChannelSummary>(&summary_json).unwrap()
+        );
+    }
+
+    #[test]
+    fn generate_fake() {
+        let _ = (0..1000)
+            .map(|_| Faker.fake::<Channel>())
+            .collect::<Vec<_>>();
+    }
+}
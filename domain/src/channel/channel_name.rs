@@ -0,0 +1,158 @@
+use crate::{DomainError, FromInput, ValidationCode, ValidationError};
+use config::CONFIG;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+use unicode_normalization::UnicodeNormalization;
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{faker::lorem::en::Words, Dummy, Fake, Faker};
+
+#[cfg(any(test, feature = "fake"))]
+use rand::Rng;
+
+/// チャンネルの表示名．NFKC正規化し，前後の空白を除いて連続する空白を一つにまとめた文字列を持つ．
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ChannelName(String);
+
+impl ChannelName {
+    pub fn new(name_str: &str) -> Result<Self, DomainError> {
+        name_str.parse()
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for ChannelName {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s
+            .nfkc()
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if normalized.is_empty() {
+            return Err(ValidationError::new(ValidationCode::Empty, "name").into());
+        }
+
+        if let Some(invalid_char) = normalized.chars().find(|c| c.is_control()) {
+            return Err(
+                ValidationError::new(ValidationCode::InvalidCharacter, "name")
+                    .with_param("character", invalid_char.escape_default())
+                    .into(),
+            );
+        }
+
+        if normalized.chars().count() > CONFIG.channel_name_lim {
+            return Err(ValidationError::new(ValidationCode::TooLong, "name")
+                .with_param("limit", CONFIG.channel_name_lim)
+                .into());
+        }
+
+        Ok(ChannelName(normalized))
+    }
+}
+
+impl TryFrom<String> for ChannelName {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl FromInput for ChannelName {
+    type Input = String;
+    fn from_input(input: String, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
+impl From<ChannelName> for String {
+    fn from(value: ChannelName) -> Self {
+        value.0
+    }
+}
+
+impl Display for ChannelName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+/// 重複しにくいように末尾に16進数の番号を付ける
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for ChannelName {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let words = Words(1..3).fake_with_rng::<Vec<String>, R>(rng).join(" ");
+        let number = rng.gen::<u32>();
+        format!("{words} {number:08x}")
+            .parse()
+            .expect("Generate fake error.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ChannelName;
+    use crate::{DomainError, ValidationCode, ValidationError};
+    use config::CONFIG;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn normalize_channel_name() {
+        assert_eq!(
+            ChannelName::new("  りこう　めいたん  ch ")
+                .unwrap()
+                .as_str(),
+            "りこう めいたん ch"
+        );
+        assert_eq!(ChannelName::new("ＡＢＣ").unwrap().as_str(), "ABC");
+        // 大文字・小文字は区別する
+        assert_ne!(
+            ChannelName::new("Abc").unwrap(),
+            ChannelName::new("abc").unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_channel_name() {
+        for (name_str, expected) in [
+            ("", ValidationError::new(ValidationCode::Empty, "name")),
+            ("　 ", ValidationError::new(ValidationCode::Empty, "name")),
+            (
+                "a\u{7}b",
+                ValidationError::new(ValidationCode::InvalidCharacter, "name")
+                    .with_param("character", "\\u{7}"),
+            ),
+            (
+                &"あ".repeat(CONFIG.channel_name_lim + 1),
+                ValidationError::new(ValidationCode::TooLong, "name")
+                    .with_param("limit", CONFIG.channel_name_lim),
+            ),
+        ] {
+            let Err(DomainError::DomainValidationError(validation_error)) =
+                ChannelName::new(name_str)
+            else {
+                panic!("`{name_str}` should be invalid.");
+            };
+            assert_eq!(validation_error, expected);
+        }
+
+        assert!(ChannelName::new(&"あ".repeat(CONFIG.channel_name_lim)).is_ok());
+    }
+
+    #[test]
+    fn generate_fake() {
+        use fake::{Fake, Faker};
+
+        let _ = (0..1000)
+            .map(|_| Faker.fake::<ChannelName>())
+            .collect::<Vec<_>>();
+    }
+}
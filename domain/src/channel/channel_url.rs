@@ -0,0 +1,266 @@
+use crate::movie_url::MOVIE_URL_ALLOW_PREFIX;
+use crate::{DomainError, FromInput, Platform, ValidationCode, ValidationError};
+use config::CONFIG;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+use url::Url;
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{faker::lorem::en::Word, Dummy, Fake, Faker};
+
+#[cfg(any(test, feature = "fake"))]
+use rand::{seq::SliceRandom, Rng};
+
+/// `field`のurlをパースし，長さとスキームがhttp(s)であることを確かめる．
+fn parse_http_url(s: &str, field: &str) -> Result<Url, DomainError> {
+    // 規定以上の長さの場合はエラーとなる
+    if s.len() > CONFIG.url_string_lim {
+        return Err(ValidationError::new(ValidationCode::TooLong, field)
+            .with_param("limit", CONFIG.url_string_lim)
+            .into());
+    }
+
+    let url = Url::parse(s.trim())
+        .map_err(|e| DomainError::DomainParseError(format!("`{s}` is not a valid url: {e}")))?;
+
+    match url.scheme() {
+        "https" | "http" => Ok(url),
+        _ => Err(
+            ValidationError::new(ValidationCode::InvalidUrlPrefix, field)
+                .with_param("allowed", "https://")
+                .into(),
+        ),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # ChannelUrl
+
+/// 動画プラットフォームのチャンネルのurl
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ChannelUrl {
+    platform: Platform,
+    url: Url,
+}
+
+impl ChannelUrl {
+    /// チャンネルのプラットフォーム
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
+}
+
+impl FromStr for ChannelUrl {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = parse_http_url(s, "url")?;
+
+        let Some(platform) = url.host_str().and_then(Platform::from_host) else {
+            return Err(
+                ValidationError::new(ValidationCode::InvalidUrlPrefix, "url")
+                    .with_param("allowed", MOVIE_URL_ALLOW_PREFIX.join(" "))
+                    .into(),
+            );
+        };
+
+        // ホームのurlはチャンネルを表さない
+        if url.path().trim_matches('/').is_empty() {
+            return Err(ValidationError::new(ValidationCode::Empty, "url").into());
+        }
+
+        Ok(ChannelUrl { platform, url })
+    }
+}
+
+impl TryFrom<String> for ChannelUrl {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for ChannelUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
+
+impl FromInput for ChannelUrl {
+    type Input = String;
+    fn from_input(input: String, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
+impl From<ChannelUrl> for String {
+    fn from(value: ChannelUrl) -> Self {
+        value.to_string()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # AvatarUrl
+
+/// チャンネルのアバター画像のurl．画像の配信元は問わない
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AvatarUrl(Url);
+
+impl FromStr for AvatarUrl {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(AvatarUrl(parse_http_url(s, "avatar_url")?))
+    }
+}
+
+impl TryFrom<String> for AvatarUrl {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for AvatarUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromInput for AvatarUrl {
+    type Input = String;
+    fn from_input(input: String, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
+impl From<AvatarUrl> for String {
+    fn from(value: AvatarUrl) -> Self {
+        value.to_string()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for ChannelUrl {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let prefixes = [
+            "https://www.youtube.com/@",
+            "https://www.twitch.tv/",
+            "https://www.nicovideo.jp/user/",
+            "https://x.com/",
+        ];
+        let prefix = prefixes.choose(rng).expect("Generate fake error.");
+        let name = Word().fake_with_rng::<String, R>(rng);
+        format!("{prefix}{name}")
+            .parse()
+            .expect("Generate fake error.")
+    }
+}
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for AvatarUrl {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let name = Word().fake_with_rng::<String, R>(rng);
+        format!("https://yt3.ggpht.com/{name}.jpg")
+            .parse()
+            .expect("Generate fake error.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AvatarUrl, ChannelUrl};
+    use crate::{DomainError, Platform, ValidationCode};
+    use config::CONFIG;
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_channel_url() {
+        for (url_str, platform) in [
+            ("https://www.youtube.com/@rikou", Platform::YouTube),
+            (
+                "https://www.youtube.com/channel/UCxxxxxxxx",
+                Platform::YouTube,
+            ),
+            ("https://www.twitch.tv/rikou", Platform::Twitch),
+            ("https://www.nicovideo.jp/user/12345", Platform::Niconico),
+            ("https://twitter.com/rikou", Platform::X),
+        ] {
+            let channel_url = url_str.parse::<ChannelUrl>().unwrap();
+            assert_eq!(channel_url.platform(), platform);
+            assert_eq!(channel_url.to_string(), url_str);
+        }
+    }
+
+    #[test]
+    fn parse_invalid_channel_url() {
+        let code = |url_str: &str| match url_str.parse::<ChannelUrl>() {
+            Err(DomainError::DomainValidationError(validation_error)) => {
+                Some(validation_error.code)
+            }
+            _ => None,
+        };
+        assert_eq!(
+            code("https://example.com/@rikou"),
+            Some(ValidationCode::InvalidUrlPrefix)
+        );
+        assert_eq!(
+            code("ftp://www.youtube.com/@rikou"),
+            Some(ValidationCode::InvalidUrlPrefix)
+        );
+        assert_eq!(
+            code("https://www.youtube.com/"),
+            Some(ValidationCode::Empty)
+        );
+        assert_eq!(
+            code(&format!(
+                "https://www.youtube.com/@{}",
+                "a".repeat(CONFIG.url_string_lim)
+            )),
+            Some(ValidationCode::TooLong)
+        );
+        assert!(matches!(
+            "rikou".parse::<ChannelUrl>(),
+            Err(DomainError::DomainParseError(_))
+        ));
+    }
+
+    #[test]
+    fn parse_avatar_url() {
+        let avatar_url = "https://yt3.ggpht.com/abc=s88"
+            .parse::<AvatarUrl>()
+            .unwrap();
+        assert_eq!(avatar_url.to_string(), "https://yt3.ggpht.com/abc=s88");
+
+        let Err(DomainError::DomainValidationError(validation_error)) =
+            "data:image/png;base64,AAAA".parse::<AvatarUrl>()
+        else {
+            panic!("data url should be invalid.");
+        };
+        assert_eq!(validation_error.code, ValidationCode::InvalidUrlPrefix);
+        assert_eq!(validation_error.field, "avatar_url");
+    }
+
+    #[test]
+    fn serialize_and_deserialize() {
+        let channel_url = Faker.fake::<ChannelUrl>();
+        let json_str = serde_json::to_string(&channel_url).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ChannelUrl>(&json_str).unwrap(),
+            channel_url
+        );
+
+        let avatar_url = Faker.fake::<AvatarUrl>();
+        let json_str = serde_json::to_string(&avatar_url).unwrap();
+        assert_eq!(
+            serde_json::from_str::<AvatarUrl>(&json_str).unwrap(),
+            avatar_url
+        );
+
+        assert!(serde_json::from_str::<ChannelUrl>(r#""https://example.com/a""#).is_err());
+    }
+}
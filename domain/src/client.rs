@@ -0,0 +1,10 @@
+use crate::ids::Id;
+
+// -------------------------------------------------------------------------------------------------
+// # ClientId
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ClientIdType;
+
+/// 匿名のクライアントに対応するID．署名付きのクッキーとして保持し，Likeの重複を防ぐために利用する．
+pub type ClientId = Id<ClientIdType>;
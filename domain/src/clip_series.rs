@@ -0,0 +1,267 @@
+use crate::cursor::Cursor;
+use crate::date::Date;
+use crate::ids::Id;
+use crate::movie_clip::MovieClipId;
+use crate::DomainError;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use sqlx::{postgres::PgRow, FromRow, Row};
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{faker::lorem::en::Words, Dummy, Fake, Faker};
+
+// -------------------------------------------------------------------------------------------------
+// # ClipSeriesId
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ClipSeriesIdType;
+
+/// ClipSeriesに対応するID
+pub type ClipSeriesId = Id<ClipSeriesIdType>;
+
+// -------------------------------------------------------------------------------------------------
+// # ClipSeries
+
+/// 続けて再生する一連のクリップのエンティティ
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClipSeries {
+    /// シリーズのタイトル
+    title: String,
+    /// シリーズの説明
+    description: String,
+    /// 再生する順番に並べたクリップのid
+    clips: Vec<MovieClipId>,
+    /// id
+    id: ClipSeriesId,
+    /// ライク(高評価の数)
+    like: u32,
+    /// シリーズの作成日
+    create_date: Date,
+}
+
+impl ClipSeries {
+    /// コンストラクタ．タイトルが空の場合・クリップが一つも無い場合はエラーとなる．
+    pub fn new(
+        title: String,
+        description: String,
+        clips: Vec<MovieClipId>,
+        create_date: Date,
+    ) -> Result<Self, DomainError> {
+        if title.trim().is_empty() {
+            return Err(DomainError::DomainLogicError(
+                "ClipSeries title must not be empty.".to_string(),
+            ));
+        }
+        if clips.is_empty() {
+            return Err(DomainError::DomainLogicError(
+                "ClipSeries must have at least one clip.".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            title,
+            description,
+            clips,
+            id: ClipSeriesId::generate(),
+            like: 0_u32,
+            create_date,
+        })
+    }
+    /// titleを取得
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+    /// titleの可変参照を取得
+    pub fn title_mut(&mut self) -> &mut String {
+        &mut self.title
+    }
+    /// descriptionを取得
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+    /// descriptionの可変参照を取得
+    pub fn description_mut(&mut self) -> &mut String {
+        &mut self.description
+    }
+    /// 再生順のクリップのidを取得
+    pub fn clips(&self) -> &[MovieClipId] {
+        &self.clips
+    }
+    /// 再生順のクリップのidの可変参照を取得
+    pub fn clips_mut(&mut self) -> &mut Vec<MovieClipId> {
+        &mut self.clips
+    }
+    /// `current`の次に再生するクリップのid．最後のクリップ・含まれないクリップの場合は`None`
+    pub fn next_clip(&self, current: MovieClipId) -> Option<MovieClipId> {
+        let position = self.clips.iter().position(|clip| *clip == current)?;
+        self.clips.get(position + 1).copied()
+    }
+    /// idを取得
+    pub fn id(&self) -> ClipSeriesId {
+        self.id
+    }
+    /// likeを取得
+    pub fn like(&self) -> u32 {
+        self.like
+    }
+    /// likeを一つ増やす
+    pub fn increment_like(&mut self) {
+        self.like += 1;
+    }
+    /// likeを一つ減らす．0の場合は何もしない
+    pub fn decrement_like(&mut self) {
+        self.like = self.like.saturating_sub(1);
+    }
+    /// create_dateを取得
+    pub fn create_date(&self) -> Date {
+        self.create_date
+    }
+    /// likeで並べたときの自身の位置を表すカーソル
+    pub fn like_cursor(&self) -> Cursor<u32, ClipSeriesId> {
+        Cursor::new(self.like, self.id)
+    }
+    /// create_dateで並べたときの自身の位置を表すカーソル
+    pub fn create_date_cursor(&self) -> Cursor<Date, ClipSeriesId> {
+        Cursor::new(self.create_date, self.id)
+    }
+    /// id, like, create_date はそのままにotherのフィールドを自身にコピー．
+    pub fn assign(&mut self, other: Self) {
+        let new_self = Self {
+            id: self.id(),
+            like: self.like(),
+            create_date: self.create_date(),
+            ..other
+        };
+        *self = new_self;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ClipSeries as entity
+
+#[cfg(feature = "server")]
+impl FromRow<'_, PgRow> for ClipSeries {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        use chrono::NaiveDate;
+        use uuid::Uuid;
+
+        let title: String = row.try_get("title")?;
+        let description: String = row.try_get("description")?;
+        let clips: Vec<Uuid> = row.try_get("clips")?;
+        let id: Uuid = row.try_get("id")?;
+        let like: i32 = row.try_get("like")?;
+        let create_date: NaiveDate = row.try_get("create_date")?;
+
+        Ok(Self {
+            title,
+            description,
+            clips: clips.into_iter().map(MovieClipId::from_uuid).collect(),
+            id: id.into(),
+            like: like as u32,
+            create_date: create_date.try_into()?,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for ClipSeries {
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let title = Words(2..10).fake_with_rng::<Vec<String>, R>(rng).join(" ");
+        let description = Words(0..50).fake_with_rng::<Vec<String>, R>(rng).join(" ");
+        let clips = (0..(1..10).fake_with_rng::<usize, R>(rng))
+            .map(|_| MovieClipId::generate())
+            .collect::<Vec<_>>();
+
+        let mut clip_series = Self::new(title, description, clips, Faker.fake_with_rng(rng))
+            .expect("Dummy ClipSeries must be valid.");
+
+        // like
+        let like_num = (0..1000).fake_with_rng::<usize, R>(rng);
+        for _ in 0..like_num {
+            clip_series.increment_like();
+        }
+
+        clip_series
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ClipSeries;
+    use crate::movie_clip::MovieClipId;
+    use crate::Date;
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn clip_series() {
+        let clips = vec![MovieClipId::generate(), MovieClipId::generate()];
+        let clip_series = ClipSeries::new(
+            "title".to_string(),
+            "description".to_string(),
+            clips.clone(),
+            Date::today(),
+        )
+        .unwrap();
+        assert_eq!(clip_series.clips(), clips.as_slice());
+        assert_eq!(clip_series.like(), 0);
+
+        assert!(ClipSeries::new(" ".to_string(), String::new(), clips, Date::today()).is_err());
+        assert!(
+            ClipSeries::new("title".to_string(), String::new(), vec![], Date::today()).is_err()
+        );
+    }
+
+    #[test]
+    fn clip_series_next_clip() {
+        let clips = vec![
+            MovieClipId::generate(),
+            MovieClipId::generate(),
+            MovieClipId::generate(),
+        ];
+        let clip_series = ClipSeries::new(
+            "title".to_string(),
+            String::new(),
+            clips.clone(),
+            Date::today(),
+        )
+        .unwrap();
+
+        assert_eq!(clip_series.next_clip(clips[0]), Some(clips[1]));
+        assert_eq!(clip_series.next_clip(clips[1]), Some(clips[2]));
+        assert_eq!(clip_series.next_clip(clips[2]), None);
+        assert_eq!(clip_series.next_clip(MovieClipId::generate()), None);
+    }
+
+    #[test]
+    fn clip_series_increment_and_decrement_like() {
+        let mut clip_series = Faker.fake::<ClipSeries>();
+        let like = clip_series.like();
+
+        clip_series.increment_like();
+        assert_eq!(like + 1, clip_series.like());
+        clip_series.decrement_like();
+        assert_eq!(like, clip_series.like());
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut clip_series = Faker.fake::<ClipSeries>();
+        let previous_id = clip_series.id();
+        let previous_like = clip_series.like();
+        let previous_create_date = clip_series.create_date();
+
+        let other_clip_series = Faker.fake::<ClipSeries>();
+        clip_series.assign(other_clip_series.clone());
+
+        assert_eq!(clip_series.id(), previous_id);
+        assert_eq!(clip_series.like(), previous_like);
+        assert_eq!(clip_series.create_date(), previous_create_date);
+        assert_eq!(clip_series.title(), other_clip_series.title());
+        assert_eq!(clip_series.clips(), other_clip_series.clips());
+    }
+}
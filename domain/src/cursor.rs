@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// ページングの基準となる位置．ソートのキーと，キーが同じ要素を区別するためのidを持つ．
+/// 基準の要素自体は含まず，それ以降の要素を取得するために用いる．
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursor<K, I> {
+    key: K,
+    id: I,
+}
+
+impl<K: Copy, I: Copy> Cursor<K, I> {
+    pub fn new(key: K, id: I) -> Self {
+        Self { key, id }
+    }
+    /// ソートのキーを取得
+    pub fn key(&self) -> K {
+        self.key
+    }
+    /// idを取得
+    pub fn id(&self) -> I {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cursor;
+    use crate::movie_clip::MovieClipId;
+    use crate::Date;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn serialize_and_deserialize() {
+        let cursor = Cursor::new(Date::from_ymd(2023, 4, 1).unwrap(), MovieClipId::generate());
+        let json_str = serde_json::to_string(&cursor).unwrap();
+        let cursor_de = serde_json::from_str::<Cursor<Date, MovieClipId>>(&json_str).unwrap();
+        assert_eq!(cursor, cursor_de);
+
+        // キーの型が異なる場合は失敗する
+        assert!(serde_json::from_str::<Cursor<u32, MovieClipId>>(&json_str).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::date_time::jst;
 use crate::DomainError::{self, DomainLogicError};
 use crate::GenericParseError;
 
@@ -35,6 +36,10 @@ impl Date {
     pub fn from_chrono(chrono_date: NaiveDate) -> Result<Self, DomainError> {
         Ok(Self(chrono_date))
     }
+    /// 現在の日付(JST)を取得．
+    pub fn today() -> Self {
+        Self(chrono::Utc::now().with_timezone(&jst()).date_naive())
+    }
 }
 
 impl TryFrom<(u32, u32, u32)> for Date {
@@ -124,7 +129,7 @@ mod test {
     #[test]
     fn test_constructor() {
         let date_ok = Date::from_ymd(2022, 12, 1);
-        assert!(matches!(date_ok, Ok(_)));
+        assert!(date_ok.is_ok());
         let date_err = Date::from_ymd(2020, 13, 1);
         assert!(matches!(date_err, Err(DomainError::DomainLogicError(_))));
     }
@@ -138,7 +143,7 @@ mod test {
         );
 
         let parsed_date_err: Result<Date, DomainError> = "2022-12-aa".parse::<Date>();
-        assert!(matches!(parsed_date_err, Err(_)));
+        assert!(parsed_date_err.is_err());
     }
 
     #[test]
@@ -0,0 +1,295 @@
+use crate::date::Date;
+use crate::DomainError::{self, DomainLogicError};
+
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{Dummy, Fake, Faker};
+
+#[cfg(any(test, feature = "fake"))]
+use rand::Rng;
+
+/// 日本標準時のUTCからのオフセット(秒)
+const JST_OFFSET_SECS: i32 = 9 * 60 * 60;
+
+/// 日本標準時のタイムゾーン．
+pub(crate) fn jst() -> FixedOffset {
+    FixedOffset::east_opt(JST_OFFSET_SECS).expect("Required sanity check")
+}
+
+/// タイムゾーン付きの日時．秒単位でUTCとして保持し，表示や日付への変換は日本標準時(JST)で行う．
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct DateTime(chrono::DateTime<Utc>);
+
+impl DateTime {
+    /// JSTでのyear, month, day, hour, minute, secondを指定してDateTimeを作成．
+    pub fn from_ymd_hms(
+        year: u32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Result<Self, DomainError> {
+        let naive = NaiveDate::from_ymd_opt(year as i32, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, second))
+            .ok_or(DomainLogicError("Invalid DateTime".to_string()))?;
+        Self::from_jst_naive(naive)
+    }
+    /// JSTでのその日の0時を表すDateTimeを作成．
+    pub fn from_date(date: Date) -> Self {
+        let naive = date
+            .to_chrono()
+            .expect("Required sanity check")
+            .and_time(NaiveTime::MIN);
+        Self::from_jst_naive(naive).expect("Required sanity check")
+    }
+    /// JSTでの日付を取得．
+    pub fn date(&self) -> Date {
+        Date::from_chrono(self.to_jst().date_naive()).expect("Required sanity check")
+    }
+    /// JSTでのhour, minute, secondを取得．
+    pub fn to_hms(&self) -> (u32, u32, u32) {
+        let jst_date_time = self.to_jst();
+        (
+            jst_date_time.hour(),
+            jst_date_time.minute(),
+            jst_date_time.second(),
+        )
+    }
+    /// JSTのchrono::DateTimeへ変換．
+    pub fn to_jst(&self) -> chrono::DateTime<FixedOffset> {
+        self.0.with_timezone(&jst())
+    }
+    /// chrono::DateTime<Utc>へ変換．
+    pub fn to_chrono(&self) -> Result<chrono::DateTime<Utc>, DomainError> {
+        Ok(self.0)
+    }
+    /// 任意のタイムゾーンのchrono::DateTimeからの変換．秒未満は切り捨てる．
+    pub fn from_chrono<Tz: TimeZone>(
+        chrono_date_time: chrono::DateTime<Tz>,
+    ) -> Result<Self, DomainError> {
+        let utc_date_time = chrono_date_time
+            .with_timezone(&Utc)
+            .with_nanosecond(0)
+            .ok_or(DomainLogicError("Invalid DateTime".to_string()))?;
+        Ok(Self(utc_date_time))
+    }
+    /// 現在の日時を取得．
+    pub fn now() -> Self {
+        Self::from_chrono(Utc::now()).expect("Required sanity check")
+    }
+    /// JSTとして解釈したNaiveDateTimeからの変換．
+    fn from_jst_naive(naive: NaiveDateTime) -> Result<Self, DomainError> {
+        let jst_date_time = naive
+            .and_local_timezone(jst())
+            .single()
+            .ok_or(DomainLogicError("Invalid DateTime".to_string()))?;
+        Self::from_chrono(jst_date_time)
+    }
+}
+
+impl From<Date> for DateTime {
+    fn from(date: Date) -> Self {
+        Self::from_date(date)
+    }
+}
+
+// String <-> DateTime
+
+/// RFC3339形式の他，タイムゾーンの無い`2023-07-05T20:00(:00)`形式・日付のみの`2023-07-05`形式はJSTとして解釈する．
+impl FromStr for DateTime {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(chrono_date_time) = chrono::DateTime::parse_from_rfc3339(s) {
+            return Self::from_chrono(chrono_date_time);
+        }
+        if let Some(naive) = [
+            "%Y-%m-%dT%H:%M:%S",
+            "%Y-%m-%dT%H:%M",
+            "%Y-%m-%d %H:%M:%S",
+            "%Y-%m-%d %H:%M",
+        ]
+        .into_iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+        {
+            return Self::from_jst_naive(naive);
+        }
+        let date: Date = s.parse()?;
+        Ok(Self::from_date(date))
+    }
+}
+
+impl TryFrom<String> for DateTime {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// JSTのRFC3339形式(`2023-07-05T20:00:00+09:00`)で表示する．`{:#}`の場合は`2023-07-05 20:00`の形式．
+impl Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_jst().format("%Y-%m-%d %H:%M"))
+        } else {
+            write!(f, "{}", self.to_jst().format("%Y-%m-%dT%H:%M:%S%:z"))
+        }
+    }
+}
+
+impl From<DateTime> for String {
+    fn from(date_time: DateTime) -> Self {
+        date_time.to_string()
+    }
+}
+
+// chrono::DateTime<Utc> <-> DateTime
+
+impl TryFrom<chrono::DateTime<Utc>> for DateTime {
+    type Error = DomainError;
+    fn try_from(value: chrono::DateTime<Utc>) -> Result<Self, Self::Error> {
+        Self::from_chrono(value)
+    }
+}
+
+impl TryFrom<DateTime> for chrono::DateTime<Utc> {
+    type Error = DomainError;
+    fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+        value.to_chrono()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+/// dateの日のJSTの日時を生成する
+#[cfg(any(feature = "fake", test))]
+fn fake_in_date<R: Rng + ?Sized>(date: Date, rng: &mut R) -> DateTime {
+    let secs = (0..24 * 60 * 60).fake_with_rng::<i64, R>(rng);
+    let chrono_date_time = DateTime::from_date(date).0 + chrono::Duration::seconds(secs);
+    chrono_date_time
+        .try_into()
+        .expect("Generate fake DateTime Error")
+}
+
+#[cfg(any(feature = "fake", test))]
+impl Dummy<Faker> for DateTime {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        fake_in_date(Faker.fake_with_rng(rng), rng)
+    }
+}
+
+/// startの日からendの前日までのJSTの日時を生成する．
+#[cfg(any(feature = "fake", test))]
+impl Dummy<std::ops::Range<Date>> for DateTime {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &std::ops::Range<Date>, rng: &mut R) -> Self {
+        fake_in_date(config.fake_with_rng(rng), rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DateTime;
+    use crate::{Date, DomainError};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_constructor() {
+        let date_time = DateTime::from_ymd_hms(2023, 7, 5, 20, 0, 0).unwrap();
+        assert_eq!(date_time.date(), Date::from_ymd(2023, 7, 5).unwrap());
+        assert_eq!(date_time.to_hms(), (20, 0, 0));
+
+        assert!(matches!(
+            DateTime::from_ymd_hms(2023, 7, 5, 24, 0, 0),
+            Err(DomainError::DomainLogicError(_))
+        ));
+
+        // JSTの0時はUTCでは前日
+        let midnight = DateTime::from_date(Date::from_ymd(2023, 1, 1).unwrap());
+        assert_eq!(
+            midnight,
+            DateTime::from_ymd_hms(2023, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            midnight.to_chrono().unwrap().to_rfc3339(),
+            "2022-12-31T15:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = DateTime::from_ymd_hms(2023, 7, 5, 20, 30, 0).unwrap();
+        for s in [
+            "2023-07-05T20:30:00+09:00",
+            "2023-07-05T11:30:00Z",
+            "2023-07-05T11:30:00.123Z",
+            "2023-07-05T20:30",
+            "2023-07-05T20:30:00",
+            "2023-07-05 20:30",
+        ] {
+            assert_eq!(s.parse::<DateTime>().unwrap(), expected, "{s}");
+        }
+
+        // 日付のみはJSTの0時
+        assert_eq!(
+            "2023-07-05".parse::<DateTime>().unwrap(),
+            DateTime::from_ymd_hms(2023, 7, 5, 0, 0, 0).unwrap()
+        );
+
+        for s in ["", "2023-07-05T25:00", "2023-13-01", "20:30"] {
+            assert!(
+                matches!(s.parse::<DateTime>(), Err(DomainError::DomainParseError(_))),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn to_string() {
+        let date_time = DateTime::from_ymd_hms(2023, 7, 5, 8, 5, 9).unwrap();
+        assert_eq!(date_time.to_string(), "2023-07-05T08:05:09+09:00");
+        assert_eq!(format!("{date_time:#}"), "2023-07-05 08:05");
+    }
+
+    #[test]
+    fn serialize_and_deserialize() {
+        let date_time = DateTime::from_ymd_hms(2022, 11, 23, 21, 0, 0).unwrap();
+        let json_str = serde_json::to_string(&date_time).unwrap();
+        assert_eq!(json_str, r#""2022-11-23T21:00:00+09:00""#);
+        assert_eq!(
+            serde_json::from_str::<DateTime>(&json_str).unwrap(),
+            date_time
+        );
+
+        // 日付のみのjsonも受け付ける
+        let date_time = serde_json::from_str::<DateTime>(r#""2022-11-24""#).unwrap();
+        assert_eq!(
+            date_time,
+            DateTime::from_ymd_hms(2022, 11, 24, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn generate_fake() {
+        use fake::{Fake, Faker};
+
+        let _ = (0..10000)
+            .map(|_| Faker.fake::<DateTime>())
+            .collect::<Vec<_>>();
+
+        let start = Date::from_ymd(2022, 12, 30).unwrap();
+        let end = Date::from_ymd(2023, 1, 2).unwrap();
+        (0..1000)
+            .map(|_| (start..end).fake::<DateTime>())
+            .for_each(|date_time| {
+                assert!(start <= date_time.date() && date_time.date() < end);
+            });
+    }
+}
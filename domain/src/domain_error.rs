@@ -1,3 +1,7 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
 /// ドメインに関するエラー
 #[derive(thiserror::Error, Debug, Clone)]
 pub enum DomainError {
@@ -10,6 +14,151 @@ pub enum DomainError {
     /// 外部クレートの対応する型との変換のエラー．
     #[error("DomainError::DomainConvertExternalError: {0}")]
     DomainConvertExternalError(String),
+    /// 入力のフィールドに関する構造化されたエラー．フロントエンドで対応する入力を特定するために用いる．
+    #[error("DomainError::DomainValidationError: {0}")]
+    DomainValidationError(ValidationError),
+}
+
+impl From<ValidationError> for DomainError {
+    fn from(value: ValidationError) -> Self {
+        DomainError::DomainValidationError(value)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ValidationError
+
+/// バリデーションエラーの種類を表す安定したコード
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationCode {
+    /// 文字列が長すぎる．パラメーター`limit`に上限の長さを持つ
+    TooLong,
+    /// 値が小さすぎる．パラメーター`min`に下限の値を持つ
+    TooSmall,
+    /// urlのプレフィックスが許可されていない．パラメーター`allowed`に許可されるプレフィックスを持つ
+    InvalidUrlPrefix,
+    /// urlのクエリパラメーターが無効
+    InvalidUrlQuery,
+    /// 範囲の終了が開始以前．パラメーター`start`に開始を持つ
+    EndBeforeStart,
+    /// 許可されていないhtmlを含む
+    InvalidHtml,
+    /// 正規化した文字列が空
+    Empty,
+    /// 許可されていない文字を含む．パラメーター`character`にその文字を持つ
+    InvalidCharacter,
+    /// 動画のidがプラットフォームの形式に合わない．パラメーター`platform`にプラットフォーム名を持つ
+    InvalidVideoId,
+    /// 範囲の終了が動画の長さを超える．パラメーター`duration`に動画の長さを持つ
+    ExceedsDuration,
+}
+
+/// 入力のどのフィールドがどのように無効であるかを表すエラー．
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationError {
+    /// エラーの種類
+    pub code: ValidationCode,
+    /// 無効なフィールドのパス．`url`や`range.end`など
+    pub field: String,
+    /// 上限値などのパラメーター
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+}
+
+impl ValidationError {
+    pub fn new(code: ValidationCode, field: &str) -> Self {
+        Self {
+            code,
+            field: field.to_string(),
+            params: BTreeMap::new(),
+        }
+    }
+    /// パラメーターを追加する．
+    pub fn with_param(mut self, key: &str, value: impl ToString) -> Self {
+        self.params.insert(key.to_string(), value.to_string());
+        self
+    }
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.params.get(key).map(|value| value.as_str())
+    }
+    /// 入力中のパス`field`にあるエラーとする．コンストラクタが付けたフィールドの先頭の名前を置き換え，
+    /// `range.end`を`[2].range`に置くと`[2].range.end`となる．`field`が空の場合はそのまま
+    pub fn at(mut self, field: &str) -> Self {
+        if !field.is_empty() {
+            self.field = match self.field.split_once('.') {
+                Some((_, rest)) => format!("{field}.{rest}"),
+                None => field.to_string(),
+            };
+        }
+        self
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            code,
+            field,
+            params,
+        } = self;
+        write!(f, "ValidationError: {field} is invalid ({code:?})")?;
+        if !params.is_empty() {
+            write!(f, " {params:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl DomainError {
+    /// バリデーションエラーの場合は入力中のパス`field`にあるエラーとする．
+    pub fn at(self, field: &str) -> Self {
+        match self {
+            DomainError::DomainValidationError(validation_error) => {
+                DomainError::DomainValidationError(validation_error.at(field))
+            }
+            domain_error => domain_error,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// FromInput
+
+/// 検証前の入力から構築するドメインの値．
+/// 入力をプリミティブな型のままデシリアライズしてからコンストラクタで検証することで，
+/// バリデーションエラーを入力中のパスとともに型のまま返す．
+pub trait FromInput: Sized {
+    /// 検証前の入力
+    type Input: DeserializeOwned;
+    /// `input`を検証して構築する．`field`は入力中の値のパスで，最上位の値の場合は空文字列
+    fn from_input(input: Self::Input, field: &str) -> Result<Self, DomainError>;
+}
+
+/// 入力中のパス`parent`にある値のフィールド`child`のパス
+pub fn field_path(parent: &str, child: &str) -> String {
+    match parent {
+        "" => child.to_string(),
+        parent => format!("{parent}.{child}"),
+    }
+}
+
+impl<T: FromInput> FromInput for Vec<T> {
+    type Input = Vec<T::Input>;
+    fn from_input(input: Self::Input, field: &str) -> Result<Self, DomainError> {
+        input
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| T::from_input(item, &format!("{field}[{i}]")))
+            .collect()
+    }
+}
+
+impl<T: FromInput> FromInput for Option<T> {
+    type Input = Option<T::Input>;
+    fn from_input(input: Self::Input, field: &str) -> Result<Self, DomainError> {
+        input.map(|item| T::from_input(item, field)).transpose()
+    }
 }
 
 /// ジェネリックなパースに関するエラー
@@ -36,3 +185,58 @@ impl From<DomainError> for sqlx::Error {
         sqlx::Error::Decode(Box::new(domain_error))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{FromInput, ValidationCode, ValidationError};
+    use crate::{movie_clip::SecondRange, DomainError, MovieUrl, Tag};
+    use pretty_assertions::assert_eq;
+
+    fn validation_error_of(domain_error: DomainError) -> ValidationError {
+        let DomainError::DomainValidationError(validation_error) = domain_error else {
+            panic!("Expected validation error: {domain_error:?}");
+        };
+        validation_error
+    }
+
+    #[test]
+    fn validation_error_at() {
+        let validation_error =
+            ValidationError::new(ValidationCode::TooLong, "url").with_param("limit", 100);
+        assert_eq!(validation_error.param("limit"), Some("100"));
+
+        assert_eq!(validation_error.clone().at("").field, "url");
+        assert_eq!(validation_error.clone().at("[2].url").field, "[2].url");
+        assert_eq!(
+            ValidationError::new(ValidationCode::EndBeforeStart, "range.end")
+                .at("[2].range")
+                .field,
+            "[2].range.end"
+        );
+    }
+
+    #[test]
+    fn from_input_with_field_path() {
+        // リストの要素は添字をパスに持つ
+        let res = Vec::<Tag>::from_input(vec!["tag".to_string(), " ".to_string()], "tags");
+        assert_eq!(
+            validation_error_of(res.unwrap_err()),
+            ValidationError::new(ValidationCode::Empty, "tags[1]")
+        );
+
+        let res = Vec::<MovieUrl>::from_input(vec!["https://example.com".to_string()], "");
+        let validation_error = validation_error_of(res.unwrap_err());
+        assert_eq!(validation_error.code, ValidationCode::InvalidUrlPrefix);
+        assert_eq!(validation_error.field, "[0]");
+
+        // 最上位の値はコンストラクタのフィールド名のまま
+        let range_input = serde_json::from_str(r#"{"start":100,"end":50}"#).unwrap();
+        let res = SecondRange::from_input(range_input, "");
+        let validation_error = validation_error_of(res.unwrap_err());
+        assert_eq!(validation_error.code, ValidationCode::EndBeforeStart);
+        assert_eq!(validation_error.field, "range.end");
+
+        // Noneは検証しない
+        assert_eq!(Option::<Tag>::from_input(None, "tag").unwrap(), None);
+    }
+}
@@ -1,8 +1,9 @@
 mod episode_content;
 
 use crate::date::Date;
+use crate::date_time::DateTime;
 use crate::ids::Id;
-use crate::DomainError;
+use crate::{field_path, DomainError, FromInput};
 pub use episode_content::EpisodeContent;
 use serde::{Deserialize, Serialize};
 
@@ -30,32 +31,37 @@ pub type EpisodeId = Id<EpisodeIdType>;
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Episode {
     /// エピソードの日時
-    date: Date,
+    date: DateTime,
     /// エピソードの内容
     content: EpisodeContent,
     /// UUID
     id: EpisodeId,
+    /// 編集の度に一つ増える版数
+    #[serde(default)]
+    version: u32,
 }
 
 impl Episode {
-    /// プリミティブな値からのコンストラクタ
+    /// プリミティブな値からのコンストラクタ．日時は`date_ymd`の日のJSTの0時とする
     pub fn new(date_ymd: (u32, u32, u32), content: String) -> Result<Self, DomainError> {
         Ok(Self {
-            date: date_ymd.try_into()?,
+            date: Date::try_from(date_ymd)?.into(),
             content: content.try_into()?,
             id: EpisodeId::generate(),
+            version: 0,
         })
     }
     /// ドメイン固有型からのコンストラクタ
-    pub fn new_with_domains(date: Date, content: EpisodeContent) -> Self {
+    pub fn new_with_domains(date: DateTime, content: EpisodeContent) -> Self {
         Self {
             date,
             content,
             id: EpisodeId::generate(),
+            version: 0,
         }
     }
     /// 日時を取得
-    pub fn date(&self) -> Date {
+    pub fn date(&self) -> DateTime {
         self.date
     }
     /// 内容を取得
@@ -67,40 +73,73 @@ impl Episode {
         self.id
     }
     /// 日時の可変参照を取得
-    pub fn date_mut(&mut self) -> &mut Date {
+    pub fn date_mut(&mut self) -> &mut DateTime {
         &mut self.date
     }
     /// 内容を編集
     pub fn content_mut(&mut self) -> &mut EpisodeContent {
         &mut self.content
     }
-    /// idはそのままにotherのフィールドを自身にコピー
+    /// versionを取得
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+    /// versionを一つ増やす
+    pub fn increment_version(&mut self) {
+        self.version += 1;
+    }
+    /// id, versionはそのままにotherのフィールドを自身にコピー
     pub fn assign(&mut self, other: Self) {
         let new_self = Self {
             id: self.id(),
+            version: self.version(),
             ..other
         };
         *self = new_self;
     }
 }
 
+/// 検証前の`Episode`の入力．内容は`FromInput`で検証する
+#[derive(Debug, Clone, Deserialize)]
+pub struct EpisodeInput {
+    pub date: DateTime,
+    pub content: String,
+    pub id: EpisodeId,
+    #[serde(default)]
+    pub version: u32,
+}
+
+impl FromInput for Episode {
+    type Input = EpisodeInput;
+    fn from_input(input: EpisodeInput, field: &str) -> Result<Self, DomainError> {
+        Ok(Self {
+            date: input.date,
+            content: EpisodeContent::from_input(input.content, &field_path(field, "content"))?,
+            id: input.id,
+            version: input.version,
+        })
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // Episode as entity
 
 #[cfg(feature = "server")]
 impl FromRow<'_, PgRow> for Episode {
     fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
-        use chrono::NaiveDate;
+        use chrono::Utc;
         use uuid::Uuid;
 
-        let date: NaiveDate = row.try_get("date")?;
+        let date: chrono::DateTime<Utc> = row.try_get("date")?;
         let content: String = row.try_get("content")?;
         let id: Uuid = row.try_get("id")?;
+        let version: i32 = row.try_get("version")?;
 
         Ok(Self {
             date: date.try_into()?,
             content: content.try_into()?,
             id: id.into(),
+            version: version as u32,
         })
     }
 }
@@ -125,14 +164,14 @@ impl Dummy<std::ops::Range<Date>> for Episode {
 #[cfg(test)]
 mod test {
     use super::{Episode, EpisodeContent};
-    use crate::Date;
+    use crate::DateTime;
     use fake::{Fake, Faker};
     use pretty_assertions::assert_eq;
 
     #[test]
     fn episode() {
         Episode::new((2022, 11, 22), "Some content".to_string()).unwrap();
-        let date = Faker.fake::<Date>();
+        let date = Faker.fake::<DateTime>();
         let content = Faker.fake::<EpisodeContent>();
         Episode::new_with_domains(date, content);
     }
@@ -140,7 +179,7 @@ mod test {
     #[test]
     fn modify_episode() {
         let mut episode = Faker.fake::<Episode>();
-        let new_date = Faker.fake::<Date>();
+        let new_date = Faker.fake::<DateTime>();
 
         *episode.date_mut() = new_date;
         assert_eq!(episode.date(), new_date);
@@ -162,11 +201,14 @@ mod test {
     fn test_assign() {
         let mut episode = Faker.fake::<Episode>();
         let previous_id = episode.id();
+        episode.increment_version();
+        let previous_version = episode.version();
 
         let other_episode = Faker.fake::<Episode>();
         episode.assign(other_episode.clone());
 
         assert_eq!(episode.id(), previous_id);
+        assert_eq!(episode.version(), previous_version);
         assert_eq!(episode.date(), other_episode.date());
         assert_eq!(episode.content(), other_episode.content());
     }
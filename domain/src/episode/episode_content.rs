@@ -1,4 +1,4 @@
-use crate::DomainError;
+use crate::{DomainError, FromInput, ValidationCode, ValidationError};
 use ammonia::Builder;
 use maplit::hashset;
 use serde::{Deserialize, Serialize};
@@ -34,6 +34,26 @@ impl Default for EpisodeContent {
     }
 }
 
+impl EpisodeContent {
+    /// タグを空白に置き換えた本文
+    pub fn text(&self) -> String {
+        let mut text = String::with_capacity(self.0.len());
+        let mut in_tag = false;
+        for c in self.0.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' if in_tag => {
+                    in_tag = false;
+                    text.push(' ');
+                }
+                _ if !in_tag => text.push(c),
+                _ => {}
+            }
+        }
+        text
+    }
+}
+
 // 高コスト
 impl FromStr for EpisodeContent {
     type Err = DomainError;
@@ -51,9 +71,7 @@ impl FromStr for EpisodeContent {
         if out == s {
             Ok(EpisodeContent(out))
         } else {
-            Err(DomainError::DomainParseError(
-                "Contains invalid html".to_string(),
-            ))
+            Err(ValidationError::new(ValidationCode::InvalidHtml, "content").into())
         }
     }
 }
@@ -71,6 +89,13 @@ impl Display for EpisodeContent {
     }
 }
 
+impl FromInput for EpisodeContent {
+    type Input = String;
+    fn from_input(input: String, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
 impl From<EpisodeContent> for String {
     fn from(value: EpisodeContent) -> Self {
         value.to_string()
@@ -92,8 +117,8 @@ impl Dummy<Faker> for EpisodeContent {
 
 #[cfg(test)]
 mod test {
-    use super::DomainError;
     use super::EpisodeContent;
+    use crate::{DomainError, ValidationCode, ValidationError};
     use fake::{Fake, Faker};
 
     #[test]
@@ -101,17 +126,29 @@ mod test {
         let valid_html = r#"おりコウの歌ってみたである<a href="https://www.youtube.com/watch?v=B7OPlsdBuVc&t=100s" rel="noopener noreferrer"> きみも悪いひとでよかった </a> は <strong>いいぞ</strong>"#.to_string();
 
         let res: Result<EpisodeContent, DomainError> = valid_html.try_into();
-        assert!(matches!(res, Ok(_)));
+        assert!(res.is_ok());
 
         let invalid_html = r#"おりコウの歌ってみたである<a href="https://www.youtube.com/watch?v=B7OPlsdBuVc" rel="noopener noreferrer"> きみも悪いひとでよかった </a> は <strong>いいぞ</strong> <script>alert();</script>"#.to_string();
 
         let res: Result<EpisodeContent, DomainError> = invalid_html.try_into();
-        assert!(matches!(res, Err(DomainError::DomainParseError(_))));
+        assert!(matches!(
+            res,
+            Err(DomainError::DomainValidationError(ValidationError {
+                code: ValidationCode::InvalidHtml,
+                ..
+            }))
+        ));
 
         let invalid_html_v2 = r#"おりコウの歌ってみたである<a href="https://www.youtube.com/watch?v=B7OPlsdBuVc&t=100s"> きみも悪いひとでよかった </a> は <strong>いいぞ</strong>"#.to_string();
 
         let res: Result<EpisodeContent, DomainError> = invalid_html_v2.try_into();
-        assert!(matches!(res, Err(DomainError::DomainParseError(_))));
+        assert!(matches!(
+            res,
+            Err(DomainError::DomainValidationError(ValidationError {
+                code: ValidationCode::InvalidHtml,
+                ..
+            }))
+        ));
     }
 
     #[test]
@@ -123,7 +160,7 @@ mod test {
         "#.to_string();
 
         let res: Result<EpisodeContent, DomainError> = html_text.try_into();
-        assert!(matches!(res, Ok(_)));
+        assert!(res.is_ok());
 
         let content = res.unwrap();
 
@@ -133,6 +170,15 @@ mod test {
         assert_eq!(content, content_from_json);
     }
 
+    #[test]
+    fn text_without_tags() {
+        let content: EpisodeContent = r#"<p>理子の<strong>歌枠</strong></p>"#.parse().unwrap();
+        assert_eq!(
+            content.text().split_whitespace().collect::<Vec<_>>(),
+            vec!["理子の", "歌枠"]
+        );
+    }
+
     #[test]
     fn generate_fake() {
         let _ = (0..100)
@@ -1,19 +1,38 @@
+pub mod channel;
+pub mod client;
+pub mod clip_series;
+mod cursor;
 mod date;
+mod date_time;
 mod domain_error;
 pub mod episode;
 mod ids;
 pub mod movie_clip;
 mod movie_url;
+mod page_size;
+pub mod quiz;
+pub mod revision;
+pub mod search;
+mod tag;
+pub mod user;
 pub mod video;
 
 #[cfg(feature = "repo")]
 mod repositories;
 
+pub use cursor::Cursor;
 pub use date::Date;
+pub use date_time::DateTime;
 pub use domain_error::DomainError;
 pub use domain_error::GenericParseError;
+pub use domain_error::{field_path, FromInput, ValidationCode, ValidationError};
 pub use ids::Id;
-pub use movie_url::MovieUrl;
+pub use movie_url::{MovieUrl, Platform};
+pub use page_size::PageSize;
+pub use tag::{Tag, TAG_SEPARATOR};
 
 #[cfg(feature = "repo")]
-pub use repositories::{EpisodeRepository, MovieClipRepository, VideoRepository};
+pub use repositories::{
+    ChannelRepository, ClipSeriesRepository, EpisodeRepository, MovieClipRepository,
+    QuizRepository, UserRepository, VideoRepository,
+};
@@ -2,11 +2,12 @@ mod second;
 
 pub use crate::MovieUrl;
 pub use second::Second;
-pub use second::SecondRange;
+pub use second::{SecondRange, SecondRangeInput};
 
+use crate::cursor::Cursor;
 use crate::date::Date;
 use crate::ids::Id;
-use crate::DomainError;
+use crate::{field_path, DomainError, FromInput};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "server")]
@@ -29,6 +30,7 @@ pub type MovieClipId = Id<MovieClipIdType>;
 
 /// VideoClipのエンティティ
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "MovieClipInput")]
 pub struct MovieClip {
     /// クリップのタイトル
     title: String,
@@ -42,6 +44,10 @@ pub struct MovieClip {
     like: u32,
     /// クリップの作成日時
     create_date: Date,
+    /// クリップの元動画の長さ．不明な場合はNone
+    duration: Option<Second>,
+    /// 編集の度に一つ増える版数
+    version: u32,
 }
 
 impl MovieClip {
@@ -60,6 +66,8 @@ impl MovieClip {
             id: MovieClipId::generate(),
             like: 0_u32,
             create_date: create_date_ymd.try_into()?,
+            duration: None,
+            version: 0,
         })
     }
 
@@ -77,8 +85,18 @@ impl MovieClip {
             create_date,
             like: 0_u32,
             id: MovieClipId::generate(),
+            duration: None,
+            version: 0,
         }
     }
+    /// 元動画の長さを設定する．再生範囲の終了が長さを超える場合はエラー
+    pub fn with_duration(mut self, duration: Option<Second>) -> Result<Self, DomainError> {
+        if let Some(duration) = duration {
+            self.range.check_duration(duration)?;
+        }
+        self.duration = duration;
+        Ok(self)
+    }
     /// titleを取得
     pub fn title(&self) -> &str {
         &self.title
@@ -99,9 +117,21 @@ impl MovieClip {
     pub fn range(&self) -> &SecondRange {
         &self.range
     }
-    /// rangeの可変参照を取得
-    pub fn range_mut(&mut self) -> &mut SecondRange {
-        &mut self.range
+    /// rangeを設定する．元動画の長さが分かっていて終了が長さを超える場合はエラー
+    pub fn set_range(&mut self, range: SecondRange) -> Result<(), DomainError> {
+        if let Some(duration) = self.duration {
+            range.check_duration(duration)?;
+        }
+        self.range = range;
+        Ok(())
+    }
+    /// 開始時間のタイムスタンプを付けた共有用のurl
+    pub fn share_url(&self) -> String {
+        self.url.timestamped_url(self.range.start())
+    }
+    /// 元動画の長さを取得
+    pub fn duration(&self) -> Option<Second> {
+        self.duration
     }
     /// idを取得
     pub fn id(&self) -> MovieClipId {
@@ -115,22 +145,84 @@ impl MovieClip {
     pub fn increment_like(&mut self) {
         self.like += 1;
     }
+    /// likeを一つ減らす．0の場合は何もしない
+    pub fn decrement_like(&mut self) {
+        self.like = self.like.saturating_sub(1);
+    }
     /// create_dateを取得
     pub fn create_date(&self) -> Date {
         self.create_date
     }
-    /// id, like, create_date はそのままにotherのフィールドを自身にコピー．
+    /// versionを取得
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+    /// versionを一つ増やす
+    pub fn increment_version(&mut self) {
+        self.version += 1;
+    }
+    /// likeで並べたときの自身の位置を表すカーソル
+    pub fn like_cursor(&self) -> Cursor<u32, MovieClipId> {
+        Cursor::new(self.like, self.id)
+    }
+    /// create_dateで並べたときの自身の位置を表すカーソル
+    pub fn create_date_cursor(&self) -> Cursor<Date, MovieClipId> {
+        Cursor::new(self.create_date, self.id)
+    }
+    /// id, like, create_date, version はそのままにotherのフィールドを自身にコピー．
     pub fn assign(&mut self, other: Self) {
         let new_self = Self {
             id: self.id(),
             like: self.like(),
             create_date: self.create_date(),
+            version: self.version(),
             ..other
         };
         *self = new_self;
     }
 }
 
+/// 検証前の`MovieClip`の入力．url・再生範囲・元動画の長さは`FromInput`で検証する
+#[derive(Debug, Clone, Deserialize)]
+pub struct MovieClipInput {
+    pub title: String,
+    pub url: String,
+    pub range: SecondRangeInput,
+    pub id: MovieClipId,
+    pub like: u32,
+    pub create_date: Date,
+    #[serde(default)]
+    pub duration: Option<Second>,
+    #[serde(default)]
+    pub version: u32,
+}
+
+impl FromInput for MovieClip {
+    type Input = MovieClipInput;
+    fn from_input(input: MovieClipInput, field: &str) -> Result<Self, DomainError> {
+        let range_field = field_path(field, "range");
+        Self {
+            title: input.title,
+            url: MovieUrl::from_input(input.url, &field_path(field, "url"))?,
+            range: SecondRange::from_input(input.range, &range_field)?,
+            id: input.id,
+            like: input.like,
+            create_date: input.create_date,
+            duration: None,
+            version: input.version,
+        }
+        .with_duration(input.duration)
+        .map_err(|domain_error| domain_error.at(&range_field))
+    }
+}
+
+impl TryFrom<MovieClipInput> for MovieClip {
+    type Error = DomainError;
+    fn try_from(value: MovieClipInput) -> Result<Self, Self::Error> {
+        Self::from_input(value, "")
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // MovieClip as entity
 
@@ -142,19 +234,23 @@ impl FromRow<'_, PgRow> for MovieClip {
 
         let title: String = row.try_get("title")?;
         let url: String = row.try_get("url")?;
-        let start: i32 = row.try_get("start")?;
-        let end: i32 = row.try_get("end")?;
+        let start: f64 = row.try_get("start")?;
+        let end: f64 = row.try_get("end")?;
         let id: Uuid = row.try_get("id")?;
         let like: i32 = row.try_get("like")?;
         let create_date: NaiveDate = row.try_get("create_date")?;
+        let duration: Option<f64> = row.try_get("duration")?;
+        let version: i32 = row.try_get("version")?;
 
         Ok(Self {
             title,
             url: url.try_into()?,
-            range: (start as u32..end as u32).try_into()?,
+            range: (Second::from_secs_f64(start)?..Second::from_secs_f64(end)?).try_into()?,
             id: id.into(),
             like: like as u32,
             create_date: create_date.try_into()?,
+            duration: duration.map(Second::from_secs_f64).transpose()?,
+            version: version as u32,
         })
     }
 }
@@ -162,6 +258,15 @@ impl FromRow<'_, PgRow> for MovieClip {
 // -------------------------------------------------------------------------------------------------
 // Dummy trait
 
+/// 再生範囲を含む元動画の長さを生成する．不明(None)の場合もある
+#[cfg(any(feature = "fake", test))]
+fn fake_duration<R: rand::Rng + ?Sized>(range: &SecondRange, rng: &mut R) -> Option<Second> {
+    rng.gen_bool(0.5).then(|| {
+        let margin = (0..600 * 1000).fake_with_rng::<u64, R>(rng);
+        Second::from_millis(range.end().to_millis() + margin)
+    })
+}
+
 #[cfg(any(feature = "fake", test))]
 impl Dummy<Faker> for MovieClip {
     fn dummy_with_rng<R: rand::Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
@@ -180,7 +285,10 @@ impl Dummy<Faker> for MovieClip {
             movie_clip.increment_like();
         }
 
+        let duration = fake_duration(movie_clip.range(), rng);
         movie_clip
+            .with_duration(duration)
+            .expect("Generate fake MovieClip error")
     }
 }
 
@@ -202,13 +310,17 @@ impl Dummy<std::ops::Range<Date>> for MovieClip {
             movie_clip.increment_like();
         }
 
+        let duration = fake_duration(movie_clip.range(), rng);
         movie_clip
+            .with_duration(duration)
+            .expect("Generate fake MovieClip error")
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::MovieClip;
+    use super::{MovieClip, Second, SecondRange};
+    use crate::{DomainError, FromInput, ValidationCode};
     use fake::{Fake, Faker};
 
     #[test]
@@ -234,6 +346,8 @@ mod test {
         let previous_id = movie_clip.id();
         let previous_like = movie_clip.like();
         let previous_create_date = movie_clip.create_date();
+        movie_clip.increment_version();
+        let previous_version = movie_clip.version();
 
         let other_clip = Faker.fake::<MovieClip>();
 
@@ -242,8 +356,104 @@ mod test {
         assert_eq!(previous_id, movie_clip.id());
         assert_eq!(previous_like, movie_clip.like());
         assert_eq!(previous_create_date, movie_clip.create_date());
+        assert_eq!(previous_version, movie_clip.version());
         assert_eq!(movie_clip.title(), other_clip.title());
         assert_eq!(movie_clip.url(), other_clip.url());
         assert_eq!(movie_clip.range(), other_clip.range());
     }
+
+    #[test]
+    fn test_share_url() {
+        let movie_clip = MovieClip::new(
+            "title".to_string(),
+            "https://www.youtube.com/watch?v=LjU5OOHu_As".to_string(),
+            100,
+            200,
+            (2023, 6, 1),
+        )
+        .unwrap();
+        assert_eq!("https://youtu.be/LjU5OOHu_As?t=100", movie_clip.share_url());
+    }
+
+    #[test]
+    fn test_duration() {
+        let movie_clip = MovieClip::new(
+            "title".to_string(),
+            "https://www.youtube.com/watch?v=LjU5OOHu_As".to_string(),
+            100,
+            200,
+            (2023, 6, 1),
+        )
+        .unwrap();
+        assert_eq!(None, movie_clip.duration());
+
+        let with_duration = movie_clip
+            .clone()
+            .with_duration(Some(Second::from_u32(200)))
+            .unwrap();
+        assert_eq!(Some(Second::from_u32(200)), with_duration.duration());
+
+        let res = movie_clip
+            .clone()
+            .with_duration(Some(Second::from_u32(199)));
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(ValidationCode::ExceedsDuration, validation_error.code);
+
+        // デシリアライズ時も検証する
+        let mut json = serde_json::to_value(&with_duration).unwrap();
+        json["duration"] = serde_json::json!(150);
+        assert!(serde_json::from_value::<MovieClip>(json.clone()).is_err());
+
+        // 入力から検証する場合はバリデーションエラーを入力中のパスとともに返す
+        let input = serde_json::from_value(json).unwrap();
+        let res = Vec::<MovieClip>::from_input(vec![input], "");
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(ValidationCode::ExceedsDuration, validation_error.code);
+        assert_eq!("[0].range.end", validation_error.field);
+
+        // durationが無いjsonもデシリアライズできる
+        let mut json = serde_json::to_value(&movie_clip).unwrap();
+        json.as_object_mut().unwrap().remove("duration");
+        assert_eq!(
+            movie_clip,
+            serde_json::from_value::<MovieClip>(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_set_range() {
+        let mut movie_clip = MovieClip::new(
+            "title".to_string(),
+            "https://www.youtube.com/watch?v=LjU5OOHu_As".to_string(),
+            100,
+            200,
+            (2023, 6, 1),
+        )
+        .unwrap()
+        .with_duration(Some(Second::from_u32(300)))
+        .unwrap();
+
+        movie_clip
+            .set_range((150..300).try_into().unwrap())
+            .unwrap();
+        assert_eq!(
+            &SecondRange::try_from(150..300).unwrap(),
+            movie_clip.range()
+        );
+
+        // 元動画の長さを超える場合は変更しない
+        let res = movie_clip.set_range((150..301).try_into().unwrap());
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(ValidationCode::ExceedsDuration, validation_error.code);
+        assert_eq!(
+            &SecondRange::try_from(150..300).unwrap(),
+            movie_clip.range()
+        );
+    }
 }
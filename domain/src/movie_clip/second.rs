@@ -1,34 +1,62 @@
-use crate::DomainError;
-use serde::{Deserialize, Serialize};
-use std::{fmt::Display, ops::Range};
+use crate::{DomainError, FromInput, ValidationCode, ValidationError};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt::Display, ops::Range, str::FromStr};
 
 #[cfg(any(test, feature = "fake"))]
 use fake::{Dummy, Fake, Faker};
 
-/// MovieClipで用いる秒指定のための秒型
-#[cfg_attr(any(test, feature = "fake"), derive(Dummy))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct Second(u32);
+/// MovieClipで用いる秒指定のための秒型．ミリ秒単位の精度を持つ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Second(u64); // ミリ秒
+
+/// 秒数がu32に収まる範囲のミリ秒の上限
+const MAX_MILLIS: u64 = u32::MAX as u64 * 1000 + 999;
 
 impl Second {
     pub fn from_u32(second: u32) -> Self {
-        Self(second)
+        Self(second as u64 * 1000)
     }
     pub fn from_hms(hours: u32, minutes: u32, seconds: u32) -> Self {
-        Self(hours * 60 * 60 + minutes * 60 + seconds)
+        Self::from_u32(hours * 60 * 60 + minutes * 60 + seconds)
+    }
+    /// ミリ秒から作成する．上限を超える場合は上限とする
+    pub fn from_millis(millis: u64) -> Self {
+        Self(millis.min(MAX_MILLIS))
+    }
+    /// 小数の秒数から作成する．ミリ秒未満は四捨五入する
+    pub fn from_secs_f64(seconds: f64) -> Result<Self, DomainError> {
+        let millis = (seconds * 1000.0).round();
+        if !millis.is_finite() || millis < 0.0 || millis > MAX_MILLIS as f64 {
+            return Err(DomainError::DomainParseError(format!(
+                "`{seconds}` is not a valid second."
+            )));
+        }
+        Ok(Self(millis as u64))
     }
+    /// 秒数(ミリ秒以下は切り捨て)
     pub fn to_u32(self) -> u32 {
-        self.0
+        (self.0 / 1000) as u32
     }
     pub fn to_hms(self) -> (u32, u32, u32) {
-        let mut all_seconds = self.0;
+        let mut all_seconds = self.to_u32();
         let hours = all_seconds / (60 * 60);
         all_seconds -= hours * (60 * 60);
         let minutes = all_seconds / 60;
         all_seconds -= minutes * 60;
         (hours, minutes, all_seconds)
     }
+    /// ミリ秒
+    pub fn to_millis(self) -> u64 {
+        self.0
+    }
+    /// 秒未満のミリ秒
+    pub fn subsec_millis(self) -> u32 {
+        (self.0 % 1000) as u32
+    }
+    /// 小数の秒数
+    pub fn to_secs_f64(self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
 }
 
 impl From<u32> for Second {
@@ -55,19 +83,176 @@ impl From<Second> for i32 {
     }
 }
 
+/// `12`・`12.5`のような小数点以下3桁までの秒数をミリ秒にパースする
+fn parse_decimal_seconds(s: &str) -> Option<u64> {
+    let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+    let (whole, fraction) = match s.split_once('.') {
+        Some((whole, fraction)) if is_digits(fraction) && fraction.len() <= 3 => (whole, fraction),
+        Some(_) => return None,
+        None => (s, ""),
+    };
+    if !is_digits(whole) {
+        return None;
+    }
+
+    let fraction_millis = format!("{fraction:0<3}").parse::<u64>().ok()?;
+    whole
+        .parse::<u64>()
+        .ok()?
+        .checked_mul(1000)?
+        .checked_add(fraction_millis)
+}
+
+/// `h:mm:ss`・`mm:ss`形式の文字列をミリ秒にパースする．二つ目以降の部分は2桁かつ60未満で，最後の部分は小数を許す
+fn parse_colon_form(s: &str) -> Option<u64> {
+    let parts = s.split(':').collect::<Vec<_>>();
+    if !(2..=3).contains(&parts.len()) {
+        return None;
+    }
+
+    let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+    let (last, init) = parts.split_last()?;
+    let (first, middle) = init.split_first()?;
+    if !is_digits(first) || !middle.iter().all(|part| part.len() == 2 && is_digits(part)) {
+        return None;
+    }
+
+    let mut all_minutes = first.parse::<u64>().ok()?;
+    for part in middle {
+        let value = part.parse::<u64>().ok()?;
+        if value >= 60 {
+            return None;
+        }
+        all_minutes = all_minutes.checked_mul(60)?.checked_add(value)?;
+    }
+
+    // 最後の部分は2桁の秒数
+    let last_millis = parse_decimal_seconds(last)?;
+    if last.split('.').next()?.len() != 2 || last_millis >= 60 * 1000 {
+        return None;
+    }
+    all_minutes.checked_mul(60 * 1000)?.checked_add(last_millis)
+}
+
+/// `1h2m3s`形式の文字列をミリ秒にパースする．単位はh・m・sの順に一度ずつのみで，sのみ小数を許す
+fn parse_unit_form(s: &str) -> Option<u64> {
+    let (mut all_millis, mut number) = (0_u64, String::new());
+    let mut last_unit = None;
+    for c in s.chars() {
+        if c.is_ascii_digit() || c == '.' {
+            number.push(c);
+            continue;
+        }
+        let (unit_order, unit_seconds) = match c {
+            'h' => (0, 60 * 60),
+            'm' => (1, 60),
+            's' => (2, 1),
+            _ => return None,
+        };
+        if last_unit.is_some_and(|last| last >= unit_order) || (c != 's' && number.contains('.')) {
+            return None;
+        }
+        let value = parse_decimal_seconds(&number)?;
+        all_millis = all_millis.checked_add(value.checked_mul(unit_seconds)?)?;
+        number.clear();
+        last_unit = Some(unit_order);
+    }
+
+    (number.is_empty() && last_unit.is_some()).then_some(all_millis)
+}
+
+impl FromStr for Second {
+    type Err = DomainError;
+    /// 秒数・`mm:ss`・`h:mm:ss`・`1h2m3s`の形式をパースする．秒の部分は小数点以下3桁まで指定できる
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let millis = if trimmed.contains(':') {
+            parse_colon_form(trimmed)
+        } else if trimmed.ends_with(['h', 'm', 's']) {
+            parse_unit_form(trimmed)
+        } else {
+            parse_decimal_seconds(trimmed)
+        };
+
+        millis
+            .filter(|millis| *millis <= MAX_MILLIS)
+            .map(Second)
+            .ok_or_else(|| {
+                DomainError::DomainParseError(format!(
+                    "`{s}` is not a valid second. Use seconds, mm:ss, h:mm:ss or 1h2m3s."
+                ))
+            })
+    }
+}
+
 impl TryFrom<String> for Second {
     type Error = DomainError;
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let value_u32 = value
-            .parse::<u32>()
-            .map_err(|_| DomainError::DomainParseError("Second parse error.".to_string()))?;
-        Ok(value_u32.into())
+        value.parse()
     }
 }
 
 impl Display for Second {
+    /// 秒数を表示する．`{:#}`の場合は`h:mm:ss`の形式で表示する．ミリ秒がある場合は小数で表示する
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        if f.alternate() {
+            let (hours, minutes, seconds) = self.to_hms();
+            write!(f, "{hours}:{minutes:02}:{seconds:02}")?;
+        } else {
+            write!(f, "{}", self.to_u32())?;
+        }
+
+        match self.subsec_millis() {
+            0 => Ok(()),
+            millis => write!(f, ".{}", format!("{millis:03}").trim_end_matches('0')),
+        }
+    }
+}
+
+impl Serialize for Second {
+    /// ミリ秒が無い場合は従来通り整数，ある場合は小数としてシリアライズする
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.subsec_millis() {
+            0 => serializer.serialize_u64(self.0 / 1000),
+            _ => serializer.serialize_f64(self.to_secs_f64()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Second {
+    /// 整数・小数の秒数からデシリアライズする
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SecondVisitor;
+
+        impl<'de> de::Visitor<'de> for SecondVisitor {
+            type Value = Second;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(formatter, "a non-negative number of seconds")
+            }
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                v.checked_mul(1000)
+                    .filter(|millis| *millis <= MAX_MILLIS)
+                    .map(Second)
+                    .ok_or_else(|| E::custom(format!("`{v}` is too large second.")))
+            }
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                let v = u64::try_from(v).map_err(|_| E::custom(format!("`{v}` is negative.")))?;
+                self.visit_u64(v)
+            }
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Second::from_secs_f64(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(SecondVisitor)
+    }
+}
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for Second {
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let seconds = Faker.fake_with_rng::<u32, R>(rng) as u64;
+        Self(seconds * 1000 + (0..1000).fake_with_rng::<u64, R>(rng))
     }
 }
 
@@ -75,6 +260,7 @@ impl Display for Second {
 // SecondRange
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "SecondRangeInput")]
 pub struct SecondRange {
     start: Second,
     end: Second,
@@ -87,13 +273,24 @@ impl SecondRange {
     pub fn end(&self) -> Second {
         self.end
     }
+    /// 範囲の終了が動画の長さ以内かどうかを検証する
+    pub fn check_duration(&self, duration: Second) -> Result<(), DomainError> {
+        if self.end > duration {
+            return Err(
+                ValidationError::new(ValidationCode::ExceedsDuration, "range.end")
+                    .with_param("duration", duration)
+                    .into(),
+            );
+        }
+        Ok(())
+    }
 }
 
 impl Default for SecondRange {
     fn default() -> Self {
         Self {
-            start: Second(0),
-            end: Second(1000),
+            start: Second::from_u32(0),
+            end: Second::from_u32(1000),
         }
     }
 }
@@ -101,11 +298,13 @@ impl Default for SecondRange {
 impl TryFrom<Range<Second>> for SecondRange {
     type Error = DomainError;
     fn try_from(value: Range<Second>) -> Result<Self, Self::Error> {
-        (value.start <= value.end)
-            .then_some(())
-            .ok_or(DomainError::DomainLogicError(
-                "It is needed start < end".to_string(),
-            ))?;
+        if value.start >= value.end {
+            return Err(
+                ValidationError::new(ValidationCode::EndBeforeStart, "range.end")
+                    .with_param("start", value.start)
+                    .into(),
+            );
+        }
         Ok(Self {
             start: value.start,
             end: value.end,
@@ -123,14 +322,66 @@ impl TryFrom<Range<u32>> for SecondRange {
     }
 }
 
+impl FromStr for SecondRange {
+    type Err = DomainError;
+    /// `12:00-13:30`のように開始と終了を`-`で区切った文字列をパースする
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split_once('-').ok_or_else(|| {
+            DomainError::DomainParseError(format!(
+                "`{s}` is not a valid range. Separate start and end with `-`."
+            ))
+        })?;
+        let (start, end): (Second, Second) = (start.parse()?, end.parse()?);
+
+        (start..end).try_into()
+    }
+}
+
+impl TryFrom<String> for SecondRange {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for SecondRange {
+    /// `開始-終了`の形式で表示する．`{:#}`の場合はそれぞれ`h:mm:ss`の形式で表示する
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#}-{:#}", self.start, self.end)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+/// 検証前の`SecondRange`の入力
+#[derive(Debug, Clone, Deserialize)]
+pub struct SecondRangeInput {
+    pub start: Second,
+    pub end: Second,
+}
+
+impl TryFrom<SecondRangeInput> for SecondRange {
+    type Error = DomainError;
+    fn try_from(value: SecondRangeInput) -> Result<Self, Self::Error> {
+        (value.start..value.end).try_into()
+    }
+}
+
+impl FromInput for SecondRange {
+    type Input = SecondRangeInput;
+    fn from_input(input: SecondRangeInput, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
 #[cfg(any(test, feature = "fake"))]
 impl Dummy<Faker> for SecondRange {
     fn dummy_with_rng<R: rand::Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
-        let start = (0..3600).fake_with_rng::<u32, R>(rng);
-        let end = start
-            .checked_add((0..3600).fake_with_rng(rng))
-            .unwrap_or(u32::MAX);
-        (start..end)
+        let start = (0..3600 * 1000).fake_with_rng::<u64, R>(rng);
+        let end = start + (1..3600 * 1000).fake_with_rng::<u64, R>(rng);
+        (Second::from_millis(start)..Second::from_millis(end))
             .try_into()
             .expect("Generate fake SecondRange Error")
     }
@@ -159,17 +410,167 @@ mod test {
         assert_eq!(Second::from_u32(356).to_string(), "356".to_string());
     }
 
+    #[test]
+    fn second_parse_human_formats() {
+        let cases = [
+            ("5025", 5025),
+            (" 5025 ", 5025),
+            ("1:23:45", 5025),
+            ("83:45", 5025),
+            ("0:05", 5),
+            ("1h23m45s", 5025),
+            ("1h", 3600),
+            ("23m", 1380),
+            ("45s", 45),
+            ("1h45s", 3645),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(
+                Second::from_u32(expected),
+                s.parse::<Second>().unwrap(),
+                "{s}"
+            );
+        }
+
+        for s in [
+            "",
+            "abc",
+            "-1",
+            "1:2",
+            "1:60",
+            "1:00:60",
+            "1:2:3:4",
+            ":30",
+            "1m2h",
+            "1h2",
+            "1.",
+            ".5",
+            "1.2345",
+            "1.5m",
+            "1:2.5",
+            "99999999999999999h",
+        ] {
+            let res = s.parse::<Second>();
+            assert!(
+                matches!(res, Err(DomainError::DomainParseError(_))),
+                "{s}: {res:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn second_millis() {
+        let cases = [
+            ("1.5", 1500),
+            ("0.05", 50),
+            ("1:23:45.678", 5025678),
+            ("23:45.1", 1425100),
+            ("1h2m3.25s", 3723250),
+            ("3.5s", 3500),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(
+                Second::from_millis(expected),
+                s.parse::<Second>().unwrap(),
+                "{s}"
+            );
+        }
+
+        let second = Second::from_millis(5025678);
+        assert_eq!(5025, second.to_u32());
+        assert_eq!(678, second.subsec_millis());
+        assert_eq!("5025.678", second.to_string());
+        assert_eq!("1:23:45.678", format!("{second:#}"));
+        assert_eq!("1.5", Second::from_millis(1500).to_string());
+
+        assert_eq!(
+            Second::from_millis(1235),
+            Second::from_secs_f64(1.2346).unwrap()
+        );
+        assert!(Second::from_secs_f64(-1.0).is_err());
+        assert!(Second::from_secs_f64(f64::NAN).is_err());
+
+        // ミリ秒の精度で開始と終了を比較する
+        let res = SecondRange::try_from(Second::from_millis(1500)..Second::from_millis(1499));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn second_display_hms() {
+        assert_eq!("5025", Second::from_u32(5025).to_string());
+        assert_eq!("1:23:45", format!("{:#}", Second::from_u32(5025)));
+        assert_eq!("0:01:05", format!("{:#}", Second::from_u32(65)));
+
+        // h:mm:ssの表示はパースして元に戻る
+        let second = Faker.fake::<Second>();
+        assert_eq!(second, format!("{second:#}").parse::<Second>().unwrap());
+    }
+
+    #[test]
+    fn second_range_parse() {
+        let range = "12:00-13:30".parse::<SecondRange>().unwrap();
+        assert_eq!(SecondRange::try_from(720_u32..810_u32).unwrap(), range);
+        assert_eq!("720-810", range.to_string());
+        assert_eq!("0:12:00-0:13:30", format!("{range:#}"));
+        assert_eq!(range, format!("{range:#}").parse::<SecondRange>().unwrap());
+
+        let range = TryInto::<SecondRange>::try_into("1h-1h30s".to_string()).unwrap();
+        assert_eq!(SecondRange::try_from(3600_u32..3630_u32).unwrap(), range);
+
+        let res = "13:30-12:00".parse::<SecondRange>();
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(ValidationCode::EndBeforeStart, validation_error.code);
+
+        for s in ["12:00", "12:00-", "a-13:30"] {
+            let res = s.parse::<SecondRange>();
+            assert!(
+                matches!(res, Err(DomainError::DomainParseError(_))),
+                "{s}: {res:?}"
+            );
+        }
+    }
+
     #[test]
     fn second_range_try_from() {
+        // 終了が開始以前の場合はエラー
         let start = Faker.fake::<Second>();
         let end: Second = start.to_u32().saturating_sub(Faker.fake()).into();
         let res: Result<SecondRange, DomainError> = (start..end).try_into();
-        assert!(matches!(res, Err(DomainError::DomainLogicError(_))));
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(
+            validation_error,
+            ValidationError::new(ValidationCode::EndBeforeStart, "range.end")
+                .with_param("start", start)
+        );
+
+        let res: Result<SecondRange, DomainError> = (start..start).try_into();
+        assert!(res.is_err());
 
-        let start = Faker.fake::<u32>();
-        let end = start.saturating_add(Faker.fake());
+        let start = (0..u32::MAX / 2).fake::<u32>();
+        let end = start + (1..u32::MAX / 2).fake::<u32>();
         let res: Result<SecondRange, DomainError> = (start..end).try_into();
-        assert!(matches!(res, Ok(_)));
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn second_range_check_duration() {
+        let range = SecondRange::try_from(10_u32..600_u32).unwrap();
+        assert!(range.check_duration(Second::from_u32(600)).is_ok());
+        assert!(range.check_duration(Second::from_u32(3600)).is_ok());
+
+        let res = range.check_duration(Second::from_millis(599_999));
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(
+            validation_error,
+            ValidationError::new(ValidationCode::ExceedsDuration, "range.end")
+                .with_param("duration", "599.999")
+        );
     }
 
     #[test]
@@ -181,6 +582,32 @@ mod test {
         let json_str = r#"200"#.to_string();
         let second = serde_json::from_str::<Second>(&json_str).unwrap();
         assert_eq!(second, Second::from_u32(200));
+
+        // ミリ秒がある場合は小数となる
+        let second = Second::from_millis(12345);
+        let json_str = serde_json::to_string(&second).unwrap();
+        assert_eq!(json_str, r#"12.345"#);
+        assert_eq!(second, serde_json::from_str::<Second>(&json_str).unwrap());
+
+        let range = serde_json::from_str::<SecondRange>(r#"{"start":10,"end":12.5}"#).unwrap();
+        assert_eq!(Second::from_u32(10), range.start());
+        assert_eq!(Second::from_millis(12500), range.end());
+
+        assert!(serde_json::from_str::<Second>("-1").is_err());
+        assert!(serde_json::from_str::<Second>(r#""10""#).is_err());
+
+        let json_str = r#"{"start":100,"end":50}"#.to_string();
+        assert!(serde_json::from_str::<SecondRange>(&json_str).is_err());
+
+        // 入力から検証する場合はバリデーションエラーを型のまま返す
+        let input = serde_json::from_str::<SecondRangeInput>(&json_str).unwrap();
+        assert!(matches!(
+            SecondRange::from_input(input, "range"),
+            Err(DomainError::DomainValidationError(ValidationError {
+                code: ValidationCode::EndBeforeStart,
+                ..
+            }))
+        ));
     }
 
     #[test]
@@ -1,7 +1,9 @@
-use crate::DomainError;
+use crate::movie_clip::Second;
+use crate::{DomainError, FromInput, ValidationCode, ValidationError};
 use config::CONFIG;
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, str::FromStr};
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+use url::Url;
 
 #[cfg(any(test, feature = "fake"))]
 use fake::{Dummy, Faker};
@@ -9,93 +11,307 @@ use fake::{Dummy, Faker};
 #[cfg(any(test, feature = "fake"))]
 use rand::{seq::SliceRandom, Rng};
 
-pub const MOVIE_URL_ALLOW_PREFIX: [&str; 2] = ["https://www.youtube.com/", "https://youtu.be/"];
+/// エラーメッセージで示す許可されたurlのプレフィックス
+pub const MOVIE_URL_ALLOW_PREFIX: [&str; 10] = [
+    "https://www.youtube.com/",
+    "https://m.youtube.com/",
+    "https://music.youtube.com/",
+    "https://youtu.be/",
+    "https://www.twitch.tv/",
+    "https://twitch.tv/",
+    "https://www.nicovideo.jp/",
+    "https://nico.ms/",
+    "https://x.com/",
+    "https://twitter.com/",
+];
+
+/// 動画プラットフォーム
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Platform {
+    #[default]
+    YouTube,
+    Twitch,
+    Niconico,
+    X,
+}
+
+impl Platform {
+    /// urlのホストに対応するプラットフォーム
+    pub(crate) fn from_host(host: &str) -> Option<Self> {
+        match host {
+            "www.youtube.com"
+            | "youtube.com"
+            | "m.youtube.com"
+            | "music.youtube.com"
+            | "www.youtube-nocookie.com"
+            | "youtu.be" => Some(Platform::YouTube),
+            "www.twitch.tv" | "twitch.tv" | "m.twitch.tv" => Some(Platform::Twitch),
+            "www.nicovideo.jp" | "nicovideo.jp" | "sp.nicovideo.jp" | "nico.ms" => {
+                Some(Platform::Niconico)
+            }
+            "x.com" | "www.x.com" | "twitter.com" | "www.twitter.com" | "mobile.twitter.com" => {
+                Some(Platform::X)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Platform::YouTube => "YouTube",
+            Platform::Twitch => "Twitch",
+            Platform::Niconico => "ニコニコ動画",
+            Platform::X => "X",
+        };
+        write!(f, "{name}")
+    }
+}
 
 /// MovieClipで用いるURL
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(try_from = "String", into = "String")]
 pub struct MovieUrl {
-    url_string: String, // 成型されたurl全体
-    video_id: String,   // 動画プラットフォームの動画ID
+    platform: Platform,    // 動画プラットフォーム
+    video_id: String,      // 動画プラットフォームの動画ID
+    start: Option<Second>, // urlのタイムスタンプ．正規化されたurlには含めない
 }
 
+/// 正規化されたurlと同様に，タイムスタンプによらず同じ動画を指す場合に等しい
+impl PartialEq for MovieUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.platform == other.platform && self.video_id == other.video_id
+    }
+}
+
+impl Eq for MovieUrl {}
+
 impl MovieUrl {
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
     pub fn video_id(&self) -> &str {
         &self.video_id
     }
+    /// urlのタイムスタンプ(`t`など)が示す開始時間
+    pub fn start(&self) -> Option<Second> {
+        self.start
+    }
     pub fn from_url_str(url_str: &str) -> Result<Self, DomainError> {
         url_str.parse()
     }
+    /// 埋め込みプレーヤーのurl．originは埋め込むページのオリジン，startは開始秒数．
+    pub fn embed_url(&self, origin: &str, start: Option<u32>) -> String {
+        let video_id = &self.video_id;
+        match self.platform {
+            Platform::YouTube => {
+                let mut url = format!("https://www.youtube.com/embed/{video_id}?origin={origin}&iv_load_policy=3&modestbranding=1&playsinline=1&showinfo=0&rel=0&enablejsapi=1");
+                if let Some(start) = start {
+                    url.push_str(&format!("&start={start}"));
+                }
+                url
+            }
+            Platform::Twitch => {
+                // parentにはスキームとポートを除いたホスト名を指定する
+                let parent = origin
+                    .split("://")
+                    .last()
+                    .and_then(|host| host.split([':', '/']).next())
+                    .unwrap_or_default();
+                let mut url = format!(
+                    "https://player.twitch.tv/?video=v{video_id}&parent={parent}&autoplay=false"
+                );
+                if let Some(start) = start {
+                    url.push_str(&format!("&time={}", hms_string(start.into())));
+                }
+                url
+            }
+            Platform::Niconico => {
+                let mut url = format!("https://embed.nicovideo.jp/watch/{video_id}");
+                if let Some(start) = start {
+                    url.push_str(&format!("?from={start}"));
+                }
+                url
+            }
+            Platform::X => format!("https://platform.twitter.com/embed/Tweet.html?id={video_id}"),
+        }
+    }
+    /// 開始時間のタイムスタンプを付けた共有用のurl．タイムスタンプに対応しないプラットフォームでは正規化されたurl．
+    pub fn timestamped_url(&self, start: Second) -> String {
+        let video_id = &self.video_id;
+        match self.platform {
+            Platform::YouTube => format!("https://youtu.be/{video_id}?t={}", start.to_u32()),
+            Platform::Twitch => format!(
+                "https://www.twitch.tv/videos/{video_id}?t={}",
+                hms_string(start)
+            ),
+            Platform::Niconico => format!(
+                "https://www.nicovideo.jp/watch/{video_id}?from={}",
+                start.to_u32()
+            ),
+            Platform::X => self.to_string(),
+        }
+    }
+    /// サムネイル画像のurl．取得できないプラットフォームの場合はNone．
+    pub fn thumbnail_url(&self) -> Option<String> {
+        let video_id = &self.video_id;
+        match self.platform {
+            Platform::YouTube => Some(format!(
+                "https://img.youtube.com/vi/{video_id}/sddefault.jpg"
+            )),
+            Platform::Niconico => {
+                let number = video_id.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+                Some(format!(
+                    "https://nicovideo.cdn.nimg.jp/thumbnails/{number}/{number}"
+                ))
+            }
+            Platform::Twitch | Platform::X => None,
+        }
+    }
 }
 
 impl Default for MovieUrl {
     fn default() -> Self {
         MovieUrl {
-            url_string: "https://www.youtube.com/watch?v=jNQXAC9IVRw".to_string(),
+            platform: Platform::YouTube,
             video_id: "jNQXAC9IVRw".to_string(),
+            start: None,
         }
     }
 }
 
+/// クエリパラメーターが無効な場合のエラー
+fn invalid_query_error() -> DomainError {
+    ValidationError::new(ValidationCode::InvalidUrlQuery, "url").into()
+}
+
+/// 動画のidがプラットフォームの形式に合わない場合のエラー
+fn invalid_video_id_error(platform: Platform) -> DomainError {
+    ValidationError::new(ValidationCode::InvalidVideoId, "url")
+        .with_param("platform", platform)
+        .into()
+}
+
+/// `1h2m3s`形式の時間の文字列
+fn hms_string(second: Second) -> String {
+    let (hours, minutes, seconds) = second.to_hms();
+    format!("{hours}h{minutes}m{seconds}s")
+}
+
+/// urlのクエリからタイムスタンプを取得する
+fn find_start(platform: Platform, url: &Url) -> Option<Second> {
+    let keys: &[&str] = match platform {
+        Platform::YouTube => &["t", "start"],
+        Platform::Twitch => &["t"],
+        Platform::Niconico => &["from", "t"],
+        Platform::X => &[],
+    };
+    url.query_pairs()
+        .find(|(key, _)| keys.contains(&key.as_ref()))
+        .and_then(|(_, value)| value.parse::<Second>().ok())
+}
+
+/// youtubeの動画ID(英数字・`-`・`_`の11文字)かどうか
+fn is_youtube_id(id: &str) -> bool {
+    id.len() == 11
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// ニコニコ動画の動画ID(sm, nm, soに数字が続く)かどうか
+fn is_niconico_id(id: &str) -> bool {
+    ["sm", "nm", "so"]
+        .iter()
+        .any(|head| id.strip_prefix(head).map(is_numeric_id).unwrap_or(false))
+}
+
+/// 数字のみからなる動画IDかどうか
+fn is_numeric_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_digit())
+}
+
+/// パースしたurlから動画IDの候補を取得する．形式に合わない場合はNone．
+fn find_video_id<'a>(
+    platform: Platform,
+    url: &'a Url,
+    segments: &[&'a str],
+) -> Option<Cow<'a, str>> {
+    // クエリパラメーターvの値
+    let query_video_id = || {
+        url.query_pairs()
+            .find_map(|(key, value)| (key == "v").then_some(value))
+    };
+
+    match (platform, url.host_str()?, segments) {
+        (Platform::YouTube, "youtu.be", ["watch"]) => query_video_id(),
+        (Platform::YouTube, "youtu.be", [video_id, ..]) => Some(Cow::Borrowed(*video_id)),
+        (Platform::YouTube, _, ["watch"]) => query_video_id(),
+        (Platform::YouTube, _, ["shorts" | "live" | "embed" | "v" | "e", video_id, ..]) => {
+            Some(Cow::Borrowed(*video_id))
+        }
+        (Platform::Twitch, _, ["videos", video_id, ..]) => Some(Cow::Borrowed(*video_id)),
+        (Platform::Niconico, "nico.ms", [video_id, ..]) => Some(Cow::Borrowed(*video_id)),
+        (Platform::Niconico, _, ["watch", video_id, ..]) => Some(Cow::Borrowed(*video_id)),
+        // {ユーザー名}/status/{id}の形式
+        (Platform::X, _, [_user, "status", video_id, ..]) => Some(Cow::Borrowed(*video_id)),
+        _ => None,
+    }
+}
+
 impl FromStr for MovieUrl {
     type Err = DomainError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // 規定以上の長さの場合はエラーとなる
         if s.len() > CONFIG.url_string_lim {
-            return Err(DomainError::DomainParseError(format!(
-                "Url length must be less than {}",
-                CONFIG.url_string_lim
-            )));
-        }
-
-        let common_base_url = MOVIE_URL_ALLOW_PREFIX[0]; // 最終的なutl_strのベース
-
-        if s.starts_with(MOVIE_URL_ALLOW_PREFIX[0]) {
-            let prefix_string = format!("{}watch?", MOVIE_URL_ALLOW_PREFIX[0]); // watch?までの部分
-            let query_str = s.strip_prefix(&prefix_string).ok_or_else(|| {
-                DomainError::DomainParseError("Invalid query parameter".to_string())
-            })?; // watch?以降の残りの部分
-
-            let video_id_query = query_str
-                .split('&')
-                .find(|one_query| one_query.starts_with("v=")); // &で分割しv=で始まる部分
-            let video_id = video_id_query
-                .map(|video_id_query_str| video_id_query_str.strip_prefix("v=").unwrap()); // idの文字列
-            match video_id {
-                Some(video_id) => Ok(MovieUrl {
-                    url_string: format!("{common_base_url}watch?v={video_id}"),
-                    video_id: video_id.to_string(),
-                }),
-                None => Err(DomainError::DomainParseError(
-                    "Invalid query parameter".to_string(),
-                )),
-            }
-        } else if s.starts_with(MOVIE_URL_ALLOW_PREFIX[1]) {
-            let prefix_str = MOVIE_URL_ALLOW_PREFIX[1];
-            let mut query_str = s.strip_prefix(prefix_str).ok_or_else(|| {
-                DomainError::DomainParseError("Invalid query parameter".to_string())
-            })?;
-
-            //watch?v=がある場合それを削除
-            if query_str.starts_with("watch?v=") {
-                query_str = query_str.strip_prefix("watch?v=").unwrap();
-            }
+            return Err(ValidationError::new(ValidationCode::TooLong, "url")
+                .with_param("limit", CONFIG.url_string_lim)
+                .into());
+        }
 
-            let video_id = query_str.split('?').next();
-            match video_id {
-                Some(video_id) => Ok(MovieUrl {
-                    url_string: format!("{common_base_url}watch?v={video_id}"),
-                    video_id: video_id.to_string(),
-                }),
-                None => Err(DomainError::DomainParseError(
-                    "Invalid query parameter".to_string(),
-                )),
-            }
-        } else {
-            Err(DomainError::DomainParseError(format!(
-                "Invalid Url. url must have prefix: {MOVIE_URL_ALLOW_PREFIX:?}"
-            )))
+        let url = Url::parse(s.trim())
+            .map_err(|e| DomainError::DomainParseError(format!("`{s}` is not a valid url: {e}")))?;
+
+        let platform = match url.scheme() {
+            "https" | "http" => url.host_str().and_then(Platform::from_host),
+            _ => None,
+        };
+        let Some(platform) = platform else {
+            return Err(
+                ValidationError::new(ValidationCode::InvalidUrlPrefix, "url")
+                    .with_param("allowed", MOVIE_URL_ALLOW_PREFIX.join(" "))
+                    .into(),
+            );
+        };
+
+        let segments = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let video_id = find_video_id(platform, &url, &segments)
+            .filter(|video_id| !video_id.is_empty())
+            .ok_or_else(invalid_query_error)?;
+
+        let is_valid_id = match platform {
+            Platform::YouTube => is_youtube_id(&video_id),
+            Platform::Twitch | Platform::X => is_numeric_id(&video_id),
+            Platform::Niconico => is_niconico_id(&video_id),
+        };
+        if !is_valid_id {
+            return Err(invalid_video_id_error(platform));
         }
+
+        Ok(MovieUrl {
+            platform,
+            video_id: video_id.into_owned(),
+            start: find_start(platform, &url),
+        })
     }
 }
 
@@ -106,6 +322,13 @@ impl TryFrom<String> for MovieUrl {
     }
 }
 
+impl FromInput for MovieUrl {
+    type Input = String;
+    fn from_input(input: String, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
 impl From<MovieUrl> for String {
     fn from(value: MovieUrl) -> Self {
         value.to_string()
@@ -113,8 +336,15 @@ impl From<MovieUrl> for String {
 }
 
 impl Display for MovieUrl {
+    /// プラットフォームごとに正規化されたurl
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.url_string)
+        let video_id = &self.video_id;
+        match self.platform {
+            Platform::YouTube => write!(f, "https://www.youtube.com/watch?v={video_id}"),
+            Platform::Twitch => write!(f, "https://www.twitch.tv/videos/{video_id}"),
+            Platform::Niconico => write!(f, "https://www.nicovideo.jp/watch/{video_id}"),
+            Platform::X => write!(f, "https://x.com/i/status/{video_id}"),
+        }
     }
 }
 
@@ -133,6 +363,9 @@ impl Dummy<Faker> for MovieUrl {
             "https://www.youtube.com/watch?v=XPQO000tBGA",
             "https://www.youtube.com/watch?v=J0Qhk3Rb1wg",
             "https://www.youtube.com/watch?v=EAStomYmWps",
+            "https://www.twitch.tv/videos/1847213360",
+            "https://www.nicovideo.jp/watch/sm9",
+            "https://x.com/i/status/1671046218342010881",
         ];
 
         let url = urls.choose(rng).expect("Generate fake error");
@@ -142,8 +375,12 @@ impl Dummy<Faker> for MovieUrl {
 
 #[cfg(test)]
 mod test {
-    use super::MovieUrl;
+    use super::{MovieUrl, Platform};
+    use crate::movie_clip::Second;
+    use crate::{DomainError, ValidationCode, ValidationError};
+    use config::CONFIG;
     use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
 
     #[test]
     fn parse_url() {
@@ -181,6 +418,502 @@ mod test {
         assert_eq!("LjU5OOHu_As", movie_url.video_id());
     }
 
+    #[test]
+    fn parse_other_platform_url() {
+        // Twitch
+        let movie_url = MovieUrl::from_url_str("https://www.twitch.tv/videos/1847213360").unwrap();
+        assert_eq!(Platform::Twitch, movie_url.platform());
+        assert_eq!("1847213360", movie_url.video_id());
+        assert_eq!(
+            "https://www.twitch.tv/videos/1847213360",
+            movie_url.to_string()
+        );
+
+        let movie_url =
+            MovieUrl::from_url_str("https://twitch.tv/videos/1847213360?t=1h2m3s").unwrap();
+        assert_eq!(
+            "https://www.twitch.tv/videos/1847213360",
+            movie_url.to_string()
+        );
+
+        // ニコニコ動画
+        let movie_url =
+            MovieUrl::from_url_str("https://www.nicovideo.jp/watch/sm9?from=10").unwrap();
+        assert_eq!(Platform::Niconico, movie_url.platform());
+        assert_eq!("sm9", movie_url.video_id());
+        assert_eq!("https://www.nicovideo.jp/watch/sm9", movie_url.to_string());
+
+        let movie_url = MovieUrl::from_url_str("https://nico.ms/sm9").unwrap();
+        assert_eq!("https://www.nicovideo.jp/watch/sm9", movie_url.to_string());
+
+        // X(Twitter)
+        let movie_url = MovieUrl::from_url_str(
+            "https://twitter.com/user_name/status/1671046218342010881/video/1",
+        )
+        .unwrap();
+        assert_eq!(Platform::X, movie_url.platform());
+        assert_eq!("1671046218342010881", movie_url.video_id());
+        assert_eq!(
+            "https://x.com/i/status/1671046218342010881",
+            movie_url.to_string()
+        );
+
+        let movie_url =
+            MovieUrl::from_url_str("https://x.com/user_name/status/1671046218342010881?s=20")
+                .unwrap();
+        assert_eq!(
+            "https://x.com/i/status/1671046218342010881",
+            movie_url.to_string()
+        );
+
+        // 正規化されたurlは再度パースしても変わらない
+        for url in [
+            "https://www.youtube.com/watch?v=LjU5OOHu_As",
+            "https://www.twitch.tv/videos/1847213360",
+            "https://www.nicovideo.jp/watch/sm9",
+            "https://x.com/i/status/1671046218342010881",
+        ] {
+            let movie_url = MovieUrl::from_url_str(url).unwrap();
+            assert_eq!(url, movie_url.to_string());
+            assert_eq!(
+                movie_url,
+                movie_url.to_string().parse::<MovieUrl>().unwrap()
+            );
+        }
+
+        // 無効なパス
+        for url in [
+            "https://www.twitch.tv/some_channel",
+            "https://www.nicovideo.jp/watch/",
+            "https://x.com/user_name",
+            "https://x.com/user_name/likes/1671046218342010881",
+        ] {
+            let res = MovieUrl::from_url_str(url);
+            assert!(
+                matches!(
+                    res,
+                    Err(DomainError::DomainValidationError(ValidationError {
+                        code: ValidationCode::InvalidUrlQuery,
+                        ..
+                    }))
+                ),
+                "{url}: {res:?}"
+            );
+        }
+    }
+
+    /// urlのパース結果の期待値
+    enum Expected {
+        Ok(&'static str),
+        Err(ValidationCode),
+    }
+
+    #[test]
+    fn parse_url_table() {
+        let cases = [
+            // youtubeのさまざまな形式
+            (
+                "https://www.youtube.com/watch?v=LjU5OOHu_As",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://www.youtube.com/watch?feature=share&v=LjU5OOHu_As&t=10",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://youtube.com/watch?v=LjU5OOHu_As",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://m.youtube.com/watch?v=LjU5OOHu_As",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://music.youtube.com/watch?v=LjU5OOHu_As&list=RDAMVM",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://www.youtube.com/shorts/LjU5OOHu_As",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://youtube.com/shorts/LjU5OOHu_As?feature=share",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://www.youtube.com/live/LjU5OOHu_As?si=abc",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://www.youtube.com/embed/LjU5OOHu_As?start=10",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://www.youtube-nocookie.com/embed/LjU5OOHu_As",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://youtu.be/LjU5OOHu_As",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://youtu.be/LjU5OOHu_As?t=100",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "https://youtu.be/watch?v=LjU5OOHu_As",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "http://www.youtube.com/watch?v=LjU5OOHu_As",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            (
+                "  https://WWW.YOUTUBE.COM/watch?v=LjU5OOHu_As  ",
+                Expected::Ok("https://www.youtube.com/watch?v=LjU5OOHu_As"),
+            ),
+            // youtubeの無効なurl
+            (
+                "https://youtu.be/",
+                Expected::Err(ValidationCode::InvalidUrlQuery),
+            ),
+            (
+                "https://www.youtube.com/watch?list=RDAMVM",
+                Expected::Err(ValidationCode::InvalidUrlQuery),
+            ),
+            (
+                "https://www.youtube.com/watch?v=",
+                Expected::Err(ValidationCode::InvalidUrlQuery),
+            ),
+            (
+                "https://www.youtube.com/shorts/",
+                Expected::Err(ValidationCode::InvalidUrlQuery),
+            ),
+            (
+                "https://www.youtube.com/@channel",
+                Expected::Err(ValidationCode::InvalidUrlQuery),
+            ),
+            (
+                "https://www.youtube.com/watch?v=LjU5OOHu_A",
+                Expected::Err(ValidationCode::InvalidVideoId),
+            ),
+            (
+                "https://youtu.be/LjU5OOHu_As1",
+                Expected::Err(ValidationCode::InvalidVideoId),
+            ),
+            (
+                "https://www.youtube.com/shorts/LjU5OOHu!As",
+                Expected::Err(ValidationCode::InvalidVideoId),
+            ),
+            (
+                "https://gaming.youtube.com/watch?v=LjU5OOHu_As",
+                Expected::Err(ValidationCode::InvalidUrlPrefix),
+            ),
+            (
+                "ftp://www.youtube.com/watch?v=LjU5OOHu_As",
+                Expected::Err(ValidationCode::InvalidUrlPrefix),
+            ),
+            // その他のプラットフォーム
+            (
+                "https://m.twitch.tv/videos/1847213360",
+                Expected::Ok("https://www.twitch.tv/videos/1847213360"),
+            ),
+            (
+                "https://www.twitch.tv/videos/abc",
+                Expected::Err(ValidationCode::InvalidVideoId),
+            ),
+            (
+                "https://sp.nicovideo.jp/watch/sm9",
+                Expected::Ok("https://www.nicovideo.jp/watch/sm9"),
+            ),
+            (
+                "https://nico.ms/lv123",
+                Expected::Err(ValidationCode::InvalidVideoId),
+            ),
+            (
+                "https://mobile.twitter.com/user_name/status/1671046218342010881",
+                Expected::Ok("https://x.com/i/status/1671046218342010881"),
+            ),
+            (
+                "https://x.com/user_name/status/abc",
+                Expected::Err(ValidationCode::InvalidVideoId),
+            ),
+        ];
+
+        for (url, expected) in cases {
+            let res = MovieUrl::from_url_str(url);
+            match expected {
+                Expected::Ok(normalized) => {
+                    assert_eq!(normalized, res.unwrap().to_string(), "{url}")
+                }
+                Expected::Err(code) => {
+                    let Err(DomainError::DomainValidationError(validation_error)) = res else {
+                        panic!("{url}: expected validation error: {res:?}");
+                    };
+                    assert_eq!(code, validation_error.code, "{url}");
+                }
+            }
+        }
+
+        // idの形式が無効な場合はプラットフォーム名を持つ
+        let res = MovieUrl::from_url_str("https://youtu.be/short");
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(
+            validation_error,
+            ValidationError::new(ValidationCode::InvalidVideoId, "url")
+                .with_param("platform", "YouTube")
+        );
+
+        // urlとして解釈できない場合はパースエラー
+        let res = MovieUrl::from_url_str("LjU5OOHu_As");
+        assert!(
+            matches!(res, Err(DomainError::DomainParseError(_))),
+            "{res:?}"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_any_youtube_form(
+            video_id in "[A-Za-z0-9_-]{11}",
+            base in prop::sample::select(vec![
+                "https://www.youtube.com/watch?v=",
+                "https://m.youtube.com/watch?v=",
+                "https://music.youtube.com/watch?v=",
+                "https://www.youtube.com/shorts/",
+                "https://www.youtube.com/live/",
+                "https://www.youtube.com/embed/",
+                "https://youtu.be/",
+            ]),
+            suffix in prop::sample::select(vec!["", "&t=10", "?si=abc", "/"]),
+        ) {
+            // watch?v=の場合は後続のクエリを&で繋ぎ，パスの区切りは付けない
+            let suffix = match (base.ends_with('='), suffix) {
+                (true, "?si=abc") => "&si=abc",
+                (true, "/") => "",
+                (false, "&t=10") => "?t=10",
+                (_, suffix) => suffix,
+            };
+            let movie_url = MovieUrl::from_url_str(&format!("{base}{video_id}{suffix}")).unwrap();
+
+            prop_assert_eq!(Platform::YouTube, movie_url.platform());
+            prop_assert_eq!(video_id.as_str(), movie_url.video_id());
+            prop_assert_eq!(format!("https://www.youtube.com/watch?v={video_id}"), movie_url.to_string());
+        }
+
+        #[test]
+        fn reject_invalid_youtube_id(
+            video_id in "[A-Za-z0-9_-]{1,10}|[A-Za-z0-9_-]{12,20}|[A-Za-z0-9_-]{5}[!.~*][A-Za-z0-9_-]{5}",
+        ) {
+            let res = MovieUrl::from_url_str(&format!("https://youtu.be/{video_id}"));
+            prop_assert!(
+                matches!(
+                    res,
+                    Err(DomainError::DomainValidationError(ValidationError {
+                        code: ValidationCode::InvalidVideoId,
+                        ..
+                    }))
+                ),
+                "{:?}",
+                res
+            );
+        }
+
+        #[test]
+        fn serialize_and_deserialize_with_start(
+            video_id in "[A-Za-z0-9_-]{11}",
+            start in 0_u32..100_000,
+        ) {
+            // タイムスタンプはシリアライズされないが，同じ動画を指すため等しい
+            let url = format!("https://www.youtube.com/watch?v={video_id}&t={start}");
+            let movie_url = MovieUrl::from_url_str(&url).unwrap();
+            prop_assert_eq!(Some(Second::from_u32(start)), movie_url.start());
+
+            let json_url = serde_json::to_string(&movie_url).unwrap();
+            prop_assert_eq!(format!(r#""https://www.youtube.com/watch?v={video_id}""#), json_url.clone());
+
+            let deserialized = serde_json::from_str::<MovieUrl>(&json_url).unwrap();
+            prop_assert_eq!(None, deserialized.start());
+            prop_assert_eq!(&movie_url, &deserialized);
+        }
+
+        #[test]
+        fn parse_arbitrary_string(s in ".*") {
+            // 任意の文字列でパニックせず，成功した場合は正規化したurlも同じ動画を指す
+            if let Ok(movie_url) = MovieUrl::from_url_str(&s) {
+                let normalized = movie_url.to_string().parse::<MovieUrl>().unwrap();
+                prop_assert_eq!(movie_url.platform(), normalized.platform());
+                prop_assert_eq!(movie_url.video_id(), normalized.video_id());
+            }
+        }
+    }
+
+    #[test]
+    fn parse_timestamp() {
+        let cases = [
+            ("https://youtu.be/LjU5OOHu_As?t=100", Some(100)),
+            ("https://youtu.be/LjU5OOHu_As?t=100s", Some(100)),
+            (
+                "https://www.youtube.com/watch?v=LjU5OOHu_As&t=1h2m3s",
+                Some(3723),
+            ),
+            (
+                "https://www.youtube.com/watch?v=LjU5OOHu_As&t=2m",
+                Some(120),
+            ),
+            (
+                "https://www.youtube.com/watch?t=1h3s&v=LjU5OOHu_As",
+                Some(3603),
+            ),
+            (
+                "https://www.youtube.com/embed/LjU5OOHu_As?start=10",
+                Some(10),
+            ),
+            (
+                "https://www.twitch.tv/videos/1847213360?t=0h10m5s",
+                Some(605),
+            ),
+            ("https://www.nicovideo.jp/watch/sm9?from=30", Some(30)),
+            ("https://x.com/i/status/1671046218342010881?t=10", None),
+            // 無効なタイムスタンプは無視する
+            ("https://youtu.be/LjU5OOHu_As", None),
+            ("https://youtu.be/LjU5OOHu_As?t=", None),
+            ("https://youtu.be/LjU5OOHu_As?t=abc", None),
+            ("https://youtu.be/LjU5OOHu_As?t=1m2h", None),
+            ("https://youtu.be/LjU5OOHu_As?t=1h2", None),
+            ("https://youtu.be/LjU5OOHu_As?t=99999999999h", None),
+        ];
+
+        for (url, expected) in cases {
+            let movie_url = MovieUrl::from_url_str(url).unwrap();
+            assert_eq!(expected.map(Second::from_u32), movie_url.start(), "{url}");
+        }
+
+        // タイムスタンプは正規化されたurlに含めない
+        let movie_url = MovieUrl::from_url_str("https://youtu.be/LjU5OOHu_As?t=100s").unwrap();
+        assert_eq!(
+            "https://www.youtube.com/watch?v=LjU5OOHu_As",
+            movie_url.to_string()
+        );
+    }
+
+    #[test]
+    fn timestamped_url() {
+        let cases = [
+            (
+                "https://www.youtube.com/watch?v=LjU5OOHu_As",
+                "https://youtu.be/LjU5OOHu_As?t=3723",
+            ),
+            (
+                "https://www.twitch.tv/videos/1847213360",
+                "https://www.twitch.tv/videos/1847213360?t=1h2m3s",
+            ),
+            (
+                "https://www.nicovideo.jp/watch/sm9",
+                "https://www.nicovideo.jp/watch/sm9?from=3723",
+            ),
+            (
+                "https://x.com/i/status/1671046218342010881",
+                "https://x.com/i/status/1671046218342010881",
+            ),
+        ];
+
+        for (url, expected) in cases {
+            let movie_url = MovieUrl::from_url_str(url).unwrap();
+            let timestamped_url = movie_url.timestamped_url(Second::from_u32(3723));
+            assert_eq!(expected, timestamped_url);
+
+            // 共有用のurlをパースすると同じ開始時間となる
+            if movie_url.platform() != Platform::X {
+                assert_eq!(
+                    Some(Second::from_u32(3723)),
+                    MovieUrl::from_url_str(&timestamped_url).unwrap().start()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn embed_and_thumbnail_url() {
+        let origin = "https://rikou-meitan.shuttleapp.rs";
+
+        let movie_url = MovieUrl::from_url_str("https://youtu.be/LjU5OOHu_As").unwrap();
+        assert_eq!(
+            "https://www.youtube.com/embed/LjU5OOHu_As?origin=https://rikou-meitan.shuttleapp.rs&iv_load_policy=3&modestbranding=1&playsinline=1&showinfo=0&rel=0&enablejsapi=1&start=10",
+            movie_url.embed_url(origin, Some(10))
+        );
+        assert_eq!(
+            Some("https://img.youtube.com/vi/LjU5OOHu_As/sddefault.jpg".to_string()),
+            movie_url.thumbnail_url()
+        );
+
+        let movie_url = MovieUrl::from_url_str("https://www.twitch.tv/videos/1847213360").unwrap();
+        assert_eq!(
+            "https://player.twitch.tv/?video=v1847213360&parent=rikou-meitan.shuttleapp.rs&autoplay=false&time=1h2m3s",
+            movie_url.embed_url(origin, Some(3723))
+        );
+        assert_eq!(
+            "https://player.twitch.tv/?video=v1847213360&parent=localhost&autoplay=false",
+            movie_url.embed_url("http://localhost:8000", None)
+        );
+        assert_eq!(None, movie_url.thumbnail_url());
+
+        let movie_url = MovieUrl::from_url_str("https://www.nicovideo.jp/watch/sm9").unwrap();
+        assert_eq!(
+            "https://embed.nicovideo.jp/watch/sm9?from=10",
+            movie_url.embed_url(origin, Some(10))
+        );
+        assert_eq!(
+            Some("https://nicovideo.cdn.nimg.jp/thumbnails/9/9".to_string()),
+            movie_url.thumbnail_url()
+        );
+
+        let movie_url =
+            MovieUrl::from_url_str("https://x.com/i/status/1671046218342010881").unwrap();
+        assert_eq!(
+            "https://platform.twitter.com/embed/Tweet.html?id=1671046218342010881",
+            movie_url.embed_url(origin, Some(10))
+        );
+        assert_eq!(None, movie_url.thumbnail_url());
+    }
+
+    #[test]
+    fn parse_invalid_url() {
+        let long_url = format!(
+            "https://www.youtube.com/watch?v={}",
+            "a".repeat(CONFIG.url_string_lim)
+        );
+        let res = MovieUrl::from_url_str(&long_url);
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(
+            validation_error,
+            ValidationError::new(ValidationCode::TooLong, "url")
+                .with_param("limit", CONFIG.url_string_lim)
+        );
+
+        let res = MovieUrl::from_url_str("https://www.youtube.com/channel/LjU5OOHu_As");
+        assert!(matches!(
+            res,
+            Err(DomainError::DomainValidationError(ValidationError {
+                code: ValidationCode::InvalidUrlQuery,
+                ..
+            }))
+        ));
+
+        let res = MovieUrl::from_url_str("https://example.com/watch?v=LjU5OOHu_As");
+        assert!(matches!(
+            res,
+            Err(DomainError::DomainValidationError(ValidationError {
+                code: ValidationCode::InvalidUrlPrefix,
+                ..
+            }))
+        ));
+    }
+
     #[test]
     fn from_str() {
         let movie_url = "https://www.youtube.com/watch?v=LjU5OOHu_As"
@@ -0,0 +1,95 @@
+use crate::{DomainError, FromInput, ValidationCode, ValidationError};
+use config::CONFIG;
+use serde::{Deserialize, Serialize};
+
+/// クエリで一度に取得する要素の数．1以上で，`CONFIG.max_page_size`を超える値は切り詰められる．
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "i64", into = "usize")]
+pub struct PageSize(usize);
+
+impl PageSize {
+    /// 1未満の場合はエラーとなり，上限を超える場合は`CONFIG.max_page_size`となる．
+    pub fn new(size: i64) -> Result<Self, DomainError> {
+        if size < 1 {
+            return Err(ValidationError::new(ValidationCode::TooSmall, "length")
+                .with_param("min", 1)
+                .into());
+        }
+        let size = usize::try_from(size).unwrap_or(usize::MAX);
+        Ok(Self(size.min(CONFIG.max_page_size)))
+    }
+    /// 上限のサイズ
+    pub fn max() -> Self {
+        Self(CONFIG.max_page_size)
+    }
+    pub fn get(&self) -> usize {
+        self.0
+    }
+    /// 続きの有無を確認するために一つ多く取得する場合の数
+    pub fn lookahead(&self) -> usize {
+        self.0 + 1
+    }
+}
+
+impl Default for PageSize {
+    fn default() -> Self {
+        Self(CONFIG.default_page_size)
+    }
+}
+
+impl TryFrom<i64> for PageSize {
+    type Error = DomainError;
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl FromInput for PageSize {
+    type Input = i64;
+    fn from_input(input: i64, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
+impl From<PageSize> for usize {
+    fn from(value: PageSize) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PageSize;
+    use crate::{DomainError, ValidationCode};
+    use config::CONFIG;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new_page_size() {
+        assert_eq!(PageSize::new(10).unwrap().get(), 10);
+        assert_eq!(PageSize::default().get(), CONFIG.default_page_size);
+
+        // 上限を超える場合は切り詰められる
+        assert_eq!(PageSize::new(i64::MAX).unwrap(), PageSize::max());
+        assert_eq!(PageSize::max().get(), CONFIG.max_page_size);
+
+        // 0以下はエラー
+        for size in [0, -1, i64::MIN] {
+            let Err(DomainError::DomainValidationError(validation_error)) = PageSize::new(size)
+            else {
+                panic!("Expected validation error: {size}");
+            };
+            assert_eq!(validation_error.code, ValidationCode::TooSmall);
+            assert_eq!(validation_error.field, "length");
+        }
+    }
+
+    #[test]
+    fn deserialize_page_size() {
+        let page_size = serde_json::from_str::<PageSize>("30").unwrap();
+        assert_eq!(page_size.get(), 30);
+
+        assert!(serde_json::from_str::<PageSize>("0").is_err());
+        assert!(serde_json::from_str::<PageSize>("-5").is_err());
+    }
+}
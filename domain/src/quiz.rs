@@ -0,0 +1,222 @@
+use crate::ids::Id;
+use crate::DomainError;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use sqlx::{postgres::PgRow, FromRow, Row};
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{faker::lorem::en::Words, Dummy, Fake, Faker};
+
+// -------------------------------------------------------------------------------------------------
+// # QuizId
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct QuizIdType;
+
+/// Quizに対応するID
+pub type QuizId = Id<QuizIdType>;
+
+// -------------------------------------------------------------------------------------------------
+// # Quiz
+
+/// 匿名での編集の前に出題するクイズのエンティティ
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "QuizFields")]
+pub struct Quiz {
+    /// 問題文
+    question: String,
+    /// 正解とみなす回答
+    answers: Vec<String>,
+    /// id
+    id: QuizId,
+}
+
+impl Quiz {
+    /// コンストラクタ．問題文が空の場合・回答が一つも無い場合はエラーとなる．
+    pub fn new(question: String, answers: Vec<String>) -> Result<Self, DomainError> {
+        check_question_and_answers(&question, &answers)?;
+
+        Ok(Self {
+            question,
+            answers,
+            id: QuizId::generate(),
+        })
+    }
+    /// 問題文を取得
+    pub fn question(&self) -> &str {
+        &self.question
+    }
+    /// 問題文の可変参照を取得
+    pub fn question_mut(&mut self) -> &mut String {
+        &mut self.question
+    }
+    /// 回答を取得
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+    /// 回答の可変参照を取得
+    pub fn answers_mut(&mut self) -> &mut Vec<String> {
+        &mut self.answers
+    }
+    /// idを取得
+    pub fn id(&self) -> QuizId {
+        self.id
+    }
+    /// `answer`が正解かどうか．前後の空白は無視する．
+    pub fn is_correct(&self, answer: &str) -> bool {
+        let answer = answer.trim();
+        !answer.is_empty() && self.answers.iter().any(|correct| correct.trim() == answer)
+    }
+    /// idはそのままにotherのフィールドを自身にコピー
+    pub fn assign(&mut self, other: Self) {
+        let new_self = Self {
+            id: self.id(),
+            ..other
+        };
+        *self = new_self;
+    }
+}
+
+/// 問題文が空でなく，空でない回答が一つ以上あるかどうか
+fn check_question_and_answers(question: &str, answers: &[String]) -> Result<(), DomainError> {
+    if question.trim().is_empty() {
+        return Err(DomainError::DomainLogicError(
+            "Quiz question must not be empty.".to_string(),
+        ));
+    }
+    if answers.iter().all(|answer| answer.trim().is_empty()) {
+        return Err(DomainError::DomainLogicError(
+            "Quiz must have at least one answer.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// デシリアライズ時に問題文と回答の検証を行うための型
+#[derive(Deserialize)]
+struct QuizFields {
+    question: String,
+    answers: Vec<String>,
+    id: QuizId,
+}
+
+impl TryFrom<QuizFields> for Quiz {
+    type Error = DomainError;
+    fn try_from(value: QuizFields) -> Result<Self, Self::Error> {
+        check_question_and_answers(&value.question, &value.answers)?;
+        Ok(Self {
+            question: value.question,
+            answers: value.answers,
+            id: value.id,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Quiz as entity
+
+#[cfg(feature = "server")]
+impl FromRow<'_, PgRow> for Quiz {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        use uuid::Uuid;
+
+        let question: String = row.try_get("question")?;
+        let answers: Vec<String> = row.try_get("answers")?;
+        let id: Uuid = row.try_get("id")?;
+
+        Ok(Self {
+            question,
+            answers,
+            id: id.into(),
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for Quiz {
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let question = Words(1..10).fake_with_rng::<Vec<String>, R>(rng).join(" ") + "?";
+        let answers = (1..4)
+            .map(|_| (4..12).fake_with_rng::<String, R>(rng))
+            .collect::<Vec<_>>();
+
+        Self {
+            question,
+            answers,
+            id: QuizId::generate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Quiz;
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn quiz() {
+        let quiz = Quiz::new(
+            "question".to_string(),
+            vec!["answer".to_string(), "another answer".to_string()],
+        )
+        .unwrap();
+        assert_eq!(quiz.question(), "question");
+
+        assert!(Quiz::new(" ".to_string(), vec!["answer".to_string()]).is_err());
+        assert!(Quiz::new("question".to_string(), vec![]).is_err());
+        assert!(Quiz::new("question".to_string(), vec![" ".to_string()]).is_err());
+    }
+
+    #[test]
+    fn deserialize_quiz() {
+        let quiz = Faker.fake::<Quiz>();
+        let json = serde_json::to_string(&quiz).unwrap();
+        assert_eq!(serde_json::from_str::<Quiz>(&json).unwrap(), quiz);
+
+        let id = quiz.id();
+        assert!(serde_json::from_value::<Quiz>(serde_json::json!({
+            "question": " ",
+            "answers": ["answer"],
+            "id": id,
+        }))
+        .is_err());
+        assert!(serde_json::from_value::<Quiz>(serde_json::json!({
+            "question": "question",
+            "answers": [],
+            "id": id,
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn quiz_is_correct() {
+        let quiz = Quiz::new(
+            "question".to_string(),
+            vec!["answer".to_string(), "another answer".to_string()],
+        )
+        .unwrap();
+
+        assert!(quiz.is_correct("answer"));
+        assert!(quiz.is_correct(" another answer "));
+        assert!(!quiz.is_correct("wrong answer"));
+        assert!(!quiz.is_correct(""));
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut quiz = Faker.fake::<Quiz>();
+        let previous_id = quiz.id();
+
+        let other_quiz = Faker.fake::<Quiz>();
+        quiz.assign(other_quiz.clone());
+
+        assert_eq!(quiz.id(), previous_id);
+        assert_eq!(quiz.question(), other_quiz.question());
+        assert_eq!(quiz.answers(), other_quiz.answers());
+    }
+}
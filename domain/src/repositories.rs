@@ -1,24 +1,57 @@
+use crate::channel::{Channel, ChannelId, ChannelSummary};
+use crate::client::ClientId;
+use crate::clip_series::{ClipSeries, ClipSeriesId};
+use crate::cursor::Cursor;
 use crate::episode::{Episode, EpisodeId};
 use crate::movie_clip::{MovieClip, MovieClipId};
+use crate::quiz::{Quiz, QuizId};
+use crate::revision::{Actor, Revision, RevisionId};
+use crate::search::{SearchHit, SearchQuery};
+use crate::user::{User, UserId};
 use crate::video::{Video, VideoId, VideoType};
-use crate::Date;
+use crate::{Date, DateTime, Tag};
 use async_trait::async_trait;
 
 /// MovieClipのリポジトリのトレイト
 #[async_trait]
 pub trait MovieClipRepository {
     type Error;
-    /// MovieClipを保存する．
-    async fn save(&self, movie_clip: MovieClip)
-        -> Result<(), <Self as MovieClipRepository>::Error>;
-    /// MovieClipを編集する．
-    async fn edit(&self, movie_clip: MovieClip)
-        -> Result<(), <Self as MovieClipRepository>::Error>;
-    /// idをもつMovieClipのLikeをインクリメントする．
+    /// `actor`の編集履歴を残してMovieClipを保存する．
+    async fn save(
+        &self,
+        movie_clip: MovieClip,
+        actor: Actor,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// `actor`の編集履歴を残して複数のMovieClipを一度に保存する．一つでも保存できない場合は何も保存しない．
+    async fn save_many(
+        &self,
+        movie_clips: Vec<MovieClip>,
+        actor: Actor,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// `actor`の編集履歴を残してMovieClipを編集する．版数が一致しない場合はエラーとなる．
+    async fn edit(
+        &self,
+        movie_clip: MovieClip,
+        actor: Actor,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// idをもつMovieClipのLikeをインクリメントする．`client`が既にLikeしている場合は何もしない．
     async fn increment_like(
         &self,
         id: MovieClipId,
+        client: ClientId,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// idをもつMovieClipのLikeをデクリメントする．`client`がLikeしていない場合は何もしない．
+    async fn unlike(
+        &self,
+        id: MovieClipId,
+        client: ClientId,
     ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// `ids`のうち`client`がLikeしているMovieClipのidを取得する．
+    async fn liked_ids(
+        &self,
+        ids: &[MovieClipId],
+        client: ClientId,
+    ) -> Result<Vec<MovieClipId>, <Self as MovieClipRepository>::Error>;
     /// 全てのMovieClipを取得する．
     async fn all(&self) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
     /// Likeで並べてlength分のMovieClipを取得する．
@@ -26,10 +59,10 @@ pub trait MovieClipRepository {
         &self,
         length: usize,
     ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
-    /// Likeで並べてreference以降のMovieClipをlength分取得する．
+    /// Likeで並べて`cursor`以降のMovieClipをlength分取得する．
     async fn order_by_like_later(
         &self,
-        reference: &MovieClip,
+        cursor: &Cursor<u32, MovieClipId>,
         length: usize,
     ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
     /// create_dateで並べてstartからendまでの範囲分のMovieClipを取得する．
@@ -43,46 +76,228 @@ pub trait MovieClipRepository {
         &self,
         length: usize,
     ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
-    /// create_dateで並べてreference以降のMovieClipをlength分取得する．
+    /// create_dateで並べて`cursor`以降のMovieClipをlength分取得する．
     async fn order_by_create_date_later(
         &self,
-        reference: &MovieClip,
+        cursor: &Cursor<Date, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
+    /// `tags`を全て持つMovieClipをLikeで並べてlength分取得する．
+    async fn order_by_like_with_tags(
+        &self,
+        tags: &[Tag],
         length: usize,
     ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
-    /// idを持つ要素を削除する．
-    async fn remove(&self, id: MovieClipId) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// `tags`を全て持つMovieClipをLikeで並べて`cursor`以降をlength分取得する．
+    async fn order_by_like_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<u32, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
+    /// `tags`を全て持つMovieClipをcreate_dateで並べてlength分取得する．
+    async fn order_by_create_date_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
+    /// `tags`を全て持つMovieClipをcreate_dateで並べて`cursor`以降をlength分取得する．
+    async fn order_by_create_date_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<Date, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
+    /// `query`に一致するMovieClipをスコアの降順でlength分取得する．
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<MovieClip>>, <Self as MovieClipRepository>::Error>;
+    /// MovieClipに付けられている全てのタグを重複無く昇順で取得する．
+    async fn all_tags(&self) -> Result<Vec<Tag>, <Self as MovieClipRepository>::Error>;
+    /// `id`をもつMovieClipのタグを昇順で取得する．
+    async fn tags(&self, id: MovieClipId)
+        -> Result<Vec<Tag>, <Self as MovieClipRepository>::Error>;
+    /// `id`をもつMovieClipにタグを付ける．既に付いている場合は何もしない．
+    async fn add_tag(
+        &self,
+        id: MovieClipId,
+        tag: Tag,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// `id`をもつMovieClipからタグを外す．付いていない場合は何もしない．
+    async fn remove_tag(
+        &self,
+        id: MovieClipId,
+        tag: Tag,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつMovieClipをゴミ箱に移す．ゴミ箱のMovieClipは通常の取得の対象とならない．
+    async fn remove(
+        &self,
+        id: MovieClipId,
+        actor: Actor,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// ゴミ箱のMovieClipを削除した日時の新しい順に取得する．
+    async fn trashed(&self) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつMovieClipをゴミ箱から戻す．
+    async fn restore(
+        &self,
+        id: MovieClipId,
+        actor: Actor,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつMovieClipをゴミ箱から完全に削除する．
+    async fn purge(
+        &self,
+        id: MovieClipId,
+        actor: Actor,
+    ) -> Result<(), <Self as MovieClipRepository>::Error>;
+    /// `id`をもつMovieClipの編集履歴を古い順に取得する．
+    async fn revisions(
+        &self,
+        id: MovieClipId,
+    ) -> Result<Vec<Revision>, <Self as MovieClipRepository>::Error>;
+    /// MovieClipの編集履歴を新しい順にlength分取得する．
+    async fn latest_revisions(
+        &self,
+        length: usize,
+    ) -> Result<Vec<Revision>, <Self as MovieClipRepository>::Error>;
+    /// `id`をもつ編集履歴を取得する．
+    async fn revision(
+        &self,
+        id: RevisionId,
+    ) -> Result<Revision, <Self as MovieClipRepository>::Error>;
 }
 
 /// Episodeのリポジトリのトレイト
 #[async_trait]
 pub trait EpisodeRepository {
     type Error;
-    /// Episodeを保存する．
-    async fn save(&self, episode: Episode) -> Result<(), <Self as EpisodeRepository>::Error>;
-    /// Episodeを編集する．
-    async fn edit(&self, episode: Episode) -> Result<(), <Self as EpisodeRepository>::Error>;
+    /// `actor`の編集履歴を残してEpisodeを保存する．
+    async fn save(
+        &self,
+        episode: Episode,
+        actor: Actor,
+    ) -> Result<(), <Self as EpisodeRepository>::Error>;
+    /// `actor`の編集履歴を残してEpisodeを編集する．版数が一致しない場合はエラーとなる．
+    async fn edit(
+        &self,
+        episode: Episode,
+        actor: Actor,
+    ) -> Result<(), <Self as EpisodeRepository>::Error>;
     /// 全てのEpisodeを取得する．
     async fn all(&self) -> Result<Vec<Episode>, <Self as EpisodeRepository>::Error>;
-    /// dateで並べてstartからendまでの範囲分のEpisodeを取得する．
+    /// dateで並べてstartの日からendの前日まで(JST)の範囲分のEpisodeを取得する．
     async fn order_by_date_range(
         &self,
         start: Date,
         end: Date,
     ) -> Result<Vec<Episode>, <Self as EpisodeRepository>::Error>;
-    /// idをもつEpisodeを削除する．
-    async fn remove(&self, id: EpisodeId) -> Result<(), <Self as EpisodeRepository>::Error>;
+    /// `tags`を全て持つEpisodeをdateで並べてstartの日からendの前日まで(JST)の範囲分取得する．
+    async fn order_by_date_range_with_tags(
+        &self,
+        tags: &[Tag],
+        start: Date,
+        end: Date,
+    ) -> Result<Vec<Episode>, <Self as EpisodeRepository>::Error>;
+    /// `query`に一致するEpisodeをスコアの降順でlength分取得する．
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<Episode>>, <Self as EpisodeRepository>::Error>;
+    /// Episodeに付けられている全てのタグを重複無く昇順で取得する．
+    async fn all_tags(&self) -> Result<Vec<Tag>, <Self as EpisodeRepository>::Error>;
+    /// `id`をもつEpisodeのタグを昇順で取得する．
+    async fn tags(&self, id: EpisodeId) -> Result<Vec<Tag>, <Self as EpisodeRepository>::Error>;
+    /// `id`をもつEpisodeにタグを付ける．既に付いている場合は何もしない．
+    async fn add_tag(
+        &self,
+        id: EpisodeId,
+        tag: Tag,
+    ) -> Result<(), <Self as EpisodeRepository>::Error>;
+    /// `id`をもつEpisodeからタグを外す．付いていない場合は何もしない．
+    async fn remove_tag(
+        &self,
+        id: EpisodeId,
+        tag: Tag,
+    ) -> Result<(), <Self as EpisodeRepository>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつEpisodeをゴミ箱に移す．ゴミ箱のEpisodeは通常の取得の対象とならない．
+    async fn remove(
+        &self,
+        id: EpisodeId,
+        actor: Actor,
+    ) -> Result<(), <Self as EpisodeRepository>::Error>;
+    /// ゴミ箱のEpisodeを削除した日時の新しい順に取得する．
+    async fn trashed(&self) -> Result<Vec<Episode>, <Self as EpisodeRepository>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつEpisodeをゴミ箱から戻す．
+    async fn restore(
+        &self,
+        id: EpisodeId,
+        actor: Actor,
+    ) -> Result<(), <Self as EpisodeRepository>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつEpisodeをゴミ箱から完全に削除する．
+    async fn purge(
+        &self,
+        id: EpisodeId,
+        actor: Actor,
+    ) -> Result<(), <Self as EpisodeRepository>::Error>;
+    /// `id`をもつEpisodeの編集履歴を古い順に取得する．
+    async fn revisions(
+        &self,
+        id: EpisodeId,
+    ) -> Result<Vec<Revision>, <Self as EpisodeRepository>::Error>;
+    /// Episodeの編集履歴を新しい順にlength分取得する．
+    async fn latest_revisions(
+        &self,
+        length: usize,
+    ) -> Result<Vec<Revision>, <Self as EpisodeRepository>::Error>;
+    /// `id`をもつ編集履歴を取得する．
+    async fn revision(
+        &self,
+        id: RevisionId,
+    ) -> Result<Revision, <Self as EpisodeRepository>::Error>;
 }
 
 /// Video<T>のリポジトリのトレイト
 #[async_trait]
 pub trait VideoRepository<T: VideoType> {
     type Error;
-    /// Video<T>を保存する．
-    async fn save(&self, video: Video<T>) -> Result<(), <Self as VideoRepository<T>>::Error>;
-    /// Video<T>を編集する．
-    async fn edit(&self, new_video: Video<T>) -> Result<(), <Self as VideoRepository<T>>::Error>;
-    /// `id`をもつVideo<T>のLikeをインクリメントする．
-    async fn increment_like(&self, id: VideoId) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `actor`の編集履歴を残してVideo<T>を保存する．
+    async fn save(
+        &self,
+        video: Video<T>,
+        actor: Actor,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `actor`の編集履歴を残して複数のVideo<T>を一度に保存する．一つでも保存できない場合は何も保存しない．
+    async fn save_many(
+        &self,
+        videos: Vec<Video<T>>,
+        actor: Actor,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `actor`の編集履歴を残してVideo<T>を編集する．版数が一致しない場合はエラーとなる．
+    async fn edit(
+        &self,
+        new_video: Video<T>,
+        actor: Actor,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `id`をもつVideo<T>のLikeをインクリメントする．`client`が既にLikeしている場合は何もしない．
+    async fn increment_like(
+        &self,
+        id: VideoId,
+        client: ClientId,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `id`をもつVideo<T>のLikeをデクリメントする．`client`がLikeしていない場合は何もしない．
+    async fn unlike(
+        &self,
+        id: VideoId,
+        client: ClientId,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `ids`のうち`client`がLikeしているVideo<T>のidを取得する．
+    async fn liked_ids(
+        &self,
+        ids: &[VideoId],
+        client: ClientId,
+    ) -> Result<Vec<VideoId>, <Self as VideoRepository<T>>::Error>;
     /// 全てのVideo<T>を取得する．
     async fn all(&self) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
     /// dateで並べて`length`分のVideo<T>を取得する．
@@ -90,10 +305,10 @@ pub trait VideoRepository<T: VideoType> {
         &self,
         length: usize,
     ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
-    /// dateで並べて`reference`以降のVideo<T>を`length`分取得する．
+    /// dateで並べて`cursor`以降のVideo<T>を`length`分取得する．
     async fn order_by_date_later(
         &self,
-        reference: &Video<T>,
+        cursor: &Cursor<DateTime, VideoId>,
         length: usize,
     ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
     /// Likeで並べてVideo<T>を`length`分取得する．
@@ -101,11 +316,226 @@ pub trait VideoRepository<T: VideoType> {
         &self,
         length: usize,
     ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
-    /// Likeで並べて`reference`以降のVideo<T>を`length`分取得する．
+    /// Likeで並べて`cursor`以降のVideo<T>を`length`分取得する．
     async fn order_by_like_later(
         &self,
-        reference: &Video<T>,
+        cursor: &Cursor<u32, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
+    /// `tags`を全て持つVideo<T>をdateで並べて`length`分取得する．
+    async fn order_by_date_with_tags(
+        &self,
+        tags: &[Tag],
         length: usize,
     ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
-    async fn remove(&self, id: VideoId) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `tags`を全て持つVideo<T>をdateで並べて`cursor`以降を`length`分取得する．
+    async fn order_by_date_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<DateTime, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
+    /// `tags`を全て持つVideo<T>をLikeで並べて`length`分取得する．
+    async fn order_by_like_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
+    /// `tags`を全て持つVideo<T>をLikeで並べて`cursor`以降を`length`分取得する．
+    async fn order_by_like_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<u32, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
+    /// `channel`が投稿したVideo<T>をdateの降順で全て取得する．
+    async fn order_by_date_with_channel(
+        &self,
+        channel: ChannelId,
+    ) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
+    /// `query`に一致するVideo<T>をスコアの降順でlength分取得する．
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<Video<T>>>, <Self as VideoRepository<T>>::Error>;
+    /// Video<T>に付けられている全てのタグを重複無く昇順で取得する．
+    async fn all_tags(&self) -> Result<Vec<Tag>, <Self as VideoRepository<T>>::Error>;
+    /// `id`をもつVideo<T>のタグを昇順で取得する．
+    async fn tags(&self, id: VideoId) -> Result<Vec<Tag>, <Self as VideoRepository<T>>::Error>;
+    /// `id`をもつVideo<T>にタグを付ける．既に付いている場合は何もしない．
+    async fn add_tag(
+        &self,
+        id: VideoId,
+        tag: Tag,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `id`をもつVideo<T>からタグを外す．付いていない場合は何もしない．
+    async fn remove_tag(
+        &self,
+        id: VideoId,
+        tag: Tag,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつVideo<T>をゴミ箱に移す．ゴミ箱のVideo<T>は通常の取得の対象とならない．
+    async fn remove(
+        &self,
+        id: VideoId,
+        actor: Actor,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// ゴミ箱のVideo<T>を削除した日時の新しい順に取得する．
+    async fn trashed(&self) -> Result<Vec<Video<T>>, <Self as VideoRepository<T>>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつVideo<T>をゴミ箱から戻す．
+    async fn restore(
+        &self,
+        id: VideoId,
+        actor: Actor,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `actor`の編集履歴を残して`id`をもつVideo<T>をゴミ箱から完全に削除する．
+    async fn purge(
+        &self,
+        id: VideoId,
+        actor: Actor,
+    ) -> Result<(), <Self as VideoRepository<T>>::Error>;
+    /// `id`をもつVideo<T>の編集履歴を古い順に取得する．
+    async fn revisions(
+        &self,
+        id: VideoId,
+    ) -> Result<Vec<Revision>, <Self as VideoRepository<T>>::Error>;
+    /// Video<T>の編集履歴を新しい順にlength分取得する．
+    async fn latest_revisions(
+        &self,
+        length: usize,
+    ) -> Result<Vec<Revision>, <Self as VideoRepository<T>>::Error>;
+    /// `id`をもつ編集履歴を取得する．
+    async fn revision(
+        &self,
+        id: RevisionId,
+    ) -> Result<Revision, <Self as VideoRepository<T>>::Error>;
+}
+
+/// Channelのリポジトリのトレイト
+#[async_trait]
+pub trait ChannelRepository {
+    type Error;
+    /// Channelを保存する．同じ名前のChannelが既に存在する場合はエラーとなる．
+    async fn save(&self, channel: Channel) -> Result<(), <Self as ChannelRepository>::Error>;
+    /// Channelを編集する．
+    async fn edit(&self, channel: Channel) -> Result<(), <Self as ChannelRepository>::Error>;
+    /// 全てのChannelを取得する．
+    async fn all(&self) -> Result<Vec<Channel>, <Self as ChannelRepository>::Error>;
+    /// `id`をもつChannelを取得する．
+    async fn find_by_id(
+        &self,
+        id: ChannelId,
+    ) -> Result<Channel, <Self as ChannelRepository>::Error>;
+    /// 全てのChannelを紐付いた動画の数とともに名前の昇順で取得する．
+    async fn all_with_video_count(
+        &self,
+    ) -> Result<Vec<ChannelSummary>, <Self as ChannelRepository>::Error>;
+    /// `id`をもつChannelを削除する．ゴミ箱のものを含めて動画が紐付いている場合はエラーとなる．
+    async fn remove(&self, id: ChannelId) -> Result<(), <Self as ChannelRepository>::Error>;
+}
+
+/// ClipSeriesのリポジトリのトレイト
+#[async_trait]
+pub trait ClipSeriesRepository {
+    type Error;
+    /// ClipSeriesを保存する．
+    async fn save(
+        &self,
+        clip_series: ClipSeries,
+    ) -> Result<(), <Self as ClipSeriesRepository>::Error>;
+    /// ClipSeriesを編集する．
+    async fn edit(
+        &self,
+        clip_series: ClipSeries,
+    ) -> Result<(), <Self as ClipSeriesRepository>::Error>;
+    /// `id`をもつClipSeriesのLikeをインクリメントする．`client`が既にLikeしている場合は何もしない．
+    async fn increment_like(
+        &self,
+        id: ClipSeriesId,
+        client: ClientId,
+    ) -> Result<(), <Self as ClipSeriesRepository>::Error>;
+    /// `id`をもつClipSeriesのLikeをデクリメントする．`client`がLikeしていない場合は何もしない．
+    async fn unlike(
+        &self,
+        id: ClipSeriesId,
+        client: ClientId,
+    ) -> Result<(), <Self as ClipSeriesRepository>::Error>;
+    /// `ids`のうち`client`がLikeしているClipSeriesのidを取得する．
+    async fn liked_ids(
+        &self,
+        ids: &[ClipSeriesId],
+        client: ClientId,
+    ) -> Result<Vec<ClipSeriesId>, <Self as ClipSeriesRepository>::Error>;
+    /// 全てのClipSeriesを取得する．
+    async fn all(&self) -> Result<Vec<ClipSeries>, <Self as ClipSeriesRepository>::Error>;
+    /// `id`をもつClipSeriesを取得する．
+    async fn find_by_id(
+        &self,
+        id: ClipSeriesId,
+    ) -> Result<ClipSeries, <Self as ClipSeriesRepository>::Error>;
+    /// Likeで並べて`length`分のClipSeriesを取得する．
+    async fn order_by_like(
+        &self,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, <Self as ClipSeriesRepository>::Error>;
+    /// Likeで並べて`cursor`以降のClipSeriesを`length`分取得する．
+    async fn order_by_like_later(
+        &self,
+        cursor: &Cursor<u32, ClipSeriesId>,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, <Self as ClipSeriesRepository>::Error>;
+    /// create_dateで並べて`length`分のClipSeriesを取得する．
+    async fn order_by_create_date(
+        &self,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, <Self as ClipSeriesRepository>::Error>;
+    /// create_dateで並べて`cursor`以降のClipSeriesを`length`分取得する．
+    async fn order_by_create_date_later(
+        &self,
+        cursor: &Cursor<Date, ClipSeriesId>,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, <Self as ClipSeriesRepository>::Error>;
+    /// `id`をもつClipSeriesを削除する．
+    async fn remove(&self, id: ClipSeriesId) -> Result<(), <Self as ClipSeriesRepository>::Error>;
+}
+
+/// Userのリポジトリのトレイト
+#[async_trait]
+pub trait UserRepository {
+    type Error;
+    /// Userを保存する．
+    async fn save(&self, user: User) -> Result<(), <Self as UserRepository>::Error>;
+    /// Userを編集する．
+    async fn edit(&self, user: User) -> Result<(), <Self as UserRepository>::Error>;
+    /// 全てのUserを取得する．
+    async fn all(&self) -> Result<Vec<User>, <Self as UserRepository>::Error>;
+    /// `name`をもつUserを取得する．
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<User>, <Self as UserRepository>::Error>;
+    /// `id`をもつUserを取得する．
+    async fn find_by_id(&self, id: UserId)
+        -> Result<Option<User>, <Self as UserRepository>::Error>;
+    /// `id`をもつUserを削除する．
+    async fn remove(&self, id: UserId) -> Result<(), <Self as UserRepository>::Error>;
+}
+
+/// Quizのリポジトリのトレイト
+#[async_trait]
+pub trait QuizRepository {
+    type Error;
+    /// Quizを保存する．
+    async fn save(&self, quiz: Quiz) -> Result<(), <Self as QuizRepository>::Error>;
+    /// Quizを編集する．
+    async fn edit(&self, quiz: Quiz) -> Result<(), <Self as QuizRepository>::Error>;
+    /// 全てのQuizを取得する．
+    async fn all(&self) -> Result<Vec<Quiz>, <Self as QuizRepository>::Error>;
+    /// `id`をもつQuizを取得する．
+    async fn find_by_id(&self, id: QuizId) -> Result<Quiz, <Self as QuizRepository>::Error>;
+    /// ランダムに一つのQuizを取得する．
+    async fn random(&self) -> Result<Quiz, <Self as QuizRepository>::Error>;
+    /// `id`をもつQuizを削除する．
+    async fn remove(&self, id: QuizId) -> Result<(), <Self as QuizRepository>::Error>;
 }
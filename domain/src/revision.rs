@@ -0,0 +1,464 @@
+use crate::date_time::DateTime;
+use crate::ids::Id;
+use crate::user::UserId;
+use crate::video::VideoType;
+use crate::DomainError;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::{fmt::Display, str::FromStr};
+use uuid::Uuid;
+
+#[cfg(feature = "server")]
+use sqlx::{postgres::PgRow, FromRow, Row};
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{Dummy, Fake, Faker};
+
+#[cfg(any(test, feature = "fake"))]
+use rand::Rng;
+
+// -------------------------------------------------------------------------------------------------
+// # RevisionId
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RevisionIdType;
+
+pub type RevisionId = Id<RevisionIdType>;
+
+// -------------------------------------------------------------------------------------------------
+// # EntityKind
+
+/// 編集履歴を持つエンティティの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum EntityKind {
+    MovieClip,
+    Original,
+    Kirinuki,
+    Episode,
+}
+
+impl EntityKind {
+    /// VideoTypeに対応する種類
+    pub fn video<T: VideoType>() -> Self {
+        T::snake_case()
+            .parse()
+            .expect("VideoType should be a kind of entity")
+    }
+}
+
+impl FromStr for EntityKind {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "movie_clip" => Ok(EntityKind::MovieClip),
+            "original" => Ok(EntityKind::Original),
+            "kirinuki" => Ok(EntityKind::Kirinuki),
+            "episode" => Ok(EntityKind::Episode),
+            _ => Err(DomainError::DomainParseError(format!(
+                "Invalid string for EntityKind: \"{s}\""
+            ))),
+        }
+    }
+}
+
+impl TryFrom<String> for EntityKind {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for EntityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntityKind::MovieClip => write!(f, "movie_clip"),
+            EntityKind::Original => write!(f, "original"),
+            EntityKind::Kirinuki => write!(f, "kirinuki"),
+            EntityKind::Episode => write!(f, "episode"),
+        }
+    }
+}
+
+impl From<EntityKind> for String {
+    fn from(value: EntityKind) -> Self {
+        value.to_string()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # Operation
+
+/// 編集履歴を残す操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Operation {
+    Save,
+    Edit,
+    /// ゴミ箱に移す
+    Remove,
+    /// ゴミ箱から戻す
+    Restore,
+    /// ゴミ箱から完全に削除する
+    Purge,
+}
+
+impl FromStr for Operation {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "save" => Ok(Operation::Save),
+            "edit" => Ok(Operation::Edit),
+            "remove" => Ok(Operation::Remove),
+            "restore" => Ok(Operation::Restore),
+            "purge" => Ok(Operation::Purge),
+            _ => Err(DomainError::DomainParseError(format!(
+                "Invalid string for Operation: \"{s}\""
+            ))),
+        }
+    }
+}
+
+impl TryFrom<String> for Operation {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Save => write!(f, "save"),
+            Operation::Edit => write!(f, "edit"),
+            Operation::Remove => write!(f, "remove"),
+            Operation::Restore => write!(f, "restore"),
+            Operation::Purge => write!(f, "purge"),
+        }
+    }
+}
+
+impl From<Operation> for String {
+    fn from(value: Operation) -> Self {
+        value.to_string()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # Actor
+
+/// 操作を行った者
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "user_id", rename_all = "snake_case")]
+pub enum Actor {
+    /// ログインしたユーザー
+    User(UserId),
+    /// Secrets.tomlの管理者
+    Admin,
+    /// クイズに正解して編集用のトークンを得た匿名の編集者
+    Anonymous,
+}
+
+impl Actor {
+    /// 種類を表す文字列
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Actor::User(_) => "user",
+            Actor::Admin => "admin",
+            Actor::Anonymous => "anonymous",
+        }
+    }
+    /// ユーザーのid．ユーザーでない場合はNone
+    pub fn user_id(&self) -> Option<UserId> {
+        match self {
+            Actor::User(user_id) => Some(*user_id),
+            _ => None,
+        }
+    }
+    /// 種類を表す文字列とユーザーのidから作成する．
+    pub fn from_parts(kind: &str, user_id: Option<UserId>) -> Result<Self, DomainError> {
+        match (kind, user_id) {
+            ("user", Some(user_id)) => Ok(Actor::User(user_id)),
+            ("admin", None) => Ok(Actor::Admin),
+            ("anonymous", None) => Ok(Actor::Anonymous),
+            _ => Err(DomainError::DomainParseError(format!(
+                "Invalid parts for Actor: \"{kind}\", {user_id:?}"
+            ))),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # Revision
+
+/// エンティティの保存・編集・削除・復元ごとに残す編集履歴．操作後(完全に削除した場合は削除前)のエンティティをjsonとして持つ．
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Revision {
+    /// UUID
+    id: RevisionId,
+    /// エンティティの種類
+    entity_kind: EntityKind,
+    /// エンティティのid
+    entity_id: Uuid,
+    /// 操作
+    operation: Operation,
+    /// エンティティのjson
+    snapshot: Value,
+    /// 操作を行った者
+    actor: Actor,
+    /// 操作を行った日時
+    created_at: DateTime,
+}
+
+impl Revision {
+    /// 現在の日時でエンティティの編集履歴を作成する．
+    pub fn new<T: Serialize>(
+        entity_kind: EntityKind,
+        entity_id: Uuid,
+        operation: Operation,
+        entity: &T,
+        actor: Actor,
+    ) -> Result<Self, DomainError> {
+        let snapshot = serde_json::to_value(entity)
+            .map_err(|e| DomainError::DomainConvertExternalError(e.to_string()))?;
+
+        Ok(Self {
+            id: RevisionId::generate(),
+            entity_kind,
+            entity_id,
+            operation,
+            snapshot,
+            actor,
+            created_at: DateTime::now(),
+        })
+    }
+    /// idを取得
+    pub fn id(&self) -> RevisionId {
+        self.id
+    }
+    /// エンティティの種類を取得
+    pub fn entity_kind(&self) -> EntityKind {
+        self.entity_kind
+    }
+    /// エンティティのidを取得
+    pub fn entity_id(&self) -> Uuid {
+        self.entity_id
+    }
+    /// 操作を取得
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+    /// エンティティのjsonを取得
+    pub fn snapshot(&self) -> &Value {
+        &self.snapshot
+    }
+    /// 操作を行った者を取得
+    pub fn actor(&self) -> Actor {
+        self.actor
+    }
+    /// 操作を行った日時を取得
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+    /// エンティティのjsonをエンティティに戻す．
+    pub fn snapshot_as<T: DeserializeOwned>(&self) -> Result<T, DomainError> {
+        serde_json::from_value(self.snapshot.clone())
+            .map_err(|e| DomainError::DomainParseError(e.to_string()))
+    }
+    /// selfからotherへの変更をフィールド名の昇順で求める．同じエンティティの編集履歴でない場合はエラー
+    pub fn diff(&self, other: &Revision) -> Result<Vec<FieldChange>, DomainError> {
+        if self.entity_kind != other.entity_kind || self.entity_id != other.entity_id {
+            return Err(DomainError::DomainLogicError(
+                "Revisions of different entities cannot be compared.".to_string(),
+            ));
+        }
+
+        let empty = Map::new();
+        let before = self.snapshot.as_object().unwrap_or(&empty);
+        let after = other.snapshot.as_object().unwrap_or(&empty);
+
+        let fields = before.keys().chain(after.keys()).collect::<BTreeSet<_>>();
+        let changes = fields
+            .into_iter()
+            .filter(|field| before.get(*field) != after.get(*field))
+            .map(|field| FieldChange {
+                field: field.to_string(),
+                before: before.get(field).cloned(),
+                after: after.get(field).cloned(),
+            })
+            .collect();
+        Ok(changes)
+    }
+}
+
+/// 二つの編集履歴の間のフィールドの変更．フィールドが無い場合はNone
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// Revision as entity
+
+#[cfg(feature = "server")]
+impl FromRow<'_, PgRow> for Revision {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        use chrono::Utc;
+
+        let id: Uuid = row.try_get("id")?;
+        let entity_kind: String = row.try_get("entity_kind")?;
+        let entity_id: Uuid = row.try_get("entity_id")?;
+        let operation: String = row.try_get("operation")?;
+        let snapshot: String = row.try_get("snapshot")?;
+        let actor_kind: String = row.try_get("actor_kind")?;
+        let actor_user_id: Option<Uuid> = row.try_get("actor_user_id")?;
+        let created_at: chrono::DateTime<Utc> = row.try_get("created_at")?;
+
+        Ok(Self {
+            id: id.into(),
+            entity_kind: entity_kind.try_into()?,
+            entity_id,
+            operation: operation.try_into()?,
+            snapshot: serde_json::from_str(&snapshot)
+                .map_err(|e| DomainError::DomainParseError(e.to_string()))?,
+            actor: Actor::from_parts(&actor_kind, actor_user_id.map(Into::into))?,
+            created_at: created_at.try_into()?,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for Actor {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        match (0..3).fake_with_rng::<u8, R>(rng) {
+            0 => Actor::User(UserId::generate()),
+            1 => Actor::Admin,
+            _ => Actor::Anonymous,
+        }
+    }
+}
+
+/// クリップの保存の編集履歴を生成する
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for Revision {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let movie_clip: crate::movie_clip::MovieClip = Faker.fake_with_rng(rng);
+        Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Save,
+            &movie_clip,
+            Faker.fake_with_rng(rng),
+        )
+        .expect("Generate fake Revision Error")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Actor, EntityKind, FieldChange, Operation, Revision};
+    use crate::episode::Episode;
+    use crate::movie_clip::MovieClip;
+    use crate::user::UserId;
+    use crate::video::{Kirinuki, Original};
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn entity_kind_of_video() {
+        assert_eq!(EntityKind::video::<Original>(), EntityKind::Original);
+        assert_eq!(EntityKind::video::<Kirinuki>(), EntityKind::Kirinuki);
+    }
+
+    #[test]
+    fn actor_serialize_and_deserialize() {
+        let user_id = UserId::generate();
+        assert_eq!(
+            serde_json::to_value(Actor::User(user_id)).unwrap(),
+            json!({"kind": "user", "user_id": user_id.to_string()})
+        );
+        assert_eq!(
+            serde_json::to_value(Actor::Anonymous).unwrap(),
+            json!({"kind": "anonymous"})
+        );
+
+        for actor in [Actor::User(user_id), Actor::Admin, Actor::Anonymous] {
+            assert_eq!(
+                Actor::from_parts(actor.kind(), actor.user_id()).unwrap(),
+                actor
+            );
+        }
+        assert!(Actor::from_parts("admin", Some(user_id)).is_err());
+    }
+
+    #[test]
+    fn snapshot_as_entity() {
+        let movie_clip = Faker.fake::<MovieClip>();
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Edit,
+            &movie_clip,
+            Actor::Admin,
+        )
+        .unwrap();
+
+        assert_eq!(revision.snapshot_as::<MovieClip>().unwrap(), movie_clip);
+        assert!(revision.snapshot_as::<Episode>().is_err());
+    }
+
+    #[test]
+    fn diff() {
+        let mut movie_clip = Faker.fake::<MovieClip>();
+        let before = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Save,
+            &movie_clip,
+            Actor::Anonymous,
+        )
+        .unwrap();
+
+        *movie_clip.title_mut() = "変更後のタイトル".to_string();
+        movie_clip.increment_version();
+        let after = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Edit,
+            &movie_clip,
+            Actor::Admin,
+        )
+        .unwrap();
+
+        // 変更したフィールドのみ
+        let changes = before.diff(&after).unwrap();
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| change.field.as_str())
+                .collect::<Vec<_>>(),
+            vec!["title", "version"]
+        );
+        assert_eq!(
+            changes[0],
+            FieldChange {
+                field: "title".to_string(),
+                before: before.snapshot().get("title").cloned(),
+                after: Some(json!("変更後のタイトル")),
+            }
+        );
+
+        // 異なるエンティティとは比較できない
+        let other = Faker.fake::<Revision>();
+        assert!(before.diff(&other).is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::episode::Episode;
+use crate::movie_clip::MovieClip;
+use crate::video::{Kirinuki, Original, Video};
+use crate::{DomainError, FromInput, ValidationCode, ValidationError};
+use config::CONFIG;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+use unicode_normalization::UnicodeNormalization;
+
+#[cfg(feature = "server")]
+use sqlx::{postgres::PgRow, FromRow, Row};
+
+/// NFKC正規化・小文字化し，連続する空白を一つにまとめる．
+fn normalize(text: &str) -> String {
+    text.nfkc()
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// -------------------------------------------------------------------------------------------------
+// # SearchQuery
+
+/// 検索の文字列．NFKC正規化・小文字化し，連続する空白を一つにまとめた文字列を持つ．空白で区切られた語を全て含むものに一致する．
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SearchQuery(String);
+
+impl SearchQuery {
+    pub fn new(query_str: &str) -> Result<Self, DomainError> {
+        query_str.parse()
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    /// 空白で区切られた語
+    pub fn terms(&self) -> impl Iterator<Item = &str> {
+        self.0.split(' ')
+    }
+    /// `texts`が全ての語を含む場合にスコアを返す．語を含む文字列が短いほどスコアは高くなる．
+    pub fn score(&self, texts: &[&str]) -> Option<f32> {
+        let texts = texts.iter().map(|text| normalize(text)).collect::<Vec<_>>();
+
+        let mut score = 0.0;
+        for term in self.terms() {
+            let term_len = term.chars().count() as f32;
+            let term_scores = texts
+                .iter()
+                .filter(|text| text.contains(term))
+                .map(|text| term_len / text.chars().count() as f32)
+                .collect::<Vec<_>>();
+
+            if term_scores.is_empty() {
+                return None;
+            }
+            score += term_scores.into_iter().sum::<f32>();
+        }
+        Some(score)
+    }
+}
+
+impl FromStr for SearchQuery {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = normalize(s);
+
+        if normalized.is_empty() {
+            return Err(ValidationError::new(ValidationCode::Empty, "query").into());
+        }
+
+        if let Some(invalid_char) = normalized.chars().find(|c| c.is_control()) {
+            return Err(
+                ValidationError::new(ValidationCode::InvalidCharacter, "query")
+                    .with_param("character", invalid_char.escape_default())
+                    .into(),
+            );
+        }
+
+        if normalized.chars().count() > CONFIG.search_query_lim {
+            return Err(ValidationError::new(ValidationCode::TooLong, "query")
+                .with_param("limit", CONFIG.search_query_lim)
+                .into());
+        }
+
+        Ok(SearchQuery(normalized))
+    }
+}
+
+impl TryFrom<String> for SearchQuery {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl FromInput for SearchQuery {
+    type Input = String;
+    fn from_input(input: String, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
+impl From<SearchQuery> for String {
+    fn from(value: SearchQuery) -> Self {
+        value.0
+    }
+}
+
+impl Display for SearchQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # SearchHit
+
+/// 検索に一致した要素とそのスコア．スコアが高いほど検索に適合する．
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchHit<T> {
+    pub item: T,
+    pub score: f32,
+}
+
+impl<T> SearchHit<T> {
+    pub fn new(item: T, score: f32) -> Self {
+        Self { item, score }
+    }
+    /// スコアはそのままに要素を変換する．
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> SearchHit<U> {
+        SearchHit {
+            item: f(self.item),
+            score: self.score,
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl<'r, T: FromRow<'r, PgRow>> FromRow<'r, PgRow> for SearchHit<T> {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(SearchHit {
+            item: T::from_row(row)?,
+            score: row.try_get("score")?,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # SearchItem
+
+/// 種類ごとの検索の対象
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "item", rename_all = "snake_case")]
+pub enum SearchItem {
+    MovieClip(MovieClip),
+    Original(Video<Original>),
+    Kirinuki(Video<Kirinuki>),
+    Episode(Episode),
+}
+
+impl From<MovieClip> for SearchItem {
+    fn from(value: MovieClip) -> Self {
+        SearchItem::MovieClip(value)
+    }
+}
+
+impl From<Video<Original>> for SearchItem {
+    fn from(value: Video<Original>) -> Self {
+        SearchItem::Original(value)
+    }
+}
+
+impl From<Video<Kirinuki>> for SearchItem {
+    fn from(value: Video<Kirinuki>) -> Self {
+        SearchItem::Kirinuki(value)
+    }
+}
+
+impl From<Episode> for SearchItem {
+    fn from(value: Episode) -> Self {
+        SearchItem::Episode(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SearchHit, SearchItem, SearchQuery};
+    use crate::movie_clip::MovieClip;
+    use crate::{DomainError, ValidationCode};
+    use config::CONFIG;
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_search_query_normalize() {
+        let query = SearchQuery::new("  ＡＢＣ　 理子\tめいたん ").unwrap();
+        assert_eq!(query.as_str(), "abc 理子 めいたん");
+        assert_eq!(
+            query.terms().collect::<Vec<_>>(),
+            vec!["abc", "理子", "めいたん"]
+        );
+    }
+
+    #[test]
+    fn test_search_query_invalid() {
+        let res = SearchQuery::new(" 　");
+        assert!(matches!(
+            res,
+            Err(DomainError::DomainValidationError(e)) if e.code == ValidationCode::Empty
+        ));
+
+        let too_long = "a".repeat(CONFIG.search_query_lim + 1);
+        let res = SearchQuery::new(&too_long);
+        assert!(matches!(
+            res,
+            Err(DomainError::DomainValidationError(e)) if e.code == ValidationCode::TooLong
+        ));
+    }
+
+    #[test]
+    fn test_search_query_score() {
+        let query = SearchQuery::new("理子 歌枠").unwrap();
+
+        // 全ての語を含まない場合は一致しない
+        assert_eq!(query.score(&["理子の雑談"]), None);
+        // 語は異なる文字列に含まれてもよい
+        assert!(query.score(&["歌枠", "理子"]).is_some());
+
+        // 語を含む文字列が短いほどスコアが高い
+        let short_score = query.score(&["理子 歌枠"]).unwrap();
+        let long_score = query.score(&["理子と名探偵の歌枠リレー"]).unwrap();
+        assert!(short_score > long_score);
+
+        // 大文字・全角を区別しない
+        let query = SearchQuery::new("ｍｅｉｔａｎ").unwrap();
+        assert!(query.score(&["Meitan Channel"]).is_some());
+    }
+
+    #[test]
+    fn test_search_hit_serialize_and_deserialize() {
+        let hit = SearchHit::new(Faker.fake::<MovieClip>(), 0.5).map(SearchItem::from);
+
+        let hit_json = serde_json::to_value(&hit).unwrap();
+        assert_eq!(hit_json["item"]["kind"], "movie_clip");
+
+        let hit_deserialized: SearchHit<SearchItem> = serde_json::from_value(hit_json).unwrap();
+        assert_eq!(hit_deserialized, hit);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{DomainError, FromInput, ValidationCode, ValidationError};
+use config::CONFIG;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+use unicode_normalization::UnicodeNormalization;
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{Dummy, Faker};
+
+#[cfg(any(test, feature = "fake"))]
+use rand::{seq::SliceRandom, Rng};
+
+/// クエリパラメーターで複数のタグを区切る文字
+pub const TAG_SEPARATOR: char = ',';
+
+/// コンテンツを分類するタグ．NFKC正規化・小文字化し，連続する空白を一つにまとめた文字列を持つ．
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Tag(String);
+
+impl Tag {
+    pub fn new(tag_str: &str) -> Result<Self, DomainError> {
+        tag_str.parse()
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    /// `TAG_SEPARATOR`で区切られた文字列を重複の無いタグの列にパースする．空の要素は無視する．
+    pub fn parse_list(tags_str: &str) -> Result<Vec<Self>, DomainError> {
+        let mut tags = tags_str
+            .split(TAG_SEPARATOR)
+            .filter(|tag_str| !tag_str.trim().is_empty())
+            .map(|tag_str| tag_str.parse::<Tag>())
+            .collect::<Result<Vec<_>, _>>()?;
+        tags.sort();
+        tags.dedup();
+
+        if tags.len() > CONFIG.max_query_tags {
+            return Err(ValidationError::new(ValidationCode::TooLong, "tags")
+                .with_param("limit", CONFIG.max_query_tags)
+                .into());
+        }
+        Ok(tags)
+    }
+    /// タグの列を`TAG_SEPARATOR`で区切った文字列にする．
+    pub fn join_list(tags: &[Tag]) -> String {
+        tags.iter()
+            .map(|tag| tag.as_str())
+            .collect::<Vec<_>>()
+            .join(&TAG_SEPARATOR.to_string())
+    }
+}
+
+impl FromStr for Tag {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s
+            .nfkc()
+            .collect::<String>()
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if normalized.is_empty() {
+            return Err(ValidationError::new(ValidationCode::Empty, "tag").into());
+        }
+
+        if let Some(invalid_char) = normalized
+            .chars()
+            .find(|c| *c == TAG_SEPARATOR || c.is_control())
+        {
+            return Err(
+                ValidationError::new(ValidationCode::InvalidCharacter, "tag")
+                    .with_param("character", invalid_char.escape_default())
+                    .into(),
+            );
+        }
+
+        if normalized.chars().count() > CONFIG.tag_string_lim {
+            return Err(ValidationError::new(ValidationCode::TooLong, "tag")
+                .with_param("limit", CONFIG.tag_string_lim)
+                .into());
+        }
+
+        Ok(Tag(normalized))
+    }
+}
+
+impl TryFrom<String> for Tag {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl FromInput for Tag {
+    type Input = String;
+    fn from_input(input: String, field: &str) -> Result<Self, DomainError> {
+        Self::try_from(input).map_err(|domain_error| domain_error.at(field))
+    }
+}
+
+impl From<Tag> for String {
+    fn from(value: Tag) -> Self {
+        value.0
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for Tag {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let tags = [
+            "singing",
+            "game",
+            "asmr",
+            "collab",
+            "talk",
+            "歌枠",
+            "ゲーム",
+            "雑談",
+        ];
+
+        let tag = tags.choose(rng).expect("Generate fake error");
+        tag.parse().expect("Generate fake error.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Tag;
+    use crate::{DomainError, ValidationCode, ValidationError};
+    use config::CONFIG;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn normalize_tag() {
+        assert_eq!(Tag::new("Singing").unwrap().as_str(), "singing");
+        assert_eq!(
+            Tag::new("  Mario   Kart\t8 ").unwrap().as_str(),
+            "mario kart 8"
+        );
+        // 全角英数字は半角になる
+        assert_eq!(Tag::new("ＡＳＭＲ").unwrap().as_str(), "asmr");
+        assert_eq!(Tag::new("ｹﾞｰﾑ").unwrap(), Tag::new("ゲーム").unwrap());
+    }
+
+    #[test]
+    fn parse_invalid_tag() {
+        let cases = [
+            ("   ", ValidationError::new(ValidationCode::Empty, "tag")),
+            (
+                "sing,game",
+                ValidationError::new(ValidationCode::InvalidCharacter, "tag")
+                    .with_param("character", ","),
+            ),
+            (
+                &"あ".repeat(CONFIG.tag_string_lim + 1),
+                ValidationError::new(ValidationCode::TooLong, "tag")
+                    .with_param("limit", CONFIG.tag_string_lim),
+            ),
+        ];
+
+        for (tag_str, expected) in cases {
+            let res = Tag::new(tag_str);
+            let Err(DomainError::DomainValidationError(validation_error)) = res else {
+                panic!("Expected validation error: {res:?}");
+            };
+            assert_eq!(validation_error, expected);
+        }
+
+        // 上限の長さはちょうど許可される
+        assert!(Tag::new(&"あ".repeat(CONFIG.tag_string_lim)).is_ok());
+    }
+
+    #[test]
+    fn parse_tag_list() {
+        let tags = Tag::parse_list("Game,singing,, GAME ").unwrap();
+        assert_eq!(
+            tags,
+            vec![Tag::new("game").unwrap(), Tag::new("singing").unwrap()]
+        );
+        assert_eq!(Tag::join_list(&tags), "game,singing");
+
+        assert_eq!(Tag::parse_list("").unwrap(), Vec::<Tag>::new());
+
+        let too_many = (0..=CONFIG.max_query_tags)
+            .map(|i| format!("tag{i}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let res = Tag::parse_list(&too_many);
+        let Err(DomainError::DomainValidationError(validation_error)) = res else {
+            panic!("Expected validation error: {res:?}");
+        };
+        assert_eq!(
+            validation_error,
+            ValidationError::new(ValidationCode::TooLong, "tags")
+                .with_param("limit", CONFIG.max_query_tags)
+        );
+    }
+
+    #[test]
+    fn serde_tag() {
+        let tag: Tag = serde_json::from_str(r#"" Singing ""#).unwrap();
+        assert_eq!(tag, Tag::new("singing").unwrap());
+        assert_eq!(serde_json::to_string(&tag).unwrap(), r#""singing""#);
+
+        assert!(serde_json::from_str::<Tag>(r#""""#).is_err());
+    }
+}
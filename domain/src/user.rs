@@ -0,0 +1,227 @@
+use crate::ids::Id;
+use crate::DomainError;
+
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(feature = "server")]
+use sqlx::{postgres::PgRow, FromRow, Row};
+
+#[cfg(any(test, feature = "fake"))]
+use fake::{Dummy, Fake, Faker};
+
+#[cfg(any(test, feature = "fake"))]
+use rand::Rng;
+
+// -------------------------------------------------------------------------------------------------
+// # UserId
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UserIdType;
+
+pub type UserId = Id<UserIdType>;
+
+// -------------------------------------------------------------------------------------------------
+// # Role
+
+/// ユーザーの権限．`Contributor < Admin`の順に強くなる．
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Role {
+    /// 追加・編集ができる協力者
+    Contributor,
+    /// 削除やjsonのインポートもできる管理者
+    Admin,
+}
+
+impl FromStr for Role {
+    type Err = DomainError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "contributor" => Ok(Role::Contributor),
+            "admin" => Ok(Role::Admin),
+            _ => Err(DomainError::DomainParseError(format!(
+                "Invalid string for Role: \"{s}\""
+            ))),
+        }
+    }
+}
+
+impl TryFrom<String> for Role {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::Contributor => write!(f, "contributor"),
+            Role::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl From<Role> for String {
+    fn from(value: Role) -> Self {
+        value.to_string()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// # User
+
+/// Userのエンティティ．パスワードはハッシュとしてのみ保持する．
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct User {
+    /// ログインに用いるユーザー名
+    name: String,
+    /// PHC形式のパスワードのハッシュ
+    password_hash: String,
+    /// 権限
+    role: Role,
+    /// UUID
+    id: UserId,
+}
+
+impl User {
+    /// コンストラクタ．ユーザー名が空の場合はエラーとなる．
+    pub fn new(name: String, password_hash: String, role: Role) -> Result<Self, DomainError> {
+        if name.trim().is_empty() {
+            return Err(DomainError::DomainLogicError(
+                "User name must not be empty.".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            name,
+            password_hash,
+            role,
+            id: UserId::generate(),
+        })
+    }
+    /// ユーザー名を取得
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// パスワードのハッシュを取得
+    pub fn password_hash(&self) -> &str {
+        &self.password_hash
+    }
+    /// 権限を取得
+    pub fn role(&self) -> Role {
+        self.role
+    }
+    /// idを取得
+    pub fn id(&self) -> UserId {
+        self.id
+    }
+    /// パスワードのハッシュの可変参照を取得
+    pub fn password_hash_mut(&mut self) -> &mut String {
+        &mut self.password_hash
+    }
+    /// 権限の可変参照を取得
+    pub fn role_mut(&mut self) -> &mut Role {
+        &mut self.role
+    }
+    /// idはそのままにotherのフィールドを自身にコピー
+    pub fn assign(&mut self, other: Self) {
+        let new_self = Self {
+            id: self.id(),
+            ..other
+        };
+        *self = new_self;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// User as entity
+
+#[cfg(feature = "server")]
+impl FromRow<'_, PgRow> for User {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        use uuid::Uuid;
+
+        let name: String = row.try_get("name")?;
+        let password_hash: String = row.try_get("password_hash")?;
+        let role: String = row.try_get("role")?;
+        let id: Uuid = row.try_get("id")?;
+
+        Ok(Self {
+            name,
+            password_hash,
+            role: role.try_into()?,
+            id: id.into(),
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Dummy trait
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for Role {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        if rng.gen_bool(0.5) {
+            Role::Admin
+        } else {
+            Role::Contributor
+        }
+    }
+}
+
+#[cfg(any(test, feature = "fake"))]
+impl Dummy<Faker> for User {
+    fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
+        let name = format!("user_{}", (16..24).fake_with_rng::<String, R>(rng));
+        Self::new(name, Faker.fake_with_rng(rng), Faker.fake_with_rng(rng)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Role, User};
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn user() {
+        let user = User::new("user".to_string(), "hash".to_string(), Role::Contributor).unwrap();
+        assert_eq!(user.name(), "user");
+        assert_eq!(user.role(), Role::Contributor);
+
+        assert!(User::new(" ".to_string(), "hash".to_string(), Role::Admin).is_err());
+    }
+
+    #[test]
+    fn role_order() {
+        assert!(Role::Contributor < Role::Admin);
+    }
+
+    #[test]
+    fn role_serialize_and_deserialize() {
+        assert_eq!(
+            serde_json::to_string(&Role::Contributor).unwrap(),
+            r#""contributor""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Role>(r#""admin""#).unwrap(),
+            Role::Admin
+        );
+        assert!(serde_json::from_str::<Role>(r#""owner""#).is_err());
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut user = Faker.fake::<User>();
+        let previous_id = user.id();
+
+        let other_user = Faker.fake::<User>();
+        user.assign(other_user.clone());
+
+        assert_eq!(user.id(), previous_id);
+        assert_eq!(user.name(), other_user.name());
+        assert_eq!(user.role(), other_user.role());
+    }
+}
@@ -5,9 +5,13 @@ use std::{
 
 pub use crate::MovieUrl;
 
+use crate::channel::{Channel, ChannelId};
+use crate::cursor::Cursor;
 use crate::date::Date;
+use crate::date_time::DateTime;
 use crate::ids::Id;
-use crate::DomainError;
+use crate::movie_clip::Second;
+use crate::{field_path, DomainError, FromInput};
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::marker::PhantomData;
@@ -191,11 +195,19 @@ pub struct Video<T: VideoType> {
     /// id
     id: VideoId,
     /// 動画の配信日時
-    date: Date,
-    /// 動画の投稿者
+    date: DateTime,
+    /// 動画を投稿したチャンネルの表示名の写し．チャンネルから導出し，直接は編集しない
     author: String,
+    /// 動画を投稿したチャンネル
+    channel_id: ChannelId,
     /// ライク数
     like: u32,
+    /// 動画の長さ．不明な場合はNone
+    #[serde(default)]
+    duration: Option<Second>,
+    /// 編集の度に一つ増える版数
+    #[serde(default)]
+    version: u32,
     /// 動画の種類(Kirinuki, Original)
     #[serde(
         serialize_with = "serialize_phantom",
@@ -205,35 +217,51 @@ pub struct Video<T: VideoType> {
 }
 
 impl<T: VideoType> Video<T> {
-    /// プリミティブを用いたコンストラクタ
+    /// プリミティブを用いたコンストラクタ．日時は`date_ymd`の日のJSTの0時とする
     pub fn new(
         title: String,
         url: String,
         date_ymd: (u32, u32, u32),
-        author: String,
+        channel: &Channel,
     ) -> Result<Video<T>, DomainError> {
         Ok(Self {
             title,
             url: url.try_into()?,
             id: VideoId::generate(),
-            date: date_ymd.try_into()?,
-            author,
+            date: Date::try_from(date_ymd)?.into(),
+            author: channel.name().to_string(),
+            channel_id: channel.id(),
             like: 0,
+            duration: None,
+            version: 0,
             video_type: PhantomData,
         })
     }
     /// ドメイン固有型を用いたコンストラクタ
-    pub fn new_with_domains(title: String, url: MovieUrl, date: Date, author: String) -> Video<T> {
+    pub fn new_with_domains(
+        title: String,
+        url: MovieUrl,
+        date: DateTime,
+        channel: &Channel,
+    ) -> Video<T> {
         Self {
             title,
             url,
             id: VideoId::generate(),
             date,
-            author,
+            author: channel.name().to_string(),
+            channel_id: channel.id(),
             like: 0,
+            duration: None,
+            version: 0,
             video_type: PhantomData,
         }
     }
+    /// 動画の長さを設定する
+    pub fn with_duration(mut self, duration: Option<Second>) -> Self {
+        self.duration = duration;
+        self
+    }
     /// titleを取得
     pub fn title(&self) -> &str {
         &self.title
@@ -255,40 +283,110 @@ impl<T: VideoType> Video<T> {
         self.id
     }
     /// dateを取得
-    pub fn date(&self) -> Date {
+    pub fn date(&self) -> DateTime {
         self.date
     }
     /// dateの可変参照を取得
-    pub fn date_mut(&mut self) -> &mut Date {
+    pub fn date_mut(&mut self) -> &mut DateTime {
         &mut self.date
     }
     /// likeを取得
     pub fn like(&self) -> u32 {
         self.like
     }
+    /// dateで並べたときの自身の位置を表すカーソル
+    pub fn date_cursor(&self) -> Cursor<DateTime, VideoId> {
+        Cursor::new(self.date, self.id)
+    }
+    /// likeで並べたときの自身の位置を表すカーソル
+    pub fn like_cursor(&self) -> Cursor<u32, VideoId> {
+        Cursor::new(self.like, self.id)
+    }
     /// likeを一つ増やす
     pub fn increment_like(&mut self) {
         self.like += 1;
     }
+    /// likeを一つ減らす．0の場合は何もしない
+    pub fn decrement_like(&mut self) {
+        self.like = self.like.saturating_sub(1);
+    }
     /// authorを取得
     pub fn author(&self) -> &str {
         &self.author
     }
-    /// authorの可変参照を取得
-    pub fn author_mut(&mut self) -> &mut String {
-        &mut self.author
+    /// channel_idを取得
+    pub fn channel_id(&self) -> ChannelId {
+        self.channel_id
+    }
+    /// 動画を投稿したチャンネルを設定し，投稿者名をチャンネルの表示名とする
+    pub fn set_channel(&mut self, channel: &Channel) {
+        self.channel_id = channel.id();
+        self.author = channel.name().to_string();
     }
-    /// id, likeはそのままにotherをコピー
+    /// durationを取得
+    pub fn duration(&self) -> Option<Second> {
+        self.duration
+    }
+    /// durationの可変参照を取得
+    pub fn duration_mut(&mut self) -> &mut Option<Second> {
+        &mut self.duration
+    }
+    /// versionを取得
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+    /// versionを一つ増やす
+    pub fn increment_version(&mut self) {
+        self.version += 1;
+    }
+    /// id, like, versionはそのままにotherをコピー
     pub fn assign(&mut self, other: Self) {
         let new_self = Self {
             id: self.id,
             like: self.like,
+            version: self.version,
             ..other
         };
         *self = new_self;
     }
 }
 
+/// 検証前の`Video`の入力．urlは`FromInput`で検証する
+#[derive(Debug, Clone, Deserialize)]
+pub struct VideoInput<T: VideoType> {
+    pub title: String,
+    pub url: String,
+    pub id: VideoId,
+    pub date: DateTime,
+    pub author: String,
+    pub channel_id: ChannelId,
+    pub like: u32,
+    #[serde(default)]
+    pub duration: Option<Second>,
+    #[serde(default)]
+    pub version: u32,
+    #[serde(deserialize_with = "deserialize_phantom")]
+    pub video_type: PhantomData<T>,
+}
+
+impl<T: VideoType> FromInput for Video<T> {
+    type Input = VideoInput<T>;
+    fn from_input(input: VideoInput<T>, field: &str) -> Result<Self, DomainError> {
+        Ok(Self {
+            title: input.title,
+            url: MovieUrl::from_input(input.url, &field_path(field, "url"))?,
+            id: input.id,
+            date: input.date,
+            author: input.author,
+            channel_id: input.channel_id,
+            like: input.like,
+            duration: input.duration,
+            version: input.version,
+            video_type: input.video_type,
+        })
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // Video as entity
 
@@ -298,15 +396,18 @@ where
     T: VideoType,
 {
     fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
-        use chrono::NaiveDate;
+        use chrono::Utc;
         use uuid::Uuid;
 
         let title: String = row.try_get("title")?;
         let url: String = row.try_get("url")?;
         let id: Uuid = row.try_get("id")?;
-        let date: NaiveDate = row.try_get("date")?;
+        let date: chrono::DateTime<Utc> = row.try_get("date")?;
         let author: String = row.try_get("author")?;
+        let channel_id: Uuid = row.try_get("channel_id")?;
         let like: i32 = row.try_get("like")?;
+        let duration: Option<f64> = row.try_get("duration")?;
+        let version: i32 = row.try_get("version")?;
         let video_type_str: String = row.try_get("video_type")?;
         let _video_type: T = video_type_str.try_into()?;
 
@@ -316,7 +417,10 @@ where
             id: id.into(),
             date: date.try_into()?,
             author,
+            channel_id: channel_id.into(),
             like: like as u32,
+            duration: duration.map(Second::from_secs_f64).transpose()?,
+            version: version as u32,
             video_type: PhantomData,
         })
     }
@@ -325,24 +429,31 @@ where
 // -------------------------------------------------------------------------------------------------
 // Dummy trait
 
+/// 動画の長さを生成する．不明(None)の場合もある
+#[cfg(any(feature = "fake", test))]
+fn fake_duration<R: Rng + ?Sized>(rng: &mut R) -> Option<Second> {
+    rng.gen_bool(0.5)
+        .then(|| Second::from_u32((60..6 * 60 * 60).fake_with_rng(rng)))
+}
+
 #[cfg(any(feature = "fake", test))]
 impl<T: VideoType> Dummy<Faker> for Video<T> {
     fn dummy_with_rng<R: Rng + ?Sized>(_config: &Faker, rng: &mut R) -> Self {
         let title = Words(2..50).fake_with_rng::<Vec<String>, R>(rng).join(" ");
-        let author = Words(1..3).fake_with_rng::<Vec<String>, R>(rng).join(" ");
+        let channel: Channel = Faker.fake_with_rng(rng);
 
         let mut video: Video<T> = Video::new_with_domains(
             title,
             Faker.fake_with_rng(rng),
             Faker.fake_with_rng(rng),
-            author,
+            &channel,
         );
 
         let like_num = (0..1000).fake_with_rng::<usize, R>(rng);
         for _ in 0..like_num {
             video.increment_like();
         }
-        video
+        video.with_duration(fake_duration(rng))
     }
 }
 
@@ -350,26 +461,27 @@ impl<T: VideoType> Dummy<Faker> for Video<T> {
 impl<T: VideoType> Dummy<std::ops::Range<Date>> for Video<T> {
     fn dummy_with_rng<R: Rng + ?Sized>(config: &std::ops::Range<Date>, rng: &mut R) -> Self {
         let title = Words(2..50).fake_with_rng::<Vec<String>, R>(rng).join(" ");
-        let author = Words(1..3).fake_with_rng::<Vec<String>, R>(rng).join(" ");
+        let channel: Channel = Faker.fake_with_rng(rng);
 
         let mut video: Video<T> = Video::new_with_domains(
             title,
             Faker.fake_with_rng(rng),
             config.fake_with_rng(rng),
-            author,
+            &channel,
         );
 
         let like_num = (0..1000).fake_with_rng::<usize, R>(rng);
         for _ in 0..like_num {
             video.increment_like();
         }
-        video
+        video.with_duration(fake_duration(rng))
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Kirinuki, Original, Video};
+    use crate::channel::Channel;
     use fake::{Fake, Faker};
     use pretty_assertions::assert_eq;
 
@@ -405,7 +517,46 @@ mod test {
         );
 
         let res_err = serde_json::from_str::<Video<Kirinuki>>(&video_json);
-        assert!(matches!(res_err, Err(_)))
+        assert!(res_err.is_err());
+
+        // channel_idの無いjsonは受け付けない
+        let mut video_json = serde_json::to_value(&video).unwrap();
+        video_json.as_object_mut().unwrap().remove("channel_id");
+        assert!(serde_json::from_value::<Video<Original>>(video_json).is_err());
+
+        // versionの無いjsonは版数0とする
+        let mut video_json = serde_json::to_value(&video).unwrap();
+        video_json.as_object_mut().unwrap().remove("version");
+        let video_without_version = serde_json::from_value::<Video<Original>>(video_json).unwrap();
+        assert_eq!(video_without_version.version(), 0);
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut video = Faker.fake::<Video<Original>>();
+        video.increment_version();
+        let previous_id = video.id();
+        let previous_like = video.like();
+        let previous_version = video.version();
+
+        let other_video = Faker.fake::<Video<Original>>();
+        video.assign(other_video.clone());
+
+        assert_eq!(video.id(), previous_id);
+        assert_eq!(video.like(), previous_like);
+        assert_eq!(video.version(), previous_version);
+        assert_eq!(video.title(), other_video.title());
+        assert_eq!(video.url(), other_video.url());
+    }
+
+    #[test]
+    fn test_set_channel() {
+        let mut video = Faker.fake::<Video<Original>>();
+        let channel = Faker.fake::<Channel>();
+        video.set_channel(&channel);
+
+        assert_eq!(video.channel_id(), channel.id());
+        assert_eq!(video.author(), channel.name().as_str());
     }
 
     #[test]
@@ -0,0 +1,21 @@
+mod db_channel_repository;
+mod inmemory_channel_repository;
+mod mock_channel_repository;
+
+pub use db_channel_repository::ChannelPgDBRepository;
+pub use inmemory_channel_repository::InMemoryChannelRepository;
+pub use mock_channel_repository::MockChannelRepository;
+
+#[cfg(test)]
+pub(crate) mod channel_assert {
+    use domain::channel::Channel;
+    use pretty_assertions::assert_eq;
+
+    /// idで昇順にソートして比較
+    pub(crate) fn channels_assert_eq(actual: &mut Vec<Channel>, expected: &mut Vec<Channel>) {
+        actual.sort_by_key(|channel| channel.id());
+        expected.sort_by_key(|channel| channel.id());
+
+        assert_eq!(actual, expected);
+    }
+}
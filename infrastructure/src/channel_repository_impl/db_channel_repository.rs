@@ -0,0 +1,445 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::channel::{Channel, ChannelId, ChannelSummary};
+use domain::ChannelRepository;
+use sqlx::PgPool;
+
+// -------------------------------------------------------------------------------------------------
+// # channel_sql_runner
+
+mod channel_sql_runner {
+    use crate::InfraError;
+    use domain::channel::{Channel, ChannelId, ChannelSummary};
+    use sqlx::postgres::{PgConnection, Postgres};
+
+    /// 一意性制約違反のエラーコード
+    const UNIQUE_VIOLATION: &str = "23505";
+    /// 外部キー制約違反のエラーコード
+    const FOREIGN_KEY_VIOLATION: &str = "23503";
+
+    /// 制約違反の場合は`ConflictError`に変換する．
+    fn conflict_or_other(e: sqlx::Error) -> InfraError {
+        match &e {
+            sqlx::Error::Database(db_error)
+                if matches!(
+                    db_error.code().as_deref(),
+                    Some(UNIQUE_VIOLATION) | Some(FOREIGN_KEY_VIOLATION)
+                ) =>
+            {
+                InfraError::ConflictError
+            }
+            _ => e.into(),
+        }
+    }
+
+    pub async fn save(conn: &mut PgConnection, channel: Channel) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"INSERT INTO channels (name, "url", avatar_url, id) VALUES ($1, $2, $3, $4)"#,
+        )
+        .bind(channel.name().to_string())
+        .bind(channel.url().map(|url| url.to_string()))
+        .bind(
+            channel
+                .avatar_url()
+                .map(|avatar_url| avatar_url.to_string()),
+        )
+        .bind(channel.id().to_uuid())
+        .execute(conn)
+        .await
+        .map_err(|_| InfraError::ConflictError)?;
+        Ok(())
+    }
+    pub async fn edit(conn: &mut PgConnection, channel: Channel) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"UPDATE channels SET name = $1, "url" = $2, avatar_url = $3 WHERE id = $4 RETURNING *"#,
+        )
+        .bind(channel.name().to_string())
+        .bind(channel.url().map(|url| url.to_string()))
+        .bind(channel.avatar_url().map(|avatar_url| avatar_url.to_string()))
+        .bind(channel.id().to_uuid())
+        .fetch_optional(conn)
+        .await
+        .map_err(conflict_or_other)?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(())
+    }
+    pub async fn all(conn: &mut PgConnection) -> Result<Vec<Channel>, InfraError> {
+        let channels = sqlx::query_as::<Postgres, Channel>(r#"SELECT * FROM channels"#)
+            .fetch_all(conn)
+            .await?;
+        Ok(channels)
+    }
+    pub async fn find_by_id(conn: &mut PgConnection, id: ChannelId) -> Result<Channel, InfraError> {
+        let channel =
+            sqlx::query_as::<Postgres, Channel>(r#"SELECT * FROM channels WHERE id = $1"#)
+                .bind(id.to_uuid())
+                .fetch_optional(conn)
+                .await?;
+        channel.ok_or(InfraError::NoRecordError)
+    }
+    pub async fn all_with_video_count(
+        conn: &mut PgConnection,
+    ) -> Result<Vec<ChannelSummary>, InfraError> {
+        let summaries = sqlx::query_as::<Postgres, ChannelSummary>(
+            r#"
+SELECT channels.*, COUNT(videos.id) AS video_count FROM channels
+LEFT JOIN videos ON videos.channel_id = channels.id AND videos.deleted_at IS NULL
+GROUP BY channels.id ORDER BY channels.name ASC, channels.id ASC
+        "#,
+        )
+        .fetch_all(conn)
+        .await?;
+        Ok(summaries)
+    }
+    pub async fn remove(conn: &mut PgConnection, id: ChannelId) -> Result<(), InfraError> {
+        sqlx::query(r#"DELETE FROM channels WHERE id = $1 RETURNING *"#)
+            .bind(id.to_uuid())
+            .fetch_optional(conn)
+            .await
+            .map_err(conflict_or_other)?
+            .ok_or(InfraError::NoRecordError)?;
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// #ChannelPgDBRepository
+
+/// ChannelのPostgresqlリポジトリ
+#[derive(Debug, Clone)]
+pub struct ChannelPgDBRepository {
+    pool: PgPool,
+}
+
+impl ChannelPgDBRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChannelRepository for ChannelPgDBRepository {
+    type Error = InfraError;
+    async fn save(&self, channel: Channel) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        channel_sql_runner::save(&mut conn, channel).await?;
+        Ok(())
+    }
+    async fn edit(&self, channel: Channel) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        channel_sql_runner::edit(&mut conn, channel).await?;
+        Ok(())
+    }
+    async fn all(&self) -> Result<Vec<Channel>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let channels = channel_sql_runner::all(&mut conn).await?;
+        Ok(channels)
+    }
+    async fn find_by_id(&self, id: ChannelId) -> Result<Channel, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let channel = channel_sql_runner::find_by_id(&mut conn, id).await?;
+        Ok(channel)
+    }
+    async fn all_with_video_count(&self) -> Result<Vec<ChannelSummary>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let summaries = channel_sql_runner::all_with_video_count(&mut conn).await?;
+        Ok(summaries)
+    }
+    async fn remove(&self, id: ChannelId) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        channel_sql_runner::remove(&mut conn, id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::channel_sql_runner;
+    use crate::channel_repository_impl::channel_assert::channels_assert_eq;
+    use crate::InfraError;
+    use domain::channel::Channel;
+    use domain::video::{Kirinuki, Original, Video};
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rand::seq::SliceRandom;
+    use rstest::{fixture, rstest};
+    use sqlx::postgres::{PgConnection, PgPool, PgPoolOptions};
+    use std::time::Duration;
+
+    #[fixture]
+    fn channels() -> Vec<Channel> {
+        (0..100)
+            .map(|_| Faker.fake::<Channel>())
+            .collect::<Vec<_>>()
+    }
+
+    #[fixture]
+    async fn pool() -> Result<PgPool, InfraError> {
+        let database_url = std::env::var("DATABASE_URL").unwrap();
+        let pool = PgPoolOptions::new()
+            .idle_timeout(Duration::from_secs(1))
+            .connect(&database_url)
+            .await?;
+        Ok(pool)
+    }
+
+    /// 既存のChannelを削除する．Videoは必ずチャンネルを持つため既存のVideoも削除する．
+    async fn clear_channels(conn: &mut PgConnection) -> Result<(), InfraError> {
+        sqlx::query("DELETE FROM videos")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("DELETE FROM channels").execute(conn).await?;
+        Ok(())
+    }
+
+    /// テスト用にチャンネル以外の列を最小限にしてVideoを保存する．
+    async fn save_video_with_channel(
+        conn: &mut PgConnection,
+        video_type: &str,
+        channel: &Channel,
+    ) -> Result<(), InfraError> {
+        let video = Faker.fake::<Video<Original>>();
+        sqlx::query(
+            r#"
+INSERT INTO videos (title, "url", id, "date", author, "like", video_type, channel_id)
+VALUES ($1, $2, $3, $4, $5, 0, $6, $7)
+        "#,
+        )
+        .bind(video.title())
+        .bind(video.url().to_string())
+        .bind(video.id().to_uuid())
+        .bind(video.date().to_chrono()?)
+        .bind(video.author())
+        .bind(video_type)
+        .bind(channel.id().to_uuid())
+        .execute(conn)
+        .await?;
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_save_and_edit_and_all(
+        channels: Vec<Channel>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut channels = channels;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+        clear_channels(&mut transaction).await?;
+
+        // データベースへ保存
+        for channel in channels.iter().cloned() {
+            channel_sql_runner::save(&mut transaction, channel).await?;
+        }
+
+        // channelsの一部を編集
+        for _ in 0..(channels.len() / 2_usize) {
+            let edited_channel = channels.choose_mut(&mut rand::thread_rng()).unwrap();
+            edited_channel.assign(Faker.fake::<Channel>());
+
+            channel_sql_runner::edit(&mut transaction, edited_channel.clone()).await?;
+        }
+
+        let mut channels_res = channel_sql_runner::all(&mut transaction).await?;
+        channels_assert_eq(&mut channels_res, &mut channels);
+
+        for channel in channels.iter() {
+            let channel_res =
+                channel_sql_runner::find_by_id(&mut transaction, channel.id()).await?;
+            assert_eq!(&channel_res, channel);
+        }
+
+        // 他のチャンネルの名前には編集できない
+        let mut edited = channels[1].clone();
+        *edited.name_mut() = channels[0].name().clone();
+        let res = channel_sql_runner::edit(&mut transaction, edited).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_save_duplicated_name(
+        channels: Vec<Channel>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+        clear_channels(&mut transaction).await?;
+
+        for channel in channels.iter().cloned() {
+            channel_sql_runner::save(&mut transaction, channel).await?;
+        }
+
+        // 同じ名前のチャンネルは保存できない
+        let mut duplicated = Faker.fake::<Channel>();
+        *duplicated.name_mut() = channels[0].name().clone();
+        let res = channel_sql_runner::save(&mut transaction, duplicated).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_edit_and_sync_author(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+        clear_channels(&mut transaction).await?;
+
+        let mut channel = Faker.fake::<Channel>();
+        channel_sql_runner::save(&mut transaction, channel.clone()).await?;
+        save_video_with_channel(&mut transaction, &Original.to_string(), &channel).await?;
+
+        // 投稿者名はチャンネル名となり，チャンネル名の変更が反映される
+        let authors = sqlx::query_scalar::<_, String>("SELECT author FROM videos")
+            .fetch_all(&mut transaction)
+            .await?;
+        assert_eq!(authors, vec![channel.name().to_string()]);
+
+        *channel.name_mut() = Faker.fake();
+        channel_sql_runner::edit(&mut transaction, channel.clone()).await?;
+        let authors = sqlx::query_scalar::<_, String>("SELECT author FROM videos")
+            .fetch_all(&mut transaction)
+            .await?;
+        assert_eq!(authors, vec![channel.name().to_string()]);
+
+        // 投稿者名を直接編集してもチャンネル名に戻される
+        sqlx::query("UPDATE videos SET author = 'other'")
+            .execute(&mut transaction)
+            .await?;
+        let authors = sqlx::query_scalar::<_, String>("SELECT author FROM videos")
+            .fetch_all(&mut transaction)
+            .await?;
+        assert_eq!(authors, vec![channel.name().to_string()]);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_with_video_count_and_remove(
+        channels: Vec<Channel>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+        clear_channels(&mut transaction).await?;
+
+        for channel in channels.iter().cloned() {
+            channel_sql_runner::save(&mut transaction, channel).await?;
+        }
+
+        // 先頭のチャンネルにOriginalを2つ，Kirinukiを1つ紐付ける
+        let channel = channels[0].clone();
+        let original_type = Original.to_string();
+        let kirinuki_type = Kirinuki.to_string();
+        for video_type in [&original_type, &original_type, &kirinuki_type] {
+            save_video_with_channel(&mut transaction, video_type, &channel).await?;
+        }
+
+        let mut summaries = channel_sql_runner::all_with_video_count(&mut transaction).await?;
+        assert_eq!(summaries.len(), channels.len());
+        summaries.sort_by_key(|summary| summary.channel().id());
+        let mut expected_channels = channels.clone();
+        expected_channels.sort_by_key(|channel| channel.id());
+        for (summary, expected_channel) in summaries.iter().zip(expected_channels.iter()) {
+            assert_eq!(summary.channel(), expected_channel);
+            let expected_count = if expected_channel.id() == channel.id() {
+                3
+            } else {
+                0
+            };
+            assert_eq!(summary.video_count(), expected_count);
+        }
+
+        // ゴミ箱のKirinukiは数えない
+        sqlx::query(
+            "UPDATE videos SET deleted_at = now() WHERE channel_id = $1 AND video_type = $2",
+        )
+        .bind(channel.id().to_uuid())
+        .bind(&kirinuki_type)
+        .execute(&mut transaction)
+        .await?;
+        let summaries = channel_sql_runner::all_with_video_count(&mut transaction).await?;
+        let summary = summaries
+            .iter()
+            .find(|summary| summary.channel().id() == channel.id())
+            .unwrap();
+        assert_eq!(summary.video_count(), 2);
+
+        // channelsの一部を削除
+        let (removed_channels, rest_channels) = channels[1..].split_at(20);
+        let mut rest_channels = [&channels[..1], rest_channels].concat();
+
+        for removed_channel in removed_channels.iter() {
+            channel_sql_runner::remove(&mut transaction, removed_channel.id()).await?;
+        }
+
+        let mut channels_res = channel_sql_runner::all(&mut transaction).await?;
+        channels_assert_eq(&mut channels_res, &mut rest_channels);
+
+        // 動画が紐付いているチャンネルは削除できない
+        let res = channel_sql_runner::remove(&mut transaction, channel.id()).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_edit_and_find_and_remove_no_exists(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let channel = Faker.fake::<Channel>();
+
+        let res = channel_sql_runner::edit(&mut transaction, channel.clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = channel_sql_runner::find_by_id(&mut transaction, channel.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = channel_sql_runner::remove(&mut transaction, channel.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+}
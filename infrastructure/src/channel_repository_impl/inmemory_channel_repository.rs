@@ -0,0 +1,357 @@
+use crate::video_repository_impl::InMemoryVideoRepository;
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::channel::{Channel, ChannelId, ChannelSummary};
+use domain::video::{Kirinuki, Original};
+use domain::{ChannelRepository, VideoRepository};
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+// -------------------------------------------------------------------------------------------------
+// # InMemoryChannelRepository
+
+/// 即席のChannelリポジトリ．動画の数や削除の可否は共有しているVideoのリポジトリから求め，
+/// チャンネル名の変更は共有しているVideoの投稿者名へ反映する．
+#[derive(Default, Debug, Clone)]
+pub struct InMemoryChannelRepository {
+    map: Arc<Mutex<HashMap<Uuid, Channel>>>,
+    originals: InMemoryVideoRepository<Original>,
+    kirinukis: InMemoryVideoRepository<Kirinuki>,
+}
+
+impl InMemoryChannelRepository {
+    /// 空のVideoのリポジトリと共に作成する．
+    pub fn new() -> Self {
+        Self::with_videos(
+            InMemoryVideoRepository::new(),
+            InMemoryVideoRepository::new(),
+        )
+    }
+    /// Videoのリポジトリを共有して作成する．
+    pub fn with_videos(
+        originals: InMemoryVideoRepository<Original>,
+        kirinukis: InMemoryVideoRepository<Kirinuki>,
+    ) -> Self {
+        Self {
+            map: Arc::new(Mutex::new(HashMap::new())),
+            originals,
+            kirinukis,
+        }
+    }
+
+    /// `id`をもつChannelに紐付いたVideoの数を取得する．`with_trashed`の場合はゴミ箱のVideoも数える．
+    async fn video_count(&self, id: ChannelId, with_trashed: bool) -> Result<u32, InfraError> {
+        let mut originals = self.originals.all().await?;
+        let mut kirinukis = self.kirinukis.all().await?;
+        if with_trashed {
+            originals.extend(self.originals.trashed().await?);
+            kirinukis.extend(self.kirinukis.trashed().await?);
+        }
+        let original_count = originals
+            .iter()
+            .filter(|video| video.channel_id() == id)
+            .count();
+        let kirinuki_count = kirinukis
+            .iter()
+            .filter(|video| video.channel_id() == id)
+            .count();
+        Ok((original_count + kirinuki_count) as u32)
+    }
+}
+
+#[async_trait]
+impl ChannelRepository for InMemoryChannelRepository {
+    type Error = InfraError;
+    async fn save(&self, channel: Channel) -> Result<(), InfraError> {
+        let mut map = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+
+        // チャンネル名はユニーク
+        if map.values().any(|saved| saved.name() == channel.name()) {
+            return Err(InfraError::ConflictError);
+        }
+
+        match map.entry(channel.id().to_uuid()) {
+            Entry::Occupied(_) => Err(InfraError::ConflictError),
+            Entry::Vacant(v) => {
+                v.insert(channel);
+                Ok(())
+            }
+        }
+    }
+    async fn edit(&self, channel: Channel) -> Result<(), InfraError> {
+        let mut map = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+
+        if map
+            .values()
+            .any(|saved| saved.name() == channel.name() && saved.id() != channel.id())
+        {
+            return Err(InfraError::ConflictError);
+        }
+
+        match map.entry(channel.id().to_uuid()) {
+            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Occupied(mut o) => {
+                self.originals.sync_channel(&channel)?;
+                self.kirinukis.sync_channel(&channel)?;
+                *o.get_mut() = channel;
+                Ok(())
+            }
+        }
+    }
+    async fn all(&self) -> Result<Vec<Channel>, InfraError> {
+        let channels = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        Ok(channels)
+    }
+    async fn find_by_id(&self, id: ChannelId) -> Result<Channel, InfraError> {
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .get(&id.to_uuid())
+            .cloned()
+            .ok_or(InfraError::NoRecordError)
+    }
+    async fn all_with_video_count(&self) -> Result<Vec<ChannelSummary>, InfraError> {
+        let mut channels = self.all().await?;
+        channels.sort_by(|x, y| x.name().cmp(y.name()));
+
+        let mut summaries = Vec::new();
+        for channel in channels.into_iter() {
+            let video_count = self.video_count(channel.id(), false).await?;
+            summaries.push(ChannelSummary::new(channel, video_count));
+        }
+        Ok(summaries)
+    }
+    async fn remove(&self, id: ChannelId) -> Result<(), InfraError> {
+        // ゴミ箱のものを含めて動画が紐付いているチャンネルは削除できない
+        if self.video_count(id, true).await? > 0 {
+            return Err(InfraError::ConflictError);
+        }
+
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .remove(&id.to_uuid())
+        {
+            None => Err(InfraError::NoRecordError),
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InMemoryChannelRepository;
+    use crate::channel_repository_impl::channel_assert::channels_assert_eq;
+    use crate::video_repository_impl::InMemoryVideoRepository;
+    use crate::InfraError;
+    use domain::channel::Channel;
+    use domain::revision::Actor;
+    use domain::video::{Kirinuki, Original, Video};
+    use domain::{ChannelRepository, VideoRepository};
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rand::seq::SliceRandom;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn channels() -> Vec<Channel> {
+        (0..100)
+            .map(|_| Faker.fake::<Channel>())
+            .collect::<Vec<_>>()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_save_and_edit_and_all(channels: Vec<Channel>) -> Result<(), InfraError> {
+        let mut channels = channels;
+
+        let repo = InMemoryChannelRepository::new();
+        for channel in channels.iter().cloned() {
+            repo.save(channel).await?;
+        }
+
+        // channelsの一部を編集
+        for _ in 0..(channels.len() / 2_usize) {
+            let edited_channel = channels.choose_mut(&mut rand::thread_rng()).unwrap();
+            edited_channel.assign(Faker.fake::<Channel>());
+
+            repo.edit(edited_channel.clone()).await?;
+        }
+
+        let mut channels_res = repo.all().await?;
+        channels_assert_eq(&mut channels_res, &mut channels);
+
+        for channel in channels.iter() {
+            assert_eq!(&repo.find_by_id(channel.id()).await?, channel);
+        }
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_duplicated_name(channels: Vec<Channel>) -> Result<(), InfraError> {
+        let repo = InMemoryChannelRepository::new();
+        for channel in channels.iter().cloned() {
+            repo.save(channel).await?;
+        }
+
+        // 同じ名前のチャンネルは保存できない
+        let mut duplicated = Faker.fake::<Channel>();
+        *duplicated.name_mut() = channels[0].name().clone();
+        let res = repo.save(duplicated).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // 他のチャンネルの名前には編集できない
+        let mut edited = channels[1].clone();
+        *edited.name_mut() = channels[0].name().clone();
+        let res = repo.edit(edited).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_with_video_count_and_remove(
+        channels: Vec<Channel>,
+    ) -> Result<(), InfraError> {
+        let originals = InMemoryVideoRepository::<Original>::new();
+        let kirinukis = InMemoryVideoRepository::<Kirinuki>::new();
+        let repo = InMemoryChannelRepository::with_videos(originals.clone(), kirinukis.clone());
+
+        for channel in channels.iter().cloned() {
+            repo.save(channel).await?;
+        }
+
+        // 先頭のチャンネルにOriginalを2つ，Kirinukiを1つ紐付ける
+        let channel = channels[0].clone();
+        for _ in 0..2 {
+            let mut original = Faker.fake::<Video<Original>>();
+            original.set_channel(&channel);
+            originals.save(original, Actor::Admin).await?;
+        }
+        let mut kirinuki = Faker.fake::<Video<Kirinuki>>();
+        kirinuki.set_channel(&channel);
+        kirinukis.save(kirinuki, Actor::Admin).await?;
+
+        let summaries = repo.all_with_video_count().await?;
+
+        let mut expected_channels = channels.clone();
+        expected_channels.sort_by(|x, y| x.name().cmp(y.name()));
+        assert_eq!(
+            summaries
+                .iter()
+                .map(|summary| summary.channel().clone())
+                .collect::<Vec<_>>(),
+            expected_channels
+        );
+        for summary in summaries.iter() {
+            let expected_count = if summary.channel().id() == channel.id() {
+                3
+            } else {
+                0
+            };
+            assert_eq!(summary.video_count(), expected_count);
+        }
+
+        // 動画が紐付いているチャンネルは削除できない
+        let res = repo.remove(channel.id()).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ゴミ箱の動画は数えないが，紐付いている間は削除できない
+        let kirinuki_id = kirinukis.all().await?[0].id();
+        kirinukis.remove(kirinuki_id, Actor::Admin).await?;
+        let summaries = repo.all_with_video_count().await?;
+        let summary = summaries
+            .iter()
+            .find(|summary| summary.channel().id() == channel.id())
+            .unwrap();
+        assert_eq!(summary.video_count(), 2);
+        let res = repo.remove(channel.id()).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // channelsの一部を削除
+        let (removed_channels, rest_channels) = channels[1..].split_at(20);
+        let mut rest_channels = [&channels[..1], rest_channels].concat();
+
+        for removed_channel in removed_channels.iter() {
+            repo.remove(removed_channel.id()).await?;
+        }
+
+        let mut channels_res = repo.all().await?;
+        channels_assert_eq(&mut channels_res, &mut rest_channels);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_edit_and_sync_author() -> Result<(), InfraError> {
+        let originals = InMemoryVideoRepository::<Original>::new();
+        let kirinukis = InMemoryVideoRepository::<Kirinuki>::new();
+        let repo = InMemoryChannelRepository::with_videos(originals.clone(), kirinukis.clone());
+
+        let mut channel = Faker.fake::<Channel>();
+        repo.save(channel.clone()).await?;
+
+        let mut original = Faker.fake::<Video<Original>>();
+        original.set_channel(&channel);
+        originals.save(original.clone(), Actor::Admin).await?;
+        let mut trashed = Faker.fake::<Video<Original>>();
+        trashed.set_channel(&channel);
+        originals.save(trashed.clone(), Actor::Admin).await?;
+        originals.remove(trashed.id(), Actor::Admin).await?;
+        let other = Faker.fake::<Video<Kirinuki>>();
+        kirinukis.save(other.clone(), Actor::Admin).await?;
+
+        // チャンネル名の変更はゴミ箱のものを含めて投稿者名に反映される
+        *channel.name_mut() = Faker.fake();
+        repo.edit(channel.clone()).await?;
+
+        assert_eq!(originals.all().await?[0].author(), channel.name().as_str());
+        assert_eq!(
+            originals.trashed().await?[0].author(),
+            channel.name().as_str()
+        );
+        assert_eq!(kirinukis.all().await?, vec![other]);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_channel_edit_and_find_and_remove_no_exists() -> Result<(), InfraError> {
+        let repo = InMemoryChannelRepository::new();
+
+        let channel = Faker.fake::<Channel>();
+
+        let res = repo.edit(channel.clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.find_by_id(channel.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.remove(channel.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+}
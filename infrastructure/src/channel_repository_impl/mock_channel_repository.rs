@@ -0,0 +1,23 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::channel::{Channel, ChannelId, ChannelSummary};
+use domain::ChannelRepository;
+
+use mockall::mock;
+
+mock! {
+    /// ChannelRepositoryトレイトのモック
+    #[derive(Debug, Clone)]
+    pub ChannelRepository {}
+
+    #[async_trait]
+    impl ChannelRepository for ChannelRepository {
+        type Error = InfraError;
+        async fn save(&self, channel: Channel) -> Result<(), <Self as ChannelRepository>::Error>;
+        async fn edit(&self, channel: Channel) -> Result<(), <Self as ChannelRepository>::Error>;
+        async fn all(&self) -> Result<Vec<Channel>, <Self as ChannelRepository>::Error>;
+        async fn find_by_id(&self, id: ChannelId) -> Result<Channel, <Self as ChannelRepository>::Error>;
+        async fn all_with_video_count(&self) -> Result<Vec<ChannelSummary>, <Self as ChannelRepository>::Error>;
+        async fn remove(&self, id: ChannelId) -> Result<(), <Self as ChannelRepository>::Error>;
+    }
+}
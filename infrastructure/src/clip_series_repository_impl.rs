@@ -0,0 +1,43 @@
+mod db_clip_series_repository;
+mod inmemory_clip_series_repository;
+mod mock_clip_series_repository;
+
+pub use db_clip_series_repository::ClipSeriesPgDBRepository;
+pub use inmemory_clip_series_repository::InMemoryClipSeriesRepository;
+pub use mock_clip_series_repository::MockClipSeriesRepository;
+
+#[cfg(test)]
+pub(crate) mod clip_series_assert {
+    use domain::clip_series::ClipSeries;
+    use pretty_assertions::assert_eq;
+    use std::cmp::Ordering;
+
+    /// idで昇順にソートして比較
+    pub(crate) fn clip_series_assert_eq(
+        actual: &mut Vec<ClipSeries>,
+        expected: &mut Vec<ClipSeries>,
+    ) {
+        actual.sort_by_key(|clip_series| clip_series.id());
+        expected.sort_by_key(|clip_series| clip_series.id());
+
+        assert_eq!(actual, expected);
+    }
+
+    /// expectedを`sort_f`・idで昇順にソートして`filter_f`でフィルタリング，`n`個に切り詰めて順番を含めて比較．
+    pub(crate) fn clip_series_assert_eq_with_sort_and_filter<SF, FF>(
+        actual: &[ClipSeries],
+        expected: &mut Vec<ClipSeries>,
+        mut sort_f: SF,
+        filter_f: FF,
+        n: usize,
+    ) where
+        SF: FnMut(&ClipSeries, &ClipSeries) -> Ordering,
+        FF: FnMut(&ClipSeries) -> bool,
+    {
+        expected.sort_by(|x, y| sort_f(x, y).then(x.id().cmp(&y.id())));
+        expected.retain(filter_f);
+        expected.truncate(n);
+
+        assert_eq!(actual, expected.as_slice());
+    }
+}
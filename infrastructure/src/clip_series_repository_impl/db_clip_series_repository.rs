@@ -0,0 +1,657 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::client::ClientId;
+use domain::clip_series::{ClipSeries, ClipSeriesId};
+use domain::ClipSeriesRepository;
+use domain::{Cursor, Date};
+use sqlx::PgPool;
+
+// -------------------------------------------------------------------------------------------------
+// clip_series_sql_runner
+
+/// ClipSeriesに関するSQLのランナーモジュール
+mod clip_series_sql_runner {
+    use crate::InfraError;
+    use domain::client::ClientId;
+    use domain::clip_series::{ClipSeries, ClipSeriesId};
+    use domain::{Cursor, Date};
+    use sqlx::{PgConnection, Postgres};
+    use uuid::Uuid;
+
+    /// ClipSeriesのクリップのidをuuidの配列に変換する．
+    fn clip_uuids(clip_series: &ClipSeries) -> Vec<Uuid> {
+        clip_series
+            .clips()
+            .iter()
+            .map(|clip| clip.to_uuid())
+            .collect()
+    }
+
+    /// ClipSeriesを一つ保存
+    pub async fn save(conn: &mut PgConnection, clip_series: ClipSeries) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"
+INSERT INTO clip_series (title, description, clips, id, "like", create_date)
+VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(clip_series.title().to_string())
+        .bind(clip_series.description().to_string())
+        .bind(clip_uuids(&clip_series))
+        .bind(clip_series.id().to_uuid())
+        .bind(clip_series.like() as i32)
+        .bind(clip_series.create_date().to_chrono()?)
+        .execute(conn)
+        .await
+        .map_err(|_| InfraError::ConflictError)?;
+
+        Ok(())
+    }
+
+    /// ClipSeriesを一つ編集
+    pub async fn edit(conn: &mut PgConnection, clip_series: ClipSeries) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"
+UPDATE clip_series SET title = $1, description = $2, clips = $3
+WHERE id = $4 RETURNING *
+            "#,
+        )
+        .bind(clip_series.title().to_string())
+        .bind(clip_series.description().to_string())
+        .bind(clip_uuids(&clip_series))
+        .bind(clip_series.id().to_uuid())
+        .fetch_one(conn)
+        .await
+        .map_err(|_| InfraError::NoRecordError)?;
+
+        Ok(())
+    }
+
+    /// `id`を持つClipSeriesの行をロックする．存在しない場合は`NoRecordError`を返す．
+    async fn lock(conn: &mut PgConnection, id: ClipSeriesId) -> Result<(), InfraError> {
+        sqlx::query(r#"SELECT id FROM clip_series WHERE id = $1 FOR UPDATE"#)
+            .bind(id.to_uuid())
+            .fetch_optional(conn)
+            .await?
+            .ok_or(InfraError::NoRecordError)?;
+
+        Ok(())
+    }
+
+    /// `id`を持つClipSeriesのLikeを一つ増やす．`client`が既にLikeしている場合は何もしない．
+    pub async fn increment_like(
+        conn: &mut PgConnection,
+        id: ClipSeriesId,
+        client: ClientId,
+    ) -> Result<(), InfraError> {
+        lock(&mut *conn, id).await?;
+
+        let inserted = sqlx::query(
+            r#"
+INSERT INTO clip_series_likes (clip_series_id, client_id) VALUES ($1, $2) ON CONFLICT DO NOTHING
+        "#,
+        )
+        .bind(id.to_uuid())
+        .bind(client.to_uuid())
+        .execute(&mut *conn)
+        .await?;
+
+        if inserted.rows_affected() == 1 {
+            sqlx::query(r#"UPDATE clip_series SET "like" = "like" + 1 WHERE id = $1"#)
+                .bind(id.to_uuid())
+                .execute(conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// `id`を持つClipSeriesのLikeを一つ減らす．`client`がLikeしていない場合は何もしない．
+    pub async fn unlike(
+        conn: &mut PgConnection,
+        id: ClipSeriesId,
+        client: ClientId,
+    ) -> Result<(), InfraError> {
+        lock(&mut *conn, id).await?;
+
+        let deleted = sqlx::query(
+            r#"
+DELETE FROM clip_series_likes WHERE clip_series_id = $1 AND client_id = $2
+        "#,
+        )
+        .bind(id.to_uuid())
+        .bind(client.to_uuid())
+        .execute(&mut *conn)
+        .await?;
+
+        if deleted.rows_affected() == 1 {
+            sqlx::query(r#"UPDATE clip_series SET "like" = GREATEST("like" - 1, 0) WHERE id = $1"#)
+                .bind(id.to_uuid())
+                .execute(conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// `ids`のうち`client`がLikeしているClipSeriesのidを`ids`の順番で取得．
+    pub async fn liked_ids(
+        conn: &mut PgConnection,
+        ids: &[ClipSeriesId],
+        client: ClientId,
+    ) -> Result<Vec<ClipSeriesId>, InfraError> {
+        let liked_ids = sqlx::query_scalar::<Postgres, Uuid>(
+            r#"
+SELECT clip_series_id FROM clip_series_likes WHERE client_id = $1 AND clip_series_id = ANY($2)
+ORDER BY array_position($2, clip_series_id)
+        "#,
+        )
+        .bind(client.to_uuid())
+        .bind(ids.iter().map(|id| id.to_uuid()).collect::<Vec<_>>())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(liked_ids
+            .into_iter()
+            .map(ClipSeriesId::from_uuid)
+            .collect::<Vec<_>>())
+    }
+
+    /// 全てのClipSeriesを取得．順番は保証されない．
+    pub async fn all(conn: &mut PgConnection) -> Result<Vec<ClipSeries>, InfraError> {
+        let all_clip_series =
+            sqlx::query_as::<Postgres, ClipSeries>(r#"SELECT * FROM clip_series"#)
+                .fetch_all(conn)
+                .await?;
+
+        Ok(all_clip_series)
+    }
+
+    /// `id`を持つClipSeriesを取得．
+    pub async fn find_by_id(
+        conn: &mut PgConnection,
+        id: ClipSeriesId,
+    ) -> Result<ClipSeries, InfraError> {
+        let clip_series =
+            sqlx::query_as::<Postgres, ClipSeries>(r#"SELECT * FROM clip_series WHERE id = $1"#)
+                .bind(id.to_uuid())
+                .fetch_optional(conn)
+                .await?;
+
+        clip_series.ok_or(InfraError::NoRecordError)
+    }
+
+    /// Likeを降順に`length`分のClipSeriesを取得．Likeが同じ場合はidで昇順で並べる
+    pub async fn order_by_like(
+        conn: &mut PgConnection,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, InfraError> {
+        let ordered_clip_series = sqlx::query_as::<Postgres, ClipSeries>(
+            r#"
+SELECT * FROM clip_series ORDER BY "like" DESC, id ASC LIMIT $1
+            "#,
+        )
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_clip_series)
+    }
+
+    /// Likeを降順・さらにidを昇順として`cursor`以降のClipSeriesを`length`分取得．
+    pub async fn order_by_like_later(
+        conn: &mut PgConnection,
+        cursor: &Cursor<u32, ClipSeriesId>,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, InfraError> {
+        let ordered_clip_series = sqlx::query_as::<Postgres, ClipSeries>(
+            r#"
+SELECT * FROM clip_series WHERE $1 > "like" OR ($1 = "like" AND $2 < id) ORDER BY "like" DESC, id ASC LIMIT $3
+            "#,
+        )
+        .bind(cursor.key() as i32)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_clip_series)
+    }
+
+    /// create_dateを降順・さらにidを昇順として`length`分のClipSeriesを取得．
+    pub async fn order_by_create_date(
+        conn: &mut PgConnection,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, InfraError> {
+        let ordered_clip_series = sqlx::query_as::<Postgres, ClipSeries>(
+            r#"
+SELECT * FROM clip_series ORDER BY create_date DESC, id ASC LIMIT $1
+            "#,
+        )
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_clip_series)
+    }
+
+    /// create_dateを降順・さらにidを昇順として`cursor`以降のClipSeriesを`length`分取得．
+    pub async fn order_by_create_date_later(
+        conn: &mut PgConnection,
+        cursor: &Cursor<Date, ClipSeriesId>,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, InfraError> {
+        let ordered_clip_series = sqlx::query_as::<Postgres, ClipSeries>(
+            r#"
+SELECT * FROM clip_series WHERE $1 > create_date OR ($1 = create_date AND $2 < id) ORDER BY create_date DESC, id ASC LIMIT $3
+            "#,
+        )
+        .bind(cursor.key().to_chrono()?)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_clip_series)
+    }
+
+    /// `id`を持つClipSeriesを削除．
+    pub async fn remove(conn: &mut PgConnection, id: ClipSeriesId) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"
+DELETE FROM clip_series WHERE id = $1 RETURNING *
+            "#,
+        )
+        .bind(id.to_uuid())
+        .fetch_one(conn)
+        .await
+        .map_err(|_| InfraError::NoRecordError)?;
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ClipSeriesPgDBRepository
+
+/// ClipSeriesのPostgresqlのリポジトリ
+#[derive(Debug, Clone)]
+pub struct ClipSeriesPgDBRepository {
+    pool: PgPool,
+}
+
+impl ClipSeriesPgDBRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ClipSeriesRepository for ClipSeriesPgDBRepository {
+    type Error = InfraError;
+    async fn save(&self, clip_series: ClipSeries) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        clip_series_sql_runner::save(&mut conn, clip_series).await?;
+        Ok(())
+    }
+    async fn edit(&self, clip_series: ClipSeries) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        clip_series_sql_runner::edit(&mut conn, clip_series).await?;
+        Ok(())
+    }
+    async fn increment_like(&self, id: ClipSeriesId, client: ClientId) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        clip_series_sql_runner::increment_like(&mut transaction, id, client).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn unlike(&self, id: ClipSeriesId, client: ClientId) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        clip_series_sql_runner::unlike(&mut transaction, id, client).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn liked_ids(
+        &self,
+        ids: &[ClipSeriesId],
+        client: ClientId,
+    ) -> Result<Vec<ClipSeriesId>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let liked_ids = clip_series_sql_runner::liked_ids(&mut conn, ids, client).await?;
+        Ok(liked_ids)
+    }
+    async fn all(&self) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let all_clip_series = clip_series_sql_runner::all(&mut conn).await?;
+        Ok(all_clip_series)
+    }
+    async fn find_by_id(&self, id: ClipSeriesId) -> Result<ClipSeries, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let clip_series = clip_series_sql_runner::find_by_id(&mut conn, id).await?;
+        Ok(clip_series)
+    }
+    async fn order_by_like(&self, length: usize) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let clip_series = clip_series_sql_runner::order_by_like(&mut conn, length).await?;
+        Ok(clip_series)
+    }
+    async fn order_by_like_later(
+        &self,
+        cursor: &Cursor<u32, ClipSeriesId>,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let clip_series =
+            clip_series_sql_runner::order_by_like_later(&mut conn, cursor, length).await?;
+        Ok(clip_series)
+    }
+    async fn order_by_create_date(&self, length: usize) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let clip_series = clip_series_sql_runner::order_by_create_date(&mut conn, length).await?;
+        Ok(clip_series)
+    }
+    async fn order_by_create_date_later(
+        &self,
+        cursor: &Cursor<Date, ClipSeriesId>,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let clip_series =
+            clip_series_sql_runner::order_by_create_date_later(&mut conn, cursor, length).await?;
+        Ok(clip_series)
+    }
+    async fn remove(&self, id: ClipSeriesId) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        clip_series_sql_runner::remove(&mut conn, id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::clip_series_sql_runner;
+    use crate::clip_series_repository_impl::clip_series_assert::{
+        clip_series_assert_eq, clip_series_assert_eq_with_sort_and_filter,
+    };
+    use crate::InfraError;
+    use domain::client::ClientId;
+    use domain::clip_series::{ClipSeries, ClipSeriesId};
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rand::{seq::SliceRandom, thread_rng, Rng};
+    use rstest::{fixture, rstest};
+    use sqlx::postgres::{PgPool, PgPoolOptions};
+    use std::time::Duration;
+
+    #[fixture]
+    fn clip_series_vec() -> Vec<ClipSeries> {
+        (0..100)
+            .map(|_| Faker.fake::<ClipSeries>())
+            .collect::<Vec<_>>()
+    }
+
+    #[fixture]
+    async fn pool() -> Result<PgPool, InfraError> {
+        let database_url = std::env::var("DATABASE_URL").unwrap();
+        let pool = PgPoolOptions::new()
+            .idle_timeout(Duration::from_secs(1))
+            .connect(&database_url)
+            .await?;
+        Ok(pool)
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_save_and_edit_and_all(
+        clip_series_vec: Vec<ClipSeries>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut clip_series_vec = clip_series_vec;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        // 既存のClipSeriesを削除
+        sqlx::query("DELETE FROM clip_series")
+            .execute(&mut transaction)
+            .await?;
+
+        for clip_series in clip_series_vec.iter().cloned() {
+            clip_series_sql_runner::save(&mut transaction, clip_series).await?;
+        }
+
+        // 一部を編集
+        for _ in 0..(clip_series_vec.len() / 2) {
+            let edited = clip_series_vec.choose_mut(&mut thread_rng()).unwrap();
+            edited.assign(Faker.fake());
+
+            clip_series_sql_runner::edit(&mut transaction, edited.clone()).await?;
+        }
+
+        let mut clip_series_res = clip_series_sql_runner::all(&mut transaction).await?;
+        clip_series_assert_eq(&mut clip_series_res, &mut clip_series_vec);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_find_by_id_and_remove(
+        clip_series_vec: Vec<ClipSeries>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for clip_series in clip_series_vec.iter().cloned() {
+            clip_series_sql_runner::save(&mut transaction, clip_series).await?;
+        }
+
+        // クリップの順番も含めて取得できる
+        for clip_series in clip_series_vec.iter() {
+            let res =
+                clip_series_sql_runner::find_by_id(&mut transaction, clip_series.id()).await?;
+            assert_eq!(&res, clip_series);
+        }
+
+        let removed = clip_series_vec[0].clone();
+        clip_series_sql_runner::remove(&mut transaction, removed.id()).await?;
+
+        let res = clip_series_sql_runner::find_by_id(&mut transaction, removed.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = clip_series_sql_runner::remove(&mut transaction, removed.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_increment_like_and_unlike_per_client(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let clip_series = Faker.fake::<ClipSeries>();
+        let other_clip_series = Faker.fake::<ClipSeries>();
+        let (client, other_client) = (ClientId::generate(), ClientId::generate());
+
+        clip_series_sql_runner::save(&mut transaction, clip_series.clone()).await?;
+        clip_series_sql_runner::save(&mut transaction, other_clip_series.clone()).await?;
+
+        // 同じクライアントのLikeは一度だけ数える
+        for client in [client, client, other_client] {
+            clip_series_sql_runner::increment_like(&mut transaction, clip_series.id(), client)
+                .await?;
+        }
+
+        let res = clip_series_sql_runner::find_by_id(&mut transaction, clip_series.id()).await?;
+        assert_eq!(res.like(), clip_series.like() + 2);
+
+        let liked_ids = clip_series_sql_runner::liked_ids(
+            &mut transaction,
+            &[other_clip_series.id(), clip_series.id()],
+            client,
+        )
+        .await?;
+        assert_eq!(liked_ids, vec![clip_series.id()]);
+
+        // unlikeもクライアントごとに一度だけ
+        for _ in 0..2 {
+            clip_series_sql_runner::unlike(&mut transaction, clip_series.id(), client).await?;
+        }
+
+        let res = clip_series_sql_runner::find_by_id(&mut transaction, clip_series.id()).await?;
+        assert_eq!(res.like(), clip_series.like() + 1);
+
+        // 削除したClipSeriesは台帳からも削除される
+        clip_series_sql_runner::remove(&mut transaction, clip_series.id()).await?;
+        let liked_ids =
+            clip_series_sql_runner::liked_ids(&mut transaction, &[clip_series.id()], other_client)
+                .await?;
+        assert_eq!(liked_ids, Vec::<ClipSeriesId>::new());
+
+        // 存在しないClipSeries
+        let res =
+            clip_series_sql_runner::increment_like(&mut transaction, clip_series.id(), client)
+                .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_order_by_like_and_later(
+        clip_series_vec: Vec<ClipSeries>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut clip_series_vec = clip_series_vec;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query("DELETE FROM clip_series")
+            .execute(&mut transaction)
+            .await?;
+
+        for clip_series in clip_series_vec.iter().cloned() {
+            clip_series_sql_runner::save(&mut transaction, clip_series).await?;
+        }
+
+        let length = clip_series_vec.len() / 2;
+
+        let res = clip_series_sql_runner::order_by_like(&mut transaction, length).await?;
+        clip_series_assert_eq_with_sort_and_filter(
+            &res,
+            &mut clip_series_vec.clone(),
+            |x, y| y.like().cmp(&x.like()),
+            |_| true,
+            length,
+        );
+
+        // referenceとなるClipSeries
+        let reference = clip_series_vec[thread_rng().gen_range(0..length)].clone();
+
+        let res = clip_series_sql_runner::order_by_like_later(
+            &mut transaction,
+            &reference.like_cursor(),
+            length,
+        )
+        .await?;
+        clip_series_assert_eq_with_sort_and_filter(
+            &res,
+            &mut clip_series_vec,
+            |x, y| y.like().cmp(&x.like()),
+            |clip_series| {
+                reference.like() > clip_series.like()
+                    || (reference.like() == clip_series.like() && reference.id() < clip_series.id())
+            },
+            length,
+        );
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_order_by_create_date_and_later(
+        clip_series_vec: Vec<ClipSeries>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut clip_series_vec = clip_series_vec;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query("DELETE FROM clip_series")
+            .execute(&mut transaction)
+            .await?;
+
+        for clip_series in clip_series_vec.iter().cloned() {
+            clip_series_sql_runner::save(&mut transaction, clip_series).await?;
+        }
+
+        let length = clip_series_vec.len() / 2;
+
+        let res = clip_series_sql_runner::order_by_create_date(&mut transaction, length).await?;
+        clip_series_assert_eq_with_sort_and_filter(
+            &res,
+            &mut clip_series_vec.clone(),
+            |x, y| y.create_date().cmp(&x.create_date()),
+            |_| true,
+            length,
+        );
+
+        // referenceとなるClipSeries
+        let reference = clip_series_vec[thread_rng().gen_range(0..length)].clone();
+
+        let res = clip_series_sql_runner::order_by_create_date_later(
+            &mut transaction,
+            &reference.create_date_cursor(),
+            length,
+        )
+        .await?;
+        clip_series_assert_eq_with_sort_and_filter(
+            &res,
+            &mut clip_series_vec,
+            |x, y| y.create_date().cmp(&x.create_date()),
+            |clip_series| {
+                reference.create_date() > clip_series.create_date()
+                    || (reference.create_date() == clip_series.create_date()
+                        && reference.id() < clip_series.id())
+            },
+            length,
+        );
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,418 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::client::ClientId;
+use domain::clip_series::{ClipSeries, ClipSeriesId};
+use domain::ClipSeriesRepository;
+use domain::{Cursor, Date};
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+// -------------------------------------------------------------------------------------------------
+// # InMemoryClipSeriesRepository
+
+/// 即席のClipSeriesリポジトリ
+#[derive(Default, Debug, Clone)]
+pub struct InMemoryClipSeriesRepository {
+    map: Arc<Mutex<HashMap<Uuid, ClipSeries>>>,
+    /// Likeの台帳．(ClipSeriesのid, クライアントのid)を保持する．
+    likes: Arc<Mutex<HashSet<(Uuid, Uuid)>>>,
+}
+
+impl InMemoryClipSeriesRepository {
+    pub fn new() -> Self {
+        Self {
+            map: Arc::new(Mutex::new(HashMap::new())),
+            likes: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl ClipSeriesRepository for InMemoryClipSeriesRepository {
+    type Error = InfraError;
+    async fn save(&self, clip_series: ClipSeries) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .entry(clip_series.id().to_uuid())
+        {
+            Entry::Occupied(_) => Err(InfraError::ConflictError),
+            Entry::Vacant(v) => {
+                v.insert(clip_series);
+                Ok(())
+            }
+        }
+    }
+    async fn edit(&self, clip_series: ClipSeries) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .entry(clip_series.id().to_uuid())
+        {
+            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Occupied(mut o) => {
+                *o.get_mut() = clip_series;
+                Ok(())
+            }
+        }
+    }
+    async fn increment_like(&self, id: ClipSeriesId, client: ClientId) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .entry(id.to_uuid())
+        {
+            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Occupied(mut o) => {
+                // 既にLikeしている場合は何もしない
+                if self
+                    .likes
+                    .lock()
+                    .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+                    .insert((id.to_uuid(), client.to_uuid()))
+                {
+                    o.get_mut().increment_like();
+                }
+                Ok(())
+            }
+        }
+    }
+    async fn unlike(&self, id: ClipSeriesId, client: ClientId) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .entry(id.to_uuid())
+        {
+            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Occupied(mut o) => {
+                // Likeしていない場合は何もしない
+                if self
+                    .likes
+                    .lock()
+                    .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+                    .remove(&(id.to_uuid(), client.to_uuid()))
+                {
+                    o.get_mut().decrement_like();
+                }
+                Ok(())
+            }
+        }
+    }
+    async fn liked_ids(
+        &self,
+        ids: &[ClipSeriesId],
+        client: ClientId,
+    ) -> Result<Vec<ClipSeriesId>, InfraError> {
+        let likes = self
+            .likes
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        Ok(ids
+            .iter()
+            .filter(|id| likes.contains(&(id.to_uuid(), client.to_uuid())))
+            .cloned()
+            .collect::<Vec<_>>())
+    }
+    async fn all(&self) -> Result<Vec<ClipSeries>, InfraError> {
+        let all_clip_series = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        Ok(all_clip_series)
+    }
+    async fn find_by_id(&self, id: ClipSeriesId) -> Result<ClipSeries, InfraError> {
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .get(&id.to_uuid())
+            .cloned()
+            .ok_or(InfraError::NoRecordError)
+    }
+    async fn order_by_like(&self, length: usize) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut all_clip_series = self.all().await?;
+        all_clip_series.sort_by(|x, y| y.like().cmp(&x.like()).then_with(|| x.id().cmp(&y.id())));
+        Ok(all_clip_series.into_iter().take(length).collect::<Vec<_>>())
+    }
+    async fn order_by_like_later(
+        &self,
+        cursor: &Cursor<u32, ClipSeriesId>,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut all_clip_series = self.all().await?;
+        all_clip_series.sort_by(|x, y| y.like().cmp(&x.like()).then_with(|| x.id().cmp(&y.id())));
+        Ok(all_clip_series
+            .into_iter()
+            .filter(|clip_series| {
+                cursor.key() > clip_series.like()
+                    || (cursor.key() == clip_series.like() && cursor.id() < clip_series.id())
+            })
+            .take(length)
+            .collect::<Vec<_>>())
+    }
+    async fn order_by_create_date(&self, length: usize) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut all_clip_series = self.all().await?;
+        all_clip_series.sort_by(|x, y| {
+            y.create_date()
+                .cmp(&x.create_date())
+                .then_with(|| x.id().cmp(&y.id()))
+        });
+        Ok(all_clip_series.into_iter().take(length).collect::<Vec<_>>())
+    }
+    async fn order_by_create_date_later(
+        &self,
+        cursor: &Cursor<Date, ClipSeriesId>,
+        length: usize,
+    ) -> Result<Vec<ClipSeries>, InfraError> {
+        let mut all_clip_series = self.all().await?;
+        all_clip_series.sort_by(|x, y| {
+            y.create_date()
+                .cmp(&x.create_date())
+                .then_with(|| x.id().cmp(&y.id()))
+        });
+        Ok(all_clip_series
+            .into_iter()
+            .filter(|clip_series| {
+                cursor.key() > clip_series.create_date()
+                    || (cursor.key() == clip_series.create_date() && cursor.id() < clip_series.id())
+            })
+            .take(length)
+            .collect::<Vec<_>>())
+    }
+    async fn remove(&self, id: ClipSeriesId) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .remove(&id.to_uuid())
+        {
+            None => Err(InfraError::NoRecordError),
+            Some(_) => {
+                // 台帳からも削除する
+                self.likes
+                    .lock()
+                    .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+                    .retain(|(liked_id, _)| *liked_id != id.to_uuid());
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InMemoryClipSeriesRepository;
+    use crate::clip_series_repository_impl::clip_series_assert::{
+        clip_series_assert_eq, clip_series_assert_eq_with_sort_and_filter,
+    };
+    use crate::InfraError;
+    use domain::client::ClientId;
+    use domain::clip_series::{ClipSeries, ClipSeriesId};
+    use domain::ClipSeriesRepository;
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rand::{seq::SliceRandom, thread_rng, Rng};
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn clip_series_vec() -> Vec<ClipSeries> {
+        (0..100)
+            .map(|_| Faker.fake::<ClipSeries>())
+            .collect::<Vec<_>>()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_save_and_edit_and_all(
+        clip_series_vec: Vec<ClipSeries>,
+    ) -> Result<(), InfraError> {
+        let mut clip_series_vec = clip_series_vec;
+        let repo = InMemoryClipSeriesRepository::new();
+
+        for clip_series in clip_series_vec.iter().cloned() {
+            repo.save(clip_series).await?;
+        }
+
+        // 同じidのClipSeriesは保存できない
+        let res = repo.save(clip_series_vec[0].clone()).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // 一部を編集
+        for _ in 0..(clip_series_vec.len() / 2) {
+            let edited = clip_series_vec.choose_mut(&mut thread_rng()).unwrap();
+            edited.assign(Faker.fake());
+
+            repo.edit(edited.clone()).await?;
+        }
+
+        let mut clip_series_res = repo.all().await?;
+        clip_series_assert_eq(&mut clip_series_res, &mut clip_series_vec);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_find_by_id_and_remove(
+        clip_series_vec: Vec<ClipSeries>,
+    ) -> Result<(), InfraError> {
+        let repo = InMemoryClipSeriesRepository::new();
+
+        for clip_series in clip_series_vec.iter().cloned() {
+            repo.save(clip_series).await?;
+        }
+
+        for clip_series in clip_series_vec.iter() {
+            assert_eq!(&repo.find_by_id(clip_series.id()).await?, clip_series);
+        }
+
+        let removed = clip_series_vec[0].clone();
+        repo.remove(removed.id()).await?;
+
+        let res = repo.find_by_id(removed.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = repo.remove(removed.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_increment_like_and_unlike_per_client() -> Result<(), InfraError> {
+        let clip_series = Faker.fake::<ClipSeries>();
+        let other_clip_series = Faker.fake::<ClipSeries>();
+        let (client, other_client) = (ClientId::generate(), ClientId::generate());
+
+        let repo = InMemoryClipSeriesRepository::new();
+        repo.save(clip_series.clone()).await?;
+        repo.save(other_clip_series.clone()).await?;
+
+        // 同じクライアントのLikeは一度だけ数える
+        for client in [client, client, other_client] {
+            repo.increment_like(clip_series.id(), client).await?;
+        }
+        assert_eq!(
+            repo.find_by_id(clip_series.id()).await?.like(),
+            clip_series.like() + 2
+        );
+
+        let liked_ids = repo
+            .liked_ids(&[other_clip_series.id(), clip_series.id()], client)
+            .await?;
+        assert_eq!(liked_ids, vec![clip_series.id()]);
+
+        // unlikeもクライアントごとに一度だけ
+        for _ in 0..2 {
+            repo.unlike(clip_series.id(), client).await?;
+        }
+        assert_eq!(
+            repo.find_by_id(clip_series.id()).await?.like(),
+            clip_series.like() + 1
+        );
+
+        // 削除したClipSeriesは台帳からも削除される
+        repo.remove(clip_series.id()).await?;
+        let liked_ids = repo.liked_ids(&[clip_series.id()], other_client).await?;
+        assert_eq!(liked_ids, Vec::<ClipSeriesId>::new());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_order_by_like_and_later(
+        clip_series_vec: Vec<ClipSeries>,
+    ) -> Result<(), InfraError> {
+        let mut clip_series_vec = clip_series_vec;
+        let repo = InMemoryClipSeriesRepository::new();
+
+        for clip_series in clip_series_vec.iter().cloned() {
+            repo.save(clip_series).await?;
+        }
+
+        let length = clip_series_vec.len() / 2;
+
+        let res = repo.order_by_like(length).await?;
+        clip_series_assert_eq_with_sort_and_filter(
+            &res,
+            &mut clip_series_vec.clone(),
+            |x, y| y.like().cmp(&x.like()),
+            |_| true,
+            length,
+        );
+
+        // referenceとなるClipSeries
+        let reference = clip_series_vec[thread_rng().gen_range(0..length)].clone();
+
+        let res = repo
+            .order_by_like_later(&reference.like_cursor(), length)
+            .await?;
+        clip_series_assert_eq_with_sort_and_filter(
+            &res,
+            &mut clip_series_vec,
+            |x, y| y.like().cmp(&x.like()),
+            |clip_series| {
+                reference.like() > clip_series.like()
+                    || (reference.like() == clip_series.like() && reference.id() < clip_series.id())
+            },
+            length,
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_clip_series_order_by_create_date_and_later(
+        clip_series_vec: Vec<ClipSeries>,
+    ) -> Result<(), InfraError> {
+        let mut clip_series_vec = clip_series_vec;
+        let repo = InMemoryClipSeriesRepository::new();
+
+        for clip_series in clip_series_vec.iter().cloned() {
+            repo.save(clip_series).await?;
+        }
+
+        let length = clip_series_vec.len() / 2;
+
+        let res = repo.order_by_create_date(length).await?;
+        clip_series_assert_eq_with_sort_and_filter(
+            &res,
+            &mut clip_series_vec.clone(),
+            |x, y| y.create_date().cmp(&x.create_date()),
+            |_| true,
+            length,
+        );
+
+        // referenceとなるClipSeries
+        let reference = clip_series_vec[thread_rng().gen_range(0..length)].clone();
+
+        let res = repo
+            .order_by_create_date_later(&reference.create_date_cursor(), length)
+            .await?;
+        clip_series_assert_eq_with_sort_and_filter(
+            &res,
+            &mut clip_series_vec,
+            |x, y| y.create_date().cmp(&x.create_date()),
+            |clip_series| {
+                reference.create_date() > clip_series.create_date()
+                    || (reference.create_date() == clip_series.create_date()
+                        && reference.id() < clip_series.id())
+            },
+            length,
+        );
+
+        Ok(())
+    }
+}
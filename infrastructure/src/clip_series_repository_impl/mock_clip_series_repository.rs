@@ -0,0 +1,57 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::client::ClientId;
+use domain::clip_series::{ClipSeries, ClipSeriesId};
+use domain::ClipSeriesRepository;
+use domain::{Cursor, Date};
+
+use mockall::mock;
+
+mock! {
+    /// ClipSeriesのモックリポジトリ
+    #[derive(Debug, Clone)]
+    pub ClipSeriesRepository {}
+
+    #[async_trait]
+    impl ClipSeriesRepository for ClipSeriesRepository {
+        type Error = InfraError;
+        async fn save(&self, clip_series: ClipSeries) -> Result<(), InfraError>;
+        async fn edit(&self, clip_series: ClipSeries) -> Result<(), InfraError>;
+        async fn increment_like(
+            &self,
+            id: ClipSeriesId,
+            client: ClientId,
+        ) -> Result<(), InfraError>;
+        async fn unlike(
+            &self,
+            id: ClipSeriesId,
+            client: ClientId,
+        ) -> Result<(), InfraError>;
+        async fn liked_ids(
+            &self,
+            ids: &[ClipSeriesId],
+            client: ClientId,
+        ) -> Result<Vec<ClipSeriesId>, InfraError>;
+        async fn all(&self) -> Result<Vec<ClipSeries>, InfraError>;
+        async fn find_by_id(&self, id: ClipSeriesId) -> Result<ClipSeries, InfraError>;
+        async fn order_by_like(
+            &self,
+            length: usize,
+        ) -> Result<Vec<ClipSeries>, InfraError>;
+        async fn order_by_like_later(
+            &self,
+            cursor: &Cursor<u32, ClipSeriesId>,
+            length: usize,
+        ) -> Result<Vec<ClipSeries>, InfraError>;
+        async fn order_by_create_date(
+            &self,
+            length: usize,
+        ) -> Result<Vec<ClipSeries>, InfraError>;
+        async fn order_by_create_date_later(
+            &self,
+            cursor: &Cursor<Date, ClipSeriesId>,
+            length: usize,
+        ) -> Result<Vec<ClipSeries>, InfraError>;
+        async fn remove(&self, id: ClipSeriesId) -> Result<(), InfraError>;
+    }
+}
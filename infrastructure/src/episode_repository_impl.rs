@@ -1,4 +1,6 @@
 mod db_episode_repository;
+#[cfg(test)]
+mod episode_repository_contract;
 mod inmemory_episode_repository;
 mod mock_episode_repository;
 
@@ -22,7 +24,7 @@ pub(crate) mod episode_assert {
 
     /// expectedを`sort_f`を基にソート(同じ場合はidて昇順に)して`filter_f`を基にフィルタリングして比較．
     pub(crate) fn episodes_assert_eq_with_sort_by_key_and_filter<SF, FF>(
-        actual: &mut Vec<Episode>,
+        actual: &mut [Episode],
         expected: &mut Vec<Episode>,
         mut sort_f: SF,
         filter_f: FF,
@@ -1,50 +1,65 @@
+use crate::revision_store::revision_sql_runner;
 use crate::InfraError;
 use async_trait::async_trait;
 use domain::episode::{Episode, EpisodeId};
-use domain::{Date, EpisodeRepository};
+use domain::revision::{Actor, EntityKind, Operation, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
+use domain::{Date, EpisodeRepository, Tag};
 use sqlx::PgPool;
 
 // -------------------------------------------------------------------------------------------------
 // # episode_sql_runner
 
 mod episode_sql_runner {
+    use crate::search_pattern::like_patterns;
     use crate::InfraError;
     use domain::episode::{Episode, EpisodeId};
-    use domain::Date;
+    use domain::search::{SearchHit, SearchQuery};
+    use domain::{Date, DateTime, Tag};
     use sqlx::postgres::{PgConnection, Postgres};
 
     pub async fn save(conn: &mut PgConnection, episode: Episode) -> Result<(), InfraError> {
         sqlx::query(
             r#"
-INSERT INTO episodes ("date", content, id) VALUES ($1, $2, $3)
+INSERT INTO episodes ("date", content, id, version) VALUES ($1, $2, $3, $4)
         "#,
         )
         .bind(episode.date().to_chrono()?)
         .bind(episode.content().to_string())
         .bind(episode.id().to_uuid())
+        .bind(episode.version() as i32)
         .execute(conn)
-        .await
-        .map_err(|_| InfraError::ConflictError)?;
+        .await?;
         Ok(())
     }
+    /// Episodeを一つ編集し，版数を一つ増やす．版数が一致しない場合は`StaleVersion`を返す．
     pub async fn edit(conn: &mut PgConnection, episode: Episode) -> Result<(), InfraError> {
-        sqlx::query(
+        let edited = sqlx::query(
             r#"
-UPDATE episodes SET "date" = $1, content = $2 WHERE id = $3 RETURNING *
+UPDATE episodes SET "date" = $1, content = $2, version = version + 1
+WHERE id = $3 AND version = $4 AND deleted_at IS NULL RETURNING id
         "#,
         )
         .bind(episode.date().to_chrono()?)
         .bind(episode.content().to_string())
         .bind(episode.id().to_uuid())
-        .fetch_one(conn)
-        .await
-        .map_err(|_| InfraError::NoRecordError)?;
+        .bind(episode.version() as i32)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        if edited.is_none() {
+            // ゴミ箱に無く存在する場合は他の編集によって版数が進んでいる
+            lock(conn, episode.id()).await?;
+            return Err(InfraError::StaleVersion);
+        }
         Ok(())
     }
     pub async fn all(conn: &mut PgConnection) -> Result<Vec<Episode>, InfraError> {
-        let episodes = sqlx::query_as::<Postgres, Episode>(r#"SELECT * FROM episodes"#)
-            .fetch_all(conn)
-            .await?;
+        let episodes = sqlx::query_as::<Postgres, Episode>(
+            r#"SELECT * FROM episodes WHERE deleted_at IS NULL"#,
+        )
+        .fetch_all(conn)
+        .await?;
         Ok(episodes)
     }
     pub async fn order_by_date_range(
@@ -53,23 +68,194 @@ UPDATE episodes SET "date" = $1, content = $2 WHERE id = $3 RETURNING *
         end: Date,
     ) -> Result<Vec<Episode>, InfraError> {
         let ordered_by_date_range = sqlx::query_as::<Postgres, Episode>(
-            r#"SELECT * FROM episodes WHERE $1 <= "date" AND "date" < $2 ORDER BY "date""#,
+            r#"
+SELECT * FROM episodes WHERE deleted_at IS NULL AND $1 <= "date" AND "date" < $2 ORDER BY "date" ASC, id ASC
+            "#,
+        )
+        .bind(DateTime::from_date(start).to_chrono()?)
+        .bind(DateTime::from_date(end).to_chrono()?)
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_by_date_range)
+    }
+    /// `tags`を全て持つEpisodeを日付の範囲で取得．`tags`が空の場合は全てが対象．
+    pub async fn order_by_date_range_with_tags(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        start: Date,
+        end: Date,
+    ) -> Result<Vec<Episode>, InfraError> {
+        let ordered_by_date_range = sqlx::query_as::<Postgres, Episode>(
+            r#"
+SELECT * FROM episodes WHERE deleted_at IS NULL AND (cardinality($1::text[]) = 0 OR id IN (
+    SELECT episode_id FROM episode_tags WHERE tag = ANY($1) GROUP BY episode_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($1::text[]) AS t)
+))
+AND $2 <= "date" AND "date" < $3 ORDER BY "date" ASC, id ASC
+            "#,
         )
-        .bind(start.to_chrono()?)
-        .bind(end.to_chrono()?)
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(DateTime::from_date(start).to_chrono()?)
+        .bind(DateTime::from_date(end).to_chrono()?)
         .fetch_all(conn)
         .await?;
 
         Ok(ordered_by_date_range)
     }
-    pub async fn remove(conn: &mut PgConnection, id: EpisodeId) -> Result<(), InfraError> {
-        sqlx::query(r#"DELETE FROM episodes WHERE id = $1 RETURNING *"#)
+    /// `query`に一致するEpisodeをスコアの降順・idの昇順に`length`分取得．`search_text`はタグを除いた内容．
+    pub async fn search(
+        conn: &mut PgConnection,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<Episode>>, InfraError> {
+        let hits = sqlx::query_as::<Postgres, SearchHit<Episode>>(
+            r#"
+SELECT *, (ts_rank(search_vector, plainto_tsquery('simple', $1)) + word_similarity($1, search_text))::real AS score
+FROM episodes WHERE deleted_at IS NULL
+    AND (search_vector @@ plainto_tsquery('simple', $1) OR search_text ILIKE ALL ($2))
+ORDER BY score DESC, id ASC LIMIT $3
+            "#,
+        )
+        .bind(query.as_str())
+        .bind(like_patterns(query))
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(hits)
+    }
+    /// ゴミ箱に無いEpisodeに付けられている全てのタグを重複無く昇順で取得．
+    pub async fn all_tags(conn: &mut PgConnection) -> Result<Vec<Tag>, InfraError> {
+        let tags = sqlx::query_scalar::<Postgres, String>(
+            r#"
+SELECT DISTINCT tag FROM episode_tags
+JOIN episodes ON episodes.id = episode_tags.episode_id
+WHERE episodes.deleted_at IS NULL ORDER BY tag ASC
+            "#,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(tags
+            .into_iter()
+            .map(Tag::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+    /// `id`を持つEpisodeのタグを昇順で取得．
+    pub async fn tags(conn: &mut PgConnection, id: EpisodeId) -> Result<Vec<Tag>, InfraError> {
+        sqlx::query(r#"SELECT id FROM episodes WHERE id = $1 AND deleted_at IS NULL"#)
+            .bind(id.to_uuid())
+            .fetch_optional(&mut *conn)
+            .await?
+            .ok_or(InfraError::NoRecordError)?;
+
+        let tags = sqlx::query_scalar::<Postgres, String>(
+            r#"SELECT tag FROM episode_tags WHERE episode_id = $1 ORDER BY tag ASC"#,
+        )
+        .bind(id.to_uuid())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(tags
+            .into_iter()
+            .map(Tag::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+    /// `id`を持つEpisodeの行をロックする．存在しないかゴミ箱にある場合は`NoRecordError`を返す．
+    async fn lock(conn: &mut PgConnection, id: EpisodeId) -> Result<(), InfraError> {
+        sqlx::query(r#"SELECT id FROM episodes WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#)
             .bind(id.to_uuid())
-            .fetch_one(conn)
-            .await
-            .map_err(|_| InfraError::NoRecordError)?;
+            .fetch_optional(conn)
+            .await?
+            .ok_or(InfraError::NoRecordError)?;
+
         Ok(())
     }
+    /// `id`を持つEpisodeにタグを付ける．既に付いている場合は何もしない．
+    pub async fn add_tag(
+        conn: &mut PgConnection,
+        id: EpisodeId,
+        tag: Tag,
+    ) -> Result<(), InfraError> {
+        lock(&mut *conn, id).await?;
+
+        sqlx::query(
+            r#"INSERT INTO episode_tags (episode_id, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING"#,
+        )
+        .bind(id.to_uuid())
+        .bind(tag.to_string())
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+    /// `id`を持つEpisodeからタグを外す．付いていない場合は何もしない．
+    pub async fn remove_tag(
+        conn: &mut PgConnection,
+        id: EpisodeId,
+        tag: Tag,
+    ) -> Result<(), InfraError> {
+        lock(&mut *conn, id).await?;
+
+        sqlx::query(r#"DELETE FROM episode_tags WHERE episode_id = $1 AND tag = $2"#)
+            .bind(id.to_uuid())
+            .bind(tag.to_string())
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+    /// `id`を持つEpisodeをゴミ箱に移し，移したEpisodeを返す．
+    pub async fn remove(conn: &mut PgConnection, id: EpisodeId) -> Result<Episode, InfraError> {
+        let removed_episode = sqlx::query_as::<Postgres, Episode>(
+            r#"
+UPDATE episodes SET deleted_at = now() WHERE id = $1 AND deleted_at IS NULL RETURNING *
+            "#,
+        )
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(removed_episode)
+    }
+    /// ゴミ箱のEpisodeを削除した日時の降順・idの昇順で取得．
+    pub async fn trashed(conn: &mut PgConnection) -> Result<Vec<Episode>, InfraError> {
+        let trashed_episodes = sqlx::query_as::<Postgres, Episode>(
+            r#"
+SELECT * FROM episodes WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC, id ASC
+            "#,
+        )
+        .fetch_all(conn)
+        .await?;
+        Ok(trashed_episodes)
+    }
+    /// `id`を持つEpisodeをゴミ箱から戻し，戻したEpisodeを返す．
+    pub async fn restore(conn: &mut PgConnection, id: EpisodeId) -> Result<Episode, InfraError> {
+        let restored_episode = sqlx::query_as::<Postgres, Episode>(
+            r#"
+UPDATE episodes SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL RETURNING *
+            "#,
+        )
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(restored_episode)
+    }
+    /// `id`を持つEpisodeをゴミ箱から完全に削除し，削除したEpisodeを返す．
+    pub async fn purge(conn: &mut PgConnection, id: EpisodeId) -> Result<Episode, InfraError> {
+        let purged_episode = sqlx::query_as::<Postgres, Episode>(
+            r#"
+DELETE FROM episodes WHERE id = $1 AND deleted_at IS NOT NULL RETURNING *
+            "#,
+        )
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(purged_episode)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -91,14 +277,35 @@ impl EpisodePgDBRepository {
 #[async_trait]
 impl EpisodeRepository for EpisodePgDBRepository {
     type Error = InfraError;
-    async fn save(&self, episode: Episode) -> Result<(), InfraError> {
-        let mut conn = self.pool.acquire().await?;
-        episode_sql_runner::save(&mut conn, episode).await?;
+    async fn save(&self, episode: Episode, actor: Actor) -> Result<(), InfraError> {
+        let revision = Revision::new(
+            EntityKind::Episode,
+            episode.id().to_uuid(),
+            Operation::Save,
+            &episode,
+            actor,
+        )?;
+        let mut transaction = self.pool.begin().await?;
+        episode_sql_runner::save(&mut transaction, episode).await?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
         Ok(())
     }
-    async fn edit(&self, episode: Episode) -> Result<(), InfraError> {
-        let mut conn = self.pool.acquire().await?;
-        episode_sql_runner::edit(&mut conn, episode).await?;
+    async fn edit(&self, episode: Episode, actor: Actor) -> Result<(), InfraError> {
+        // 編集後は版数が一つ進む
+        let mut edited_episode = episode.clone();
+        edited_episode.increment_version();
+        let revision = Revision::new(
+            EntityKind::Episode,
+            edited_episode.id().to_uuid(),
+            Operation::Edit,
+            &edited_episode,
+            actor,
+        )?;
+        let mut transaction = self.pool.begin().await?;
+        episode_sql_runner::edit(&mut transaction, episode).await?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
         Ok(())
     }
     async fn all(&self) -> Result<Vec<Episode>, InfraError> {
@@ -116,23 +323,129 @@ impl EpisodeRepository for EpisodePgDBRepository {
             episode_sql_runner::order_by_date_range(&mut conn, start, end).await?;
         Ok(ordered_by_date_range)
     }
-    async fn remove(&self, id: EpisodeId) -> Result<(), InfraError> {
+    async fn order_by_date_range_with_tags(
+        &self,
+        tags: &[Tag],
+        start: Date,
+        end: Date,
+    ) -> Result<Vec<Episode>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let ordered_by_date_range =
+            episode_sql_runner::order_by_date_range_with_tags(&mut conn, tags, start, end).await?;
+        Ok(ordered_by_date_range)
+    }
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<Episode>>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let hits = episode_sql_runner::search(&mut conn, query, length).await?;
+        Ok(hits)
+    }
+    async fn all_tags(&self) -> Result<Vec<Tag>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let tags = episode_sql_runner::all_tags(&mut conn).await?;
+        Ok(tags)
+    }
+    async fn tags(&self, id: EpisodeId) -> Result<Vec<Tag>, InfraError> {
         let mut conn = self.pool.acquire().await?;
-        episode_sql_runner::remove(&mut conn, id).await?;
+        let tags = episode_sql_runner::tags(&mut conn, id).await?;
+        Ok(tags)
+    }
+    async fn add_tag(&self, id: EpisodeId, tag: Tag) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        episode_sql_runner::add_tag(&mut transaction, id, tag).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn remove_tag(&self, id: EpisodeId, tag: Tag) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        episode_sql_runner::remove_tag(&mut transaction, id, tag).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn remove(&self, id: EpisodeId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let removed_episode = episode_sql_runner::remove(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::Episode,
+            id.to_uuid(),
+            Operation::Remove,
+            &removed_episode,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
         Ok(())
     }
+    async fn trashed(&self) -> Result<Vec<Episode>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let episodes = episode_sql_runner::trashed(&mut conn).await?;
+        Ok(episodes)
+    }
+    async fn restore(&self, id: EpisodeId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let restored_episode = episode_sql_runner::restore(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::Episode,
+            id.to_uuid(),
+            Operation::Restore,
+            &restored_episode,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn purge(&self, id: EpisodeId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let purged_episode = episode_sql_runner::purge(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::Episode,
+            id.to_uuid(),
+            Operation::Purge,
+            &purged_episode,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn revisions(&self, id: EpisodeId) -> Result<Vec<Revision>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revisions =
+            revision_sql_runner::of_entity(&mut conn, EntityKind::Episode, id.to_uuid()).await?;
+        Ok(revisions)
+    }
+    async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revisions = revision_sql_runner::latest(&mut conn, EntityKind::Episode, length).await?;
+        Ok(revisions)
+    }
+    async fn revision(&self, id: RevisionId) -> Result<Revision, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revision = revision_sql_runner::find(&mut conn, EntityKind::Episode, id).await?;
+        Ok(revision)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::episode_sql_runner;
+    use super::{episode_sql_runner, EpisodePgDBRepository};
     use crate::episode_repository_impl::episode_assert::{
         episodes_assert_eq, episodes_assert_eq_with_sort_by_key_and_filter,
     };
+    use crate::episode_repository_impl::episode_repository_contract::episode_repository_contract;
     use crate::InfraError;
-    use domain::{episode::Episode, Date};
+    use domain::{
+        episode::{Episode, EpisodeId},
+        search::SearchQuery,
+        Date, Tag,
+    };
 
     use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
     use rand::seq::SliceRandom;
     use rstest::{fixture, rstest};
     use sqlx::postgres::{PgPool, PgPoolOptions};
@@ -207,6 +520,7 @@ mod test {
             edited_episode.assign(new_episode);
 
             episode_sql_runner::edit(&mut transaction, edited_episode.clone()).await?;
+            edited_episode.increment_version();
         }
 
         let mut episodes_res = episode_sql_runner::all(&mut transaction).await?;
@@ -246,7 +560,7 @@ mod test {
             &mut episodes_res,
             &mut episodes,
             |x, y| x.date().cmp(&y.date()),
-            |episode| start <= episode.date() && episode.date() < end,
+            |episode| start <= episode.date().date() && episode.date().date() < end,
         );
 
         // ロールバック
@@ -323,6 +637,134 @@ mod test {
         Ok(())
     }
 
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_edit_stale_version(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let mut episode = Faker.fake::<Episode>();
+        episode_sql_runner::save(&mut transaction, episode.clone()).await?;
+
+        // 同じ版数からの編集は先の一つのみ成功する
+        let stale_episode = episode.clone();
+        episode.assign(Faker.fake());
+        episode_sql_runner::edit(&mut transaction, episode.clone()).await?;
+
+        let res = episode_sql_runner::edit(&mut transaction, stale_episode).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)));
+
+        // 版数が一つ増えている
+        episode.increment_version();
+        let res = episode_sql_runner::all(&mut transaction).await?;
+        assert_eq!(res, vec![episode]);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_add_tag_and_order_with_tags(
+        episodes: Result<Vec<Episode>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let episodes = episodes?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for episode in episodes.iter().cloned() {
+            episode_sql_runner::save(&mut transaction, episode).await?;
+        }
+
+        let talk = Tag::new("Talk")?;
+
+        // 偶数番目にtalkを付ける
+        for episode in episodes.iter().step_by(2) {
+            episode_sql_runner::add_tag(&mut transaction, episode.id(), talk.clone()).await?;
+        }
+        episode_sql_runner::add_tag(&mut transaction, episodes[0].id(), talk.clone()).await?;
+
+        let tags = episode_sql_runner::tags(&mut transaction, episodes[0].id()).await?;
+        assert_eq!(tags, vec![talk.clone()]);
+        let all_tags = episode_sql_runner::all_tags(&mut transaction).await?;
+        assert_eq!(all_tags, vec![talk.clone()]);
+
+        let start = Date::from_ymd(1000, 1, 1)?;
+        let end = Date::from_ymd(2000, 1, 1)?;
+
+        let mut episodes_res = episode_sql_runner::order_by_date_range_with_tags(
+            &mut transaction,
+            std::slice::from_ref(&talk),
+            start,
+            end,
+        )
+        .await?;
+
+        let tagged_ids = episodes
+            .iter()
+            .step_by(2)
+            .map(|episode| episode.id())
+            .collect::<Vec<_>>();
+        episodes_assert_eq_with_sort_by_key_and_filter(
+            &mut episodes_res,
+            &mut episodes.clone(),
+            |x, y| x.date().cmp(&y.date()),
+            |episode| {
+                tagged_ids.contains(&episode.id())
+                    && start <= episode.date().date()
+                    && episode.date().date() < end
+            },
+        );
+
+        // タグを外す
+        episode_sql_runner::remove_tag(&mut transaction, episodes[0].id(), talk.clone()).await?;
+        let tags = episode_sql_runner::tags(&mut transaction, episodes[0].id()).await?;
+        assert_eq!(tags, Vec::<Tag>::new());
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_tags_no_exists(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+        let id = EpisodeId::generate();
+
+        let res = episode_sql_runner::add_tag(&mut transaction, id, Tag::new("talk")?).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = episode_sql_runner::remove_tag(&mut transaction, id, Tag::new("talk")?).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = episode_sql_runner::tags(&mut transaction, id).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
     #[ignore]
     #[rstest]
     #[tokio::test]
@@ -343,4 +785,114 @@ mod test {
 
         Ok(())
     }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_trash(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let mut episodes = (0..3).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>();
+        episodes.sort_by_key(|episode| episode.id());
+        for episode in episodes.iter().cloned() {
+            episode_sql_runner::save(&mut transaction, episode).await?;
+        }
+
+        // ゴミ箱に移したものは通常の取得の対象とならない
+        let removed = episode_sql_runner::remove(&mut transaction, episodes[0].id()).await?;
+        assert_eq!(removed, episodes[0]);
+        episode_sql_runner::remove(&mut transaction, episodes[1].id()).await?;
+        let res = episode_sql_runner::all(&mut transaction).await?;
+        assert_eq!(res, vec![episodes[2].clone()]);
+
+        // 同じトランザクション内では削除した日時が同じためidの昇順
+        let res = episode_sql_runner::trashed(&mut transaction).await?;
+        assert_eq!(res, episodes[..2].to_vec());
+
+        // ゴミ箱のものは編集できない
+        let res = episode_sql_runner::edit(&mut transaction, episodes[0].clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ゴミ箱から戻す
+        let restored = episode_sql_runner::restore(&mut transaction, episodes[0].id()).await?;
+        assert_eq!(restored, episodes[0]);
+        let mut res = episode_sql_runner::all(&mut transaction).await?;
+        res.sort_by_key(|episode| episode.id());
+        assert_eq!(res, vec![episodes[0].clone(), episodes[2].clone()]);
+
+        // ゴミ箱に無いものは戻せず，完全に削除もできない
+        let res = episode_sql_runner::restore(&mut transaction, episodes[2].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = episode_sql_runner::purge(&mut transaction, episodes[2].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // 完全に削除したものは戻せない
+        let purged = episode_sql_runner::purge(&mut transaction, episodes[1].id()).await?;
+        assert_eq!(purged, episodes[1]);
+        let res = episode_sql_runner::trashed(&mut transaction).await?;
+        assert_eq!(res, Vec::<Episode>::new());
+        let res = episode_sql_runner::restore(&mut transaction, episodes[1].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_search(
+        episodes: Result<Vec<Episode>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut episodes = episodes?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for episode in episodes.iter_mut().take(10) {
+            *episode.content_mut() = "<p>理子の<strong>歌枠</strong></p>".parse().unwrap();
+        }
+
+        for episode in episodes.iter().cloned() {
+            episode_sql_runner::save(&mut transaction, episode).await?;
+        }
+
+        let query = SearchQuery::new("歌枠").unwrap();
+        let hits = episode_sql_runner::search(&mut transaction, &query, 100).await?;
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+        let mut episodes_res = hits.into_iter().map(|hit| hit.item).collect::<Vec<_>>();
+        episodes_assert_eq(&mut episodes_res, &mut episodes[..10].to_vec());
+
+        // タグは検索の対象にならない
+        let query = SearchQuery::new("strong").unwrap();
+        let hits = episode_sql_runner::search(&mut transaction, &query, 100).await?;
+        assert!(hits.is_empty());
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_repository_contract(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        // DATABASE_URLが設定されていない場合は実行しない
+        if std::env::var("DATABASE_URL").is_err() {
+            return Ok(());
+        }
+        let repo = EpisodePgDBRepository::new(pool.await?);
+        episode_repository_contract(&repo).await
+    }
 }
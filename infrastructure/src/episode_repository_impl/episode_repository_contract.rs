@@ -0,0 +1,184 @@
+use crate::InfraError;
+use domain::episode::{Episode, EpisodeId};
+use domain::revision::Actor;
+use domain::{Date, DateTime, EpisodeRepository, Tag};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use std::cmp::Ordering;
+
+/// dateの昇順，同じ場合はidの昇順
+fn date_order(x: &Episode, y: &Episode) -> Ordering {
+    x.date().cmp(&y.date()).then_with(|| x.id().cmp(&y.id()))
+}
+
+/// `start`から`end`までの範囲でdateが重複するものと範囲の境界にあるものを含むフェイクのエピソード
+fn fake_episodes(start: Date, end: Date) -> Vec<Episode> {
+    let dates = (0..3)
+        .map(|_| (start..end).fake::<DateTime>())
+        .collect::<Vec<_>>();
+
+    let mut episodes = (0..20).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>();
+    episodes
+        .extend((0..20).map(|i| Episode::new_with_domains(dates[i % dates.len()], Faker.fake())));
+    episodes.push(Episode::new_with_domains(
+        DateTime::from_date(start),
+        Faker.fake(),
+    ));
+    episodes.push(Episode::new_with_domains(
+        DateTime::from_date(end),
+        Faker.fake(),
+    ));
+    episodes
+}
+
+/// `episodes`から`ids`をもつエピソードのみを順番を保って取り出す．
+fn own_episodes(episodes: &[Episode], ids: &[EpisodeId]) -> Vec<Episode> {
+    episodes
+        .iter()
+        .filter(|episode| ids.contains(&episode.id()))
+        .cloned()
+        .collect()
+}
+
+/// EpisodeRepositoryの実装が満たすべき性質を確かめる．
+/// 既存のエピソードがあっても成り立つように保存したエピソードのみを比較し，最後にそれらを完全に削除する．
+pub(crate) async fn episode_repository_contract<R>(repo: &R) -> Result<(), InfraError>
+where
+    R: EpisodeRepository<Error = InfraError>,
+{
+    let (start, end) = (Date::try_from((2000, 1, 1))?, Date::try_from((2001, 1, 1))?);
+    let mut episodes = fake_episodes(start, end);
+    let ids = episodes
+        .iter()
+        .map(|episode| episode.id())
+        .collect::<Vec<_>>();
+
+    for episode in episodes.iter().cloned() {
+        repo.save(episode, Actor::Admin).await?;
+    }
+
+    // 同じidのエピソードは保存できず，保存済みのものは変わらない
+    {
+        let mut conflicted = episodes[0].clone();
+        conflicted.assign(Faker.fake());
+        let res = repo.save(conflicted, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)), "{res:?}");
+    }
+
+    // 古い版数では編集できない
+    {
+        let mut edited = episodes[1].clone();
+        edited.assign(Faker.fake());
+        repo.edit(edited.clone(), Actor::Admin).await?;
+
+        let res = repo.edit(episodes[1].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)), "{res:?}");
+
+        edited.increment_version();
+        episodes[1] = edited;
+    }
+
+    {
+        let mut res = own_episodes(&repo.all().await?, &ids);
+        res.sort_by_key(|episode| episode.id());
+        let mut expected = episodes.clone();
+        expected.sort_by_key(|episode| episode.id());
+        assert_eq!(res, expected);
+    }
+
+    let in_range = |episode: &Episode| {
+        DateTime::from_date(start) <= episode.date() && episode.date() < DateTime::from_date(end)
+    };
+
+    // 範囲の取得はstartを含みendを含まず，dateが同じ場合はidで順番が一意に決まる
+    {
+        let ranged = repo.order_by_date_range(start, end).await?;
+        assert!(ranged.iter().all(in_range));
+        assert!(ranged
+            .windows(2)
+            .all(|pair| date_order(&pair[0], &pair[1]) == Ordering::Less));
+
+        let mut expected = episodes
+            .iter()
+            .filter(|episode| in_range(episode))
+            .cloned()
+            .collect::<Vec<_>>();
+        expected.sort_by(date_order);
+        assert_eq!(own_episodes(&ranged, &ids), expected);
+    }
+
+    // タグを指定した場合も同様
+    {
+        let tag = Faker.fake::<Tag>();
+        let tagged_ids = ids.iter().copied().step_by(2).collect::<Vec<_>>();
+        for id in tagged_ids.iter().copied() {
+            repo.add_tag(id, tag.clone()).await?;
+        }
+
+        let ranged = repo
+            .order_by_date_range_with_tags(&[tag], start, end)
+            .await?;
+        assert!(ranged
+            .windows(2)
+            .all(|pair| date_order(&pair[0], &pair[1]) == Ordering::Less));
+
+        let mut expected = episodes
+            .iter()
+            .filter(|episode| tagged_ids.contains(&episode.id()) && in_range(episode))
+            .cloned()
+            .collect::<Vec<_>>();
+        expected.sort_by(date_order);
+        assert_eq!(ranged, expected);
+    }
+
+    // 存在しないエピソードに対する操作
+    {
+        let no_exists = Faker.fake::<Episode>();
+        let tag = Faker.fake::<Tag>();
+
+        let res = repo.edit(no_exists.clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.tags(no_exists.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.add_tag(no_exists.id(), tag).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.remove(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.restore(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.purge(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+    }
+
+    // ゴミ箱のエピソードは取得できず，同じidで保存もできない
+    {
+        repo.remove(ids[2], Actor::Admin).await?;
+
+        assert!(own_episodes(&repo.all().await?, &ids[2..3]).is_empty());
+        assert_eq!(
+            own_episodes(&repo.trashed().await?, &ids),
+            vec![episodes[2].clone()]
+        );
+        let res = repo.tags(ids[2]).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.save(episodes[2].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)), "{res:?}");
+
+        repo.restore(ids[2], Actor::Admin).await?;
+        assert_eq!(
+            own_episodes(&repo.all().await?, &ids[2..3]),
+            vec![episodes[2].clone()]
+        );
+    }
+
+    // 後片付け
+    for id in ids.iter().copied() {
+        repo.remove(id, Actor::Admin).await?;
+        repo.purge(id, Actor::Admin).await?;
+    }
+    assert!(own_episodes(&repo.all().await?, &ids).is_empty());
+    assert!(own_episodes(&repo.trashed().await?, &ids).is_empty());
+
+    Ok(())
+}
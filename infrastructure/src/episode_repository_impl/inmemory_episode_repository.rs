@@ -1,11 +1,14 @@
+use crate::revision_store::InMemoryRevisions;
 use crate::InfraError;
 use async_trait::async_trait;
 use domain::episode::{Episode, EpisodeId};
-use domain::Date;
+use domain::revision::{Actor, EntityKind, Operation, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
 use domain::EpisodeRepository;
+use domain::{Date, DateTime, Tag};
 
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
@@ -16,43 +19,110 @@ use uuid::Uuid;
 #[derive(Default, Debug, Clone)]
 pub struct InMemoryEpisodeRepository {
     map: Arc<Mutex<HashMap<Uuid, Episode>>>,
+    /// タグの関係．(Episodeのid, タグ)を保持する．
+    tags: Arc<Mutex<HashSet<(Uuid, Tag)>>>,
+    /// ゴミ箱．移した順に保持し，タグの関係は完全に削除するまで残す．
+    trash: Arc<Mutex<Vec<Episode>>>,
+    /// 編集履歴
+    revisions: InMemoryRevisions,
 }
 
 impl InMemoryEpisodeRepository {
     pub fn new() -> Self {
         Self {
             map: Arc::new(Mutex::new(HashMap::new())),
+            tags: Arc::new(Mutex::new(HashSet::new())),
+            trash: Arc::new(Mutex::new(Vec::new())),
+            revisions: InMemoryRevisions::default(),
         }
     }
+
+    /// `id`を持つEpisodeが存在しない場合に`NoRecordError`を返す．
+    fn check_exists(&self, id: EpisodeId) -> Result<(), InfraError> {
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .contains_key(&id.to_uuid())
+            .then_some(())
+            .ok_or(InfraError::NoRecordError)
+    }
+
+    /// `id`を持つEpisodeをゴミ箱から取り出す．ゴミ箱に無い場合は`NoRecordError`を返す．
+    fn take_trashed(&self, id: EpisodeId) -> Result<Episode, InfraError> {
+        let mut trash = self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        let position = trash
+            .iter()
+            .position(|trashed| trashed.id() == id)
+            .ok_or(InfraError::NoRecordError)?;
+        Ok(trash.remove(position))
+    }
 }
 
 #[async_trait]
 impl EpisodeRepository for InMemoryEpisodeRepository {
     type Error = InfraError;
-    async fn save(&self, episode: Episode) -> Result<(), InfraError> {
-        let old_episode = self
+    async fn save(&self, episode: Episode, actor: Actor) -> Result<(), InfraError> {
+        let revision = Revision::new(
+            EntityKind::Episode,
+            episode.id().to_uuid(),
+            Operation::Save,
+            &episode,
+            actor,
+        )?;
+        // ゴミ箱にある場合も保存できない
+        if self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .any(|trashed| trashed.id() == episode.id())
+        {
+            return Err(InfraError::ConflictError);
+        }
+        // 既に存在する場合は上書きしない
+        match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
-            .insert(episode.id().to_uuid(), episode);
-        match old_episode {
-            Some(_) => Err(InfraError::ConflictError),
-            None => Ok(()),
+            .entry(episode.id().to_uuid())
+        {
+            Entry::Occupied(_) => return Err(InfraError::ConflictError),
+            Entry::Vacant(v) => {
+                v.insert(episode);
+            }
         }
+        self.revisions.push(revision)
     }
-    async fn edit(&self, episode: Episode) -> Result<(), InfraError> {
-        match self
+    async fn edit(&self, episode: Episode, actor: Actor) -> Result<(), InfraError> {
+        let revision = match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
             .entry(episode.id().to_uuid())
         {
-            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Vacant(_) => return Err(InfraError::NoRecordError),
             Entry::Occupied(mut o) => {
+                // 版数が一致しない場合は他の編集が行われている
+                if o.get().version() != episode.version() {
+                    return Err(InfraError::StaleVersion);
+                }
+                let mut episode = episode;
+                episode.increment_version();
+                let revision = Revision::new(
+                    EntityKind::Episode,
+                    episode.id().to_uuid(),
+                    Operation::Edit,
+                    &episode,
+                    actor,
+                )?;
                 *o.get_mut() = episode;
-                Ok(())
+                revision
             }
-        }
+        };
+        self.revisions.push(revision)
     }
     async fn all(&self) -> Result<Vec<Episode>, InfraError> {
         let episodes = self
@@ -70,24 +140,176 @@ impl EpisodeRepository for InMemoryEpisodeRepository {
         end: Date,
     ) -> Result<Vec<Episode>, InfraError> {
         let mut episodes = self.all().await?;
-        episodes.sort_by_key(|episode| episode.date());
+        episodes.sort_by(|x, y| x.date().cmp(&y.date()).then_with(|| x.id().cmp(&y.id())));
 
+        let (start, end) = (DateTime::from_date(start), DateTime::from_date(end));
         let episodes = episodes
             .into_iter()
             .filter(|episode| start <= episode.date() && episode.date() < end)
             .collect::<Vec<_>>();
         Ok(episodes)
     }
-    async fn remove(&self, id: EpisodeId) -> Result<(), InfraError> {
-        match self
+    async fn order_by_date_range_with_tags(
+        &self,
+        tags: &[Tag],
+        start: Date,
+        end: Date,
+    ) -> Result<Vec<Episode>, InfraError> {
+        let episodes = self.order_by_date_range(start, end).await?;
+        let tag_set = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+
+        Ok(episodes
+            .into_iter()
+            .filter(|episode| {
+                tags.iter()
+                    .all(|tag| tag_set.contains(&(episode.id().to_uuid(), tag.clone())))
+            })
+            .collect::<Vec<_>>())
+    }
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<Episode>>, InfraError> {
+        let mut hits = self
+            .all()
+            .await?
+            .into_iter()
+            .filter_map(|episode| {
+                let score = query.score(&[&episode.content().text()])?;
+                Some(SearchHit::new(episode, score))
+            })
+            .collect::<Vec<_>>();
+        hits.sort_by(|x, y| {
+            y.score
+                .total_cmp(&x.score)
+                .then_with(|| x.item.id().cmp(&y.item.id()))
+        });
+        Ok(hits.into_iter().take(length).collect::<Vec<_>>())
+    }
+    async fn all_tags(&self) -> Result<Vec<Tag>, InfraError> {
+        let live_ids = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .keys()
+            .cloned()
+            .collect::<HashSet<_>>();
+        let mut tags = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .filter(|(tagged_id, _)| live_ids.contains(tagged_id))
+            .map(|(_, tag)| tag.clone())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.dedup();
+        Ok(tags)
+    }
+    async fn tags(&self, id: EpisodeId) -> Result<Vec<Tag>, InfraError> {
+        self.check_exists(id)?;
+        let mut tags = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .filter(|(tagged_id, _)| *tagged_id == id.to_uuid())
+            .map(|(_, tag)| tag.clone())
+            .collect::<Vec<_>>();
+        tags.sort();
+        Ok(tags)
+    }
+    async fn add_tag(&self, id: EpisodeId, tag: Tag) -> Result<(), InfraError> {
+        self.check_exists(id)?;
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .insert((id.to_uuid(), tag));
+        Ok(())
+    }
+    async fn remove_tag(&self, id: EpisodeId, tag: Tag) -> Result<(), InfraError> {
+        self.check_exists(id)?;
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .remove(&(id.to_uuid(), tag));
+        Ok(())
+    }
+    async fn remove(&self, id: EpisodeId, actor: Actor) -> Result<(), InfraError> {
+        let removed_episode = match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
             .remove(&id.to_uuid())
         {
-            None => Err(InfraError::NoRecordError),
-            Some(_) => Ok(()),
-        }
+            None => return Err(InfraError::NoRecordError),
+            Some(removed_episode) => removed_episode,
+        };
+        let revision = Revision::new(
+            EntityKind::Episode,
+            id.to_uuid(),
+            Operation::Remove,
+            &removed_episode,
+            actor,
+        )?;
+        self.trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .push(removed_episode);
+        self.revisions.push(revision)
+    }
+    async fn trashed(&self) -> Result<Vec<Episode>, InfraError> {
+        Ok(self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .rev()
+            .cloned()
+            .collect())
+    }
+    async fn restore(&self, id: EpisodeId, actor: Actor) -> Result<(), InfraError> {
+        let restored_episode = self.take_trashed(id)?;
+        let revision = Revision::new(
+            EntityKind::Episode,
+            id.to_uuid(),
+            Operation::Restore,
+            &restored_episode,
+            actor,
+        )?;
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .insert(id.to_uuid(), restored_episode);
+        self.revisions.push(revision)
+    }
+    async fn purge(&self, id: EpisodeId, actor: Actor) -> Result<(), InfraError> {
+        let purged_episode = self.take_trashed(id)?;
+        // タグの関係も削除する
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .retain(|(tagged_id, _)| *tagged_id != id.to_uuid());
+        self.revisions.push(Revision::new(
+            EntityKind::Episode,
+            id.to_uuid(),
+            Operation::Purge,
+            &purged_episode,
+            actor,
+        )?)
+    }
+    async fn revisions(&self, id: EpisodeId) -> Result<Vec<Revision>, InfraError> {
+        self.revisions.of_entity(id.to_uuid())
+    }
+    async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, InfraError> {
+        self.revisions.latest(length)
+    }
+    async fn revision(&self, id: RevisionId) -> Result<Revision, InfraError> {
+        self.revisions.find(id)
     }
 }
 
@@ -97,11 +319,19 @@ mod test {
     use crate::episode_repository_impl::episode_assert::{
         episodes_assert_eq, episodes_assert_eq_with_sort_by_key_and_filter,
     };
+    use crate::episode_repository_impl::episode_repository_contract::episode_repository_contract;
     use crate::InfraError;
-    use domain::Date;
-    use domain::{episode::Episode, EpisodeRepository};
+    use domain::{
+        episode::{Episode, EpisodeId},
+        revision::{Actor, Operation, RevisionId},
+        search::SearchQuery,
+        user::UserId,
+        EpisodeRepository,
+    };
+    use domain::{Date, Tag};
 
     use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
     use rand::seq::SliceRandom;
     use rstest::{fixture, rstest};
 
@@ -121,7 +351,7 @@ mod test {
 
         let repo = InMemoryEpisodeRepository::new();
         for episode in episodes.iter().cloned() {
-            repo.save(episode).await?;
+            repo.save(episode, Actor::Admin).await?;
         }
 
         let mut episodes_res = repo.all().await?;
@@ -139,7 +369,7 @@ mod test {
 
         let repo = InMemoryEpisodeRepository::new();
         for episode in episodes.iter().cloned() {
-            repo.save(episode).await?;
+            repo.save(episode, Actor::Admin).await?;
         }
         // episodesの一部を編集
         for _ in 0..(episodes.len() / 2_usize) {
@@ -147,7 +377,8 @@ mod test {
             let new_episode = Faker.fake::<Episode>();
             edited_episode.assign(new_episode);
 
-            repo.edit(edited_episode.clone()).await?;
+            repo.edit(edited_episode.clone(), Actor::Admin).await?;
+            edited_episode.increment_version();
         }
 
         let mut episodes_res = repo.all().await?;
@@ -165,7 +396,7 @@ mod test {
 
         let repo = InMemoryEpisodeRepository::new();
         for episode in episodes.iter().cloned() {
-            repo.save(episode).await?;
+            repo.save(episode, Actor::Admin).await?;
         }
 
         let start = Date::from_ymd(1000, 1, 1)?;
@@ -177,7 +408,7 @@ mod test {
             &mut episodes_res,
             &mut episodes,
             |x, y| x.date().cmp(&y.date()),
-            |episode| start <= episode.date() && episode.date() < end,
+            |episode| start <= episode.date().date() && episode.date().date() < end,
         );
         Ok(())
     }
@@ -191,7 +422,7 @@ mod test {
 
         let repo = InMemoryEpisodeRepository::new();
         for episode in episodes.iter().cloned() {
-            repo.save(episode).await?;
+            repo.save(episode, Actor::Admin).await?;
         }
 
         // episodesの一部を削除
@@ -212,7 +443,7 @@ mod test {
             .collect::<Vec<_>>();
 
         for episode in removed_episodes.into_iter() {
-            repo.remove(episode.id()).await?
+            repo.remove(episode.id(), Actor::Admin).await?
         }
 
         let mut episodes_res = repo.all().await?;
@@ -228,7 +459,108 @@ mod test {
 
         let episode = Faker.fake::<Episode>();
 
-        let res = repo.edit(episode).await;
+        let res = repo.edit(episode, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_edit_stale_version() -> Result<(), InfraError> {
+        let repo = InMemoryEpisodeRepository::new();
+
+        let mut episode = Faker.fake::<Episode>();
+        repo.save(episode.clone(), Actor::Admin).await?;
+
+        // 同じ版数からの編集は先の一つのみ成功する
+        let stale_episode = episode.clone();
+        episode.assign(Faker.fake());
+        repo.edit(episode.clone(), Actor::Admin).await?;
+
+        let res = repo.edit(stale_episode, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)));
+
+        // 版数が一つ増えている
+        episode.increment_version();
+        let res = repo.all().await?;
+        assert_eq!(res, vec![episode]);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_add_tag_and_order_with_tags(
+        episodes: Result<Vec<Episode>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let episodes = episodes?;
+
+        let repo = InMemoryEpisodeRepository::new();
+        for episode in episodes.iter().cloned() {
+            repo.save(episode, Actor::Admin).await?;
+        }
+
+        let talk = Tag::new("Talk")?;
+
+        // 偶数番目にtalkを付ける
+        for episode in episodes.iter().step_by(2) {
+            repo.add_tag(episode.id(), talk.clone()).await?;
+        }
+        repo.add_tag(episodes[0].id(), talk.clone()).await?;
+
+        assert_eq!(repo.tags(episodes[0].id()).await?, vec![talk.clone()]);
+        assert_eq!(repo.all_tags().await?, vec![talk.clone()]);
+
+        let start = Date::from_ymd(1000, 1, 1)?;
+        let end = Date::from_ymd(2000, 1, 1)?;
+
+        let mut episodes_res = repo
+            .order_by_date_range_with_tags(std::slice::from_ref(&talk), start, end)
+            .await?;
+
+        let tagged_ids = episodes
+            .iter()
+            .step_by(2)
+            .map(|episode| episode.id())
+            .collect::<Vec<_>>();
+        episodes_assert_eq_with_sort_by_key_and_filter(
+            &mut episodes_res,
+            &mut episodes.clone(),
+            |x, y| x.date().cmp(&y.date()),
+            |episode| {
+                tagged_ids.contains(&episode.id())
+                    && start <= episode.date().date()
+                    && episode.date().date() < end
+            },
+        );
+
+        // タグを外す
+        repo.remove_tag(episodes[0].id(), talk.clone()).await?;
+        assert_eq!(repo.tags(episodes[0].id()).await?, Vec::<Tag>::new());
+
+        // ゴミ箱のEpisodeのタグは取得できない
+        repo.remove(episodes[2].id(), Actor::Admin).await?;
+        let res = repo.tags(episodes[2].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_tags_no_exists() -> Result<(), InfraError> {
+        let repo = InMemoryEpisodeRepository::new();
+
+        let res = repo.add_tag(EpisodeId::generate(), Tag::new("talk")?).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo
+            .remove_tag(EpisodeId::generate(), Tag::new("talk")?)
+            .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.tags(EpisodeId::generate()).await;
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
         Ok(())
@@ -241,9 +573,164 @@ mod test {
 
         let episode = Faker.fake::<Episode>();
 
-        let res = repo.remove(episode.id()).await;
+        let res = repo.remove(episode.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_search(
+        episodes: Result<Vec<Episode>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut episodes = episodes?;
+
+        // 先頭の10個の内容に検索する語を含める
+        for episode in episodes.iter_mut().take(10) {
+            *episode.content_mut() = "<p>理子の<strong>歌枠</strong></p>".parse().unwrap();
+        }
+
+        let repo = InMemoryEpisodeRepository::new();
+        for episode in episodes.iter().cloned() {
+            repo.save(episode, Actor::Admin).await?;
+        }
+
+        let query = SearchQuery::new("歌枠").unwrap();
+        let mut episodes_res = repo
+            .search(&query, 100)
+            .await?
+            .into_iter()
+            .map(|hit| hit.item)
+            .collect::<Vec<_>>();
+        episodes_assert_eq(&mut episodes_res, &mut episodes[..10].to_vec());
+
+        // タグは検索の対象としない
+        let query = SearchQuery::new("strong").unwrap();
+        assert!(repo.search(&query, 100).await?.is_empty());
+
+        Ok(())
+    }
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_revisions() -> Result<(), InfraError> {
+        let repo = InMemoryEpisodeRepository::new();
+
+        let mut episode = Faker.fake::<Episode>();
+        let user_id = UserId::generate();
+        repo.save(episode.clone(), Actor::User(user_id)).await?;
+
+        let saved_episode = episode.clone();
+        episode.assign(Faker.fake());
+        repo.edit(episode.clone(), Actor::Admin).await?;
+        episode.increment_version();
+
+        repo.remove(episode.id(), Actor::Anonymous).await?;
+
+        // 保存・編集・削除の順に残る
+        let revisions = repo.revisions(episode.id()).await?;
+        assert_eq!(
+            revisions
+                .iter()
+                .map(|revision| (revision.operation(), revision.actor()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Operation::Save, Actor::User(user_id)),
+                (Operation::Edit, Actor::Admin),
+                (Operation::Remove, Actor::Anonymous),
+            ]
+        );
+        assert_eq!(revisions[0].snapshot_as::<Episode>()?, saved_episode);
+        assert_eq!(revisions[1].snapshot_as::<Episode>()?, episode);
+        assert_eq!(revisions[2].snapshot_as::<Episode>()?, episode);
+
+        // 新しい順に取得できる
+        let latest = repo.latest_revisions(2).await?;
+        assert_eq!(latest, vec![revisions[2].clone(), revisions[1].clone()]);
+
+        assert_eq!(repo.revision(revisions[0].id()).await?, revisions[0]);
+        let res = repo.revision(RevisionId::generate()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_episode_trash() -> Result<(), InfraError> {
+        let repo = InMemoryEpisodeRepository::new();
+
+        let mut episodes = (0..3).map(|_| Faker.fake::<Episode>()).collect::<Vec<_>>();
+        episodes.sort_by_key(|episode| episode.id());
+        for episode in episodes.iter().cloned() {
+            repo.save(episode, Actor::Admin).await?;
+        }
+        let tag = Tag::new("歌枠")?;
+        repo.add_tag(episodes[0].id(), tag.clone()).await?;
+
+        // ゴミ箱に移したものは通常の取得の対象とならない
+        repo.remove(episodes[0].id(), Actor::Admin).await?;
+        repo.remove(episodes[1].id(), Actor::Admin).await?;
+        assert_eq!(repo.all().await?, vec![episodes[2].clone()]);
+        assert_eq!(repo.all_tags().await?, Vec::<Tag>::new());
+
+        // 移した日時の新しい順
+        assert_eq!(
+            repo.trashed().await?,
+            vec![episodes[1].clone(), episodes[0].clone()]
+        );
+
+        // ゴミ箱のものは編集できず，同じidでは保存できない
+        let res = repo.edit(episodes[0].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = repo.save(episodes[0].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ゴミ箱から戻すとタグも戻る
+        repo.restore(episodes[0].id(), Actor::Admin).await?;
+        let mut res = repo.all().await?;
+        res.sort_by_key(|episode| episode.id());
+        assert_eq!(res, vec![episodes[0].clone(), episodes[2].clone()]);
+        assert_eq!(repo.tags(episodes[0].id()).await?, vec![tag]);
+
+        // ゴミ箱に無いものは戻せず，完全に削除もできない
+        let res = repo.restore(episodes[2].id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = repo.purge(episodes[2].id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // 完全に削除したものは戻せない
+        repo.purge(episodes[1].id(), Actor::Admin).await?;
+        assert_eq!(repo.trashed().await?, Vec::<Episode>::new());
+        let res = repo.restore(episodes[1].id(), Actor::Admin).await;
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
+        let operations = repo
+            .revisions(episodes[1].id())
+            .await?
+            .iter()
+            .map(|revision| revision.operation())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operations,
+            vec![Operation::Save, Operation::Remove, Operation::Purge]
+        );
+        let operations = repo
+            .revisions(episodes[0].id())
+            .await?
+            .iter()
+            .map(|revision| revision.operation())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operations,
+            vec![Operation::Save, Operation::Remove, Operation::Restore]
+        );
+
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_episode_repository_contract() -> Result<(), InfraError> {
+        let repo = InMemoryEpisodeRepository::new();
+        episode_repository_contract(&repo).await
+    }
 }
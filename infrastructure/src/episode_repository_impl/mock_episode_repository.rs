@@ -1,8 +1,10 @@
 use crate::InfraError;
 use async_trait::async_trait;
 use domain::episode::{Episode, EpisodeId};
-use domain::Date;
+use domain::revision::{Actor, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
 use domain::EpisodeRepository;
+use domain::{Date, Tag};
 
 use mockall::mock;
 
@@ -14,14 +16,43 @@ mock! {
     #[async_trait]
     impl EpisodeRepository for EpisodeRepository {
         type Error = InfraError;
-        async fn save(&self, episode: Episode) -> Result<(), <Self as EpisodeRepository>::Error>;
-        async fn edit(&self, episode: Episode) -> Result<(), <Self as EpisodeRepository>::Error>;
+        async fn save(&self, episode: Episode, actor: Actor) -> Result<(), <Self as EpisodeRepository>::Error>;
+        async fn edit(&self, episode: Episode, actor: Actor) -> Result<(), <Self as EpisodeRepository>::Error>;
         async fn all(&self) -> Result<Vec<Episode>, <Self as EpisodeRepository>::Error>;
         async fn order_by_date_range(
             &self,
             start: Date,
             end: Date,
         ) -> Result<Vec<Episode>, <Self as EpisodeRepository>::Error>;
-        async fn remove(&self, id: EpisodeId) -> Result<(), <Self as EpisodeRepository>::Error>;
+        async fn order_by_date_range_with_tags(
+            &self,
+            tags: &[Tag],
+            start: Date,
+            end: Date,
+        ) -> Result<Vec<Episode>, <Self as EpisodeRepository>::Error>;
+        async fn search(
+            &self,
+            query: &SearchQuery,
+            length: usize,
+        ) -> Result<Vec<SearchHit<Episode>>, <Self as EpisodeRepository>::Error>;
+        async fn all_tags(&self) -> Result<Vec<Tag>, <Self as EpisodeRepository>::Error>;
+        async fn tags(&self, id: EpisodeId) -> Result<Vec<Tag>, <Self as EpisodeRepository>::Error>;
+        async fn add_tag(
+            &self,
+            id: EpisodeId,
+            tag: Tag,
+        ) -> Result<(), <Self as EpisodeRepository>::Error>;
+        async fn remove_tag(
+            &self,
+            id: EpisodeId,
+            tag: Tag,
+        ) -> Result<(), <Self as EpisodeRepository>::Error>;
+        async fn remove(&self, id: EpisodeId, actor: Actor) -> Result<(), <Self as EpisodeRepository>::Error>;
+        async fn trashed(&self) -> Result<Vec<Episode>, <Self as EpisodeRepository>::Error>;
+        async fn restore(&self, id: EpisodeId, actor: Actor) -> Result<(), <Self as EpisodeRepository>::Error>;
+        async fn purge(&self, id: EpisodeId, actor: Actor) -> Result<(), <Self as EpisodeRepository>::Error>;
+        async fn revisions(&self, id: EpisodeId) -> Result<Vec<Revision>, <Self as EpisodeRepository>::Error>;
+        async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, <Self as EpisodeRepository>::Error>;
+        async fn revision(&self, id: RevisionId) -> Result<Revision, <Self as EpisodeRepository>::Error>;
     }
 }
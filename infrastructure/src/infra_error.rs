@@ -1,4 +1,5 @@
-use domain::DomainError;
+use domain::{DomainError, ValidationCode, ValidationError};
+use sqlx::error::DatabaseError;
 
 /// インフラに関するエラー
 #[derive(thiserror::Error, Debug, Clone)]
@@ -21,6 +22,9 @@ pub enum InfraError {
     /// 編集・削除を行うときにデータが存在しない場合のエラー．テストでのみ起こると想定
     #[error("InfraError::NoRecordError: Removed row accessed")]
     NoRecordError,
+    /// 編集を行うときに他の編集によって版数が進んでいる場合のエラー
+    #[error("InfraError::StaleVersion: row has been edited by another request")]
+    StaleVersion,
 }
 
 impl From<sqlx::Error> for InfraError {
@@ -30,8 +34,9 @@ impl From<sqlx::Error> for InfraError {
             | e @ sqlx::Error::PoolClosed
             | e @ sqlx::Error::PoolTimedOut
             | e @ sqlx::Error::Tls(_)
-            | e @ sqlx::Error::Protocol(_)
-            | e @ sqlx::Error::Database(_) => InfraError::DBConnectionError(format!("{e}")),
+            | e @ sqlx::Error::Protocol(_) => InfraError::DBConnectionError(format!("{e}")),
+
+            sqlx::Error::Database(db_error) => db_error.as_ref().into(),
 
             e @ sqlx::Error::Configuration(_)
             | e @ sqlx::Error::RowNotFound
@@ -46,7 +51,34 @@ impl From<sqlx::Error> for InfraError {
             | e @ sqlx::Error::Migrate(_) => InfraError::OtherSQLXError(format!("{e}")),
 
             e @ sqlx::Error::Decode(_) => InfraError::DBDecodeError(format!("{e}")),
-            e @ _ => InfraError::OtherSQLXError(format!("Undefined Error: {e}")),
+            e => InfraError::OtherSQLXError(format!("Undefined Error: {e}")),
+        }
+    }
+}
+
+impl From<&dyn DatabaseError> for InfraError {
+    /// SQLSTATEと制約名からエラーを変換する．一意制約・外部キー制約違反は`ConflictError`，既知のCHECK制約違反はバリデーションエラーとする．
+    fn from(db_error: &dyn DatabaseError) -> Self {
+        match db_error.code().as_deref() {
+            // unique_violation, foreign_key_violation
+            Some("23505") | Some("23503") => InfraError::ConflictError,
+            // check_violation
+            Some("23514") => match db_error.constraint() {
+                Some("movie_clips_start_before_end") => {
+                    ValidationError::new(ValidationCode::EndBeforeStart, "range.end").into()
+                }
+                Some("movie_clips_end_within_duration") => {
+                    ValidationError::new(ValidationCode::ExceedsDuration, "range.end").into()
+                }
+                _ => InfraError::OtherSQLXError(format!("{db_error}")),
+            },
+            _ => InfraError::OtherSQLXError(format!("{db_error}")),
         }
     }
 }
+
+impl From<ValidationError> for InfraError {
+    fn from(validation_error: ValidationError) -> Self {
+        InfraError::DomainError(validation_error.into())
+    }
+}
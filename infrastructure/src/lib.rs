@@ -1,6 +1,12 @@
+pub mod channel_repository_impl;
+pub mod clip_series_repository_impl;
 pub mod episode_repository_impl;
 mod infra_error;
 pub mod movie_clip_repository_impl;
+pub mod quiz_repository_impl;
+mod revision_store;
+mod search_pattern;
+pub mod user_repository_impl;
 pub mod video_repository_impl;
 
 pub use infra_error::InfraError;
@@ -1,6 +1,8 @@
 mod db_movie_clip_repository;
 mod inmemory_movie_clip_repository;
 mod mock_movie_clip_repository;
+#[cfg(test)]
+mod movie_clip_repository_contract;
 
 pub use db_movie_clip_repository::MovieClipPgDBRepository;
 pub use inmemory_movie_clip_repository::InMemoryMovieClipRepository;
@@ -22,7 +24,7 @@ mod assert_movie_clip {
 
     /// expectedを`sort_f`を基にソート(同じ場合はidて昇順に)して`filter_f`を基にフィルタリングして比較．
     pub(crate) fn clips_assert_eq_with_sort_by_key_and_filter<SF, FF>(
-        actual: &mut Vec<MovieClip>,
+        actual: &mut [MovieClip],
         expected: &mut Vec<MovieClip>,
         mut sort_f: SF,
         filter_f: Option<FF>,
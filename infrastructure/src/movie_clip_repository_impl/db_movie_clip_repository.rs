@@ -1,8 +1,12 @@
+use crate::revision_store::revision_sql_runner;
 use crate::InfraError;
 use async_trait::async_trait;
+use domain::client::ClientId;
 use domain::movie_clip::{MovieClip, MovieClipId};
-use domain::Date;
+use domain::revision::{Actor, EntityKind, Operation, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
 use domain::MovieClipRepository;
+use domain::{Cursor, Date, Tag};
 use sqlx::PgPool;
 
 // -------------------------------------------------------------------------------------------------
@@ -10,75 +14,166 @@ use sqlx::PgPool;
 
 /// MovieClipに関するSQLのランナーモジュール
 mod movie_clip_sql_runner {
+    use crate::search_pattern::like_patterns;
     use crate::InfraError;
+    use domain::client::ClientId;
     use domain::movie_clip::{MovieClip, MovieClipId};
-    use domain::Date;
+    use domain::search::{SearchHit, SearchQuery};
+    use domain::{Cursor, Date, Tag};
     use sqlx::{PgConnection, Postgres};
+    use uuid::Uuid;
 
     /// MovieClipを一つ保存
     pub async fn save(conn: &mut PgConnection, movie_clip: MovieClip) -> Result<(), InfraError> {
         sqlx::query(
             r#"
-INSERT INTO movie_clips (title, "url", "start", "end", id, "like", create_date)
-VALUES ($1, $2, $3, $4,  $5, $6, $7)
+INSERT INTO movie_clips (title, "url", "start", "end", id, "like", create_date, duration, version)
+VALUES ($1, $2, $3, $4,  $5, $6, $7, $8, $9)
             "#,
         )
         .bind(movie_clip.title().to_string())
         .bind(movie_clip.url().to_string())
-        .bind(movie_clip.range().start().to_u32() as i32)
-        .bind(movie_clip.range().end().to_u32() as i32)
+        .bind(movie_clip.range().start().to_secs_f64())
+        .bind(movie_clip.range().end().to_secs_f64())
         .bind(movie_clip.id().to_uuid())
         .bind(movie_clip.like() as i32)
         .bind(movie_clip.create_date().to_chrono()?)
+        .bind(movie_clip.duration().map(|duration| duration.to_secs_f64()))
+        .bind(movie_clip.version() as i32)
         .execute(conn)
-        .await
-        .map_err(|_| InfraError::ConflictError)?;
+        .await?;
 
         Ok(())
     }
 
-    /// MovieClipを一つ編集
+    /// MovieClipを一つ編集し，版数を一つ増やす．版数が一致しない場合は`StaleVersion`を返す．
     pub async fn edit(conn: &mut PgConnection, movie_clip: MovieClip) -> Result<(), InfraError> {
-        sqlx::query(
+        let edited = sqlx::query(
             r#"
-UPDATE movie_clips SET title = $1, "url" = $2, "start" = $3, "end" = $4
-WHERE id = $5 RETURNING *
+UPDATE movie_clips SET title = $1, "url" = $2, "start" = $3, "end" = $4, duration = $5,
+    version = version + 1
+WHERE id = $6 AND version = $7 AND deleted_at IS NULL RETURNING id
             "#,
         )
         .bind(movie_clip.title().to_string())
         .bind(movie_clip.url().to_string())
-        .bind(movie_clip.range().start().to_u32() as i32)
-        .bind(movie_clip.range().end().to_u32() as i32)
+        .bind(movie_clip.range().start().to_secs_f64())
+        .bind(movie_clip.range().end().to_secs_f64())
+        .bind(movie_clip.duration().map(|duration| duration.to_secs_f64()))
         .bind(movie_clip.id().to_uuid())
-        .fetch_one(conn)
-        .await
-        .map_err(|_| InfraError::NoRecordError)?;
+        .bind(movie_clip.version() as i32)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        if edited.is_none() {
+            // ゴミ箱に無く存在する場合は他の編集によって版数が進んでいる
+            lock(conn, movie_clip.id()).await?;
+            return Err(InfraError::StaleVersion);
+        }
 
         Ok(())
     }
-    /// `id`を持つMovieClipのLikeを一つ増やす
+    /// `id`を持つMovieClipの行をロックする．存在しないかゴミ箱にある場合は`NoRecordError`を返す．
+    async fn lock(conn: &mut PgConnection, id: MovieClipId) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"SELECT id FROM movie_clips WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#,
+        )
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+
+        Ok(())
+    }
+
+    /// `id`を持つMovieClipのLikeを一つ増やす．`client`が既にLikeしている場合は何もしない．
     pub async fn increment_like(
         conn: &mut PgConnection,
         id: MovieClipId,
+        client: ClientId,
     ) -> Result<(), InfraError> {
-        sqlx::query(
+        lock(&mut *conn, id).await?;
+
+        let inserted = sqlx::query(
+            r#"
+INSERT INTO movie_clip_likes (movie_clip_id, client_id) VALUES ($1, $2) ON CONFLICT DO NOTHING
+        "#,
+        )
+        .bind(id.to_uuid())
+        .bind(client.to_uuid())
+        .execute(&mut *conn)
+        .await?;
+
+        if inserted.rows_affected() == 1 {
+            sqlx::query(r#"UPDATE movie_clips SET "like" = "like" + 1 WHERE id = $1"#)
+                .bind(id.to_uuid())
+                .execute(conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// `id`を持つMovieClipのLikeを一つ減らす．`client`がLikeしていない場合は何もしない．
+    pub async fn unlike(
+        conn: &mut PgConnection,
+        id: MovieClipId,
+        client: ClientId,
+    ) -> Result<(), InfraError> {
+        lock(&mut *conn, id).await?;
+
+        let deleted = sqlx::query(
             r#"
-UPDATE movie_clips SET "like" = "like" + 1 WHERE id = $1 RETURNING *
+DELETE FROM movie_clip_likes WHERE movie_clip_id = $1 AND client_id = $2
         "#,
         )
         .bind(id.to_uuid())
-        .fetch_one(conn)
-        .await
-        .map_err(|_| InfraError::NoRecordError)?;
+        .bind(client.to_uuid())
+        .execute(&mut *conn)
+        .await?;
+
+        if deleted.rows_affected() == 1 {
+            sqlx::query(r#"UPDATE movie_clips SET "like" = GREATEST("like" - 1, 0) WHERE id = $1"#)
+                .bind(id.to_uuid())
+                .execute(conn)
+                .await?;
+        }
 
         Ok(())
     }
 
+    /// `ids`のうち`client`がLikeしているゴミ箱に無いMovieClipのidを`ids`の順番で取得．
+    pub async fn liked_ids(
+        conn: &mut PgConnection,
+        ids: &[MovieClipId],
+        client: ClientId,
+    ) -> Result<Vec<MovieClipId>, InfraError> {
+        let liked_ids = sqlx::query_scalar::<Postgres, Uuid>(
+            r#"
+SELECT movie_clip_id FROM movie_clip_likes
+JOIN movie_clips ON movie_clips.id = movie_clip_likes.movie_clip_id
+WHERE client_id = $1 AND movie_clip_id = ANY($2) AND movie_clips.deleted_at IS NULL
+ORDER BY array_position($2, movie_clip_id)
+        "#,
+        )
+        .bind(client.to_uuid())
+        .bind(ids.iter().map(|id| id.to_uuid()).collect::<Vec<_>>())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(liked_ids
+            .into_iter()
+            .map(MovieClipId::from_uuid)
+            .collect::<Vec<_>>())
+    }
+
     /// 全てのMovieClipを取得．順番は保証されない．
     pub async fn all(conn: &mut PgConnection) -> Result<Vec<MovieClip>, InfraError> {
-        let all_clips = sqlx::query_as::<Postgres, MovieClip>(r#"SELECT * FROM movie_clips"#)
-            .fetch_all(conn)
-            .await?;
+        let all_clips = sqlx::query_as::<Postgres, MovieClip>(
+            r#"SELECT * FROM movie_clips WHERE deleted_at IS NULL"#,
+        )
+        .fetch_all(conn)
+        .await?;
 
         Ok(all_clips)
     }
@@ -90,37 +185,38 @@ UPDATE movie_clips SET "like" = "like" + 1 WHERE id = $1 RETURNING *
     ) -> Result<Vec<MovieClip>, InfraError> {
         let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
             r#"
-SELECT * FROM movie_clips ORDER BY "like" DESC, id ASC LIMIT $1
+SELECT * FROM movie_clips WHERE deleted_at IS NULL ORDER BY "like" DESC, id ASC LIMIT $1
                 "#,
         )
-        .bind(length as i32)
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
         .fetch_all(conn)
         .await?;
 
         Ok(ordered_clips)
     }
 
-    /// Likeを降順・さらにidを昇順として`reference`以降のMovieClipを`length`分取得．
+    /// Likeを降順・さらにidを昇順として`cursor`以降のMovieClipを`length`分取得．
     pub async fn order_by_like_later(
         conn: &mut PgConnection,
-        reference: &MovieClip,
+        cursor: &Cursor<u32, MovieClipId>,
         length: usize,
     ) -> Result<Vec<MovieClip>, InfraError> {
         let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
             r#"
-SELECT * FROM movie_clips WHERE $1 > "like" OR ($1 = "like" AND $2 < id) ORDER BY "like" DESC, id ASC LIMIT $3         
+SELECT * FROM movie_clips WHERE deleted_at IS NULL AND ($1 > "like" OR ($1 = "like" AND $2 < id))
+ORDER BY "like" DESC, id ASC LIMIT $3
             "#,
         )
-        .bind(reference.like() as i32)
-        .bind(reference.id().to_uuid())
-        .bind(length as i32)
+        .bind(cursor.key() as i32)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
         .fetch_all(conn)
         .await?;
 
         Ok(ordered_clips)
     }
 
-    /// create_dateを降順として指定した範囲分のMovieClipを`length`分取得．create_dateが同じ場合の順番は保証されない．
+    /// create_dateを昇順として指定した範囲分のMovieClipを取得．create_dateが同じ場合はidの昇順．
     pub async fn order_by_create_date_range(
         conn: &mut PgConnection,
         start: Date,
@@ -128,7 +224,8 @@ SELECT * FROM movie_clips WHERE $1 > "like" OR ($1 = "like" AND $2 < id) ORDER B
     ) -> Result<Vec<MovieClip>, InfraError> {
         let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
             r#"
-SELECT * FROM movie_clips WHERE $1 <= create_date AND create_date < $2 ORDER BY create_date ASC
+SELECT * FROM movie_clips WHERE deleted_at IS NULL AND $1 <= create_date AND create_date < $2
+ORDER BY create_date ASC, id ASC
             "#,
         )
         .bind(start.to_chrono()?)
@@ -146,49 +243,292 @@ SELECT * FROM movie_clips WHERE $1 <= create_date AND create_date < $2 ORDER BY
     ) -> Result<Vec<MovieClip>, InfraError> {
         let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
             r#"
-SELECT * FROM movie_clips ORDER BY create_date DESC, id ASC LIMIT $1
+SELECT * FROM movie_clips WHERE deleted_at IS NULL ORDER BY create_date DESC, id ASC LIMIT $1
             "#,
         )
-        .bind(length as i32)
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
         .fetch_all(conn)
         .await?;
 
         Ok(ordered_clips)
     }
 
-    /// create_dateを降順・さらにidを昇順として`reference`以降のMovieClipを`length`分取得．
+    /// create_dateを降順・さらにidを昇順として`cursor`以降のMovieClipを`length`分取得．
     pub async fn order_by_create_date_later(
         conn: &mut PgConnection,
-        reference: &MovieClip,
+        cursor: &Cursor<Date, MovieClipId>,
         length: usize,
     ) -> Result<Vec<MovieClip>, InfraError> {
         let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
             r#"
-SELECT * FROM movie_clips WHERE $1 > create_date OR ($1 = create_date AND $2 < id) ORDER BY create_date DESC, id ASC LIMIT $3
+SELECT * FROM movie_clips WHERE deleted_at IS NULL AND ($1 > create_date OR ($1 = create_date AND $2 < id))
+ORDER BY create_date DESC, id ASC LIMIT $3
             "#,
         )
-        .bind(reference.create_date().to_chrono()?)
-        .bind(reference.id().to_uuid())
-        .bind(length as i32)
+        .bind(cursor.key().to_chrono()?)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
         .fetch_all(conn)
         .await?;
 
         Ok(ordered_clips)
     }
 
-    /// `id`を持つMovieClipを削除．
-    pub async fn remove(conn: &mut PgConnection, id: MovieClipId) -> Result<(), InfraError> {
+    /// `tags`を全て持つMovieClipをLikeを降順・さらにidを昇順として`length`分取得．`tags`が空の場合は全てが対象．
+    pub async fn order_by_like_with_tags(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
+            r#"
+SELECT * FROM movie_clips WHERE deleted_at IS NULL AND (cardinality($1::text[]) = 0 OR id IN (
+    SELECT movie_clip_id FROM movie_clip_tags WHERE tag = ANY($1) GROUP BY movie_clip_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($1::text[]) AS t)
+))
+ORDER BY "like" DESC, id ASC LIMIT $2
+            "#,
+        )
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_clips)
+    }
+
+    /// `tags`を全て持つMovieClipをLikeを降順・さらにidを昇順として`cursor`以降を`length`分取得．
+    pub async fn order_by_like_later_with_tags(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        cursor: &Cursor<u32, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
+            r#"
+SELECT * FROM movie_clips WHERE deleted_at IS NULL AND (cardinality($1::text[]) = 0 OR id IN (
+    SELECT movie_clip_id FROM movie_clip_tags WHERE tag = ANY($1) GROUP BY movie_clip_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($1::text[]) AS t)
+))
+AND ($2 > "like" OR ($2 = "like" AND $3 < id)) ORDER BY "like" DESC, id ASC LIMIT $4
+            "#,
+        )
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(cursor.key() as i32)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_clips)
+    }
+
+    /// `tags`を全て持つMovieClipをcreate_dateを降順・さらにidを昇順として`length`分取得．`tags`が空の場合は全てが対象．
+    pub async fn order_by_create_date_with_tags(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
+            r#"
+SELECT * FROM movie_clips WHERE deleted_at IS NULL AND (cardinality($1::text[]) = 0 OR id IN (
+    SELECT movie_clip_id FROM movie_clip_tags WHERE tag = ANY($1) GROUP BY movie_clip_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($1::text[]) AS t)
+))
+ORDER BY create_date DESC, id ASC LIMIT $2
+            "#,
+        )
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_clips)
+    }
+
+    /// `tags`を全て持つMovieClipをcreate_dateを降順・さらにidを昇順として`cursor`以降を`length`分取得．
+    pub async fn order_by_create_date_later_with_tags(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        cursor: &Cursor<Date, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let ordered_clips = sqlx::query_as::<Postgres, MovieClip>(
+            r#"
+SELECT * FROM movie_clips WHERE deleted_at IS NULL AND (cardinality($1::text[]) = 0 OR id IN (
+    SELECT movie_clip_id FROM movie_clip_tags WHERE tag = ANY($1) GROUP BY movie_clip_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($1::text[]) AS t)
+))
+AND ($2 > create_date OR ($2 = create_date AND $3 < id)) ORDER BY create_date DESC, id ASC LIMIT $4
+            "#,
+        )
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(cursor.key().to_chrono()?)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_clips)
+    }
+
+    /// `query`に一致するMovieClipをスコアの降順・idの昇順に`length`分取得．
+    /// 単語の一致は`search_vector`，日本語などの部分一致は`search_text`のトライグラムを用いる．
+    pub async fn search(
+        conn: &mut PgConnection,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<MovieClip>>, InfraError> {
+        let hits = sqlx::query_as::<Postgres, SearchHit<MovieClip>>(
+            r#"
+SELECT *, (ts_rank(search_vector, plainto_tsquery('simple', $1)) + word_similarity($1, search_text))::real AS score
+FROM movie_clips WHERE deleted_at IS NULL
+    AND (search_vector @@ plainto_tsquery('simple', $1) OR search_text ILIKE ALL ($2))
+ORDER BY score DESC, id ASC LIMIT $3
+            "#,
+        )
+        .bind(query.as_str())
+        .bind(like_patterns(query))
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(hits)
+    }
+
+    /// ゴミ箱に無いMovieClipに付けられている全てのタグを重複無く昇順で取得．
+    pub async fn all_tags(conn: &mut PgConnection) -> Result<Vec<Tag>, InfraError> {
+        let tags = sqlx::query_scalar::<Postgres, String>(
+            r#"
+SELECT DISTINCT tag FROM movie_clip_tags
+JOIN movie_clips ON movie_clips.id = movie_clip_tags.movie_clip_id
+WHERE movie_clips.deleted_at IS NULL ORDER BY tag ASC
+            "#,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(tags
+            .into_iter()
+            .map(Tag::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// `id`を持つMovieClipのタグを昇順で取得．
+    pub async fn tags(conn: &mut PgConnection, id: MovieClipId) -> Result<Vec<Tag>, InfraError> {
+        sqlx::query(r#"SELECT id FROM movie_clips WHERE id = $1 AND deleted_at IS NULL"#)
+            .bind(id.to_uuid())
+            .fetch_optional(&mut *conn)
+            .await?
+            .ok_or(InfraError::NoRecordError)?;
+
+        let tags = sqlx::query_scalar::<Postgres, String>(
+            r#"SELECT tag FROM movie_clip_tags WHERE movie_clip_id = $1 ORDER BY tag ASC"#,
+        )
+        .bind(id.to_uuid())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(tags
+            .into_iter()
+            .map(Tag::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// `id`を持つMovieClipにタグを付ける．既に付いている場合は何もしない．
+    pub async fn add_tag(
+        conn: &mut PgConnection,
+        id: MovieClipId,
+        tag: Tag,
+    ) -> Result<(), InfraError> {
+        lock(&mut *conn, id).await?;
+
         sqlx::query(
             r#"
-DELETE FROM movie_clips WHERE id = $1 RETURNING *
+INSERT INTO movie_clip_tags (movie_clip_id, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING
             "#,
         )
         .bind(id.to_uuid())
-        .fetch_one(conn)
-        .await
-        .map_err(|_| InfraError::NoRecordError)?;
+        .bind(tag.to_string())
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// `id`を持つMovieClipからタグを外す．付いていない場合は何もしない．
+    pub async fn remove_tag(
+        conn: &mut PgConnection,
+        id: MovieClipId,
+        tag: Tag,
+    ) -> Result<(), InfraError> {
+        lock(&mut *conn, id).await?;
+
+        sqlx::query(r#"DELETE FROM movie_clip_tags WHERE movie_clip_id = $1 AND tag = $2"#)
+            .bind(id.to_uuid())
+            .bind(tag.to_string())
+            .execute(conn)
+            .await?;
+
         Ok(())
     }
+
+    /// `id`を持つMovieClipをゴミ箱に移し，移したMovieClipを返す．
+    pub async fn remove(conn: &mut PgConnection, id: MovieClipId) -> Result<MovieClip, InfraError> {
+        let removed_clip = sqlx::query_as::<Postgres, MovieClip>(
+            r#"
+UPDATE movie_clips SET deleted_at = now() WHERE id = $1 AND deleted_at IS NULL RETURNING *
+            "#,
+        )
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(removed_clip)
+    }
+
+    /// ゴミ箱のMovieClipを削除した日時の降順・idの昇順で取得．
+    pub async fn trashed(conn: &mut PgConnection) -> Result<Vec<MovieClip>, InfraError> {
+        let trashed_clips = sqlx::query_as::<Postgres, MovieClip>(
+            r#"
+SELECT * FROM movie_clips WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC, id ASC
+            "#,
+        )
+        .fetch_all(conn)
+        .await?;
+        Ok(trashed_clips)
+    }
+
+    /// `id`を持つMovieClipをゴミ箱から戻し，戻したMovieClipを返す．
+    pub async fn restore(
+        conn: &mut PgConnection,
+        id: MovieClipId,
+    ) -> Result<MovieClip, InfraError> {
+        let restored_clip = sqlx::query_as::<Postgres, MovieClip>(
+            r#"
+UPDATE movie_clips SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL RETURNING *
+            "#,
+        )
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(restored_clip)
+    }
+
+    /// `id`を持つMovieClipをゴミ箱から完全に削除し，削除したMovieClipを返す．
+    pub async fn purge(conn: &mut PgConnection, id: MovieClipId) -> Result<MovieClip, InfraError> {
+        let purged_clip = sqlx::query_as::<Postgres, MovieClip>(
+            r#"
+DELETE FROM movie_clips WHERE id = $1 AND deleted_at IS NOT NULL RETURNING *
+            "#,
+        )
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(purged_clip)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -209,21 +549,75 @@ impl MovieClipPgDBRepository {
 #[async_trait]
 impl MovieClipRepository for MovieClipPgDBRepository {
     type Error = InfraError;
-    async fn save(&self, movie_clip: MovieClip) -> Result<(), InfraError> {
-        let mut conn = self.pool.acquire().await?;
-        movie_clip_sql_runner::save(&mut conn, movie_clip).await?;
+    async fn save(&self, movie_clip: MovieClip, actor: Actor) -> Result<(), InfraError> {
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            movie_clip.id().to_uuid(),
+            Operation::Save,
+            &movie_clip,
+            actor,
+        )?;
+        let mut transaction = self.pool.begin().await?;
+        movie_clip_sql_runner::save(&mut transaction, movie_clip).await?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
         Ok(())
     }
-    async fn edit(&self, movie_clip: MovieClip) -> Result<(), InfraError> {
-        let mut conn = self.pool.acquire().await?;
-        movie_clip_sql_runner::edit(&mut conn, movie_clip).await?;
+    async fn save_many(&self, movie_clips: Vec<MovieClip>, actor: Actor) -> Result<(), InfraError> {
+        // 途中で失敗した場合はコミットせずにロールバックする
+        let mut transaction = self.pool.begin().await?;
+        for movie_clip in movie_clips {
+            let revision = Revision::new(
+                EntityKind::MovieClip,
+                movie_clip.id().to_uuid(),
+                Operation::Save,
+                &movie_clip,
+                actor,
+            )?;
+            movie_clip_sql_runner::save(&mut transaction, movie_clip).await?;
+            revision_sql_runner::save(&mut transaction, &revision).await?;
+        }
+        transaction.commit().await?;
         Ok(())
     }
-    async fn increment_like(&self, id: MovieClipId) -> Result<(), InfraError> {
-        let mut conn = self.pool.acquire().await?;
-        movie_clip_sql_runner::increment_like(&mut conn, id).await?;
+    async fn edit(&self, movie_clip: MovieClip, actor: Actor) -> Result<(), InfraError> {
+        // 編集後は版数が一つ進む
+        let mut edited_clip = movie_clip.clone();
+        edited_clip.increment_version();
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            edited_clip.id().to_uuid(),
+            Operation::Edit,
+            &edited_clip,
+            actor,
+        )?;
+        let mut transaction = self.pool.begin().await?;
+        movie_clip_sql_runner::edit(&mut transaction, movie_clip).await?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn increment_like(&self, id: MovieClipId, client: ClientId) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        movie_clip_sql_runner::increment_like(&mut transaction, id, client).await?;
+        transaction.commit().await?;
         Ok(())
     }
+    async fn unlike(&self, id: MovieClipId, client: ClientId) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        movie_clip_sql_runner::unlike(&mut transaction, id, client).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn liked_ids(
+        &self,
+        ids: &[MovieClipId],
+        client: ClientId,
+    ) -> Result<Vec<MovieClipId>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let liked_ids = movie_clip_sql_runner::liked_ids(&mut conn, ids, client).await?;
+        Ok(liked_ids)
+    }
     async fn all(&self) -> Result<Vec<MovieClip>, InfraError> {
         let mut conn = self.pool.acquire().await?;
         let movie_clips = movie_clip_sql_runner::all(&mut conn).await?;
@@ -236,12 +630,12 @@ impl MovieClipRepository for MovieClipPgDBRepository {
     }
     async fn order_by_like_later(
         &self,
-        reference: &MovieClip,
+        cursor: &Cursor<u32, MovieClipId>,
         length: usize,
     ) -> Result<Vec<MovieClip>, InfraError> {
         let mut conn = self.pool.acquire().await?;
         let movie_clips =
-            movie_clip_sql_runner::order_by_like_later(&mut conn, reference, length).await?;
+            movie_clip_sql_runner::order_by_like_later(&mut conn, cursor, length).await?;
         Ok(movie_clips)
     }
     async fn order_by_create_date_range(
@@ -261,32 +655,171 @@ impl MovieClipRepository for MovieClipPgDBRepository {
     }
     async fn order_by_create_date_later(
         &self,
-        reference: &MovieClip,
+        cursor: &Cursor<Date, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let movie_clips =
+            movie_clip_sql_runner::order_by_create_date_later(&mut conn, cursor, length).await?;
+        Ok(movie_clips)
+    }
+    async fn order_by_like_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let movie_clips =
+            movie_clip_sql_runner::order_by_like_with_tags(&mut conn, tags, length).await?;
+        Ok(movie_clips)
+    }
+    async fn order_by_like_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<u32, MovieClipId>,
         length: usize,
     ) -> Result<Vec<MovieClip>, InfraError> {
         let mut conn = self.pool.acquire().await?;
         let movie_clips =
-            movie_clip_sql_runner::order_by_create_date_later(&mut conn, reference, length).await?;
+            movie_clip_sql_runner::order_by_like_later_with_tags(&mut conn, tags, cursor, length)
+                .await?;
         Ok(movie_clips)
     }
-    async fn remove(&self, id: MovieClipId) -> Result<(), InfraError> {
+    async fn order_by_create_date_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
         let mut conn = self.pool.acquire().await?;
-        movie_clip_sql_runner::remove(&mut conn, id).await?;
+        let movie_clips =
+            movie_clip_sql_runner::order_by_create_date_with_tags(&mut conn, tags, length).await?;
+        Ok(movie_clips)
+    }
+    async fn order_by_create_date_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<Date, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let movie_clips = movie_clip_sql_runner::order_by_create_date_later_with_tags(
+            &mut conn, tags, cursor, length,
+        )
+        .await?;
+        Ok(movie_clips)
+    }
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<MovieClip>>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let hits = movie_clip_sql_runner::search(&mut conn, query, length).await?;
+        Ok(hits)
+    }
+    async fn all_tags(&self) -> Result<Vec<Tag>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let tags = movie_clip_sql_runner::all_tags(&mut conn).await?;
+        Ok(tags)
+    }
+    async fn tags(&self, id: MovieClipId) -> Result<Vec<Tag>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let tags = movie_clip_sql_runner::tags(&mut conn, id).await?;
+        Ok(tags)
+    }
+    async fn add_tag(&self, id: MovieClipId, tag: Tag) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        movie_clip_sql_runner::add_tag(&mut transaction, id, tag).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn remove_tag(&self, id: MovieClipId, tag: Tag) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        movie_clip_sql_runner::remove_tag(&mut transaction, id, tag).await?;
+        transaction.commit().await?;
         Ok(())
     }
+    async fn remove(&self, id: MovieClipId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let removed_clip = movie_clip_sql_runner::remove(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            id.to_uuid(),
+            Operation::Remove,
+            &removed_clip,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn trashed(&self) -> Result<Vec<MovieClip>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let movie_clips = movie_clip_sql_runner::trashed(&mut conn).await?;
+        Ok(movie_clips)
+    }
+    async fn restore(&self, id: MovieClipId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let restored_clip = movie_clip_sql_runner::restore(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            id.to_uuid(),
+            Operation::Restore,
+            &restored_clip,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn purge(&self, id: MovieClipId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let purged_clip = movie_clip_sql_runner::purge(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            id.to_uuid(),
+            Operation::Purge,
+            &purged_clip,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn revisions(&self, id: MovieClipId) -> Result<Vec<Revision>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revisions =
+            revision_sql_runner::of_entity(&mut conn, EntityKind::MovieClip, id.to_uuid()).await?;
+        Ok(revisions)
+    }
+    async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revisions =
+            revision_sql_runner::latest(&mut conn, EntityKind::MovieClip, length).await?;
+        Ok(revisions)
+    }
+    async fn revision(&self, id: RevisionId) -> Result<Revision, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revision = revision_sql_runner::find(&mut conn, EntityKind::MovieClip, id).await?;
+        Ok(revision)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::movie_clip_sql_runner;
+    use super::{movie_clip_sql_runner, MovieClipPgDBRepository};
     use crate::movie_clip_repository_impl::assert_movie_clip::{
         clips_assert_eq, clips_assert_eq_with_sort_by_key_and_filter,
     };
+    use crate::movie_clip_repository_impl::movie_clip_repository_contract::movie_clip_repository_contract;
     use crate::InfraError;
+    use domain::client::ClientId;
     use domain::movie_clip::{MovieClip, MovieClipId};
-    use domain::Date;
+    use domain::search::SearchQuery;
+    use domain::{Date, DomainError, Tag, ValidationCode, ValidationError};
 
     use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
     use rand::{seq::SliceRandom, thread_rng, Rng};
     use rstest::{fixture, rstest};
     use sqlx::postgres::{PgPool, PgPoolOptions};
@@ -358,6 +891,7 @@ mod test {
             edited_clip.assign(Faker.fake());
 
             movie_clip_sql_runner::edit(&mut transaction, edited_clip.clone()).await?;
+            edited_clip.increment_version();
         }
 
         let mut clips_res = movie_clip_sql_runner::all(&mut transaction).await?;
@@ -390,7 +924,12 @@ mod test {
         for _ in 0..(clips.len() / 2) {
             let incremented_clip = clips.choose_mut(&mut rand::thread_rng()).unwrap();
             incremented_clip.increment_like();
-            movie_clip_sql_runner::increment_like(&mut transaction, incremented_clip.id()).await?;
+            movie_clip_sql_runner::increment_like(
+                &mut transaction,
+                incremented_clip.id(),
+                ClientId::generate(),
+            )
+            .await?;
         }
 
         let mut clips_res = movie_clip_sql_runner::all(&mut transaction).await?;
@@ -402,6 +941,68 @@ mod test {
         Ok(())
     }
 
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_increment_like_and_unlike_per_client(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let clip = Faker.fake::<MovieClip>();
+        let other_clip = Faker.fake::<MovieClip>();
+        let (client, other_client) = (ClientId::generate(), ClientId::generate());
+
+        movie_clip_sql_runner::save(&mut transaction, clip.clone()).await?;
+        movie_clip_sql_runner::save(&mut transaction, other_clip.clone()).await?;
+
+        // 同じクライアントのLikeは一度だけ数える
+        for client in [client, client, other_client] {
+            movie_clip_sql_runner::increment_like(&mut transaction, clip.id(), client).await?;
+        }
+        movie_clip_sql_runner::increment_like(&mut transaction, other_clip.id(), other_client)
+            .await?;
+
+        let clips_res = movie_clip_sql_runner::all(&mut transaction).await?;
+        let clip_res = clips_res.iter().find(|res| res.id() == clip.id()).unwrap();
+        assert_eq!(clip_res.like(), clip.like() + 2);
+
+        let liked_ids = movie_clip_sql_runner::liked_ids(
+            &mut transaction,
+            &[other_clip.id(), clip.id()],
+            client,
+        )
+        .await?;
+        assert_eq!(liked_ids, vec![clip.id()]);
+
+        // unlikeもクライアントごとに一度だけ
+        for _ in 0..2 {
+            movie_clip_sql_runner::unlike(&mut transaction, clip.id(), client).await?;
+        }
+
+        let clips_res = movie_clip_sql_runner::all(&mut transaction).await?;
+        let clip_res = clips_res.iter().find(|res| res.id() == clip.id()).unwrap();
+        assert_eq!(clip_res.like(), clip.like() + 1);
+
+        let liked_ids =
+            movie_clip_sql_runner::liked_ids(&mut transaction, &[clip.id()], client).await?;
+        assert_eq!(liked_ids, Vec::<MovieClipId>::new());
+
+        // ゴミ箱のMovieClipは台帳から除かれる
+        movie_clip_sql_runner::remove(&mut transaction, clip.id()).await?;
+        let liked_ids =
+            movie_clip_sql_runner::liked_ids(&mut transaction, &[clip.id()], other_client).await?;
+        assert_eq!(liked_ids, Vec::<MovieClipId>::new());
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
     #[ignore]
     #[rstest]
     #[tokio::test]
@@ -465,9 +1066,12 @@ mod test {
             clips[reference_index].clone()
         };
 
-        let mut clips_res =
-            movie_clip_sql_runner::order_by_like_later(&mut transaction, &reference, length)
-                .await?;
+        let mut clips_res = movie_clip_sql_runner::order_by_like_later(
+            &mut transaction,
+            &reference.like_cursor(),
+            length,
+        )
+        .await?;
 
         // 参照元をlike(降順), idの順でソート・フィルタリングして比較
         clips_assert_eq_with_sort_by_key_and_filter(
@@ -588,9 +1192,12 @@ mod test {
             clips[reference_index].clone()
         };
 
-        let mut clips_res =
-            movie_clip_sql_runner::order_by_create_date_later(&mut transaction, &reference, length)
-                .await?;
+        let mut clips_res = movie_clip_sql_runner::order_by_create_date_later(
+            &mut transaction,
+            &reference.create_date_cursor(),
+            length,
+        )
+        .await?;
 
         // 参照元をcreate_date(降順)でソート・範囲をフィルタリング
         clips_assert_eq_with_sort_by_key_and_filter(
@@ -678,6 +1285,98 @@ mod test {
         Ok(())
     }
 
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_save_duplicated(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+        let clip = Faker.fake::<MovieClip>();
+        movie_clip_sql_runner::save(&mut transaction, clip.clone()).await?;
+
+        // 一意制約違反はConflictErrorとなる
+        let res = movie_clip_sql_runner::save(&mut transaction, clip).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_check_violation(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+        let clip = Faker.fake::<MovieClip>();
+        movie_clip_sql_runner::save(&mut transaction, clip.clone()).await?;
+
+        // CHECK制約違反はバリデーションエラーとなる
+        let res: Result<_, InfraError> =
+            sqlx::query(r#"UPDATE movie_clips SET duration = "end" - 1 WHERE id = $1"#)
+                .bind(clip.id().to_uuid())
+                .execute(&mut transaction)
+                .await
+                .map_err(Into::into);
+        assert!(matches!(
+            res,
+            Err(InfraError::DomainError(DomainError::DomainValidationError(
+                ValidationError {
+                    code: ValidationCode::ExceedsDuration,
+                    ..
+                }
+            )))
+        ));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_edit_stale_version(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let mut clip = Faker.fake::<MovieClip>();
+        movie_clip_sql_runner::save(&mut transaction, clip.clone()).await?;
+
+        // 同じ版数からの編集は先の一つのみ成功する
+        let stale_clip = clip.clone();
+        clip.assign(Faker.fake());
+        movie_clip_sql_runner::edit(&mut transaction, clip.clone()).await?;
+
+        let res = movie_clip_sql_runner::edit(&mut transaction, stale_clip).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)));
+
+        // 版数が一つ増えている
+        clip.increment_version();
+        let res = movie_clip_sql_runner::all(&mut transaction).await?;
+        assert_eq!(res, vec![clip]);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
     #[ignore]
     #[rstest]
     #[tokio::test]
@@ -690,7 +1389,141 @@ mod test {
         let mut transaction = pool.begin().await?;
         let clip = Faker.fake::<MovieClip>();
 
-        let res = movie_clip_sql_runner::increment_like(&mut transaction, clip.id()).await;
+        let res = movie_clip_sql_runner::increment_like(
+            &mut transaction,
+            clip.id(),
+            ClientId::generate(),
+        )
+        .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res =
+            movie_clip_sql_runner::unlike(&mut transaction, clip.id(), ClientId::generate()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_add_tag_and_order_with_tags(
+        movie_clips: Result<Vec<MovieClip>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let clips = movie_clips?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for clip in clips.iter().cloned() {
+            movie_clip_sql_runner::save(&mut transaction, clip).await?;
+        }
+
+        let (singing, game) = (Tag::new("Singing")?, Tag::new("game")?);
+
+        // 前半にsinging，偶数番目にgameを付ける
+        for (i, clip) in clips.iter().enumerate() {
+            if i < clips.len() / 2 {
+                movie_clip_sql_runner::add_tag(&mut transaction, clip.id(), singing.clone())
+                    .await?;
+            }
+            if i % 2 == 0 {
+                movie_clip_sql_runner::add_tag(&mut transaction, clip.id(), game.clone()).await?;
+            }
+        }
+        // 同じタグは一度だけ付く
+        movie_clip_sql_runner::add_tag(&mut transaction, clips[0].id(), game.clone()).await?;
+
+        let tags = movie_clip_sql_runner::tags(&mut transaction, clips[0].id()).await?;
+        assert_eq!(tags, vec![game.clone(), singing.clone()]);
+
+        let all_tags = movie_clip_sql_runner::all_tags(&mut transaction).await?;
+        assert_eq!(all_tags, vec![game.clone(), singing.clone()]);
+
+        let has_both = |clip: &MovieClip| {
+            let i = clips.iter().position(|c| c.id() == clip.id()).unwrap();
+            i < clips.len() / 2 && i % 2 == 0
+        };
+
+        let length = clips.len() / 2;
+        let mut clips_res = movie_clip_sql_runner::order_by_like_with_tags(
+            &mut transaction,
+            &[singing.clone(), game.clone()],
+            length,
+        )
+        .await?;
+
+        clips_assert_eq_with_sort_by_key_and_filter(
+            &mut clips_res,
+            &mut clips.clone(),
+            |x, y| y.like().cmp(&x.like()),
+            Some(has_both),
+            Some(length),
+        );
+
+        // referenceとなるclipを取得
+        let reference = clips[0].clone();
+        let mut clips_res = movie_clip_sql_runner::order_by_create_date_later_with_tags(
+            &mut transaction,
+            &[singing.clone(), game.clone()],
+            &reference.create_date_cursor(),
+            length,
+        )
+        .await?;
+
+        clips_assert_eq_with_sort_by_key_and_filter(
+            &mut clips_res,
+            &mut clips.clone(),
+            |x, y| y.create_date().cmp(&x.create_date()),
+            Some(|clip: &MovieClip| {
+                has_both(clip)
+                    && (clip.create_date() < reference.create_date()
+                        || (reference.create_date() == clip.create_date()
+                            && clip.id() > reference.id()))
+            }),
+            Some(length),
+        );
+
+        // タグを外す
+        movie_clip_sql_runner::remove_tag(&mut transaction, clips[0].id(), singing.clone()).await?;
+        let tags = movie_clip_sql_runner::tags(&mut transaction, clips[0].id()).await?;
+        assert_eq!(tags, vec![game.clone()]);
+
+        // ゴミ箱のMovieClipのタグは取得できない
+        movie_clip_sql_runner::remove(&mut transaction, clips[0].id()).await?;
+        let res = movie_clip_sql_runner::tags(&mut transaction, clips[0].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_tags_no_exists(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+        let id = MovieClipId::generate();
+
+        let res = movie_clip_sql_runner::add_tag(&mut transaction, id, Tag::new("game")?).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = movie_clip_sql_runner::remove_tag(&mut transaction, id, Tag::new("game")?).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = movie_clip_sql_runner::tags(&mut transaction, id).await;
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
         // ロールバック
@@ -718,4 +1551,126 @@ mod test {
 
         Ok(())
     }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_trash(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let mut clips = (0..3)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+        clips.sort_by_key(|clip| clip.id());
+        for clip in clips.iter().cloned() {
+            movie_clip_sql_runner::save(&mut transaction, clip).await?;
+        }
+
+        // ゴミ箱に移したものは通常の取得の対象とならない
+        let removed = movie_clip_sql_runner::remove(&mut transaction, clips[0].id()).await?;
+        assert_eq!(removed, clips[0]);
+        movie_clip_sql_runner::remove(&mut transaction, clips[1].id()).await?;
+        let res = movie_clip_sql_runner::all(&mut transaction).await?;
+        assert_eq!(res, vec![clips[2].clone()]);
+
+        // 同じトランザクション内では削除した日時が同じためidの昇順
+        let res = movie_clip_sql_runner::trashed(&mut transaction).await?;
+        assert_eq!(res, clips[..2].to_vec());
+
+        // ゴミ箱のものは編集できない
+        let res = movie_clip_sql_runner::edit(&mut transaction, clips[0].clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ゴミ箱から戻す
+        let restored = movie_clip_sql_runner::restore(&mut transaction, clips[0].id()).await?;
+        assert_eq!(restored, clips[0]);
+        let mut res = movie_clip_sql_runner::all(&mut transaction).await?;
+        res.sort_by_key(|clip| clip.id());
+        assert_eq!(res, vec![clips[0].clone(), clips[2].clone()]);
+
+        // ゴミ箱に無いものは戻せず，完全に削除もできない
+        let res = movie_clip_sql_runner::restore(&mut transaction, clips[2].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = movie_clip_sql_runner::purge(&mut transaction, clips[2].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // 完全に削除したものは戻せない
+        let purged = movie_clip_sql_runner::purge(&mut transaction, clips[1].id()).await?;
+        assert_eq!(purged, clips[1]);
+        let res = movie_clip_sql_runner::trashed(&mut transaction).await?;
+        assert_eq!(res, Vec::<MovieClip>::new());
+        let res = movie_clip_sql_runner::restore(&mut transaction, clips[1].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_search(
+        movie_clips: Result<Vec<MovieClip>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut clips = movie_clips?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        // 日本語の部分一致と単語の一致
+        for clip in clips.iter_mut().take(10) {
+            *clip.title_mut() = format!("{} 理子の歌枠", clip.title());
+        }
+        for clip in clips.iter_mut().skip(10).take(5) {
+            *clip.title_mut() = format!("Meitan {} Rikou", clip.title());
+        }
+
+        for clip in clips.iter().cloned() {
+            movie_clip_sql_runner::save(&mut transaction, clip).await?;
+        }
+
+        let query = SearchQuery::new("歌枠").unwrap();
+        let hits = movie_clip_sql_runner::search(&mut transaction, &query, 100).await?;
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+        let mut clips_res = hits.into_iter().map(|hit| hit.item).collect::<Vec<_>>();
+        clips_assert_eq(&mut clips_res, &mut clips[..10].to_vec());
+
+        // 語の順番と大文字・小文字は問わない
+        let query = SearchQuery::new("rikou MEITAN").unwrap();
+        let hits = movie_clip_sql_runner::search(&mut transaction, &query, 100).await?;
+        let mut clips_res = hits.into_iter().map(|hit| hit.item).collect::<Vec<_>>();
+        clips_assert_eq(&mut clips_res, &mut clips[10..15].to_vec());
+
+        // LIKEの特殊文字はそのまま検索する
+        let query = SearchQuery::new("%").unwrap();
+        let hits = movie_clip_sql_runner::search(&mut transaction, &query, 100).await?;
+        assert!(hits.is_empty());
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_repository_contract(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        // DATABASE_URLが設定されていない場合は実行しない
+        if std::env::var("DATABASE_URL").is_err() {
+            return Ok(());
+        }
+        let repo = MovieClipPgDBRepository::new(pool.await?);
+        movie_clip_repository_contract(&repo).await
+    }
 }
@@ -1,11 +1,15 @@
+use crate::revision_store::InMemoryRevisions;
 use crate::InfraError;
 use async_trait::async_trait;
+use domain::client::ClientId;
 use domain::movie_clip::{MovieClip, MovieClipId};
-use domain::Date;
+use domain::revision::{Actor, EntityKind, Operation, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
 use domain::MovieClipRepository;
+use domain::{Cursor, Date, Tag};
 
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
@@ -16,46 +20,206 @@ use uuid::Uuid;
 #[derive(Default, Debug, Clone)]
 pub struct InMemoryMovieClipRepository {
     map: Arc<Mutex<HashMap<Uuid, MovieClip>>>,
+    /// Likeの台帳．(MovieClipのid, クライアントのid)を保持する．
+    likes: Arc<Mutex<HashSet<(Uuid, Uuid)>>>,
+    /// タグの関係．(MovieClipのid, タグ)を保持する．
+    tags: Arc<Mutex<HashSet<(Uuid, Tag)>>>,
+    /// ゴミ箱．移した順に保持し，Likeとタグの関係は完全に削除するまで残す．
+    trash: Arc<Mutex<Vec<MovieClip>>>,
+    /// 編集履歴
+    revisions: InMemoryRevisions,
 }
 
 impl InMemoryMovieClipRepository {
     pub fn new() -> Self {
         Self {
             map: Arc::new(Mutex::new(HashMap::new())),
+            likes: Arc::new(Mutex::new(HashSet::new())),
+            tags: Arc::new(Mutex::new(HashSet::new())),
+            trash: Arc::new(Mutex::new(Vec::new())),
+            revisions: InMemoryRevisions::default(),
         }
     }
+
+    /// `tags`を全て持つMovieClipを取得する．
+    async fn all_with_tags(&self, tags: &[Tag]) -> Result<Vec<MovieClip>, InfraError> {
+        let clips = self.all().await?;
+        let tag_set = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        Ok(clips
+            .into_iter()
+            .filter(|clip| {
+                tags.iter()
+                    .all(|tag| tag_set.contains(&(clip.id().to_uuid(), tag.clone())))
+            })
+            .collect::<Vec<_>>())
+    }
+
+    /// `id`を持つMovieClipが存在しない場合に`NoRecordError`を返す．
+    fn check_exists(&self, id: MovieClipId) -> Result<(), InfraError> {
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .contains_key(&id.to_uuid())
+            .then_some(())
+            .ok_or(InfraError::NoRecordError)
+    }
+
+    /// `id`を持つMovieClipをゴミ箱から取り出す．ゴミ箱に無い場合は`NoRecordError`を返す．
+    fn take_trashed(&self, id: MovieClipId) -> Result<MovieClip, InfraError> {
+        let mut trash = self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        let position = trash
+            .iter()
+            .position(|trashed| trashed.id() == id)
+            .ok_or(InfraError::NoRecordError)?;
+        Ok(trash.remove(position))
+    }
+
+    /// ゴミ箱に無いMovieClipのidを取得する．
+    fn live_ids(&self) -> Result<HashSet<Uuid>, InfraError> {
+        Ok(self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .keys()
+            .cloned()
+            .collect())
+    }
 }
 
 #[async_trait]
 impl MovieClipRepository for InMemoryMovieClipRepository {
     type Error = InfraError;
-    async fn save(&self, clip: MovieClip) -> Result<(), InfraError> {
-        let old_clip = self
+    async fn save(&self, clip: MovieClip, actor: Actor) -> Result<(), InfraError> {
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            clip.id().to_uuid(),
+            Operation::Save,
+            &clip,
+            actor,
+        )?;
+        // ゴミ箱にある場合も保存できない
+        if self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .any(|trashed| trashed.id() == clip.id())
+        {
+            return Err(InfraError::ConflictError);
+        }
+        // 既に存在する場合は上書きしない
+        match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
-            .insert(clip.id().to_uuid(), clip);
-        match old_clip {
-            Some(_) => Err(InfraError::ConflictError),
-            None => Ok(()),
+            .entry(clip.id().to_uuid())
+        {
+            Entry::Occupied(_) => return Err(InfraError::ConflictError),
+            Entry::Vacant(v) => {
+                v.insert(clip);
+            }
         }
+        self.revisions.push(revision)
     }
 
-    async fn edit(&self, clip: MovieClip) -> Result<(), InfraError> {
-        match self
+    async fn save_many(&self, clips: Vec<MovieClip>, actor: Actor) -> Result<(), InfraError> {
+        let revisions = clips
+            .iter()
+            .map(|clip| {
+                Revision::new(
+                    EntityKind::MovieClip,
+                    clip.id().to_uuid(),
+                    Operation::Save,
+                    clip,
+                    actor,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // ゴミ箱にある場合も保存できない
+        let trashed_ids = self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .map(|trashed| trashed.id().to_uuid())
+            .collect::<HashSet<_>>();
+        {
+            let mut map = self
+                .map
+                .lock()
+                .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+            // 一つでも保存できない場合は何も保存しない
+            let mut ids = HashSet::new();
+            if !clips.iter().all(|clip| {
+                let id = clip.id().to_uuid();
+                !trashed_ids.contains(&id) && !map.contains_key(&id) && ids.insert(id)
+            }) {
+                return Err(InfraError::ConflictError);
+            }
+            map.extend(clips.into_iter().map(|clip| (clip.id().to_uuid(), clip)));
+        }
+        revisions
+            .into_iter()
+            .try_for_each(|revision| self.revisions.push(revision))
+    }
+
+    async fn edit(&self, clip: MovieClip, actor: Actor) -> Result<(), InfraError> {
+        let revision = match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
             .entry(clip.id().to_uuid())
         {
-            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Vacant(_) => return Err(InfraError::NoRecordError),
             Entry::Occupied(mut o) => {
+                // 版数が一致しない場合は他の編集が行われている
+                if o.get().version() != clip.version() {
+                    return Err(InfraError::StaleVersion);
+                }
+                let mut clip = clip;
+                clip.increment_version();
+                let revision = Revision::new(
+                    EntityKind::MovieClip,
+                    clip.id().to_uuid(),
+                    Operation::Edit,
+                    &clip,
+                    actor,
+                )?;
                 *o.get_mut() = clip;
+                revision
+            }
+        };
+        self.revisions.push(revision)
+    }
+    async fn increment_like(&self, id: MovieClipId, client: ClientId) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .entry(id.to_uuid())
+        {
+            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Occupied(mut o) => {
+                // 既にLikeしている場合は何もしない
+                if self
+                    .likes
+                    .lock()
+                    .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+                    .insert((id.to_uuid(), client.to_uuid()))
+                {
+                    o.get_mut().increment_like();
+                }
                 Ok(())
             }
         }
     }
-    async fn increment_like(&self, id: MovieClipId) -> Result<(), InfraError> {
+    async fn unlike(&self, id: MovieClipId, client: ClientId) -> Result<(), InfraError> {
         match self
             .map
             .lock()
@@ -64,11 +228,38 @@ impl MovieClipRepository for InMemoryMovieClipRepository {
         {
             Entry::Vacant(_) => Err(InfraError::NoRecordError),
             Entry::Occupied(mut o) => {
-                o.get_mut().increment_like();
+                // Likeしていない場合は何もしない
+                if self
+                    .likes
+                    .lock()
+                    .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+                    .remove(&(id.to_uuid(), client.to_uuid()))
+                {
+                    o.get_mut().decrement_like();
+                }
                 Ok(())
             }
         }
     }
+    async fn liked_ids(
+        &self,
+        ids: &[MovieClipId],
+        client: ClientId,
+    ) -> Result<Vec<MovieClipId>, InfraError> {
+        let live_ids = self.live_ids()?;
+        let likes = self
+            .likes
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        Ok(ids
+            .iter()
+            .filter(|id| {
+                live_ids.contains(&id.to_uuid())
+                    && likes.contains(&(id.to_uuid(), client.to_uuid()))
+            })
+            .cloned()
+            .collect::<Vec<_>>())
+    }
 
     async fn all(&self) -> Result<Vec<MovieClip>, InfraError> {
         let clips = self
@@ -88,7 +279,7 @@ impl MovieClipRepository for InMemoryMovieClipRepository {
 
     async fn order_by_like_later(
         &self,
-        reference: &MovieClip,
+        cursor: &Cursor<u32, MovieClipId>,
         length: usize,
     ) -> Result<Vec<MovieClip>, InfraError> {
         let mut clips = self.all().await?;
@@ -96,8 +287,8 @@ impl MovieClipRepository for InMemoryMovieClipRepository {
         Ok(clips
             .into_iter()
             .filter(|clip| {
-                reference.like() > clip.like()
-                    || (reference.like() == clip.like() && reference.id() < clip.id())
+                cursor.key() > clip.like()
+                    || (cursor.key() == clip.like() && cursor.id() < clip.id())
             })
             .take(length)
             .collect::<Vec<_>>())
@@ -109,7 +300,11 @@ impl MovieClipRepository for InMemoryMovieClipRepository {
         end: Date,
     ) -> Result<Vec<MovieClip>, InfraError> {
         let mut clips = self.all().await?;
-        clips.sort_by_key(|clip| clip.create_date());
+        clips.sort_by(|x, y| {
+            x.create_date()
+                .cmp(&y.create_date())
+                .then_with(|| x.id().cmp(&y.id()))
+        });
         Ok(clips
             .into_iter()
             .filter(|clip| start <= clip.create_date() && clip.create_date() < end)
@@ -131,7 +326,7 @@ impl MovieClipRepository for InMemoryMovieClipRepository {
 
     async fn order_by_create_date_later(
         &self,
-        reference: &MovieClip,
+        cursor: &Cursor<Date, MovieClipId>,
         length: usize,
     ) -> Result<Vec<MovieClip>, <Self as MovieClipRepository>::Error> {
         let mut clips = self.all().await?;
@@ -143,23 +338,221 @@ impl MovieClipRepository for InMemoryMovieClipRepository {
         Ok(clips
             .into_iter()
             .filter(|clip| {
-                reference.create_date() > clip.create_date()
-                    || (reference.create_date() == clip.create_date() && reference.id() < clip.id())
+                cursor.key() > clip.create_date()
+                    || (cursor.key() == clip.create_date() && cursor.id() < clip.id())
             })
             .take(length)
             .collect::<Vec<_>>())
     }
 
-    async fn remove(&self, id: MovieClipId) -> Result<(), InfraError> {
-        match self
+    async fn order_by_like_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let mut clips = self.all_with_tags(tags).await?;
+        clips.sort_by(|x, y| y.like().cmp(&x.like()).then_with(|| x.id().cmp(&y.id())));
+        Ok(clips.into_iter().take(length).collect::<Vec<_>>())
+    }
+
+    async fn order_by_like_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<u32, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let mut clips = self.all_with_tags(tags).await?;
+        clips.sort_by(|x, y| y.like().cmp(&x.like()).then_with(|| x.id().cmp(&y.id())));
+        Ok(clips
+            .into_iter()
+            .filter(|clip| {
+                cursor.key() > clip.like()
+                    || (cursor.key() == clip.like() && cursor.id() < clip.id())
+            })
+            .take(length)
+            .collect::<Vec<_>>())
+    }
+
+    async fn order_by_create_date_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let mut clips = self.all_with_tags(tags).await?;
+        clips.sort_by(|x, y| {
+            y.create_date()
+                .cmp(&x.create_date())
+                .then_with(|| x.id().cmp(&y.id()))
+        });
+        Ok(clips.into_iter().take(length).collect::<Vec<_>>())
+    }
+
+    async fn order_by_create_date_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<Date, MovieClipId>,
+        length: usize,
+    ) -> Result<Vec<MovieClip>, InfraError> {
+        let mut clips = self.all_with_tags(tags).await?;
+        clips.sort_by(|x, y| {
+            y.create_date()
+                .cmp(&x.create_date())
+                .then_with(|| x.id().cmp(&y.id()))
+        });
+        Ok(clips
+            .into_iter()
+            .filter(|clip| {
+                cursor.key() > clip.create_date()
+                    || (cursor.key() == clip.create_date() && cursor.id() < clip.id())
+            })
+            .take(length)
+            .collect::<Vec<_>>())
+    }
+
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<MovieClip>>, InfraError> {
+        let mut hits = self
+            .all()
+            .await?
+            .into_iter()
+            .filter_map(|clip| {
+                let score = query.score(&[clip.title()])?;
+                Some(SearchHit::new(clip, score))
+            })
+            .collect::<Vec<_>>();
+        hits.sort_by(|x, y| {
+            y.score
+                .total_cmp(&x.score)
+                .then_with(|| x.item.id().cmp(&y.item.id()))
+        });
+        Ok(hits.into_iter().take(length).collect::<Vec<_>>())
+    }
+
+    async fn all_tags(&self) -> Result<Vec<Tag>, InfraError> {
+        let live_ids = self.live_ids()?;
+        let mut tags = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .filter(|(tagged_id, _)| live_ids.contains(tagged_id))
+            .map(|(_, tag)| tag.clone())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.dedup();
+        Ok(tags)
+    }
+
+    async fn tags(&self, id: MovieClipId) -> Result<Vec<Tag>, InfraError> {
+        self.check_exists(id)?;
+        let mut tags = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .filter(|(tagged_id, _)| *tagged_id == id.to_uuid())
+            .map(|(_, tag)| tag.clone())
+            .collect::<Vec<_>>();
+        tags.sort();
+        Ok(tags)
+    }
+
+    async fn add_tag(&self, id: MovieClipId, tag: Tag) -> Result<(), InfraError> {
+        self.check_exists(id)?;
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .insert((id.to_uuid(), tag));
+        Ok(())
+    }
+
+    async fn remove_tag(&self, id: MovieClipId, tag: Tag) -> Result<(), InfraError> {
+        self.check_exists(id)?;
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .remove(&(id.to_uuid(), tag));
+        Ok(())
+    }
+
+    async fn remove(&self, id: MovieClipId, actor: Actor) -> Result<(), InfraError> {
+        let removed_clip = match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
             .remove(&id.to_uuid())
         {
-            None => Err(InfraError::NoRecordError),
-            Some(_) => Ok(()),
-        }
+            None => return Err(InfraError::NoRecordError),
+            Some(removed_clip) => removed_clip,
+        };
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            id.to_uuid(),
+            Operation::Remove,
+            &removed_clip,
+            actor,
+        )?;
+        self.trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .push(removed_clip);
+        self.revisions.push(revision)
+    }
+    async fn trashed(&self) -> Result<Vec<MovieClip>, InfraError> {
+        Ok(self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .rev()
+            .cloned()
+            .collect())
+    }
+    async fn restore(&self, id: MovieClipId, actor: Actor) -> Result<(), InfraError> {
+        let restored_clip = self.take_trashed(id)?;
+        let revision = Revision::new(
+            EntityKind::MovieClip,
+            id.to_uuid(),
+            Operation::Restore,
+            &restored_clip,
+            actor,
+        )?;
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .insert(id.to_uuid(), restored_clip);
+        self.revisions.push(revision)
+    }
+    async fn purge(&self, id: MovieClipId, actor: Actor) -> Result<(), InfraError> {
+        let purged_clip = self.take_trashed(id)?;
+        // 台帳からも削除する
+        self.likes
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .retain(|(liked_id, _)| *liked_id != id.to_uuid());
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .retain(|(tagged_id, _)| *tagged_id != id.to_uuid());
+        self.revisions.push(Revision::new(
+            EntityKind::MovieClip,
+            id.to_uuid(),
+            Operation::Purge,
+            &purged_clip,
+            actor,
+        )?)
+    }
+    async fn revisions(&self, id: MovieClipId) -> Result<Vec<Revision>, InfraError> {
+        self.revisions.of_entity(id.to_uuid())
+    }
+    async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, InfraError> {
+        self.revisions.latest(length)
+    }
+    async fn revision(&self, id: RevisionId) -> Result<Revision, InfraError> {
+        self.revisions.find(id)
     }
 }
 
@@ -169,14 +562,20 @@ mod test {
     use crate::movie_clip_repository_impl::assert_movie_clip::{
         clips_assert_eq, clips_assert_eq_with_sort_by_key_and_filter,
     };
+    use crate::movie_clip_repository_impl::movie_clip_repository_contract::movie_clip_repository_contract;
     use crate::InfraError;
     use domain::MovieClipRepository;
     use domain::{
+        client::ClientId,
         movie_clip::{MovieClip, MovieClipId},
-        Date,
+        revision::{Actor, Operation, RevisionId},
+        search::SearchQuery,
+        user::UserId,
+        Date, Tag,
     };
 
     use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
     use rand::seq::SliceRandom;
     use rand::{thread_rng, Rng};
     use rstest::{fixture, rstest};
@@ -198,7 +597,7 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         let mut clips_res = repo.all().await?;
@@ -217,7 +616,7 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         // 編集
@@ -226,7 +625,8 @@ mod test {
             let edited_clip = clips.choose_mut(&mut rand::thread_rng()).unwrap();
             edited_clip.assign(Faker.fake());
 
-            repo.edit(edited_clip.clone()).await?;
+            repo.edit(edited_clip.clone(), Actor::Admin).await?;
+            edited_clip.increment_version();
         }
 
         let mut clips_res = repo.all().await?;
@@ -244,14 +644,15 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         // clipsの一部をincrement_like
         for _ in 0..(clips.len() / 2) {
             let incremented_clip = clips.choose_mut(&mut rand::thread_rng()).unwrap();
             incremented_clip.increment_like();
-            repo.increment_like(incremented_clip.id()).await?;
+            repo.increment_like(incremented_clip.id(), ClientId::generate())
+                .await?;
         }
 
         let mut clips_res = repo.all().await?;
@@ -260,6 +661,56 @@ mod test {
         Ok(())
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_increment_like_and_unlike_per_client() -> Result<(), InfraError> {
+        let clip = Faker.fake::<MovieClip>();
+        let other_clip = Faker.fake::<MovieClip>();
+        let (client, other_client) = (ClientId::generate(), ClientId::generate());
+
+        let repo = InMemoryMovieClipRepository::new();
+        repo.save(clip.clone(), Actor::Admin).await?;
+        repo.save(other_clip.clone(), Actor::Admin).await?;
+
+        let like_of = |clips: Vec<MovieClip>| {
+            clips
+                .into_iter()
+                .find(|res_clip| res_clip.id() == clip.id())
+                .unwrap()
+                .like()
+        };
+
+        // 同じクライアントのLikeは一度だけ数える
+        repo.increment_like(clip.id(), client).await?;
+        repo.increment_like(clip.id(), client).await?;
+        assert_eq!(like_of(repo.all().await?), clip.like() + 1);
+
+        repo.increment_like(clip.id(), other_client).await?;
+        assert_eq!(like_of(repo.all().await?), clip.like() + 2);
+
+        let liked_ids = repo
+            .liked_ids(&[clip.id(), other_clip.id()], client)
+            .await?;
+        assert_eq!(liked_ids, vec![clip.id()]);
+
+        // unlikeもクライアントごとに一度だけ
+        repo.unlike(clip.id(), client).await?;
+        repo.unlike(clip.id(), client).await?;
+        assert_eq!(like_of(repo.all().await?), clip.like() + 1);
+
+        let liked_ids = repo
+            .liked_ids(&[clip.id(), other_clip.id()], client)
+            .await?;
+        assert_eq!(liked_ids, Vec::<MovieClipId>::new());
+
+        // ゴミ箱のMovieClipは台帳から除かれる
+        repo.remove(clip.id(), Actor::Admin).await?;
+        let liked_ids = repo.liked_ids(&[clip.id()], other_client).await?;
+        assert_eq!(liked_ids, Vec::<MovieClipId>::new());
+
+        Ok(())
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_movie_clip_save_and_order_by_like(
@@ -270,7 +721,7 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         let length = clips.len() / 2;
@@ -299,7 +750,7 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         let length = clips.len() / 2;
@@ -310,7 +761,9 @@ mod test {
             clips[reference_index].clone()
         };
 
-        let mut clips_res = repo.order_by_like_later(&reference, length).await?;
+        let mut clips_res = repo
+            .order_by_like_later(&reference.like_cursor(), length)
+            .await?;
 
         // 参照元をlike(降順), idの順でソート・フィルタリングして比較
         clips_assert_eq_with_sort_by_key_and_filter(
@@ -337,7 +790,7 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         let start = Faker.fake::<Date>();
@@ -367,7 +820,7 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         let length = clips.len() / 2;
@@ -396,7 +849,7 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         let length = clips.len() / 2;
@@ -407,7 +860,9 @@ mod test {
             clips[reference_index].clone()
         };
 
-        let mut clips_res = repo.order_by_create_date_later(&reference, length).await?;
+        let mut clips_res = repo
+            .order_by_create_date_later(&reference.create_date_cursor(), length)
+            .await?;
 
         // 参照元をcreate_date(降順)でソート・範囲をフィルタリング
         clips_assert_eq_with_sort_by_key_and_filter(
@@ -434,7 +889,7 @@ mod test {
         let repo = InMemoryMovieClipRepository::new();
 
         for clip in clips.iter().cloned() {
-            repo.save(clip).await?;
+            repo.save(clip, Actor::Admin).await?;
         }
 
         // clipsの一部を削除
@@ -455,7 +910,7 @@ mod test {
             .collect::<Vec<_>>();
 
         for clip in removed_clips.into_iter() {
-            repo.remove(clip.id()).await?
+            repo.remove(clip.id(), Actor::Admin).await?
         }
 
         let mut clips_res = repo.all().await?;
@@ -471,7 +926,156 @@ mod test {
 
         let clip = Faker.fake::<MovieClip>();
 
-        let res = repo.edit(clip).await;
+        let res = repo.edit(clip, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_edit_stale_version() -> Result<(), InfraError> {
+        let repo = InMemoryMovieClipRepository::new();
+
+        let mut clip = Faker.fake::<MovieClip>();
+        repo.save(clip.clone(), Actor::Admin).await?;
+
+        // 同じ版数からの編集は先の一つのみ成功する
+        let stale_clip = clip.clone();
+        clip.assign(Faker.fake());
+        repo.edit(clip.clone(), Actor::Admin).await?;
+
+        let res = repo.edit(stale_clip, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)));
+
+        // 版数が一つ増えている
+        clip.increment_version();
+        let res = repo.all().await?;
+        assert_eq!(res, vec![clip]);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_increment_like_and_unlike_no_exists() -> Result<(), InfraError> {
+        let repo = InMemoryMovieClipRepository::new();
+
+        let res = repo
+            .increment_like(MovieClipId::generate(), ClientId::generate())
+            .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo
+            .unlike(MovieClipId::generate(), ClientId::generate())
+            .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_add_tag_and_order_with_tags(
+        movie_clips: Result<Vec<MovieClip>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let clips = movie_clips?;
+
+        let repo = InMemoryMovieClipRepository::new();
+
+        for clip in clips.iter().cloned() {
+            repo.save(clip, Actor::Admin).await?;
+        }
+
+        let (singing, game) = (Tag::new("Singing")?, Tag::new("game")?);
+
+        // 前半にsinging，偶数番目にgameを付ける
+        for (i, clip) in clips.iter().enumerate() {
+            if i < clips.len() / 2 {
+                repo.add_tag(clip.id(), singing.clone()).await?;
+            }
+            if i % 2 == 0 {
+                repo.add_tag(clip.id(), game.clone()).await?;
+            }
+        }
+        // 同じタグは一度だけ付く
+        repo.add_tag(clips[0].id(), game.clone()).await?;
+
+        assert_eq!(
+            repo.tags(clips[0].id()).await?,
+            vec![game.clone(), singing.clone()]
+        );
+        assert_eq!(repo.all_tags().await?, vec![game.clone(), singing.clone()]);
+
+        let has_both = |clip: &MovieClip| {
+            let i = clips.iter().position(|c| c.id() == clip.id()).unwrap();
+            i < clips.len() / 2 && i % 2 == 0
+        };
+
+        let length = clips.len() / 2;
+        let mut clips_res = repo
+            .order_by_like_with_tags(&[singing.clone(), game.clone()], length)
+            .await?;
+
+        clips_assert_eq_with_sort_by_key_and_filter(
+            &mut clips_res,
+            &mut clips.clone(),
+            |x, y| y.like().cmp(&x.like()),
+            Some(has_both),
+            Some(length),
+        );
+
+        // referenceとなるclipを取得
+        let reference = clips[0].clone();
+        let mut clips_res = repo
+            .order_by_create_date_later_with_tags(
+                &[singing.clone(), game.clone()],
+                &reference.create_date_cursor(),
+                length,
+            )
+            .await?;
+
+        clips_assert_eq_with_sort_by_key_and_filter(
+            &mut clips_res,
+            &mut clips.clone(),
+            |x, y| y.create_date().cmp(&x.create_date()),
+            Some(|clip: &MovieClip| {
+                has_both(clip)
+                    && (clip.create_date() < reference.create_date()
+                        || (reference.create_date() == clip.create_date()
+                            && clip.id() > reference.id()))
+            }),
+            Some(length),
+        );
+
+        // タグを外す
+        repo.remove_tag(clips[0].id(), singing.clone()).await?;
+        assert_eq!(repo.tags(clips[0].id()).await?, vec![game.clone()]);
+
+        // ゴミ箱のMovieClipのタグは取得できない
+        repo.remove(clips[0].id(), Actor::Admin).await?;
+        let res = repo.tags(clips[0].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_tags_no_exists() -> Result<(), InfraError> {
+        let repo = InMemoryMovieClipRepository::new();
+
+        let res = repo
+            .add_tag(MovieClipId::generate(), Tag::new("game")?)
+            .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo
+            .remove_tag(MovieClipId::generate(), Tag::new("game")?)
+            .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.tags(MovieClipId::generate()).await;
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
         Ok(())
@@ -482,10 +1086,173 @@ mod test {
     async fn test_movie_clip_remove_no_exists() -> Result<(), InfraError> {
         let repo = InMemoryMovieClipRepository::new();
 
-        let res = repo.remove(MovieClipId::generate()).await;
+        let res = repo.remove(MovieClipId::generate(), Actor::Admin).await;
 
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
         Ok(())
     }
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_search(
+        movie_clips: Result<Vec<MovieClip>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut clips = movie_clips?;
+
+        // 先頭の10個のタイトルに検索する語を含める．後ろほどタイトルが長い
+        for (i, clip) in clips.iter_mut().take(10).enumerate() {
+            *clip.title_mut() = format!("理子の歌枠{}", "!".repeat(i));
+        }
+
+        let repo = InMemoryMovieClipRepository::new();
+        for clip in clips.iter().cloned() {
+            repo.save(clip, Actor::Admin).await?;
+        }
+
+        // 語を含むタイトルが短いほどスコアが高い
+        let query = SearchQuery::new("歌枠").unwrap();
+        let hits = repo.search(&query, 5).await?;
+        assert_eq!(
+            hits.into_iter().map(|hit| hit.item).collect::<Vec<_>>(),
+            clips[..5].to_vec()
+        );
+
+        // 全ての語を含むもののみ一致する
+        let query = SearchQuery::new("理子 雑談").unwrap();
+        assert!(repo.search(&query, 100).await?.is_empty());
+
+        Ok(())
+    }
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_revisions() -> Result<(), InfraError> {
+        let repo = InMemoryMovieClipRepository::new();
+
+        let mut clip = Faker.fake::<MovieClip>();
+        let user_id = UserId::generate();
+        repo.save(clip.clone(), Actor::User(user_id)).await?;
+
+        let saved_clip = clip.clone();
+        clip.assign(Faker.fake());
+        repo.edit(clip.clone(), Actor::Admin).await?;
+        clip.increment_version();
+
+        repo.remove(clip.id(), Actor::Anonymous).await?;
+
+        // 保存・編集・削除の順に残る
+        let revisions = repo.revisions(clip.id()).await?;
+        assert_eq!(
+            revisions
+                .iter()
+                .map(|revision| (revision.operation(), revision.actor()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Operation::Save, Actor::User(user_id)),
+                (Operation::Edit, Actor::Admin),
+                (Operation::Remove, Actor::Anonymous),
+            ]
+        );
+        assert_eq!(revisions[0].snapshot_as::<MovieClip>()?, saved_clip);
+        assert_eq!(revisions[1].snapshot_as::<MovieClip>()?, clip);
+        assert_eq!(revisions[2].snapshot_as::<MovieClip>()?, clip);
+
+        // 新しい順に取得できる
+        let latest = repo.latest_revisions(2).await?;
+        assert_eq!(latest, vec![revisions[2].clone(), revisions[1].clone()]);
+
+        assert_eq!(repo.revision(revisions[0].id()).await?, revisions[0]);
+        let res = repo.revision(RevisionId::generate()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_movie_clip_trash() -> Result<(), InfraError> {
+        let repo = InMemoryMovieClipRepository::new();
+
+        let mut clips = (0..3)
+            .map(|_| Faker.fake::<MovieClip>())
+            .collect::<Vec<_>>();
+        clips.sort_by_key(|clip| clip.id());
+        for clip in clips.iter().cloned() {
+            repo.save(clip, Actor::Admin).await?;
+        }
+        let tag = Tag::new("歌枠")?;
+        repo.add_tag(clips[0].id(), tag.clone()).await?;
+        let client = ClientId::generate();
+        repo.increment_like(clips[0].id(), client).await?;
+        clips[0].increment_like();
+
+        // ゴミ箱に移したものは通常の取得の対象とならない
+        repo.remove(clips[0].id(), Actor::Admin).await?;
+        repo.remove(clips[1].id(), Actor::Admin).await?;
+        assert_eq!(repo.all().await?, vec![clips[2].clone()]);
+        assert_eq!(repo.all_tags().await?, Vec::<Tag>::new());
+        let liked_ids = repo.liked_ids(&[clips[0].id()], client).await?;
+        assert_eq!(liked_ids, Vec::<MovieClipId>::new());
+
+        // 移した日時の新しい順
+        assert_eq!(
+            repo.trashed().await?,
+            vec![clips[1].clone(), clips[0].clone()]
+        );
+
+        // ゴミ箱のものは編集できず，同じidでは保存できない
+        let res = repo.edit(clips[0].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = repo.save(clips[0].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ゴミ箱から戻すとタグも戻る
+        repo.restore(clips[0].id(), Actor::Admin).await?;
+        let mut res = repo.all().await?;
+        res.sort_by_key(|clip| clip.id());
+        assert_eq!(res, vec![clips[0].clone(), clips[2].clone()]);
+        assert_eq!(repo.tags(clips[0].id()).await?, vec![tag]);
+        let liked_ids = repo.liked_ids(&[clips[0].id()], client).await?;
+        assert_eq!(liked_ids, vec![clips[0].id()]);
+
+        // ゴミ箱に無いものは戻せず，完全に削除もできない
+        let res = repo.restore(clips[2].id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = repo.purge(clips[2].id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // 完全に削除したものは戻せない
+        repo.purge(clips[1].id(), Actor::Admin).await?;
+        assert_eq!(repo.trashed().await?, Vec::<MovieClip>::new());
+        let res = repo.restore(clips[1].id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let operations = repo
+            .revisions(clips[1].id())
+            .await?
+            .iter()
+            .map(|revision| revision.operation())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operations,
+            vec![Operation::Save, Operation::Remove, Operation::Purge]
+        );
+        let operations = repo
+            .revisions(clips[0].id())
+            .await?
+            .iter()
+            .map(|revision| revision.operation())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operations,
+            vec![Operation::Save, Operation::Remove, Operation::Restore]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_movie_clip_repository_contract() -> Result<(), InfraError> {
+        let repo = InMemoryMovieClipRepository::new();
+        movie_clip_repository_contract(&repo).await
+    }
 }
@@ -1,8 +1,11 @@
 use crate::InfraError;
 use async_trait::async_trait;
+use domain::client::ClientId;
 use domain::movie_clip::{MovieClip, MovieClipId};
-use domain::Date;
+use domain::revision::{Actor, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
 use domain::MovieClipRepository;
+use domain::{Cursor, Date, Tag};
 
 use mockall::mock;
 
@@ -14,14 +17,27 @@ mock! {
     #[async_trait]
     impl MovieClipRepository for MovieClipRepository {
         type Error = InfraError;
-        async fn save(&self, movie_clip: MovieClip)
+        async fn save(&self, movie_clip: MovieClip, actor: Actor)
         -> Result<(), InfraError>;
-        async fn edit(&self, movie_clip: MovieClip)
+        async fn save_many(&self, movie_clips: Vec<MovieClip>, actor: Actor)
+            -> Result<(), InfraError>;
+        async fn edit(&self, movie_clip: MovieClip, actor: Actor)
             -> Result<(), InfraError>;
         async fn increment_like(
             &self,
             id: MovieClipId,
+            client: ClientId,
         ) -> Result<(), InfraError>;
+        async fn unlike(
+            &self,
+            id: MovieClipId,
+            client: ClientId,
+        ) -> Result<(), InfraError>;
+        async fn liked_ids(
+            &self,
+            ids: &[MovieClipId],
+            client: ClientId,
+        ) -> Result<Vec<MovieClipId>, InfraError>;
         async fn all(&self) -> Result<Vec<MovieClip>, InfraError>;
         async fn order_by_like(
             &self,
@@ -29,7 +45,7 @@ mock! {
         ) -> Result<Vec<MovieClip>, InfraError>;
         async fn order_by_like_later(
             &self,
-            reference: &MovieClip,
+            cursor: &Cursor<u32, MovieClipId>,
             length: usize,
         ) -> Result<Vec<MovieClip>, InfraError>;
         async fn order_by_create_date_range(
@@ -43,12 +59,75 @@ mock! {
         ) -> Result<Vec<MovieClip>, InfraError>;
         async fn order_by_create_date_later(
             &self,
-            reference: &MovieClip,
+            cursor: &Cursor<Date, MovieClipId>,
+            length: usize,
+        ) -> Result<Vec<MovieClip>, InfraError>;
+        async fn order_by_like_with_tags(
+            &self,
+            tags: &[Tag],
+            length: usize,
+        ) -> Result<Vec<MovieClip>, InfraError>;
+        async fn order_by_like_later_with_tags(
+            &self,
+            tags: &[Tag],
+            cursor: &Cursor<u32, MovieClipId>,
+            length: usize,
+        ) -> Result<Vec<MovieClip>, InfraError>;
+        async fn order_by_create_date_with_tags(
+            &self,
+            tags: &[Tag],
             length: usize,
         ) -> Result<Vec<MovieClip>, InfraError>;
+        async fn order_by_create_date_later_with_tags(
+            &self,
+            tags: &[Tag],
+            cursor: &Cursor<Date, MovieClipId>,
+            length: usize,
+        ) -> Result<Vec<MovieClip>, InfraError>;
+        async fn search(
+            &self,
+            query: &SearchQuery,
+            length: usize,
+        ) -> Result<Vec<SearchHit<MovieClip>>, InfraError>;
+        async fn all_tags(&self) -> Result<Vec<Tag>, InfraError>;
+        async fn tags(&self, id: MovieClipId) -> Result<Vec<Tag>, InfraError>;
+        async fn add_tag(
+            &self,
+            id: MovieClipId,
+            tag: Tag,
+        ) -> Result<(), InfraError>;
+        async fn remove_tag(
+            &self,
+            id: MovieClipId,
+            tag: Tag,
+        ) -> Result<(), InfraError>;
         async fn remove(
             &self,
             id: MovieClipId,
+            actor: Actor,
+        ) -> Result<(), InfraError>;
+        async fn trashed(&self) -> Result<Vec<MovieClip>, InfraError>;
+        async fn restore(
+            &self,
+            id: MovieClipId,
+            actor: Actor,
         ) -> Result<(), InfraError>;
+        async fn purge(
+            &self,
+            id: MovieClipId,
+            actor: Actor,
+        ) -> Result<(), InfraError>;
+        async fn revisions(
+            &self,
+            id: MovieClipId,
+        ) -> Result<Vec<Revision>, InfraError>;
+        async fn latest_revisions(
+            &self,
+            length: usize,
+        ) -> Result<Vec<Revision>, InfraError>;
+        async fn revision(
+            &self,
+            id: RevisionId,
+        ) -> Result<Revision, InfraError>;
     }
 }
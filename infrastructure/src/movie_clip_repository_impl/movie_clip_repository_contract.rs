@@ -0,0 +1,273 @@
+use crate::InfraError;
+use domain::client::ClientId;
+use domain::movie_clip::{MovieClip, MovieClipId};
+use domain::revision::Actor;
+use domain::MovieClipRepository;
+use domain::{Date, Tag};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use std::cmp::Ordering;
+
+/// likeの降順，同じ場合はidの昇順
+fn like_order(x: &MovieClip, y: &MovieClip) -> Ordering {
+    y.like().cmp(&x.like()).then_with(|| x.id().cmp(&y.id()))
+}
+
+/// create_dateの降順，同じ場合はidの昇順
+fn create_date_order(x: &MovieClip, y: &MovieClip) -> Ordering {
+    y.create_date()
+        .cmp(&x.create_date())
+        .then_with(|| x.id().cmp(&y.id()))
+}
+
+/// create_dateの昇順，同じ場合はidの昇順
+fn create_date_range_order(x: &MovieClip, y: &MovieClip) -> Ordering {
+    x.create_date()
+        .cmp(&y.create_date())
+        .then_with(|| x.id().cmp(&y.id()))
+}
+
+/// likeとcreate_dateが重複するものを含むフェイクのクリップ
+fn fake_movie_clips() -> Vec<MovieClip> {
+    let create_dates = (0..3).map(|_| Faker.fake::<Date>()).collect::<Vec<_>>();
+
+    let mut clips = (0..20)
+        .map(|_| Faker.fake::<MovieClip>())
+        .collect::<Vec<_>>();
+    clips.extend((0..20).map(|i| {
+        let fake_clip = Faker.fake::<MovieClip>();
+        let mut clip = MovieClip::new_with_domains(
+            fake_clip.title().to_string(),
+            fake_clip.url().clone(),
+            fake_clip.range().clone(),
+            create_dates[i % create_dates.len()],
+        );
+        for _ in 0..(i % 3) {
+            clip.increment_like();
+        }
+        clip
+    }));
+    clips
+}
+
+/// `clips`から`ids`をもつクリップのみを順番を保って取り出す．
+fn own_clips(clips: &[MovieClip], ids: &[MovieClipId]) -> Vec<MovieClip> {
+    clips
+        .iter()
+        .filter(|clip| ids.contains(&clip.id()))
+        .cloned()
+        .collect()
+}
+
+/// `length`ずつ`order_by_like_later`で辿ったクリップ
+async fn walk_order_by_like<R>(repo: &R, length: usize) -> Result<Vec<MovieClip>, InfraError>
+where
+    R: MovieClipRepository<Error = InfraError>,
+{
+    let mut clips = repo.order_by_like(length).await?;
+    let mut page_len = clips.len();
+    while page_len == length {
+        let cursor = clips.last().unwrap().like_cursor();
+        let page = repo.order_by_like_later(&cursor, length).await?;
+        page_len = page.len();
+        clips.extend(page);
+    }
+    Ok(clips)
+}
+
+/// `length`ずつ`order_by_create_date_later`で辿ったクリップ
+async fn walk_order_by_create_date<R>(repo: &R, length: usize) -> Result<Vec<MovieClip>, InfraError>
+where
+    R: MovieClipRepository<Error = InfraError>,
+{
+    let mut clips = repo.order_by_create_date(length).await?;
+    let mut page_len = clips.len();
+    while page_len == length {
+        let cursor = clips.last().unwrap().create_date_cursor();
+        let page = repo.order_by_create_date_later(&cursor, length).await?;
+        page_len = page.len();
+        clips.extend(page);
+    }
+    Ok(clips)
+}
+
+/// MovieClipRepositoryの実装が満たすべき性質を確かめる．
+/// 既存のクリップがあっても成り立つように保存したクリップのみを比較し，最後にそれらを完全に削除する．
+pub(crate) async fn movie_clip_repository_contract<R>(repo: &R) -> Result<(), InfraError>
+where
+    R: MovieClipRepository<Error = InfraError>,
+{
+    let mut clips = fake_movie_clips();
+    let ids = clips.iter().map(|clip| clip.id()).collect::<Vec<_>>();
+
+    // 半分は一つずつ，残りは一度に保存する
+    let (saved_one_by_one, saved_at_once) = clips.split_at(clips.len() / 2);
+    for clip in saved_one_by_one.iter().cloned() {
+        repo.save(clip, Actor::Admin).await?;
+    }
+    // 一つでも保存できないクリップがある場合は何も保存しない
+    {
+        let mut with_conflicted = saved_at_once.to_vec();
+        with_conflicted.push(saved_one_by_one[0].clone());
+        let res = repo.save_many(with_conflicted, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)), "{res:?}");
+        assert!(own_clips(&repo.all().await?, &ids[saved_one_by_one.len()..]).is_empty());
+    }
+    repo.save_many(saved_at_once.to_vec(), Actor::Admin).await?;
+
+    // 同じidのクリップは保存できず，保存済みのものは変わらない
+    {
+        let mut conflicted = clips[0].clone();
+        conflicted.assign(Faker.fake());
+        let res = repo.save(conflicted, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)), "{res:?}");
+    }
+
+    // Likeはクライアントごとに一回のみ
+    let (client, other_client) = (ClientId::generate(), ClientId::generate());
+    {
+        repo.increment_like(ids[0], client).await?;
+        repo.increment_like(ids[0], client).await?;
+        clips[0].increment_like();
+
+        repo.unlike(ids[1], other_client).await?;
+        repo.increment_like(ids[1], client).await?;
+        repo.unlike(ids[1], client).await?;
+
+        let liked_ids = repo.liked_ids(&ids, client).await?;
+        assert_eq!(liked_ids, vec![ids[0]]);
+    }
+
+    // 古い版数では編集できない
+    {
+        let mut edited = clips[2].clone();
+        edited.assign(Faker.fake());
+        repo.edit(edited.clone(), Actor::Admin).await?;
+
+        let res = repo.edit(clips[2].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)), "{res:?}");
+
+        edited.increment_version();
+        clips[2] = edited;
+    }
+
+    {
+        let mut res = own_clips(&repo.all().await?, &ids);
+        res.sort_by_key(|clip| clip.id());
+        let mut expected = clips.clone();
+        expected.sort_by_key(|clip| clip.id());
+        assert_eq!(res, expected);
+    }
+
+    // likeで並べたときはidで順番が一意に決まり，ページをつなげても変わらない
+    {
+        let total = repo.all().await?.len();
+        let ordered = repo.order_by_like(total).await?;
+        assert_eq!(ordered.len(), total);
+        assert!(ordered
+            .windows(2)
+            .all(|pair| like_order(&pair[0], &pair[1]) == Ordering::Less));
+
+        let mut expected = clips.clone();
+        expected.sort_by(like_order);
+        assert_eq!(own_clips(&ordered, &ids), expected);
+
+        for length in [3, 7] {
+            assert_eq!(walk_order_by_like(repo, length).await?, ordered);
+        }
+    }
+
+    // create_dateで並べたときも同様
+    {
+        let total = repo.all().await?.len();
+        let ordered = repo.order_by_create_date(total).await?;
+        assert_eq!(ordered.len(), total);
+        assert!(ordered
+            .windows(2)
+            .all(|pair| create_date_order(&pair[0], &pair[1]) == Ordering::Less));
+
+        let mut expected = clips.clone();
+        expected.sort_by(create_date_order);
+        assert_eq!(own_clips(&ordered, &ids), expected);
+
+        for length in [3, 7] {
+            assert_eq!(walk_order_by_create_date(repo, length).await?, ordered);
+        }
+    }
+
+    // 範囲の取得はstartを含みendを含まない
+    {
+        let start = clips.iter().map(|clip| clip.create_date()).min().unwrap();
+        let end = clips.iter().map(|clip| clip.create_date()).max().unwrap();
+        let ranged = repo.order_by_create_date_range(start, end).await?;
+        assert!(ranged
+            .iter()
+            .all(|clip| start <= clip.create_date() && clip.create_date() < end));
+        assert!(ranged
+            .windows(2)
+            .all(|pair| create_date_range_order(&pair[0], &pair[1]) == Ordering::Less));
+
+        let mut expected = clips
+            .iter()
+            .filter(|clip| start <= clip.create_date() && clip.create_date() < end)
+            .cloned()
+            .collect::<Vec<_>>();
+        expected.sort_by(create_date_range_order);
+        assert_eq!(own_clips(&ranged, &ids), expected);
+    }
+
+    // 存在しないクリップに対する操作
+    {
+        let no_exists = Faker.fake::<MovieClip>();
+        let tag = Faker.fake::<Tag>();
+
+        let res = repo.edit(no_exists.clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.increment_like(no_exists.id(), client).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.unlike(no_exists.id(), client).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.tags(no_exists.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.add_tag(no_exists.id(), tag).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.remove(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.restore(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.purge(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+    }
+
+    // ゴミ箱のクリップは取得できず，同じidで保存もできない
+    {
+        repo.remove(ids[3], Actor::Admin).await?;
+
+        assert!(own_clips(&repo.all().await?, &ids[3..4]).is_empty());
+        assert_eq!(
+            own_clips(&repo.trashed().await?, &ids),
+            vec![clips[3].clone()]
+        );
+        let res = repo.increment_like(ids[3], client).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.save(clips[3].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)), "{res:?}");
+
+        repo.restore(ids[3], Actor::Admin).await?;
+        assert_eq!(
+            own_clips(&repo.all().await?, &ids[3..4]),
+            vec![clips[3].clone()]
+        );
+    }
+
+    // 後片付け
+    for id in ids.iter().copied() {
+        repo.remove(id, Actor::Admin).await?;
+        repo.purge(id, Actor::Admin).await?;
+    }
+    assert!(own_clips(&repo.all().await?, &ids).is_empty());
+    assert!(own_clips(&repo.trashed().await?, &ids).is_empty());
+
+    Ok(())
+}
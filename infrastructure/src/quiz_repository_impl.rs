@@ -0,0 +1,21 @@
+mod db_quiz_repository;
+mod inmemory_quiz_repository;
+mod mock_quiz_repository;
+
+pub use db_quiz_repository::QuizPgDBRepository;
+pub use inmemory_quiz_repository::InMemoryQuizRepository;
+pub use mock_quiz_repository::MockQuizRepository;
+
+#[cfg(test)]
+pub(crate) mod quiz_assert {
+    use domain::quiz::Quiz;
+    use pretty_assertions::assert_eq;
+
+    /// idで昇順にソートして比較
+    pub(crate) fn quizzes_assert_eq(actual: &mut Vec<Quiz>, expected: &mut Vec<Quiz>) {
+        actual.sort_by_key(|quiz| quiz.id());
+        expected.sort_by_key(|quiz| quiz.id());
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::quiz::{Quiz, QuizId};
+use domain::QuizRepository;
+use sqlx::PgPool;
+
+// -------------------------------------------------------------------------------------------------
+// # quiz_sql_runner
+
+mod quiz_sql_runner {
+    use crate::InfraError;
+    use domain::quiz::{Quiz, QuizId};
+    use sqlx::postgres::{PgConnection, Postgres};
+
+    pub async fn save(conn: &mut PgConnection, quiz: Quiz) -> Result<(), InfraError> {
+        sqlx::query(r#"INSERT INTO quizzes (question, answers, id) VALUES ($1, $2, $3)"#)
+            .bind(quiz.question())
+            .bind(quiz.answers())
+            .bind(quiz.id().to_uuid())
+            .execute(conn)
+            .await
+            .map_err(|_| InfraError::ConflictError)?;
+        Ok(())
+    }
+    pub async fn edit(conn: &mut PgConnection, quiz: Quiz) -> Result<(), InfraError> {
+        sqlx::query(r#"UPDATE quizzes SET question = $1, answers = $2 WHERE id = $3 RETURNING *"#)
+            .bind(quiz.question())
+            .bind(quiz.answers())
+            .bind(quiz.id().to_uuid())
+            .fetch_one(conn)
+            .await
+            .map_err(|_| InfraError::NoRecordError)?;
+        Ok(())
+    }
+    pub async fn all(conn: &mut PgConnection) -> Result<Vec<Quiz>, InfraError> {
+        let quizzes = sqlx::query_as::<Postgres, Quiz>(r#"SELECT * FROM quizzes"#)
+            .fetch_all(conn)
+            .await?;
+        Ok(quizzes)
+    }
+    pub async fn find_by_id(conn: &mut PgConnection, id: QuizId) -> Result<Quiz, InfraError> {
+        let quiz = sqlx::query_as::<Postgres, Quiz>(r#"SELECT * FROM quizzes WHERE id = $1"#)
+            .bind(id.to_uuid())
+            .fetch_optional(conn)
+            .await?;
+        quiz.ok_or(InfraError::NoRecordError)
+    }
+    pub async fn random(conn: &mut PgConnection) -> Result<Quiz, InfraError> {
+        let quiz =
+            sqlx::query_as::<Postgres, Quiz>(r#"SELECT * FROM quizzes ORDER BY random() LIMIT 1"#)
+                .fetch_optional(conn)
+                .await?;
+        quiz.ok_or(InfraError::NoRecordError)
+    }
+    pub async fn remove(conn: &mut PgConnection, id: QuizId) -> Result<(), InfraError> {
+        sqlx::query(r#"DELETE FROM quizzes WHERE id = $1 RETURNING *"#)
+            .bind(id.to_uuid())
+            .fetch_one(conn)
+            .await
+            .map_err(|_| InfraError::NoRecordError)?;
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// #QuizPgDBRepository
+
+/// QuizのPostgresqlリポジトリ
+#[derive(Debug, Clone)]
+pub struct QuizPgDBRepository {
+    pool: PgPool,
+}
+
+impl QuizPgDBRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QuizRepository for QuizPgDBRepository {
+    type Error = InfraError;
+    async fn save(&self, quiz: Quiz) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        quiz_sql_runner::save(&mut conn, quiz).await?;
+        Ok(())
+    }
+    async fn edit(&self, quiz: Quiz) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        quiz_sql_runner::edit(&mut conn, quiz).await?;
+        Ok(())
+    }
+    async fn all(&self) -> Result<Vec<Quiz>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let quizzes = quiz_sql_runner::all(&mut conn).await?;
+        Ok(quizzes)
+    }
+    async fn find_by_id(&self, id: QuizId) -> Result<Quiz, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let quiz = quiz_sql_runner::find_by_id(&mut conn, id).await?;
+        Ok(quiz)
+    }
+    async fn random(&self) -> Result<Quiz, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let quiz = quiz_sql_runner::random(&mut conn).await?;
+        Ok(quiz)
+    }
+    async fn remove(&self, id: QuizId) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        quiz_sql_runner::remove(&mut conn, id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::quiz_sql_runner;
+    use crate::quiz_repository_impl::quiz_assert::quizzes_assert_eq;
+    use crate::InfraError;
+    use domain::quiz::Quiz;
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rand::seq::SliceRandom;
+    use rstest::{fixture, rstest};
+    use sqlx::postgres::{PgPool, PgPoolOptions};
+    use std::time::Duration;
+
+    #[fixture]
+    fn quizzes() -> Vec<Quiz> {
+        (0..100).map(|_| Faker.fake::<Quiz>()).collect::<Vec<_>>()
+    }
+
+    #[fixture]
+    async fn pool() -> Result<PgPool, InfraError> {
+        let database_url = std::env::var("DATABASE_URL").unwrap();
+        let pool = PgPoolOptions::new()
+            .idle_timeout(Duration::from_secs(1))
+            .connect(&database_url)
+            .await?;
+        Ok(pool)
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_save_and_edit_and_all(
+        quizzes: Vec<Quiz>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut quizzes = quizzes;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        // 既存のQuizを削除
+        sqlx::query("DELETE FROM quizzes")
+            .execute(&mut transaction)
+            .await?;
+
+        // データベースへ保存
+        for quiz in quizzes.iter().cloned() {
+            quiz_sql_runner::save(&mut transaction, quiz).await?;
+        }
+
+        // quizzesの一部を編集
+        for _ in 0..(quizzes.len() / 2_usize) {
+            let edited_quiz = quizzes.choose_mut(&mut rand::thread_rng()).unwrap();
+            edited_quiz.assign(Faker.fake::<Quiz>());
+
+            quiz_sql_runner::edit(&mut transaction, edited_quiz.clone()).await?;
+        }
+
+        let mut quizzes_res = quiz_sql_runner::all(&mut transaction).await?;
+        quizzes_assert_eq(&mut quizzes_res, &mut quizzes);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_find_by_id_and_random(
+        quizzes: Vec<Quiz>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        // 既存のQuizを削除
+        sqlx::query("DELETE FROM quizzes")
+            .execute(&mut transaction)
+            .await?;
+
+        // Quizが無い場合
+        let res = quiz_sql_runner::random(&mut transaction).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        for quiz in quizzes.iter().cloned() {
+            quiz_sql_runner::save(&mut transaction, quiz).await?;
+        }
+
+        for quiz in quizzes.iter() {
+            let quiz_res = quiz_sql_runner::find_by_id(&mut transaction, quiz.id()).await?;
+            assert_eq!(&quiz_res, quiz);
+        }
+
+        let random_quiz = quiz_sql_runner::random(&mut transaction).await?;
+        assert!(quizzes.contains(&random_quiz));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_save_and_remove(
+        quizzes: Vec<Quiz>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        // 既存のQuizを削除
+        sqlx::query("DELETE FROM quizzes")
+            .execute(&mut transaction)
+            .await?;
+
+        for quiz in quizzes.iter().cloned() {
+            quiz_sql_runner::save(&mut transaction, quiz).await?;
+        }
+
+        // quizzesの一部を削除
+        let (removed_quizzes, rest_quizzes) = quizzes.split_at(20);
+        let mut rest_quizzes = rest_quizzes.to_vec();
+
+        for quiz in removed_quizzes.iter() {
+            quiz_sql_runner::remove(&mut transaction, quiz.id()).await?;
+        }
+
+        let mut quizzes_res = quiz_sql_runner::all(&mut transaction).await?;
+        quizzes_assert_eq(&mut quizzes_res, &mut rest_quizzes);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_edit_and_find_and_remove_no_exists(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let quiz = Faker.fake::<Quiz>();
+
+        let res = quiz_sql_runner::edit(&mut transaction, quiz.clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = quiz_sql_runner::find_by_id(&mut transaction, quiz.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = quiz_sql_runner::remove(&mut transaction, quiz.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+}
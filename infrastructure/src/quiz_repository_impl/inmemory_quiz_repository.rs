@@ -0,0 +1,219 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::quiz::{Quiz, QuizId};
+use domain::QuizRepository;
+
+use rand::seq::IteratorRandom;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+// -------------------------------------------------------------------------------------------------
+// # InMemoryQuizRepository
+
+/// 即席のQuizリポジトリ
+#[derive(Default, Debug, Clone)]
+pub struct InMemoryQuizRepository {
+    map: Arc<Mutex<HashMap<Uuid, Quiz>>>,
+}
+
+impl InMemoryQuizRepository {
+    pub fn new() -> Self {
+        Self {
+            map: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl QuizRepository for InMemoryQuizRepository {
+    type Error = InfraError;
+    async fn save(&self, quiz: Quiz) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .entry(quiz.id().to_uuid())
+        {
+            Entry::Occupied(_) => Err(InfraError::ConflictError),
+            Entry::Vacant(v) => {
+                v.insert(quiz);
+                Ok(())
+            }
+        }
+    }
+    async fn edit(&self, quiz: Quiz) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .entry(quiz.id().to_uuid())
+        {
+            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Occupied(mut o) => {
+                *o.get_mut() = quiz;
+                Ok(())
+            }
+        }
+    }
+    async fn all(&self) -> Result<Vec<Quiz>, InfraError> {
+        let quizzes = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        Ok(quizzes)
+    }
+    async fn find_by_id(&self, id: QuizId) -> Result<Quiz, InfraError> {
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .get(&id.to_uuid())
+            .cloned()
+            .ok_or(InfraError::NoRecordError)
+    }
+    async fn random(&self) -> Result<Quiz, InfraError> {
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .values()
+            .choose(&mut rand::thread_rng())
+            .cloned()
+            .ok_or(InfraError::NoRecordError)
+    }
+    async fn remove(&self, id: QuizId) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .remove(&id.to_uuid())
+        {
+            None => Err(InfraError::NoRecordError),
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InMemoryQuizRepository;
+    use crate::quiz_repository_impl::quiz_assert::quizzes_assert_eq;
+    use crate::InfraError;
+    use domain::{quiz::Quiz, QuizRepository};
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rand::seq::SliceRandom;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn quizzes() -> Vec<Quiz> {
+        (0..100).map(|_| Faker.fake::<Quiz>()).collect::<Vec<_>>()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_save_and_all(quizzes: Vec<Quiz>) -> Result<(), InfraError> {
+        let mut quizzes = quizzes;
+
+        let repo = InMemoryQuizRepository::new();
+        for quiz in quizzes.iter().cloned() {
+            repo.save(quiz).await?;
+        }
+
+        let mut quizzes_res = repo.all().await?;
+
+        quizzes_assert_eq(&mut quizzes_res, &mut quizzes);
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_save_and_edit_and_all(quizzes: Vec<Quiz>) -> Result<(), InfraError> {
+        let mut quizzes = quizzes;
+
+        let repo = InMemoryQuizRepository::new();
+        for quiz in quizzes.iter().cloned() {
+            repo.save(quiz).await?;
+        }
+        // quizzesの一部を編集
+        for _ in 0..(quizzes.len() / 2_usize) {
+            let edited_quiz = quizzes.choose_mut(&mut rand::thread_rng()).unwrap();
+            edited_quiz.assign(Faker.fake::<Quiz>());
+
+            repo.edit(edited_quiz.clone()).await?;
+        }
+
+        let mut quizzes_res = repo.all().await?;
+
+        quizzes_assert_eq(&mut quizzes_res, &mut quizzes);
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_find_by_id_and_random(quizzes: Vec<Quiz>) -> Result<(), InfraError> {
+        let repo = InMemoryQuizRepository::new();
+
+        // Quizが無い場合
+        let res = repo.random().await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        for quiz in quizzes.iter().cloned() {
+            repo.save(quiz).await?;
+        }
+
+        for quiz in quizzes.iter() {
+            assert_eq!(&repo.find_by_id(quiz.id()).await?, quiz);
+        }
+
+        let random_quiz = repo.random().await?;
+        assert!(quizzes.contains(&random_quiz));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_save_and_remove(quizzes: Vec<Quiz>) -> Result<(), InfraError> {
+        let repo = InMemoryQuizRepository::new();
+        for quiz in quizzes.iter().cloned() {
+            repo.save(quiz).await?;
+        }
+
+        // quizzesの一部を削除
+        let (removed_quizzes, rest_quizzes) = quizzes.split_at(20);
+        let mut rest_quizzes = rest_quizzes.to_vec();
+
+        for quiz in removed_quizzes.iter() {
+            repo.remove(quiz.id()).await?;
+        }
+
+        let mut quizzes_res = repo.all().await?;
+        quizzes_assert_eq(&mut quizzes_res, &mut rest_quizzes);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_quiz_edit_and_find_and_remove_no_exists() -> Result<(), InfraError> {
+        let repo = InMemoryQuizRepository::new();
+
+        let quiz = Faker.fake::<Quiz>();
+
+        let res = repo.edit(quiz.clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.find_by_id(quiz.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.remove(quiz.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::quiz::{Quiz, QuizId};
+use domain::QuizRepository;
+
+use mockall::mock;
+
+mock! {
+    /// QuizRepositoryトレイトのモック
+    #[derive(Debug, Clone)]
+    pub QuizRepository {}
+
+    #[async_trait]
+    impl QuizRepository for QuizRepository {
+        type Error = InfraError;
+        async fn save(&self, quiz: Quiz) -> Result<(), <Self as QuizRepository>::Error>;
+        async fn edit(&self, quiz: Quiz) -> Result<(), <Self as QuizRepository>::Error>;
+        async fn all(&self) -> Result<Vec<Quiz>, <Self as QuizRepository>::Error>;
+        async fn find_by_id(&self, id: QuizId) -> Result<Quiz, <Self as QuizRepository>::Error>;
+        async fn random(&self) -> Result<Quiz, <Self as QuizRepository>::Error>;
+        async fn remove(&self, id: QuizId) -> Result<(), <Self as QuizRepository>::Error>;
+    }
+}
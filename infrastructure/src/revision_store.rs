@@ -0,0 +1,278 @@
+use crate::InfraError;
+use domain::revision::{Revision, RevisionId};
+
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+// -------------------------------------------------------------------------------------------------
+// revision_sql_runner
+
+/// 編集履歴に関するSQLのランナーモジュール．全てのエンティティの編集履歴を一つのテーブルに保存する．
+pub(crate) mod revision_sql_runner {
+    use crate::InfraError;
+    use domain::revision::{EntityKind, Revision, RevisionId};
+    use sqlx::{PgConnection, Postgres};
+    use uuid::Uuid;
+
+    /// 編集履歴を一つ保存
+    pub async fn save(conn: &mut PgConnection, revision: &Revision) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"
+INSERT INTO revisions (id, entity_kind, entity_id, operation, snapshot, actor_kind, actor_user_id, created_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(revision.id().to_uuid())
+        .bind(revision.entity_kind().to_string())
+        .bind(revision.entity_id())
+        .bind(revision.operation().to_string())
+        .bind(revision.snapshot().to_string())
+        .bind(revision.actor().kind())
+        .bind(revision.actor().user_id().map(|user_id| user_id.to_uuid()))
+        .bind(revision.created_at().to_chrono()?)
+        .execute(conn)
+        .await?;
+        Ok(())
+    }
+
+    /// `entity_id`をもつエンティティの編集履歴を古い順に取得
+    pub async fn of_entity(
+        conn: &mut PgConnection,
+        entity_kind: EntityKind,
+        entity_id: Uuid,
+    ) -> Result<Vec<Revision>, InfraError> {
+        let revisions = sqlx::query_as::<Postgres, Revision>(
+            r#"
+SELECT * FROM revisions WHERE entity_kind = $1 AND entity_id = $2 ORDER BY seq
+            "#,
+        )
+        .bind(entity_kind.to_string())
+        .bind(entity_id)
+        .fetch_all(conn)
+        .await?;
+        Ok(revisions)
+    }
+
+    /// `entity_kind`の編集履歴を新しい順にlength分取得
+    pub async fn latest(
+        conn: &mut PgConnection,
+        entity_kind: EntityKind,
+        length: usize,
+    ) -> Result<Vec<Revision>, InfraError> {
+        let revisions = sqlx::query_as::<Postgres, Revision>(
+            r#"
+SELECT * FROM revisions WHERE entity_kind = $1 ORDER BY seq DESC LIMIT $2
+            "#,
+        )
+        .bind(entity_kind.to_string())
+        .bind(length as i64)
+        .fetch_all(conn)
+        .await?;
+        Ok(revisions)
+    }
+
+    /// `id`をもつ`entity_kind`の編集履歴を取得．存在しない場合は`NoRecordError`
+    pub async fn find(
+        conn: &mut PgConnection,
+        entity_kind: EntityKind,
+        id: RevisionId,
+    ) -> Result<Revision, InfraError> {
+        sqlx::query_as::<Postgres, Revision>(
+            r#"
+SELECT * FROM revisions WHERE entity_kind = $1 AND id = $2
+            "#,
+        )
+        .bind(entity_kind.to_string())
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// InMemoryRevisions
+
+/// 即席のリポジトリの編集履歴．古い順に保持する．
+#[derive(Default, Debug, Clone)]
+pub(crate) struct InMemoryRevisions {
+    revisions: Arc<Mutex<Vec<Revision>>>,
+}
+
+impl InMemoryRevisions {
+    /// 編集履歴を追加する．
+    pub fn push(&self, revision: Revision) -> Result<(), InfraError> {
+        self.revisions
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .push(revision);
+        Ok(())
+    }
+    /// `entity_id`をもつエンティティの編集履歴を古い順に取得する．
+    pub fn of_entity(&self, entity_id: Uuid) -> Result<Vec<Revision>, InfraError> {
+        Ok(self
+            .revisions
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .filter(|revision| revision.entity_id() == entity_id)
+            .cloned()
+            .collect())
+    }
+    /// 編集履歴を新しい順にlength分取得する．
+    pub fn latest(&self, length: usize) -> Result<Vec<Revision>, InfraError> {
+        Ok(self
+            .revisions
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .rev()
+            .take(length)
+            .cloned()
+            .collect())
+    }
+    /// `id`をもつ編集履歴を取得する．存在しない場合は`NoRecordError`
+    pub fn find(&self, id: RevisionId) -> Result<Revision, InfraError> {
+        self.revisions
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .find(|revision| revision.id() == id)
+            .cloned()
+            .ok_or(InfraError::NoRecordError)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{revision_sql_runner, InMemoryRevisions};
+    use crate::InfraError;
+    use domain::episode::Episode;
+    use domain::movie_clip::MovieClip;
+    use domain::revision::{Actor, EntityKind, Operation, Revision, RevisionId};
+    use domain::user::UserId;
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use sqlx::postgres::{PgPool, PgPoolOptions};
+    use std::time::Duration;
+
+    #[fixture]
+    async fn pool() -> Result<PgPool, InfraError> {
+        let database_url = std::env::var("DATABASE_URL").unwrap();
+        let pool = PgPoolOptions::new()
+            .idle_timeout(Duration::from_secs(1))
+            .connect(&database_url)
+            .await?;
+        Ok(pool)
+    }
+
+    /// 一つのMovieClipの保存・編集・削除とEpisodeの保存の編集履歴
+    fn revisions() -> Result<(Vec<Revision>, Revision), InfraError> {
+        let mut clip = Faker.fake::<MovieClip>();
+        let save = Revision::new(
+            EntityKind::MovieClip,
+            clip.id().to_uuid(),
+            Operation::Save,
+            &clip,
+            Actor::User(UserId::generate()),
+        )?;
+        clip.assign(Faker.fake());
+        clip.increment_version();
+        let edit = Revision::new(
+            EntityKind::MovieClip,
+            clip.id().to_uuid(),
+            Operation::Edit,
+            &clip,
+            Actor::Anonymous,
+        )?;
+        let remove = Revision::new(
+            EntityKind::MovieClip,
+            clip.id().to_uuid(),
+            Operation::Remove,
+            &clip,
+            Actor::Admin,
+        )?;
+
+        let episode = Faker.fake::<Episode>();
+        let other = Revision::new(
+            EntityKind::Episode,
+            episode.id().to_uuid(),
+            Operation::Save,
+            &episode,
+            Actor::Admin,
+        )?;
+        Ok((vec![save, edit, remove], other))
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_revision_save_and_find(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let (revisions, other) = revisions()?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for revision in revisions.iter() {
+            revision_sql_runner::save(&mut transaction, revision).await?;
+        }
+        revision_sql_runner::save(&mut transaction, &other).await?;
+
+        // エンティティごとに古い順に取得できる
+        let entity_id = revisions[0].entity_id();
+        let res =
+            revision_sql_runner::of_entity(&mut transaction, EntityKind::MovieClip, entity_id)
+                .await?;
+        assert_eq!(res, revisions);
+
+        // 種類ごとに新しい順に取得できる
+        let res = revision_sql_runner::latest(&mut transaction, EntityKind::MovieClip, 2).await?;
+        assert_eq!(res, vec![revisions[2].clone(), revisions[1].clone()]);
+        let res = revision_sql_runner::latest(&mut transaction, EntityKind::Episode, 1).await?;
+        assert_eq!(res, vec![other.clone()]);
+
+        let res =
+            revision_sql_runner::find(&mut transaction, EntityKind::MovieClip, revisions[1].id())
+                .await?;
+        assert_eq!(res, revisions[1]);
+
+        // 種類が異なる場合は存在しない
+        let res =
+            revision_sql_runner::find(&mut transaction, EntityKind::Episode, revisions[1].id())
+                .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_inmemory_revisions() -> Result<(), InfraError> {
+        let (revisions, other) = revisions()?;
+        let inmemory_revisions = InMemoryRevisions::default();
+
+        for revision in revisions.iter().cloned() {
+            inmemory_revisions.push(revision)?;
+        }
+        inmemory_revisions.push(other.clone())?;
+
+        let res = inmemory_revisions.of_entity(revisions[0].entity_id())?;
+        assert_eq!(res, revisions);
+
+        let res = inmemory_revisions.latest(2)?;
+        assert_eq!(res, vec![other, revisions[2].clone()]);
+
+        assert_eq!(inmemory_revisions.find(revisions[1].id())?, revisions[1]);
+        let res = inmemory_revisions.find(RevisionId::generate());
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use domain::search::SearchQuery;
+
+/// `query`の語ごとの部分一致のLIKEのパターン．`\`，`%`，`_`はエスケープする．
+pub(crate) fn like_patterns(query: &SearchQuery) -> Vec<String> {
+    query
+        .terms()
+        .map(|term| {
+            let escaped = term
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            format!("%{escaped}%")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::like_patterns;
+    use domain::search::SearchQuery;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_like_patterns() {
+        let query = SearchQuery::new("理子 100%_歌枠").unwrap();
+        assert_eq!(
+            like_patterns(&query),
+            vec!["%理子%".to_string(), "%100\\%\\_歌枠%".to_string()]
+        );
+    }
+}
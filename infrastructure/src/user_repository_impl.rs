@@ -0,0 +1,21 @@
+mod db_user_repository;
+mod inmemory_user_repository;
+mod mock_user_repository;
+
+pub use db_user_repository::UserPgDBRepository;
+pub use inmemory_user_repository::InMemoryUserRepository;
+pub use mock_user_repository::MockUserRepository;
+
+#[cfg(test)]
+pub(crate) mod user_assert {
+    use domain::user::User;
+    use pretty_assertions::assert_eq;
+
+    /// idで昇順にソートして比較
+    pub(crate) fn users_assert_eq(actual: &mut Vec<User>, expected: &mut Vec<User>) {
+        actual.sort_by_key(|user| user.id());
+        expected.sort_by_key(|user| user.id());
+
+        assert_eq!(actual, expected);
+    }
+}
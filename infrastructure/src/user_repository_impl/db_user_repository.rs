@@ -0,0 +1,366 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::user::{User, UserId};
+use domain::UserRepository;
+use sqlx::PgPool;
+
+// -------------------------------------------------------------------------------------------------
+// # user_sql_runner
+
+mod user_sql_runner {
+    use crate::InfraError;
+    use domain::user::{User, UserId};
+    use sqlx::postgres::{PgConnection, Postgres};
+
+    pub async fn save(conn: &mut PgConnection, user: User) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"
+INSERT INTO users ("name", password_hash, "role", id) VALUES ($1, $2, $3, $4)
+        "#,
+        )
+        .bind(user.name())
+        .bind(user.password_hash())
+        .bind(user.role().to_string())
+        .bind(user.id().to_uuid())
+        .execute(conn)
+        .await
+        .map_err(|_| InfraError::ConflictError)?;
+        Ok(())
+    }
+    pub async fn edit(conn: &mut PgConnection, user: User) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"
+UPDATE users SET "name" = $1, password_hash = $2, "role" = $3 WHERE id = $4 RETURNING *
+        "#,
+        )
+        .bind(user.name())
+        .bind(user.password_hash())
+        .bind(user.role().to_string())
+        .bind(user.id().to_uuid())
+        .fetch_one(conn)
+        .await
+        .map_err(|_| InfraError::NoRecordError)?;
+        Ok(())
+    }
+    pub async fn all(conn: &mut PgConnection) -> Result<Vec<User>, InfraError> {
+        let users = sqlx::query_as::<Postgres, User>(r#"SELECT * FROM users"#)
+            .fetch_all(conn)
+            .await?;
+        Ok(users)
+    }
+    pub async fn find_by_name(
+        conn: &mut PgConnection,
+        name: &str,
+    ) -> Result<Option<User>, InfraError> {
+        let user = sqlx::query_as::<Postgres, User>(r#"SELECT * FROM users WHERE "name" = $1"#)
+            .bind(name)
+            .fetch_optional(conn)
+            .await?;
+        Ok(user)
+    }
+    pub async fn find_by_id(
+        conn: &mut PgConnection,
+        id: UserId,
+    ) -> Result<Option<User>, InfraError> {
+        let user = sqlx::query_as::<Postgres, User>(r#"SELECT * FROM users WHERE id = $1"#)
+            .bind(id.to_uuid())
+            .fetch_optional(conn)
+            .await?;
+        Ok(user)
+    }
+    pub async fn remove(conn: &mut PgConnection, id: UserId) -> Result<(), InfraError> {
+        sqlx::query(r#"DELETE FROM users WHERE id = $1 RETURNING *"#)
+            .bind(id.to_uuid())
+            .fetch_one(conn)
+            .await
+            .map_err(|_| InfraError::NoRecordError)?;
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// #UserPgDBRepository
+
+/// UserのPostgresqlリポジトリ
+
+#[derive(Debug, Clone)]
+pub struct UserPgDBRepository {
+    pool: PgPool,
+}
+
+impl UserPgDBRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserRepository for UserPgDBRepository {
+    type Error = InfraError;
+    async fn save(&self, user: User) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        user_sql_runner::save(&mut conn, user).await?;
+        Ok(())
+    }
+    async fn edit(&self, user: User) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        user_sql_runner::edit(&mut conn, user).await?;
+        Ok(())
+    }
+    async fn all(&self) -> Result<Vec<User>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let users = user_sql_runner::all(&mut conn).await?;
+        Ok(users)
+    }
+    async fn find_by_name(&self, name: &str) -> Result<Option<User>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let user = user_sql_runner::find_by_name(&mut conn, name).await?;
+        Ok(user)
+    }
+    async fn find_by_id(&self, id: UserId) -> Result<Option<User>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let user = user_sql_runner::find_by_id(&mut conn, id).await?;
+        Ok(user)
+    }
+    async fn remove(&self, id: UserId) -> Result<(), InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        user_sql_runner::remove(&mut conn, id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::user_sql_runner;
+    use crate::user_repository_impl::user_assert::users_assert_eq;
+    use crate::InfraError;
+    use domain::user::{Role, User, UserId};
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rand::seq::SliceRandom;
+    use rstest::{fixture, rstest};
+    use sqlx::postgres::{PgPool, PgPoolOptions};
+    use std::time::Duration;
+
+    #[fixture]
+    fn users() -> Result<Vec<User>, InfraError> {
+        Ok((0..100).map(|_| Faker.fake::<User>()).collect::<Vec<_>>())
+    }
+
+    #[fixture]
+    async fn pool() -> Result<PgPool, InfraError> {
+        let database_url = std::env::var("DATABASE_URL").unwrap();
+        let pool = PgPoolOptions::new()
+            .idle_timeout(Duration::from_secs(1))
+            .connect(&database_url)
+            .await?;
+        Ok(pool)
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_all(
+        users: Result<Vec<User>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut users = users?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        // データベースへ保存
+        for user in users.iter().cloned() {
+            user_sql_runner::save(&mut transaction, user).await?;
+        }
+
+        let mut users_res = user_sql_runner::all(&mut transaction).await?;
+        users_assert_eq(&mut users_res, &mut users);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_edit_and_all(
+        users: Result<Vec<User>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut users = users?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        // データベースへ保存
+        for user in users.iter().cloned() {
+            user_sql_runner::save(&mut transaction, user).await?;
+        }
+
+        // usersの一部の権限を編集
+        for _ in 0..(users.len() / 2_usize) {
+            let edited_user = users.choose_mut(&mut rand::thread_rng()).unwrap();
+            *edited_user.role_mut() = Faker.fake::<Role>();
+
+            user_sql_runner::edit(&mut transaction, edited_user.clone()).await?;
+        }
+
+        let mut users_res = user_sql_runner::all(&mut transaction).await?;
+        users_assert_eq(&mut users_res, &mut users);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_find_by_name(
+        users: Result<Vec<User>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let users = users?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for user in users.iter().cloned() {
+            user_sql_runner::save(&mut transaction, user).await?;
+        }
+
+        for user in users.iter() {
+            let user_res = user_sql_runner::find_by_name(&mut transaction, user.name()).await?;
+            assert_eq!(user_res.as_ref(), Some(user));
+        }
+
+        let user_res = user_sql_runner::find_by_name(&mut transaction, "no_exists").await?;
+        assert_eq!(user_res, None);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_find_by_id(
+        users: Result<Vec<User>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let users = users?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for user in users.iter().cloned() {
+            user_sql_runner::save(&mut transaction, user).await?;
+        }
+
+        for user in users.iter() {
+            let user_res = user_sql_runner::find_by_id(&mut transaction, user.id()).await?;
+            assert_eq!(user_res.as_ref(), Some(user));
+        }
+
+        let user_res = user_sql_runner::find_by_id(&mut transaction, UserId::generate()).await?;
+        assert_eq!(user_res, None);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_remove(
+        users: Result<Vec<User>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let users = users?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for user in users.iter().cloned() {
+            user_sql_runner::save(&mut transaction, user).await?;
+        }
+
+        // usersの一部を削除
+        let (removed_users, rest_users) = users.split_at(20);
+        let mut rest_users = rest_users.to_vec();
+
+        for user in removed_users.iter() {
+            user_sql_runner::remove(&mut transaction, user.id()).await?;
+        }
+
+        let mut users_res = user_sql_runner::all(&mut transaction).await?;
+        users_assert_eq(&mut users_res, &mut rest_users);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_duplicated_name(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let user = Faker.fake::<User>();
+        user_sql_runner::save(&mut transaction, user.clone()).await?;
+
+        let same_name_user = User::new(user.name().to_string(), "hash".to_string(), Role::Admin)?;
+        let res = user_sql_runner::save(&mut transaction, same_name_user).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_edit_and_remove_no_exists(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let user = Faker.fake::<User>();
+        let res = user_sql_runner::edit(&mut transaction, user.clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = user_sql_runner::remove(&mut transaction, user.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::user::{User, UserId};
+use domain::UserRepository;
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+// -------------------------------------------------------------------------------------------------
+// # InMemoryUserRepository
+
+/// 即席のUserリポジトリ
+#[derive(Default, Debug, Clone)]
+pub struct InMemoryUserRepository {
+    map: Arc<Mutex<HashMap<Uuid, User>>>,
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self {
+            map: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    type Error = InfraError;
+    async fn save(&self, user: User) -> Result<(), InfraError> {
+        let mut map = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+
+        // ユーザー名はユニーク
+        if map.values().any(|saved| saved.name() == user.name()) {
+            return Err(InfraError::ConflictError);
+        }
+
+        match map.entry(user.id().to_uuid()) {
+            Entry::Occupied(_) => Err(InfraError::ConflictError),
+            Entry::Vacant(v) => {
+                v.insert(user);
+                Ok(())
+            }
+        }
+    }
+    async fn edit(&self, user: User) -> Result<(), InfraError> {
+        let mut map = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+
+        if map
+            .values()
+            .any(|saved| saved.name() == user.name() && saved.id() != user.id())
+        {
+            return Err(InfraError::ConflictError);
+        }
+
+        match map.entry(user.id().to_uuid()) {
+            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Occupied(mut o) => {
+                *o.get_mut() = user;
+                Ok(())
+            }
+        }
+    }
+    async fn all(&self) -> Result<Vec<User>, InfraError> {
+        let users = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        Ok(users)
+    }
+    async fn find_by_name(&self, name: &str) -> Result<Option<User>, InfraError> {
+        let user = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .values()
+            .find(|user| user.name() == name)
+            .cloned();
+        Ok(user)
+    }
+    async fn find_by_id(&self, id: UserId) -> Result<Option<User>, InfraError> {
+        let user = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .get(&id.to_uuid())
+            .cloned();
+        Ok(user)
+    }
+    async fn remove(&self, id: UserId) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .remove(&id.to_uuid())
+        {
+            None => Err(InfraError::NoRecordError),
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InMemoryUserRepository;
+    use crate::user_repository_impl::user_assert::users_assert_eq;
+    use crate::InfraError;
+    use domain::{
+        user::{Role, User, UserId},
+        UserRepository,
+    };
+
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use rand::seq::SliceRandom;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn users() -> Result<Vec<User>, InfraError> {
+        Ok((0..100).map(|_| Faker.fake::<User>()).collect::<Vec<_>>())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_all(
+        users: Result<Vec<User>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut users = users?;
+
+        let repo = InMemoryUserRepository::new();
+        for user in users.iter().cloned() {
+            repo.save(user).await?;
+        }
+
+        let mut users_res = repo.all().await?;
+
+        users_assert_eq(&mut users_res, &mut users);
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_edit_and_all(
+        users: Result<Vec<User>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut users = users?;
+
+        let repo = InMemoryUserRepository::new();
+        for user in users.iter().cloned() {
+            repo.save(user).await?;
+        }
+        // usersの一部の権限を編集
+        for _ in 0..(users.len() / 2_usize) {
+            let edited_user = users.choose_mut(&mut rand::thread_rng()).unwrap();
+            *edited_user.role_mut() = Faker.fake::<Role>();
+
+            repo.edit(edited_user.clone()).await?;
+        }
+
+        let mut users_res = repo.all().await?;
+
+        users_assert_eq(&mut users_res, &mut users);
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_find_by_name(
+        users: Result<Vec<User>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let users = users?;
+
+        let repo = InMemoryUserRepository::new();
+        for user in users.iter().cloned() {
+            repo.save(user).await?;
+        }
+
+        for user in users.iter() {
+            let user_res = repo.find_by_name(user.name()).await?;
+            assert_eq!(user_res.as_ref(), Some(user));
+        }
+
+        let user_res = repo.find_by_name("no_exists").await?;
+        assert_eq!(user_res, None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_find_by_id(
+        users: Result<Vec<User>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let users = users?;
+
+        let repo = InMemoryUserRepository::new();
+        for user in users.iter().cloned() {
+            repo.save(user).await?;
+        }
+
+        for user in users.iter() {
+            let user_res = repo.find_by_id(user.id()).await?;
+            assert_eq!(user_res.as_ref(), Some(user));
+        }
+
+        let user_res = repo.find_by_id(UserId::generate()).await?;
+        assert_eq!(user_res, None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_duplicated_name() -> Result<(), InfraError> {
+        let repo = InMemoryUserRepository::new();
+
+        let user = Faker.fake::<User>();
+        repo.save(user.clone()).await?;
+
+        let same_name_user = User::new(user.name().to_string(), "hash".to_string(), Role::Admin)?;
+        let res = repo.save(same_name_user).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_save_and_remove(
+        users: Result<Vec<User>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let users = users?;
+
+        let repo = InMemoryUserRepository::new();
+        for user in users.iter().cloned() {
+            repo.save(user).await?;
+        }
+
+        // usersの一部を削除
+        let (removed_users, rest_users) = users.split_at(20);
+        let mut rest_users = rest_users.to_vec();
+
+        for user in removed_users.iter() {
+            repo.remove(user.id()).await?
+        }
+
+        let mut users_res = repo.all().await?;
+        users_assert_eq(&mut users_res, &mut rest_users);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_user_edit_and_remove_no_exists() -> Result<(), InfraError> {
+        let repo = InMemoryUserRepository::new();
+
+        let user = Faker.fake::<User>();
+
+        let res = repo.edit(user.clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.remove(user.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+}
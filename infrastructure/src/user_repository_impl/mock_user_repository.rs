@@ -0,0 +1,25 @@
+use crate::InfraError;
+use async_trait::async_trait;
+use domain::user::{User, UserId};
+use domain::UserRepository;
+
+use mockall::mock;
+
+mock! {
+    /// UserRepositoryトレイトのモック
+    #[derive(Debug, Clone)]
+    pub UserRepository {}
+
+    #[async_trait]
+    impl UserRepository for UserRepository {
+        type Error = InfraError;
+        async fn save(&self, user: User) -> Result<(), <Self as UserRepository>::Error>;
+        async fn edit(&self, user: User) -> Result<(), <Self as UserRepository>::Error>;
+        async fn all(&self) -> Result<Vec<User>, <Self as UserRepository>::Error>;
+        async fn find_by_name(&self, name: &str)
+            -> Result<Option<User>, <Self as UserRepository>::Error>;
+        async fn find_by_id(&self, id: UserId)
+            -> Result<Option<User>, <Self as UserRepository>::Error>;
+        async fn remove(&self, id: UserId) -> Result<(), <Self as UserRepository>::Error>;
+    }
+}
@@ -1,6 +1,8 @@
 mod db_video_repository;
 mod inmemory_video_repository;
 mod mock_video_repository;
+#[cfg(test)]
+mod video_repository_contract;
 
 pub use db_video_repository::VideoPgDbRepository;
 pub use inmemory_video_repository::InMemoryVideoRepository;
@@ -25,7 +27,7 @@ mod assert_video {
 
     /// expectedを`sort_f`を基にソート(同じ場合はidて昇順に)して`filter_f`を基にフィルタリングして比較．
     pub(crate) fn videos_assert_eq_with_sort_by_key_and_filter<V, SF, FF>(
-        actual: &mut Vec<Video<V>>,
+        actual: &mut [Video<V>],
         expected: &mut Vec<Video<V>>,
         mut sort_f: SF,
         filter_f: Option<FF>,
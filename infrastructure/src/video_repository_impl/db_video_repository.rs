@@ -1,6 +1,11 @@
+use crate::revision_store::revision_sql_runner;
 use crate::InfraError;
+use domain::channel::ChannelId;
+use domain::client::ClientId;
+use domain::revision::{Actor, EntityKind, Operation, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
 use domain::video::{Video, VideoId, VideoType};
-use domain::VideoRepository;
+use domain::{Cursor, DateTime, Tag, VideoRepository};
 
 use async_trait::async_trait;
 use sqlx::PgPool;
@@ -11,9 +16,15 @@ use std::marker::PhantomData;
 
 /// videoに関するSQLのランナーモジュール
 mod video_sql_runner {
+    use crate::search_pattern::like_patterns;
     use crate::InfraError;
+    use domain::channel::ChannelId;
+    use domain::client::ClientId;
+    use domain::search::{SearchHit, SearchQuery};
     use domain::video::{Video, VideoId, VideoType};
+    use domain::{Cursor, DateTime, Tag};
     use sqlx::{PgConnection, Postgres};
+    use uuid::Uuid;
 
     /// Video<T>を一つ保存
     pub async fn save<T: VideoType>(
@@ -22,8 +33,8 @@ mod video_sql_runner {
     ) -> Result<(), InfraError> {
         sqlx::query(
             r#"
-INSERT INTO videos (title, "url", id, "date", author, "like", video_type)
-VALUES ($1, $2, $3, $4, $5, $6, $7)
+INSERT INTO videos (title, "url", id, "date", author, "like", video_type, duration, channel_id, version)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
         "#,
         )
         .bind(video.title().to_string())
@@ -33,61 +44,148 @@ VALUES ($1, $2, $3, $4, $5, $6, $7)
         .bind(video.author().to_string())
         .bind(video.like() as i32)
         .bind(T::default().to_string())
+        .bind(video.duration().map(|duration| duration.to_secs_f64()))
+        .bind(video.channel_id().to_uuid())
+        .bind(video.version() as i32)
         .execute(conn)
-        .await
-        .map_err(|_| InfraError::ConflictError)?;
+        .await?;
 
         Ok(())
     }
 
-    /// Video<T>を一つ編集
+    /// Video<T>を一つ編集し，版数を一つ増やす．版数が一致しない場合は`StaleVersion`を返す．
     pub async fn edit<T: VideoType>(
         conn: &mut PgConnection,
         video: Video<T>,
     ) -> Result<(), InfraError> {
-        sqlx::query(
+        let edited = sqlx::query(
             r#"
-UPDATE videos SET title = $1, "url" = $2, "date" = $3, author = $4
-WHERE video_type = $5 AND id = $6 RETURNING *
+UPDATE videos SET title = $1, "url" = $2, "date" = $3, author = $4, duration = $5, channel_id = $6,
+    version = version + 1
+WHERE video_type = $7 AND id = $8 AND version = $9 AND deleted_at IS NULL RETURNING id
         "#,
         )
         .bind(video.title().to_string())
         .bind(video.url().to_string())
         .bind(video.date().to_chrono()?)
         .bind(video.author().to_string())
+        .bind(video.duration().map(|duration| duration.to_secs_f64()))
+        .bind(video.channel_id().to_uuid())
         .bind(T::default().to_string())
         .bind(video.id().to_uuid())
-        .fetch_one(conn)
-        .await
-        .map_err(|_| InfraError::NoRecordError)?;
+        .bind(video.version() as i32)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        if edited.is_none() {
+            // ゴミ箱に無く存在する場合は他の編集によって版数が進んでいる
+            lock::<T>(conn, video.id()).await?;
+            return Err(InfraError::StaleVersion);
+        }
+
+        Ok(())
+    }
+
+    /// `id`を持つVideo<T>の行をロックする．存在しないかゴミ箱にある場合は`NoRecordError`を返す．
+    async fn lock<T: VideoType>(conn: &mut PgConnection, id: VideoId) -> Result<(), InfraError> {
+        sqlx::query(
+            r#"SELECT id FROM videos WHERE video_type = $1 AND id = $2 AND deleted_at IS NULL FOR UPDATE"#,
+        )
+            .bind(T::default().to_string())
+            .bind(id.to_uuid())
+            .fetch_optional(conn)
+            .await?
+            .ok_or(InfraError::NoRecordError)?;
 
         Ok(())
     }
 
-    /// `id`を持つVideo<T>のLikeを一つ増やす
+    /// `id`を持つVideo<T>のLikeを一つ増やす．`client`が既にLikeしている場合は何もしない．
     pub async fn increment_like<T: VideoType>(
         conn: &mut PgConnection,
         id: VideoId,
+        client: ClientId,
     ) -> Result<(), InfraError> {
-        sqlx::query(
+        lock::<T>(&mut *conn, id).await?;
+
+        let inserted = sqlx::query(
             r#"
-UPDATE videos SET "like" = "like" + 1 WHERE video_type = $1 AND id = $2 RETURNING *
+INSERT INTO video_likes (video_id, client_id) VALUES ($1, $2) ON CONFLICT DO NOTHING
+        "#,
+        )
+        .bind(id.to_uuid())
+        .bind(client.to_uuid())
+        .execute(&mut *conn)
+        .await?;
+
+        if inserted.rows_affected() == 1 {
+            sqlx::query(r#"UPDATE videos SET "like" = "like" + 1 WHERE id = $1"#)
+                .bind(id.to_uuid())
+                .execute(conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// `id`を持つVideo<T>のLikeを一つ減らす．`client`がLikeしていない場合は何もしない．
+    pub async fn unlike<T: VideoType>(
+        conn: &mut PgConnection,
+        id: VideoId,
+        client: ClientId,
+    ) -> Result<(), InfraError> {
+        lock::<T>(&mut *conn, id).await?;
+
+        let deleted = sqlx::query(
+            r#"
+DELETE FROM video_likes WHERE video_id = $1 AND client_id = $2
         "#,
         )
-        .bind(T::default().to_string())
         .bind(id.to_uuid())
-        .fetch_one(conn)
-        .await
-        .map_err(|_| InfraError::NoRecordError)?;
+        .bind(client.to_uuid())
+        .execute(&mut *conn)
+        .await?;
+
+        if deleted.rows_affected() == 1 {
+            sqlx::query(r#"UPDATE videos SET "like" = GREATEST("like" - 1, 0) WHERE id = $1"#)
+                .bind(id.to_uuid())
+                .execute(conn)
+                .await?;
+        }
 
         Ok(())
     }
 
+    /// `ids`のうち`client`がLikeしているゴミ箱に無いVideo<T>のidを`ids`の順番で取得．
+    pub async fn liked_ids<T: VideoType>(
+        conn: &mut PgConnection,
+        ids: &[VideoId],
+        client: ClientId,
+    ) -> Result<Vec<VideoId>, InfraError> {
+        let liked_ids = sqlx::query_scalar::<Postgres, Uuid>(
+            r#"
+SELECT video_id FROM video_likes INNER JOIN videos ON video_likes.video_id = videos.id
+WHERE videos.video_type = $1 AND client_id = $2 AND video_id = ANY($3) AND videos.deleted_at IS NULL
+ORDER BY array_position($3, video_id)
+        "#,
+        )
+        .bind(T::default().to_string())
+        .bind(client.to_uuid())
+        .bind(ids.iter().map(|id| id.to_uuid()).collect::<Vec<_>>())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(liked_ids
+            .into_iter()
+            .map(VideoId::from_uuid)
+            .collect::<Vec<_>>())
+    }
+
     /// 全てのVideo<T>を取得
     pub async fn all<T: VideoType>(conn: &mut PgConnection) -> Result<Vec<Video<T>>, InfraError> {
         let all_videos = sqlx::query_as::<Postgres, Video<T>>(
             r#"
-SELECT * FROM videos WHERE video_type = $1
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL
         "#,
         )
         .bind(T::default().to_string())
@@ -104,32 +202,32 @@ SELECT * FROM videos WHERE video_type = $1
     ) -> Result<Vec<Video<T>>, InfraError> {
         let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
             r#"
-SELECT * FROM videos WHERE video_type = $1 ORDER BY "like" DESC, id ASC LIMIT $2
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL ORDER BY "like" DESC, id ASC LIMIT $2
         "#,
         )
         .bind(T::default().to_string())
-        .bind(length as i32)
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
         .fetch_all(conn)
         .await?;
 
         Ok(ordered_videos)
     }
 
-    /// Likeを降順・idを昇順に`reference`以降のVideo<T>を`length`分取得．
+    /// Likeを降順・idを昇順に`cursor`以降のVideo<T>を`length`分取得．
     pub async fn order_by_like_later<T: VideoType>(
         conn: &mut PgConnection,
-        reference: &Video<T>,
+        cursor: &Cursor<u32, VideoId>,
         length: usize,
     ) -> Result<Vec<Video<T>>, InfraError> {
         let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
             r#"
-SELECT * FROM videos WHERE video_type = $1 AND ($2 > "like" OR ($2 = "like" AND $3 < id)) ORDER BY "like" DESC, id ASC LIMIT $4
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL AND ($2 > "like" OR ($2 = "like" AND $3 < id)) ORDER BY "like" DESC, id ASC LIMIT $4
         "#,
         )
         .bind(T::default().to_string())
-        .bind(reference.like() as i32)
-        .bind(reference.id().to_uuid())
-        .bind(length as i32)
+        .bind(cursor.key() as i32)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
         .fetch_all(conn)
         .await?;
 
@@ -143,51 +241,334 @@ SELECT * FROM videos WHERE video_type = $1 AND ($2 > "like" OR ($2 = "like" AND
     ) -> Result<Vec<Video<T>>, InfraError> {
         let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
             r#"
-SELECT * FROM videos WHERE video_type = $1 ORDER BY "date" DESC, id ASC LIMIT $2
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL ORDER BY "date" DESC, id ASC LIMIT $2
         "#,
         )
         .bind(T::default().to_string())
-        .bind(length as i32)
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
         .fetch_all(conn)
         .await?;
 
         Ok(ordered_videos)
     }
 
-    /// dateを降順・idを昇順に`reference`以降の`Video<T>`を`length`分取得．
+    /// dateを降順・idを昇順に`cursor`以降の`Video<T>`を`length`分取得．
     pub async fn order_by_date_later<T: VideoType>(
         conn: &mut PgConnection,
-        reference: &Video<T>,
+        cursor: &Cursor<DateTime, VideoId>,
         length: usize,
     ) -> Result<Vec<Video<T>>, InfraError> {
         let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
             r#"
-SELECT * FROM videos WHERE video_type = $1 AND ($2 > "date" OR ($2 = "date" AND $3 < id)) ORDER BY "date" DESC, id ASC LIMIT $4
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL AND ($2 > "date" OR ($2 = "date" AND $3 < id)) ORDER BY "date" DESC, id ASC LIMIT $4
         "#,
         )
         .bind(T::default().to_string())
-        .bind(reference.date().to_chrono()?)
-        .bind(reference.id().to_uuid())
-        .bind(length as i32)
+        .bind(cursor.key().to_chrono()?)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
         .fetch_all(conn)
         .await?;
 
         Ok(ordered_videos)
     }
 
-    /// `id`を持つVideo<T>を削除する．
-    pub async fn remove(conn: &mut PgConnection, id: VideoId) -> Result<(), InfraError> {
-        sqlx::query(
+    /// `tags`を全て持つVideo<T>をLikeを降順に`length`分取得．Likeが同じ場合はidを昇順で並べる．`tags`が空の場合は全てが対象．
+    pub async fn order_by_like_with_tags<T: VideoType>(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
+            r#"
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL AND (cardinality($2::text[]) = 0 OR id IN (
+    SELECT video_id FROM video_tags WHERE tag = ANY($2) GROUP BY video_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($2::text[]) AS t)
+))
+ORDER BY "like" DESC, id ASC LIMIT $3
+        "#,
+        )
+        .bind(T::default().to_string())
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_videos)
+    }
+
+    /// `tags`を全て持つVideo<T>をLikeを降順・idを昇順に`cursor`以降を`length`分取得．
+    pub async fn order_by_like_later_with_tags<T: VideoType>(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        cursor: &Cursor<u32, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
+            r#"
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL AND (cardinality($2::text[]) = 0 OR id IN (
+    SELECT video_id FROM video_tags WHERE tag = ANY($2) GROUP BY video_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($2::text[]) AS t)
+))
+AND ($3 > "like" OR ($3 = "like" AND $4 < id)) ORDER BY "like" DESC, id ASC LIMIT $5
+        "#,
+        )
+        .bind(T::default().to_string())
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(cursor.key() as i32)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_videos)
+    }
+
+    /// `tags`を全て持つVideo<T>をdateを降順に`length`分取得．dateが同じ場合はidを昇順で並べる．`tags`が空の場合は全てが対象．
+    pub async fn order_by_date_with_tags<T: VideoType>(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
+            r#"
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL AND (cardinality($2::text[]) = 0 OR id IN (
+    SELECT video_id FROM video_tags WHERE tag = ANY($2) GROUP BY video_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($2::text[]) AS t)
+))
+ORDER BY "date" DESC, id ASC LIMIT $3
+        "#,
+        )
+        .bind(T::default().to_string())
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_videos)
+    }
+
+    /// `tags`を全て持つVideo<T>をdateを降順・idを昇順に`cursor`以降を`length`分取得．
+    pub async fn order_by_date_later_with_tags<T: VideoType>(
+        conn: &mut PgConnection,
+        tags: &[Tag],
+        cursor: &Cursor<DateTime, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
             r#"
-DELETE FROM videos WHERE id = $1 RETURNING *
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL AND (cardinality($2::text[]) = 0 OR id IN (
+    SELECT video_id FROM video_tags WHERE tag = ANY($2) GROUP BY video_id
+    HAVING COUNT(*) = (SELECT COUNT(DISTINCT t) FROM unnest($2::text[]) AS t)
+))
+AND ($3 > "date" OR ($3 = "date" AND $4 < id)) ORDER BY "date" DESC, id ASC LIMIT $5
+        "#,
+        )
+        .bind(T::default().to_string())
+        .bind(tags.iter().map(|tag| tag.to_string()).collect::<Vec<_>>())
+        .bind(cursor.key().to_chrono()?)
+        .bind(cursor.id().to_uuid())
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_videos)
+    }
+
+    /// `channel`が投稿したVideo<T>をdateを降順に全て取得．dateが同じ場合はidを昇順で並べる．
+    pub async fn order_by_date_with_channel<T: VideoType>(
+        conn: &mut PgConnection,
+        channel: ChannelId,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let ordered_videos = sqlx::query_as::<Postgres, Video<T>>(
+            r#"
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NULL AND channel_id = $2 ORDER BY "date" DESC, id ASC
+        "#,
+        )
+        .bind(T::default().to_string())
+        .bind(channel.to_uuid())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(ordered_videos)
+    }
+
+    /// `query`に一致するVideo<T>をスコアの降順・idの昇順に`length`分取得．`search_text`はタイトルと投稿者．
+    pub async fn search<T: VideoType>(
+        conn: &mut PgConnection,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<Video<T>>>, InfraError> {
+        let hits = sqlx::query_as::<Postgres, SearchHit<Video<T>>>(
+            r#"
+SELECT *, (ts_rank(search_vector, plainto_tsquery('simple', $2)) + word_similarity($2, search_text))::real AS score
+FROM videos WHERE video_type = $1 AND deleted_at IS NULL
+AND (search_vector @@ plainto_tsquery('simple', $2) OR search_text ILIKE ALL ($3))
+ORDER BY score DESC, id ASC LIMIT $4
             "#,
         )
+        .bind(T::default().to_string())
+        .bind(query.as_str())
+        .bind(like_patterns(query))
+        .bind(i64::try_from(length).unwrap_or(i64::MAX))
+        .fetch_all(conn)
+        .await?;
+
+        Ok(hits)
+    }
+
+    /// ゴミ箱に無いVideo<T>に付けられている全てのタグを重複無く昇順で取得．
+    pub async fn all_tags<T: VideoType>(conn: &mut PgConnection) -> Result<Vec<Tag>, InfraError> {
+        let tags = sqlx::query_scalar::<Postgres, String>(
+            r#"
+SELECT DISTINCT tag FROM video_tags JOIN videos ON videos.id = video_tags.video_id
+WHERE videos.video_type = $1 AND videos.deleted_at IS NULL ORDER BY tag ASC
+        "#,
+        )
+        .bind(T::default().to_string())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(tags
+            .into_iter()
+            .map(Tag::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// `id`を持つVideo<T>のタグを昇順で取得．
+    pub async fn tags<T: VideoType>(
+        conn: &mut PgConnection,
+        id: VideoId,
+    ) -> Result<Vec<Tag>, InfraError> {
+        sqlx::query(
+            r#"SELECT id FROM videos WHERE video_type = $1 AND id = $2 AND deleted_at IS NULL"#,
+        )
+        .bind(T::default().to_string())
+        .bind(id.to_uuid())
+        .fetch_optional(&mut *conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+
+        let tags = sqlx::query_scalar::<Postgres, String>(
+            r#"SELECT tag FROM video_tags WHERE video_id = $1 ORDER BY tag ASC"#,
+        )
+        .bind(id.to_uuid())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(tags
+            .into_iter()
+            .map(Tag::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// `id`を持つVideo<T>にタグを付ける．既に付いている場合は何もしない．
+    pub async fn add_tag<T: VideoType>(
+        conn: &mut PgConnection,
+        id: VideoId,
+        tag: Tag,
+    ) -> Result<(), InfraError> {
+        lock::<T>(&mut *conn, id).await?;
+
+        sqlx::query(
+            r#"INSERT INTO video_tags (video_id, tag) VALUES ($1, $2) ON CONFLICT DO NOTHING"#,
+        )
         .bind(id.to_uuid())
-        .fetch_one(conn)
-        .await
-        .map_err(|_| InfraError::NoRecordError)?;
+        .bind(tag.to_string())
+        .execute(conn)
+        .await?;
+
         Ok(())
     }
+
+    /// `id`を持つVideo<T>からタグを外す．付いていない場合は何もしない．
+    pub async fn remove_tag<T: VideoType>(
+        conn: &mut PgConnection,
+        id: VideoId,
+        tag: Tag,
+    ) -> Result<(), InfraError> {
+        lock::<T>(&mut *conn, id).await?;
+
+        sqlx::query(r#"DELETE FROM video_tags WHERE video_id = $1 AND tag = $2"#)
+            .bind(id.to_uuid())
+            .bind(tag.to_string())
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// `id`を持つVideo<T>をゴミ箱に移し，移したVideo<T>を返す．
+    pub async fn remove<T: VideoType>(
+        conn: &mut PgConnection,
+        id: VideoId,
+    ) -> Result<Video<T>, InfraError> {
+        let removed_video = sqlx::query_as::<Postgres, Video<T>>(
+            r#"
+UPDATE videos SET deleted_at = now()
+WHERE video_type = $1 AND id = $2 AND deleted_at IS NULL RETURNING *
+            "#,
+        )
+        .bind(T::default().to_string())
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(removed_video)
+    }
+
+    /// ゴミ箱のVideo<T>を削除した日時の降順・idの昇順で取得．
+    pub async fn trashed<T: VideoType>(
+        conn: &mut PgConnection,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let trashed_videos = sqlx::query_as::<Postgres, Video<T>>(
+            r#"
+SELECT * FROM videos WHERE video_type = $1 AND deleted_at IS NOT NULL
+ORDER BY deleted_at DESC, id ASC
+            "#,
+        )
+        .bind(T::default().to_string())
+        .fetch_all(conn)
+        .await?;
+        Ok(trashed_videos)
+    }
+
+    /// `id`を持つVideo<T>をゴミ箱から戻し，戻したVideo<T>を返す．
+    pub async fn restore<T: VideoType>(
+        conn: &mut PgConnection,
+        id: VideoId,
+    ) -> Result<Video<T>, InfraError> {
+        let restored_video = sqlx::query_as::<Postgres, Video<T>>(
+            r#"
+UPDATE videos SET deleted_at = NULL
+WHERE video_type = $1 AND id = $2 AND deleted_at IS NOT NULL RETURNING *
+            "#,
+        )
+        .bind(T::default().to_string())
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(restored_video)
+    }
+
+    /// `id`を持つVideo<T>をゴミ箱から完全に削除し，削除したVideo<T>を返す．
+    pub async fn purge<T: VideoType>(
+        conn: &mut PgConnection,
+        id: VideoId,
+    ) -> Result<Video<T>, InfraError> {
+        let purged_video = sqlx::query_as::<Postgres, Video<T>>(
+            r#"
+DELETE FROM videos WHERE video_type = $1 AND id = $2 AND deleted_at IS NOT NULL RETURNING *
+            "#,
+        )
+        .bind(T::default().to_string())
+        .bind(id.to_uuid())
+        .fetch_optional(conn)
+        .await?
+        .ok_or(InfraError::NoRecordError)?;
+        Ok(purged_video)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -212,21 +593,75 @@ impl<T: VideoType> VideoPgDbRepository<T> {
 #[async_trait]
 impl<T: VideoType> VideoRepository<T> for VideoPgDbRepository<T> {
     type Error = InfraError;
-    async fn save(&self, video: Video<T>) -> Result<(), InfraError> {
-        let mut conn = self.pool.acquire().await?;
-        video_sql_runner::save(&mut conn, video).await?;
+    async fn save(&self, video: Video<T>, actor: Actor) -> Result<(), InfraError> {
+        let revision = Revision::new(
+            EntityKind::video::<T>(),
+            video.id().to_uuid(),
+            Operation::Save,
+            &video,
+            actor,
+        )?;
+        let mut transaction = self.pool.begin().await?;
+        video_sql_runner::save(&mut transaction, video).await?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
         Ok(())
     }
-    async fn edit(&self, new_video: Video<T>) -> Result<(), InfraError> {
-        let mut conn = self.pool.acquire().await?;
-        video_sql_runner::edit(&mut conn, new_video).await?;
+    async fn save_many(&self, videos: Vec<Video<T>>, actor: Actor) -> Result<(), InfraError> {
+        // 途中で失敗した場合はコミットせずにロールバックする
+        let mut transaction = self.pool.begin().await?;
+        for video in videos {
+            let revision = Revision::new(
+                EntityKind::video::<T>(),
+                video.id().to_uuid(),
+                Operation::Save,
+                &video,
+                actor,
+            )?;
+            video_sql_runner::save(&mut transaction, video).await?;
+            revision_sql_runner::save(&mut transaction, &revision).await?;
+        }
+        transaction.commit().await?;
         Ok(())
     }
-    async fn increment_like(&self, id: VideoId) -> Result<(), InfraError> {
-        let mut conn = self.pool.acquire().await?;
-        video_sql_runner::increment_like::<T>(&mut conn, id).await?;
+    async fn edit(&self, new_video: Video<T>, actor: Actor) -> Result<(), InfraError> {
+        // 編集後は版数が一つ進む
+        let mut edited_video = new_video.clone();
+        edited_video.increment_version();
+        let revision = Revision::new(
+            EntityKind::video::<T>(),
+            edited_video.id().to_uuid(),
+            Operation::Edit,
+            &edited_video,
+            actor,
+        )?;
+        let mut transaction = self.pool.begin().await?;
+        video_sql_runner::edit(&mut transaction, new_video).await?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn increment_like(&self, id: VideoId, client: ClientId) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        video_sql_runner::increment_like::<T>(&mut transaction, id, client).await?;
+        transaction.commit().await?;
         Ok(())
     }
+    async fn unlike(&self, id: VideoId, client: ClientId) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        video_sql_runner::unlike::<T>(&mut transaction, id, client).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn liked_ids(
+        &self,
+        ids: &[VideoId],
+        client: ClientId,
+    ) -> Result<Vec<VideoId>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let liked_ids = video_sql_runner::liked_ids::<T>(&mut conn, ids, client).await?;
+        Ok(liked_ids)
+    }
     async fn all(&self) -> Result<Vec<Video<T>>, InfraError> {
         let mut conn = self.pool.acquire().await?;
         let clips = video_sql_runner::all(&mut conn).await?;
@@ -239,11 +674,11 @@ impl<T: VideoType> VideoRepository<T> for VideoPgDbRepository<T> {
     }
     async fn order_by_like_later(
         &self,
-        reference: &Video<T>,
+        cursor: &Cursor<u32, VideoId>,
         length: usize,
     ) -> Result<Vec<Video<T>>, InfraError> {
         let mut conn = self.pool.acquire().await?;
-        let clips = video_sql_runner::order_by_like_later(&mut conn, reference, length).await?;
+        let clips = video_sql_runner::order_by_like_later(&mut conn, cursor, length).await?;
         Ok(clips)
     }
     async fn order_by_date(&self, length: usize) -> Result<Vec<Video<T>>, InfraError> {
@@ -253,34 +688,181 @@ impl<T: VideoType> VideoRepository<T> for VideoPgDbRepository<T> {
     }
     async fn order_by_date_later(
         &self,
-        reference: &Video<T>,
+        cursor: &Cursor<DateTime, VideoId>,
         length: usize,
     ) -> Result<Vec<Video<T>>, InfraError> {
         let mut conn = self.pool.acquire().await?;
-        let clips = video_sql_runner::order_by_date_later(&mut conn, reference, length).await?;
+        let clips = video_sql_runner::order_by_date_later(&mut conn, cursor, length).await?;
         Ok(clips)
     }
-    async fn remove(&self, id: VideoId) -> Result<(), InfraError> {
+    async fn order_by_date_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let videos = video_sql_runner::order_by_date_with_tags(&mut conn, tags, length).await?;
+        Ok(videos)
+    }
+    async fn order_by_date_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<DateTime, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
         let mut conn = self.pool.acquire().await?;
-        video_sql_runner::remove(&mut conn, id).await?;
+        let videos =
+            video_sql_runner::order_by_date_later_with_tags(&mut conn, tags, cursor, length)
+                .await?;
+        Ok(videos)
+    }
+    async fn order_by_like_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let videos = video_sql_runner::order_by_like_with_tags(&mut conn, tags, length).await?;
+        Ok(videos)
+    }
+    async fn order_by_like_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<u32, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let videos =
+            video_sql_runner::order_by_like_later_with_tags(&mut conn, tags, cursor, length)
+                .await?;
+        Ok(videos)
+    }
+    async fn order_by_date_with_channel(
+        &self,
+        channel: ChannelId,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let videos = video_sql_runner::order_by_date_with_channel(&mut conn, channel).await?;
+        Ok(videos)
+    }
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<Video<T>>>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let hits = video_sql_runner::search(&mut conn, query, length).await?;
+        Ok(hits)
+    }
+    async fn all_tags(&self) -> Result<Vec<Tag>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let tags = video_sql_runner::all_tags::<T>(&mut conn).await?;
+        Ok(tags)
+    }
+    async fn tags(&self, id: VideoId) -> Result<Vec<Tag>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let tags = video_sql_runner::tags::<T>(&mut conn, id).await?;
+        Ok(tags)
+    }
+    async fn add_tag(&self, id: VideoId, tag: Tag) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        video_sql_runner::add_tag::<T>(&mut transaction, id, tag).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn remove_tag(&self, id: VideoId, tag: Tag) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        video_sql_runner::remove_tag::<T>(&mut transaction, id, tag).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn remove(&self, id: VideoId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let removed_video = video_sql_runner::remove::<T>(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::video::<T>(),
+            id.to_uuid(),
+            Operation::Remove,
+            &removed_video,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
         Ok(())
     }
+    async fn trashed(&self) -> Result<Vec<Video<T>>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let videos = video_sql_runner::trashed(&mut conn).await?;
+        Ok(videos)
+    }
+    async fn restore(&self, id: VideoId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let restored_video = video_sql_runner::restore::<T>(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::video::<T>(),
+            id.to_uuid(),
+            Operation::Restore,
+            &restored_video,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn purge(&self, id: VideoId, actor: Actor) -> Result<(), InfraError> {
+        let mut transaction = self.pool.begin().await?;
+        let purged_video = video_sql_runner::purge::<T>(&mut transaction, id).await?;
+        let revision = Revision::new(
+            EntityKind::video::<T>(),
+            id.to_uuid(),
+            Operation::Purge,
+            &purged_video,
+            actor,
+        )?;
+        revision_sql_runner::save(&mut transaction, &revision).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn revisions(&self, id: VideoId) -> Result<Vec<Revision>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revisions =
+            revision_sql_runner::of_entity(&mut conn, EntityKind::video::<T>(), id.to_uuid())
+                .await?;
+        Ok(revisions)
+    }
+    async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revisions =
+            revision_sql_runner::latest(&mut conn, EntityKind::video::<T>(), length).await?;
+        Ok(revisions)
+    }
+    async fn revision(&self, id: RevisionId) -> Result<Revision, InfraError> {
+        let mut conn = self.pool.acquire().await?;
+        let revision = revision_sql_runner::find(&mut conn, EntityKind::video::<T>(), id).await?;
+        Ok(revision)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::video_sql_runner;
+    use super::{video_sql_runner, VideoPgDbRepository};
     use crate::video_repository_impl::assert_video::{
         videos_assert_eq, videos_assert_eq_with_sort_by_key_and_filter,
     };
+    use crate::video_repository_impl::video_repository_contract::video_repository_contract;
     use crate::InfraError;
-    use domain::video::{Kirinuki, Original, Video};
+    use domain::channel::Channel;
+    use domain::client::ClientId;
+    use domain::search::SearchQuery;
+    use domain::video::{Kirinuki, Original, Video, VideoId, VideoType};
+    use domain::Tag;
 
     use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
     use rand::seq::SliceRandom;
     use rand::Rng;
     use rstest::{fixture, rstest};
-    use sqlx::postgres::{PgPool, PgPoolOptions};
+    use sqlx::postgres::{PgConnection, PgPool, PgPoolOptions};
     use std::time::Duration;
 
     #[fixture]
@@ -307,6 +889,37 @@ mod test {
         Ok(pool)
     }
 
+    /// `video`のチャンネルを投稿者名を名前として保存する．既に存在する場合は何もしない．
+    async fn save_channel_of<T: VideoType>(
+        conn: &mut PgConnection,
+        video: &Video<T>,
+    ) -> Result<(), InfraError> {
+        sqlx::query(r#"INSERT INTO channels (name, id) VALUES ($1, $2) ON CONFLICT DO NOTHING"#)
+            .bind(video.author())
+            .bind(video.channel_id().to_uuid())
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// `video`のチャンネルを保存してから`video`を保存する．
+    async fn save_with_channel<T: VideoType>(
+        conn: &mut PgConnection,
+        video: Video<T>,
+    ) -> Result<(), InfraError> {
+        save_channel_of(&mut *conn, &video).await?;
+        video_sql_runner::save(conn, video).await
+    }
+
+    /// `video`のチャンネルを保存してから`video`を編集する．
+    async fn edit_with_channel<T: VideoType>(
+        conn: &mut PgConnection,
+        video: Video<T>,
+    ) -> Result<(), InfraError> {
+        save_channel_of(&mut *conn, &video).await?;
+        video_sql_runner::edit(conn, video).await
+    }
+
     #[ignore]
     #[rstest]
     #[tokio::test]
@@ -324,8 +937,8 @@ mod test {
         let mut transaction = pool.begin().await?;
 
         for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
-            video_sql_runner::save(&mut transaction, original).await?;
-            video_sql_runner::save(&mut transaction, kirinuki).await?;
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
         }
 
         let mut originals_res = video_sql_runner::all::<Original>(&mut transaction).await?;
@@ -355,15 +968,16 @@ mod test {
         let mut transaction = pool.begin().await?;
 
         for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
-            video_sql_runner::save(&mut transaction, original).await?;
-            video_sql_runner::save(&mut transaction, kirinuki).await?;
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
         }
 
         // originalsの一部を編集．
         for _ in 0..(originals.len() / 2) {
             let edited_original = originals.choose_mut(&mut rand::thread_rng()).unwrap();
             edited_original.assign(Faker.fake());
-            video_sql_runner::edit(&mut transaction, edited_original.clone()).await?;
+            edit_with_channel(&mut transaction, edited_original.clone()).await?;
+            edited_original.increment_version();
         }
 
         let mut originals_res = video_sql_runner::all::<Original>(&mut transaction).await?;
@@ -392,8 +1006,8 @@ mod test {
         let mut transaction = pool.begin().await?;
 
         for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
-            video_sql_runner::save(&mut transaction, original).await?;
-            video_sql_runner::save(&mut transaction, kirinuki).await?;
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
         }
 
         // originalsの一部をincrement_like
@@ -403,6 +1017,7 @@ mod test {
             video_sql_runner::increment_like::<Original>(
                 &mut transaction,
                 incremented_original.id(),
+                ClientId::generate(),
             )
             .await?;
         }
@@ -417,6 +1032,71 @@ mod test {
         Ok(())
     }
 
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_increment_like_and_unlike_per_client(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let original = Faker.fake::<Video<Original>>();
+        let kirinuki = Faker.fake::<Video<Kirinuki>>();
+        let (client, other_client) = (ClientId::generate(), ClientId::generate());
+
+        save_with_channel(&mut transaction, original.clone()).await?;
+        save_with_channel(&mut transaction, kirinuki.clone()).await?;
+
+        // 同じクライアントのLikeは一度だけ数える
+        for client in [client, client, other_client] {
+            video_sql_runner::increment_like::<Original>(&mut transaction, original.id(), client)
+                .await?;
+        }
+        video_sql_runner::increment_like::<Kirinuki>(&mut transaction, kirinuki.id(), client)
+            .await?;
+
+        let originals_res = video_sql_runner::all::<Original>(&mut transaction).await?;
+        let original_res = originals_res
+            .iter()
+            .find(|res| res.id() == original.id())
+            .unwrap();
+        assert_eq!(original_res.like(), original.like() + 2);
+
+        // 異なるVideoTypeのidは含まない
+        let liked_ids = video_sql_runner::liked_ids::<Original>(
+            &mut transaction,
+            &[kirinuki.id(), original.id()],
+            client,
+        )
+        .await?;
+        assert_eq!(liked_ids, vec![original.id()]);
+
+        // unlikeもクライアントごとに一度だけ
+        for _ in 0..2 {
+            video_sql_runner::unlike::<Original>(&mut transaction, original.id(), client).await?;
+        }
+
+        let originals_res = video_sql_runner::all::<Original>(&mut transaction).await?;
+        let original_res = originals_res
+            .iter()
+            .find(|res| res.id() == original.id())
+            .unwrap();
+        assert_eq!(original_res.like(), original.like() + 1);
+
+        let liked_ids =
+            video_sql_runner::liked_ids::<Original>(&mut transaction, &[original.id()], client)
+                .await?;
+        assert_eq!(liked_ids, Vec::<VideoId>::new());
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
     #[ignore]
     #[rstest]
     #[tokio::test]
@@ -434,8 +1114,8 @@ mod test {
         let mut transaction = pool.begin().await?;
 
         for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
-            video_sql_runner::save(&mut transaction, original).await?;
-            video_sql_runner::save(&mut transaction, kirinuki).await?;
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
         }
 
         let length = originals.len() / 2;
@@ -474,8 +1154,8 @@ mod test {
         let mut transaction = pool.begin().await?;
 
         for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
-            video_sql_runner::save(&mut transaction, original).await?;
-            video_sql_runner::save(&mut transaction, kirinuki).await?;
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
         }
 
         let length = originals.len() / 2;
@@ -486,8 +1166,12 @@ mod test {
             originals[reference_index].clone()
         };
 
-        let mut originals_res =
-            video_sql_runner::order_by_like_later(&mut transaction, &reference, length).await?;
+        let mut originals_res = video_sql_runner::order_by_like_later(
+            &mut transaction,
+            &reference.like_cursor(),
+            length,
+        )
+        .await?;
 
         // originalsをlike(降順)・idの順に並べ，フィルタリングして比較
         videos_assert_eq_with_sort_by_key_and_filter(
@@ -524,8 +1208,8 @@ mod test {
         let mut transaction = pool.begin().await?;
 
         for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
-            video_sql_runner::save(&mut transaction, original).await?;
-            video_sql_runner::save(&mut transaction, kirinuki).await?;
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
         }
 
         let length = originals.len() / 2;
@@ -564,8 +1248,8 @@ mod test {
         let mut transaction = pool.begin().await?;
 
         for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
-            video_sql_runner::save(&mut transaction, original).await?;
-            video_sql_runner::save(&mut transaction, kirinuki).await?;
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
         }
 
         let length = originals.len() / 2;
@@ -576,8 +1260,12 @@ mod test {
             originals[reference_index].clone()
         };
 
-        let mut originals_res =
-            video_sql_runner::order_by_date_later(&mut transaction, &reference, length).await?;
+        let mut originals_res = video_sql_runner::order_by_date_later(
+            &mut transaction,
+            &reference.date_cursor(),
+            length,
+        )
+        .await?;
 
         // originalsをdate(降順)・idの順に並べ，フィルタリング
         videos_assert_eq_with_sort_by_key_and_filter(
@@ -614,8 +1302,8 @@ mod test {
         let mut transaction = pool.begin().await?;
 
         for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
-            video_sql_runner::save(&mut transaction, original).await?;
-            video_sql_runner::save(&mut transaction, kirinuki).await?;
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
         }
 
         // originalsの一部を削除
@@ -636,7 +1324,7 @@ mod test {
             .collect::<Vec<_>>();
 
         for original in removed_originals.into_iter() {
-            video_sql_runner::remove(&mut transaction, original.id()).await?;
+            video_sql_runner::remove::<Original>(&mut transaction, original.id()).await?;
         }
 
         let mut originals_res = video_sql_runner::all::<Original>(&mut transaction).await?;
@@ -661,7 +1349,7 @@ mod test {
 
         let original = Faker.fake::<Video<Original>>();
 
-        let res = video_sql_runner::edit(&mut transaction, original).await;
+        let res = edit_with_channel(&mut transaction, original).await;
 
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
@@ -671,6 +1359,39 @@ mod test {
         Ok(())
     }
 
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_edit_stale_version(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let mut original = Faker.fake::<Video<Original>>();
+        save_with_channel(&mut transaction, original.clone()).await?;
+
+        // 同じ版数からの編集は先の一つのみ成功する
+        let stale_original = original.clone();
+        original.assign(Faker.fake());
+        edit_with_channel(&mut transaction, original.clone()).await?;
+
+        let res = edit_with_channel(&mut transaction, stale_original).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)));
+
+        // 版数が一つ増えている
+        original.increment_version();
+        let res = video_sql_runner::all(&mut transaction).await?;
+        assert_eq!(res, vec![original]);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
     #[ignore]
     #[rstest]
     #[tokio::test]
@@ -684,8 +1405,21 @@ mod test {
 
         let original = Faker.fake::<Video<Original>>();
 
-        let res =
-            video_sql_runner::increment_like::<Original>(&mut transaction, original.id()).await;
+        let res = video_sql_runner::increment_like::<Original>(
+            &mut transaction,
+            original.id(),
+            ClientId::generate(),
+        )
+        .await;
+
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = video_sql_runner::unlike::<Original>(
+            &mut transaction,
+            original.id(),
+            ClientId::generate(),
+        )
+        .await;
 
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
@@ -695,6 +1429,171 @@ mod test {
         Ok(())
     }
 
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_add_tag_and_order_with_tags(
+        original_videos: Result<Vec<Video<Original>>, InfraError>,
+        kirinuki_videos: Result<Vec<Video<Kirinuki>>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let originals = original_videos?;
+        let kirinukis = kirinuki_videos?;
+
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
+        }
+
+        let (singing, game) = (Tag::new("Singing")?, Tag::new("game")?);
+
+        // originalsの偶数番目にsinging，kirinukisの全てにgameを付ける
+        for original in originals.iter().step_by(2) {
+            video_sql_runner::add_tag::<Original>(&mut transaction, original.id(), singing.clone())
+                .await?;
+        }
+        for kirinuki in kirinukis.iter() {
+            video_sql_runner::add_tag::<Kirinuki>(&mut transaction, kirinuki.id(), game.clone())
+                .await?;
+        }
+
+        // 動画の種類ごとにタグを取得する
+        let all_tags = video_sql_runner::all_tags::<Original>(&mut transaction).await?;
+        assert_eq!(all_tags, vec![singing.clone()]);
+        let res = video_sql_runner::add_tag::<Original>(
+            &mut transaction,
+            kirinukis[0].id(),
+            singing.clone(),
+        )
+        .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let tags = video_sql_runner::tags::<Original>(&mut transaction, originals[0].id()).await?;
+        assert_eq!(tags, vec![singing.clone()]);
+
+        let tagged_ids = originals
+            .iter()
+            .step_by(2)
+            .map(|original| original.id())
+            .collect::<Vec<_>>();
+
+        let length = originals.len() / 4;
+        let mut originals_res = video_sql_runner::order_by_date_with_tags::<Original>(
+            &mut transaction,
+            std::slice::from_ref(&singing),
+            length,
+        )
+        .await?;
+
+        videos_assert_eq_with_sort_by_key_and_filter(
+            &mut originals_res,
+            &mut originals.clone(),
+            |x, y| y.date().cmp(&x.date()),
+            Some(|original: &Video<Original>| tagged_ids.contains(&original.id())),
+            Some(length),
+        );
+
+        // referenceとなるvideoを取得
+        let reference = originals[0].clone();
+        let mut originals_res = video_sql_runner::order_by_like_later_with_tags::<Original>(
+            &mut transaction,
+            std::slice::from_ref(&singing),
+            &reference.like_cursor(),
+            length,
+        )
+        .await?;
+
+        videos_assert_eq_with_sort_by_key_and_filter(
+            &mut originals_res,
+            &mut originals.clone(),
+            |x, y| y.like().cmp(&x.like()),
+            Some(|original: &Video<Original>| {
+                tagged_ids.contains(&original.id())
+                    && (original.like() < reference.like()
+                        || (original.like() == reference.like() && original.id() > reference.id()))
+            }),
+            Some(length),
+        );
+
+        // タグを外す
+        video_sql_runner::remove_tag::<Original>(&mut transaction, originals[0].id(), singing)
+            .await?;
+        let tags = video_sql_runner::tags::<Original>(&mut transaction, originals[0].id()).await?;
+        assert_eq!(tags, Vec::<Tag>::new());
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_order_by_date_with_channel(
+        original_videos: Result<Vec<Video<Original>>, InfraError>,
+        kirinuki_videos: Result<Vec<Video<Kirinuki>>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let channel = Faker.fake::<Channel>();
+        sqlx::query(r#"INSERT INTO channels (name, id) VALUES ($1, $2)"#)
+            .bind(channel.name().to_string())
+            .bind(channel.id().to_uuid())
+            .execute(&mut transaction)
+            .await?;
+
+        // 偶数番目にchannelを設定
+        let mut originals = original_videos?;
+        for original in originals.iter_mut().step_by(2) {
+            original.set_channel(&channel);
+        }
+        let mut kirinukis = kirinuki_videos?;
+        for kirinuki in kirinukis.iter_mut() {
+            kirinuki.set_channel(&channel);
+        }
+
+        for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
+        }
+
+        // 動画の種類ごとに取得する
+        let originals_res = video_sql_runner::order_by_date_with_channel::<Original>(
+            &mut transaction,
+            channel.id(),
+        )
+        .await?;
+
+        let mut expected = originals
+            .iter()
+            .filter(|original| original.channel_id() == channel.id())
+            .cloned()
+            .collect::<Vec<_>>();
+        expected.sort_by(|x, y| y.date().cmp(&x.date()).then_with(|| x.id().cmp(&y.id())));
+        assert_eq!(originals_res, expected);
+
+        // 存在しないチャンネルは設定できない
+        let mut original = Faker.fake::<Video<Original>>();
+        original.set_channel(&Faker.fake::<Channel>());
+        let res = video_sql_runner::save(&mut transaction, original).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
     #[ignore]
     #[rstest]
     #[tokio::test]
@@ -708,7 +1607,7 @@ mod test {
 
         let original = Faker.fake::<Video<Original>>();
 
-        let res = video_sql_runner::remove(&mut transaction, original.id()).await;
+        let res = video_sql_runner::remove::<Original>(&mut transaction, original.id()).await;
 
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
@@ -717,4 +1616,157 @@ mod test {
 
         Ok(())
     }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_trash(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        let mut originals = (0..3)
+            .map(|_| Faker.fake::<Video<Original>>())
+            .collect::<Vec<_>>();
+        originals.sort_by_key(|original| original.id());
+        for original in originals.iter().cloned() {
+            save_with_channel(&mut transaction, original).await?;
+        }
+
+        // ゴミ箱に移したものは通常の取得の対象とならない
+        let removed =
+            video_sql_runner::remove::<Original>(&mut transaction, originals[0].id()).await?;
+        assert_eq!(removed, originals[0]);
+        video_sql_runner::remove::<Original>(&mut transaction, originals[1].id()).await?;
+        let res = video_sql_runner::all::<Original>(&mut transaction).await?;
+        assert_eq!(res, vec![originals[2].clone()]);
+
+        // 同じトランザクション内では削除した日時が同じためidの昇順
+        let res = video_sql_runner::trashed::<Original>(&mut transaction).await?;
+        assert_eq!(res, originals[..2].to_vec());
+
+        // ゴミ箱のものは編集できない
+        let res = edit_with_channel(&mut transaction, originals[0].clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ゴミ箱から戻す
+        let restored =
+            video_sql_runner::restore::<Original>(&mut transaction, originals[0].id()).await?;
+        assert_eq!(restored, originals[0]);
+        let mut res = video_sql_runner::all::<Original>(&mut transaction).await?;
+        res.sort_by_key(|original| original.id());
+        assert_eq!(res, vec![originals[0].clone(), originals[2].clone()]);
+
+        // ゴミ箱に無いものは戻せず，完全に削除もできない
+        let res = video_sql_runner::restore::<Original>(&mut transaction, originals[2].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = video_sql_runner::purge::<Original>(&mut transaction, originals[2].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // 完全に削除したものは戻せない
+        let purged =
+            video_sql_runner::purge::<Original>(&mut transaction, originals[1].id()).await?;
+        assert_eq!(purged, originals[1]);
+        let res = video_sql_runner::trashed::<Original>(&mut transaction).await?;
+        assert_eq!(res, Vec::<Video<Original>>::new());
+        let res = video_sql_runner::restore::<Original>(&mut transaction, originals[1].id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[ignore]
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_search(
+        original_videos: Result<Vec<Video<Original>>, InfraError>,
+        kirinuki_videos: Result<Vec<Video<Kirinuki>>, InfraError>,
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut originals = original_videos?;
+        let mut kirinukis = kirinuki_videos?;
+        let pool = pool.await?;
+
+        // トランザクションの開始
+        let mut transaction = pool.begin().await?;
+
+        // タイトルまたは投稿者に検索する語を含める
+        for original in originals.iter_mut().take(10) {
+            *original.title_mut() = format!("{} 歌枠", original.title());
+        }
+        let relay_channel = Channel::new("歌枠リレー".parse().unwrap(), None, None);
+        for original in originals.iter_mut().skip(10).take(5) {
+            original.set_channel(&relay_channel);
+        }
+        for kirinuki in kirinukis.iter_mut().take(3) {
+            *kirinuki.title_mut() = "歌枠".to_string();
+        }
+
+        for (original, kirinuki) in originals.iter().cloned().zip(kirinukis.iter().cloned()) {
+            save_with_channel(&mut transaction, original).await?;
+            save_with_channel(&mut transaction, kirinuki).await?;
+        }
+
+        // 動画の種類ごとに検索する
+        let query = SearchQuery::new("歌枠").unwrap();
+        let hits = video_sql_runner::search::<Original>(&mut transaction, &query, 100).await?;
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+        let mut originals_res = hits.into_iter().map(|hit| hit.item).collect::<Vec<_>>();
+        videos_assert_eq(&mut originals_res, &mut originals[..15].to_vec());
+
+        let hits = video_sql_runner::search::<Kirinuki>(&mut transaction, &query, 100).await?;
+        let mut kirinukis_res = hits.into_iter().map(|hit| hit.item).collect::<Vec<_>>();
+        videos_assert_eq(&mut kirinukis_res, &mut kirinukis[..3].to_vec());
+
+        // lengthを超えない
+        let hits = video_sql_runner::search::<Original>(&mut transaction, &query, 3).await?;
+        assert_eq!(hits.len(), 3);
+
+        // ロールバック
+        transaction.rollback().await?;
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_repository_contract(
+        #[future] pool: Result<PgPool, InfraError>,
+    ) -> Result<(), InfraError> {
+        // DATABASE_URLが設定されていない場合は実行しない
+        if std::env::var("DATABASE_URL").is_err() {
+            return Ok(());
+        }
+        let pool = pool.await?;
+
+        let channel = Faker.fake::<Channel>();
+        sqlx::query(r#"INSERT INTO channels (name, id) VALUES ($1, $2)"#)
+            .bind(channel.name().to_string())
+            .bind(channel.id().to_uuid())
+            .execute(&pool)
+            .await?;
+
+        video_repository_contract(
+            &VideoPgDbRepository::<Original>::new(pool.clone()),
+            &channel,
+        )
+        .await?;
+        video_repository_contract(
+            &VideoPgDbRepository::<Kirinuki>::new(pool.clone()),
+            &channel,
+        )
+        .await?;
+
+        sqlx::query(r#"DELETE FROM channels WHERE id = $1"#)
+            .bind(channel.id().to_uuid())
+            .execute(&pool)
+            .await?;
+        Ok(())
+    }
 }
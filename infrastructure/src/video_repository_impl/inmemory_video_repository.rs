@@ -1,10 +1,15 @@
+use crate::revision_store::InMemoryRevisions;
 use crate::InfraError;
 use async_trait::async_trait;
+use domain::channel::{Channel, ChannelId};
+use domain::client::ClientId;
+use domain::revision::{Actor, EntityKind, Operation, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
 use domain::video::{Video, VideoId, VideoType};
-use domain::VideoRepository;
+use domain::{Cursor, DateTime, Tag, VideoRepository};
 
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
@@ -15,46 +20,226 @@ use uuid::Uuid;
 #[derive(Default, Debug, Clone)]
 pub struct InMemoryVideoRepository<T: VideoType> {
     map: Arc<Mutex<HashMap<Uuid, Video<T>>>>,
+    /// Likeの台帳．(Videoのid, クライアントのid)を保持する．
+    likes: Arc<Mutex<HashSet<(Uuid, Uuid)>>>,
+    /// タグの関係．(Videoのid, タグ)を保持する．
+    tags: Arc<Mutex<HashSet<(Uuid, Tag)>>>,
+    /// ゴミ箱．移した順に保持し，Likeとタグの関係は完全に削除するまで残す．
+    trash: Arc<Mutex<Vec<Video<T>>>>,
+    /// 編集履歴
+    revisions: InMemoryRevisions,
 }
 
 impl<T: VideoType> InMemoryVideoRepository<T> {
     pub fn new() -> Self {
         Self {
             map: Arc::new(Mutex::new(HashMap::new())),
+            likes: Arc::new(Mutex::new(HashSet::new())),
+            tags: Arc::new(Mutex::new(HashSet::new())),
+            trash: Arc::new(Mutex::new(Vec::new())),
+            revisions: InMemoryRevisions::default(),
         }
     }
+
+    /// `tags`を全て持つVideo<T>を取得する．
+    async fn all_with_tags(&self, tags: &[Tag]) -> Result<Vec<Video<T>>, InfraError> {
+        let videos = self.all().await?;
+        let tag_set = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        Ok(videos
+            .into_iter()
+            .filter(|video| {
+                tags.iter()
+                    .all(|tag| tag_set.contains(&(video.id().to_uuid(), tag.clone())))
+            })
+            .collect::<Vec<_>>())
+    }
+
+    /// `id`を持つVideo<T>が存在しない場合に`NoRecordError`を返す．
+    fn check_exists(&self, id: VideoId) -> Result<(), InfraError> {
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .contains_key(&id.to_uuid())
+            .then_some(())
+            .ok_or(InfraError::NoRecordError)
+    }
+
+    /// `id`を持つVideo<T>をゴミ箱から取り出す．ゴミ箱に無い場合は`NoRecordError`を返す．
+    fn take_trashed(&self, id: VideoId) -> Result<Video<T>, InfraError> {
+        let mut trash = self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        let position = trash
+            .iter()
+            .position(|trashed| trashed.id() == id)
+            .ok_or(InfraError::NoRecordError)?;
+        Ok(trash.remove(position))
+    }
+
+    /// `channel`のVideo<T>の投稿者名をチャンネルの表示名に揃える．ゴミ箱のVideo<T>も含む．
+    pub(crate) fn sync_channel(&self, channel: &Channel) -> Result<(), InfraError> {
+        let mut map = self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        let mut trash = self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        map.values_mut()
+            .chain(trash.iter_mut())
+            .filter(|video| video.channel_id() == channel.id())
+            .for_each(|video| video.set_channel(channel));
+        Ok(())
+    }
+
+    /// ゴミ箱に無いVideo<T>のidを取得する．
+    fn live_ids(&self) -> Result<HashSet<Uuid>, InfraError> {
+        Ok(self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .keys()
+            .cloned()
+            .collect())
+    }
 }
 
 #[async_trait]
 impl<T: VideoType> VideoRepository<T> for InMemoryVideoRepository<T> {
     type Error = InfraError;
-    async fn save(&self, video: Video<T>) -> Result<(), InfraError> {
-        let old_video = self
+    async fn save(&self, video: Video<T>, actor: Actor) -> Result<(), InfraError> {
+        let revision = Revision::new(
+            EntityKind::video::<T>(),
+            video.id().to_uuid(),
+            Operation::Save,
+            &video,
+            actor,
+        )?;
+        // ゴミ箱にある場合も保存できない
+        if self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .any(|trashed| trashed.id() == video.id())
+        {
+            return Err(InfraError::ConflictError);
+        }
+        // 既に存在する場合は上書きしない
+        match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
-            .insert(video.id().to_uuid(), video);
+            .entry(video.id().to_uuid())
+        {
+            Entry::Occupied(_) => return Err(InfraError::ConflictError),
+            Entry::Vacant(v) => {
+                v.insert(video);
+            }
+        }
+        self.revisions.push(revision)
+    }
 
-        match old_video {
-            Some(_) => Err(InfraError::ConflictError),
-            None => Ok(()),
+    async fn save_many(&self, videos: Vec<Video<T>>, actor: Actor) -> Result<(), InfraError> {
+        let revisions = videos
+            .iter()
+            .map(|video| {
+                Revision::new(
+                    EntityKind::video::<T>(),
+                    video.id().to_uuid(),
+                    Operation::Save,
+                    video,
+                    actor,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // ゴミ箱にある場合も保存できない
+        let trashed_ids = self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .map(|trashed| trashed.id().to_uuid())
+            .collect::<HashSet<_>>();
+        {
+            let mut map = self
+                .map
+                .lock()
+                .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+            // 一つでも保存できない場合は何も保存しない
+            let mut ids = HashSet::new();
+            if !videos.iter().all(|video| {
+                let id = video.id().to_uuid();
+                !trashed_ids.contains(&id) && !map.contains_key(&id) && ids.insert(id)
+            }) {
+                return Err(InfraError::ConflictError);
+            }
+            map.extend(
+                videos
+                    .into_iter()
+                    .map(|video| (video.id().to_uuid(), video)),
+            );
         }
+        revisions
+            .into_iter()
+            .try_for_each(|revision| self.revisions.push(revision))
     }
-    async fn edit(&self, new_video: Video<T>) -> Result<(), InfraError> {
-        match self
+    async fn edit(&self, new_video: Video<T>, actor: Actor) -> Result<(), InfraError> {
+        let revision = match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
             .entry(new_video.id().to_uuid())
         {
-            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Vacant(_) => return Err(InfraError::NoRecordError),
             Entry::Occupied(mut o) => {
+                // 版数が一致しない場合は他の編集が行われている
+                if o.get().version() != new_video.version() {
+                    return Err(InfraError::StaleVersion);
+                }
+                let mut new_video = new_video;
+                new_video.increment_version();
+                let revision = Revision::new(
+                    EntityKind::video::<T>(),
+                    new_video.id().to_uuid(),
+                    Operation::Edit,
+                    &new_video,
+                    actor,
+                )?;
                 o.insert(new_video);
+                revision
+            }
+        };
+        self.revisions.push(revision)
+    }
+    async fn increment_like(&self, id: VideoId, client: ClientId) -> Result<(), InfraError> {
+        match self
+            .map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .entry(id.to_uuid())
+        {
+            Entry::Vacant(_) => Err(InfraError::NoRecordError),
+            Entry::Occupied(mut o) => {
+                // 既にLikeしている場合は何もしない
+                if self
+                    .likes
+                    .lock()
+                    .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+                    .insert((id.to_uuid(), client.to_uuid()))
+                {
+                    o.get_mut().increment_like();
+                }
                 Ok(())
             }
         }
     }
-    async fn increment_like(&self, id: VideoId) -> Result<(), InfraError> {
+    async fn unlike(&self, id: VideoId, client: ClientId) -> Result<(), InfraError> {
         match self
             .map
             .lock()
@@ -63,11 +248,38 @@ impl<T: VideoType> VideoRepository<T> for InMemoryVideoRepository<T> {
         {
             Entry::Vacant(_) => Err(InfraError::NoRecordError),
             Entry::Occupied(mut o) => {
-                o.get_mut().increment_like();
+                // Likeしていない場合は何もしない
+                if self
+                    .likes
+                    .lock()
+                    .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+                    .remove(&(id.to_uuid(), client.to_uuid()))
+                {
+                    o.get_mut().decrement_like();
+                }
                 Ok(())
             }
         }
     }
+    async fn liked_ids(
+        &self,
+        ids: &[VideoId],
+        client: ClientId,
+    ) -> Result<Vec<VideoId>, InfraError> {
+        let live_ids = self.live_ids()?;
+        let likes = self
+            .likes
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?;
+        Ok(ids
+            .iter()
+            .filter(|id| {
+                live_ids.contains(&id.to_uuid())
+                    && likes.contains(&(id.to_uuid(), client.to_uuid()))
+            })
+            .cloned()
+            .collect::<Vec<_>>())
+    }
     async fn all(&self) -> Result<Vec<Video<T>>, InfraError> {
         let videos = self
             .map
@@ -87,7 +299,7 @@ impl<T: VideoType> VideoRepository<T> for InMemoryVideoRepository<T> {
     }
     async fn order_by_date_later(
         &self,
-        reference: &Video<T>,
+        cursor: &Cursor<DateTime, VideoId>,
         length: usize,
     ) -> Result<Vec<Video<T>>, InfraError> {
         let mut clips = self.all().await?;
@@ -95,8 +307,8 @@ impl<T: VideoType> VideoRepository<T> for InMemoryVideoRepository<T> {
         let clips = clips
             .into_iter()
             .filter(|clip| {
-                reference.date() > clip.date()
-                    || (reference.date() == clip.date() && reference.id() < clip.id())
+                cursor.key() > clip.date()
+                    || (cursor.key() == clip.date() && cursor.id() < clip.id())
             })
             .take(length)
             .collect::<Vec<_>>();
@@ -110,7 +322,7 @@ impl<T: VideoType> VideoRepository<T> for InMemoryVideoRepository<T> {
     }
     async fn order_by_like_later(
         &self,
-        reference: &Video<T>,
+        cursor: &Cursor<u32, VideoId>,
         length: usize,
     ) -> Result<Vec<Video<T>>, InfraError> {
         let mut clips = self.all().await?;
@@ -118,23 +330,217 @@ impl<T: VideoType> VideoRepository<T> for InMemoryVideoRepository<T> {
         let clips = clips
             .into_iter()
             .filter(|clip| {
-                reference.like() > clip.like()
-                    || (reference.like() == clip.like() && reference.id() < clip.id())
+                cursor.key() > clip.like()
+                    || (cursor.key() == clip.like() && cursor.id() < clip.id())
             })
             .take(length)
             .collect::<Vec<_>>();
         Ok(clips)
     }
-    async fn remove(&self, id: VideoId) -> Result<(), InfraError> {
-        match self
+    async fn order_by_date_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut videos = self.all_with_tags(tags).await?;
+        videos.sort_by(|x, y| y.date().cmp(&x.date()).then_with(|| x.id().cmp(&y.id())));
+        Ok(videos.into_iter().take(length).collect::<Vec<_>>())
+    }
+    async fn order_by_date_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<DateTime, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut videos = self.all_with_tags(tags).await?;
+        videos.sort_by(|x, y| y.date().cmp(&x.date()).then_with(|| x.id().cmp(&y.id())));
+        Ok(videos
+            .into_iter()
+            .filter(|video| {
+                cursor.key() > video.date()
+                    || (cursor.key() == video.date() && cursor.id() < video.id())
+            })
+            .take(length)
+            .collect::<Vec<_>>())
+    }
+    async fn order_by_date_with_channel(
+        &self,
+        channel: ChannelId,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut videos = self
+            .all()
+            .await?
+            .into_iter()
+            .filter(|video| video.channel_id() == channel)
+            .collect::<Vec<_>>();
+        videos.sort_by(|x, y| y.date().cmp(&x.date()).then_with(|| x.id().cmp(&y.id())));
+        Ok(videos)
+    }
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        length: usize,
+    ) -> Result<Vec<SearchHit<Video<T>>>, InfraError> {
+        let mut hits = self
+            .all()
+            .await?
+            .into_iter()
+            .filter_map(|video| {
+                let score = query.score(&[video.title(), video.author()])?;
+                Some(SearchHit::new(video, score))
+            })
+            .collect::<Vec<_>>();
+        hits.sort_by(|x, y| {
+            y.score
+                .total_cmp(&x.score)
+                .then_with(|| x.item.id().cmp(&y.item.id()))
+        });
+        Ok(hits.into_iter().take(length).collect::<Vec<_>>())
+    }
+    async fn order_by_like_with_tags(
+        &self,
+        tags: &[Tag],
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut videos = self.all_with_tags(tags).await?;
+        videos.sort_by(|x, y| y.like().cmp(&x.like()).then_with(|| x.id().cmp(&y.id())));
+        Ok(videos.into_iter().take(length).collect::<Vec<_>>())
+    }
+    async fn order_by_like_later_with_tags(
+        &self,
+        tags: &[Tag],
+        cursor: &Cursor<u32, VideoId>,
+        length: usize,
+    ) -> Result<Vec<Video<T>>, InfraError> {
+        let mut videos = self.all_with_tags(tags).await?;
+        videos.sort_by(|x, y| y.like().cmp(&x.like()).then_with(|| x.id().cmp(&y.id())));
+        Ok(videos
+            .into_iter()
+            .filter(|video| {
+                cursor.key() > video.like()
+                    || (cursor.key() == video.like() && cursor.id() < video.id())
+            })
+            .take(length)
+            .collect::<Vec<_>>())
+    }
+    async fn all_tags(&self) -> Result<Vec<Tag>, InfraError> {
+        let live_ids = self.live_ids()?;
+        let mut tags = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .filter(|(tagged_id, _)| live_ids.contains(tagged_id))
+            .map(|(_, tag)| tag.clone())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.dedup();
+        Ok(tags)
+    }
+    async fn tags(&self, id: VideoId) -> Result<Vec<Tag>, InfraError> {
+        self.check_exists(id)?;
+        let mut tags = self
+            .tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .filter(|(tagged_id, _)| *tagged_id == id.to_uuid())
+            .map(|(_, tag)| tag.clone())
+            .collect::<Vec<_>>();
+        tags.sort();
+        Ok(tags)
+    }
+    async fn add_tag(&self, id: VideoId, tag: Tag) -> Result<(), InfraError> {
+        self.check_exists(id)?;
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .insert((id.to_uuid(), tag));
+        Ok(())
+    }
+    async fn remove_tag(&self, id: VideoId, tag: Tag) -> Result<(), InfraError> {
+        self.check_exists(id)?;
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .remove(&(id.to_uuid(), tag));
+        Ok(())
+    }
+    async fn remove(&self, id: VideoId, actor: Actor) -> Result<(), InfraError> {
+        let removed_video = match self
             .map
             .lock()
             .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
             .remove(&id.to_uuid())
         {
-            None => Err(InfraError::NoRecordError),
-            Some(_) => Ok(()),
-        }
+            None => return Err(InfraError::NoRecordError),
+            Some(removed_video) => removed_video,
+        };
+        let revision = Revision::new(
+            EntityKind::video::<T>(),
+            id.to_uuid(),
+            Operation::Remove,
+            &removed_video,
+            actor,
+        )?;
+        self.trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .push(removed_video);
+        self.revisions.push(revision)
+    }
+    async fn trashed(&self) -> Result<Vec<Video<T>>, InfraError> {
+        Ok(self
+            .trash
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .iter()
+            .rev()
+            .cloned()
+            .collect())
+    }
+    async fn restore(&self, id: VideoId, actor: Actor) -> Result<(), InfraError> {
+        let restored_video = self.take_trashed(id)?;
+        let revision = Revision::new(
+            EntityKind::video::<T>(),
+            id.to_uuid(),
+            Operation::Restore,
+            &restored_video,
+            actor,
+        )?;
+        self.map
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .insert(id.to_uuid(), restored_video);
+        self.revisions.push(revision)
+    }
+    async fn purge(&self, id: VideoId, actor: Actor) -> Result<(), InfraError> {
+        let purged_video = self.take_trashed(id)?;
+        // 台帳からも削除する
+        self.likes
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .retain(|(liked_id, _)| *liked_id != id.to_uuid());
+        self.tags
+            .lock()
+            .map_err(|e| InfraError::OtherSQLXError(format!("Inmemory mutex error.{e}")))?
+            .retain(|(tagged_id, _)| *tagged_id != id.to_uuid());
+        self.revisions.push(Revision::new(
+            EntityKind::video::<T>(),
+            id.to_uuid(),
+            Operation::Purge,
+            &purged_video,
+            actor,
+        )?)
+    }
+    async fn revisions(&self, id: VideoId) -> Result<Vec<Revision>, InfraError> {
+        self.revisions.of_entity(id.to_uuid())
+    }
+    async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, InfraError> {
+        self.revisions.latest(length)
+    }
+    async fn revision(&self, id: RevisionId) -> Result<Revision, InfraError> {
+        self.revisions.find(id)
     }
 }
 
@@ -144,11 +550,18 @@ mod test {
     use crate::video_repository_impl::assert_video::{
         videos_assert_eq, videos_assert_eq_with_sort_by_key_and_filter,
     };
+    use crate::video_repository_impl::video_repository_contract::video_repository_contract;
     use crate::InfraError;
-    use domain::video::{Original, Video};
-    use domain::VideoRepository;
+    use domain::channel::{Channel, ChannelId};
+    use domain::client::ClientId;
+    use domain::revision::{Actor, Operation, RevisionId};
+    use domain::search::SearchQuery;
+    use domain::user::UserId;
+    use domain::video::{Kirinuki, Original, Video, VideoId};
+    use domain::{Tag, VideoRepository};
 
     use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
     use rand::seq::SliceRandom;
     use rand::Rng;
     use rstest::{fixture, rstest};
@@ -170,7 +583,7 @@ mod test {
         let repo = InMemoryVideoRepository::<Original>::new();
 
         for original in originals.iter().cloned() {
-            repo.save(original).await?;
+            repo.save(original, Actor::Admin).await?;
         }
 
         let mut originals_res = repo.all().await?;
@@ -190,14 +603,15 @@ mod test {
         let repo = InMemoryVideoRepository::<Original>::new();
 
         for original in originals.iter().cloned() {
-            repo.save(original).await?;
+            repo.save(original, Actor::Admin).await?;
         }
 
         // originalsの一部を編集．
         for _ in 0..(originals.len() / 2) {
             let edited_original = originals.choose_mut(&mut rand::thread_rng()).unwrap();
             edited_original.assign(Faker.fake());
-            repo.edit(edited_original.clone()).await?;
+            repo.edit(edited_original.clone(), Actor::Admin).await?;
+            edited_original.increment_version();
         }
 
         let mut originals_res = repo.all().await?;
@@ -216,14 +630,15 @@ mod test {
         let repo = InMemoryVideoRepository::<Original>::new();
 
         for original in originals.iter().cloned() {
-            repo.save(original).await?;
+            repo.save(original, Actor::Admin).await?;
         }
 
         // originalsの一部をincrement_like
         for _ in 0..(originals.len() / 2) {
             let incremented_original = originals.choose_mut(&mut rand::thread_rng()).unwrap();
             incremented_original.increment_like();
-            repo.increment_like(incremented_original.id()).await?;
+            repo.increment_like(incremented_original.id(), ClientId::generate())
+                .await?;
         }
 
         let mut originals_res = repo.all().await?;
@@ -232,6 +647,56 @@ mod test {
         Ok(())
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_increment_like_and_unlike_per_client() -> Result<(), InfraError> {
+        let original = Faker.fake::<Video<Original>>();
+        let other_original = Faker.fake::<Video<Original>>();
+        let (client, other_client) = (ClientId::generate(), ClientId::generate());
+
+        let repo = InMemoryVideoRepository::<Original>::new();
+        repo.save(original.clone(), Actor::Admin).await?;
+        repo.save(other_original.clone(), Actor::Admin).await?;
+
+        let like_of = |originals: Vec<Video<Original>>| {
+            originals
+                .into_iter()
+                .find(|res_original| res_original.id() == original.id())
+                .unwrap()
+                .like()
+        };
+
+        // 同じクライアントのLikeは一度だけ数える
+        repo.increment_like(original.id(), client).await?;
+        repo.increment_like(original.id(), client).await?;
+        assert_eq!(like_of(repo.all().await?), original.like() + 1);
+
+        repo.increment_like(original.id(), other_client).await?;
+        assert_eq!(like_of(repo.all().await?), original.like() + 2);
+
+        let liked_ids = repo
+            .liked_ids(&[original.id(), other_original.id()], client)
+            .await?;
+        assert_eq!(liked_ids, vec![original.id()]);
+
+        // unlikeもクライアントごとに一度だけ
+        repo.unlike(original.id(), client).await?;
+        repo.unlike(original.id(), client).await?;
+        assert_eq!(like_of(repo.all().await?), original.like() + 1);
+
+        let liked_ids = repo
+            .liked_ids(&[original.id(), other_original.id()], client)
+            .await?;
+        assert_eq!(liked_ids, Vec::<VideoId>::new());
+
+        // ゴミ箱のVideoは台帳から除かれる
+        repo.remove(original.id(), Actor::Admin).await?;
+        let liked_ids = repo.liked_ids(&[original.id()], other_client).await?;
+        assert_eq!(liked_ids, Vec::<VideoId>::new());
+
+        Ok(())
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_video_save_and_order_by_like_and_all(
@@ -242,7 +707,7 @@ mod test {
         let repo = InMemoryVideoRepository::<Original>::new();
 
         for original in originals.iter().cloned() {
-            repo.save(original).await?;
+            repo.save(original, Actor::Admin).await?;
         }
 
         let length = originals.len() / 2;
@@ -271,7 +736,7 @@ mod test {
         let repo = InMemoryVideoRepository::<Original>::new();
 
         for original in originals.iter().cloned() {
-            repo.save(original).await?;
+            repo.save(original, Actor::Admin).await?;
         }
 
         let length = originals.len() / 2;
@@ -282,7 +747,9 @@ mod test {
             originals[reference_index].clone()
         };
 
-        let mut originals_res = repo.order_by_like_later(&reference, length).await?;
+        let mut originals_res = repo
+            .order_by_like_later(&reference.like_cursor(), length)
+            .await?;
 
         // originalsをlike(降順)・idの順に並べ，フィルタリングして比較
         videos_assert_eq_with_sort_by_key_and_filter(
@@ -309,7 +776,7 @@ mod test {
         let repo = InMemoryVideoRepository::<Original>::new();
 
         for original in originals.iter().cloned() {
-            repo.save(original).await?;
+            repo.save(original, Actor::Admin).await?;
         }
 
         let length = originals.len() / 2;
@@ -338,7 +805,7 @@ mod test {
         let repo = InMemoryVideoRepository::<Original>::new();
 
         for original in originals.iter().cloned() {
-            repo.save(original).await?;
+            repo.save(original, Actor::Admin).await?;
         }
 
         let length = originals.len() / 2;
@@ -349,7 +816,9 @@ mod test {
             originals[reference_index].clone()
         };
 
-        let mut originals_res = repo.order_by_date_later(&reference, length).await?;
+        let mut originals_res = repo
+            .order_by_date_later(&reference.date_cursor(), length)
+            .await?;
 
         // originalsをdate(降順)・idの順に並べ，フィルタリング
         videos_assert_eq_with_sort_by_key_and_filter(
@@ -376,7 +845,7 @@ mod test {
         let repo = InMemoryVideoRepository::<Original>::new();
 
         for original in originals.iter().cloned() {
-            repo.save(original).await?;
+            repo.save(original, Actor::Admin).await?;
         }
 
         // originalsの一部を削除
@@ -397,7 +866,7 @@ mod test {
             .collect::<Vec<_>>();
 
         for original in removed_originals.into_iter() {
-            repo.remove(original.id()).await?
+            repo.remove(original.id(), Actor::Admin).await?
         }
 
         let mut originals_res = repo.all().await?;
@@ -414,10 +883,174 @@ mod test {
 
         let original = Faker.fake::<Video<Original>>();
 
-        let res = repo.edit(original).await;
+        let res = repo.edit(original, Actor::Admin).await;
+
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_edit_stale_version() -> Result<(), InfraError> {
+        let repo = InMemoryVideoRepository::<Original>::new();
+
+        let mut original = Faker.fake::<Video<Original>>();
+        repo.save(original.clone(), Actor::Admin).await?;
+
+        // 同じ版数からの編集は先の一つのみ成功する
+        let stale_original = original.clone();
+        original.assign(Faker.fake());
+        repo.edit(original.clone(), Actor::Admin).await?;
+
+        let res = repo.edit(stale_original, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)));
+
+        // 版数が一つ増えている
+        original.increment_version();
+        let res = repo.all().await?;
+        assert_eq!(res, vec![original]);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_increment_like_and_unlike_no_exists() -> Result<(), InfraError> {
+        let repo = InMemoryVideoRepository::<Original>::new();
+
+        let res = repo
+            .increment_like(VideoId::generate(), ClientId::generate())
+            .await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.unlike(VideoId::generate(), ClientId::generate()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_add_tag_and_order_with_tags(
+        original_videos: Result<Vec<Video<Original>>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let originals = original_videos?;
+
+        let repo = InMemoryVideoRepository::<Original>::new();
+
+        for original in originals.iter().cloned() {
+            repo.save(original, Actor::Admin).await?;
+        }
+
+        let singing = Tag::new("Singing")?;
+
+        // 偶数番目にsingingを付ける
+        for original in originals.iter().step_by(2) {
+            repo.add_tag(original.id(), singing.clone()).await?;
+        }
+        repo.add_tag(originals[0].id(), singing.clone()).await?;
+
+        assert_eq!(repo.tags(originals[0].id()).await?, vec![singing.clone()]);
+        assert_eq!(repo.all_tags().await?, vec![singing.clone()]);
+
+        let tagged_ids = originals
+            .iter()
+            .step_by(2)
+            .map(|original| original.id())
+            .collect::<Vec<_>>();
+
+        let length = originals.len() / 4;
+        let mut originals_res = repo
+            .order_by_date_with_tags(std::slice::from_ref(&singing), length)
+            .await?;
+
+        videos_assert_eq_with_sort_by_key_and_filter(
+            &mut originals_res,
+            &mut originals.clone(),
+            |x, y| y.date().cmp(&x.date()),
+            Some(|original: &Video<Original>| tagged_ids.contains(&original.id())),
+            Some(length),
+        );
+
+        // referenceとなるvideoを取得
+        let reference = originals[0].clone();
+        let mut originals_res = repo
+            .order_by_like_later_with_tags(
+                std::slice::from_ref(&singing),
+                &reference.like_cursor(),
+                length,
+            )
+            .await?;
+
+        videos_assert_eq_with_sort_by_key_and_filter(
+            &mut originals_res,
+            &mut originals.clone(),
+            |x, y| y.like().cmp(&x.like()),
+            Some(|original: &Video<Original>| {
+                tagged_ids.contains(&original.id())
+                    && (original.like() < reference.like()
+                        || (original.like() == reference.like() && original.id() > reference.id()))
+            }),
+            Some(length),
+        );
+
+        // タグを外す
+        repo.remove_tag(originals[0].id(), singing.clone()).await?;
+        assert_eq!(repo.tags(originals[0].id()).await?, Vec::<Tag>::new());
 
+        // ゴミ箱のVideoのタグは取得できない
+        repo.remove(originals[2].id(), Actor::Admin).await?;
+        let res = repo.tags(originals[2].id()).await;
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
+        let res = repo.add_tag(VideoId::generate(), singing.clone()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let res = repo.remove_tag(VideoId::generate(), singing).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_order_by_date_with_channel(
+        original_videos: Result<Vec<Video<Original>>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let (channel, other_channel) = (Faker.fake::<Channel>(), Faker.fake::<Channel>());
+
+        // 3つおきにchannel，それ以外の奇数番目にother_channelを設定
+        let mut originals = original_videos?;
+        for (i, original) in originals.iter_mut().enumerate() {
+            match (i % 3, i % 2) {
+                (0, _) => original.set_channel(&channel),
+                (_, 1) => original.set_channel(&other_channel),
+                _ => {}
+            }
+        }
+
+        let repo = InMemoryVideoRepository::<Original>::new();
+        for original in originals.iter().cloned() {
+            repo.save(original, Actor::Admin).await?;
+        }
+
+        let originals_res = repo.order_by_date_with_channel(channel.id()).await?;
+
+        let mut expected = originals
+            .iter()
+            .filter(|original| original.channel_id() == channel.id())
+            .cloned()
+            .collect::<Vec<_>>();
+        expected.sort_by(|x, y| y.date().cmp(&x.date()).then_with(|| x.id().cmp(&y.id())));
+        assert_eq!(originals_res, expected);
+
+        // 動画の無いチャンネル
+        let originals_res = repo
+            .order_by_date_with_channel(ChannelId::generate())
+            .await?;
+        assert_eq!(originals_res, Vec::<Video<Original>>::new());
+
         Ok(())
     }
 
@@ -428,10 +1061,182 @@ mod test {
 
         let original = Faker.fake::<Video<Original>>();
 
-        let res = repo.remove(original.id()).await;
+        let res = repo.remove(original.id(), Actor::Admin).await;
+
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_search(
+        original_videos: Result<Vec<Video<Original>>, InfraError>,
+    ) -> Result<(), InfraError> {
+        let mut originals = original_videos?;
+
+        // タイトルまたは投稿者に検索する語を含める
+        for original in originals.iter_mut().take(10) {
+            *original.title_mut() = format!("{} 歌枠", original.title());
+        }
+        let relay_channel = Channel::new("歌枠リレー".parse().unwrap(), None, None);
+        for original in originals.iter_mut().skip(10).take(5) {
+            original.set_channel(&relay_channel);
+        }
+
+        let repo = InMemoryVideoRepository::<Original>::new();
+        for original in originals.iter().cloned() {
+            repo.save(original, Actor::Admin).await?;
+        }
+
+        let query = SearchQuery::new("歌枠").unwrap();
+        let hits = repo.search(&query, 100).await?;
+
+        // スコアの降順．短い投稿者に含まれる方がスコアが高い
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+        assert_eq!(hits[0].item.author(), "歌枠リレー");
+
+        let mut originals_res = hits.into_iter().map(|hit| hit.item).collect::<Vec<_>>();
+        videos_assert_eq(&mut originals_res, &mut originals[..15].to_vec());
+
+        // lengthを超えない
+        assert_eq!(repo.search(&query, 3).await?.len(), 3);
+
+        Ok(())
+    }
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_revisions() -> Result<(), InfraError> {
+        let repo = InMemoryVideoRepository::<Original>::new();
+
+        let mut original = Faker.fake::<Video<Original>>();
+        let user_id = UserId::generate();
+        repo.save(original.clone(), Actor::User(user_id)).await?;
+
+        let saved_original = original.clone();
+        original.assign(Faker.fake());
+        repo.edit(original.clone(), Actor::Admin).await?;
+        original.increment_version();
+
+        repo.remove(original.id(), Actor::Anonymous).await?;
+
+        // 保存・編集・削除の順に残る
+        let revisions = repo.revisions(original.id()).await?;
+        assert_eq!(
+            revisions
+                .iter()
+                .map(|revision| (revision.operation(), revision.actor()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Operation::Save, Actor::User(user_id)),
+                (Operation::Edit, Actor::Admin),
+                (Operation::Remove, Actor::Anonymous),
+            ]
+        );
+        assert_eq!(
+            revisions[0].snapshot_as::<Video<Original>>()?,
+            saved_original
+        );
+        assert_eq!(revisions[1].snapshot_as::<Video<Original>>()?, original);
+        assert_eq!(revisions[2].snapshot_as::<Video<Original>>()?, original);
+
+        // 新しい順に取得できる
+        let latest = repo.latest_revisions(2).await?;
+        assert_eq!(latest, vec![revisions[2].clone(), revisions[1].clone()]);
+
+        assert_eq!(repo.revision(revisions[0].id()).await?, revisions[0]);
+        let res = repo.revision(RevisionId::generate()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_video_trash() -> Result<(), InfraError> {
+        let repo = InMemoryVideoRepository::<Original>::new();
+
+        let mut originals = (0..3)
+            .map(|_| Faker.fake::<Video<Original>>())
+            .collect::<Vec<_>>();
+        originals.sort_by_key(|original| original.id());
+        for original in originals.iter().cloned() {
+            repo.save(original, Actor::Admin).await?;
+        }
+        let tag = Tag::new("歌枠")?;
+        repo.add_tag(originals[0].id(), tag.clone()).await?;
+        let client = ClientId::generate();
+        repo.increment_like(originals[0].id(), client).await?;
+        originals[0].increment_like();
+
+        // ゴミ箱に移したものは通常の取得の対象とならない
+        repo.remove(originals[0].id(), Actor::Admin).await?;
+        repo.remove(originals[1].id(), Actor::Admin).await?;
+        assert_eq!(repo.all().await?, vec![originals[2].clone()]);
+        assert_eq!(repo.all_tags().await?, Vec::<Tag>::new());
+        let liked_ids = repo.liked_ids(&[originals[0].id()], client).await?;
+        assert_eq!(liked_ids, Vec::<VideoId>::new());
+
+        // 移した日時の新しい順
+        assert_eq!(
+            repo.trashed().await?,
+            vec![originals[1].clone(), originals[0].clone()]
+        );
 
+        // ゴミ箱のものは編集できず，同じidでは保存できない
+        let res = repo.edit(originals[0].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = repo.save(originals[0].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)));
+
+        // ゴミ箱から戻すとタグも戻る
+        repo.restore(originals[0].id(), Actor::Admin).await?;
+        let mut res = repo.all().await?;
+        res.sort_by_key(|original| original.id());
+        assert_eq!(res, vec![originals[0].clone(), originals[2].clone()]);
+        assert_eq!(repo.tags(originals[0].id()).await?, vec![tag]);
+        let liked_ids = repo.liked_ids(&[originals[0].id()], client).await?;
+        assert_eq!(liked_ids, vec![originals[0].id()]);
+
+        // ゴミ箱に無いものは戻せず，完全に削除もできない
+        let res = repo.restore(originals[2].id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+        let res = repo.purge(originals[2].id(), Actor::Admin).await;
         assert!(matches!(res, Err(InfraError::NoRecordError)));
 
+        // 完全に削除したものは戻せない
+        repo.purge(originals[1].id(), Actor::Admin).await?;
+        assert_eq!(repo.trashed().await?, Vec::<Video<Original>>::new());
+        let res = repo.restore(originals[1].id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)));
+
+        let operations = repo
+            .revisions(originals[1].id())
+            .await?
+            .iter()
+            .map(|revision| revision.operation())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operations,
+            vec![Operation::Save, Operation::Remove, Operation::Purge]
+        );
+        let operations = repo
+            .revisions(originals[0].id())
+            .await?
+            .iter()
+            .map(|revision| revision.operation())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operations,
+            vec![Operation::Save, Operation::Remove, Operation::Restore]
+        );
+
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_video_repository_contract() -> Result<(), InfraError> {
+        let channel = Faker.fake::<Channel>();
+        video_repository_contract(&InMemoryVideoRepository::<Original>::new(), &channel).await?;
+        video_repository_contract(&InMemoryVideoRepository::<Kirinuki>::new(), &channel).await
+    }
 }
@@ -1,7 +1,11 @@
 use crate::InfraError;
 use async_trait::async_trait;
+use domain::channel::ChannelId;
+use domain::client::ClientId;
+use domain::revision::{Actor, Revision, RevisionId};
+use domain::search::{SearchHit, SearchQuery};
 use domain::video::{Kirinuki, Original, Video, VideoId};
-use domain::VideoRepository;
+use domain::{Cursor, DateTime, Tag, VideoRepository};
 
 use mockall::mock;
 
@@ -13,9 +17,12 @@ mock! {
     #[async_trait]
     impl VideoRepository<Original> for VideoOriginalRepository {
         type Error = InfraError;
-        async fn save(&self, video: Video<Original>) -> Result<(), InfraError>;
-        async fn edit(&self, new_video: Video<Original>) -> Result<(), InfraError>;
-        async fn increment_like(&self, id: VideoId) -> Result<(), InfraError>;
+        async fn save(&self, video: Video<Original>, actor: Actor) -> Result<(), InfraError>;
+        async fn save_many(&self, videos: Vec<Video<Original>>, actor: Actor) -> Result<(), InfraError>;
+        async fn edit(&self, new_video: Video<Original>, actor: Actor) -> Result<(), InfraError>;
+        async fn increment_like(&self, id: VideoId, client: ClientId) -> Result<(), InfraError>;
+        async fn unlike(&self, id: VideoId, client: ClientId) -> Result<(), InfraError>;
+        async fn liked_ids(&self, ids: &[VideoId], client: ClientId) -> Result<Vec<VideoId>, InfraError>;
         async fn all(&self) -> Result<Vec<Video<Original>>,InfraError>;
         async fn order_by_date(
             &self,
@@ -23,7 +30,7 @@ mock! {
         ) -> Result<Vec<Video<Original>>, InfraError>;
         async fn order_by_date_later(
             &self,
-            reference: &Video<Original>,
+            cursor: &Cursor<DateTime, VideoId>,
             length: usize,
         ) -> Result<Vec<Video<Original>>, InfraError>;
         async fn order_by_like(
@@ -32,10 +39,51 @@ mock! {
         ) -> Result<Vec<Video<Original>>, InfraError>;
         async fn order_by_like_later(
             &self,
-            reference: &Video<Original>,
+            cursor: &Cursor<u32, VideoId>,
             length: usize,
         ) -> Result<Vec<Video<Original>>, InfraError>;
-        async fn remove(&self, id: VideoId) -> Result<(), InfraError>;
+        async fn order_by_date_with_tags(
+            &self,
+            tags: &[Tag],
+            length: usize,
+        ) -> Result<Vec<Video<Original>>, InfraError>;
+        async fn order_by_date_later_with_tags(
+            &self,
+            tags: &[Tag],
+            cursor: &Cursor<DateTime, VideoId>,
+            length: usize,
+        ) -> Result<Vec<Video<Original>>, InfraError>;
+        async fn order_by_like_with_tags(
+            &self,
+            tags: &[Tag],
+            length: usize,
+        ) -> Result<Vec<Video<Original>>, InfraError>;
+        async fn order_by_like_later_with_tags(
+            &self,
+            tags: &[Tag],
+            cursor: &Cursor<u32, VideoId>,
+            length: usize,
+        ) -> Result<Vec<Video<Original>>, InfraError>;
+        async fn order_by_date_with_channel(
+            &self,
+            channel: ChannelId,
+        ) -> Result<Vec<Video<Original>>, InfraError>;
+        async fn search(
+            &self,
+            query: &SearchQuery,
+            length: usize,
+        ) -> Result<Vec<SearchHit<Video<Original>>>, InfraError>;
+        async fn all_tags(&self) -> Result<Vec<Tag>, InfraError>;
+        async fn tags(&self, id: VideoId) -> Result<Vec<Tag>, InfraError>;
+        async fn add_tag(&self, id: VideoId, tag: Tag) -> Result<(), InfraError>;
+        async fn remove_tag(&self, id: VideoId, tag: Tag) -> Result<(), InfraError>;
+        async fn remove(&self, id: VideoId, actor: Actor) -> Result<(), InfraError>;
+        async fn trashed(&self) -> Result<Vec<Video<Original>>, InfraError>;
+        async fn restore(&self, id: VideoId, actor: Actor) -> Result<(), InfraError>;
+        async fn purge(&self, id: VideoId, actor: Actor) -> Result<(), InfraError>;
+        async fn revisions(&self, id: VideoId) -> Result<Vec<Revision>, InfraError>;
+        async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, InfraError>;
+        async fn revision(&self, id: RevisionId) -> Result<Revision, InfraError>;
         }
 }
 
@@ -46,9 +94,12 @@ mock! {
     #[async_trait]
     impl VideoRepository<Kirinuki> for VideoKirinukiRepository {
         type Error = InfraError;
-        async fn save(&self, video: Video<Kirinuki>) -> Result<(), InfraError>;
-        async fn edit(&self, new_video: Video<Kirinuki>) -> Result<(), InfraError>;
-        async fn increment_like(&self, id: VideoId) -> Result<(), InfraError>;
+        async fn save(&self, video: Video<Kirinuki>, actor: Actor) -> Result<(), InfraError>;
+        async fn save_many(&self, videos: Vec<Video<Kirinuki>>, actor: Actor) -> Result<(), InfraError>;
+        async fn edit(&self, new_video: Video<Kirinuki>, actor: Actor) -> Result<(), InfraError>;
+        async fn increment_like(&self, id: VideoId, client: ClientId) -> Result<(), InfraError>;
+        async fn unlike(&self, id: VideoId, client: ClientId) -> Result<(), InfraError>;
+        async fn liked_ids(&self, ids: &[VideoId], client: ClientId) -> Result<Vec<VideoId>, InfraError>;
         async fn all(&self) -> Result<Vec<Video<Kirinuki>>,InfraError>;
         async fn order_by_date(
             &self,
@@ -56,7 +107,7 @@ mock! {
         ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
         async fn order_by_date_later(
             &self,
-            reference: &Video<Kirinuki>,
+            cursor: &Cursor<DateTime, VideoId>,
             length: usize,
         ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
         async fn order_by_like(
@@ -65,9 +116,50 @@ mock! {
         ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
         async fn order_by_like_later(
             &self,
-            reference: &Video<Kirinuki>,
+            cursor: &Cursor<u32, VideoId>,
             length: usize,
         ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
-        async fn remove(&self, id: VideoId) -> Result<(), InfraError>;
+        async fn order_by_date_with_tags(
+            &self,
+            tags: &[Tag],
+            length: usize,
+        ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
+        async fn order_by_date_later_with_tags(
+            &self,
+            tags: &[Tag],
+            cursor: &Cursor<DateTime, VideoId>,
+            length: usize,
+        ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
+        async fn order_by_like_with_tags(
+            &self,
+            tags: &[Tag],
+            length: usize,
+        ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
+        async fn order_by_like_later_with_tags(
+            &self,
+            tags: &[Tag],
+            cursor: &Cursor<u32, VideoId>,
+            length: usize,
+        ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
+        async fn order_by_date_with_channel(
+            &self,
+            channel: ChannelId,
+        ) -> Result<Vec<Video<Kirinuki>>, InfraError>;
+        async fn search(
+            &self,
+            query: &SearchQuery,
+            length: usize,
+        ) -> Result<Vec<SearchHit<Video<Kirinuki>>>, InfraError>;
+        async fn all_tags(&self) -> Result<Vec<Tag>, InfraError>;
+        async fn tags(&self, id: VideoId) -> Result<Vec<Tag>, InfraError>;
+        async fn add_tag(&self, id: VideoId, tag: Tag) -> Result<(), InfraError>;
+        async fn remove_tag(&self, id: VideoId, tag: Tag) -> Result<(), InfraError>;
+        async fn remove(&self, id: VideoId, actor: Actor) -> Result<(), InfraError>;
+        async fn trashed(&self) -> Result<Vec<Video<Kirinuki>>, InfraError>;
+        async fn restore(&self, id: VideoId, actor: Actor) -> Result<(), InfraError>;
+        async fn purge(&self, id: VideoId, actor: Actor) -> Result<(), InfraError>;
+        async fn revisions(&self, id: VideoId) -> Result<Vec<Revision>, InfraError>;
+        async fn latest_revisions(&self, length: usize) -> Result<Vec<Revision>, InfraError>;
+        async fn revision(&self, id: RevisionId) -> Result<Revision, InfraError>;
         }
 }
@@ -0,0 +1,256 @@
+use crate::InfraError;
+use domain::channel::Channel;
+use domain::client::ClientId;
+use domain::revision::Actor;
+use domain::video::{Video, VideoId, VideoType};
+use domain::{DateTime, Tag, VideoRepository};
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use std::cmp::Ordering;
+
+/// likeの降順，同じ場合はidの昇順
+fn like_order<T: VideoType>(x: &Video<T>, y: &Video<T>) -> Ordering {
+    y.like().cmp(&x.like()).then_with(|| x.id().cmp(&y.id()))
+}
+
+/// dateの降順，同じ場合はidの昇順
+fn date_order<T: VideoType>(x: &Video<T>, y: &Video<T>) -> Ordering {
+    y.date().cmp(&x.date()).then_with(|| x.id().cmp(&y.id()))
+}
+
+/// `channel`のフェイクのVideo<T>
+fn fake_video<T: VideoType>(channel: &Channel) -> Video<T> {
+    let mut video = Faker.fake::<Video<T>>();
+    video.set_channel(channel);
+    video
+}
+
+/// likeとdateが重複するものを含む`channel`のフェイクのVideo<T>
+fn fake_videos<T: VideoType>(channel: &Channel) -> Vec<Video<T>> {
+    let dates = (0..3).map(|_| Faker.fake::<DateTime>()).collect::<Vec<_>>();
+
+    let mut videos = (0..20)
+        .map(|_| fake_video::<T>(channel))
+        .collect::<Vec<_>>();
+    videos.extend((0..20).map(|i| {
+        let fake_video = Faker.fake::<Video<T>>();
+        let mut video = Video::new_with_domains(
+            fake_video.title().to_string(),
+            fake_video.url().clone(),
+            dates[i % dates.len()],
+            channel,
+        );
+        for _ in 0..(i % 3) {
+            video.increment_like();
+        }
+        video
+    }));
+    videos
+}
+
+/// `videos`から`ids`をもつVideo<T>のみを順番を保って取り出す．
+fn own_videos<T: VideoType>(videos: &[Video<T>], ids: &[VideoId]) -> Vec<Video<T>> {
+    videos
+        .iter()
+        .filter(|video| ids.contains(&video.id()))
+        .cloned()
+        .collect()
+}
+
+/// `length`ずつ`order_by_like_later`で辿ったVideo<T>
+async fn walk_order_by_like<T, R>(repo: &R, length: usize) -> Result<Vec<Video<T>>, InfraError>
+where
+    T: VideoType,
+    R: VideoRepository<T, Error = InfraError>,
+{
+    let mut videos = repo.order_by_like(length).await?;
+    let mut page_len = videos.len();
+    while page_len == length {
+        let cursor = videos.last().unwrap().like_cursor();
+        let page = repo.order_by_like_later(&cursor, length).await?;
+        page_len = page.len();
+        videos.extend(page);
+    }
+    Ok(videos)
+}
+
+/// `length`ずつ`order_by_date_later`で辿ったVideo<T>
+async fn walk_order_by_date<T, R>(repo: &R, length: usize) -> Result<Vec<Video<T>>, InfraError>
+where
+    T: VideoType,
+    R: VideoRepository<T, Error = InfraError>,
+{
+    let mut videos = repo.order_by_date(length).await?;
+    let mut page_len = videos.len();
+    while page_len == length {
+        let cursor = videos.last().unwrap().date_cursor();
+        let page = repo.order_by_date_later(&cursor, length).await?;
+        page_len = page.len();
+        videos.extend(page);
+    }
+    Ok(videos)
+}
+
+/// VideoRepository<T>の実装が満たすべき性質を確かめる．保存するVideo<T>は全て保存済みの`channel`のものとする．
+/// 既存のVideo<T>があっても成り立つように保存したVideo<T>のみを比較し，最後にそれらを完全に削除する．
+pub(crate) async fn video_repository_contract<T, R>(
+    repo: &R,
+    channel: &Channel,
+) -> Result<(), InfraError>
+where
+    T: VideoType,
+    R: VideoRepository<T, Error = InfraError>,
+{
+    let mut videos = fake_videos::<T>(channel);
+    let ids = videos.iter().map(|video| video.id()).collect::<Vec<_>>();
+
+    // 半分は一つずつ，残りは一度に保存する
+    let (saved_one_by_one, saved_at_once) = videos.split_at(videos.len() / 2);
+    for video in saved_one_by_one.iter().cloned() {
+        repo.save(video, Actor::Admin).await?;
+    }
+    // 一つでも保存できないVideo<T>がある場合は何も保存しない
+    {
+        let mut with_conflicted = saved_at_once.to_vec();
+        with_conflicted.push(saved_one_by_one[0].clone());
+        let res = repo.save_many(with_conflicted, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)), "{res:?}");
+        assert!(own_videos(&repo.all().await?, &ids[saved_one_by_one.len()..]).is_empty());
+    }
+    repo.save_many(saved_at_once.to_vec(), Actor::Admin).await?;
+
+    // 同じidのVideo<T>は保存できず，保存済みのものは変わらない
+    {
+        let mut conflicted = videos[0].clone();
+        conflicted.assign(fake_video(channel));
+        let res = repo.save(conflicted, Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)), "{res:?}");
+    }
+
+    // Likeはクライアントごとに一回のみ
+    let (client, other_client) = (ClientId::generate(), ClientId::generate());
+    {
+        repo.increment_like(ids[0], client).await?;
+        repo.increment_like(ids[0], client).await?;
+        videos[0].increment_like();
+
+        repo.unlike(ids[1], other_client).await?;
+        repo.increment_like(ids[1], client).await?;
+        repo.unlike(ids[1], client).await?;
+
+        let liked_ids = repo.liked_ids(&ids, client).await?;
+        assert_eq!(liked_ids, vec![ids[0]]);
+    }
+
+    // 古い版数では編集できない
+    {
+        let mut edited = videos[2].clone();
+        edited.assign(fake_video(channel));
+        repo.edit(edited.clone(), Actor::Admin).await?;
+
+        let res = repo.edit(videos[2].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::StaleVersion)), "{res:?}");
+
+        edited.increment_version();
+        videos[2] = edited;
+    }
+
+    {
+        let mut res = own_videos(&repo.all().await?, &ids);
+        res.sort_by_key(|video| video.id());
+        let mut expected = videos.clone();
+        expected.sort_by_key(|video| video.id());
+        assert_eq!(res, expected);
+    }
+
+    // likeで並べたときはidで順番が一意に決まり，ページをつなげても変わらない
+    {
+        let total = repo.all().await?.len();
+        let ordered = repo.order_by_like(total).await?;
+        assert_eq!(ordered.len(), total);
+        assert!(ordered
+            .windows(2)
+            .all(|pair| like_order(&pair[0], &pair[1]) == Ordering::Less));
+
+        let mut expected = videos.clone();
+        expected.sort_by(like_order);
+        assert_eq!(own_videos(&ordered, &ids), expected);
+
+        for length in [3, 7] {
+            assert_eq!(walk_order_by_like(repo, length).await?, ordered);
+        }
+    }
+
+    // dateで並べたときも同様
+    {
+        let total = repo.all().await?.len();
+        let ordered = repo.order_by_date(total).await?;
+        assert_eq!(ordered.len(), total);
+        assert!(ordered
+            .windows(2)
+            .all(|pair| date_order(&pair[0], &pair[1]) == Ordering::Less));
+
+        let mut expected = videos.clone();
+        expected.sort_by(date_order);
+        assert_eq!(own_videos(&ordered, &ids), expected);
+
+        for length in [3, 7] {
+            assert_eq!(walk_order_by_date(repo, length).await?, ordered);
+        }
+    }
+
+    // 存在しないVideo<T>に対する操作
+    {
+        let no_exists = fake_video::<T>(channel);
+        let tag = Faker.fake::<Tag>();
+
+        let res = repo.edit(no_exists.clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.increment_like(no_exists.id(), client).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.unlike(no_exists.id(), client).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.tags(no_exists.id()).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.add_tag(no_exists.id(), tag).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.remove(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.restore(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.purge(no_exists.id(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+    }
+
+    // ゴミ箱のVideo<T>は取得できず，同じidで保存もできない
+    {
+        repo.remove(ids[3], Actor::Admin).await?;
+
+        assert!(own_videos(&repo.all().await?, &ids[3..4]).is_empty());
+        assert_eq!(
+            own_videos(&repo.trashed().await?, &ids),
+            vec![videos[3].clone()]
+        );
+        let res = repo.increment_like(ids[3], client).await;
+        assert!(matches!(res, Err(InfraError::NoRecordError)), "{res:?}");
+        let res = repo.save(videos[3].clone(), Actor::Admin).await;
+        assert!(matches!(res, Err(InfraError::ConflictError)), "{res:?}");
+
+        repo.restore(ids[3], Actor::Admin).await?;
+        assert_eq!(
+            own_videos(&repo.all().await?, &ids[3..4]),
+            vec![videos[3].clone()]
+        );
+    }
+
+    // 後片付け
+    for id in ids.iter().copied() {
+        repo.remove(id, Actor::Admin).await?;
+        repo.purge(id, Actor::Admin).await?;
+    }
+    assert!(own_videos(&repo.all().await?, &ids).is_empty());
+    assert!(own_videos(&repo.trashed().await?, &ids).is_empty());
+
+    Ok(())
+}
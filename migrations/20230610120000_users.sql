@@ -0,0 +1,7 @@
+-- usersテーブルについて
+CREATE TABLE IF NOT EXISTS users (
+    "name" TEXT UNIQUE NOT NULL,
+    password_hash TEXT NOT NULL,
+    "role" TEXT NOT NULL,
+    id uuid PRIMARY KEY NOT NULL
+);
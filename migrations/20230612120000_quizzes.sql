@@ -0,0 +1,8 @@
+-- quizzesテーブルについて
+CREATE TABLE IF NOT EXISTS quizzes (
+    question TEXT NOT NULL,
+    answers TEXT[] NOT NULL,
+    id uuid PRIMARY KEY NOT NULL
+);
+
+-- 問題と回答は公開しないため，クイズは管理者がAPIから登録する
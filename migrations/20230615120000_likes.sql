@@ -0,0 +1,13 @@
+-- movie_clip_likesテーブルについて(クライアントごとのLikeの台帳)
+CREATE TABLE IF NOT EXISTS movie_clip_likes (
+    movie_clip_id uuid NOT NULL REFERENCES movie_clips (id) ON DELETE CASCADE,
+    client_id uuid NOT NULL,
+    PRIMARY KEY (movie_clip_id, client_id)
+);
+
+-- video_likesテーブルについて(クライアントごとのLikeの台帳)
+CREATE TABLE IF NOT EXISTS video_likes (
+    video_id uuid NOT NULL REFERENCES videos (id) ON DELETE CASCADE,
+    client_id uuid NOT NULL,
+    PRIMARY KEY (video_id, client_id)
+);
@@ -0,0 +1,16 @@
+-- clip_seriesテーブルについて(clipsは再生する順番のmovie_clipsのid)
+CREATE TABLE IF NOT EXISTS clip_series (
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    clips uuid[] NOT NULL,
+    id uuid PRIMARY KEY NOT NULL,
+    "like" INT4 NOT NULL,
+    create_date DATE NOT NULL
+);
+
+-- clip_series_likesテーブルについて(クライアントごとのLikeの台帳)
+CREATE TABLE IF NOT EXISTS clip_series_likes (
+    clip_series_id uuid NOT NULL REFERENCES clip_series (id) ON DELETE CASCADE,
+    client_id uuid NOT NULL,
+    PRIMARY KEY (clip_series_id, client_id)
+);
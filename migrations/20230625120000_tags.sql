@@ -0,0 +1,26 @@
+-- movie_clip_tagsテーブルについて(MovieClipとタグの多対多の関係)
+CREATE TABLE IF NOT EXISTS movie_clip_tags (
+    movie_clip_id uuid NOT NULL REFERENCES movie_clips (id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (movie_clip_id, tag)
+);
+
+CREATE INDEX IF NOT EXISTS movie_clip_tags_tag_idx ON movie_clip_tags (tag);
+
+-- video_tagsテーブルについて(Videoとタグの多対多の関係)
+CREATE TABLE IF NOT EXISTS video_tags (
+    video_id uuid NOT NULL REFERENCES videos (id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (video_id, tag)
+);
+
+CREATE INDEX IF NOT EXISTS video_tags_tag_idx ON video_tags (tag);
+
+-- episode_tagsテーブルについて(Episodeとタグの多対多の関係)
+CREATE TABLE IF NOT EXISTS episode_tags (
+    episode_id uuid NOT NULL REFERENCES episodes (id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (episode_id, tag)
+);
+
+CREATE INDEX IF NOT EXISTS episode_tags_tag_idx ON episode_tags (tag);
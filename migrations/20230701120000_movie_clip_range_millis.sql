@@ -0,0 +1,4 @@
+-- movie_clipsの再生範囲をミリ秒の精度で保持するため小数の秒数とする
+ALTER TABLE movie_clips
+    ALTER COLUMN "start" TYPE FLOAT8,
+    ALTER COLUMN "end" TYPE FLOAT8;
@@ -0,0 +1,29 @@
+-- 動画の長さ(秒)．不明な場合はNULL
+ALTER TABLE videos ADD COLUMN IF NOT EXISTS duration FLOAT8;
+
+-- クリップの元動画の長さ(秒)．不明な場合はNULL
+ALTER TABLE movie_clips ADD COLUMN IF NOT EXISTS duration FLOAT8;
+
+-- 終了が開始以前のクリップがある場合はデータを書き換えずに移行を中止し，該当するクリップを一覧する．
+-- 管理者がクリップの範囲を修正してから再度移行する
+DO $$
+DECLARE
+    invalid_clips TEXT;
+BEGIN
+    SELECT string_agg(format('%s (%s - %s)', id, "start", "end"), ', ' ORDER BY id)
+        INTO invalid_clips
+        FROM movie_clips
+        WHERE "end" <= "start";
+
+    IF invalid_clips IS NOT NULL THEN
+        RAISE EXCEPTION 'movie clips whose end is not after start: %', invalid_clips
+            USING HINT = 'Fix "start" and "end" of these movie clips and run the migration again.';
+    END IF;
+END
+$$;
+
+ALTER TABLE movie_clips
+    ADD CONSTRAINT movie_clips_start_before_end CHECK ("start" < "end");
+
+ALTER TABLE movie_clips
+    ADD CONSTRAINT movie_clips_end_within_duration CHECK (duration IS NULL OR "end" <= duration);
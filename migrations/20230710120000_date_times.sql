@@ -0,0 +1,6 @@
+-- 配信日時・エピソードの日時をタイムゾーン付きにする．既存の日付はJSTの0時とする
+ALTER TABLE videos
+    ALTER COLUMN "date" TYPE TIMESTAMPTZ USING ("date"::TIMESTAMP AT TIME ZONE 'Asia/Tokyo');
+
+ALTER TABLE episodes
+    ALTER COLUMN "date" TYPE TIMESTAMPTZ USING ("date"::TIMESTAMP AT TIME ZONE 'Asia/Tokyo');
@@ -0,0 +1,81 @@
+-- channelsテーブルについて(動画を投稿するチャンネル)
+CREATE TABLE IF NOT EXISTS channels (
+    name TEXT NOT NULL UNIQUE,
+    "url" TEXT,
+    avatar_url TEXT,
+    id uuid PRIMARY KEY NOT NULL
+);
+
+-- 投稿者名をチャンネル名と同様にNFKC正規化し，空白を整えたもの
+CREATE OR REPLACE FUNCTION channel_name_of_author(author TEXT) RETURNS TEXT AS $$
+    SELECT left(regexp_replace(btrim(normalize(author, NFKC)), '\s+', ' ', 'g'), 50)
+$$ LANGUAGE SQL IMMUTABLE;
+
+-- チャンネル名にできない投稿者名の動画がある場合はデータを書き換えずに移行を中止し，該当する動画を一覧する．
+-- 管理者が投稿者名を修正してから再度移行する
+DO $$
+DECLARE
+    invalid_videos TEXT;
+BEGIN
+    SELECT string_agg(format('%s (%L)', id, author), ', ' ORDER BY id)
+        INTO invalid_videos
+        FROM videos
+        WHERE channel_name_of_author(author) = '' OR channel_name_of_author(author) ~ '[[:cntrl:]]';
+
+    IF invalid_videos IS NOT NULL THEN
+        RAISE EXCEPTION 'videos whose author cannot be a channel name: %', invalid_videos
+            USING HINT = 'Fix "author" of these videos and run the migration again.';
+    END IF;
+END
+$$;
+
+-- 既存の投稿者名をチャンネルへ移行する(表記の揺れは後から統合する)
+INSERT INTO channels (name, id)
+SELECT name, gen_random_uuid() FROM (
+    SELECT DISTINCT channel_name_of_author(author) AS name FROM videos
+) AS authors
+ON CONFLICT (name) DO NOTHING;
+
+-- 動画を投稿したチャンネル．全ての動画が投稿者名からチャンネルを得る
+ALTER TABLE videos ADD COLUMN IF NOT EXISTS channel_id uuid REFERENCES channels (id);
+
+UPDATE videos SET channel_id = channels.id FROM channels
+WHERE channels.name = channel_name_of_author(videos.author);
+
+ALTER TABLE videos ALTER COLUMN channel_id SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS videos_channel_id_idx ON videos (channel_id);
+
+DROP FUNCTION channel_name_of_author(TEXT);
+
+-- 投稿者名はチャンネル名の読み取り専用の写しとし，チャンネルから導出する
+UPDATE videos SET author = channels.name FROM channels WHERE channels.id = videos.channel_id;
+
+-- チャンネルが無い場合は外部キー制約の違反とする
+CREATE OR REPLACE FUNCTION set_video_author() RETURNS trigger AS $$
+DECLARE
+    channel_name TEXT;
+BEGIN
+    SELECT name INTO channel_name FROM channels WHERE id = NEW.channel_id;
+    IF FOUND THEN
+        NEW.author := channel_name;
+    END IF;
+    RETURN NEW;
+END
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER videos_author_from_channel
+    BEFORE INSERT OR UPDATE OF author, channel_id ON videos
+    FOR EACH ROW EXECUTE FUNCTION set_video_author();
+
+-- チャンネル名の変更を投稿者名の写しへ反映する
+CREATE OR REPLACE FUNCTION sync_videos_author() RETURNS trigger AS $$
+BEGIN
+    UPDATE videos SET author = NEW.name WHERE channel_id = NEW.id;
+    RETURN NULL;
+END
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER channels_name_to_videos_author
+    AFTER UPDATE OF name ON channels
+    FOR EACH ROW EXECUTE FUNCTION sync_videos_author();
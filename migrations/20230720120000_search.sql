@@ -0,0 +1,26 @@
+-- 日本語の部分一致のためのトライグラム
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- movie_clipsの検索用の列(タイトル)
+ALTER TABLE movie_clips
+    ADD COLUMN IF NOT EXISTS search_text TEXT GENERATED ALWAYS AS (title) STORED,
+    ADD COLUMN IF NOT EXISTS search_vector tsvector GENERATED ALWAYS AS (to_tsvector('simple', title)) STORED;
+
+CREATE INDEX IF NOT EXISTS movie_clips_search_vector_idx ON movie_clips USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS movie_clips_search_text_trgm_idx ON movie_clips USING GIN (search_text gin_trgm_ops);
+
+-- videosの検索用の列(タイトルと投稿者)
+ALTER TABLE videos
+    ADD COLUMN IF NOT EXISTS search_text TEXT GENERATED ALWAYS AS (title || ' ' || author) STORED,
+    ADD COLUMN IF NOT EXISTS search_vector tsvector GENERATED ALWAYS AS (to_tsvector('simple', title || ' ' || author)) STORED;
+
+CREATE INDEX IF NOT EXISTS videos_search_vector_idx ON videos USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS videos_search_text_trgm_idx ON videos USING GIN (search_text gin_trgm_ops);
+
+-- episodesの検索用の列(タグを除いた内容)
+ALTER TABLE episodes
+    ADD COLUMN IF NOT EXISTS search_text TEXT GENERATED ALWAYS AS (regexp_replace(content, '<[^>]*>', ' ', 'g')) STORED,
+    ADD COLUMN IF NOT EXISTS search_vector tsvector GENERATED ALWAYS AS (to_tsvector('simple', regexp_replace(content, '<[^>]*>', ' ', 'g'))) STORED;
+
+CREATE INDEX IF NOT EXISTS episodes_search_vector_idx ON episodes USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS episodes_search_text_trgm_idx ON episodes USING GIN (search_text gin_trgm_ops);
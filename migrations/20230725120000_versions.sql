@@ -0,0 +1,4 @@
+-- 楽観的な排他制御のための版数(編集の度に一つ増える)
+ALTER TABLE movie_clips ADD COLUMN IF NOT EXISTS version integer NOT NULL DEFAULT 0;
+ALTER TABLE videos ADD COLUMN IF NOT EXISTS version integer NOT NULL DEFAULT 0;
+ALTER TABLE episodes ADD COLUMN IF NOT EXISTS version integer NOT NULL DEFAULT 0;
@@ -0,0 +1,14 @@
+-- 切り抜き・動画・エピソードの編集履歴(保存・編集・削除の度に一件追加される)
+CREATE TABLE IF NOT EXISTS revisions (
+    seq bigserial,
+    id uuid PRIMARY KEY,
+    entity_kind text NOT NULL,
+    entity_id uuid NOT NULL,
+    operation text NOT NULL,
+    snapshot text NOT NULL,
+    actor_kind text NOT NULL,
+    actor_user_id uuid,
+    created_at timestamptz NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS revisions_entity_index ON revisions (entity_kind, entity_id, seq);
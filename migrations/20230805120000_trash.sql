@@ -0,0 +1,4 @@
+-- ゴミ箱に移した日時(ゴミ箱に無い場合はNULL)
+ALTER TABLE movie_clips ADD COLUMN IF NOT EXISTS deleted_at timestamptz;
+ALTER TABLE videos ADD COLUMN IF NOT EXISTS deleted_at timestamptz;
+ALTER TABLE episodes ADD COLUMN IF NOT EXISTS deleted_at timestamptz;
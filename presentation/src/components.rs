@@ -13,7 +13,7 @@ mod video_page_menu;
 
 pub use accordion_episodes::AccordionEpisodes;
 pub use add_button::AddButton;
-pub use edit_modal::EditModal;
+pub use edit_modal::{EditModal, STALE_VERSION_MESSAGE};
 pub use flow_script::FlowScript;
 pub use intersection_bottom::IntersectionBottom;
 pub use movie_card::{MovieCard, MovieContainer};
@@ -21,5 +21,7 @@ pub use player::Player;
 pub use quiz::Quiz;
 pub use spinner::Spinner;
 pub use tooltip_menu_button::{TooltipMenuButton, TooltipMenuItem};
-pub use validation_input::{InputType, RequiredString, ValidationInput};
+pub use validation_input::{
+    validation_message, DateTimeInput, InputType, OptionalInput, RequiredString, ValidationInput,
+};
 pub use video_page_menu::VideoPageMenu;
@@ -64,7 +64,7 @@ pub fn AccordionEpisodes<'a>(cx: Scope<'a, AccordionEpisodesProps<'a>>) -> Eleme
                             cx.props.episodes.read().as_ref().map(|episodes|{
                                 rsx!{
                                     episodes.iter().map(|episode|{
-                                        let (year, month, day) = episode.date().to_ymd();
+                                        let (year, month, day) = episode.date().date().to_ymd();
                                         let content = episode.content();
                                         rsx! {
                                             li {key: "{episode.id()}",
@@ -1,5 +1,8 @@
 use dioxus::prelude::*;
 
+/// 他の編集によって版数が進んでいた場合のメッセージ
+pub const STALE_VERSION_MESSAGE: &str = "※他の人が編集しました．再読み込みしてください";
+
 #[derive(Props)]
 pub struct EditModalProps<'a> {
     // キャプション
@@ -8,6 +11,8 @@ pub struct EditModalProps<'a> {
     on_cancel: EventHandler<'a>,
     // 削除時の挙動
     on_remove: Option<EventHandler<'a>>,
+    // 送信に失敗した場合のメッセージ
+    error_message: Option<String>,
     // 入力部分の要素
     input: Element<'a>,
     // プレビュー部分の要素
@@ -25,6 +30,11 @@ pub fn EditModal<'a>(cx: Scope<'a, EditModalProps<'a>>) -> Element {
                 onmousedown: move |e| {e.stop_propagation()},
                 div { class: "edit-input-container",
                     div { class: "edit-input-caption", "{cx.props.caption}"}
+                    cx.props.error_message.as_ref().map(|message|{
+                        rsx!{
+                            div { class: "edit-error-message", "{message}"}
+                        }
+                    })
                     &cx.props.input
                     div { class: "edit-input-bottom",
                         button { onclick:move |_|{is_preview_show.set(true)}, "プレビューを表示"}
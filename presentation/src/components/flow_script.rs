@@ -10,13 +10,13 @@ pub fn FlowScript(cx: Scope) -> Element {
         to_owned![late_video_state];
         |_| async move {
             let res = {
-                let cmd = video_commands::OrderByDateVideosCommand::new(1);
+                let cmd = video_commands::OrderByDateVideosCommand::new(&[], 1);
                 video_usecase::order_by_date_videos::<Original>(cmd).await
             };
 
             match res {
-                Ok(mut late_videos) => {
-                    if let Some(first) = late_videos.first_mut() {
+                Ok(mut page) => {
+                    if let Some(first) = page.items.first_mut() {
                         late_video_state.set(Some(std::mem::take(first)));
                     }
                 }
@@ -2,7 +2,7 @@ use crate::components::{Player, TooltipMenuButton, TooltipMenuItem};
 use crate::include_str_from_root;
 use domain::{
     movie_clip::{MovieUrl, SecondRange},
-    Date,
+    DateTime,
 };
 
 use dioxus::prelude::*;
@@ -20,11 +20,11 @@ pub struct MovieCardProps<'a> {
     #[props(into)]
     title: String,
     /// 日時
-    date: Option<Date>,
+    date: Option<DateTime>,
     /// playerのid
     #[props(into)]
     id: String,
-    /// youtube url
+    /// 動画のurl
     movie_url: MovieUrl,
     /// 編集ボタンを押したときの処理
     on_modify: Option<EventHandler<'a>>,
@@ -63,16 +63,21 @@ pub fn MovieCard<'a>(cx: Scope<'a, MovieCardProps<'a>>) -> Element {
     cx.render(rsx! {
         div {class: "movie-card-container",
             div { class: "movie-card-player",
-                Player{id: &cx.props.id, video_id:cx.props.movie_url.video_id(), range:cx.props.range.clone()}
+                Player{id: &cx.props.id, movie_url:cx.props.movie_url.clone(), range:cx.props.range.clone()}
             }
             div { class: "movie-card-caption",
                 div { class: "movie-card-left",
                     div { class: "movie-card-title", "{cx.props.title}"}
                     div { class: "movie-card-left-bottom",
                         cx.props.date.map(|date|{
-                            let (year, month, day) = date.to_ymd();
+                            let (year, month, day) = date.date().to_ymd();
+                            // 時刻が分からない(0時の)場合は日付のみ表示する
+                            let date_str = match date.to_hms() {
+                                (0, 0, 0) => format!("{year}/{month}/{day}"),
+                                (hour, minute, _) => format!("{year}/{month}/{day} {hour}:{minute:02}"),
+                            };
                             rsx!{
-                                div { format!("{year}/{month}/{day}")}
+                                div { "{date_str}"}
                             } 
                         })
                         cx.props.author.as_ref().map(|author|{
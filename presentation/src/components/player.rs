@@ -1,8 +1,9 @@
 use crate::PLAYING_PLAYER_ID;
+use crate::ENDED_PLAYER_ID;
 use crate::ACTIVE_PLAYER_IDS;
 use crate::ACTIVE_PLAYER_NUMBER;
 use crate::include_str_from_root;
-use domain::movie_clip::SecondRange;
+use domain::{movie_clip::SecondRange, MovieUrl, Platform};
 
 const ORIGIN: &str = "https://rikou-meitan.shuttleapp.rs";
 
@@ -24,10 +25,13 @@ use std::rc::Rc;
 pub struct PlayerProps {
     #[props(into)]
     id: String,
-    #[props(into)]
-    video_id: String,
+    /// 動画のurl．プラットフォームに応じた埋め込みプレーヤーを表示する
+    movie_url: MovieUrl,
     #[props(!optional)]
     range: Option<SecondRange>,
+    /// サムネイルの表示後にクリックを待たずに再生するかどうか
+    #[props(default = false)]
+    autoplay: bool,
 }
 
 pub fn Player(cx: Scope<PlayerProps>) -> Element {
@@ -49,17 +53,26 @@ pub fn Player(cx: Scope<PlayerProps>) -> Element {
     );
 
     let onplay_event_listener = cx.use_hook(||{Rc::new(Cell::new(Option::<PlyrStandardEventListener>::None))});
+    let onended_event_listener = cx.use_hook(||{Rc::new(Cell::new(Option::<PlyrStandardEventListener>::None))});
+    let onready_event_listener = cx.use_hook(||{Rc::new(Cell::new(Option::<PlyrStandardEventListener>::None))});
+    let setter_ended_player_id = use_set(cx, ENDED_PLAYER_ID);
 
     let player_container_id = format!("{}-player-container", &cx.props.id);
-    let src_url = format!("https://www.youtube.com/embed/{}?origin={ORIGIN}&iv_load_policy=3&modestbranding=1&playsinline=1&showinfo=0&rel=0&enablejsapi=1", &cx.props.video_id);
+    let is_youtube = cx.props.movie_url.platform() == Platform::YouTube;
+    // youtubeの再生範囲はplyrのオプションで指定する
+    let src_url = match is_youtube {
+        true => cx.props.movie_url.embed_url(ORIGIN, None),
+        false => cx.props.movie_url.embed_url(ORIGIN, cx.props.range.as_ref().map(|range|{range.start().to_u32()}))
+    };
 
     let first_clicked_time = cx.use_hook(||{Rc::new(Cell::new(Option::<chrono::NaiveDateTime>::None))});
 
 
     // 初期化
     use_effect(cx, (), {
-        to_owned![thumbnail_url, intersecting_handler, player_container_id, intersecting_handler];
-        let video_id = cx.props.video_id.clone();
+        to_owned![thumbnail_url, is_active, intersecting_handler, player_container_id, intersecting_handler];
+        let movie_url = cx.props.movie_url.clone();
+        let autoplay = cx.props.autoplay;
         let player_container_id = player_container_id.clone();
         |_| async move {
 
@@ -71,13 +84,18 @@ pub fn Player(cx: Scope<PlayerProps>) -> Element {
                     let first_entry = entries.first().unwrap_throw();
                     if first_entry.is_intersecting() {
                         wasm_bindgen_futures::spawn_local({
-                            to_owned![thumbnail_url, video_id, intersecting_handler];
+                            to_owned![thumbnail_url, is_active, movie_url, intersecting_handler];
                             async move {
                                 TimeoutFuture::new(500).await;
                                 // サムネイルのurlをセット
-                                thumbnail_url.set(Some(
-                                    format!("https://img.youtube.com/vi/{video_id}/sddefault.jpg"),
-                                ));
+                                let movie_thumbnail_url = movie_url.thumbnail_url();
+                                let has_thumbnail = movie_thumbnail_url.is_some();
+                                thumbnail_url.set(movie_thumbnail_url);
+
+                                // 自動再生・サムネイルが無い場合はそのままプレーヤーを初期化
+                                if autoplay || !has_thumbnail {
+                                    is_active.set(true);
+                                }
 
                                 // もう不要であるため交差オブザーバーを削除
                                 intersecting_handler.set(None);
@@ -99,7 +117,10 @@ pub fn Player(cx: Scope<PlayerProps>) -> Element {
         to_owned![
             player_state, 
             onplay_event_listener, 
+            onended_event_listener,
+            onready_event_listener,
             setter_playing_player_id,
+            setter_ended_player_id,
             active_player_ids_state,
             intersecting_handler
         ];
@@ -108,16 +129,21 @@ pub fn Player(cx: Scope<PlayerProps>) -> Element {
         selector.push_str(&id);
 
         let player_container_id = player_container_id.clone();
+        let fractional_start = cx.props.range.as_ref()
+            .map(|range|{range.start()})
+            .filter(|start|{start.subsec_millis() != 0});
 
+        // youtubeのオプションは整数秒のため，終了は切り上げる
         let youtube_options = YoutubeOptions {
             start: cx.props.range.as_ref().map(|range|{range.start().to_u32()}),
-            end: cx.props.range.as_ref().map(|range|{range.end().to_u32()}),
+            end: cx.props.range.as_ref().map(|range|{((range.end().to_millis() + 999) / 1000) as u32}),
             autoplay: Some(1),
             ..Default::default()
         };
 
         |is_active| async move {
-            if *is_active.current() {
+            // plyrで制御するのはyoutubeのみ
+            if *is_active.current() && is_youtube {
                 // プレーヤー関連
 
                 let player_options = PlyrOptions::builder()
@@ -137,8 +163,36 @@ pub fn Player(cx: Scope<PlayerProps>) -> Element {
                         }
                     });
 
+                // 再生範囲の終わりに達したとき
+                let onended_handler = PlyrStandardEventListener::new(
+                    &player,
+                    PlyrStandardEventType::ended, 
+                    {
+                        to_owned![id];
+                        move |_|{
+                            setter_ended_player_id(Some(id.clone()));
+                        }
+                    });
+
+                // 開始時間にミリ秒がある場合は準備完了後に小数の秒数でシークする
+                let onready_handler = fractional_start.map(|start|{
+                    PlyrStandardEventListener::new(
+                        &player,
+                        PlyrStandardEventType::ready,
+                        {
+                            to_owned![player_state];
+                            move |_|{
+                                if let Some(player) = player_state.current().as_ref(){
+                                    player.set_current_time(start.to_secs_f64());
+                                }
+                            }
+                        })
+                });
+
                 player_state.set(Some(player));
                 onplay_event_listener.set(Some(onplay_handler));
+                onended_event_listener.set(Some(onended_handler));
+                onready_event_listener.set(onready_handler);
 
                 active_player_ids_state.with_mut({
                     to_owned![id];
@@ -178,7 +232,7 @@ pub fn Player(cx: Scope<PlayerProps>) -> Element {
 
     // アクティプリストに入っていない場合の処理
     use_effect(cx, active_player_ids,{
-        to_owned![is_active, player_state, onplay_event_listener, intersecting_handler];
+        to_owned![is_active, player_state, onplay_event_listener, onended_event_listener, onready_event_listener, intersecting_handler];
         let player_id = cx.props.id.clone(); 
         |active_player_ids| 
             async move{
@@ -187,6 +241,8 @@ pub fn Player(cx: Scope<PlayerProps>) -> Element {
                     is_active.set(false);
                     player_state.set(None);
                     onplay_event_listener.set(None);
+                    onended_event_listener.set(None);
+                    onready_event_listener.set(None);
                     intersecting_handler.set(None);
                 }
         }
@@ -214,69 +270,77 @@ pub fn Player(cx: Scope<PlayerProps>) -> Element {
 
     cx.render(rsx! {
         div { class:"player-container", id:"{player_container_id}",
-            if let Some(url) = thumbnail_url.get() {
-                rsx! {
-                    if *is_active.get() {
-                        rsx! {
-                            div { class: "my-iframe-player",
-                                div{ class: "plyr__video-embed", id:"{cx.props.id}",
-                                    iframe {
-                                        src: "{src_url}",
-                                        allowfullscreen: "true",
-                                        allow: "autoplay"
-                                    }                           
-                                }
-                                div { class: "player-wrapper",
-                                    prevent_default: "touchstart", // 動画のピンチズームを防ぐのに必要 <- 防げなかった
-                                    onclick: move |_|{
-                                        let now_time = chrono::Local::now().naive_utc();
-
-                                        if let Some(player) = player_state.get() {
-                                            match first_clicked_time.get() {
-                                                // 既にクリックされている場合
-                                                Some(clicked_time) => {
-                                                    if (now_time - clicked_time).num_milliseconds() < 800 {
-                                                        // クリックした間隔が短い場合
-                                                        player.fullscreen().enter();
-                                                    } else {
-                                                        // クリックした間隔が長い場合
-                                                        player.toggle_play();
-                                                    }
-
-                                                    first_clicked_time.set(None); // 完全に初期化
-                                                },
-                                                // クリックされていない・未初期化の場合
-                                                None => {
+            if *is_active.get() {
+                if is_youtube {
+                    rsx! {
+                        div { class: "my-iframe-player",
+                            div{ class: "plyr__video-embed", id:"{cx.props.id}",
+                                iframe {
+                                    src: "{src_url}",
+                                    allowfullscreen: "true",
+                                    allow: "autoplay"
+                                }                           
+                            }
+                            div { class: "player-wrapper",
+                                prevent_default: "touchstart", // 動画のピンチズームを防ぐのに必要 <- 防げなかった
+                                onclick: move |_|{
+                                    let now_time = chrono::Local::now().naive_utc();
+
+                                    if let Some(player) = player_state.get() {
+                                        match first_clicked_time.get() {
+                                            // 既にクリックされている場合
+                                            Some(clicked_time) => {
+                                                if (now_time - clicked_time).num_milliseconds() < 800 {
+                                                    // クリックした間隔が短い場合
+                                                    player.fullscreen().enter();
+                                                } else {
+                                                    // クリックした間隔が長い場合
                                                     player.toggle_play();
-                                                    first_clicked_time.set(Some(now_time));
-    
-                                                    wasm_bindgen_futures::spawn_local({
-                                                        to_owned![first_clicked_time];
-                                                        async move {
-                                                            TimeoutFuture::new(800).await;
-                                                            first_clicked_time.set(None);  // 間隔が長いと初期化
-                                                        }
-                                                    });
                                                 }
+
+                                                first_clicked_time.set(None); // 完全に初期化
+                                            },
+                                            // クリックされていない・未初期化の場合
+                                            None => {
+                                                player.toggle_play();
+                                                first_clicked_time.set(Some(now_time));
+
+                                                wasm_bindgen_futures::spawn_local({
+                                                    to_owned![first_clicked_time];
+                                                    async move {
+                                                        TimeoutFuture::new(800).await;
+                                                        first_clicked_time.set(None);  // 間隔が長いと初期化
+                                                    }
+                                                });
                                             }
                                         }
-                                    },
-                                    // ondblclick: move |_|{
-                                    //     if let Some(player) = player_state.get() {
-                                    //         player.fullscreen().enter();
-                                    //     }
-                                    // },
-                                }
+                                    }
+                                },
+                                // ondblclick: move |_|{
+                                //     if let Some(player) = player_state.get() {
+                                //         player.fullscreen().enter();
+                                //     }
+                                // },
                             }
                         }
-                    } else {
-                        rsx! {
-                            div {  class: "my-player-thumbnail", onclick: move |_| {is_active.set(true)},
-                                img { src: "{url}"}
+                    }
+                } else {
+                    rsx! {
+                        div { class: "my-iframe-player",
+                            iframe {
+                                src: "{src_url}",
+                                allowfullscreen: "true",
+                                allow: "autoplay; fullscreen"
                             }
                         }
                     }
                 }
+            } else if let Some(url) = thumbnail_url.get() {
+                rsx! {
+                    div {  class: "my-player-thumbnail", onclick: move |_| {is_active.set(true)},
+                        img { src: "{url}"}
+                    }
+                }
             } else {
                 rsx! { div { class: "player-cover",dangerous_inner_html: "{movie_cover_svg_str}"}}
             }
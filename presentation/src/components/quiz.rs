@@ -1,18 +1,12 @@
-use crate::include_str_from_root;
+use frontend::{
+    commands::quiz_commands, usecases::quiz_usecase, AppCommonError, AppFrontError, QuizAnswer,
+    QuizQuestion,
+};
 
 use dioxus::prelude::*;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
-use serde::Deserialize;
 use std::cell::Cell;
 use std::rc::Rc;
 
-#[derive(Deserialize, Clone)]
-pub struct QuizJson {
-    question: String,
-    answers: Vec<String>,
-}
-
 #[derive(Props)]
 pub struct QuizProps<'a> {
     on_cancel: EventHandler<'a>,
@@ -25,24 +19,72 @@ pub fn Quiz<'a>(cx: Scope<'a, QuizProps<'a>>) -> Element {
     let is_correct = use_state(cx, || false);
     let value_state = cx.use_hook(|| Rc::new(Cell::new(String::new())));
 
-    let quiz_list_str = include_str_from_root!("contents/orikou_quiz.json");
+    let quiz_state = use_state(cx, || Option::<QuizQuestion>::None);
+    let error_message = use_state(cx, || Option::<String>::None);
 
-    let quiz_list: Vec<QuizJson> =
-        serde_json::from_str(quiz_list_str).expect("Quiz Deserialize sanity check");
+    // クイズをAPIから出題してもらう
+    let fetch_question = {
+        to_owned![quiz_state, error_message];
+        move || {
+            to_owned![quiz_state, error_message];
+            async move {
+                let res = {
+                    let cmd = quiz_commands::QuestionCommand;
+                    quiz_usecase::question(cmd).await
+                };
+                match res {
+                    Ok(quiz_question) => quiz_state.set(Some(quiz_question)),
+                    Err(e) => {
+                        log::error!("{}", e);
+                        error_message.set(Some("※クイズを取得できませんでした".to_string()));
+                    }
+                }
+            }
+        }
+    };
 
-    let quiz = quiz_list
-        .choose(&mut thread_rng())
-        .expect("Zero length sanity check")
-        .clone();
+    // 状態の初期化(最初のみ実行)
+    use_effect(cx, (), {
+        to_owned![fetch_question];
+        |_| fetch_question()
+    });
 
-    let onsubmit = {
-        to_owned![value_state];
-        move |_| {
-            let value = value_state.take();
-            if quiz.answers.iter().any(|answer| *answer == value) {
-                is_correct.set(true);
+    let onsubmit = move |_| {
+        let quiz_question = match quiz_state.get().clone() {
+            Some(quiz_question) => quiz_question,
+            None => return,
+        };
+        let value = value_state.take();
+        cx.spawn({
+            to_owned![is_correct, error_message, fetch_question];
+            async move {
+                let res = {
+                    let quiz_answer = QuizAnswer::new(quiz_question.challenge_id, value);
+                    let cmd = quiz_commands::AnswerCommand::new(&quiz_answer);
+                    quiz_usecase::answer(cmd).await
+                };
+                match res {
+                    Ok(_) => {
+                        error_message.set(None);
+                        is_correct.set(true);
+                    }
+                    Err(AppFrontError::CommonError(AppCommonError::IncorrectAnswerError)) => {
+                        error_message.set(Some("※不正解です".to_string()));
+                    }
+                    Err(AppFrontError::CommonError(AppCommonError::UnauthorizedError)) => {
+                        // 回答期限が切れた場合は出題しなおす
+                        error_message.set(Some(
+                            "※回答期限が切れました．新しいクイズに回答してください".to_string(),
+                        ));
+                        fetch_question().await;
+                    }
+                    Err(e) => {
+                        log::error!("{}", e);
+                        error_message.set(Some("※回答を送信できませんでした".to_string()));
+                    }
+                }
             }
-        }
+        });
     };
 
     cx.render(rsx! {
@@ -54,11 +96,16 @@ pub fn Quiz<'a>(cx: Scope<'a, QuizProps<'a>>) -> Element {
                     div { class: "quiz-ui-container", onmousedown: move |e|{e.stop_propagation();},
                         div { class: "quiz-caption", "おりコウクイズ"}
                         div { class: "quiz-desc", "※編集するにはクイズに正解してください(荒らし等を防ぐためです。分からない場合は管理人にお気軽にお尋ねください。)"}
-                        div { class: "question", "{quiz.question}"}
+                        quiz_state.get().as_ref().map(|quiz_question| rsx!{
+                            div { class: "question", "{quiz_question.question}"}
+                        })
                         div { class: "quiz-input-container",
                             div {"回答"}
                             input { class: "quiz-input", r#type:"text", onchange: move |e|{value_state.set(e.value.clone())}}
                         }
+                        error_message.get().as_ref().map(|message| rsx!{
+                            div { class: "quiz-error-message", "{message}"}
+                        })
                         div { class: "quiz-bottom",
                             button {onclick: onsubmit, "回答する"}
                             button {onclick: move |_| {cx.props.on_cancel.call(())}, "キャンセル"}
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use domain::{DateTime, DomainError, ValidationCode, ValidationError};
 use dioxus::{events::FormEvent, prelude::*};
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
 use web_sys::HtmlInputElement;
@@ -29,12 +30,106 @@ impl Display for RequiredString {
     }
 }
 
+// -------------------------------------------------------------------------------------------------
+/// 空でも良い入力．空の場合はNoneとなる
+#[derive(Clone)]
+pub struct OptionalInput<T>(pub Option<T>);
+
+impl<T: TryFrom<String>> TryFrom<String> for OptionalInput<T> {
+    type Error = T::Error;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.trim().is_empty() {
+            Ok(OptionalInput(None))
+        } else {
+            T::try_from(value).map(|value| OptionalInput(Some(value)))
+        }
+    }
+}
+
+impl<T: Display> Display for OptionalInput<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(value) => write!(f, "{value}"),
+            None => Ok(()),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+/// datetime-localの入力．入力はJSTとして解釈する
+#[derive(Clone)]
+pub struct DateTimeInput(pub DateTime);
+
+impl TryFrom<String> for DateTimeInput {
+    type Error = DomainError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse().map(DateTimeInput)
+    }
+}
+
+impl Display for DateTimeInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.to_jst().format("%Y-%m-%dT%H:%M"))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ValidationError
+
+/// 入力の変換時のエラーから構造化されたバリデーションエラーを取り出すためのトレイト
+pub trait AsValidationError {
+    fn as_validation_error(&self) -> Option<&ValidationError>;
+}
+
+impl AsValidationError for DomainError {
+    fn as_validation_error(&self) -> Option<&ValidationError> {
+        match self {
+            DomainError::DomainValidationError(validation_error) => Some(validation_error),
+            _ => None,
+        }
+    }
+}
+
+impl AsValidationError for RequiredError {
+    fn as_validation_error(&self) -> Option<&ValidationError> {
+        None
+    }
+}
+
+/// バリデーションエラーのコードとパラメーターから表示するメッセージを作成する．
+pub fn validation_message(validation_error: &ValidationError) -> String {
+    let param = |key: &str| validation_error.param(key).unwrap_or_default().to_string();
+    match validation_error.code {
+        ValidationCode::TooLong => format!("※{}文字以下で入力してください", param("limit")),
+        ValidationCode::TooSmall => format!("※{}以上を指定してください", param("min")),
+        ValidationCode::InvalidUrlPrefix => {
+            format!("※urlは{}のいずれかで始まる必要があります", param("allowed"))
+        }
+        ValidationCode::InvalidUrlQuery => "※urlに動画のidが含まれていません".to_string(),
+        ValidationCode::EndBeforeStart => {
+            format!("※開始時間({}秒)より後を指定してください", param("start"))
+        }
+        ValidationCode::InvalidHtml => "※利用できないhtmlが含まれています".to_string(),
+        ValidationCode::Empty => "※空白以外の文字を入力してください".to_string(),
+        ValidationCode::InvalidCharacter => {
+            format!("※「{}」は利用できません", param("character"))
+        }
+        ValidationCode::InvalidVideoId => {
+            format!("※{}の動画のidとして無効です", param("platform"))
+        }
+        ValidationCode::ExceedsDuration => {
+            format!("※動画の長さ({}秒)以内で指定してください", param("duration"))
+        }
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // InputType
 #[derive(Clone)]
 pub enum InputType {
     InputText,
     InputDate,
+    InputDateTime,
     TextArea,
     InputUrl,
     InputNum,
@@ -65,11 +160,22 @@ pub struct ValidationInputProps<'a, T: TryFrom<String> + ToString + Clone + 'sta
     /// 最初に与える初期値
     #[props(!optional)]
     pub initial_value: Option<T>,
+    /// 外部から与える値．Someに変化したときに入力の値を置き換える
+    #[props(default, !optional)]
+    pub preset_value: Option<T>,
+    // 入力に対応するフィールドのパス(`range.end`など)
+    #[props(into, default)]
+    pub field: String,
+    // 複数の入力にまたがる検証などで外部から与えるバリデーションエラー．フィールドが一致する場合に表示する
+    #[props(default, !optional)]
+    pub validation_error: Option<ValidationError>,
 }
 
-pub fn ValidationInput<'a, T: TryFrom<String> + ToString + Clone + 'static>(
-    cx: Scope<'a, ValidationInputProps<'a, T>>,
-) -> Element {
+pub fn ValidationInput<'a, T>(cx: Scope<'a, ValidationInputProps<'a, T>>) -> Element
+where
+    T: TryFrom<String> + ToString + Clone + 'static,
+    <T as TryFrom<String>>::Error: AsValidationError,
+{
     let error_message = use_state(cx, || Some("※必須の項目です".to_string()));
     let input_type = cx.use_hook(|| cx.props.input_type.clone());
 
@@ -94,6 +200,27 @@ pub fn ValidationInput<'a, T: TryFrom<String> + ToString + Clone + 'static>(
         }
     });
 
+    // 外部から与えられた値の反映
+    let preset_value_string = cx.props.preset_value.as_ref().map(|value| value.to_string());
+    use_effect(cx, (&preset_value_string,), {
+        let mut selector = ".".to_string();
+        selector.push_str(&cx.props.class);
+        to_owned![error_message];
+
+        |(preset_value_string,)| async move {
+            if let Some(preset_value_string) = preset_value_string {
+                let input_element = gloo_utils::document()
+                    .query_selector(&selector)
+                    .unwrap_throw()
+                    .unwrap_throw()
+                    .unchecked_into::<HtmlInputElement>();
+                input_element.set_value(&preset_value_string);
+
+                error_message.set(None);
+            }
+        }
+    });
+
     // String -> Result<T, String>に変換する関数
     let try_into_func = move |s: String| -> Result<T, String> {
         // required
@@ -103,7 +230,11 @@ pub fn ValidationInput<'a, T: TryFrom<String> + ToString + Clone + 'static>(
                 .try_into()
                 .map_err(|_| "＊必須の項目です．".to_string())?;
         }
-        let domain_value: T = s.try_into().map_err(|_| cx.props.error_message.clone())?;
+        let domain_value: T = s.try_into().map_err(|e: <T as TryFrom<String>>::Error| {
+            e.as_validation_error()
+                .map(validation_message)
+                .unwrap_or_else(|| cx.props.error_message.clone())
+        })?;
         Ok(domain_value)
     };
 
@@ -128,6 +259,9 @@ pub fn ValidationInput<'a, T: TryFrom<String> + ToString + Clone + 'static>(
         InputType::InputDate => {
             rsx! {input { class: "{cx.props.class}", r#type: "date", oninput:on_input}}
         }
+        InputType::InputDateTime => {
+            rsx! {input { class: "{cx.props.class}", r#type: "datetime-local", oninput:on_input}}
+        }
         InputType::InputUrl => {
             rsx! {input { class: "{cx.props.class}", r#type: "url", oninput:on_input}}
         }
@@ -139,11 +273,19 @@ pub fn ValidationInput<'a, T: TryFrom<String> + ToString + Clone + 'static>(
         }
     };
 
+    // 外部から与えられたエラーのうちこの入力に対応するもの
+    let field_error_message = cx
+        .props
+        .validation_error
+        .as_ref()
+        .filter(|validation_error| validation_error.field == cx.props.field)
+        .map(validation_message);
+
     cx.render(rsx! {
         label {
             div{ class:"validation-input-label-container",
                 &cx.props.label_component,
-                error_message.get().as_ref()
+                error_message.get().as_ref().or(field_error_message.as_ref())
                     .map(|message| {
                         rsx! {div { class:"error-message","{message}"}}
                     })
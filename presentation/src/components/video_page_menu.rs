@@ -4,6 +4,7 @@ use std::{
 };
 
 use crate::components::AddButton;
+use domain::Tag;
 
 use dioxus::prelude::*;
 
@@ -16,6 +17,11 @@ where
     on_click_add_button: EventHandler<'a>,
     // ソートのセレクトボックスが変更したときの処理
     on_change_sort_select: EventHandler<'a, T>,
+    // タグのセレクトボックスの選択肢
+    #[props(default)]
+    tags: &'a [Tag],
+    // タグのセレクトボックスが変更したときの処理．Noneの場合は絞り込まない
+    on_change_tag: EventHandler<'a, Option<Tag>>,
 }
 
 pub fn VideoPageMenu<'a, T, E>(cx: Scope<'a, VideoPageMenuProps<'a, T>>) -> Element
@@ -39,6 +45,21 @@ where
                     })
                 }
             }
+            div { class: "tag-select-container",
+                select {
+                    onchange: move |e|{
+                        // 空の値は全てのタグ
+                        let tag = e.value.parse::<Tag>().ok();
+                        cx.props.on_change_tag.call(tag);
+                    },
+                    option { class: "tag-option", value: "", "全てのタグ"}
+                    cx.props.tags.iter().map(|tag|{
+                        rsx!{
+                            option { class: "tag-option", key: "{tag}", value: "{tag}", "{tag}"}
+                        }
+                    })
+                }
+            }
             div { class: "add-button",
                 AddButton{onclick: move |_|{cx.props.on_click_add_button.call(())}}
             }
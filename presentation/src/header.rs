@@ -60,6 +60,7 @@ pub fn Header(cx: Scope) -> Element {
                 HeaderMenuItem{Link{ to:"/clips", "クリップ"}}
                 HeaderMenuItem{Link{ to:"/originals", "コラボ配信"}}
                 HeaderMenuItem{Link{ to:"/kirinukis", "切り抜き"}}
+                HeaderMenuItem{Link{ to:"/search", "検索"}}
             }
             is_hidden_menu_open.get().then(||{
                 rsx! {
@@ -79,6 +80,9 @@ pub fn Header(cx: Scope) -> Element {
                         HiddenMenuItem{
                             Link{ to:"/kirinukis", "切り抜き", onclick: close_hidden_menu}
                         }
+                        HiddenMenuItem{
+                            Link{ to:"/search", "検索", onclick: close_hidden_menu}
+                        }
                     }
                     div {
                         id: "hidden-menu-hamburger-button",
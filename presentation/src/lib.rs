@@ -13,7 +13,8 @@ use crate::footer::Footer;
 use crate::header::Header;
 // 以下はroutes
 use crate::routes::{
-    AdminPage, ClipsPage, EpisodesPage, HomePage, NotFoundPage, VideosPage, VideosPageProps,
+    AdminPage, ClipSeriesPage, ClipsPage, EpisodesPage, HomePage, NotFoundPage, SearchPage,
+    VideosPage, VideosPageProps,
 };
 // 以下はcomponents
 use crate::components::FlowScript;
@@ -38,27 +39,19 @@ pub static IS_DARK_MODE: Atom<bool> = |_| false;
 // 現在再生されているプレーヤーのID．
 pub static PLAYING_PLAYER_ID: Atom<Option<String>> = |_| None;
 
+// 再生範囲の終わりまで再生されたプレーヤーのID．
+pub static ENDED_PLAYER_ID: Atom<Option<String>> = |_| None;
+
 // アクティブなプレーヤーのID
 pub const ACTIVE_PLAYER_NUMBER: usize = 3;
 pub static ACTIVE_PLAYER_IDS: Atom<VecDeque<String>> =
     |_| VecDeque::with_capacity(ACTIVE_PLAYER_NUMBER);
 
-// アドミン用のパスワード
-pub struct AdminPassword(pub String);
-
-/// メインのアプリケーションの引数
-#[derive(Props, PartialEq, Clone)]
-pub struct AppProps {
-    pub admin_password: String,
-}
-
 /// メインのアプリケーション．
-pub fn App(cx: Scope<AppProps>) -> Element {
+pub fn App(cx: Scope) -> Element {
     use_init_atom_root(cx);
     utils::use_dark_mode(cx);
 
-    use_shared_state_provider(cx, || AdminPassword(cx.props.admin_password.clone()));
-
     let admin = cfg!(feature = "develop");
 
     cx.render(rsx! {
@@ -71,12 +64,14 @@ pub fn App(cx: Scope<AppProps>) -> Element {
                     Route { to: "/", HomePage{}}
                     Route { to: "/episodes", EpisodesPage{admin: admin}}
                     Route { to: "/clips", ClipsPage{admin: admin}}
+                    Route { to: "/clip_series/:id", ClipSeriesPage{}}
                     Route { to: "/originals", 
                         VideosPage{..VideosPageProps::<domain::video::Original>::builder().admin(admin).build()}
                     }
                     Route { to: "/kirinukis", 
                         VideosPage{..VideosPageProps::<domain::video::Kirinuki>::builder().admin(admin).build()}
                     }
+                    Route { to: "/search", SearchPage{}}
                     // 以下はadmin関連
                     Route { to: "/admin", AdminPage{}}
                     Route { to: "/admin/episodes", EpisodesPage{admin:true}}
@@ -1,31 +1,12 @@
-use presentation::{App, AppProps};
+use presentation::App;
 
 use dioxus_web::Config;
-use toml::Table;
 use wasm_bindgen::UnwrapThrowExt;
 
-fn get_admin_password(toml_str: &str) -> String {
-    let table = toml_str.parse::<Table>().expect("Cannot Read Secrets.toml");
-    table
-        .get("admin_password")
-        .expect("Cannot Get admin_password from Secrets.toml")
-        .as_str()
-        .expect("admin_password is invalid type.")
-        .to_string()
-}
-
 #[cfg(not(feature = "ssr"))]
 fn main() {
     console_log::init_with_level(log::Level::Info).unwrap_throw();
-    dioxus_web::launch_with_props(
-        App,
-        AppProps {
-            admin_password: get_admin_password(presentation::include_str_from_root!(
-                "../Secrets.toml"
-            )),
-        },
-        Config::new().with_default_panic_hook(true),
-    );
+    dioxus_web::launch_cfg(App, Config::new().with_default_panic_hook(true));
 }
 
 #[cfg(feature = "ssr")]
@@ -36,14 +17,7 @@ fn main() {
 
     log::info!("リハイドレーションを開始");
 
-    // admin_passwordの取得
-    let admin_password =
-        get_admin_password(presentation::include_str_from_root!("../Secrets.toml"));
-    let app_props = AppProps {
-        admin_password: admin_password,
-    };
-
-    let mut dom = VirtualDom::new_with_props(App, app_props.clone());
+    let mut dom = VirtualDom::new(App);
     let _ = dom.rebuild();
 
     let pre = dioxus_ssr::pre_render(&dom);
@@ -55,9 +29,5 @@ fn main() {
         .set_inner_html(&pre);
 
     // リハイドレーション
-    dioxus_web::launch_with_props(
-        App,
-        app_props,
-        Config::new().with_default_panic_hook(true).hydrate(true),
-    );
+    dioxus_web::launch_cfg(App, Config::new().with_default_panic_hook(true).hydrate(true));
 }
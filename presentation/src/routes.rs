@@ -1,13 +1,17 @@
 mod admin;
+mod clip_series;
 mod clips;
 mod episodes;
 mod home;
 mod not_found;
+mod search;
 mod videos;
 
 pub use admin::AdminPage;
+pub use clip_series::ClipSeriesPage;
 pub use clips::ClipsPage;
 pub use episodes::EpisodesPage;
 pub use home::HomePage;
 pub use not_found::NotFoundPage;
+pub use search::SearchPage;
 pub use videos::{VideosPage, VideosPageProps};
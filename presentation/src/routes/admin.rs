@@ -1,15 +1,21 @@
 mod admin_login;
 mod json_loader;
+mod revision_manager;
+mod user_manager;
 
 use crate::utils::use_overlay;
 use admin_login::AdminLogin;
 use domain::{
     episode::Episode,
     movie_clip::MovieClip,
+    quiz::Quiz,
+    user::Role,
     video::{Kirinuki, Original, Video},
 };
-use frontend::{commands, usecases};
+use frontend::{commands, usecases, AppCommonError, AppFrontError};
 use json_loader::{JsonLoader, JsonLoaderProps};
+use revision_manager::RevisionManager;
+use user_manager::UserManager;
 
 use dioxus::prelude::*;
 use dioxus_router::Link;
@@ -19,17 +25,68 @@ pub fn AdminPage(cx: Scope) -> Element {
     let is_admin_login_open = use_state(cx, || true);
     let overlay_state = use_overlay(cx, 2);
 
+    let login_error_message = use_state(cx, || Option::<String>::None);
+    // ログイン中のセッションの権限
+    let role_state = use_state(cx, || Option::<Role>::None);
+
+    // 既にセッションを持っている場合はログインを省略する
     use_effect(cx, (), {
-        to_owned![overlay_state];
+        to_owned![overlay_state, is_admin_login_open, role_state];
         |_| async move {
             overlay_state.activate().expect("Cannot overlay activate");
+
+            let res = {
+                let cmd = commands::auth_commands::CheckSessionCommand;
+                usecases::auth_usecase::check_session(cmd).await
+            };
+
+            if let Ok(role) = res {
+                role_state.set(Some(role));
+                is_admin_login_open.set(false);
+                overlay_state.deactivate();
+            }
         }
     });
 
-    // 管理者ログインモーダルを閉じた処理
-    let close_admin_login = move |_| {
-        is_admin_login_open.set(false);
-        overlay_state.deactivate();
+    // ユーザー名とパスワードを送信したときの処理
+    let submit_password = move |(name, password): (String, String)| {
+        cx.spawn({
+            to_owned![overlay_state, is_admin_login_open, login_error_message, role_state];
+            async move {
+                let res = {
+                    // ユーザー名が空の場合は管理者としてログインする
+                    let name = (!name.trim().is_empty()).then_some(name.trim());
+                    let cmd = commands::auth_commands::LoginCommand::new(name, &password);
+                    usecases::auth_usecase::login(cmd).await
+                };
+
+                let res = match res {
+                    Ok(_) => {
+                        let cmd = commands::auth_commands::CheckSessionCommand;
+                        usecases::auth_usecase::check_session(cmd).await
+                    }
+                    Err(e) => Err(e),
+                };
+
+                match res {
+                    // 管理者ログインモーダルを閉じる
+                    Ok(role) => {
+                        role_state.set(Some(role));
+                        login_error_message.set(None);
+                        is_admin_login_open.set(false);
+                        overlay_state.deactivate();
+                    }
+                    Err(AppFrontError::CommonError(AppCommonError::UnauthorizedError)) => {
+                        login_error_message
+                            .set(Some("※ユーザー名かパスワードが違います".to_string()));
+                    }
+                    Err(e) => {
+                        log::error!("{e}");
+                        login_error_message.set(Some("※ログインに失敗しました".to_string()));
+                    }
+                }
+            }
+        });
     };
 
     // エピソードに関するJsonLoaderの引数
@@ -61,12 +118,11 @@ pub fn AdminPage(cx: Scope) -> Element {
         id: "clips-json-loader".to_string(),
         on_upload: Rc::new(move |clips: Vec<MovieClip>| {
             wasm_bindgen_futures::spawn_local(async move {
-                for clip in clips.into_iter() {
-                    let cmd = commands::movie_clip_commands::SaveMovieClipCommand::new(&clip);
-                    let res = usecases::movie_clip_usecase::save_movie_clip(cmd).await;
-                    if let Err(e) = res {
-                        log::error!("{} Cannot save movie_clip: {:?}", e, clip);
-                    }
+                // id, like, create_dateを保ったまま復元する
+                let cmd = commands::movie_clip_commands::ImportMovieClipsCommand::new(&clips);
+                let res = usecases::movie_clip_usecase::import_movie_clips(cmd).await;
+                if let Err(e) = res {
+                    log::error!("{} Cannot import movie_clips", e);
                 }
             });
         }),
@@ -84,12 +140,11 @@ pub fn AdminPage(cx: Scope) -> Element {
         id: "originals-json-loader".to_string(),
         on_upload: Rc::new(move |originals: Vec<Video<Original>>| {
             wasm_bindgen_futures::spawn_local(async move {
-                for original in originals.into_iter() {
-                    let cmd = commands::video_commands::SaveVideoCommand::new(&original);
-                    let res = usecases::video_usecase::save_video(cmd).await;
-                    if let Err(e) = res {
-                        log::error!("{} Cannot save original: {:?}", e, original);
-                    }
+                // id, likeを保ったまま復元する
+                let cmd = commands::video_commands::ImportVideosCommand::new(&originals);
+                let res = usecases::video_usecase::import_videos(cmd).await;
+                if let Err(e) = res {
+                    log::error!("{} Cannot import originals", e);
                 }
             });
         }),
@@ -107,12 +162,11 @@ pub fn AdminPage(cx: Scope) -> Element {
         id: "kirinukis-json-loader".to_string(),
         on_upload: Rc::new(move |kirinukis: Vec<Video<Kirinuki>>| {
             wasm_bindgen_futures::spawn_local(async move {
-                for kirinuki in kirinukis.into_iter() {
-                    let cmd = commands::video_commands::SaveVideoCommand::new(&kirinuki);
-                    let res = usecases::video_usecase::save_video(cmd).await;
-                    if let Err(e) = res {
-                        log::error!("{} Cannot save kirinuki: {:?}", e, kirinuki);
-                    }
+                // id, likeを保ったまま復元する
+                let cmd = commands::video_commands::ImportVideosCommand::new(&kirinukis);
+                let res = usecases::video_usecase::import_videos(cmd).await;
+                if let Err(e) = res {
+                    log::error!("{} Cannot import kirinukis", e);
                 }
             });
         }),
@@ -124,10 +178,36 @@ pub fn AdminPage(cx: Scope) -> Element {
         }),
     };
 
+    // クイズに関するJsonLoaderの引数
+    let quizzes_json_loader_props = JsonLoaderProps {
+        title: "クイズデータ".to_string(),
+        id: "quizzes-json-loader".to_string(),
+        on_upload: Rc::new(move |quizzes: Vec<Quiz>| {
+            wasm_bindgen_futures::spawn_local(async move {
+                for quiz in quizzes.into_iter() {
+                    let cmd = commands::quiz_commands::SaveQuizCommand::new(&quiz);
+                    let res = usecases::quiz_usecase::save_quiz(cmd).await;
+                    if let Err(e) = res {
+                        log::error!("{} Cannot save quiz: {:?}", e, quiz);
+                    }
+                }
+            });
+        }),
+        make_json_source: Rc::new(|| async move {
+            let cmd = commands::quiz_commands::AllQuizzesCommand;
+            usecases::quiz_usecase::all_quizzes(cmd)
+                .await
+                .expect("All quizzes fetch failed.")
+        }),
+    };
+
     cx.render(rsx! {
         is_admin_login_open.get().then(||{
             rsx!{
-                AdminLogin{on_cancel: close_admin_login}
+                AdminLogin{
+                    on_submit: submit_password,
+                    error_message: login_error_message.get().clone(),
+                }
             }
         })
 
@@ -138,12 +218,20 @@ pub fn AdminPage(cx: Scope) -> Element {
             Link{ to: "/admin/originals", "コラボ配信"}
             Link{ to: "/admin/kirinukis", "切り抜き"}
 
-            div {id: "admin-json-loader-container",
-                JsonLoader{..episode_json_loader_props},
-                JsonLoader{..clips_json_loader_props},
-                JsonLoader{..originals_json_loader_props},
-                JsonLoader{..kirinukis_json_loader_props}
-            }
+            // jsonのインポート・ユーザーの管理・編集履歴は管理者のみ
+            (*role_state.get() == Some(Role::Admin)).then(||{
+                rsx!{
+                    div {id: "admin-json-loader-container",
+                        JsonLoader{..episode_json_loader_props},
+                        JsonLoader{..clips_json_loader_props},
+                        JsonLoader{..originals_json_loader_props},
+                        JsonLoader{..kirinukis_json_loader_props},
+                        JsonLoader{..quizzes_json_loader_props}
+                    }
+                    UserManager{}
+                    RevisionManager{}
+                }
+            })
         }
     })
 }
@@ -1,29 +1,28 @@
-use crate::AdminPassword;
-
 use dioxus::prelude::*;
 use std::cell::Cell;
 use std::rc::Rc;
 
 #[derive(Props)]
 pub struct AdminLoginProps<'a> {
-    on_cancel: EventHandler<'a>,
+    // ユーザー名とパスワードを送信した時の処理．ユーザー名が空の場合は管理者としてログインする
+    on_submit: EventHandler<'a, (String, String)>,
+    // ログインに失敗した時のメッセージ
+    #[props(!optional)]
+    error_message: Option<String>,
 }
 
 pub fn AdminLogin<'a>(cx: Scope<'a, AdminLoginProps<'a>>) -> Element {
+    let name_state = cx.use_hook(|| Rc::new(Cell::new("".to_string())));
     let value_state = cx.use_hook(|| Rc::new(Cell::new("".to_string())));
-    let correct_value = use_shared_state::<AdminPassword>(cx)
-        .expect("Cannot get ShareState: AdminPassword")
-        .read()
-        .0
-        .clone();
 
     let submit_password = {
-        to_owned![value_state];
+        to_owned![name_state, value_state];
         move |_| {
+            let name = name_state.take();
+            name_state.set(name.clone());
             let value = value_state.take();
-            if value == correct_value {
-                cx.props.on_cancel.call(());
-            }
+            value_state.set(value.clone());
+            cx.props.on_submit.call((name, value));
         }
     };
 
@@ -31,10 +30,17 @@ pub fn AdminLogin<'a>(cx: Scope<'a, AdminLoginProps<'a>>) -> Element {
         div {id: "admin-login-container",
             div {id: "admin-login-ui-container",
                 div {id: "admin-login-caption", "管理者ページへログイン"}
-                div {id: "admin-login-input-container",
+                div {class: "admin-login-input-container",
+                    div {"ユーザー名"}
+                    input {id: "admin-login-name", r#type: "text", placeholder: "空欄の場合は管理者", oninput: move |e|{name_state.set(e.value.clone())}}
+                }
+                div {class: "admin-login-input-container",
                     div {"パスワード"}
-                    input {id: "admin-login-password", oninput: move |e|{value_state.set(e.value.clone())}}
+                    input {id: "admin-login-password", r#type: "password", oninput: move |e|{value_state.set(e.value.clone())}}
                 }
+                cx.props.error_message.as_ref().map(|message|{
+                    rsx!{div {class: "error-message", "{message}"}}
+                })
                 div {id: "admin-login-bottom",
                     button {onclick: submit_password, "ログイン"}
                 }
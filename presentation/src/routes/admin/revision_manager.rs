@@ -0,0 +1,211 @@
+use domain::revision::{Actor, EntityKind, FieldChange, Revision, RevisionId};
+use frontend::{commands, usecases};
+
+use dioxus::prelude::*;
+
+/// 一度に表示する最近の編集履歴の数
+const LATEST_LENGTH: usize = 30;
+
+/// 最近の編集履歴を取得してステートに反映する
+async fn fetch_latest_revisions(kind: EntityKind, revisions: UseState<Vec<Revision>>) {
+    let res = {
+        let cmd = commands::revision_commands::LatestRevisionsCommand::new(kind, LATEST_LENGTH);
+        usecases::revision_usecase::latest_revisions(cmd).await
+    };
+
+    match res {
+        Ok(new_revisions) => revisions.set(new_revisions),
+        Err(e) => log::error!("{e}"),
+    }
+}
+
+/// `revision`と同じエンティティの編集履歴を取得してステートに反映する
+async fn fetch_entity_revisions(revision: &Revision, revisions: UseState<Vec<Revision>>) {
+    let res = {
+        let cmd = commands::revision_commands::EntityRevisionsCommand::new(
+            revision.entity_kind(),
+            revision.entity_id(),
+        );
+        usecases::revision_usecase::entity_revisions(cmd).await
+    };
+
+    match res {
+        Ok(new_revisions) => revisions.set(new_revisions),
+        Err(e) => log::error!("{e}"),
+    }
+}
+
+/// 操作者の表示用の文字列
+fn actor_label(actor: Actor) -> String {
+    match actor {
+        Actor::User(user_id) => format!("ユーザー({user_id})"),
+        Actor::Admin => "管理者".to_string(),
+        Actor::Anonymous => "匿名".to_string(),
+    }
+}
+
+/// エピソード・クリップ・動画の編集履歴を参照し，過去の内容に戻すコンポーネント．管理者のみ利用できる．
+pub fn RevisionManager(cx: Scope) -> Element {
+    let kind_state = use_state(cx, || EntityKind::MovieClip);
+    let latest_revisions = use_state(cx, Vec::<Revision>::new);
+    // 選択したエンティティの編集履歴(古い順)
+    let entity_revisions = use_state(cx, Vec::<Revision>::new);
+    // 選択した編集履歴と一つ前の編集履歴との差分
+    let changes = use_state(cx, || Option::<Vec<FieldChange>>::None);
+    let error_message = use_state(cx, || Option::<String>::None);
+
+    use_effect(cx, (kind_state,), {
+        to_owned![latest_revisions, entity_revisions, changes];
+        |(kind,)| async move {
+            entity_revisions.set(Vec::new());
+            changes.set(None);
+            fetch_latest_revisions(*kind.current(), latest_revisions).await
+        }
+    });
+
+    // エンティティを選択したときの処理
+    let select_entity = move |revision: Revision| {
+        cx.spawn({
+            to_owned![entity_revisions, changes];
+            async move {
+                changes.set(None);
+                fetch_entity_revisions(&revision, entity_revisions).await
+            }
+        });
+    };
+
+    // 一つ前の編集履歴との差分を表示するときの処理
+    let show_diff = move |(from, to): (RevisionId, RevisionId)| {
+        cx.spawn({
+            to_owned![kind_state, changes];
+            async move {
+                let res = {
+                    let cmd = commands::revision_commands::RevisionDiffCommand::new(
+                        *kind_state.get(),
+                        from,
+                        to,
+                    );
+                    usecases::revision_usecase::revision_diff(cmd).await
+                };
+
+                match res {
+                    Ok(new_changes) => changes.set(Some(new_changes)),
+                    Err(e) => log::error!("{e}"),
+                }
+            }
+        });
+    };
+
+    // 編集履歴の時点の内容に戻すときの処理
+    let restore = move |revision: Revision| {
+        cx.spawn({
+            to_owned![kind_state, latest_revisions, entity_revisions, changes, error_message];
+            async move {
+                let res = {
+                    let cmd = commands::revision_commands::RestoreRevisionCommand::new(
+                        revision.entity_kind(),
+                        revision.id(),
+                    );
+                    usecases::revision_usecase::restore_revision(cmd).await
+                };
+
+                match res {
+                    Ok(_) => {
+                        error_message.set(None);
+                        changes.set(None);
+                        fetch_latest_revisions(*kind_state.get(), latest_revisions).await;
+                        fetch_entity_revisions(&revision, entity_revisions).await;
+                    }
+                    Err(e) => {
+                        log::error!("{e}");
+                        error_message.set(Some("※復元に失敗しました".to_string()));
+                    }
+                }
+            }
+        });
+    };
+
+    cx.render(rsx! {
+        div {id: "revision-manager-container",
+            div {class: "revision-manager-caption", "編集履歴"}
+            select {
+                onchange: move |e|{
+                    let kind: EntityKind = e.value.parse().expect("Enum Parse Error");
+                    kind_state.set(kind);
+                },
+                option {value: "movie_clip", "クリップ"}
+                option {value: "episode", "エピソード"}
+                option {value: "original", "コラボ配信"}
+                option {value: "kirinuki", "切り抜き"}
+            }
+            div {class: "revision-manager-list",
+                latest_revisions.get().iter().map(|revision|{
+                    let actor = actor_label(revision.actor());
+                    rsx!{
+                        div {class: "revision-manager-item", key: "{revision.id()}",
+                            div {"{revision.created_at()}"}
+                            div {"{revision.operation()}"}
+                            div {"{actor}"}
+                            div {class: "revision-manager-entity-id", "{revision.entity_id()}"}
+                            button {onclick: {
+                                let revision = revision.clone();
+                                move |_|{select_entity(revision.clone())}
+                            }, "履歴"}
+                        }
+                    }
+                })
+            }
+            (!entity_revisions.get().is_empty()).then(||{
+                rsx!{
+                    div {class: "revision-manager-caption", "エンティティの履歴"}
+                    div {class: "revision-manager-list",
+                        entity_revisions.get().iter().enumerate().map(|(i, revision)|{
+                            let actor = actor_label(revision.actor());
+                            let previous = i.checked_sub(1).map(|i| entity_revisions.get()[i].id());
+                            let id = revision.id();
+                            rsx!{
+                                div {class: "revision-manager-item", key: "{revision.id()}",
+                                    div {"{revision.created_at()}"}
+                                    div {"{revision.operation()}"}
+                                    div {"{actor}"}
+                                    previous.map(|previous|{
+                                        rsx!{
+                                            button {onclick: move |_|{show_diff((previous, id))}, "差分"}
+                                        }
+                                    })
+                                    button {onclick: {
+                                        let revision = revision.clone();
+                                        move |_|{restore(revision.clone())}
+                                    }, "この時点に戻す"}
+                                }
+                            }
+                        })
+                    }
+                }
+            })
+            changes.get().as_ref().map(|changes|{
+                rsx!{
+                    div {class: "revision-manager-caption", "差分"}
+                    div {class: "revision-manager-list",
+                        changes.iter().map(|change|{
+                            let before = change.before.as_ref().map_or("-".to_string(), |value| value.to_string());
+                            let after = change.after.as_ref().map_or("-".to_string(), |value| value.to_string());
+                            rsx!{
+                                div {class: "revision-manager-change", key: "{change.field}",
+                                    div {"{change.field}"}
+                                    div {class: "revision-manager-before", "{before}"}
+                                    div {class: "revision-manager-after", "{after}"}
+                                }
+                            }
+                        })
+                    }
+                }
+            })
+            error_message.get().as_ref().map(|error_message|{
+                rsx!{
+                    div {class: "revision-manager-error", "{error_message}"}
+                }
+            })
+        }
+    })
+}
@@ -0,0 +1,130 @@
+use domain::user::{Role, UserId};
+use frontend::{commands, usecases, AppCommonError, AppFrontError, NewUserInfo, UserInfo};
+
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// ユーザーの一覧を取得してステートに反映する
+async fn fetch_users(users: UseState<Vec<UserInfo>>) {
+    let res = {
+        let cmd = commands::user_commands::AllUsersCommand;
+        usecases::user_usecase::all_users(cmd).await
+    };
+
+    match res {
+        Ok(new_users) => users.set(new_users),
+        Err(e) => log::error!("{e}"),
+    }
+}
+
+/// 協力者などのユーザーを追加・削除するコンポーネント．管理者のみ利用できる．
+pub fn UserManager(cx: Scope) -> Element {
+    let users = use_state(cx, Vec::<UserInfo>::new);
+    let error_message = use_state(cx, || Option::<String>::None);
+
+    let name_state = cx.use_hook(|| Rc::new(Cell::new("".to_string())));
+    let password_state = cx.use_hook(|| Rc::new(Cell::new("".to_string())));
+    let role_state = cx.use_hook(|| Rc::new(Cell::new(Role::Contributor)));
+
+    use_effect(cx, (), {
+        to_owned![users];
+        |_| fetch_users(users)
+    });
+
+    // ユーザーを追加したときの処理
+    let add_user = {
+        to_owned![name_state, password_state, role_state];
+        move |_| {
+            let name = name_state.take();
+            name_state.set(name.clone());
+            let password = password_state.take();
+            password_state.set(password.clone());
+
+            if name.trim().is_empty() || password.is_empty() {
+                error_message.set(Some("※ユーザー名とパスワードを入力してください".to_string()));
+                return;
+            }
+
+            let user_info = NewUserInfo::new(name.trim(), password, role_state.get());
+
+            cx.spawn({
+                to_owned![users, error_message];
+                async move {
+                    let res = {
+                        let cmd = commands::user_commands::SaveUserCommand::new(&user_info);
+                        usecases::user_usecase::save_user(cmd).await
+                    };
+
+                    match res {
+                        Ok(_) => {
+                            error_message.set(None);
+                            fetch_users(users).await;
+                        }
+                        Err(AppFrontError::CommonError(AppCommonError::ConflictError)) => {
+                            error_message.set(Some("※既に存在するユーザー名です".to_string()));
+                        }
+                        Err(e) => {
+                            log::error!("{e}");
+                            error_message.set(Some("※ユーザーの追加に失敗しました".to_string()));
+                        }
+                    }
+                }
+            });
+        }
+    };
+
+    // ユーザーを削除したときの処理
+    let remove_user = move |id: UserId| {
+        cx.spawn({
+            to_owned![users];
+            async move {
+                let res = {
+                    let cmd = commands::user_commands::RemoveUserCommand::new(id);
+                    usecases::user_usecase::remove_user(cmd).await
+                };
+
+                match res {
+                    Ok(_) => fetch_users(users).await,
+                    Err(e) => log::error!("{e}"),
+                }
+            }
+        });
+    };
+
+    cx.render(rsx! {
+        div {id: "user-manager-container",
+            div {class: "user-manager-caption", "ユーザー"}
+            div {class: "user-manager-list",
+                users.get().iter().map(|user|{
+                    let id = user.id;
+                    rsx!{
+                        div {class: "user-manager-item", key: "{user.id}",
+                            div {"{user.name}"}
+                            div {"{user.role}"}
+                            button {onclick: move |_|{remove_user(id)}, "削除"}
+                        }
+                    }
+                })
+            }
+            div {class: "user-manager-input-container",
+                input {r#type: "text", placeholder: "ユーザー名", oninput: move |e|{name_state.set(e.value.clone())}}
+                input {r#type: "password", placeholder: "パスワード", oninput: move |e|{password_state.set(e.value.clone())}}
+                select {
+                    onchange: move |e|{
+                        let role: Role = e.value.parse().expect("Enum Parse Error");
+                        role_state.set(role);
+                    },
+                    option {value: "contributor", "協力者"}
+                    option {value: "admin", "管理者"}
+                }
+                button {onclick: add_user, "追加"}
+            }
+            error_message.get().as_ref().map(|error_message|{
+                rsx!{
+                    div {class: "user-manager-error", "{error_message}"}
+                }
+            })
+        }
+    })
+}
@@ -0,0 +1,176 @@
+use crate::components::{Player, Spinner};
+use crate::ENDED_PLAYER_ID;
+use domain::clip_series::{ClipSeries, ClipSeriesId};
+use domain::movie_clip::MovieClip;
+
+use frontend::{
+    commands::{clip_series_commands, movie_clip_commands},
+    usecases::{clip_series_usecase, movie_clip_usecase},
+};
+
+use dioxus::prelude::*;
+use dioxus_router::{use_route, Link};
+use fermi::use_read;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// シリーズのプレーヤーのid
+fn series_player_id(movie_clip: &MovieClip) -> String {
+    format!("clip-series-player-{}", movie_clip.id())
+}
+
+pub fn ClipSeriesPage(cx: Scope) -> Element {
+    let series_id = use_route(cx)
+        .segment("id")
+        .and_then(|id| id.parse::<ClipSeriesId>().ok());
+
+    let clip_series_state = use_state(cx, || Option::<Rc<ClipSeries>>::None);
+    // シリーズの順番に並べた再生可能なクリップ
+    let playlist_state = use_state(cx, Vec::<Rc<MovieClip>>::new);
+    let current_index = use_state(cx, || 0_usize);
+    let is_not_found = use_state(cx, || false);
+
+    let ended_player_id = use_read(cx, ENDED_PLAYER_ID);
+
+    // 状態の初期化(最初のみ実行)
+    use_effect(cx, (), {
+        to_owned![clip_series_state, playlist_state, is_not_found];
+        |_| async move {
+            let clip_series = match series_id {
+                Some(series_id) => {
+                    let cmd = clip_series_commands::FindClipSeriesCommand::new(series_id);
+                    clip_series_usecase::find_clip_series(cmd).await
+                }
+                None => {
+                    is_not_found.set(true);
+                    return;
+                }
+            };
+
+            let clip_series = match clip_series {
+                Ok(clip_series) => clip_series,
+                Err(e) => {
+                    log::error!("{}", e);
+                    is_not_found.set(true);
+                    return;
+                }
+            };
+
+            // シリーズに含まれるクリップを取得
+            let movie_clips = {
+                let cmd = movie_clip_commands::AllMovieClipsCommand;
+                movie_clip_usecase::all_movie_clips(cmd).await
+            };
+
+            match movie_clips {
+                Ok(movie_clips) => {
+                    let mut movie_clips_map = movie_clips
+                        .into_iter()
+                        .map(|clip| (clip.id(), clip))
+                        .collect::<HashMap<_, _>>();
+
+                    // 削除されたクリップは飛ばす
+                    let playlist = clip_series
+                        .clips()
+                        .iter()
+                        .filter_map(|id| movie_clips_map.remove(id))
+                        .map(Rc::new)
+                        .collect::<Vec<_>>();
+
+                    playlist_state.set(playlist);
+                }
+                Err(e) => log::error!("{}", e),
+            }
+
+            clip_series_state.set(Some(Rc::new(clip_series)));
+        }
+    });
+
+    // 再生範囲の終わりに達したときに次のクリップへ移る
+    use_effect(cx, ended_player_id, {
+        to_owned![clip_series_state, playlist_state, current_index];
+        |ended_player_id| async move {
+            let clip_series = (*clip_series_state.current()).clone();
+            let playlist = playlist_state.current();
+            let current_clip = playlist.get(*current_index.current());
+
+            if let (Some(ended_player_id), Some(clip_series), Some(current_clip)) =
+                (ended_player_id, clip_series, current_clip)
+            {
+                // 現在のクリップが終わった場合のみ
+                if ended_player_id != series_player_id(current_clip) {
+                    return;
+                }
+
+                // 再生可能な次のクリップを探す
+                let mut next_clip_id = clip_series.next_clip(current_clip.id());
+                while let Some(clip_id) = next_clip_id {
+                    if let Some(position) = playlist.iter().position(|clip| clip.id() == clip_id) {
+                        current_index.set(position);
+                        break;
+                    }
+                    next_clip_id = clip_series.next_clip(clip_id);
+                }
+            }
+        }
+    });
+
+    cx.render(rsx! {
+        div { id: "clip-series-container",
+            match (*is_not_found.get(), clip_series_state.get()) {
+                (true, _) => rsx! {
+                    div { id: "clip-series-not-found",
+                        "シリーズが見つかりませんでした"
+                    }
+                    Link {to: "/clips", "クリップのページへ戻る"}
+                },
+                (false, Some(clip_series)) => {
+                    let playlist = playlist_state.get();
+                    rsx! {
+                        div {id: "clip-series-title-container",
+                            h2 {id: "clip-series-title", "{clip_series.title()}"}
+                        }
+                        div {id: "clip-series-caption", "{clip_series.description()}"}
+                        playlist.get(*current_index.get()).map(|movie_clip| {
+                            let player_id = series_player_id(movie_clip);
+                            rsx! {
+                                div { id: "clip-series-player",
+                                    Player {
+                                        key: "{player_id}",
+                                        id: player_id.clone(),
+                                        movie_url: movie_clip.url().clone(),
+                                        range: Some(movie_clip.range().clone()),
+                                        // 二つ目以降のクリップは続けて再生する
+                                        autoplay: *current_index.get() != 0,
+                                    }
+                                }
+                            }
+                        })
+                        ol { id: "clip-series-playlist",
+                            playlist.iter().enumerate().map(|(i, movie_clip)| {
+                                let playing_class = match i == *current_index.get() {
+                                    true => " playing",
+                                    false => ""
+                                };
+                                rsx! {
+                                    li { key: "{movie_clip.id()}",
+                                        class: "clip-series-playlist-item{playing_class}",
+                                        onclick: move |_| current_index.set(i),
+                                        "{movie_clip.title()}"
+                                    }
+                                }
+                            })
+                        }
+                    }
+                }
+                (false, None) => rsx! {
+                    div { id: "clip-series-loading-container",
+                        div { id: "clip-series-loading-spinner",
+                            Spinner{}
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
@@ -1,17 +1,18 @@
 mod edit_clip;
 
-use crate::components::{MovieCard, MovieContainer, IntersectionBottom, Quiz, VideoPageMenu, Spinner};
+use crate::components::{MovieCard, MovieContainer, IntersectionBottom, Quiz, VideoPageMenu, Spinner, STALE_VERSION_MESSAGE};
 use crate::utils::{use_overlay, get_liked_ids, push_liked_id};
 use domain::movie_clip::MovieClip;
+use domain::Tag;
 use edit_clip::EditMovieClip;
 
-use frontend::{commands::movie_clip_commands, usecases::movie_clip_usecase, AppCommonError, AppFrontError};
+use frontend::{commands::movie_clip_commands, usecases::movie_clip_usecase, AppCommonError, AppFrontError, NewMovieClipInfo};
 
 use dioxus::prelude::*;
 use gloo_intersection::IntersectionObserverHandler;
 use strum_macros::{Display, EnumIter, EnumString};
 use std::rc::Rc;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashSet;
 
 enum EditMovieClipOpen {
@@ -38,11 +39,18 @@ pub struct ClipsPageProps {
 pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
     let movie_clips_ref = use_ref(cx, || Option::<Vec<Rc<MovieClip>>>::None);
     let is_load_continue = cx.use_hook(|| Rc::new(Cell::new(true)));
+    // 続きを取得するためのカーソル
+    let next_cursor = cx.use_hook(|| Rc::new(RefCell::new(Option::<String>::None)));
     let sort_type_state = use_state(cx, SortType::default);
+    // 絞り込むタグ
+    let tag_state = use_state(cx, || Option::<Tag>::None);
+    let all_tags = use_state(cx, Vec::<Tag>::new);
     let init_liked_ids = use_state(cx, HashSet::<String>::new);
 
     // AddMovieClip関連
     let edit_movie_clip_open = use_state(cx, || EditMovieClipOpen::Close);
+    // 編集に失敗した場合にモーダルに表示するメッセージ
+    let edit_error_message = use_state(cx, || Option::<String>::None);
     let overlay_state = use_overlay(cx, 2);
 
     // 新規追加モーダルを開いたときの処理
@@ -54,11 +62,12 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
     // モーダルを閉じたときの処理
     let close_edit_movie_clip = move |_| {
         edit_movie_clip_open.set(EditMovieClipOpen::Close);
+        edit_error_message.set(None);
         overlay_state.deactivate();
     };
     // 状態の初期化(最初のみ実行)
     use_effect(cx, (), {
-        to_owned![init_liked_ids];
+        to_owned![init_liked_ids, all_tags];
         |_| async move {
             match get_liked_ids() {
                 Ok(liked_ids) => {
@@ -68,34 +77,41 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
                     log::error!("{e}");
                 }
             }
+
+            let cmd = movie_clip_commands::AllMovieClipTagsCommand;
+            match movie_clip_usecase::all_movie_clip_tags(cmd).await {
+                Ok(tags) => all_tags.set(tags),
+                Err(e) => log::error!("{e}"),
+            }
         }
     });
 
-    // 状態の初期化(ソートタイプの変更)
-    use_effect(cx, sort_type_state, {
-        to_owned![movie_clips_ref, is_load_continue];
-        |sort_type| async move {
+    // 状態の初期化(ソートタイプ・タグの変更)
+    use_effect(cx, (sort_type_state, tag_state), {
+        to_owned![movie_clips_ref, is_load_continue, next_cursor];
+        |(sort_type, tag)| async move {
             // ロードを許可
             is_load_continue.set(true);
+            let tags = tag.current().iter().cloned().collect::<Vec<_>>();
 
             // データをフェッチ
             let res = match *sort_type.current() {
                 SortType::CreateDate => {
-                    let cmd = movie_clip_commands::OrderByCreateDateMovieClipsCommand::new(20);
+                    let cmd = movie_clip_commands::OrderByCreateDateMovieClipsCommand::new(&tags, 20);
                     movie_clip_usecase::order_by_create_date_movie_clips(cmd).await
                 },
                 SortType::Like => {
-                    let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(20);
+                    let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(&tags, 20);
                     movie_clip_usecase::order_by_like_movie_clips(cmd).await
                 }
             };
 
             match res {
-                Ok(new_movie_clips) => {
-                    // データが一つも取得できない場合以降のデータのロードを拒否
-                    if new_movie_clips.is_empty() {
-                        is_load_continue.set(false);
-                    }
+                Ok(page) => {
+                    let new_movie_clips = page.items;
+                    // 続きが存在しない場合は以降のロードを拒否
+                    is_load_continue.set(page.has_more);
+                    next_cursor.replace(page.next_cursor);
 
                     movie_clips_ref.set(Some(new_movie_clips.into_iter().map(|clip|{Rc::new(clip)}).collect()));
                 },
@@ -107,42 +123,35 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
     // 底が交差するときのオブザーバー
     let intersection_handler = cx.use_hook(||{
         let handler = IntersectionObserverHandler::new({
-            to_owned![movie_clips_ref, is_load_continue, sort_type_state];
+            to_owned![movie_clips_ref, is_load_continue, next_cursor, sort_type_state, tag_state];
             move |entries, _| {
                 let target_entry = entries.into_iter().next().expect("Observe sanity check");
                 if is_load_continue.get() && target_entry.is_intersecting() {
                     {
-                        to_owned![movie_clips_ref, is_load_continue, sort_type_state];
+                        to_owned![movie_clips_ref, is_load_continue, next_cursor, sort_type_state, tag_state];
                         wasm_bindgen_futures::spawn_local(async move {
-                            // 最後の値を取得
-                            let last_movie_clip = movie_clips_ref.with(
-                                |movie_clips_opt|{
-                                    if let Some(movie_clips) = movie_clips_opt.as_ref() {
-                                        movie_clips.last().cloned()
-                                    } else {
-                                        None
-                                    }
-                                }
-                            );
+                            // 前のページのカーソルを取得
+                            let cursor = next_cursor.borrow().clone();
+                            let tags = tag_state.current().iter().cloned().collect::<Vec<_>>();
 
-                            if let Some(last_movie_clip) = last_movie_clip {
+                            if let Some(cursor) = cursor {
                                 // データをフェッチ
                                 let res = match *sort_type_state.current() {
                                     SortType::CreateDate => {
-                                        let cmd = movie_clip_commands::OrderByCreateDateLaterMovieClipsCommand::new(&last_movie_clip,20);
+                                        let cmd = movie_clip_commands::OrderByCreateDateLaterMovieClipsCommand::new(&tags, &cursor,20);
                                         movie_clip_usecase::order_by_create_date_later_movie_clips(cmd).await
                                     },
                                     SortType::Like => {
-                                        let cmd = movie_clip_commands::OrderByLikeLaterMovieClipsCommand::new(&last_movie_clip,20);
+                                        let cmd = movie_clip_commands::OrderByLikeLaterMovieClipsCommand::new(&tags, &cursor,20);
                                         movie_clip_usecase::order_by_like_later_movie_clips(cmd).await
                                     }
                                 };
                                 match res {
-                                    Ok(new_movie_clips) => {
-                                        // データが一つも取得できない場合以降のデータのロードを拒否
-                                        if new_movie_clips.is_empty(){
-                                            is_load_continue.set(false);
-                                        }
+                                    Ok(page) => {
+                                        let new_movie_clips = page.items;
+                                        // 続きが存在しない場合は以降のロードを拒否
+                                        is_load_continue.set(page.has_more);
+                                        next_cursor.replace(page.next_cursor);
 
                                         movie_clips_ref.with_mut(|movie_clips_vec|{
                                             if let Some(movie_clips_vec) = movie_clips_vec.as_mut() {
@@ -190,21 +199,34 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
             to_owned![movie_clips_ref];
             async move {          
                 let res = {
-                    let cmd = movie_clip_commands::SaveMovieClipCommand::new(&new_movie_clip);
+                    let new_movie_clip_info = NewMovieClipInfo::from(MovieClip::clone(&new_movie_clip));
+                    let cmd = movie_clip_commands::SaveMovieClipCommand::new(&new_movie_clip_info);
                     movie_clip_usecase::save_movie_clip(cmd).await
                 };
 
-                // レスポンスがエラーの場合
-                if let Err(e) = res {
-                    log::error!("{}", e);
+                match res {
+                    // サーバーがid等を決定したクリップに置き換える
+                    Ok(saved_movie_clip) => {
+                        movie_clips_ref.with_mut(|movie_clips|{
+                            if let Some(movie_clips) = movie_clips.as_mut() {
+                                if let Some(movie_clip) = movie_clips.iter_mut().find(|clip|{clip.id() == new_movie_clip.id()}) {
+                                    *movie_clip = Rc::new(saved_movie_clip);
+                                }
+                            }
+                        })
+                    },
+                    // レスポンスがエラーの場合
+                    Err(e) => {
+                        log::error!("{}", e);
 
-                    // new_movie_clipを削除
-                    movie_clips_ref.with_mut(|movie_clips|{
-                        if let Some(movie_clips) = movie_clips.as_mut() {
-                            log::info!("Removed movie_clip: {:?}", new_movie_clip);
-                            movie_clips.retain(|clip|{clip.id() != new_movie_clip.id()});
-                        }
-                    })
+                        // new_movie_clipを削除
+                        movie_clips_ref.with_mut(|movie_clips|{
+                            if let Some(movie_clips) = movie_clips.as_mut() {
+                                log::info!("Removed movie_clip: {:?}", new_movie_clip);
+                                movie_clips.retain(|clip|{clip.id() != new_movie_clip.id()});
+                            }
+                        })
+                    }
                 }
             }
         });
@@ -232,13 +254,24 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
 
         // API
         cx.spawn({
-            to_owned![movie_clips_ref];
+            to_owned![movie_clips_ref, edit_movie_clip_open, edit_error_message, overlay_state];
             async move {
                 let res = {
                     let cmd = movie_clip_commands::EditMovieClipCommand::new(&modified_movie_clip);
                     movie_clip_usecase::edit_movie_clip(cmd).await
                 };
 
+                // 成功した場合はサーバーと同様に版数を進める
+                if res.is_ok() {
+                    movie_clips_ref.with_mut(|movie_clips_opt|{
+                        if let Some(movie_clips) = movie_clips_opt.as_mut() {
+                            if let Some(found_movie_clip) = movie_clips.iter_mut().find(|movie_clip|{movie_clip.id() == modified_movie_clip.id()}) {
+                                Rc::make_mut(found_movie_clip).increment_version();
+                            }
+                        }
+                    });
+                }
+
                 // レスポンスがエラーの場合
                 if let Err(e) = res {
                     log::error!("{}", e);
@@ -260,9 +293,16 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
 
                                     log::info!("Roll backed movie_clip: {:?}", old_movie_clip);
                                     // 更新したデータをロールバック
-                                    *found_movie_clip = old_movie_clip;
+                                    *found_movie_clip = old_movie_clip.clone();
                                 }
                             });
+
+                            // 他の人が編集していた場合はメッセージと共にモーダルを開き直す
+                            if matches!(e, AppFrontError::CommonError(AppCommonError::StaleVersion)) {
+                                edit_error_message.set(Some(STALE_VERSION_MESSAGE.to_string()));
+                                edit_movie_clip_open.set(EditMovieClipOpen::Modify(old_movie_clip));
+                                overlay_state.activate().expect("Cannot Overlay activate.");
+                            }
                         }
                     }
                 }
@@ -353,12 +393,20 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
                 on_change_sort_select: move |sort_type: SortType|{
                     sort_type_state.set(sort_type);
                 },
+                tags: all_tags.get().as_slice(),
+                on_change_tag: move |tag: Option<Tag>|{
+                    tag_state.set(tag);
+                },
             }
             match edit_movie_clip_open.get() {
                 EditMovieClipOpen::Add => rsx!{
-                    EditMovieClip{
-                        on_submit: add_submitted_clip,
+                    Quiz{
                         on_cancel: close_edit_movie_clip,
+                        admin: cx.props.admin,
+                        EditMovieClip{
+                            on_submit: add_submitted_clip,
+                            on_cancel: close_edit_movie_clip,
+                        }
                     }
                 },
                 EditMovieClipOpen::Modify(movie_clip) => rsx!{
@@ -371,7 +419,8 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
                                     base_movie_clip: movie_clip.clone(),
                                     on_submit: modify_submitted_clip,
                                     on_cancel: close_edit_movie_clip,
-                                    on_remove: remove_clip
+                                    on_remove: remove_clip,
+                                    error_message: edit_error_message.get().clone(),
                                 }
                             },
                             false => rsx!{ // 管理者でない時
@@ -379,6 +428,7 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
                                     base_movie_clip: movie_clip.clone(),
                                     on_submit: modify_submitted_clip,
                                     on_cancel: close_edit_movie_clip,
+                                    error_message: edit_error_message.get().clone(),
                                 }
                             }
                         }
@@ -398,7 +448,7 @@ pub fn ClipsPage(cx: Scope<ClipsPageProps>) -> Element {
                             rsx!{
                                 MovieCard{
                                     key:"{id}",
-                                    date: movie_clip.create_date(),
+                                    date: movie_clip.create_date().into(),
                                     range: movie_clip.range().clone(),
                                     title: movie_clip.title(),
                                     movie_url: movie_clip.url().clone(),
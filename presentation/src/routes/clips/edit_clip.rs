@@ -1,17 +1,32 @@
-use crate::components::{EditModal, InputType, MovieCard, RequiredString, ValidationInput};
-use domain::movie_clip::{MovieClip, MovieUrl, Second};
+use crate::components::{
+    EditModal, InputType, MovieCard, OptionalInput, RequiredString, ValidationInput,
+};
+use domain::movie_clip::{MovieClip, MovieUrl, Second, SecondRange};
+use domain::{Date, DomainError, ValidationError};
 
-use chrono::Local;
 use dioxus::prelude::*;
 use std::rc::Rc;
-use wasm_bindgen::UnwrapThrowExt;
 
-#[derive(Clone, Default)]
+#[derive(Clone)]
 struct MovieClipForm {
     title: Option<String>,
     url: Option<MovieUrl>,
     start: Option<Second>,
     end: Option<Second>,
+    /// 元動画の長さ．入力が無効な場合はNone，空の場合はSome(None)
+    duration: Option<Option<Second>>,
+}
+
+impl Default for MovieClipForm {
+    fn default() -> Self {
+        Self {
+            title: None,
+            url: None,
+            start: None,
+            end: None,
+            duration: Some(None),
+        }
+    }
 }
 
 impl TryFrom<MovieClipForm> for MovieClip {
@@ -22,16 +37,33 @@ impl TryFrom<MovieClipForm> for MovieClip {
             value.end.ok_or("終了時間が無効です".to_string())?,
         );
 
-        let create_date = Local::now().naive_utc().date();
-
-        Ok(MovieClip::new_with_domains(
+        MovieClip::new_with_domains(
             value.title.ok_or("タイトルが無効です")?,
             value.url.ok_or("Urlが無効です．".to_string())?,
             (start..end)
                 .try_into()
                 .map_err(|_| "再生範囲が無効です".to_string())?,
-            create_date.try_into().unwrap_throw(),
-        ))
+            Date::today(),
+        )
+        .with_duration(value.duration.ok_or("元動画の長さが無効です".to_string())?)
+        .map_err(|_| "再生範囲が元動画の長さを超えています".to_string())
+    }
+}
+
+impl MovieClipForm {
+    /// 開始時間と終了時間がともに有効な場合の再生範囲のバリデーションエラー．元動画の長さが分かる場合はそれも検証する
+    fn range_error(&self) -> Option<ValidationError> {
+        let (start, end) = (self.start?, self.end?);
+        let res = SecondRange::try_from(start..end).and_then(|range| {
+            match self.duration.flatten() {
+                Some(duration) => range.check_duration(duration),
+                None => Ok(()),
+            }
+        });
+        match res {
+            Err(DomainError::DomainValidationError(validation_error)) => Some(validation_error),
+            _ => None,
+        }
     }
 }
 
@@ -48,6 +80,8 @@ pub struct EditMovieClipProps<'a> {
     on_cancel: EventHandler<'a, ()>,
     // 削除時の処理
     on_remove: Option<EventHandler<'a, Rc<MovieClip>>>,
+    // 送信に失敗した場合のメッセージ
+    error_message: Option<String>,
 }
 
 pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
@@ -58,6 +92,7 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
                 start: Some(base_movie_clip.range().start()),
                 end: Some(base_movie_clip.range().end()),
                 url: Some(base_movie_clip.url().clone()),
+                duration: Some(base_movie_clip.duration()),
             }
         } else {
             MovieClipForm::default()
@@ -69,10 +104,15 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
         true => "クリップを編集",
     };
 
+    let range_error = movie_clip_form.with(|form| form.range_error());
+    // urlのタイムスタンプが示す開始時間
+    let url_start = movie_clip_form.with(|form| form.url.as_ref().and_then(|url| url.start()));
+
     // フォーム入力部分
     let input_element = rsx! {
         ValidationInput{
             class: "edit-clip-input-title",
+            field: "title",
             on_input: move |title: Option<RequiredString>|{
                 movie_clip_form.with_mut(|form|{form.title = title.map(|title|{title.to_string()})
             })},
@@ -88,14 +128,21 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
         }
         ValidationInput{
             class: "edit-clip-input-url",
+            field: "url",
             on_input: move |url: Option<MovieUrl>|{
-                movie_clip_form.with_mut(|form|{form.url = url})
+                movie_clip_form.with_mut(|form|{
+                    // urlにタイムスタンプがある場合は開始時間とする
+                    if let Some(start) = url.as_ref().and_then(|url|{url.start()}) {
+                        form.start = Some(start);
+                    }
+                    form.url = url;
+                })
             },
             error_message: "※無効なurlです",
             label_component: cx.render(rsx!{
                 div { class: "label-container",
                     div { class:"label-main", "クリップの動画のurl"}
-                    div { class:"label-detail", "youtubeのみ可能です"}
+                    div { class:"label-detail", "YouTube・Twitch・ニコニコ動画・Xのurlが可能です．タイムスタンプ付きのurlの場合は開始時間に反映されます．"}
                 }
             }),
             required: true,
@@ -104,6 +151,8 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
         }
         ValidationInput{
             class: "edit-clip-input-start",
+            field: "range.start",
+            validation_error: range_error.clone(),
             on_input: move |start: Option<Second>|{
                 movie_clip_form.with_mut(|form|{form.start = start})
             },
@@ -111,15 +160,18 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
             label_component: cx.render(rsx!{
                 div { class: "label-container",
                     div { class:"label-main", "クリップの開始時間"}
-                    div { class:"label-detail", "秒数で指定してください．"}
+                    div { class:"label-detail", "秒数または1:23:45・1h23m45sの形式で指定してください．"}
                 }
             }),
             required: true,
-            input_type: InputType::InputNum,
-            initial_value: cx.props.base_movie_clip.as_ref().map(|clip|{clip.range().start()})
+            input_type: InputType::InputText,
+            initial_value: cx.props.base_movie_clip.as_ref().map(|clip|{clip.range().start()}),
+            preset_value: url_start,
         }
         ValidationInput{
             class: "edit-clip-input-end",
+            field: "range.end",
+            validation_error: range_error,
             on_input: move |end: Option<Second>|{
                 movie_clip_form.with_mut(|form|{form.end = end})
             },
@@ -127,13 +179,29 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
             label_component: cx.render(rsx!{
                 div { class: "label-container",
                     div { class:"label-main", "クリップの終了時間"}
-                    div { class:"label-detail", "秒数で指定してください．"}
+                    div { class:"label-detail", "秒数または1:23:45・1h23m45sの形式で指定してください．"}
                 }
             }),
             required: true,
-            input_type: InputType::InputNum,
+            input_type: InputType::InputText,
             initial_value: cx.props.base_movie_clip.as_ref().map(|clip|{clip.range().end()})
         }
+        ValidationInput{
+            class: "edit-clip-input-duration",
+            field: "duration",
+            on_input: move |duration: Option<OptionalInput<Second>>|{
+                movie_clip_form.with_mut(|form|{form.duration = duration.map(|duration|{duration.0})})
+            },
+            error_message: "※無効な動画の長さです",
+            label_component: cx.render(rsx!{
+                div { class: "label-container",
+                    div { class:"label-main", "元動画の長さ"}
+                    div { class:"label-detail", "任意です．指定した場合は終了時間が長さ以内か検証されます．"}
+                }
+            }),
+            input_type: InputType::InputText,
+            initial_value: cx.props.base_movie_clip.as_ref().and_then(|clip|{clip.duration()}).map(|duration|{OptionalInput(Some(duration))})
+        }
     };
 
     // プレビュー部分
@@ -144,7 +212,7 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
                     MovieCard{
                         range: movie_clip.range().clone(),
                         title: movie_clip.title(),
-                        date: movie_clip.create_date(),
+                        date: movie_clip.create_date().into(),
                         id: "movie-clip-preview-player",
                         movie_url: movie_clip.url().clone(),
                     }
@@ -183,6 +251,7 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
                     caption: input_caption.to_string(),
                     on_cancel: move |_| {cx.props.on_cancel.call(())},
                     on_remove: move |_| {on_remove.call(base_movie_clip.clone())},
+                    error_message: cx.props.error_message.clone(),
                     input: cx.render(input_element),
                     preview: cx.render(preview_element)
                 }
@@ -192,6 +261,7 @@ pub fn EditMovieClip<'a>(cx: Scope<'a, EditMovieClipProps<'a>>) -> Element {
                 EditModal{
                     caption: input_caption.to_string(),
                     on_cancel: move |_| {cx.props.on_cancel.call(())},
+                    error_message: cx.props.error_message.clone(),
                     input: cx.render(input_element),
                     preview: cx.render(preview_element)
                 }
@@ -1,6 +1,6 @@
 mod edit_episode;
 
-use crate::components::{AccordionEpisodes, AddButton, Quiz};
+use crate::components::{AccordionEpisodes, AddButton, Quiz, STALE_VERSION_MESSAGE};
 use crate::utils::use_overlay;
 use domain::{episode::Episode, Date};
 use edit_episode::EditEpisode;
@@ -39,6 +39,8 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
 
     // AddButton関連
     let edit_episode_open = use_state(cx, || EditEpisodeOpen::Close);
+    // 編集に失敗した場合にモーダルに表示するメッセージ
+    let edit_error_message = use_state(cx, || Option::<String>::None);
     let is_add_button_show = use_state(cx, || cx.props.initial_is_open);
     let overlay_state = use_overlay(cx, 2);
 
@@ -51,6 +53,7 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
     // モーダルを閉じるときの処理
     let close_add_episode = move |_| {
         edit_episode_open.set(EditEpisodeOpen::Close);
+        edit_error_message.set(None);
         overlay_state.deactivate();
     };
 
@@ -72,7 +75,7 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
             // initial_is_openかtrueの場合にデータをフェッチ
             if initial_is_open {
                 let res = {
-                    let cmd = episode_commands::OrderByDateRangeEpisodesCommand::new(start, end);
+                    let cmd = episode_commands::OrderByDateRangeEpisodesCommand::new(&[], start, end);
                     episode_usecase::order_by_date_range_episodes(cmd).await
                 };
 
@@ -103,7 +106,7 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
                 if episodes_ref.with(|episodes_opt| episodes_opt.is_none()) {
                     let res = {
                         let cmd =
-                            episode_commands::OrderByDateRangeEpisodesCommand::new(start, end);
+                            episode_commands::OrderByDateRangeEpisodesCommand::new(&[], start, end);
                         episode_usecase::order_by_date_range_episodes(cmd).await
                     };
 
@@ -201,13 +204,27 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
 
         // API
         cx.spawn({
-            to_owned![episodes_ref];
+            to_owned![episodes_ref, edit_episode_open, edit_error_message, overlay_state];
             async move {
                 let res = {
                     let cmd = episode_commands::EditEpisodeCommand::new(&modified_episode);
                     episode_usecase::edit_episode(cmd).await
                 };
 
+                // 成功した場合はサーバーと同様に版数を進める
+                if res.is_ok() {
+                    episodes_ref.with_mut(|episodes_opt| {
+                        if let Some(episodes) = episodes_opt.as_mut() {
+                            if let Some(found_episode) = episodes
+                                .iter_mut()
+                                .find(|episode| episode.id() == modified_episode.id())
+                            {
+                                Rc::make_mut(found_episode).increment_version();
+                            }
+                        }
+                    });
+                }
+
                 // レスポンスがエラーの場合
                 if let Err(e) = res {
                     log::error!("{}", e);
@@ -233,9 +250,16 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
                                         .expect("Cannot find old episode");
 
                                     log::info!("Roll back episode: {:?}", old_episode);
-                                    *found_episode = old_episode;
+                                    *found_episode = old_episode.clone();
                                 }
                             });
+
+                            // 他の人が編集していた場合はメッセージと共にモーダルを開き直す
+                            if matches!(e, AppFrontError::CommonError(AppCommonError::StaleVersion)) {
+                                edit_error_message.set(Some(STALE_VERSION_MESSAGE.to_string()));
+                                edit_episode_open.set(EditEpisodeOpen::Modify(old_episode));
+                                overlay_state.activate().expect("Cannot Overlay activate");
+                            }
                         }
                     }
                 }
@@ -320,9 +344,13 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
         match edit_episode_open.get() {
             EditEpisodeOpen::Add => {
                 rsx! {
-                    EditEpisode{
-                        on_submit: add_submitted_episode,
-                        on_cancel: close_add_episode
+                    Quiz{
+                        on_cancel: close_add_episode,
+                        admin: cx.props.admin,
+                        EditEpisode{
+                            on_submit: add_submitted_episode,
+                            on_cancel: close_add_episode
+                        }
                     }
                 }
             },
@@ -337,7 +365,8 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
                                     on_submit: modify_submitted_episode,
                                     base_episode: episode.clone(),
                                     on_cancel: close_add_episode,
-                                    on_remove: remove_episode
+                                    on_remove: remove_episode,
+                                    error_message: edit_error_message.get().clone(),
                                 }
                             },
                             false => rsx!{ // 管理者でない時
@@ -345,6 +374,7 @@ pub fn RangeEpisodes(cx: Scope<RangeEpisodesProps>) -> Element {
                                     on_submit: modify_submitted_episode,
                                     base_episode: episode.clone(),
                                     on_cancel: close_add_episode,
+                                    error_message: edit_error_message.get().clone(),
                                 }
                             }
                         }
@@ -1,7 +1,7 @@
 use crate::components::{EditModal, InputType, ValidationInput};
 use domain::{
     episode::{Episode, EpisodeContent},
-    Date,
+    Date, DateTime,
 };
 
 use dioxus::prelude::*;
@@ -9,7 +9,7 @@ use std::rc::Rc;
 
 #[derive(Clone, Default)]
 struct EpisodeForm {
-    date: Option<Date>,
+    date: Option<DateTime>,
     content: Option<EpisodeContent>,
 }
 
@@ -38,6 +38,8 @@ pub struct EditEpisodeProps<'a> {
     on_cancel: EventHandler<'a, ()>,
     // 削除時の処理
     on_remove: Option<EventHandler<'a, Rc<Episode>>>,
+    /// 送信に失敗した場合のメッセージ
+    error_message: Option<String>,
 }
 
 pub fn EditEpisode<'a>(cx: Scope<'a, EditEpisodeProps<'a>>) -> Element {
@@ -61,7 +63,18 @@ pub fn EditEpisode<'a>(cx: Scope<'a, EditEpisodeProps<'a>>) -> Element {
     let input_element = rsx! {
         ValidationInput{
             class:"edit-episode-input-date",
-            on_input: move |value: Option<Date>|{episode_form.with_mut(|form|{form.date = value})},
+            field: "date",
+            on_input: move |value: Option<Date>|{
+                episode_form.with_mut(|form|{
+                    // 日付が変わらない場合は元の時刻を保つ
+                    form.date = value.map(|date|{
+                        match form.date {
+                            Some(date_time) if date_time.date() == date => date_time,
+                            _ => date.into(),
+                        }
+                    })
+                })
+            },
             error_message: "※有効なDateではありません",
             label_component: cx.render(rsx!{
                 div { class: "label-container",
@@ -71,10 +84,11 @@ pub fn EditEpisode<'a>(cx: Scope<'a, EditEpisodeProps<'a>>) -> Element {
             }),
             required: true,
             input_type:InputType::InputDate,
-            initial_value: cx.props.base_episode.as_ref().map(|episode|{episode.date()}),
+            initial_value: cx.props.base_episode.as_ref().map(|episode|{episode.date().date()}),
         }
         ValidationInput{
             class:"edit-episode-input-content",
+            field: "content",
             on_input: move |value: Option<EpisodeContent>|{episode_form.with_mut(|form|{form.content = value})},
             error_message: "※無効なhtmlが含まれています",
             label_component: cx.render(rsx!{
@@ -94,7 +108,7 @@ pub fn EditEpisode<'a>(cx: Scope<'a, EditEpisodeProps<'a>>) -> Element {
         match TryInto::<Episode>::try_into(episode_form.with(|form|{form.clone()})) {
             Ok(episode) => {
                 let content = episode.content().to_string();
-                let (year, month, day) = episode.date().to_ymd();
+                let (year, month, day) = episode.date().date().to_ymd();
                 rsx! {
                     ul {
                         li{
@@ -135,6 +149,7 @@ pub fn EditEpisode<'a>(cx: Scope<'a, EditEpisodeProps<'a>>) -> Element {
                     caption: input_caption.to_string(),
                     on_cancel: move |_| {cx.props.on_cancel.call(())},
                     on_remove: move |_| {on_remove.call(base_episode.clone())},
+                    error_message: cx.props.error_message.clone(),
                     input: cx.render(input_element),
                     preview: cx.render(preview_element)
                 }
@@ -144,6 +159,7 @@ pub fn EditEpisode<'a>(cx: Scope<'a, EditEpisodeProps<'a>>) -> Element {
                 EditModal{
                     caption: input_caption.to_string(),
                     on_cancel: move |_| {cx.props.on_cancel.call(())},
+                    error_message: cx.props.error_message.clone(),
                     input: cx.render(input_element),
                     preview: cx.render(preview_element)
                 }
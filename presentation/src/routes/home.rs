@@ -8,7 +8,7 @@ use domain::{
     episode::Episode,
     movie_clip::MovieClip,
     video::{Kirinuki, Original, Video},
-    Date,
+    Date, MovieUrl,
 };
 use more_button::MoreButton;
 use toc::{Toc, TocContent};
@@ -57,6 +57,7 @@ pub fn HomePage(cx: Scope) -> Element {
             // episodesの初期化
             {
                 let cmd = episode_commands::OrderByDateRangeEpisodesCommand::new(
+                    &[],
                     episode_start,
                     episode_end,
                 );
@@ -73,30 +74,30 @@ pub fn HomePage(cx: Scope) -> Element {
             }
             // movie_clipsの初期化
             {
-                let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(6);
+                let cmd = movie_clip_commands::OrderByLikeMovieClipsCommand::new(&[], 6);
                 let res = movie_clip_usecase::order_by_like_movie_clips(cmd).await;
                 match res {
-                    Ok(movie_clips) => movie_clips_ref.set(Some(movie_clips)),
+                    Ok(page) => movie_clips_ref.set(Some(page.items)),
                     Err(e) => log::error!("{}", e),
                 }
             }
 
             // originalsの初期化
             {
-                let cmd = video_commands::OrderByLikeVideosCommand::new(6);
+                let cmd = video_commands::OrderByLikeVideosCommand::new(&[], 6);
                 let res = video_usecase::order_by_like_videos(cmd).await;
                 match res {
-                    Ok(originals) => originals_ref.set(Some(originals)),
+                    Ok(page) => originals_ref.set(Some(page.items)),
                     Err(e) => log::error!("{}", e),
                 }
             }
 
             // kirinukisの初期化
             {
-                let cmd = video_commands::OrderByLikeVideosCommand::new(6);
+                let cmd = video_commands::OrderByLikeVideosCommand::new(&[], 6);
                 let res = video_usecase::order_by_like_videos(cmd).await;
                 match res {
-                    Ok(kirinukis) => kirinukis_ref.set(Some(kirinukis)),
+                    Ok(page) => kirinukis_ref.set(Some(page.items)),
                     Err(e) => log::error!("{}", e),
                 }
             }
@@ -113,7 +114,7 @@ pub fn HomePage(cx: Scope) -> Element {
                     div { id: "orikou-desc-string", dangerous_inner_html: "{orikou_desc_str}"}
                     div { id: "orikou-desc-movie-container-outer",
                         div { id: "orikou-desc-movie-container-inner",
-                            Player{id:"orikou-desc-movie-player", video_id:"B7OPlsdBuVc", range:None}
+                            Player{id:"orikou-desc-movie-player", movie_url: MovieUrl::from_url_str("https://www.youtube.com/watch?v=B7OPlsdBuVc").expect("Required sanity check"), range:None}
                         }
                     }
                 }
@@ -143,7 +144,7 @@ pub fn HomePage(cx: Scope) -> Element {
                                     rsx!{
                                         MovieCard{
                                             key:"{id}",
-                                            date: movie_clip.create_date(),
+                                            date: movie_clip.create_date().into(),
                                             range: movie_clip.range().clone(),
                                             title: movie_clip.title(),
                                             movie_url: movie_clip.url().clone(),
@@ -0,0 +1,176 @@
+use crate::components::{validation_message, MovieCard, MovieContainer, Spinner};
+use domain::search::{SearchHit, SearchItem, SearchQuery};
+use domain::DomainError;
+use frontend::{commands::search_commands, usecases::search_usecase};
+
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// 一度に取得する検索結果の数
+const SEARCH_LENGTH: usize = 50;
+
+/// 検索結果の種類の表示名
+fn kind_label(item: &SearchItem) -> &'static str {
+    match item {
+        SearchItem::MovieClip(_) => "クリップ",
+        SearchItem::Original(_) => "コラボ配信",
+        SearchItem::Kirinuki(_) => "切り抜き",
+        SearchItem::Episode(_) => "エピソード",
+    }
+}
+
+pub fn SearchPage(cx: Scope) -> Element {
+    let value_state = cx.use_hook(|| Rc::new(Cell::new(String::new())));
+
+    // 検索結果．検索していない場合はNone
+    let hits_state = use_state(cx, || Option::<Rc<Vec<SearchHit<SearchItem>>>>::None);
+    let is_searching = use_state(cx, || false);
+    let error_message = use_state(cx, || Option::<String>::None);
+
+    let onsubmit = move |_| {
+        let value = value_state.take();
+        value_state.set(value.clone());
+
+        let query = match SearchQuery::new(&value) {
+            Ok(query) => query,
+            Err(DomainError::DomainValidationError(validation_error)) => {
+                error_message.set(Some(validation_message(&validation_error)));
+                return;
+            }
+            Err(e) => {
+                log::error!("{}", e);
+                return;
+            }
+        };
+
+        is_searching.set(true);
+        cx.spawn({
+            to_owned![hits_state, is_searching, error_message];
+            async move {
+                let res = {
+                    let cmd = search_commands::SearchCommand::new(&query, SEARCH_LENGTH);
+                    search_usecase::search(cmd).await
+                };
+                match res {
+                    Ok(hits) => {
+                        error_message.set(None);
+                        hits_state.set(Some(Rc::new(hits)));
+                    }
+                    Err(e) => {
+                        log::error!("{}", e);
+                        error_message.set(Some("※検索できませんでした".to_string()));
+                    }
+                }
+                is_searching.set(false);
+            }
+        });
+    };
+
+    cx.render(rsx! {
+        div { id: "search-container",
+            div { id: "search-title-container",
+                h2 { id: "search-title", "検索"}
+            }
+            div { id: "search-desc", "クリップ・エピソード・動画をまとめて検索します．空白で区切った語を全て含むものが表示されます．"}
+            div { id: "search-input-container",
+                input { id: "search-input", r#type: "search", placeholder: "キーワード",
+                    onchange: move |e|{value_state.set(e.value.clone())}
+                }
+                button { id: "search-button", onclick: onsubmit, "検索する"}
+            }
+            error_message.get().as_ref().map(|message| rsx!{
+                div { id: "search-error-message", "{message}"}
+            })
+            if *is_searching.get() {
+                rsx! {
+                    div { id: "search-loading-spinner",
+                        Spinner{}
+                    }
+                }
+            } else if let Some(hits) = hits_state.get() {
+                if hits.is_empty() {
+                    rsx! {
+                        div { id: "search-no-result", "一致するものはありませんでした"}
+                    }
+                } else {
+                    rsx! {
+                        ul { id: "search-results",
+                            hits.iter().map(|hit| {
+                                let kind = kind_label(&hit.item);
+                                match &hit.item {
+                                    SearchItem::MovieClip(movie_clip) => {
+                                        let id = movie_clip.id();
+                                        rsx! {
+                                            li { key: "{id}", class: "search-result",
+                                                div { class: "search-result-kind", "{kind}"}
+                                                MovieContainer{
+                                                    MovieCard{
+                                                        date: movie_clip.create_date().into(),
+                                                        range: movie_clip.range().clone(),
+                                                        title: movie_clip.title(),
+                                                        movie_url: movie_clip.url().clone(),
+                                                        id: format!("search-movie-clip-{id}"),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    SearchItem::Original(video) => {
+                                        let id = video.id();
+                                        rsx! {
+                                            li { key: "{id}", class: "search-result",
+                                                div { class: "search-result-kind", "{kind}"}
+                                                MovieContainer{
+                                                    MovieCard{
+                                                        date: video.date(),
+                                                        title: video.title(),
+                                                        movie_url: video.url().clone(),
+                                                        author: video.author(),
+                                                        id: format!("search-video-{id}"),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    SearchItem::Kirinuki(video) => {
+                                        let id = video.id();
+                                        rsx! {
+                                            li { key: "{id}", class: "search-result",
+                                                div { class: "search-result-kind", "{kind}"}
+                                                MovieContainer{
+                                                    MovieCard{
+                                                        date: video.date(),
+                                                        title: video.title(),
+                                                        movie_url: video.url().clone(),
+                                                        author: video.author(),
+                                                        id: format!("search-video-{id}"),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    SearchItem::Episode(episode) => {
+                                        let (year, month, day) = episode.date().date().to_ymd();
+                                        let content = episode.content();
+                                        rsx! {
+                                            li { key: "{episode.id()}", class: "search-result",
+                                                div { class: "search-result-kind", "{kind}"}
+                                                div { class: "search-result-episode",
+                                                    span { class: "episode-date", format!("{year}/{month}/{day}")}
+                                                    span { class: "episode-content", dangerous_inner_html: "{content}"}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            })
+                        }
+                    }
+                }
+            } else {
+                rsx! {div {}}
+            }
+        }
+    })
+}
@@ -1,15 +1,16 @@
 mod edit_video;
 
-use crate::components::{IntersectionBottom, MovieCard, MovieContainer, Quiz, VideoPageMenu, Spinner};
+use crate::components::{IntersectionBottom, MovieCard, MovieContainer, Quiz, VideoPageMenu, Spinner, STALE_VERSION_MESSAGE};
 use crate::utils::use_overlay;
 use domain::video::{Video, VideoType};
+use domain::Tag;
 use edit_video::EditVideo;
-use frontend::{commands::video_commands, usecases::video_usecase, AppFrontError, AppCommonError};
+use frontend::{commands::video_commands, usecases::video_usecase, AppFrontError, AppCommonError, NewVideoInfo};
 use crate::utils::{get_liked_ids, push_liked_id};
 
 use dioxus::prelude::*;
 use gloo_intersection::IntersectionObserverHandler;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use std::collections::HashSet;
 use strum_macros::{Display, EnumIter, EnumString};
@@ -45,11 +46,18 @@ where
 {
     let videos_ref = use_ref(cx, || Option::<Vec<Rc<Video<T>>>>::None);
     let is_load_continue = cx.use_hook(|| Rc::new(Cell::new(true)));
+    // 続きを取得するためのカーソル
+    let next_cursor = cx.use_hook(|| Rc::new(RefCell::new(Option::<String>::None)));
     let sort_type_state = use_state(cx, SortType::default);
+    // 絞り込むタグ
+    let tag_state = use_state(cx, || Option::<Tag>::None);
+    let all_tags = use_state(cx, Vec::<Tag>::new);
     let init_liked_ids = use_state(cx, HashSet::<String>::new);
 
     // EditVideo関連
     let edit_video_open = use_state(cx, || EditVideoOpen::Close);
+    // 編集に失敗した場合にモーダルに表示するメッセージ
+    let edit_error_message = use_state(cx, || Option::<String>::None);
     let overlay_state = use_overlay(cx, 2);
 
     // 新規追加モーダルを開く処理
@@ -61,12 +69,13 @@ where
     // モーダルを閉じる処理
     let close_edit_video = move |_| {
         edit_video_open.set(EditVideoOpen::Close);
+        edit_error_message.set(None);
         overlay_state.deactivate();
     };
 
     // 状態の初期化(作成時のみ)
     use_effect(cx, (), {
-        to_owned![init_liked_ids];
+        to_owned![init_liked_ids, all_tags];
         |_| async move {
             match get_liked_ids() {
                 Ok(liked_ids) => {
@@ -76,34 +85,41 @@ where
                     log::error!("{e}");
                 }
             }
+
+            let cmd = video_commands::AllVideoTagsCommand;
+            match video_usecase::all_video_tags::<T>(cmd).await {
+                Ok(tags) => all_tags.set(tags),
+                Err(e) => log::error!("{e}"),
+            }
         }
     });
 
-    // 状態の初期化(ソートタイプに依存)
-    use_effect(cx, sort_type_state, {
-        to_owned![videos_ref, is_load_continue];
-        |sort_type| async move {
+    // 状態の初期化(ソートタイプ・タグに依存)
+    use_effect(cx, (sort_type_state, tag_state), {
+        to_owned![videos_ref, is_load_continue, next_cursor];
+        |(sort_type, tag)| async move {
             // ロードを許可
             is_load_continue.set(true);
+            let tags = tag.current().iter().cloned().collect::<Vec<_>>();
 
             // データをフェッチ
             let res = match *sort_type.current() {
                 SortType::Date => {
-                    let cmd = video_commands::OrderByDateVideosCommand::new(20);
+                    let cmd = video_commands::OrderByDateVideosCommand::new(&tags, 20);
                     video_usecase::order_by_date_videos(cmd).await
                 }
                 SortType::Like => {
-                    let cmd = video_commands::OrderByLikeVideosCommand::new(20);
+                    let cmd = video_commands::OrderByLikeVideosCommand::new(&tags, 20);
                     video_usecase::order_by_like_videos(cmd).await
                 }
             };
 
             match res {
-                Ok(new_videos) => {
-                    // データが一つも取得できない場合は今後のロードを拒否
-                    if new_videos.is_empty() {
-                        is_load_continue.set(false);
-                    }
+                Ok(page) => {
+                    let new_videos = page.items;
+                    // 続きが存在しない場合は以降のロードを拒否
+                    is_load_continue.set(page.has_more);
+                    next_cursor.replace(page.next_cursor);
 
                     videos_ref.set(Some(new_videos.into_iter().map(|video|{Rc::new(video)}).collect::<Vec<_>>()));
                 }
@@ -117,46 +133,43 @@ where
     // 底が交差するときのオブザーバー
     let intersection_handler = cx.use_hook(|| {
         let handler = IntersectionObserverHandler::new({
-            to_owned![videos_ref, is_load_continue, sort_type_state];
+            to_owned![videos_ref, is_load_continue, next_cursor, sort_type_state, tag_state];
             move |entries, _| {
                 let target_entry = entries.into_iter().next().expect("Observe sanity check");
                 if target_entry.is_intersecting() {
-                    to_owned![videos_ref, is_load_continue, sort_type_state];
+                    to_owned![videos_ref, is_load_continue, next_cursor, sort_type_state, tag_state];
                     wasm_bindgen_futures::spawn_local(async move {
-                        // 最後の値を取得
-                        let last_video = videos_ref.with(|videos_opt| {
-                            if let Some(videos) = videos_opt.as_ref() {
-                                videos.last().cloned()
-                            } else {
-                                None
-                            }
-                        });
+                        // 前のページのカーソルを取得
+                        let cursor = next_cursor.borrow().clone();
+                        let tags = tag_state.current().iter().cloned().collect::<Vec<_>>();
 
-                        if let Some(last_video) = last_video {
+                        if let Some(cursor) = cursor {
                             // データをフェッチ
                             let res = match *sort_type_state.current() {
                                 SortType::Date => {
                                     let cmd = video_commands::OrderByDateLaterVideosCommand::new(
-                                        &last_video,
+                                        &tags,
+                                        &cursor,
                                         20,
                                     );
-                                    video_usecase::order_by_date_later_videos(cmd).await
+                                    video_usecase::order_by_date_later_videos::<T>(cmd).await
                                 }
                                 SortType::Like => {
                                     let cmd = video_commands::OrderByLikeLaterVideosCommand::new(
-                                        &last_video,
+                                        &tags,
+                                        &cursor,
                                         20,
                                     );
-                                    video_usecase::order_by_like_later_videos(cmd).await
+                                    video_usecase::order_by_like_later_videos::<T>(cmd).await
                                 }
                             };
 
                             match res {
-                                Ok(new_videos) => {
-                                    // データが一つも取得できない場合以降のロードを拒否
-                                    if new_videos.is_empty() {
-                                        is_load_continue.set(false);
-                                    }
+                                Ok(page) => {
+                                    let new_videos = page.items;
+                                    // 続きが存在しない場合は以降のロードを拒否
+                                    is_load_continue.set(page.has_more);
+                                    next_cursor.replace(page.next_cursor);
                                     videos_ref.with_mut(|videos_opt| {
                                         if let Some(videos) = videos_opt.as_mut() {
                                             // 重複を防ぎながら挿入
@@ -206,20 +219,33 @@ where
             to_owned![videos_ref];
             async move {
                 let res = {
-                    let cmd = video_commands::SaveVideoCommand::new(&new_video);
-                    video_usecase::save_video(cmd).await
+                    let new_video_info = NewVideoInfo::from(Video::clone(&new_video));
+                    let cmd = video_commands::SaveVideoCommand::new(&new_video_info);
+                    video_usecase::save_video::<T>(cmd).await
                 };
 
-                if let Err(e) = res {
-                    log::error!("{}", e);
+                match res {
+                    // サーバーがid等を決定したVideoに置き換える
+                    Ok(saved_video) => {
+                        videos_ref.with_mut(|videos_opt| {
+                            if let Some(videos) = videos_opt.as_mut() {
+                                if let Some(video) = videos.iter_mut().find(|video| video.id() == new_video.id()) {
+                                    *video = Rc::new(saved_video);
+                                }
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        log::error!("{}", e);
 
-                    // new_videoを削除
-                    videos_ref.with_mut(|videos_opt| {
-                        if let Some(videos) = videos_opt.as_mut() {
-                            log::info!("Remove video: {:?}", new_video);
-                            videos.retain(|video| video.id() != new_video.id());
-                        }
-                    });
+                        // new_videoを削除
+                        videos_ref.with_mut(|videos_opt| {
+                            if let Some(videos) = videos_opt.as_mut() {
+                                log::info!("Remove video: {:?}", new_video);
+                                videos.retain(|video| video.id() != new_video.id());
+                            }
+                        });
+                    }
                 }
             }
         })
@@ -249,13 +275,27 @@ where
 
         // API
         cx.spawn({
-            to_owned![videos_ref];
+            to_owned![videos_ref, edit_video_open, edit_error_message, overlay_state];
             async move {
                 let res = {
                     let cmd = video_commands::EditVideoCommand::new(&modified_video);
                     video_usecase::edit_video(cmd).await
                 };
 
+                // 成功した場合はサーバーと同様に版数を進める
+                if res.is_ok() {
+                    videos_ref.with_mut(|videos_opt| {
+                        if let Some(videos) = videos_opt.as_mut() {
+                            if let Some(found_video) = videos
+                                .iter_mut()
+                                .find(|video| video.id() == modified_video.id())
+                            {
+                                Rc::make_mut(found_video).increment_version();
+                            }
+                        }
+                    });
+                }
+
                 // レスポンスがエラーの場合
                 if let Err(e) = res {
                     log::error!("{}", e);
@@ -282,9 +322,16 @@ where
     
                                     // ロールバック
                                     log::info!("Roll backed video: {:?}", modified_video);
-                                    *found_video = old_video;
+                                    *found_video = old_video.clone();
                                 }
                             });
+
+                            // 他の人が編集していた場合はメッセージと共にモーダルを開き直す
+                            if matches!(e, AppFrontError::CommonError(AppCommonError::StaleVersion)) {
+                                edit_error_message.set(Some(STALE_VERSION_MESSAGE.to_string()));
+                                edit_video_open.set(EditVideoOpen::Modify(old_video));
+                                overlay_state.activate().expect("Cannot Overlay activate.");
+                            }
                         }
                     }
                 }
@@ -363,12 +410,18 @@ where
             VideoPageMenu{
                 on_click_add_button: open_add_video,
                 on_change_sort_select: move |sort_type: SortType|{sort_type_state.set(sort_type)},
+                tags: all_tags.get().as_slice(),
+                on_change_tag: move |tag: Option<Tag>|{tag_state.set(tag)},
             }
             match edit_video_open.get() {
                 EditVideoOpen::Add => rsx!{
-                    EditVideo{
-                        on_submit: add_video,
-                        on_cancel: close_edit_video
+                    Quiz{
+                        on_cancel: close_edit_video,
+                        admin: cx.props.admin,
+                        EditVideo{
+                            on_submit: add_video,
+                            on_cancel: close_edit_video
+                        }
                     }
                 },
                 EditVideoOpen::Modify(modified_video) => rsx!{
@@ -381,7 +434,8 @@ where
                                     on_submit: modify_video,
                                     on_cancel: close_edit_video,
                                     base_video: modified_video.clone(),
-                                    on_remove: remove_video
+                                    on_remove: remove_video,
+                                    error_message: edit_error_message.get().clone(),
                                 }
                             },
                             false => rsx!{ // 管理者でない時
@@ -389,6 +443,7 @@ where
                                     on_submit: modify_video,
                                     on_cancel: close_edit_video,
                                     base_video: modified_video.clone(),
+                                    error_message: edit_error_message.get().clone(),
                                 }
                             }
                         }
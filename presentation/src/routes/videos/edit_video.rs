@@ -1,18 +1,38 @@
-use crate::components::{EditModal, InputType, MovieCard, RequiredString, ValidationInput};
+use crate::components::{
+    DateTimeInput, EditModal, InputType, MovieCard, OptionalInput, RequiredString, ValidationInput,
+};
 use domain::{
+    channel::Channel,
+    movie_clip::Second,
     video::{Video, VideoType},
-    Date, MovieUrl,
+    DateTime, MovieUrl,
 };
+use frontend::{commands, usecases};
 
 use dioxus::prelude::*;
 use std::rc::Rc;
 
-#[derive(Clone, Default)]
+#[derive(Clone)]
 struct VideoForm {
     title: Option<String>,
     url: Option<MovieUrl>,
-    date: Option<Date>,
-    author: Option<String>,
+    date: Option<DateTime>,
+    /// 動画を投稿したチャンネル．投稿者名はチャンネルの表示名となる
+    channel: Option<Channel>,
+    /// 動画の長さ．入力が無効な場合はNone，空の場合はSome(None)
+    duration: Option<Option<Second>>,
+}
+
+impl Default for VideoForm {
+    fn default() -> Self {
+        Self {
+            title: None,
+            url: None,
+            date: None,
+            channel: None,
+            duration: Some(None),
+        }
+    }
 }
 
 impl<T: VideoType> TryFrom<VideoForm> for Video<T> {
@@ -21,9 +41,12 @@ impl<T: VideoType> TryFrom<VideoForm> for Video<T> {
         Ok(Video::<T>::new_with_domains(
             value.title.ok_or("タイトルが無効です".to_string())?,
             value.url.ok_or("Urlが無効です".to_string())?,
-            value.date.ok_or("投稿日が無効です".to_string())?,
-            value.author.ok_or("投稿者が無効です".to_string())?,
-        ))
+            value.date.ok_or("配信日時が無効です".to_string())?,
+            &value
+                .channel
+                .ok_or("チャンネルが選択されていません".to_string())?,
+        )
+        .with_duration(value.duration.ok_or("動画の長さが無効です".to_string())?))
     }
 }
 
@@ -40,6 +63,8 @@ pub struct EditVideoProps<'a, T: VideoType> {
     on_cancel: EventHandler<'a, ()>,
     /// 削除時の処理
     on_remove: Option<EventHandler<'a, Rc<Video<T>>>>,
+    /// 送信に失敗した場合のメッセージ
+    error_message: Option<String>,
 }
 
 pub fn EditVideo<'a, T>(cx: Scope<'a, EditVideoProps<'a, T>>) -> Element
@@ -52,12 +77,41 @@ where
                 title: Some(base_video.title().to_string()),
                 url: Some(base_video.url().clone()),
                 date: Some(base_video.date()),
-                author: Some(base_video.author().to_string()),
+                channel: None,
+                duration: Some(base_video.duration()),
             }
         } else {
             VideoForm::default()
         }
     });
+    let channels = use_state(cx, Vec::<Channel>::new);
+
+    // チャンネルの一覧を取得し，編集モードの場合はベースのVideoのチャンネルを選択する
+    use_effect(cx, (), {
+        to_owned![channels, video_form];
+        let base_channel_id = cx.props.base_video.as_ref().map(|video| video.channel_id());
+        |_| async move {
+            let res = {
+                let cmd = commands::channel_commands::AllChannelsCommand;
+                usecases::channel_usecase::all_channels(cmd).await
+            };
+
+            match res {
+                Ok(mut new_channels) => {
+                    new_channels.sort_by(|x, y| x.name().cmp(y.name()));
+                    if let Some(base_channel_id) = base_channel_id {
+                        let base_channel = new_channels
+                            .iter()
+                            .find(|channel| channel.id() == base_channel_id)
+                            .cloned();
+                        video_form.with_mut(|video_form| video_form.channel = base_channel);
+                    }
+                    channels.set(new_channels);
+                }
+                Err(e) => log::error!("{e}"),
+            }
+        }
+    });
 
     let caption_name = &T::caption();
 
@@ -70,6 +124,7 @@ where
     let input_element = rsx! {
         ValidationInput{
             class: "edit-video-title",
+            field: "title",
             on_input: move |title: Option<RequiredString>|{
                 video_form.with_mut(|video_form|{video_form.title = title.map(|title|{title.to_string()})})
             },
@@ -85,6 +140,7 @@ where
         }
         ValidationInput{
             class: "edit-video-url",
+            field: "url",
             on_input: move |url: Option<MovieUrl>|{
                 video_form.with_mut(|video_form|{video_form.url = url})
             },
@@ -100,33 +156,56 @@ where
         }
         ValidationInput{
             class: "edit-video-date",
-            on_input: move |date: Option<Date>|{
-                video_form.with_mut(|video_form|{video_form.date = date})
+            field: "date",
+            on_input: move |date: Option<DateTimeInput>|{
+                video_form.with_mut(|video_form|{video_form.date = date.map(|date|{date.0})})
             },
-            error_message: "※無効な投稿日です",
+            error_message: "※無効な配信日時です",
             label_component: cx.render(rsx!{
                 div { class: "label-container",
-                    div { class:"label-main", "{caption_name}の投稿日"}
+                    div { class:"label-main", "{caption_name}の配信日時"}
+                    div { class:"label-detail", "日本時間で指定してください．"}
                 }
             }),
             required: true,
-            input_type: InputType::InputDate,
-            initial_value: cx.props.base_video.as_ref().map(|video|{video.date()})
+            input_type: InputType::InputDateTime,
+            initial_value: cx.props.base_video.as_ref().map(|video|{DateTimeInput(video.date())})
+        }
+        div { class: "label-container",
+            div { class:"label-main", "{caption_name}のチャンネル"}
+            div { class:"label-detail", "投稿者名はチャンネルの表示名となります．"}
+        }
+        select {
+            class: "edit-video-channel",
+            onchange: move |e|{
+                let channel = channels.get().iter().find(|channel|{channel.id().to_string() == e.value}).cloned();
+                video_form.with_mut(|video_form|{video_form.channel = channel})
+            },
+            option {value: "", "チャンネルを選択してください"}
+            channels.get().iter().map(|channel|{
+                let selected = video_form.with(|video_form|{
+                    video_form.channel.as_ref().map(|selected|{selected.id()}) == Some(channel.id())
+                });
+                rsx!{
+                    option {key: "{channel.id()}", value: "{channel.id()}", selected: "{selected}", "{channel.name()}"}
+                }
+            })
         }
         ValidationInput{
-            class: "edit-video-author",
-            on_input: move |author: Option<RequiredString>|{
-                video_form.with_mut(|video_form|{video_form.author = author.map(|author|{author.to_string()})})
+            class: "edit-video-duration",
+            field: "duration",
+            on_input: move |duration: Option<OptionalInput<Second>>|{
+                video_form.with_mut(|video_form|{video_form.duration = duration.map(|duration|{duration.0})})
             },
-            error_message: "※無効な投稿者です",
+            error_message: "※無効な動画の長さです",
             label_component: cx.render(rsx!{
                 div { class: "label-container",
-                    div { class:"label-main", "{caption_name}の投稿者"}
+                    div { class:"label-main", "{caption_name}の長さ"}
+                    div { class:"label-detail", "任意です．秒数または1:23:45の形式で指定してください．"}
                 }
             }),
-            required: true,
             input_type: InputType::InputText,
-            initial_value: cx.props.base_video.as_ref().map(|video|{video.author().to_string().try_into().expect("Required sanity check")})
+            initial_value: cx.props.base_video.as_ref().and_then(|video|{video.duration()}).map(|duration|{OptionalInput(Some(duration))})
         }
     };
 
@@ -177,6 +256,7 @@ where
                     caption: input_caption.to_string(),
                     on_cancel: move |_| {cx.props.on_cancel.call(())},
                     on_remove: move |_| {on_remove.call(base_video.clone())},
+                    error_message: cx.props.error_message.clone(),
                     input: cx.render(input_element),
                     preview: cx.render(preview_element)
                 }
@@ -186,6 +266,7 @@ where
                 EditModal{
                     caption: input_caption.to_string(),
                     on_cancel: move |_| {cx.props.on_cancel.call(())},
+                    error_message: cx.props.error_message.clone(),
                     input: cx.render(input_element),
                     preview: cx.render(preview_element)
                 }
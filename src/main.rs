@@ -1,17 +1,17 @@
-use presentation::{App, AppProps};
+use presentation::App;
 
 use axum::{extract::State, http::Response, response::IntoResponse};
 use dioxus::prelude::*;
-use shuttle_axum::ShuttleAxum;
 use shuttle_runtime::CustomError as ShuttleCustomError;
 use shuttle_secrets::SecretStore;
 use sqlx::postgres::PgPool;
 use std::convert::Infallible;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 
 /// dioxusアプリケーションのレンダリングを行う
-fn render(props: AppProps) -> String {
-    let mut vdom = VirtualDom::new_with_props(App, props);
+fn render() -> String {
+    let mut vdom = VirtualDom::new(App);
     let _ = vdom.rebuild();
 
     dioxus_ssr::pre_render(&vdom)
@@ -28,6 +28,20 @@ async fn serve_text(State(full_html): State<String>) -> impl IntoResponse {
     Result::<_, Infallible>::Ok(response)
 }
 
+/// 接続元のアドレスを`ConnectInfo`として渡すaxumのサービス．レート制限でクライアントを区別するために用いる
+struct AxumConnectInfoService(axum::Router);
+
+#[shuttle_runtime::async_trait]
+impl shuttle_runtime::Service for AxumConnectInfoService {
+    async fn bind(mut self, addr: SocketAddr) -> Result<(), shuttle_runtime::Error> {
+        axum::Server::bind(&addr)
+            .serve(self.0.into_make_service_with_connect_info::<SocketAddr>())
+            .await
+            .map_err(ShuttleCustomError::new)?;
+        Ok(())
+    }
+}
+
 /// メインサーバー
 #[shuttle_runtime::main]
 async fn main_server(
@@ -37,17 +51,24 @@ async fn main_server(
     pool: PgPool,
     #[shuttle_static_folder::StaticFolder(folder = "dist_ssr")] static_folder: PathBuf,
     #[shuttle_secrets::Secrets] secret_store: SecretStore,
-) -> ShuttleAxum {
+) -> Result<AxumConnectInfoService, shuttle_runtime::Error> {
     use domain::video::{Kirinuki, Original};
 
-    use serverside::handlers::{episode_handlers, movie_clip_handlers, video_handlers};
+    use serverside::auth::AuthConfig;
+    use serverside::handlers::{
+        auth_handlers, channel_handlers, clip_series_handlers, episode_handlers,
+        movie_clip_handlers, quiz_handlers, search_handlers, user_handlers, video_handlers,
+        SessionUserRepository,
+    };
+    use serverside::rate_limit::{self, RateLimiter};
 
     use std::sync::Arc;
 
     use axum::{
         http::StatusCode,
-        routing::{delete, get, get_service, patch, put},
-        Router,
+        middleware,
+        routing::{delete, get, get_service, patch, post, put},
+        Extension, Router,
     };
 
     use tower_http::services::ServeDir;
@@ -59,12 +80,27 @@ async fn main_server(
         .map_err(|e| ShuttleCustomError::msg(format!("Migration error. {e}")))?;
 
     // Secretsの読み込み
-    let admin_password = secret_store
-        .get("admin_password")
+    let admin_password_hash =
+        secret_store
+            .get("admin_password_hash")
+            .ok_or(ShuttleCustomError::msg(format!(
+                "Cannot get admin_password_hash from Secrets.toml."
+            )))?;
+
+    let session_secret = secret_store
+        .get("session_secret")
         .ok_or(ShuttleCustomError::msg(format!(
-            "Cannot get admin_password from Secrets.toml."
+            "Cannot get session_secret from Secrets.toml."
         )))?;
 
+    // 前段の信頼するプロキシの数．設定されている場合のみX-Forwarded-Forからクライアントのアドレスを得る
+    let trusted_proxies = match secret_store.get("trusted_proxies") {
+        Some(trusted_proxies) => trusted_proxies.parse::<usize>().map_err(|e| {
+            ShuttleCustomError::msg(format!("Invalid trusted_proxies in Secrets.toml. {e}"))
+        })?,
+        None => 0,
+    };
+
     // Htmlの作成・ディレクトリサーバー
 
     let index_html_text = include_str!("../dist_ssr/index.html");
@@ -82,13 +118,49 @@ async fn main_server(
     </html>
             "#,
         base_html,
-        render(AppProps { admin_password })
+        render()
     );
 
     let serve_dir = ServeDir::new(static_folder)
         .append_index_html_on_directories(false)
         .fallback(get(serve_text).with_state(full_html));
 
+    // 認証
+    let auth_config = Arc::new(AuthConfig::new(admin_password_hash, session_secret));
+    let rate_limiter = Arc::new(RateLimiter::from_config().with_trusted_proxies(trusted_proxies));
+    let user_repo =
+        Arc::new(infrastructure::user_repository_impl::UserPgDBRepository::new(pool.clone()));
+    let auth_api_router: Router<()> = Router::new()
+        .route("/auth/login", post(auth_handlers::login))
+        .route("/auth/logout", post(auth_handlers::logout))
+        .route("/auth/client", post(auth_handlers::issue_client))
+        .route("/auth/session", get(auth_handlers::check_session))
+        .with_state(user_repo.clone());
+
+    // UserについてのAPI
+    let user_api_router: Router<()> = Router::new()
+        .route(
+            "/user",
+            put(user_handlers::save_user).get(user_handlers::all_users),
+        )
+        .route("/user/:id", delete(user_handlers::remove_user))
+        .with_state(user_repo.clone());
+
+    // QuizについてのAPI
+    let quiz_repo =
+        Arc::new(infrastructure::quiz_repository_impl::QuizPgDBRepository::new(pool.clone()));
+    let quiz_api_router: Router<()> = Router::new()
+        .route(
+            "/quiz",
+            put(quiz_handlers::save_quiz)
+                .patch(quiz_handlers::edit_quiz)
+                .get(quiz_handlers::all_quizzes),
+        )
+        .route("/quiz/question", get(quiz_handlers::question))
+        .route("/quiz/answer", post(quiz_handlers::answer))
+        .route("/quiz/:id", delete(quiz_handlers::remove_quiz))
+        .with_state(quiz_repo);
+
     // EpisodeについてのAPI
     let episode_repo =
         Arc::new(infrastructure::episode_repository_impl::EpisodePgDBRepository::new(pool.clone()));
@@ -104,7 +176,36 @@ async fn main_server(
             get(episode_handlers::get_episodes_with_query),
         )
         .route("/episode/:id", delete(episode_handlers::remove_episode))
-        .with_state(episode_repo);
+        .route("/episode/tags", get(episode_handlers::all_episode_tags))
+        .route(
+            "/episode/tags/:id",
+            get(episode_handlers::episode_tags)
+                .put(episode_handlers::add_episode_tag)
+                .delete(episode_handlers::remove_episode_tag),
+        )
+        .route(
+            "/episode/revisions",
+            get(episode_handlers::latest_episode_revisions),
+        )
+        .route(
+            "/episode/revisions/:id",
+            get(episode_handlers::episode_revisions),
+        )
+        .route(
+            "/episode/revision_diff",
+            get(episode_handlers::episode_revision_diff),
+        )
+        .route(
+            "/episode/restore/:id",
+            patch(episode_handlers::restore_episode_revision),
+        )
+        .route("/episode/trash", get(episode_handlers::trashed_episodes))
+        .route(
+            "/episode/trash/:id",
+            patch(episode_handlers::restore_trashed_episode)
+                .delete(episode_handlers::purge_episode),
+        )
+        .with_state(episode_repo.clone());
 
     // MovieClipについてのAPI
     let movie_clip_repo = Arc::new(
@@ -119,8 +220,7 @@ async fn main_server(
         )
         .route(
             "/movie_clip/query",
-            get(movie_clip_handlers::get_movie_clips_with_query)
-                .post(movie_clip_handlers::get_movie_clips_with_query),
+            get(movie_clip_handlers::get_movie_clips_with_query),
         )
         .route(
             "/movie_clip/:id",
@@ -130,7 +230,92 @@ async fn main_server(
             "/movie_clip/increment_like/:id",
             patch(movie_clip_handlers::increment_like_movie_clip),
         )
-        .with_state(movie_clip_repo);
+        .route(
+            "/movie_clip/unlike/:id",
+            patch(movie_clip_handlers::unlike_movie_clip),
+        )
+        .route(
+            "/movie_clip/liked",
+            post(movie_clip_handlers::liked_movie_clip_ids),
+        )
+        .route(
+            "/movie_clip/tags",
+            get(movie_clip_handlers::all_movie_clip_tags),
+        )
+        .route(
+            "/movie_clip/tags/:id",
+            get(movie_clip_handlers::movie_clip_tags)
+                .put(movie_clip_handlers::add_movie_clip_tag)
+                .delete(movie_clip_handlers::remove_movie_clip_tag),
+        )
+        .route(
+            "/movie_clip/revisions",
+            get(movie_clip_handlers::latest_movie_clip_revisions),
+        )
+        .route(
+            "/movie_clip/revisions/:id",
+            get(movie_clip_handlers::movie_clip_revisions),
+        )
+        .route(
+            "/movie_clip/revision_diff",
+            get(movie_clip_handlers::movie_clip_revision_diff),
+        )
+        .route(
+            "/movie_clip/restore/:id",
+            patch(movie_clip_handlers::restore_movie_clip_revision),
+        )
+        .route(
+            "/movie_clip/trash",
+            get(movie_clip_handlers::trashed_movie_clips),
+        )
+        .route(
+            "/movie_clip/trash/:id",
+            patch(movie_clip_handlers::restore_trashed_movie_clip)
+                .delete(movie_clip_handlers::purge_movie_clip),
+        )
+        .route(
+            "/movie_clip/import",
+            put(movie_clip_handlers::import_movie_clips),
+        )
+        .with_state(movie_clip_repo.clone());
+
+    // ClipSeriesについてのAPI
+    let clip_series_repo = Arc::new(
+        infrastructure::clip_series_repository_impl::ClipSeriesPgDBRepository::new(pool.clone()),
+    );
+    let clip_series_api_router: Router<()> = Router::new()
+        .route(
+            "/clip_series",
+            put(clip_series_handlers::save_clip_series)
+                .patch(clip_series_handlers::edit_clip_series)
+                .get(clip_series_handlers::all_clip_series),
+        )
+        .route(
+            "/clip_series/query",
+            get(clip_series_handlers::get_clip_series_with_query),
+        )
+        .route(
+            "/clip_series/:id",
+            get(clip_series_handlers::find_clip_series)
+                .delete(clip_series_handlers::remove_clip_series),
+        )
+        .route(
+            "/clip_series/increment_like/:id",
+            patch(clip_series_handlers::increment_like_clip_series),
+        )
+        .route(
+            "/clip_series/unlike/:id",
+            patch(clip_series_handlers::unlike_clip_series),
+        )
+        .route(
+            "/clip_series/liked",
+            post(clip_series_handlers::liked_clip_series_ids),
+        )
+        .with_state(clip_series_repo);
+
+    // 動画の保存・編集でも利用する
+    let channel_repo =
+        Arc::new(infrastructure::channel_repository_impl::ChannelPgDBRepository::new(pool.clone()));
 
     // OriginalについてのAPI
     let original_repo = Arc::new(
@@ -145,8 +330,7 @@ async fn main_server(
         )
         .route(
             "/original/query",
-            get(video_handlers::get_videos_with_query::<Original>)
-                .post(video_handlers::get_videos_with_query::<Original>),
+            get(video_handlers::get_videos_with_query::<Original>),
         )
         .route(
             "/original/:id",
@@ -156,7 +340,59 @@ async fn main_server(
             "/original/increment_like/:id",
             patch(video_handlers::increment_like_video::<Original>),
         )
-        .with_state(original_repo);
+        .route(
+            "/original/unlike/:id",
+            patch(video_handlers::unlike_video::<Original>),
+        )
+        .route(
+            "/original/liked",
+            post(video_handlers::liked_video_ids::<Original>),
+        )
+        .route(
+            "/original/tags",
+            get(video_handlers::all_video_tags::<Original>),
+        )
+        .route(
+            "/original/channel/:id",
+            get(video_handlers::channel_videos::<Original>),
+        )
+        .route(
+            "/original/tags/:id",
+            get(video_handlers::video_tags::<Original>)
+                .put(video_handlers::add_video_tag::<Original>)
+                .delete(video_handlers::remove_video_tag::<Original>),
+        )
+        .route(
+            "/original/revisions",
+            get(video_handlers::latest_video_revisions::<Original>),
+        )
+        .route(
+            "/original/revisions/:id",
+            get(video_handlers::video_revisions::<Original>),
+        )
+        .route(
+            "/original/revision_diff",
+            get(video_handlers::video_revision_diff::<Original>),
+        )
+        .route(
+            "/original/restore/:id",
+            patch(video_handlers::restore_video_revision::<Original>),
+        )
+        .route(
+            "/original/trash",
+            get(video_handlers::trashed_videos::<Original>),
+        )
+        .route(
+            "/original/trash/:id",
+            patch(video_handlers::restore_trashed_video::<Original>)
+                .delete(video_handlers::purge_video::<Original>),
+        )
+        .route(
+            "/original/import",
+            put(video_handlers::import_videos::<Original>),
+        )
+        .with_state(original_repo.clone())
+        .layer(Extension(channel_repo.clone()));
 
     // KirinukiについてのAPI
     let kirinuki_repo = Arc::new(
@@ -171,8 +407,7 @@ async fn main_server(
         )
         .route(
             "/kirinuki/query",
-            get(video_handlers::get_videos_with_query::<Kirinuki>)
-                .post(video_handlers::get_videos_with_query::<Kirinuki>),
+            get(video_handlers::get_videos_with_query::<Kirinuki>),
         )
         .route(
             "/kirinuki/:id",
@@ -182,7 +417,84 @@ async fn main_server(
             "/kirinuki/increment_like/:id",
             patch(video_handlers::increment_like_video::<Kirinuki>),
         )
-        .with_state(kirinuki_repo);
+        .route(
+            "/kirinuki/unlike/:id",
+            patch(video_handlers::unlike_video::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/liked",
+            post(video_handlers::liked_video_ids::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/tags",
+            get(video_handlers::all_video_tags::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/channel/:id",
+            get(video_handlers::channel_videos::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/tags/:id",
+            get(video_handlers::video_tags::<Kirinuki>)
+                .put(video_handlers::add_video_tag::<Kirinuki>)
+                .delete(video_handlers::remove_video_tag::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/revisions",
+            get(video_handlers::latest_video_revisions::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/revisions/:id",
+            get(video_handlers::video_revisions::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/revision_diff",
+            get(video_handlers::video_revision_diff::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/restore/:id",
+            patch(video_handlers::restore_video_revision::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/trash",
+            get(video_handlers::trashed_videos::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/trash/:id",
+            patch(video_handlers::restore_trashed_video::<Kirinuki>)
+                .delete(video_handlers::purge_video::<Kirinuki>),
+        )
+        .route(
+            "/kirinuki/import",
+            put(video_handlers::import_videos::<Kirinuki>),
+        )
+        .with_state(kirinuki_repo.clone())
+        .layer(Extension(channel_repo.clone()));
+
+    // ChannelについてのAPI
+    let channel_api_router: Router<()> = Router::new()
+        .route(
+            "/channel",
+            put(channel_handlers::save_channel)
+                .patch(channel_handlers::edit_channel)
+                .get(channel_handlers::all_channels),
+        )
+        .route("/channel/summary", get(channel_handlers::channel_summaries))
+        .route(
+            "/channel/:id",
+            get(channel_handlers::find_channel).delete(channel_handlers::remove_channel),
+        )
+        .with_state(channel_repo);
+
+    // 検索は各ルーターと同じリポジトリを共有する
+    let search_api_router: Router<()> = Router::new()
+        .route("/search", get(search_handlers::search))
+        .with_state(search_handlers::SearchRepositories {
+            movie_clip_repo,
+            episode_repo,
+            original_repo,
+            kirinuki_repo,
+        });
 
     // アプリルーター
     let app_router: Router<()> = Router::new()
@@ -193,10 +505,20 @@ async fn main_server(
         )
         .nest(
             "/api",
-            episode_api_router
+            auth_api_router
+                .merge(user_api_router)
+                .merge(quiz_api_router)
+                .merge(episode_api_router)
                 .merge(movie_clip_api_router)
+                .merge(clip_series_api_router)
                 .merge(original_api_router)
-                .merge(kirinuki_api_router),
+                .merge(kirinuki_api_router)
+                .merge(channel_api_router)
+                .merge(search_api_router)
+                .layer(middleware::from_fn(rate_limit::limit_rate))
+                .layer(Extension(rate_limiter))
+                .layer(Extension(user_repo as SessionUserRepository))
+                .layer(Extension(auth_config)),
         );
-    Ok(app_router.into())
+    Ok(AxumConnectInfoService(app_router))
 }